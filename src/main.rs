@@ -1,15 +1,47 @@
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
 use colored::*;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
 use std::process;
-use std::time::Duration;
+use std::sync::Arc;
 
 mod modules;
 
-use modules::forecaster::WeatherForecaster;
+use modules::accuracy::{accuracy_report, AccuracyLog, LEAD_HOURS};
+use modules::aviation::parse_runway;
+use modules::cache::{CachingSource, ForecastCache};
+use modules::climate::ClimateService;
+use modules::comfort::FeelsLike;
+use modules::commands::{
+    self, BatchArgs, CheckArgs, DigestArgs, ExportArgs, PrefetchArgs, PushArgs, ReportArgs,
+    SunArgs, TmuxArgs,
+};
+use modules::compare::{parse_providers, DEFAULT_PROVIDERS};
+use modules::config::{ConfigFile, Theme};
+use modules::errors::{error_json, error_message, ErrorKind, WeatherError};
+use modules::forecaster::{parse_variables, RequestVariables, WeatherForecaster};
+use modules::hazards::HazardService;
+use modules::hourly::{parse_date, parse_start, HourlyStart};
+use modules::http::{Endpoints, HttpContext};
+use modules::icons::IconSet;
+use modules::interrupt::install_interrupt_handler;
 use modules::location::LocationService;
+use modules::models::parse_models;
+use modules::palette::ScenePalette;
+use modules::paths::Paths;
+use modules::profile::ActivityProfile;
+use modules::runner::{resolve_location, ForecastSource, LocationSource, Mode, Runner};
+use modules::sections::{Section, Sections};
+use modules::setup::{needs_setup, run_setup_wizard};
+use modules::stats::{UsageLog, UsageStats};
+use modules::timezones::parse_timezone;
 use modules::tui::WeatherTui;
 use modules::types::{DetailLevel, WeatherConfig};
 use modules::ui::WeatherUI;
+use modules::utils::{
+    to_json, ClockStyle, DisplayUnits, Language, PrecipUnit, PressureUnit, WindUnit,
+};
+use modules::widget::{WIDGET_HEIGHT, WIDGET_MIN_HEIGHT, WIDGET_MIN_WIDTH, WIDGET_WIDTH};
 
 #[derive(Parser)]
 #[command(
@@ -20,26 +52,69 @@ use modules::ui::WeatherUI;
     long_about = "A feature-rich Rust-based CLI to get weather forecasts with cyberpunk-themed animations and atmospheric weather canvas scenes"
 )]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Display mode for the application
     #[arg(short, long, default_value = "current")]
     mode: String,
 
     /// Location to check weather for (default: auto-detect from IP)
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     location: Option<String>,
 
-    /// Units to display (metric, imperial, standard)
-    #[arg(short, long, default_value = "metric")]
-    units: String,
+    /// Units to display (metric, imperial, standard; default: metric)
+    #[arg(short, long)]
+    units: Option<String>,
+
+    /// Wind speed unit (default: m/s, or mph for imperial units)
+    #[arg(long, value_enum)]
+    wind_unit: Option<WindUnit>,
+
+    /// Pressure unit (default: hPa, or inHg for imperial units)
+    #[arg(long, value_enum)]
+    pressure_unit: Option<PressureUnit>,
+
+    /// Precipitation unit (default: mm, or in for imperial units)
+    #[arg(long, value_enum)]
+    precip_unit: Option<PrecipUnit>,
+
+    /// Formula for feels-like temperatures (default: the provider's apparent temperature)
+    #[arg(long, value_enum)]
+    feels_like: Option<FeelsLike>,
+
+    /// Weather icons: emoji, nerdfont (needs a Nerd Font) or ascii; the last two keep tables aligned
+    #[arg(long, value_enum)]
+    icons: Option<IconSet>,
+
+    /// Show all times in this zone instead of the location's, e.g. Europe/Paris or America/New_York
+    #[arg(long, value_name = "ZONE", global = true, value_parser = parse_timezone)]
+    tz: Option<String>,
 
     /// Level of detail to display
     #[arg(short, long, default_value = "standard")]
     detail: String,
 
     /// Output results as JSON
-    #[arg(short, long, default_value = "false")]
+    #[arg(short, long, default_value = "false", global = true)]
     json: bool,
 
+    /// Output JSON on a single line, for jq and log pipelines (implies --json)
+    #[arg(long, global = true)]
+    json_compact: bool,
+
+    /// Read a short weather summary aloud (say, espeak or Windows speech) and print it
+    #[arg(long)]
+    speak: bool,
+
+    /// Show how the daily forecast changed since the last `--diff` for this location
+    #[arg(long)]
+    diff: bool,
+
+    /// Output format: json (same as --json) or waybar for a waybar custom module
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    format: Option<OutputFormat>,
+
     /// Disable animations
     #[arg(short = 'a', long, default_value = "false")]
     no_animations: bool,
@@ -48,472 +123,714 @@ struct Cli {
     #[arg(long, default_value = "false")]
     no_charts: bool,
 
+    /// Report sections to print, e.g. current,hourly,risk (default: all)
+    #[arg(long, value_enum, value_delimiter = ',', value_name = "SECTIONS")]
+    sections: Vec<Section>,
+
+    /// Report sections to leave out, e.g. activities,astro
+    #[arg(long, value_enum, value_delimiter = ',', value_name = "SECTIONS")]
+    hide: Vec<Section>,
+
+    /// Page long text reports through $PAGER (default when stdout is a terminal)
+    #[arg(long, overrides_with = "no_pager")]
+    pager: bool,
+
+    /// Print text reports straight to the terminal
+    #[arg(long, overrides_with = "pager")]
+    no_pager: bool,
+
     /// Run test weather canvas with mock data
     #[arg(long, default_value = "false")]
     test_charts: bool,
+
+    /// Render the weather canvas off-screen and save it (.png for an image, anything else for ANSI text)
+    #[arg(long, value_name = "PATH")]
+    screenshot: Option<PathBuf>,
+
+    /// Print only these current-weather fields (e.g. temperature,humidity,wind_speed); use --json for an object
+    #[arg(long, visible_alias = "get", value_name = "FIELDS")]
+    query: Option<String>,
+
+    /// Tailor recommendations to an activity
+    #[arg(long, value_enum, default_value = "default")]
+    profile: ActivityProfile,
+
+    /// First hour of the hourly table: now, today, tomorrow or a date, optionally with an hour ('tomorrow 14:00')
+    #[arg(long, value_name = "WHEN", value_parser = parse_start, conflicts_with = "date")]
+    start: Option<HourlyStart>,
+
+    /// Day of the hourly table (YYYY-MM-DD), up to 15 days ahead
+    #[arg(long, value_name = "DATE", value_parser = parse_date)]
+    date: Option<HourlyStart>,
+
+    /// Length of the window searched by the best-window mode (e.g. 2h, 90m)
+    #[arg(long, default_value = "2h", value_name = "DURATION")]
+    duration: String,
+
+    /// Choose the default location, units and theme (runs automatically on first launch)
+    #[arg(long)]
+    setup: bool,
+
+    /// Append recent earthquakes and other natural hazards nearby to the full report
+    #[arg(long)]
+    hazards: bool,
+
+    /// Append today's climate normals and records (1991–2020) to the full report
+    #[arg(long)]
+    normals: bool,
+
+    /// Extra Open-Meteo hourly variables to fetch, e.g. cape,freezing_level_height;
+    /// shown in JSON, batch CSV and at the detailed level
+    #[arg(long, value_name = "VARIABLES")]
+    vars: Option<String>,
+
+    /// Request only the data the selected mode shows, for metered or slow connections
+    #[arg(long)]
+    minimal: bool,
+
+    /// Always fetch the forecast, even when a cached one is still fresh
+    #[arg(long, global = true)]
+    no_cache: bool,
+
+    /// For shared environments: no IP-based location, offline timezones only,
+    /// coordinates rounded to 1 decimal and no usage log
+    #[arg(long, global = true)]
+    privacy: bool,
+
+    /// Runway for the aviation mode's crosswind check: number (27, 09L) or heading (270)
+    #[arg(long, value_name = "RUNWAY", value_parser = parse_runway)]
+    runway: Option<f64>,
+
+    /// Columns of the widget mode's card
+    #[arg(long, default_value_t = WIDGET_WIDTH as u16, value_parser = clap::value_parser!(u16).range(WIDGET_MIN_WIDTH as i64..))]
+    width: u16,
+
+    /// Rows of the widget mode's card
+    #[arg(long, default_value_t = WIDGET_HEIGHT as u16, value_parser = clap::value_parser!(u16).range(WIDGET_MIN_HEIGHT as i64..))]
+    height: u16,
+
+    /// 2-3 Open-Meteo models for the models mode (default: ecmwf_ifs025,icon_seamless,gfs_seamless)
+    #[arg(long, value_name = "MODELS")]
+    models: Option<String>,
+
+    /// Two providers for the compare mode: best_match or an Open-Meteo model (default: best_match,ecmwf_ifs025)
+    #[arg(long, value_name = "PROVIDERS", value_parser = parse_providers)]
+    providers: Option<Vec<String>>,
+
+    /// iCalendar file or URL whose outdoor events the events mode checks (default: [events] calendar)
+    #[arg(long, value_name = "PATH|URL")]
+    calendar: Option<String>,
+
+    /// Output template with Rhai expressions, e.g. '${temp}°, bike: ${wind < 8 && pop < 0.3}' (implies --mode template)
+    #[arg(long, value_name = "TEMPLATE")]
+    template: Option<String>,
+
+    /// Config file to use instead of the default (see `weather_man paths`)
+    #[arg(long, value_name = "PATH", global = true)]
+    config: Option<PathBuf>,
+
+    /// Directory for cached forecasts and feeds instead of the default
+    #[arg(long, value_name = "PATH", global = true)]
+    cache_dir: Option<PathBuf>,
+
+    /// Proxy for all requests, e.g. http://proxy:3128 (default: HTTPS_PROXY/HTTP_PROXY)
+    #[arg(long, value_name = "URL", global = true)]
+    proxy: Option<String>,
+
+    /// Extra CA certificate to trust (PEM or DER), e.g. for TLS-intercepting firewalls
+    #[arg(long, value_name = "PATH", global = true)]
+    ca_cert: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Export forecast charts (SVG/PNG) or a calendar of daily forecasts (iCalendar)
+    Export(ExportArgs),
+    /// Post a daily weather summary to a webhook (Slack, Discord or plain JSON)
+    Push(PushArgs),
+    /// Exit 0 if forecast thresholds are met in the next hours, 1 if not (2 on error)
+    Check(CheckArgs),
+    /// Compose a morning digest mail for cron: today, tomorrow, alerts and the best time outside
+    Digest(DigestArgs),
+    /// Fetch the weather for every location in a file (one name or lat,lon per line)
+    Batch(BatchArgs),
+    /// Print icon and temperature for the tmux status line, from a cached forecast
+    Tmux(TmuxArgs),
+    /// Print the time of the next sunrise or sunset, e.g. for `at` or systemd timers
+    Sun(SunArgs),
+    /// Print the colors of the current weather scene for terminal backgrounds or wallpapers
+    Palette(PaletteArgs),
+    /// Quietly cache the forecasts for the default and favorite locations (e.g. from your shell's rc file)
+    Prefetch(PrefetchArgs),
+    /// Print where the config file and caches are (with --json for scripts)
+    Paths,
+    /// Summarize a past year at the location: extremes, wettest month and degree days
+    Report(ReportArgs),
+    /// Show local statistics of forecast requests and cache hits (nothing is ever sent anywhere)
+    Stats,
+    /// Compare the forecasts logged by `push --watch` with what was observed, per provider
+    Accuracy,
+    /// List, add or remove the favorite locations shown by `--mode favorites`
+    Favorites {
+        #[command(subcommand)]
+        action: Option<FavoritesAction>,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum OutputFormat {
+    Json,
+    Waybar,
+}
+
+#[derive(Subcommand)]
+enum FavoritesAction {
+    /// List the favorite locations
+    List,
+    /// Add a favorite location
+    Add { location: String },
+    /// Remove a favorite location
+    Remove { location: String },
+}
+
+#[derive(Args)]
+struct PaletteArgs {
+    /// Print shell variable assignments (WEATHER_MAN_SKY=...) instead of JSON
+    #[arg(long)]
+    env: bool,
 }
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
+async fn main() {
     let cli = Cli::parse();
+    let json = cli.json || cli.json_compact || cli.format == Some(OutputFormat::Json);
+    if let Err(e) = run(cli).await {
+        // With --json, wrappers get the error as JSON on stdout instead
+        if json {
+            println!("{}", error_json(&e));
+        } else {
+            eprintln!("{} {}", "Error:".bright_red(), error_message(&e));
+        }
+        process::exit(ErrorKind::of(&e).exit_code());
+    }
+}
+
+async fn run(cli: Cli) -> anyhow::Result<()> {
+    install_interrupt_handler()?;
+    let paths = Paths::resolve(cli.config.clone(), cli.cache_dir.clone());
+    let mut config_file = config_file(&paths);
+    let json = cli.json || cli.json_compact;
+
+    if let Some(Command::Paths) = &cli.command {
+        return run_paths(&paths, json, cli.json_compact);
+    }
+    if let Some(Command::Stats) = &cli.command {
+        return run_stats(&paths, json, cli.json_compact);
+    }
+    if let Some(Command::Accuracy) = &cli.command {
+        return run_accuracy(&paths, json, cli.json_compact);
+    }
+
+    // First launch: ask for defaults before showing any weather
+    if let Some(path) = &paths.config_file {
+        let first_run = cli.command.is_none()
+            && cli.query.is_none()
+            && !json
+            && cli.format.is_none()
+            && !cli.test_charts
+            && needs_setup(path);
+        if cli.setup || first_run {
+            config_file = run_setup_wizard(path)?;
+        }
+    }
+    if config_file.general.theme == Theme::Monochrome {
+        colored::control::set_override(false);
+    }
+
+    if let Some(Command::Favorites { action }) = &cli.command {
+        return run_favorites(config_file, &paths, action.as_ref());
+    }
+
+    // A template on the command line replaces the configured one
+    let mode_name = match &cli.template {
+        Some(template) => {
+            config_file.templates.output = Some(template.clone());
+            Mode::Template.name()
+        }
+        // A start for the hourly table means the hourly table, unless another mode was asked for
+        None if (cli.start.is_some() || cli.date.is_some()) && cli.mode == Mode::Current.name() => {
+            Mode::Hourly.name()
+        }
+        None => cli.mode.as_str(),
+    };
 
-    // Configure based on command-line arguments
+    // Configure based on command-line arguments, then the config file
+    let units = cli
+        .units
+        .clone()
+        .or_else(|| config_file.general.units.clone())
+        .unwrap_or_else(|| "metric".to_string());
+    let unit_defaults = DisplayUnits::for_system(&units);
+    let display_units = DisplayUnits {
+        temperature: unit_defaults.temperature,
+        wind: cli.wind_unit.unwrap_or(unit_defaults.wind),
+        pressure: cli.pressure_unit.unwrap_or(unit_defaults.pressure),
+        precip: cli.precip_unit.unwrap_or(unit_defaults.precip),
+        clock: ClockStyle::from_env(),
+        language: Language::from_env(),
+    };
     let config = WeatherConfig {
-        units: cli.units,
-        location: cli.location.clone(),
-        json_output: cli.json,
+        units,
+        location: cli
+            .location
+            .clone()
+            .or_else(|| config_file.general.location.clone()),
+        json_output: json || cli.format == Some(OutputFormat::Json),
+        json_compact: cli.json_compact,
         animation_enabled: !cli.no_animations,
         detail_level: parse_detail_level(&cli.detail),
         no_charts: cli.no_charts,
+        profile: cli.profile,
+        thresholds: config_file.thresholds.clone(),
+        display_units,
+        feels_like: cli
+            .feels_like
+            .or(config_file.general.feels_like)
+            .unwrap_or_default(),
+        icons: cli.icons.or(config_file.general.icons).unwrap_or_default(),
+        sections: Sections::new(
+            if cli.sections.is_empty() {
+                &config_file.general.sections
+            } else {
+                &cli.sections
+            },
+            if cli.hide.is_empty() {
+                &config_file.general.hide
+            } else {
+                &cli.hide
+            },
+        ),
+        timezone: cli.tz.clone(),
     };
 
-    // Initialize components
-    let ui = WeatherUI::new(config.animation_enabled, config.json_output);
-    let location_service = LocationService::new();
-    let forecaster = WeatherForecaster::new(config.clone());
-
-    // Check for test charts flag first
-    if cli.test_charts {
-        return run_test_charts(config).await;
+    let needs_location = !cli.test_charts && !matches!(cli.command, Some(Command::Batch(_)));
+    let auto_location = config_file.general.auto_location && !cli.privacy;
+    if config.location.is_none() && !auto_location && needs_location {
+        return Err(WeatherError::new(
+            ErrorKind::InvalidInput,
+            "No location given and IP-based location is disabled; use --location or set one with --setup",
+        )
+        .into());
     }
 
-    // Run selected mode
-    match cli.mode.as_str() {
-        "current" => {
-            run_current_weather(
-                forecaster.clone(),
-                location_service.clone(),
-                ui.clone(),
-                config.clone(),
-            )
-            .await?
+    // Initialize components
+    let ui = WeatherUI::new(config.animation_enabled, config.json_output)
+        .with_units(display_units)
+        .with_icons(config.icons)
+        .with_sections(config.sections.clone());
+    let http = match HttpContext::with_network(cli.proxy.as_deref(), cli.ca_cert.as_deref())
+        .and_then(|http| {
+            let endpoints = Endpoints::from_settings(&config_file.api.clone().with_env())?;
+            Ok(http.with_endpoints(endpoints))
+        }) {
+        Ok(http) => http,
+        Err(e) => return Err(WeatherError::new(ErrorKind::Config, format!("{:#}", e)).into()),
+    };
+    let location_service = LocationService::new(http.clone()).with_privacy(cli.privacy);
+    let extra_variables = match &cli.vars {
+        Some(vars) => {
+            parse_variables(vars).map_err(|e| WeatherError::new(ErrorKind::InvalidInput, e))?
         }
-        "forecast" => {
-            run_forecast(
-                forecaster.clone(),
-                location_service.clone(),
-                ui.clone(),
-                config.clone(),
+        None => Vec::new(),
+    };
+    let forecaster = WeatherForecaster::new(config.clone(), http.clone())
+        .with_variables(RequestVariables::full().with_extra_hourly(extra_variables.clone()));
+    // The mode is validated again below, when it runs
+    let mode_forecaster = match mode_name.parse::<Mode>() {
+        Ok(mode) if cli.minimal && cli.screenshot.is_none() => forecaster.clone().with_variables(
+            mode.minimal_variables(!config.no_charts)
+                .with_extra_hourly(extra_variables),
+        ),
+        _ => forecaster.clone(),
+    };
+    // Trimmed or extended requests are never cached, so other runs don't get
+    // partial data or miss variables
+    let cache = paths
+        .forecasts_dir()
+        .filter(|_| !cli.no_cache && !cli.minimal && cli.vars.is_none())
+        .map(ForecastCache::new);
+    let (forecasts, locations): (Arc<dyn ForecastSource>, Arc<dyn LocationSource>) = match &cache {
+        Some(cache) => {
+            let mut source = CachingSource::new(
+                Arc::new(mode_forecaster.with_feels_like(FeelsLike::Provider)),
+                Arc::new(location_service.clone()),
+                cache.clone(),
             )
-            .await?
+            .with_feels_like(config.feels_like);
+            if let Some(path) = paths.usage_log().filter(|_| !cli.privacy) {
+                source = source.with_log(UsageLog::new(path));
+            }
+            let source = Arc::new(source);
+            (source.clone(), source)
         }
-        "hourly" => {
-            run_hourly_forecast(
-                forecaster.clone(),
-                location_service.clone(),
-                ui.clone(),
-                config.clone(),
-            )
-            .await?
+        None => (
+            Arc::new(mode_forecaster),
+            Arc::new(location_service.clone()),
+        ),
+    };
+    let mut runner = Runner::new(
+        forecasts.clone(),
+        locations.clone(),
+        Arc::new(ui),
+        config.clone(),
+    )
+    .with_settings(config_file.clone())
+    .with_duration(&cli.duration)
+    .with_widget_size(cli.width.into(), cli.height.into())
+    .with_pager(cli.pager || (!cli.no_pager && std::io::stdout().is_terminal()))
+    .with_privacy(cli.privacy)
+    .with_http(http.clone());
+    // The climate mode always compares with the archive
+    if cli.normals || mode_name == Mode::Climate.name() {
+        runner = runner.with_climate(ClimateService::new(http.clone(), paths.climate_dir()));
+    }
+    if cli.hazards {
+        runner = runner.with_hazards(HazardService::new(http.clone(), paths.hazards_dir()));
+    }
+    if let Some(heading) = cli.runway {
+        runner = runner.with_runway(heading);
+    }
+    if let Some(calendar) = &cli.calendar {
+        runner = runner.with_calendar(calendar);
+    }
+    if let Some(start) = cli.start.or(cli.date) {
+        runner = runner.with_hourly_start(start);
+    }
+    if let Some(models) = &cli.models {
+        let models =
+            parse_models(models).map_err(|e| WeatherError::new(ErrorKind::InvalidInput, e))?;
+        runner = runner.with_models(models);
+    }
+    if mode_name == Mode::Compare.name() {
+        let names = cli
+            .providers
+            .clone()
+            .unwrap_or_else(|| DEFAULT_PROVIDERS.map(str::to_string).to_vec());
+        let providers = names
+            .into_iter()
+            .map(|name| {
+                let source: Arc<dyn ForecastSource> =
+                    Arc::new(forecaster.clone().with_model(&name));
+                (name, source)
+            })
+            .collect();
+        runner = runner.with_providers(providers);
+    }
+
+    match &cli.command {
+        Some(Command::Export(args)) => {
+            let climate = ClimateService::new(http.clone(), paths.climate_dir());
+            return commands::export(&forecaster, &location_service, &climate, &config, args).await;
         }
-        "daily" => {
-            run_daily_forecast(
-                forecaster.clone(),
-                location_service.clone(),
-                ui.clone(),
-                config.clone(),
+        Some(Command::Push(args)) => {
+            let accuracy = paths.accuracy_log().map(AccuracyLog::new);
+            return commands::push(
+                &forecaster,
+                &location_service,
+                &http,
+                &config,
+                &config_file,
+                accuracy.as_ref(),
+                args,
             )
-            .await?
+            .await;
         }
-        "full" => {
-            run_full_weather(
-                forecaster.clone(),
-                location_service.clone(),
-                ui.clone(),
-                config.clone(),
+        Some(Command::Check(args)) => {
+            let checked = commands::check(&forecaster, &location_service, &config, args).await;
+            let code = match checked {
+                Ok(true) => 0,
+                Ok(false) => 1,
+                // Exit codes 0 and 1 answer the check, so every error is 2
+                Err(e) if config.json_output => {
+                    println!("{}", error_json(&e));
+                    2
+                }
+                Err(e) => {
+                    eprintln!("{} {}", "Error:".bright_red(), error_message(&e));
+                    2
+                }
+            };
+            process::exit(code);
+        }
+        Some(Command::Digest(args)) => {
+            let settings = config_file.email.clone().with_env();
+            return commands::digest(
+                forecasts.as_ref(),
+                locations.as_ref(),
+                &config,
+                &settings,
+                args,
             )
-            .await?
+            .await;
+        }
+        Some(Command::Batch(args)) => {
+            return commands::batch(&forecaster, &location_service, args, config.json_compact)
+                .await;
         }
-        "interactive" => {
-            run_interactive_menu(
-                forecaster.clone(),
-                location_service.clone(),
-                ui.clone(),
-                config.clone(),
+        Some(Command::Sun(args)) => {
+            return commands::sun(forecasts.as_ref(), locations.as_ref(), &config, args).await;
+        }
+        Some(Command::Palette(args)) => {
+            let location = resolve_location(locations.as_ref(), &config).await?;
+            let weather = forecasts.current_weather(&location).await?;
+            let palette = ScenePalette::from_weather(&weather);
+            if args.env {
+                print!("{}", palette.to_env());
+            } else {
+                println!("{}", to_json(&palette, config.json_compact)?);
+            }
+            return Ok(());
+        }
+        Some(Command::Tmux(args)) => {
+            let cache = paths.forecasts_dir().map(ForecastCache::new);
+            let log = paths
+                .usage_log()
+                .filter(|_| !cli.privacy)
+                .map(UsageLog::new);
+            // The tmux cache is shared with the other runs, which apply the formula themselves
+            let forecaster = forecaster.with_feels_like(FeelsLike::Provider);
+            return commands::tmux(
+                &forecaster,
+                &location_service,
+                cache.as_ref(),
+                log.as_ref(),
+                &config,
+                args,
             )
-            .await?
+            .await;
         }
-        "canvas" => {
-            run_charts_mode(forecaster.clone(), location_service.clone(), config.clone()).await?
+        Some(Command::Prefetch(args)) => {
+            let favorites = &config_file.favorites.locations;
+            return commands::prefetch(
+                forecasts.as_ref(),
+                locations.as_ref(),
+                &config,
+                favorites,
+                args,
+            )
+            .await;
         }
-        _ => {
-            eprintln!("{}", "Invalid mode specified!".bright_red());
-            eprintln!("Valid modes: current, forecast, hourly, daily, full, interactive, canvas");
-            process::exit(1);
+        Some(Command::Report(args)) => {
+            let climate = ClimateService::new(http.clone(), paths.climate_dir());
+            return commands::report(&location_service, &climate, &config, args).await;
         }
+        Some(Command::Favorites { .. })
+        | Some(Command::Paths)
+        | Some(Command::Stats)
+        | Some(Command::Accuracy)
+        | None => {}
     }
 
-    Ok(())
-}
-
-async fn run_current_weather(
-    forecaster: WeatherForecaster,
-    location_service: LocationService,
-    ui: WeatherUI,
-    config: WeatherConfig,
-) -> Result<(), Box<dyn std::error::Error>> {
-    if !config.json_output {
-        ui.show_welcome_banner()?;
-        ui.show_connecting_animation()?;
+    // Check for test charts flag first
+    if cli.test_charts {
+        return run_test_charts(config, &config_file, cli.screenshot.as_deref()).await;
     }
 
-    // Determine location (auto-detect or use provided)
-    let location = match &config.location {
-        Some(loc) => location_service.get_location_by_name(loc).await?,
-        None => location_service.get_location_from_ip().await?,
-    };
-
-    if !config.json_output {
-        ui.show_location_info(&location)?;
+    if let Some(fields) = &cli.query {
+        return commands::query(&forecaster, &location_service, &config, fields).await;
     }
 
-    // Get current weather
-    let weather = forecaster.get_current_weather(&location).await?;
-
-    // Display results
-    if config.json_output {
-        println!("{}", serde_json::to_string_pretty(&weather)?);
-    } else {
-        ui.show_current_weather(&weather, &location)?;
-        ui.show_weather_recommendations(&weather)?;
-
-        // Show weather canvas unless disabled
-        if !config.no_charts {
-            println!("\n🌤️  Loading interactive weather view...");
-            if let Err(e) = run_charts_mode(forecaster, location_service, config).await {
-                eprintln!("⚠️  Weather view unavailable: {}", e);
-                eprintln!("💡 Try running with --no-charts for text-only output");
-            }
+    if cli.format == Some(OutputFormat::Waybar) {
+        if !commands::waybar(&forecaster, &location_service, &config).await? {
+            process::exit(1);
         }
+        return Ok(());
     }
 
-    Ok(())
-}
-
-async fn run_forecast(
-    forecaster: WeatherForecaster,
-    location_service: LocationService,
-    ui: WeatherUI,
-    config: WeatherConfig,
-) -> Result<(), Box<dyn std::error::Error>> {
-    if !config.json_output {
-        ui.show_welcome_banner()?;
-        ui.show_connecting_animation()?;
+    if cli.speak {
+        return commands::speak(forecasts.as_ref(), locations.as_ref(), &config).await;
     }
 
-    // Determine location
-    let location = match &config.location {
-        Some(loc) => location_service.get_location_by_name(loc).await?,
-        None => location_service.get_location_from_ip().await?,
-    };
-
-    if !config.json_output {
-        ui.show_location_info(&location)?;
+    if cli.diff {
+        let snapshots = paths
+            .snapshots_dir()
+            .map(ForecastCache::new)
+            .ok_or_else(|| {
+                anyhow::anyhow!("No cache directory to keep forecasts in; give --cache-dir")
+            })?;
+        return commands::diff(forecasts.as_ref(), locations.as_ref(), &snapshots, &config).await;
     }
 
-    // Get weather forecast
-    let forecast = forecaster.get_forecast(&location).await?;
-
-    // Display results
-    if config.json_output {
-        println!("{}", serde_json::to_string_pretty(&forecast)?);
-    } else {
-        ui.show_forecast(&forecast, &location)?;
-
-        // Show weather canvas unless disabled
-        if !config.no_charts {
-            println!("\n🌤️  Loading interactive weather view...");
-            if let Err(e) = run_charts_mode(forecaster, location_service, config).await {
-                eprintln!("⚠️  Weather view unavailable: {}", e);
-                eprintln!("💡 Try running with --no-charts for text-only output");
-            }
-        }
+    // A screenshot always captures the canvas view, whatever the mode
+    if let Some(path) = cli.screenshot.as_deref() {
+        return runner.screenshot(path).await;
     }
 
+    // Run selected mode
+    let mode = mode_name
+        .parse::<Mode>()
+        .map_err(|e| WeatherError::new(ErrorKind::InvalidInput, e.to_string()))?;
+    runner.run(mode).await?;
+
     Ok(())
 }
 
-async fn run_daily_forecast(
-    forecaster: WeatherForecaster,
-    location_service: LocationService,
-    ui: WeatherUI,
-    config: WeatherConfig,
-) -> Result<(), Box<dyn std::error::Error>> {
-    if !config.json_output {
-        ui.show_welcome_banner()?;
-        ui.show_connecting_animation()?;
-    }
-
-    // Determine location
-    let location = match &config.location {
-        Some(loc) => location_service.get_location_by_name(loc).await?,
-        None => location_service.get_location_from_ip().await?,
-    };
-
-    if !config.json_output {
-        ui.show_location_info(&location)?;
-    }
-
-    // Get daily forecast
-    let forecast = forecaster.get_daily_forecast(&location).await?;
-
-    // Display results
-    if config.json_output {
-        println!("{}", serde_json::to_string_pretty(&forecast)?);
-    } else {
-        ui.show_daily_forecast(&forecast, &location)?;
+/// Settings from the config file, falling back to defaults if it is invalid
+fn config_file(paths: &Paths) -> ConfigFile {
+    ConfigFile::load(paths.config_file.as_deref()).unwrap_or_else(|e| {
+        eprintln!("⚠️  Ignoring config file: {:#}", e);
+        ConfigFile::default()
+    })
+}
 
-        // Show weather canvas unless disabled
-        if !config.no_charts {
-            println!("\n🌤️  Loading interactive weather view...");
-            if let Err(e) = run_charts_mode(forecaster, location_service, config).await {
-                eprintln!("⚠️  Weather view unavailable: {}", e);
-                eprintln!("💡 Try running with --no-charts for text-only output");
-            }
-        }
+/// Print where the config file and caches are
+fn run_paths(paths: &Paths, json: bool, compact: bool) -> anyhow::Result<()> {
+    if json {
+        println!("{}", to_json(paths, compact)?);
+        return Ok(());
     }
-
+    let show = |path: &Option<PathBuf>| match path {
+        Some(path) if path.exists() => path.display().to_string(),
+        Some(path) => format!("{} (not created yet)", path.display()),
+        None => "none found".to_string(),
+    };
+    println!("Config file: {}", show(&paths.config_file));
+    println!("Cache:       {}", show(&paths.cache_dir));
     Ok(())
 }
 
-async fn run_hourly_forecast(
-    forecaster: WeatherForecaster,
-    location_service: LocationService,
-    ui: WeatherUI,
-    config: WeatherConfig,
-) -> Result<(), Box<dyn std::error::Error>> {
-    if !config.json_output {
-        ui.show_welcome_banner()?;
-        ui.show_connecting_animation()?;
+/// Summarize the local usage log
+fn run_stats(paths: &Paths, json: bool, compact: bool) -> anyhow::Result<()> {
+    let events = match paths.usage_log() {
+        Some(path) => UsageLog::new(path).read()?,
+        None => Vec::new(),
+    };
+    let stats = UsageStats::from_events(&events);
+    if json {
+        println!("{}", to_json(&stats, compact)?);
+        return Ok(());
     }
-
-    // Determine location
-    let location = match &config.location {
-        Some(loc) => location_service.get_location_by_name(loc).await?,
-        None => location_service.get_location_from_ip().await?,
+    let Some(since) = stats.since else {
+        println!("No forecast requests logged yet");
+        return Ok(());
     };
 
-    if !config.json_output {
-        ui.show_location_info(&location)?;
+    println!(
+        "📊 {} forecast requests since {}",
+        stats.requests,
+        since.format("%Y-%m-%d")
+    );
+    println!(
+        "   Cache hits: {} ({:.0}%), API calls saved",
+        stats.cache_hits,
+        stats.hit_rate * 100.0
+    );
+    match (stats.average_latency_ms, stats.slowest_latency_ms) {
+        (Some(average), Some(slowest)) => println!(
+            "   API calls:  {} (average {} ms, slowest {} ms)",
+            stats.api_calls, average, slowest
+        ),
+        _ => println!("   API calls:  {}", stats.api_calls),
     }
-
-    // Get hourly forecast
-    let forecast = forecaster.get_hourly_forecast(&location).await?;
-
-    // Display results
-    if config.json_output {
-        println!("{}", serde_json::to_string_pretty(&forecast)?);
-    } else {
-        ui.show_hourly_forecast(&forecast, &location)?;
-
-        // Show weather canvas unless disabled
-        if !config.no_charts {
-            println!("\n🌤️  Loading interactive weather view...");
-            if let Err(e) = run_charts_mode(forecaster, location_service, config).await {
-                eprintln!("⚠️  Weather view unavailable: {}", e);
-                eprintln!("💡 Try running with --no-charts for text-only output");
-            }
-        }
+    println!("   Top locations:");
+    for (location, count) in &stats.top_locations {
+        println!("     {:<24} {}", location, count);
     }
-
     Ok(())
 }
 
-async fn run_full_weather(
-    forecaster: WeatherForecaster,
-    location_service: LocationService,
-    ui: WeatherUI,
-    config: WeatherConfig,
-) -> Result<(), Box<dyn std::error::Error>> {
-    if !config.json_output {
-        ui.show_welcome_banner()?;
-        ui.show_connecting_animation()?;
-    }
-
-    // Determine location
-    let location = match &config.location {
-        Some(loc) => location_service.get_location_by_name(loc).await?,
-        None => location_service.get_location_from_ip().await?,
+fn run_accuracy(paths: &Paths, json: bool, compact: bool) -> anyhow::Result<()> {
+    let entries = match paths.accuracy_log() {
+        Some(path) => AccuracyLog::new(path).read()?,
+        None => Vec::new(),
     };
-
-    if !config.json_output {
-        ui.show_location_info(&location)?;
+    let report = accuracy_report(&entries);
+    if json {
+        println!("{}", to_json(&report, compact)?);
+        return Ok(());
     }
-
-    // Get current weather, hourly and daily forecasts
-    let current = forecaster.get_current_weather(&location).await?;
-    let hourly = forecaster.get_hourly_forecast(&location).await?;
-    let daily = forecaster.get_daily_forecast(&location).await?;
-
-    // Display results
-    if config.json_output {
-        let full_data = serde_json::json!({
-            "current": current,
-            "hourly": hourly,
-            "daily": daily,
-        });
-        println!("{}", serde_json::to_string_pretty(&full_data)?);
-    } else {
-        ui.show_current_weather(&current, &location)?;
-
-        if config.animation_enabled {
-            std::thread::sleep(Duration::from_millis(800));
-        }
-
-        ui.show_hourly_forecast(&hourly, &location)?;
-
-        if config.animation_enabled {
-            std::thread::sleep(Duration::from_millis(800));
-        }
-
-        ui.show_daily_forecast(&daily, &location)?;
-        ui.show_weather_recommendations(&current)?;
-
-        // Show weather canvas unless disabled
-        if !config.no_charts {
-            // First run the weather canvas mode in a separate function
-            run_charts_mode(forecaster, location_service, config).await?;
-        }
+    if report.is_empty() {
+        println!(
+            "No verified forecasts yet; keep `weather_man push --watch` running for a few hours"
+        );
+        return Ok(());
     }
 
+    println!("🎯 Forecast accuracy for the next {} hours", LEAD_HOURS);
+    println!(
+        "   {:<16} {:>8} {:>12} {:>8}",
+        "Provider", "Hours", "Temp MAE", "Brier"
+    );
+    for provider in &report {
+        println!(
+            "   {:<16} {:>8} {:>11.1}° {:>8.3}",
+            provider.provider, provider.verified, provider.temperature_mae, provider.brier_score
+        );
+    }
+    println!("   Lower is better; a Brier score of 0.25 is no better than a 50% guess");
     Ok(())
 }
 
-async fn run_interactive_menu(
-    forecaster: WeatherForecaster,
-    location_service: LocationService,
-    ui: WeatherUI,
-    config: WeatherConfig,
-) -> Result<(), Box<dyn std::error::Error>> {
-    ui.show_welcome_banner()?;
-
-    // Loop until exit
-    loop {
-        let choice = ui.show_interactive_menu(!config.no_charts)?;
-
-        match choice.as_str() {
-            "current" => {
-                // Clear terminal first for clean output
-                print!("\x1B[2J\x1B[1;1H");
-                run_current_weather(
-                    forecaster.clone(),
-                    location_service.clone(),
-                    ui.clone(),
-                    config.clone(),
-                )
-                .await?;
+fn run_favorites(
+    mut config_file: ConfigFile,
+    paths: &Paths,
+    action: Option<&FavoritesAction>,
+) -> anyhow::Result<()> {
+    let favorites = &mut config_file.favorites;
+    let changed = match action {
+        None | Some(FavoritesAction::List) => {
+            if favorites.locations.is_empty() {
+                println!("No favorite locations yet; add one with `weather_man favorites add <LOCATION>`");
             }
-            "hourly" => {
-                run_hourly_forecast(
-                    forecaster.clone(),
-                    location_service.clone(),
-                    ui.clone(),
-                    config.clone(),
-                )
-                .await?;
+            for location in &favorites.locations {
+                println!("⭐ {}", location);
             }
-            "daily" => {
-                run_daily_forecast(
-                    forecaster.clone(),
-                    location_service.clone(),
-                    ui.clone(),
-                    config.clone(),
-                )
-                .await?;
-            }
-            "full" => {
-                run_full_weather(
-                    forecaster.clone(),
-                    location_service.clone(),
-                    ui.clone(),
-                    config.clone(),
-                )
-                .await?;
-            }
-            "change_location" => {
-                // Prompt for a new location
-                let new_location = ui.prompt_for_location()?;
-                let mut new_config = config.clone();
-                new_config.location = Some(new_location);
-
-                run_full_weather(
-                    forecaster.clone(),
-                    location_service.clone(),
-                    ui.clone(),
-                    new_config,
-                )
-                .await?;
+            return Ok(());
+        }
+        Some(FavoritesAction::Add { location }) => {
+            let added = favorites.add(location);
+            if !added {
+                println!("{} is already a favorite", location);
             }
-            "change_units" => {
-                // Prompt for units
-                let new_units = ui.prompt_for_units()?;
-                let mut new_config = config.clone();
-                new_config.units = new_units;
-
-                run_full_weather(
-                    forecaster.clone(),
-                    location_service.clone(),
-                    ui.clone(),
-                    new_config,
+            added
+        }
+        Some(FavoritesAction::Remove { location }) => {
+            let removed = favorites.remove(location);
+            if !removed {
+                return Err(WeatherError::new(
+                    ErrorKind::InvalidInput,
+                    format!("{} is not a favorite", location),
                 )
-                .await?;
-            }
-            "canvas" => {
-                // Get hourly and daily forecasts for weather canvas
-                let hourly = forecaster
-                    .get_hourly_forecast(&location_service.get_location_from_ip().await?)
-                    .await?;
-                let daily = forecaster
-                    .get_daily_forecast(&location_service.get_location_from_ip().await?)
-                    .await?;
-
-                // Create and run the TUI
-                let mut tui = WeatherTui::new(
-                    hourly,
-                    daily,
-                    location_service.get_location_from_ip().await?,
-                    config.clone(),
-                )?;
-                tui.run()?;
-            }
-            "exit" => break,
-            _ => {
-                eprintln!("{}", "Invalid option selected!".bright_red());
+                .into());
             }
+            removed
         }
-    }
-
-    Ok(())
-}
-
-async fn run_charts_mode(
-    forecaster: WeatherForecaster,
-    location_service: LocationService,
-    config: WeatherConfig,
-) -> Result<(), Box<dyn std::error::Error>> {
-    // Determine location (auto-detect or use provided)
-    let location = match &config.location {
-        Some(loc) => location_service.get_location_by_name(loc).await?,
-        None => location_service.get_location_from_ip().await?,
     };
 
-    // Get the data we need for the charts
-    let hourly = forecaster.get_hourly_forecast(&location).await?;
-    let daily = forecaster.get_daily_forecast(&location).await?;
-
-    // Clear screen for clean TUI transition
-    print!("\x1B[2J\x1B[1;1H");
-    std::io::Write::flush(&mut std::io::stdout()).unwrap_or(());
+    if changed {
+        let path = paths
+            .config_file
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("No config directory found"))?;
+        config_file.save_to(path)?;
+        println!(
+            "⭐ Favorites: {}",
+            config_file.favorites.locations.join(", ")
+        );
+    }
 
-    // Create and run the TUI directly
-    let mut tui = WeatherTui::new(hourly, daily, location, config)?;
-    tui.run()?;
     Ok(())
 }
 
-async fn run_test_charts(config: WeatherConfig) -> Result<(), Box<dyn std::error::Error>> {
+async fn run_test_charts(
+    config: WeatherConfig,
+    settings: &ConfigFile,
+    screenshot: Option<&Path>,
+) -> anyhow::Result<()> {
     use chrono::Utc;
-    use modules::types::{DailyForecast, HourlyForecast, Location, WeatherCondition};
+    use modules::types::{
+        DailyForecast, HourlyForecast, Hpa, Intensity, Location, Percent, WeatherCondition,
+    };
 
     println!("🧪 Testing Weather Canvas TUI");
     println!("===============================");
@@ -528,6 +845,7 @@ async fn run_test_charts(config: WeatherConfig) -> Result<(), Box<dyn std::error
         timezone: "UTC".to_string(),
         region: Some("Test Region".to_string()),
         state: Some("Test State".to_string()),
+        display_timezone: None,
     };
 
     // Generate test hourly data
@@ -539,21 +857,19 @@ async fn run_test_charts(config: WeatherConfig) -> Result<(), Box<dyn std::error
             timestamp: base_time + chrono::Duration::hours(i),
             temperature: 20.0 + (i as f64 * 0.5),
             feels_like: 18.0 + (i as f64 * 0.5),
-            humidity: 60 + (i % 20) as u8,
-            pressure: 1013 + (i % 10) as u32,
+            humidity: Percent(60.0 + (i % 20) as f64),
+            pressure: Hpa(1013.0 + (i % 10) as f64),
             wind_speed: 5.0 + (i as f64 * 0.2),
             wind_direction: (i * 15) as u16,
-            conditions: vec![],
             main_condition: if i % 4 == 0 {
                 WeatherCondition::Rain
             } else {
                 WeatherCondition::Clear
             },
             pop: (i as f64 * 0.04).min(1.0),
-            visibility: 10000,
-            clouds: (i * 5) as u8,
+            clouds: Percent((i * 5) as f64),
             rain: if i % 4 == 0 { Some(0.5) } else { None },
-            snow: None,
+            ..Default::default()
         };
         hourly_data.push(forecast);
     }
@@ -574,22 +890,30 @@ async fn run_test_charts(config: WeatherConfig) -> Result<(), Box<dyn std::error
             temp_max: 28.0 + (i as f64),
             feels_like_day: 23.0 + (i as f64),
             feels_like_night: 8.0 + (i as f64),
-            pressure: 1015 + (i % 5) as u32,
-            humidity: 65 + (i % 15) as u8,
+            pressure: Hpa(1015.0 + (i % 5) as f64),
+            humidity: Percent(65.0 + (i % 15) as f64),
             wind_speed: 4.0 + (i as f64 * 0.3),
             wind_direction: (i * 30) as u16,
+            wind_gusts: None,
             conditions: vec![],
-            main_condition: match i % 5 {
+            // Dust, squall and tornado are never sent by Open-Meteo
+            main_condition: match i {
                 0 => WeatherCondition::Clear,
-                1 => WeatherCondition::Clouds,
-                2 => WeatherCondition::Rain,
-                3 => WeatherCondition::Snow,
-                _ => WeatherCondition::Thunderstorm,
+                1 => WeatherCondition::Rain,
+                2 => WeatherCondition::Snow,
+                3 => WeatherCondition::Thunderstorm,
+                4 => WeatherCondition::Dust,
+                5 => WeatherCondition::Squall,
+                _ => WeatherCondition::Tornado,
             },
-            clouds: (i * 15) as u8,
+            intensity: Intensity::Moderate,
+            raw_code: None,
+            provider_extra: None,
+            clouds: Percent((i * 15) as f64),
             pop: (i as f64 * 0.15).min(1.0),
             rain: if i % 3 == 0 { Some(1.5) } else { None },
             snow: if i == 3 { Some(2.0) } else { None },
+            precipitation: None,
             uv_index: (i as f64 * 1.5).min(10.0),
         };
         daily_data.push(forecast);
@@ -597,13 +921,20 @@ async fn run_test_charts(config: WeatherConfig) -> Result<(), Box<dyn std::error
 
     println!("📊 Created {} hourly forecasts", hourly_data.len());
     println!("📅 Created {} daily forecasts", daily_data.len());
+
+    if let Some(path) = screenshot {
+        WeatherTui::screenshot(hourly_data, daily_data, location, config, settings, path)?;
+        println!("📸 Screenshot saved to {}", path.display());
+        return Ok(());
+    }
+
     println!("🎯 Starting TUI in 2 seconds...");
-    println!("💡 Use arrow keys or 1-5 to switch tabs, 'q' to exit");
+    println!("💡 Use arrow keys or 1-6 to switch tabs, 'q' to exit");
 
     std::thread::sleep(std::time::Duration::from_millis(2000));
 
     // Create and run TUI
-    let mut tui = WeatherTui::new(hourly_data, daily_data, location, config)?;
+    let mut tui = WeatherTui::new(hourly_data, daily_data, location, config, settings)?;
     tui.run()?;
 
     println!("✅ TUI test completed successfully!");
@@ -0,0 +1,124 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
+use crate::modules::types::{HourlyForecast, Location};
+use crate::modules::ui::convert_to_local;
+use crate::modules::utils::PrecipUnit;
+
+/// Accumulation windows, in hours from now
+pub const WINDOWS: [usize; 3] = [6, 12, 24];
+/// Length of the wettest stretch picked out of the 24-hour window
+pub const PEAK_HOURS: usize = 6;
+/// Totals below this (mm) count as dry
+const TRACE_MM: f64 = 0.1;
+
+/// Rain and snow expected over the next `hours` hours, in mm
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct WindowTotal {
+    pub hours: usize,
+    pub rain: f64,
+    pub snow: f64,
+    pub total: f64,
+}
+
+/// The wettest `PEAK_HOURS` stretch of the longest window
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct PeakWindow {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub total: f64,
+}
+
+/// Rolling precipitation totals from the current hour
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Accumulation {
+    pub windows: Vec<WindowTotal>,
+    pub peak: Option<PeakWindow>,
+}
+
+impl Accumulation {
+    /// The longest window, e.g. the next 24 hours
+    pub fn longest(&self) -> Option<&WindowTotal> {
+        self.windows.last()
+    }
+}
+
+fn amount(hour: &HourlyForecast) -> f64 {
+    hour.rain.unwrap_or(0.0) + hour.snow.unwrap_or(0.0)
+}
+
+/// Totals for each of `WINDOWS` from the hour containing `now`; `None` when
+/// the forecast has no upcoming hours
+pub fn accumulation(hourly: &[HourlyForecast], now: DateTime<Utc>) -> Option<Accumulation> {
+    let longest = WINDOWS[WINDOWS.len() - 1];
+    let hours: Vec<&HourlyForecast> = hourly
+        .iter()
+        .filter(|h| h.timestamp + Duration::hours(1) > now)
+        .take(longest)
+        .collect();
+    if hours.is_empty() {
+        return None;
+    }
+
+    let windows = WINDOWS
+        .iter()
+        .map(|&window| {
+            let span = &hours[..window.min(hours.len())];
+            let rain: f64 = span.iter().map(|h| h.rain.unwrap_or(0.0)).sum();
+            let snow: f64 = span.iter().map(|h| h.snow.unwrap_or(0.0)).sum();
+            WindowTotal {
+                hours: window,
+                rain,
+                snow,
+                total: rain + snow,
+            }
+        })
+        .collect();
+
+    let peak = hours
+        .windows(PEAK_HOURS.min(hours.len()))
+        .map(|span| (span, span.iter().map(|h| amount(h)).sum::<f64>()))
+        // The first of equally wet stretches
+        .reduce(|best, next| if next.1 > best.1 { next } else { best })
+        .filter(|(_, total)| *total >= TRACE_MM)
+        .map(|(span, total)| PeakWindow {
+            start: span[0].timestamp,
+            end: span[span.len() - 1].timestamp + Duration::hours(1),
+            total,
+        });
+
+    Some(Accumulation { windows, peak })
+}
+
+/// One line for the longest window, e.g. "12.0 mm expected in the next 24h
+/// (8.0 mm between 14:00–20:00)"
+pub fn accumulation_text(
+    accumulation: &Accumulation,
+    location: &Location,
+    unit: PrecipUnit,
+) -> Option<String> {
+    let longest = accumulation.longest()?;
+    if longest.total < TRACE_MM {
+        return Some(format!(
+            "No precipitation expected in the next {}h",
+            longest.hours
+        ));
+    }
+
+    let mut text = format!(
+        "{} expected in the next {}h",
+        unit.format(longest.total),
+        longest.hours
+    );
+    if let Some(peak) = &accumulation.peak {
+        let time =
+            |t: &DateTime<Utc>| convert_to_local(t, location.clock_timezone()).format("%H:%M");
+        text.push_str(&format!(
+            " ({} between {}–{})",
+            unit.format(peak.total),
+            time(&peak.start),
+            time(&peak.end)
+        ));
+    }
+    Some(text)
+}
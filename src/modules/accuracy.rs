@@ -0,0 +1,200 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::modules::status::write_status;
+use crate::modules::types::Forecast;
+
+/// Hours ahead whose forecast is recorded on each refresh
+pub const LEAD_HOURS: i64 = 24;
+/// The log is trimmed to its newest entries once it grows past this size
+const MAX_LOG_BYTES: u64 = 4 * 1024 * 1024;
+/// Entries kept when the log is trimmed, about two weeks of hourly refreshes
+const KEEP_ENTRIES: usize = 20_000;
+
+/// A line of the accuracy log: a forecast for an hour, or what that hour
+/// turned out like
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum AccuracyEntry {
+    Forecast {
+        provider: String,
+        location: String,
+        issued: DateTime<Utc>,
+        valid: DateTime<Utc>,
+        temperature: f64,
+        /// Probability of precipitation, 0-1
+        pop: f64,
+    },
+    Observed {
+        location: String,
+        time: DateTime<Utc>,
+        temperature: f64,
+        precipitation: bool,
+    },
+}
+
+/// The entries to log for a refresh: the next `LEAD_HOURS` forecast hours
+/// and the current conditions, on the hour
+pub fn accuracy_entries(
+    forecast: &Forecast,
+    provider: &str,
+    location: &str,
+    now: DateTime<Utc>,
+) -> Vec<AccuracyEntry> {
+    let hour = truncate_to_hour(now);
+    let mut entries: Vec<AccuracyEntry> = forecast
+        .hourly
+        .iter()
+        .filter(|h| h.timestamp > hour && h.timestamp <= hour + Duration::hours(LEAD_HOURS))
+        .map(|h| AccuracyEntry::Forecast {
+            provider: provider.to_string(),
+            location: location.to_string(),
+            issued: now,
+            valid: h.timestamp,
+            temperature: h.temperature,
+            pop: h.pop,
+        })
+        .collect();
+
+    if let Some(current) = &forecast.current {
+        entries.push(AccuracyEntry::Observed {
+            location: location.to_string(),
+            time: truncate_to_hour(current.timestamp),
+            temperature: current.temperature,
+            precipitation: current.rain_last_hour.unwrap_or(0.0)
+                + current.snow_last_hour.unwrap_or(0.0)
+                > 0.0,
+        });
+    }
+    entries
+}
+
+fn truncate_to_hour(time: DateTime<Utc>) -> DateTime<Utc> {
+    time.duration_trunc(Duration::hours(1)).unwrap_or(time)
+}
+
+/// Forecasts and observations logged by `push --watch`, one JSON object per line
+#[derive(Debug, Clone)]
+pub struct AccuracyLog {
+    path: PathBuf,
+}
+
+impl AccuracyLog {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Append entries, trimming the log when it gets large
+    pub fn record(&self, entries: &[AccuracyEntry]) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Could not open {}", self.path.display()))?;
+        let mut lines = String::new();
+        for entry in entries {
+            lines.push_str(&serde_json::to_string(entry)?);
+            lines.push('\n');
+        }
+        file.write_all(lines.as_bytes())?;
+
+        if file.metadata()?.len() > MAX_LOG_BYTES {
+            let entries = self.read()?;
+            let start = entries.len().saturating_sub(KEEP_ENTRIES);
+            let mut lines = String::new();
+            for entry in &entries[start..] {
+                lines.push_str(&serde_json::to_string(entry)?);
+                lines.push('\n');
+            }
+            write_status(&self.path, &lines)?;
+        }
+        Ok(())
+    }
+
+    /// All logged entries, oldest first; unreadable lines are skipped
+    pub fn read(&self) -> Result<Vec<AccuracyEntry>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let contents = fs::read_to_string(&self.path)
+            .with_context(|| format!("Could not read {}", self.path.display()))?;
+        Ok(contents
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect())
+    }
+}
+
+/// How well one provider's forecasts matched what was observed
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProviderAccuracy {
+    pub provider: String,
+    /// Forecast hours that could be checked against an observation
+    pub verified: usize,
+    /// Mean absolute temperature error, in the units the forecasts were logged in
+    pub temperature_mae: f64,
+    /// Brier score of the rain chances: 0 is perfect, 0.25 is no better
+    /// than always saying 50%
+    pub brier_score: f64,
+}
+
+/// Score each provider's logged forecasts against the observations for the
+/// same location and hour, best temperature forecast first
+pub fn accuracy_report(entries: &[AccuracyEntry]) -> Vec<ProviderAccuracy> {
+    let observed: HashMap<(&str, DateTime<Utc>), (f64, bool)> = entries
+        .iter()
+        .filter_map(|entry| match entry {
+            AccuracyEntry::Observed {
+                location,
+                time,
+                temperature,
+                precipitation,
+            } => Some(((location.as_str(), *time), (*temperature, *precipitation))),
+            _ => None,
+        })
+        .collect();
+
+    // Sums of absolute temperature errors and squared rain errors per provider
+    let mut sums: BTreeMap<&str, (usize, f64, f64)> = BTreeMap::new();
+    for entry in entries {
+        let AccuracyEntry::Forecast {
+            provider,
+            location,
+            valid,
+            temperature,
+            pop,
+            ..
+        } = entry
+        else {
+            continue;
+        };
+        let Some((actual, precipitation)) = observed.get(&(location.as_str(), *valid)) else {
+            continue;
+        };
+        let outcome = if *precipitation { 1.0 } else { 0.0 };
+        let sum = sums.entry(provider.as_str()).or_default();
+        sum.0 += 1;
+        sum.1 += (temperature - actual).abs();
+        sum.2 += (pop - outcome).powi(2);
+    }
+
+    let mut report: Vec<ProviderAccuracy> = sums
+        .into_iter()
+        .map(|(provider, (count, temperature, rain))| ProviderAccuracy {
+            provider: provider.to_string(),
+            verified: count,
+            temperature_mae: temperature / count as f64,
+            brier_score: rain / count as f64,
+        })
+        .collect();
+    report.sort_by(|a, b| a.temperature_mae.total_cmp(&b.temperature_mae));
+    report
+}
@@ -0,0 +1,131 @@
+use crate::modules::types::{DailyForecast, HourlyForecast, Location};
+use crate::modules::ui::convert_to_local;
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// Comfortable temperature range in °C; each degree outside costs 3 points (max 40)
+const IDEAL_TEMP: (f64, f64) = (10.0, 20.0);
+
+/// Outdoor activity score for one hour
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HourScore {
+    pub timestamp: DateTime<Utc>,
+    pub score: u8,
+}
+
+/// The best-scoring run of consecutive hours
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BestWindow {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub average_score: f64,
+}
+
+/// Score an hour from 0 (stay in) to 100 (perfect) for outdoor activity
+///
+/// Starts at 100 and subtracts:
+/// - temperature: 3 per °C outside 10–20 °C, at most 40
+/// - wind: 2 per m/s above 5 m/s, at most 20
+/// - precipitation: 30 × probability
+/// - UV: 3 per index point above 5, at most 15
+/// - humidity: 0.5 per % above 70 %, at most 15
+pub fn activity_score(hour: &HourlyForecast, uv_index: f64) -> u8 {
+    let temp_gap = if hour.temperature < IDEAL_TEMP.0 {
+        IDEAL_TEMP.0 - hour.temperature
+    } else {
+        (hour.temperature - IDEAL_TEMP.1).max(0.0)
+    };
+
+    let penalty = (temp_gap * 3.0).min(40.0)
+        + ((hour.wind_speed - 5.0).max(0.0) * 2.0).min(20.0)
+        + hour.pop.clamp(0.0, 1.0) * 30.0
+        + ((uv_index - 5.0).max(0.0) * 3.0).min(15.0)
+        + ((hour.humidity.0 - 70.0).max(0.0) * 0.5).min(15.0);
+
+    (100.0 - penalty).clamp(0.0, 100.0).round() as u8
+}
+
+/// Score every hour of the forecast
+///
+/// Hourly UV is not available, so the day's maximum UV index is applied to the
+/// hours between sunrise and sunset and zero otherwise.
+pub fn hourly_scores(
+    hourly_data: &[HourlyForecast],
+    daily_data: &[DailyForecast],
+    location: &Location,
+) -> Vec<HourScore> {
+    hourly_data
+        .iter()
+        .map(|hour| {
+            let date = convert_to_local(&hour.timestamp, &location.timezone).date_naive();
+            let uv_index = daily_data
+                .iter()
+                .find(|day| day.date.date_naive() == date)
+                .filter(|day| hour.timestamp >= day.sunrise && hour.timestamp < day.sunset)
+                .map_or(0.0, |day| day.uv_index);
+
+            HourScore {
+                timestamp: hour.timestamp,
+                score: activity_score(hour, uv_index),
+            }
+        })
+        .collect()
+}
+
+/// Find the window of `hours` consecutive hours with the highest average score
+pub fn best_window(scores: &[HourScore], hours: usize) -> Option<BestWindow> {
+    if hours == 0 {
+        return None;
+    }
+
+    scores
+        .windows(hours)
+        .map(|window| {
+            let average = window.iter().map(|s| s.score as f64).sum::<f64>() / window.len() as f64;
+            (window, average)
+        })
+        // Prefer the earliest window on ties
+        .fold(
+            None,
+            |best: Option<(&[HourScore], f64)>, (window, average)| match best {
+                Some((_, best_average)) if best_average >= average => best,
+                _ => Some((window, average)),
+            },
+        )
+        .map(|(window, average)| BestWindow {
+            start: window[0].timestamp,
+            end: window[window.len() - 1].timestamp + chrono::Duration::hours(1),
+            average_score: average,
+        })
+}
+
+/// Render scores as a sparkline, one block per hour
+pub fn sparkline(scores: &[HourScore]) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    scores
+        .iter()
+        .map(|s| BLOCKS[(s.score as usize * (BLOCKS.len() - 1) + 50) / 100])
+        .collect()
+}
+
+/// Parse a window length such as `2h`, `90m` or `3` (hours), rounding up to whole hours
+pub fn parse_duration_hours(value: &str) -> Result<usize> {
+    let value = value.trim().to_lowercase();
+    let (number, minutes) = if let Some(number) = value.strip_suffix('m') {
+        (number, true)
+    } else {
+        (value.strip_suffix('h').unwrap_or(&value), false)
+    };
+
+    let amount: usize = number
+        .trim()
+        .parse()
+        .map_err(|_| anyhow!("Invalid duration '{}', expected e.g. 2h or 90m", value))?;
+    let hours = if minutes { amount.div_ceil(60) } else { amount };
+
+    if hours == 0 {
+        return Err(anyhow!("Duration must be at least one hour"));
+    }
+    Ok(hours)
+}
@@ -0,0 +1,209 @@
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::modules::forecaster::parse_api_time;
+use crate::modules::http::HttpContext;
+use crate::modules::types::Location;
+
+/// Soil layers reported by Open-Meteo, as (API suffix, label)
+pub const SOIL_LAYERS: [(&str, &str); 5] = [
+    ("0_to_1cm", "0-1 cm"),
+    ("1_to_3cm", "1-3 cm"),
+    ("3_to_9cm", "3-9 cm"),
+    ("9_to_27cm", "9-27 cm"),
+    ("27_to_81cm", "27-81 cm"),
+];
+/// Days covered by the agriculture report
+pub const AGRICULTURE_DAYS: u8 = 7;
+/// Leaf wetness probability (%) counted as a wet hour
+pub const LEAF_WET_PERCENT: f64 = 50.0;
+
+/// Agricultural variables for one hour
+#[derive(Debug, Clone, Serialize)]
+pub struct AgricultureHour {
+    pub timestamp: DateTime<Utc>,
+    /// Reference evapotranspiration (mm)
+    pub et0: Option<f64>,
+    /// Volumetric soil moisture (m³/m³) per layer of `SOIL_LAYERS`
+    pub soil_moisture: Vec<Option<f64>>,
+    pub soil_temperature: Option<f64>,
+    /// Probability (%) that leaves are wet
+    pub leaf_wetness: Option<f64>,
+}
+
+/// Daily water balance
+#[derive(Debug, Clone, Serialize)]
+pub struct AgricultureDay {
+    pub date: NaiveDate,
+    /// Reference evapotranspiration (mm)
+    pub et0: f64,
+    pub precipitation: f64,
+    /// Precipitation minus ET0; negative when the soil dries out
+    pub water_balance: f64,
+    /// Hours with a leaf wetness probability of at least `LEAF_WET_PERCENT`
+    pub leaf_wet_hours: usize,
+}
+
+/// Soil moisture of one layer at the current hour
+#[derive(Debug, Clone, Serialize)]
+pub struct SoilLayer {
+    pub depth: String,
+    pub moisture: Option<f64>,
+}
+
+/// Evapotranspiration, soil and leaf wetness data for irrigation scheduling
+#[derive(Debug, Clone, Serialize)]
+pub struct AgricultureReport {
+    pub soil: Vec<SoilLayer>,
+    pub soil_temperature: Option<f64>,
+    pub days: Vec<AgricultureDay>,
+    /// Water (mm) to add over the period to make up for the deficit
+    pub irrigation_need: f64,
+    pub hourly: Vec<AgricultureHour>,
+}
+
+/// Parse an Open-Meteo response with the agriculture variables
+pub fn parse_agriculture(json: &Value, now: DateTime<Utc>) -> Result<AgricultureReport> {
+    let utc_offset = json["utc_offset_seconds"].as_i64().unwrap_or(0);
+    let hourly = &json["hourly"];
+    let times = hourly["time"]
+        .as_array()
+        .ok_or_else(|| anyhow!("Missing hourly time data"))?;
+    let value = |key: &str, i: usize| hourly[key].get(i).and_then(Value::as_f64);
+
+    let hours: Vec<AgricultureHour> = times
+        .iter()
+        .enumerate()
+        .filter_map(|(i, time)| {
+            Some(AgricultureHour {
+                timestamp: parse_api_time(time.as_str()?, utc_offset)?,
+                et0: value("et0_fao_evapotranspiration", i),
+                soil_moisture: SOIL_LAYERS
+                    .iter()
+                    .map(|(suffix, _)| value(&format!("soil_moisture_{}", suffix), i))
+                    .collect(),
+                soil_temperature: value("soil_temperature_6cm", i),
+                leaf_wetness: value("leaf_wetness_probability", i),
+            })
+        })
+        .collect();
+
+    let daily = &json["daily"];
+    let dates = daily["time"]
+        .as_array()
+        .ok_or_else(|| anyhow!("Missing daily time data"))?;
+    let days: Vec<AgricultureDay> = dates
+        .iter()
+        .enumerate()
+        .filter_map(|(i, date)| {
+            let date = NaiveDate::parse_from_str(date.as_str()?, "%Y-%m-%d").ok()?;
+            let et0 = daily["et0_fao_evapotranspiration"]
+                .get(i)
+                .and_then(Value::as_f64)
+                .unwrap_or(0.0);
+            let precipitation = daily["precipitation_sum"]
+                .get(i)
+                .and_then(Value::as_f64)
+                .unwrap_or(0.0);
+            // Hourly times are local, so the day's hours share its date prefix
+            let prefix = date.format("%Y-%m-%d").to_string();
+            let leaf_wet_hours = times
+                .iter()
+                .enumerate()
+                .filter(|(_, time)| time.as_str().is_some_and(|t| t.starts_with(&prefix)))
+                .filter(|(h, _)| {
+                    value("leaf_wetness_probability", *h).is_some_and(|p| p >= LEAF_WET_PERCENT)
+                })
+                .count();
+            Some(AgricultureDay {
+                date,
+                et0,
+                precipitation,
+                water_balance: precipitation - et0,
+                leaf_wet_hours,
+            })
+        })
+        .collect();
+
+    // Latest hour that has started
+    let current = hours
+        .iter()
+        .rev()
+        .find(|hour| hour.timestamp <= now)
+        .or(hours.first());
+    let soil = SOIL_LAYERS
+        .iter()
+        .enumerate()
+        .map(|(layer, (_, depth))| SoilLayer {
+            depth: depth.to_string(),
+            moisture: current.and_then(|hour| hour.soil_moisture[layer]),
+        })
+        .collect();
+
+    let irrigation_need = -days.iter().map(|day| day.water_balance).sum::<f64>();
+
+    Ok(AgricultureReport {
+        soil,
+        soil_temperature: current.and_then(|hour| hour.soil_temperature),
+        irrigation_need: irrigation_need.max(0.0),
+        days,
+        hourly: hours,
+    })
+}
+
+/// Soil moisture description for volumetric water content (m³/m³)
+pub fn moisture_description(moisture: f64) -> &'static str {
+    match moisture {
+        m if m < 0.1 => "Very dry",
+        m if m < 0.2 => "Dry",
+        m if m < 0.3 => "Moist",
+        m if m < 0.4 => "Wet",
+        _ => "Saturated",
+    }
+}
+
+/// Fetches the agriculture variables from Open-Meteo
+#[derive(Clone)]
+pub struct AgricultureService {
+    http: HttpContext,
+}
+
+impl AgricultureService {
+    pub fn new(http: HttpContext) -> Self {
+        Self { http }
+    }
+
+    pub async fn report(&self, location: &Location) -> Result<AgricultureReport> {
+        let soil_moisture: Vec<_> = SOIL_LAYERS
+            .iter()
+            .map(|(suffix, _)| format!("soil_moisture_{}", suffix))
+            .collect();
+        let url = format!(
+            "{}/forecast?latitude={}&longitude={}&hourly=et0_fao_evapotranspiration,{},soil_temperature_6cm,leaf_wetness_probability&daily=et0_fao_evapotranspiration,precipitation_sum&timezone=auto&forecast_days={}",
+            self.http.endpoints().openmeteo,
+            location.latitude,
+            location.longitude,
+            soil_moisture.join(","),
+            AGRICULTURE_DAYS
+        );
+
+        let json = self
+            .http
+            .get_json(&url)
+            .await
+            .context("Could not reach Open-Meteo")?;
+        if json["error"].as_bool() == Some(true) {
+            let reason = json["reason"].as_str().unwrap_or("Unknown error");
+            return Err(anyhow!("Open-Meteo API error: {}", reason));
+        }
+        parse_agriculture(&json, Utc::now())
+    }
+}
+
+impl Default for AgricultureService {
+    fn default() -> Self {
+        Self::new(HttpContext::default())
+    }
+}
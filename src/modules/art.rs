@@ -0,0 +1,330 @@
+use crate::modules::types::{
+    CurrentWeather, DailyForecast, HourlyForecast, Location, WeatherCondition,
+};
+use crate::modules::ui::{convert_to_local, get_wind_direction_arrow};
+use chrono::{Datelike, NaiveDate, Timelike};
+use colored::*;
+use unicode_width::UnicodeWidthStr;
+
+/// Width of one column in the outlook table (without borders)
+const COLUMN_WIDTH: usize = 30;
+/// Width of the condition art
+const ART_WIDTH: usize = 13;
+
+/// Parts of the day shown in the outlook, with the local hour sampled for each
+const DAY_PARTS: [(&str, u32); 4] = [("Morning", 9), ("Noon", 12), ("Evening", 18), ("Night", 21)];
+
+/// Five-line ASCII art for a weather condition, 13 columns wide
+pub fn get_weather_ascii_art(condition: WeatherCondition, is_day: bool) -> [&'static str; 5] {
+    match condition {
+        WeatherCondition::Clear if is_day => [
+            "    \\   /    ",
+            "     .-.     ",
+            "  ― (   ) ―  ",
+            "     `-’     ",
+            "    /   \\    ",
+        ],
+        WeatherCondition::Clear => [
+            "      _..    ",
+            "    .' .'    ",
+            "   /  /      ",
+            "   \\  '.__   ",
+            "    '.___.'  ",
+        ],
+        WeatherCondition::Clouds => [
+            "   \\  /      ",
+            " _ /\"\".-.    ",
+            "   \\_(   ).  ",
+            "   /(___(__) ",
+            "             ",
+        ],
+        WeatherCondition::Rain | WeatherCondition::Drizzle => [
+            "     .-.     ",
+            "    (   ).   ",
+            "   (___(__)  ",
+            "    ‘ ‘ ‘ ‘  ",
+            "   ‘ ‘ ‘ ‘   ",
+        ],
+        WeatherCondition::Thunderstorm => [
+            "     .-.     ",
+            "    (   ).   ",
+            "   (___(__)  ",
+            "   ‚‘ϟ‘‚ϟ‚‘  ",
+            "   ‚’‚’ϟ’‚’  ",
+        ],
+        WeatherCondition::Snow => [
+            "     .-.     ",
+            "    (   ).   ",
+            "   (___(__)  ",
+            "    *  *  *  ",
+            "   *  *  *   ",
+        ],
+        WeatherCondition::Sleet => [
+            "     .-.     ",
+            "    (   ).   ",
+            "   (___(__)  ",
+            "    ‘ * ‘ *  ",
+            "   * ‘ * ‘   ",
+        ],
+        WeatherCondition::Hail => [
+            "     .-.     ",
+            "    (   ).   ",
+            "   (___(__)  ",
+            "    o ° o °  ",
+            "   ° o ° o   ",
+        ],
+        WeatherCondition::Mist
+        | WeatherCondition::Fog
+        | WeatherCondition::Haze
+        | WeatherCondition::Smoke => [
+            "             ",
+            " _ - _ - _ - ",
+            "  _ - _ - _  ",
+            " _ - _ - _ - ",
+            "             ",
+        ],
+        WeatherCondition::Dust | WeatherCondition::Sand | WeatherCondition::Ash => [
+            "             ",
+            "  . : . : .  ",
+            " ~ ~ ~ ~ ~ ~ ",
+            "  : . : . :  ",
+            " ~ ~ ~ ~ ~ ~ ",
+        ],
+        WeatherCondition::Squall => [
+            "             ",
+            "  ~~~~~~>    ",
+            "    ~~~~~~>  ",
+            "  ~~~~~~>    ",
+            "             ",
+        ],
+        WeatherCondition::Tornado => [
+            "  _________  ",
+            "   \\~~~~~/   ",
+            "    \\~~~/    ",
+            "     \\~/     ",
+            "      V      ",
+        ],
+        WeatherCondition::Unknown => [
+            "    .-.      ",
+            "     __)     ",
+            "    (        ",
+            "     `-’     ",
+            "      •      ",
+        ],
+    }
+}
+
+/// Color a line of condition art
+fn color_art(line: &str, condition: WeatherCondition, is_day: bool) -> ColoredString {
+    match condition {
+        WeatherCondition::Clear if is_day => line.bright_yellow(),
+        WeatherCondition::Clear => line.bright_white(),
+        WeatherCondition::Clouds => line.white(),
+        WeatherCondition::Rain | WeatherCondition::Drizzle => line.bright_blue(),
+        WeatherCondition::Thunderstorm => line.yellow(),
+        WeatherCondition::Snow | WeatherCondition::Sleet | WeatherCondition::Hail => {
+            line.bright_white().bold()
+        }
+        WeatherCondition::Dust | WeatherCondition::Sand | WeatherCondition::Ash => {
+            line.truecolor(166, 123, 74)
+        }
+        WeatherCondition::Tornado | WeatherCondition::Squall => line.bright_red(),
+        _ => line.bright_black(),
+    }
+}
+
+/// Build a wttr.in-style report: current conditions followed by a day-part outlook
+pub fn render_art_report(
+    location: &Location,
+    current: &CurrentWeather,
+    hourly_data: &[HourlyForecast],
+    daily_data: &[DailyForecast],
+    days: usize,
+) -> String {
+    let mut lines = vec![
+        format!(
+            "Weather report: {}, {}",
+            location.name.bold(),
+            location.country_code
+        ),
+        String::new(),
+    ];
+
+    // Current conditions
+    let is_day = current.timestamp >= current.sunrise && current.timestamp < current.sunset;
+    let info = [
+        describe(current.main_condition, current.conditions.first()),
+        format!("{:+.0}({:+.0}) °C", current.temperature, current.feels_like),
+        format!(
+            "{} {:.0} m/s",
+            get_wind_direction_arrow(current.wind_direction),
+            current.wind_speed
+        ),
+        format!("{} humidity", current.humidity),
+        format!(
+            "{:.1} mm",
+            current.rain_last_hour.unwrap_or(0.0) + current.snow_last_hour.unwrap_or(0.0)
+        ),
+    ];
+    let art = get_weather_ascii_art(current.main_condition, is_day);
+    for (art_line, info_line) in art.iter().zip(info.iter()) {
+        lines.push(format!(
+            "{} {}",
+            color_art(art_line, current.main_condition, is_day),
+            info_line
+        ));
+    }
+
+    // Day-part outlook
+    for day in daily_data.iter().take(days) {
+        let date = day.date.date_naive();
+        lines.push(String::new());
+        lines.extend(render_day_table(day, date, hourly_data, location));
+    }
+
+    lines.join("\n")
+}
+
+/// Render the bordered four-column table for one day
+fn render_day_table(
+    day: &DailyForecast,
+    date: NaiveDate,
+    hourly_data: &[HourlyForecast],
+    location: &Location,
+) -> Vec<String> {
+    let bar = "─".repeat(COLUMN_WIDTH);
+    let half_bar = "─".repeat(COLUMN_WIDTH - 7);
+    let date_label = format!("{:^13}", date.format("%a %d %b").to_string());
+
+    let mut lines = vec![
+        format!("{}┌─────────────┐", " ".repeat(2 * COLUMN_WIDTH - 5)),
+        format!(
+            "┌{bar}┬{half}┤{label}├{half}┬{bar}┐",
+            bar = bar,
+            half = half_bar,
+            label = date_label.bold()
+        ),
+        format!(
+            "│{:^w$}│{:^h$}└──────┬──────┘{:^h$}│{:^w$}│",
+            DAY_PARTS[0].0,
+            DAY_PARTS[1].0,
+            DAY_PARTS[2].0,
+            DAY_PARTS[3].0,
+            w = COLUMN_WIDTH,
+            h = COLUMN_WIDTH - 7
+        ),
+        format!("├{bar}┼{bar}┼{bar}┼{bar}┤", bar = bar),
+    ];
+
+    let cells: Vec<[String; 5]> = DAY_PARTS
+        .iter()
+        .enumerate()
+        .map(|(slot, (_, hour))| day_part_cell(day, date, slot, *hour, hourly_data, location))
+        .collect();
+
+    for row in 0..5 {
+        let columns: Vec<&str> = cells.iter().map(|cell| cell[row].as_str()).collect();
+        lines.push(format!("│{}│", columns.join("│")));
+    }
+
+    lines.push(format!("└{bar}┴{bar}┴{bar}┴{bar}┘", bar = bar));
+    lines
+}
+
+/// Render the five lines of one day-part column
+///
+/// Uses the matching hourly forecast when available, otherwise the daily summary.
+fn day_part_cell(
+    day: &DailyForecast,
+    date: NaiveDate,
+    slot: usize,
+    hour: u32,
+    hourly_data: &[HourlyForecast],
+    location: &Location,
+) -> [String; 5] {
+    let is_day = hour < 20;
+    let hourly = hourly_data.iter().find(|h| {
+        let local = convert_to_local(&h.timestamp, &location.timezone);
+        local.year() == date.year() && local.ordinal() == date.ordinal() && local.hour() == hour
+    });
+
+    let (condition, info) = match hourly {
+        Some(h) => (
+            h.main_condition,
+            [
+                describe(h.main_condition, h.conditions.first()),
+                format!("{:+.0}({:+.0}) °C", h.temperature, h.feels_like),
+                format!(
+                    "{} {:.0} m/s",
+                    get_wind_direction_arrow(h.wind_direction),
+                    h.wind_speed
+                ),
+                format!("{:.0}% chance", h.pop * 100.0),
+                format!("{:.1} mm", h.rain.unwrap_or(0.0) + h.snow.unwrap_or(0.0)),
+            ],
+        ),
+        None => {
+            let (temp, feels) = match slot {
+                0 => (day.temp_morning, day.feels_like_day),
+                1 => (day.temp_day, day.feels_like_day),
+                2 => (day.temp_evening, day.feels_like_day),
+                _ => (day.temp_night, day.feels_like_night),
+            };
+            (
+                day.main_condition,
+                [
+                    describe(day.main_condition, day.conditions.first()),
+                    format!("{:+.0}({:+.0}) °C", temp, feels),
+                    format!(
+                        "{} {:.0} m/s",
+                        get_wind_direction_arrow(day.wind_direction),
+                        day.wind_speed
+                    ),
+                    format!("{:.0}% chance", day.pop * 100.0),
+                    String::new(),
+                ],
+            )
+        }
+    };
+
+    let art = get_weather_ascii_art(condition, is_day);
+    let mut cell: [String; 5] = Default::default();
+    for (row, line) in cell.iter_mut().enumerate() {
+        *line = format!(
+            " {} {}",
+            color_art(art[row], condition, is_day),
+            fit(&info[row], COLUMN_WIDTH - ART_WIDTH - 2)
+        );
+    }
+    cell
+}
+
+/// Short condition description, preferring the provider's wording
+fn describe(
+    condition: WeatherCondition,
+    description: Option<&crate::modules::types::WeatherDescription>,
+) -> String {
+    match description {
+        Some(desc) if !desc.description.is_empty() => {
+            let mut chars = desc.description.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => condition.to_string(),
+            }
+        }
+        _ => condition.to_string(),
+    }
+}
+
+/// Pad or truncate text to an exact display width
+fn fit(text: &str, width: usize) -> String {
+    let mut result = String::new();
+    for c in text.chars() {
+        if (result.as_str().width() + c.to_string().width()) > width {
+            break;
+        }
+        result.push(c);
+    }
+    let padding = width - result.as_str().width();
+    result + " ".repeat(padding).as_str()
+}
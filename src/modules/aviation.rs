@@ -0,0 +1,133 @@
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+
+use crate::modules::types::CurrentWeather;
+
+/// Standard sea level pressure (hPa)
+const STANDARD_PRESSURE: f64 = 1013.25;
+const FEET_PER_METER: f64 = 3.280_84;
+/// Gas constants of dry air and water vapour (J/(kg·K))
+const R_DRY: f64 = 287.058;
+const R_VAPOR: f64 = 461.495;
+
+/// Which side of the runway the crosswind blows from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// Wind components along and across a runway, in m/s
+#[derive(Debug, Clone, Serialize)]
+pub struct RunwayWind {
+    pub heading: f64,
+    /// Negative for a tailwind
+    pub headwind: f64,
+    pub crosswind: f64,
+    pub crosswind_from: Side,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gust_crosswind: Option<f64>,
+}
+
+/// Quick pre-flight numbers from the current conditions
+#[derive(Debug, Clone, Serialize)]
+pub struct AviationReport {
+    pub pressure_altitude_ft: f64,
+    pub density_altitude_ft: f64,
+    pub dew_point: f64,
+    /// Estimated cumulus base above ground from the temperature/dew point spread
+    pub cloud_base_ft: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runway: Option<RunwayWind>,
+}
+
+/// Parse a runway heading in degrees (e.g. 270) or a runway number (e.g. 27, 09L)
+pub fn parse_runway(value: &str) -> Result<f64> {
+    let digits = value
+        .trim()
+        .trim_end_matches(['L', 'R', 'C', 'l', 'r', 'c']);
+    let number: u16 = digits
+        .parse()
+        .map_err(|_| anyhow!("Invalid runway '{}', expected e.g. 27, 09L or 270", value))?;
+    match number {
+        1..=36 if digits.len() <= 2 => Ok(number as f64 * 10.0),
+        0..=360 if digits.len() == 3 => Ok(number as f64 % 360.0),
+        _ => Err(anyhow!(
+            "Invalid runway '{}', expected a number 01-36 or a heading 000-360",
+            value
+        )),
+    }
+}
+
+/// Pressure altitude (ft) from the station pressure (hPa)
+pub fn pressure_altitude(station_pressure: f64) -> f64 {
+    145_366.45 * (1.0 - (station_pressure / STANDARD_PRESSURE).powf(0.190_284))
+}
+
+/// Dew point (°C) with the Magnus formula
+pub fn dew_point(temperature: f64, humidity: f64) -> f64 {
+    let (b, c) = (17.62, 243.12);
+    let gamma = (humidity.clamp(1.0, 100.0) / 100.0).ln() + b * temperature / (c + temperature);
+    c * gamma / (b - gamma)
+}
+
+/// Density altitude (ft) from the station pressure (hPa), temperature (°C) and
+/// relative humidity (%)
+///
+/// Moist air is lighter than dry air, so humidity raises the density altitude.
+pub fn density_altitude(station_pressure: f64, temperature: f64, humidity: f64) -> f64 {
+    let kelvin = temperature + 273.15;
+    let saturation = 6.1078 * 10f64.powf(7.5 * temperature / (temperature + 237.3));
+    let vapor = saturation * humidity.clamp(0.0, 100.0) / 100.0;
+    let dry = station_pressure - vapor;
+    let density = (dry * 100.0) / (R_DRY * kelvin) + (vapor * 100.0) / (R_VAPOR * kelvin);
+    (44.3308 - 42.2665 * density.powf(0.234_969)) * 1000.0 * FEET_PER_METER
+}
+
+/// Headwind and crosswind for a runway heading; wind directions are where the
+/// wind blows from
+pub fn runway_wind(
+    heading: f64,
+    wind_direction: f64,
+    wind_speed: f64,
+    gusts: Option<f64>,
+) -> RunwayWind {
+    let angle = (wind_direction - heading).to_radians();
+    let crosswind = wind_speed * angle.sin();
+    RunwayWind {
+        heading,
+        headwind: wind_speed * angle.cos(),
+        crosswind: crosswind.abs(),
+        crosswind_from: if crosswind < 0.0 {
+            Side::Left
+        } else {
+            Side::Right
+        },
+        gust_crosswind: gusts.map(|gust| (gust * angle.sin()).abs()),
+    }
+}
+
+/// Compute the aviation numbers, with runway components when a heading is given
+pub fn aviation_report(weather: &CurrentWeather, runway: Option<f64>) -> AviationReport {
+    let dew_point = dew_point(weather.temperature, weather.humidity.0);
+    AviationReport {
+        pressure_altitude_ft: pressure_altitude(weather.pressure.0),
+        density_altitude_ft: density_altitude(
+            weather.pressure.0,
+            weather.temperature,
+            weather.humidity.0,
+        ),
+        dew_point,
+        // About 400 ft per °C of spread
+        cloud_base_ft: ((weather.temperature - dew_point) * 400.0).max(0.0),
+        runway: runway.map(|heading| {
+            runway_wind(
+                heading,
+                weather.wind_direction as f64,
+                weather.wind_speed,
+                weather.wind_gusts,
+            )
+        }),
+    }
+}
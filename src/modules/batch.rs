@@ -0,0 +1,220 @@
+use chrono::{Duration, Utc};
+use clap::ValueEnum;
+use futures::stream::{self, Stream, StreamExt};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::modules::runner::{ForecastSource, LocationSource};
+use crate::modules::types::{CurrentWeather, DailyForecast, Location};
+
+/// Columns of the CSV output, all in metric units
+pub const CSV_HEADER: &str = "input,name,country,latitude,longitude,temperature,feels_like,humidity,wind_speed,wind_gusts,conditions,temp_min,temp_max,precipitation_chance,precipitation,error";
+
+/// Output formats of `weather_man batch`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum BatchFormat {
+    /// One row per location
+    Csv,
+    /// An array with the full current conditions and today's forecast
+    Json,
+    /// The JSON rows one per line, each printed as soon as it is fetched
+    Ndjson,
+}
+
+/// One line of the batch input
+#[derive(Debug, Clone, PartialEq)]
+pub enum BatchTarget {
+    Name(String),
+    Coordinates { latitude: f64, longitude: f64 },
+}
+
+impl BatchTarget {
+    /// Parse a location name or `latitude,longitude`; blank lines and `#`
+    /// comments give `None`
+    pub fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+
+        let coordinates = line
+            .split_once(',')
+            .or_else(|| line.split_once(char::is_whitespace))
+            .and_then(|(lat, lon)| Some((lat.trim().parse().ok()?, lon.trim().parse().ok()?)))
+            .filter(|(lat, lon): &(f64, f64)| lat.abs() <= 90.0 && lon.abs() <= 180.0);
+        Some(match coordinates {
+            Some((latitude, longitude)) => BatchTarget::Coordinates {
+                latitude,
+                longitude,
+            },
+            None => BatchTarget::Name(line.to_string()),
+        })
+    }
+
+    /// Every location in a batch file, in order
+    pub fn parse_all(input: &str) -> Vec<Self> {
+        input.lines().filter_map(Self::parse).collect()
+    }
+
+    fn input(&self) -> String {
+        match self {
+            BatchTarget::Name(name) => name.clone(),
+            BatchTarget::Coordinates {
+                latitude,
+                longitude,
+            } => format!("{},{}", latitude, longitude),
+        }
+    }
+}
+
+/// Weather for one line of the batch input, or why it is missing
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchRow {
+    pub input: String,
+    pub location: Option<Location>,
+    pub current: Option<CurrentWeather>,
+    pub today: Option<DailyForecast>,
+    /// This hour's `--vars` values
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: BTreeMap<String, f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Fetch the forecasts for all targets, at most `concurrency` at a time
+///
+/// Rows come back in input order; a failed location gets an error instead of
+/// stopping the batch.
+pub async fn fetch_batch(
+    forecaster: &dyn ForecastSource,
+    locations: &dyn LocationSource,
+    targets: &[BatchTarget],
+    concurrency: usize,
+) -> Vec<BatchRow> {
+    batch_stream(forecaster, locations, targets, concurrency)
+        .collect()
+        .await
+}
+
+/// The rows of `fetch_batch` as a stream, each yielded once it and all rows
+/// before it are fetched
+pub fn batch_stream<'a>(
+    forecaster: &'a dyn ForecastSource,
+    locations: &'a dyn LocationSource,
+    targets: &'a [BatchTarget],
+    concurrency: usize,
+) -> impl Stream<Item = BatchRow> + 'a {
+    let fetches = targets.iter().map(move |target| async move {
+        let mut row = BatchRow {
+            input: target.input(),
+            location: None,
+            current: None,
+            today: None,
+            extra: BTreeMap::new(),
+            error: None,
+        };
+        let location = match target {
+            BatchTarget::Name(name) => locations.location_by_name(name).await,
+            BatchTarget::Coordinates {
+                latitude,
+                longitude,
+            } => Ok(Location {
+                name: format!("{:.4}, {:.4}", latitude, longitude),
+                latitude: *latitude,
+                longitude: *longitude,
+                country: String::new(),
+                ..Location::default()
+            }),
+        };
+        let location = match location {
+            Ok(location) => location,
+            Err(e) => {
+                row.error = Some(format!("{:#}", e));
+                return row;
+            }
+        };
+
+        match forecaster.forecast(&location).await {
+            Ok(forecast) => {
+                let now = Utc::now();
+                row.extra = forecast
+                    .hourly
+                    .iter()
+                    .find(|h| h.timestamp <= now && now < h.timestamp + Duration::hours(1))
+                    .or(forecast.hourly.first())
+                    .map(|h| h.extra.clone())
+                    .unwrap_or_default();
+                row.current = forecast.current;
+                row.today = forecast.daily.into_iter().next();
+            }
+            Err(e) => row.error = Some(format!("{:#}", e)),
+        }
+        row.location = Some(location);
+        row
+    });
+
+    stream::iter(fetches).buffered(concurrency.max(1))
+}
+
+/// Quote a CSV field if it contains a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Render the rows as CSV with a header line; `--vars` values follow the
+/// fixed columns
+pub fn to_csv(rows: &[BatchRow]) -> String {
+    let number = |value: Option<f64>| value.map(|v| format!("{:.1}", v)).unwrap_or_default();
+    let extra: BTreeSet<&String> = rows.iter().flat_map(|row| row.extra.keys()).collect();
+    let mut out = CSV_HEADER.to_string();
+    for name in &extra {
+        out.push(',');
+        out.push_str(&csv_field(name));
+    }
+    out.push('\n');
+
+    for row in rows {
+        let location = row.location.as_ref();
+        let current = row.current.as_ref();
+        let today = row.today.as_ref();
+        let fields = [
+            row.input.clone(),
+            location.map(|l| l.name.clone()).unwrap_or_default(),
+            location.map(|l| l.country.clone()).unwrap_or_default(),
+            location
+                .map(|l| format!("{:.4}", l.latitude))
+                .unwrap_or_default(),
+            location
+                .map(|l| format!("{:.4}", l.longitude))
+                .unwrap_or_default(),
+            number(current.map(|c| c.temperature)),
+            number(current.map(|c| c.feels_like)),
+            number(current.map(|c| c.humidity.0)),
+            number(current.map(|c| c.wind_speed)),
+            number(current.and_then(|c| c.wind_gusts)),
+            current
+                .map(|c| c.main_condition.to_string())
+                .unwrap_or_default(),
+            number(today.map(|d| d.temp_min)),
+            number(today.map(|d| d.temp_max)),
+            today
+                .map(|d| format!("{:.0}", d.pop * 100.0))
+                .unwrap_or_default(),
+            number(today.and_then(|d| d.precipitation)),
+            row.error.clone().unwrap_or_default(),
+        ];
+        let mut line: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+        line.extend(
+            extra
+                .iter()
+                .map(|name| number(row.extra.get(*name).copied())),
+        );
+        out.push_str(&line.join(","));
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,65 @@
+use font8x8::{UnicodeFonts, BASIC_FONTS, LATIN_FONTS};
+
+/// Pixel width of one glyph in the embedded 8x8 font
+const GLYPH_SIZE: usize = 8;
+
+/// Render text in large block letters using the embedded 8x8 bitmap font
+///
+/// Scale 1 packs two pixel rows into one line with half blocks (8 columns x 4 lines
+/// per character); scale 2 draws every pixel as two full blocks (16 columns x 8 lines).
+pub fn render_big_text(text: &str, scale: usize) -> Vec<String> {
+    let glyphs: Vec<[u8; 8]> = text.chars().map(glyph).collect();
+
+    match scale {
+        0 | 1 => (0..GLYPH_SIZE)
+            .step_by(2)
+            .map(|row| {
+                glyphs
+                    .iter()
+                    .flat_map(|g| {
+                        (0..GLYPH_SIZE).map(move |col| {
+                            match (pixel(g, row, col), pixel(g, row + 1, col)) {
+                                (true, true) => '█',
+                                (true, false) => '▀',
+                                (false, true) => '▄',
+                                (false, false) => ' ',
+                            }
+                        })
+                    })
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect(),
+        _ => (0..GLYPH_SIZE)
+            .flat_map(|row| {
+                let line = glyphs
+                    .iter()
+                    .flat_map(|g| {
+                        (0..GLYPH_SIZE).map(move |col| if pixel(g, row, col) { '█' } else { ' ' })
+                    })
+                    .flat_map(|c| std::iter::repeat_n(c, scale))
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string();
+                std::iter::repeat_n(line, scale / 2)
+            })
+            .collect(),
+    }
+}
+
+/// Terminal columns needed to render text at the given scale
+pub fn big_text_width(text: &str, scale: usize) -> usize {
+    text.chars().count() * GLYPH_SIZE * scale.max(1)
+}
+
+fn glyph(c: char) -> [u8; 8] {
+    BASIC_FONTS
+        .get(c)
+        .or_else(|| LATIN_FONTS.get(c))
+        .unwrap_or([0; 8])
+}
+
+fn pixel(glyph: &[u8; 8], row: usize, col: usize) -> bool {
+    glyph[row] & (1 << col) != 0
+}
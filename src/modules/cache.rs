@@ -0,0 +1,270 @@
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use crate::modules::comfort::{apply_forecast, FeelsLike};
+use crate::modules::forecaster::FORECAST_DAYS;
+use crate::modules::runner::{ForecastSource, LocationSource};
+use crate::modules::stats::{UsageEvent, UsageLog};
+use crate::modules::status::write_status;
+use crate::modules::types::{CurrentWeather, DailyForecast, Forecast, HourlyForecast, Location};
+
+/// A forecast saved with where and when it was fetched
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedForecast {
+    pub fetched: DateTime<Utc>,
+    pub location: Location,
+    pub forecast: Forecast,
+}
+
+impl CachedForecast {
+    /// Whether it was fetched less than `max_age` before `now`
+    pub fn is_fresh(&self, now: DateTime<Utc>, max_age: Duration) -> bool {
+        now - self.fetched < max_age
+    }
+}
+
+/// Forecasts kept on disk, one file per location query
+#[derive(Debug, Clone)]
+pub struct ForecastCache {
+    dir: PathBuf,
+}
+
+impl ForecastCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// File name for a location query; `None` is the IP-detected location
+    pub fn key(query: Option<&str>) -> String {
+        match query {
+            Some(query) => query
+                .trim()
+                .to_lowercase()
+                .chars()
+                .map(|c| if c.is_alphanumeric() { c } else { '_' })
+                .collect(),
+            None => "_auto".to_string(),
+        }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", key))
+    }
+
+    /// The cached forecast, if there is a readable one
+    pub fn load(&self, key: &str) -> Option<CachedForecast> {
+        serde_json::from_str(&fs::read_to_string(self.path(key)).ok()?).ok()
+    }
+
+    pub fn store(&self, key: &str, cached: &CachedForecast) -> Result<()> {
+        let json = serde_json::to_string(cached).context("Could not encode the forecast")?;
+        write_status(&self.path(key), &json)
+    }
+}
+
+/// How long a cached forecast is used before fetching a new one
+pub const CACHE_MAX_AGE_MINUTES: i64 = 15;
+
+/// Serves locations and forecasts from the cache while they are fresh,
+/// fetching and caching them otherwise
+pub struct CachingSource {
+    forecaster: Arc<dyn ForecastSource>,
+    locations: Arc<dyn LocationSource>,
+    cache: ForecastCache,
+    max_age: Duration,
+    /// Cache key of each location resolved so far
+    resolved: Mutex<Vec<(Location, String)>>,
+    log: Option<UsageLog>,
+    /// Applied on the way out, so the cache keeps the provider's values
+    feels_like: FeelsLike,
+}
+
+impl CachingSource {
+    pub fn new(
+        forecaster: Arc<dyn ForecastSource>,
+        locations: Arc<dyn LocationSource>,
+        cache: ForecastCache,
+    ) -> Self {
+        Self {
+            forecaster,
+            locations,
+            cache,
+            max_age: Duration::minutes(CACHE_MAX_AGE_MINUTES),
+            resolved: Mutex::new(Vec::new()),
+            log: None,
+            feels_like: FeelsLike::Provider,
+        }
+    }
+
+    /// Record every forecast request in the usage log
+    pub fn with_log(mut self, log: UsageLog) -> Self {
+        self.log = Some(log);
+        self
+    }
+
+    /// Recompute feels-like temperatures with this formula; the wrapped
+    /// forecaster should return the provider's values
+    pub fn with_feels_like(mut self, formula: FeelsLike) -> Self {
+        self.feels_like = formula;
+        self
+    }
+
+    fn record(&self, location: &Location, latency: Option<std::time::Duration>) {
+        if let Some(log) = &self.log {
+            // The log is only statistics, so failures are ignored
+            let _ = log.record(&UsageEvent {
+                time: Utc::now(),
+                location: location.name.clone(),
+                cached: latency.is_none(),
+                latency_ms: latency.map(|latency| latency.as_millis() as u64),
+            });
+        }
+    }
+
+    async fn fetch_timed(&self, location: &Location) -> Result<Forecast> {
+        let start = Instant::now();
+        let forecast = self.forecaster.forecast(location).await?;
+        self.record(location, Some(start.elapsed()));
+        Ok(forecast)
+    }
+
+    fn fresh(&self, key: &str) -> Option<CachedForecast> {
+        self.cache
+            .load(key)
+            .filter(|cached| cached.is_fresh(Utc::now(), self.max_age))
+    }
+
+    async fn resolve(&self, query: Option<&str>) -> Result<Location> {
+        let key = ForecastCache::key(query);
+        let location = match self.fresh(&key) {
+            Some(cached) => cached.location,
+            None => match query {
+                Some(name) => self.locations.location_by_name(name).await?,
+                None => self.locations.location_from_ip().await?,
+            },
+        };
+        self.resolved.lock().unwrap().push((location.clone(), key));
+        Ok(location)
+    }
+
+    fn key_of(&self, location: &Location) -> Option<String> {
+        self.resolved
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(resolved, _)| same_place(resolved, location))
+            .map(|(_, key)| key.clone())
+    }
+
+    async fn fetch(&self, location: &Location) -> Result<Forecast> {
+        let mut forecast = self.fetch_cached(location).await?;
+        apply_forecast(&mut forecast, self.feels_like);
+        Ok(forecast)
+    }
+
+    async fn fetch_cached(&self, location: &Location) -> Result<Forecast> {
+        // Locations not resolved here (e.g. coordinates) are never cached
+        let Some(key) = self.key_of(location) else {
+            return self.fetch_timed(location).await;
+        };
+        if let Some(cached) = self
+            .fresh(&key)
+            .filter(|cached| same_place(&cached.location, location))
+        {
+            self.record(location, None);
+            let mut forecast = cached.forecast;
+            forecast.fetched_at = Some(cached.fetched);
+            return Ok(forecast);
+        }
+
+        let forecast = self.fetch_timed(location).await?;
+        let cached = CachedForecast {
+            fetched: Utc::now(),
+            location: location.clone(),
+            forecast,
+        };
+        // Caching is best effort
+        let _ = self.cache.store(&key, &cached);
+        Ok(cached.forecast)
+    }
+}
+
+fn same_place(a: &Location, b: &Location) -> bool {
+    a.latitude == b.latitude && a.longitude == b.longitude
+}
+
+impl LocationSource for CachingSource {
+    fn location_by_name<'a>(&'a self, name: &'a str) -> BoxFuture<'a, Result<Location>> {
+        Box::pin(self.resolve(Some(name)))
+    }
+
+    fn location_from_ip(&self) -> BoxFuture<'_, Result<Location>> {
+        Box::pin(self.resolve(None))
+    }
+}
+
+impl ForecastSource for CachingSource {
+    fn current_weather<'a>(
+        &'a self,
+        location: &'a Location,
+    ) -> BoxFuture<'a, Result<CurrentWeather>> {
+        Box::pin(async move {
+            self.fetch(location)
+                .await?
+                .current
+                .ok_or_else(|| anyhow!("No current weather for {}", location.name))
+        })
+    }
+
+    fn hourly_forecast<'a>(
+        &'a self,
+        location: &'a Location,
+    ) -> BoxFuture<'a, Result<Vec<HourlyForecast>>> {
+        Box::pin(async move { Ok(self.fetch(location).await?.hourly) })
+    }
+
+    fn daily_forecast<'a>(
+        &'a self,
+        location: &'a Location,
+    ) -> BoxFuture<'a, Result<Vec<DailyForecast>>> {
+        Box::pin(async move { Ok(self.fetch(location).await?.daily) })
+    }
+
+    fn forecast<'a>(&'a self, location: &'a Location) -> BoxFuture<'a, Result<Forecast>> {
+        Box::pin(self.fetch(location))
+    }
+
+    /// Only the usual week is cached; longer forecasts are always fetched
+    fn forecast_days<'a>(
+        &'a self,
+        location: &'a Location,
+        days: u8,
+    ) -> BoxFuture<'a, Result<Forecast>> {
+        Box::pin(async move {
+            if days <= FORECAST_DAYS {
+                return self.fetch(location).await;
+            }
+            let mut forecast = self.forecaster.forecast_days(location, days).await?;
+            apply_forecast(&mut forecast, self.feels_like);
+            Ok(forecast)
+        })
+    }
+
+    fn fetched_at(&self, location: &Location) -> Option<DateTime<Utc>> {
+        let key = self.key_of(location)?;
+        self.cache
+            .load(&key)
+            .filter(|cached| same_place(&cached.location, location))
+            .map(|cached| cached.fetched)
+    }
+
+    fn provider(&self) -> String {
+        self.forecaster.provider()
+    }
+}
@@ -1,4 +1,5 @@
-use crate::modules::types::{DailyForecast, HourlyForecast, WeatherCondition};
+use crate::modules::types::{DailyForecast, HourlyForecast, Intensity, Percent, WeatherCondition};
+use crate::modules::utils::{beaufort, WindBarb};
 use ratatui::{
     layout::Rect,
     style::Color,
@@ -9,11 +10,18 @@ use ratatui::{
 use std::f64::consts::PI;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// Brownish tone used for blowing dust and sand haze
+const DUST_BROWN: Color = Color::Rgb(166, 123, 74);
+
 /// Renders a stunning weather canvas with highly detailed, professional-quality visuals
+///
+/// Rain, snow, sleet and hail get denser with the condition's intensity.
+#[allow(clippy::too_many_arguments)]
 pub fn render_weather_canvas<B: ratatui::backend::Backend>(
     condition: &WeatherCondition,
+    intensity: Intensity,
     temperature: f64,
-    humidity: u8,
+    humidity: Percent,
     wind_speed: f64,
     is_day: bool,
     frame: &mut Frame<B>,
@@ -50,24 +58,44 @@ pub fn render_weather_canvas<B: ratatui::backend::Backend>(
                     }
                 }
                 WeatherCondition::Rain | WeatherCondition::Drizzle => {
-                    draw_rain_system(ctx, condition == &WeatherCondition::Rain, wind_speed);
+                    draw_rain_system(
+                        ctx,
+                        condition == &WeatherCondition::Rain,
+                        intensity,
+                        wind_speed,
+                    );
                 }
                 WeatherCondition::Thunderstorm => {
                     draw_storm_system(ctx, wind_speed);
                 }
                 WeatherCondition::Snow => {
-                    draw_snow_system(ctx, temperature, wind_speed);
+                    draw_snow_system(ctx, temperature, intensity, wind_speed);
                 }
                 WeatherCondition::Fog | WeatherCondition::Mist => {
                     draw_fog_system(ctx, condition == &WeatherCondition::Fog, wind_speed);
                 }
+                WeatherCondition::Hail => {
+                    draw_hail_system(ctx, intensity, wind_speed);
+                }
+                WeatherCondition::Sleet => {
+                    draw_sleet_system(ctx, intensity, wind_speed);
+                }
+                WeatherCondition::Dust | WeatherCondition::Sand => {
+                    draw_dust_storm(ctx, wind_speed);
+                }
+                WeatherCondition::Squall => {
+                    draw_squall_line(ctx, wind_speed);
+                }
+                WeatherCondition::Tornado => {
+                    draw_tornado_funnel(ctx, 200.0, wind_speed);
+                }
                 _ => {
-                    draw_cloud_formations(ctx, 50, is_day, false);
+                    draw_cloud_formations(ctx, Percent(50.0), is_day, false);
                 }
             }
 
             // Add atmospheric effects
-            if wind_speed > 8.0 {
+            if beaufort(wind_speed) >= 5 {
                 draw_wind_patterns(ctx, wind_speed);
             }
 
@@ -78,82 +106,100 @@ pub fn render_weather_canvas<B: ratatui::backend::Backend>(
     frame.render_widget(canvas, area);
 }
 
-/// Draw realistic sky gradient with atmospheric effects
-fn draw_sky_gradient(
-    ctx: &mut Context,
+/// Sky color `intensity` of the way up the scene (0 at the horizon, 1 at the top)
+pub fn sky_color(
+    condition: &WeatherCondition,
     is_day: bool,
     temperature: f64,
-    condition: &WeatherCondition,
-) {
-    let layers = 25;
-
-    for layer in 0..layers {
-        let y_start = 100.0 + (layer as f64 * 4.0);
-        let y_end = y_start + 4.0;
-        let intensity = layer as f64 / layers as f64;
-
-        let color = if is_day {
-            match condition {
-                WeatherCondition::Thunderstorm => {
-                    if intensity < 0.3 {
-                        Color::Black
-                    } else if intensity < 0.7 {
-                        Color::DarkGray
-                    } else {
-                        Color::Gray
-                    }
+    intensity: f64,
+) -> Color {
+    if is_day {
+        match condition {
+            WeatherCondition::Thunderstorm
+            | WeatherCondition::Hail
+            | WeatherCondition::Squall
+            | WeatherCondition::Tornado => {
+                if intensity < 0.3 {
+                    Color::Black
+                } else if intensity < 0.7 {
+                    Color::DarkGray
+                } else {
+                    Color::Gray
                 }
-                WeatherCondition::Fog | WeatherCondition::Mist => {
-                    if intensity < 0.5 {
-                        Color::Gray
-                    } else {
-                        Color::White
-                    }
+            }
+            WeatherCondition::Fog | WeatherCondition::Mist => {
+                if intensity < 0.5 {
+                    Color::Gray
+                } else {
+                    Color::White
                 }
-                _ => {
-                    if temperature > 35.0 {
-                        if intensity < 0.2 {
-                            Color::Yellow
-                        } else if intensity < 0.5 {
-                            Color::LightYellow
-                        } else if intensity < 0.8 {
-                            Color::LightBlue
-                        } else {
-                            Color::Blue
-                        }
-                    } else if temperature < 5.0 {
-                        if intensity < 0.3 {
-                            Color::White
-                        } else if intensity < 0.7 {
-                            Color::LightBlue
-                        } else {
-                            Color::Blue
-                        }
-                    } else if intensity < 0.4 {
+            }
+            WeatherCondition::Dust | WeatherCondition::Sand => {
+                if intensity < 0.5 {
+                    DUST_BROWN
+                } else {
+                    Color::Yellow
+                }
+            }
+            _ => {
+                if temperature > 35.0 {
+                    if intensity < 0.2 {
+                        Color::Yellow
+                    } else if intensity < 0.5 {
+                        Color::LightYellow
+                    } else if intensity < 0.8 {
                         Color::LightBlue
                     } else {
                         Color::Blue
                     }
-                }
-            }
-        } else {
-            match condition {
-                WeatherCondition::Clear => {
-                    if intensity < 0.6 {
-                        Color::Black
+                } else if temperature < 5.0 {
+                    if intensity < 0.3 {
+                        Color::White
+                    } else if intensity < 0.7 {
+                        Color::LightBlue
                     } else {
                         Color::Blue
                     }
+                } else if intensity < 0.4 {
+                    Color::LightBlue
+                } else {
+                    Color::Blue
                 }
-                _ => {
-                    if intensity < 0.8 {
-                        Color::Black
-                    } else {
-                        Color::DarkGray
-                    }
+            }
+        }
+    } else {
+        match condition {
+            WeatherCondition::Clear => {
+                if intensity < 0.6 {
+                    Color::Black
+                } else {
+                    Color::Blue
                 }
             }
-        };
+            _ => {
+                if intensity < 0.8 {
+                    Color::Black
+                } else {
+                    Color::DarkGray
+                }
+            }
+        }
+    }
+}
+
+/// Draw realistic sky gradient with atmospheric effects
+fn draw_sky_gradient(
+    ctx: &mut Context,
+    is_day: bool,
+    temperature: f64,
+    condition: &WeatherCondition,
+) {
+    let layers = 25;
+
+    for layer in 0..layers {
+        let y_start = 100.0 + (layer as f64 * 4.0);
+        let y_end = y_start + 4.0;
+        let color = sky_color(condition, is_day, temperature, layer as f64 / layers as f64);
 
         for y in (y_start as u32)..=(y_end as u32) {
             ctx.draw(&Line {
@@ -167,6 +213,20 @@ fn draw_sky_gradient(
     }
 }
 
+/// Ground color for the condition; grass near the horizon, earth below
+pub fn ground_color(condition: &WeatherCondition, near_horizon: bool) -> Color {
+    match condition {
+        WeatherCondition::Snow | WeatherCondition::Hail => Color::White,
+        WeatherCondition::Rain | WeatherCondition::Drizzle | WeatherCondition::Sleet => {
+            Color::DarkGray
+        }
+        WeatherCondition::Dust | WeatherCondition::Sand => DUST_BROWN,
+        WeatherCondition::Fog | WeatherCondition::Mist => Color::Gray,
+        _ if near_horizon => Color::Green,
+        _ => Color::DarkGray,
+    }
+}
+
 /// Draw detailed ground terrain with environmental adaptation
 fn draw_ground_terrain(ctx: &mut Context, condition: &WeatherCondition) {
     // Main horizon line
@@ -180,18 +240,7 @@ fn draw_ground_terrain(ctx: &mut Context, condition: &WeatherCondition) {
 
     // Ground surface with condition-specific details
     for y in 0..50 {
-        let base_color = match condition {
-            WeatherCondition::Snow => Color::White,
-            WeatherCondition::Rain | WeatherCondition::Drizzle => Color::DarkGray,
-            WeatherCondition::Fog | WeatherCondition::Mist => Color::Gray,
-            _ => {
-                if y > 30 {
-                    Color::Green
-                } else {
-                    Color::DarkGray
-                }
-            }
-        };
+        let base_color = ground_color(condition, y > 30);
 
         let density = match y {
             0..=15 => 30,
@@ -212,12 +261,13 @@ fn draw_ground_terrain(ctx: &mut Context, condition: &WeatherCondition) {
 
     // Add ground features based on weather
     match condition {
-        WeatherCondition::Rain | WeatherCondition::Drizzle => {
+        WeatherCondition::Rain | WeatherCondition::Drizzle | WeatherCondition::Sleet => {
             draw_puddles(ctx);
         }
         WeatherCondition::Snow => {
             draw_snow_drifts(ctx);
         }
+        WeatherCondition::Dust | WeatherCondition::Sand | WeatherCondition::Hail => {}
         _ => {
             draw_grass_details(ctx);
         }
@@ -467,7 +517,7 @@ fn draw_twinkling_star(ctx: &mut Context, x: f64, y: f64, size: f64) {
 }
 
 /// Draw realistic cloud formations with depth and detail
-fn draw_cloud_formations(ctx: &mut Context, humidity: u8, is_day: bool, is_storm: bool) {
+fn draw_cloud_formations(ctx: &mut Context, humidity: Percent, is_day: bool, is_storm: bool) {
     let base_color = if is_storm {
         Color::Black
     } else if is_day {
@@ -476,7 +526,7 @@ fn draw_cloud_formations(ctx: &mut Context, humidity: u8, is_day: bool, is_storm
         Color::Gray
     };
 
-    let num_clouds = ((humidity / 15).clamp(2, 8)) as usize;
+    let num_clouds = ((humidity.0 / 15.0) as usize).clamp(2, 8);
     let cloud_data = [
         (60.0, 140.0, 32.0, 0.8),
         (140.0, 155.0, 38.0, 1.0),
@@ -630,9 +680,9 @@ fn draw_sun_through_clouds(ctx: &mut Context, x: f64, y: f64) {
 }
 
 /// Draw detailed rain system with varying intensity
-fn draw_rain_system(ctx: &mut Context, heavy_rain: bool, wind_speed: f64) {
+fn draw_rain_system(ctx: &mut Context, heavy_rain: bool, intensity: Intensity, wind_speed: f64) {
     // Rain clouds
-    draw_cloud_formations(ctx, 90, true, false);
+    draw_cloud_formations(ctx, Percent(90.0), true, false);
 
     // Animate rain drops
     let time = SystemTime::now()
@@ -641,13 +691,13 @@ fn draw_rain_system(ctx: &mut Context, heavy_rain: bool, wind_speed: f64) {
         .as_millis();
     let animation_offset = (time / 120) % 80;
 
-    let drop_density = if heavy_rain { 70 } else { 45 };
+    let drop_density = intensity.scale(if heavy_rain { 70 } else { 45 });
     let drop_length = if heavy_rain { 18.0 } else { 12.0 };
     let wind_lean = (wind_speed * 0.8).min(8.0);
 
     for i in 0..drop_density {
         for layer in 0..25 {
-            let base_x = (i * 6) as f64;
+            let base_x = i as f64 * 420.0 / drop_density as f64;
             let fall_speed = if heavy_rain { 10 } else { 8 };
             let y_pos =
                 ((layer * fall_speed + animation_offset as usize + i * 2) % 140 + 60) as f64;
@@ -731,7 +781,7 @@ fn draw_puddles(ctx: &mut Context) {
 /// Draw dramatic thunderstorm system
 fn draw_storm_system(ctx: &mut Context, wind_speed: f64) {
     // Massive storm clouds
-    draw_cloud_formations(ctx, 95, true, true);
+    draw_cloud_formations(ctx, Percent(95.0), true, true);
 
     // Lightning system
     let time = SystemTime::now()
@@ -915,9 +965,9 @@ fn draw_storm_ground_effects(ctx: &mut Context) {
 }
 
 /// Draw beautiful snow system with different flake types
-fn draw_snow_system(ctx: &mut Context, temperature: f64, wind_speed: f64) {
+fn draw_snow_system(ctx: &mut Context, temperature: f64, intensity: Intensity, wind_speed: f64) {
     // Snow clouds
-    draw_cloud_formations(ctx, 80, true, false);
+    draw_cloud_formations(ctx, Percent(80.0), true, false);
 
     let time = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -926,11 +976,11 @@ fn draw_snow_system(ctx: &mut Context, temperature: f64, wind_speed: f64) {
     let snow_frame = (time / 500) % 60;
     let wind_drift = wind_speed * 0.8;
 
-    let flake_count = if temperature < -5.0 { 45 } else { 35 };
+    let flake_count = intensity.scale(if temperature < -5.0 { 45 } else { 35 });
 
     for i in 0..flake_count {
         for layer in 0..20 {
-            let base_x = (i * 9) as f64;
+            let base_x = i as f64 * 405.0 / flake_count as f64;
             let base_drift = 12.0 * ((layer as f64 * 0.15).sin());
             let wind_drift_effect = layer as f64 * wind_drift * 0.02;
             let final_x = base_x + base_drift + wind_drift_effect;
@@ -1255,6 +1305,255 @@ fn draw_fog_tendrils(ctx: &mut Context, wind_speed: f64, thick_fog: bool) {
     }
 }
 
+/// Draw hailstorm with bouncing ice pellets
+fn draw_hail_system(ctx: &mut Context, intensity: Intensity, wind_speed: f64) {
+    // Heavy storm clouds
+    draw_cloud_formations(ctx, Percent(95.0), true, true);
+
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis();
+    let hail_frame = (time / 90) % 120;
+    let wind_lean = (wind_speed * 0.6).min(8.0);
+
+    // Falling hailstones
+    let stones = intensity.scale(40);
+    for i in 0..stones {
+        for layer in 0..8 {
+            let base_x = i as f64 * 400.0 / stones as f64 + 3.0;
+            let y_pos = ((layer * 17 + hail_frame as usize + i * 7) % 120 + 55) as f64;
+            let final_x = base_x + (y_pos - 55.0) * wind_lean * 0.02;
+
+            if (0.0..400.0).contains(&final_x) {
+                ctx.draw(&Circle {
+                    x: final_x,
+                    y: y_pos,
+                    radius: 1.2 + (i % 3) as f64 * 0.4,
+                    color: if (i + layer) % 2 == 0 {
+                        Color::White
+                    } else {
+                        Color::LightCyan
+                    },
+                });
+            }
+        }
+    }
+
+    // Bouncing pellets tracing small arcs above the ground
+    for i in 0..25 {
+        let phase = ((hail_frame as usize + i * 11) % 20) as f64 / 20.0;
+        let start_x = (i * 16) as f64 + 8.0;
+        let bounce_height = 6.0 + (i % 4) as f64 * 2.0;
+        let x = start_x + phase * 10.0;
+        let y = 50.0 + bounce_height * (phase * PI).sin();
+
+        ctx.draw(&Points {
+            coords: &[(x, y)],
+            color: Color::White,
+        });
+    }
+
+    // Hailstones collected on the ground
+    for x in (0..400).step_by(7) {
+        let y = 44.0 + ((x as f64 * 0.37).sin() * 3.0);
+        ctx.draw(&Points {
+            coords: &[(x as f64, y), (x as f64 + 2.0, y - 1.0)],
+            color: Color::White,
+        });
+    }
+}
+
+/// Draw sleet as a mix of rain streaks and ice pellets
+fn draw_sleet_system(ctx: &mut Context, intensity: Intensity, wind_speed: f64) {
+    draw_cloud_formations(ctx, Percent(85.0), true, false);
+
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis();
+    let sleet_frame = (time / 110) % 100;
+    let wind_lean = (wind_speed * 0.8).min(8.0);
+
+    let pellets = intensity.scale(55);
+    for i in 0..pellets {
+        for layer in 0..15 {
+            let base_x = i as f64 * 385.0 / pellets as f64;
+            let y_pos = ((layer * 9 + sleet_frame as usize + i * 3) % 130 + 55) as f64;
+            let final_x = base_x + (y_pos - 55.0) * wind_lean * 0.02;
+
+            if !(0.0..400.0).contains(&final_x) {
+                continue;
+            }
+
+            if (i + layer) % 3 == 0 {
+                // Ice pellet
+                ctx.draw(&Points {
+                    coords: &[(final_x, y_pos), (final_x + 1.0, y_pos)],
+                    color: Color::White,
+                });
+            } else {
+                // Short, cold rain streak
+                ctx.draw(&Line {
+                    x1: final_x,
+                    y1: y_pos,
+                    x2: final_x + wind_lean * 0.2,
+                    y2: y_pos - 7.0,
+                    color: Color::LightBlue,
+                });
+            }
+        }
+    }
+}
+
+/// Draw blowing dust or sand with a brown haze
+fn draw_dust_storm(ctx: &mut Context, wind_speed: f64) {
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis();
+    let drift = ((time / 60) % 400) as f64;
+    let gust = (wind_speed * 0.5).clamp(1.0, 6.0);
+
+    // Layered haze bands obscuring the horizon
+    for layer in 0..14 {
+        let base_y = 52.0 + (layer as f64 * 9.0);
+        for x in (0..400).step_by(6) {
+            let wave = 5.0 * ((x as f64 * 0.02 + layer as f64 * 0.7 + drift * 0.01).sin());
+            ctx.draw(&Line {
+                x1: x as f64,
+                y1: base_y + wave,
+                x2: x as f64 + 6.0,
+                y2: base_y + wave,
+                color: if layer % 3 == 0 {
+                    Color::Yellow
+                } else {
+                    DUST_BROWN
+                },
+            });
+        }
+    }
+
+    // Wind-driven grains streaking across the scene
+    for i in 0..120 {
+        let y = 52.0 + ((i * 37) % 130) as f64;
+        let x = ((i * 53) as f64 + drift * gust) % 400.0;
+        ctx.draw(&Points {
+            coords: &[(x, y), ((x + gust).min(399.0), y + 0.5)],
+            color: DUST_BROWN,
+        });
+    }
+
+    // Dimmed sun disc peering through the haze
+    ctx.draw(&Circle {
+        x: 320.0,
+        y: 160.0,
+        radius: 10.0,
+        color: Color::LightRed,
+    });
+}
+
+/// Draw a squall line with horizontal wind-driven streaks
+fn draw_squall_line(ctx: &mut Context, wind_speed: f64) {
+    draw_cloud_formations(ctx, Percent(90.0), true, true);
+
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis();
+    let sweep = ((time / 40) % 400) as f64;
+    let streak_length = (20.0 + wind_speed * 2.0).min(60.0);
+
+    // Shelf cloud edge along the leading gust front
+    for x in (0..400).step_by(4) {
+        let edge_y = 120.0 - ((x as f64 * 0.03).sin() * 6.0) - (x as f64 * 0.05);
+        ctx.draw(&Points {
+            coords: &[(x as f64, edge_y)],
+            color: Color::DarkGray,
+        });
+    }
+
+    // Horizontal gust streaks
+    for i in 0..45 {
+        let y = 55.0 + ((i * 29) % 110) as f64;
+        let x = ((i * 71) as f64 + sweep) % 400.0;
+        let end_x = (x + streak_length).min(400.0);
+
+        ctx.draw(&Line {
+            x1: x,
+            y1: y,
+            x2: end_x,
+            y2: y - 1.5,
+            color: if i % 3 == 0 {
+                Color::White
+            } else {
+                Color::Gray
+            },
+        });
+    }
+
+    // Slanted rain curtains driven by the wind
+    for i in 0..30 {
+        let x = (i * 13) as f64;
+        ctx.draw(&Line {
+            x1: x,
+            y1: 110.0,
+            x2: x + 25.0,
+            y2: 55.0,
+            color: Color::Blue,
+        });
+    }
+}
+
+/// Draw a rotating tornado funnel touching down
+fn draw_tornado_funnel(ctx: &mut Context, base_x: f64, wind_speed: f64) {
+    draw_cloud_formations(ctx, Percent(95.0), true, true);
+
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis();
+    let spin = (time as f64 / 150.0) % (2.0 * PI);
+    let sway = (time as f64 / 900.0).sin() * (4.0 + wind_speed * 0.3).min(12.0);
+
+    // Funnel narrows from the cloud base down to the ground
+    let top_y = 150.0;
+    let bottom_y = 52.0;
+    let rings = 28;
+
+    for ring in 0..rings {
+        let t = ring as f64 / rings as f64;
+        let y = top_y - t * (top_y - bottom_y);
+        let radius = 30.0 * (1.0 - t).powf(1.6) + 2.0;
+        let center_x = base_x + sway * t;
+
+        for step in 0..10 {
+            let angle = spin + step as f64 * PI / 5.0 + t * 4.0;
+            ctx.draw(&Points {
+                coords: &[(center_x + radius * angle.cos(), y + angle.sin() * 1.5)],
+                color: if step % 2 == 0 {
+                    Color::Gray
+                } else {
+                    Color::DarkGray
+                },
+            });
+        }
+    }
+
+    // Debris cloud at the point of touchdown
+    for i in 0..30 {
+        let angle = spin * 2.0 + i as f64 * PI / 15.0;
+        let radius = 8.0 + (i % 5) as f64 * 3.0;
+        ctx.draw(&Points {
+            coords: &[(
+                base_x + sway + radius * angle.cos(),
+                bottom_y + 4.0 + (radius * 0.4) * angle.sin().abs(),
+            )],
+            color: DUST_BROWN,
+        });
+    }
+}
+
 /// Draw dynamic wind patterns
 fn draw_wind_patterns(ctx: &mut Context, wind_speed: f64) {
     let time = SystemTime::now()
@@ -1262,7 +1561,8 @@ fn draw_wind_patterns(ctx: &mut Context, wind_speed: f64) {
         .unwrap()
         .as_millis();
     let motion_offset = (time / 150) % 200;
-    let num_streams = ((wind_speed / 6.0).clamp(3.0, 10.0)) as usize;
+    let force = beaufort(wind_speed);
+    let num_streams = (force.saturating_sub(2) as usize).clamp(3, 10);
 
     for stream in 0..num_streams {
         let base_y = 70.0 + (stream as f64 * 18.0);
@@ -1280,11 +1580,11 @@ fn draw_wind_patterns(ctx: &mut Context, wind_speed: f64) {
                 let end_y = wave_y + 2.0 * ((end_x * 0.02).sin());
 
                 // Wind streak colors based on intensity
-                let wind_color = match wind_speed as u32 {
-                    s if s > 20 => Color::Red,    // Severe winds
-                    s if s > 15 => Color::Yellow, // Strong winds
-                    s if s > 10 => Color::White,  // Moderate winds
-                    _ => Color::Gray,             // Light winds
+                let wind_color = match force {
+                    f if f >= 10 => Color::Red,   // Storm
+                    f if f >= 8 => Color::Yellow, // Gale
+                    f if f >= 6 => Color::White,  // Strong breeze
+                    _ => Color::Gray,             // Fresh breeze
                 };
 
                 // Main wind line
@@ -1314,6 +1614,91 @@ fn draw_wind_patterns(ctx: &mut Context, wind_speed: f64) {
     }
 }
 
+/// Draw a station-model wind barb at (x, y)
+///
+/// The staff points into the wind and the barbs sit on its clockwise side;
+/// calm wind is a small circle.
+fn draw_wind_barb(ctx: &mut Context, x: f64, y: f64, speed: f64, direction: u16, color: Color) {
+    const STAFF: f64 = 12.0;
+    const BARB: f64 = 5.0;
+    const SPACING: f64 = 2.5;
+
+    let barb = WindBarb::from_speed(speed);
+    if barb.is_calm() {
+        ctx.draw(&Circle {
+            x,
+            y,
+            radius: 2.0,
+            color,
+        });
+        return;
+    }
+
+    // Unit vector along the staff and the direction barbs are drawn in
+    let angle = (direction as f64).to_radians();
+    let (ux, uy) = (angle.sin(), angle.cos());
+    let (bx, by) = (
+        (angle + PI / 2.5).sin() * BARB,
+        (angle + PI / 2.5).cos() * BARB,
+    );
+    let along = |distance: f64| (x + ux * distance, y + uy * distance);
+
+    let (tip_x, tip_y) = along(STAFF);
+    ctx.draw(&Line {
+        x1: x,
+        y1: y,
+        x2: tip_x,
+        y2: tip_y,
+        color,
+    });
+
+    let mut distance = STAFF;
+    for _ in 0..barb.pennants {
+        let (x1, y1) = along(distance);
+        let (x2, y2) = along(distance - SPACING);
+        ctx.draw(&Line {
+            x1,
+            y1,
+            x2: x1 + bx,
+            y2: y1 + by,
+            color,
+        });
+        ctx.draw(&Line {
+            x1: x1 + bx,
+            y1: y1 + by,
+            x2,
+            y2,
+            color,
+        });
+        distance -= SPACING * 1.5;
+    }
+    for _ in 0..barb.full {
+        let (x1, y1) = along(distance);
+        ctx.draw(&Line {
+            x1,
+            y1,
+            x2: x1 + bx,
+            y2: y1 + by,
+            color,
+        });
+        distance -= SPACING;
+    }
+    if barb.half > 0 {
+        // A lone half barb is set back from the tip so it reads as 5 kt
+        if distance == STAFF {
+            distance -= SPACING;
+        }
+        let (x1, y1) = along(distance);
+        ctx.draw(&Line {
+            x1,
+            y1,
+            x2: x1 + bx / 2.0,
+            y2: y1 + by / 2.0,
+            color,
+        });
+    }
+}
+
 /// Draw grass details for clear weather
 fn draw_grass_details(ctx: &mut Context) {
     let time = SystemTime::now()
@@ -1343,7 +1728,12 @@ fn draw_grass_details(ctx: &mut Context) {
 }
 
 /// Draw comprehensive weather data indicators
-fn draw_weather_indicators(ctx: &mut Context, temperature: f64, humidity: u8, wind_speed: f64) {
+fn draw_weather_indicators(
+    ctx: &mut Context,
+    temperature: f64,
+    humidity: Percent,
+    wind_speed: f64,
+) {
     let panel_x = 15.0;
     let panel_y = 185.0;
 
@@ -1375,7 +1765,7 @@ fn draw_weather_indicators(ctx: &mut Context, temperature: f64, humidity: u8, wi
     }
 
     // Humidity indicator (water drops)
-    let humidity_drops = (humidity / 20).min(5);
+    let humidity_drops = ((humidity.0 / 20.0) as u8).min(5);
     for drop in 0..humidity_drops {
         let drop_x = panel_x + 12.0;
         let drop_y = panel_y - 35.0 + (drop as f64 * 8.0);
@@ -1442,6 +1832,7 @@ pub fn render_current_weather_canvas<B: ratatui::backend::Backend>(
 
         render_weather_canvas(
             &current.main_condition,
+            current.intensity,
             current.temperature,
             current.humidity,
             current.wind_speed,
@@ -1509,8 +1900,12 @@ fn is_daytime(timestamp: &chrono::DateTime<chrono::Utc>) -> bool {
 }
 
 /// Render enhanced forecast canvas with detailed mini weather scenes
+///
+/// Days in `matches` (from a search) are underlined in yellow.
 pub fn render_forecast_canvas<B: ratatui::backend::Backend>(
     daily_data: &[DailyForecast],
+    selected_day: Option<usize>,
+    matches: &[usize],
     frame: &mut Frame<B>,
     area: Rect,
 ) {
@@ -1545,6 +1940,36 @@ pub fn render_forecast_canvas<B: ratatui::backend::Backend>(
             for (i, day) in daily_data.iter().take(7).enumerate() {
                 let x_offset = i as f64 * 70.0 + 10.0;
 
+                // Highlight the selected day column
+                if selected_day == Some(i) {
+                    ctx.draw(&Rectangle {
+                        x: x_offset + 2.0,
+                        y: 2.0,
+                        width: 66.0,
+                        height: 94.0,
+                        color: Color::LightCyan,
+                    });
+                    ctx.draw(&Line {
+                        x1: x_offset + 10.0,
+                        y1: 8.0,
+                        x2: x_offset + 60.0,
+                        y2: 8.0,
+                        color: Color::LightCyan,
+                    });
+                }
+
+                if matches.contains(&i) {
+                    for y in [4.0, 5.0] {
+                        ctx.draw(&Line {
+                            x1: x_offset + 10.0,
+                            y1: y,
+                            x2: x_offset + 60.0,
+                            y2: y,
+                            color: Color::Yellow,
+                        });
+                    }
+                }
+
                 // Enhanced weather scene for each day
                 match day.main_condition {
                     WeatherCondition::Clear => {
@@ -1692,6 +2117,94 @@ pub fn render_forecast_canvas<B: ratatui::backend::Backend>(
                             });
                         }
                     }
+                    WeatherCondition::Hail | WeatherCondition::Sleet => {
+                        draw_realistic_cloud(
+                            ctx,
+                            x_offset + 30.0,
+                            70.0,
+                            7.0,
+                            Color::DarkGray,
+                            1.0,
+                            day.main_condition == WeatherCondition::Hail,
+                        );
+
+                        // Mini ice pellets, mixed with rain for sleet
+                        for pellet in 0..6 {
+                            let px = x_offset + 24.0 + (pellet as f64 * 2.5);
+                            let py = 58.0 - (pellet % 3) as f64 * 5.0;
+                            if day.main_condition == WeatherCondition::Sleet && pellet % 2 == 1 {
+                                ctx.draw(&Line {
+                                    x1: px,
+                                    y1: py,
+                                    x2: px,
+                                    y2: py - 5.0,
+                                    color: Color::LightBlue,
+                                });
+                            } else {
+                                ctx.draw(&Circle {
+                                    x: px,
+                                    y: py,
+                                    radius: 1.0,
+                                    color: Color::White,
+                                });
+                            }
+                        }
+                    }
+                    WeatherCondition::Dust | WeatherCondition::Sand => {
+                        // Brown haze bands
+                        for layer in 0..6 {
+                            let haze_y = 45.0 + (layer as f64 * 5.0);
+                            ctx.draw(&Line {
+                                x1: x_offset + 15.0,
+                                y1: haze_y,
+                                x2: x_offset + 50.0,
+                                y2: haze_y + if layer % 2 == 0 { 1.5 } else { -1.5 },
+                                color: if layer % 3 == 0 {
+                                    Color::Yellow
+                                } else {
+                                    DUST_BROWN
+                                },
+                            });
+                        }
+                    }
+                    WeatherCondition::Squall => {
+                        draw_realistic_cloud(
+                            ctx,
+                            x_offset + 30.0,
+                            72.0,
+                            7.0,
+                            Color::Gray,
+                            1.0,
+                            true,
+                        );
+
+                        // Horizontal gust streaks
+                        for streak in 0..5 {
+                            let streak_y = 45.0 + (streak as f64 * 4.0);
+                            ctx.draw(&Line {
+                                x1: x_offset + 15.0 + (streak % 2) as f64 * 5.0,
+                                y1: streak_y,
+                                x2: x_offset + 48.0,
+                                y2: streak_y,
+                                color: Color::White,
+                            });
+                        }
+                    }
+                    WeatherCondition::Tornado => {
+                        // Narrowing funnel
+                        for ring in 0..8 {
+                            let t = ring as f64 / 8.0;
+                            let half_width = 12.0 * (1.0 - t) + 1.0;
+                            let ring_y = 75.0 - t * 35.0;
+                            ctx.draw(&Line {
+                                x1: x_offset + 30.0 - half_width + t * 4.0,
+                                y1: ring_y,
+                                x2: x_offset + 30.0 + half_width + t * 4.0,
+                                y2: ring_y,
+                                color: Color::Gray,
+                            });
+                        }
+                    }
                     _ => {
                         // Default weather symbol
                         ctx.draw(&Circle {
@@ -1730,6 +2243,15 @@ pub fn render_forecast_canvas<B: ratatui::backend::Backend>(
                     }
                 }
 
+                draw_wind_barb(
+                    ctx,
+                    x_offset + 30.0,
+                    28.0,
+                    day.wind_speed,
+                    day.wind_direction,
+                    Color::White,
+                );
+
                 // Enhanced temperature visualization
                 let temp_height = (day.temp_max * 0.8).min(25.0);
                 let temp_color = match day.temp_max as i32 {
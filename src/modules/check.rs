@@ -0,0 +1,127 @@
+use crate::modules::config::ThresholdSettings;
+use crate::modules::types::HourlyForecast;
+use crate::modules::utils::DisplayUnits;
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, Utc};
+
+/// Thresholds for `weather_man check`; unset thresholds are not checked
+///
+/// Temperatures and wind speeds are in the display units, as the user reads
+/// them in the forecast.
+#[derive(Debug, Clone, Default)]
+pub struct Thresholds {
+    /// Precipitation probability in percent
+    pub rain_above: Option<f64>,
+    /// Temperature in the display unit
+    pub temp_above: Option<f64>,
+    /// Temperature in the display unit
+    pub temp_below: Option<f64>,
+    /// Wind speed in the display unit
+    pub wind_above: Option<f64>,
+}
+
+/// Outcome of a single threshold over the forecast window
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThresholdCheck {
+    pub description: String,
+    pub observed: f64,
+    pub met: bool,
+}
+
+impl Thresholds {
+    /// Rain and wind thresholds from the config file's `[thresholds]` section,
+    /// whose wind speeds are in m/s
+    pub fn from_settings(settings: &ThresholdSettings, units: &DisplayUnits) -> Self {
+        Self {
+            rain_above: settings.rain_notify_pop.map(|pop| pop * 100.0),
+            wind_above: settings.wind_warning.map(|speed| units.wind.convert(speed)),
+            ..Self::default()
+        }
+    }
+
+    /// Whether no threshold was given
+    pub fn is_empty(&self) -> bool {
+        self.rain_above.is_none()
+            && self.temp_above.is_none()
+            && self.temp_below.is_none()
+            && self.wind_above.is_none()
+    }
+
+    /// Evaluate every threshold against the `hours` hours of forecast from
+    /// the hour containing `now`
+    ///
+    /// "Above" thresholds compare against the window's maximum and "below"
+    /// thresholds against its minimum, so a single hour is enough to meet them.
+    pub fn evaluate(
+        &self,
+        hourly_data: &[HourlyForecast],
+        hours: usize,
+        now: DateTime<Utc>,
+        units: &DisplayUnits,
+    ) -> Result<Vec<ThresholdCheck>> {
+        let window: Vec<&HourlyForecast> = hourly_data
+            .iter()
+            .filter(|h| h.timestamp + Duration::hours(1) > now)
+            .take(hours)
+            .collect();
+        if window.is_empty() {
+            return Err(anyhow!("No hourly forecast data to check"));
+        }
+
+        let max = |value: &dyn Fn(&HourlyForecast) -> f64| {
+            window.iter().map(|h| value(h)).fold(f64::MIN, f64::max)
+        };
+        let min = |value: &dyn Fn(&HourlyForecast) -> f64| {
+            window.iter().map(|h| value(h)).fold(f64::MAX, f64::min)
+        };
+        let temperature = |h: &HourlyForecast| units.temperature.convert(h.temperature);
+
+        let mut checks = Vec::new();
+
+        if let Some(limit) = self.rain_above {
+            let observed = max(&|h| h.pop * 100.0);
+            checks.push(ThresholdCheck {
+                description: format!("rain chance above {:.0}%", limit),
+                observed,
+                met: observed > limit,
+            });
+        }
+
+        if let Some(limit) = self.temp_above {
+            let observed = max(&temperature);
+            checks.push(ThresholdCheck {
+                description: format!(
+                    "temperature above {:.1}{}",
+                    limit,
+                    units.temperature.label()
+                ),
+                observed,
+                met: observed > limit,
+            });
+        }
+
+        if let Some(limit) = self.temp_below {
+            let observed = min(&temperature);
+            checks.push(ThresholdCheck {
+                description: format!(
+                    "temperature below {:.1}{}",
+                    limit,
+                    units.temperature.label()
+                ),
+                observed,
+                met: observed < limit,
+            });
+        }
+
+        if let Some(limit) = self.wind_above {
+            let observed = max(&|h| units.wind.convert(h.wind_speed));
+            checks.push(ThresholdCheck {
+                description: format!("wind above {:.1} {}", limit, units.wind.label()),
+                observed,
+                met: observed > limit,
+            });
+        }
+
+        Ok(checks)
+    }
+}
@@ -0,0 +1,345 @@
+use anyhow::{anyhow, Context, Result};
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::Serialize;
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::modules::http::HttpContext;
+use crate::modules::types::{DailyForecast, Location};
+
+/// Open-Meteo's historical weather API
+pub const ARCHIVE_URL: &str = "https://archive-api.open-meteo.com/v1";
+/// The WMO reference period for climate normals
+pub const NORMALS_PERIOD: (i32, i32) = (1991, 2020);
+/// First year in the archive
+pub const ARCHIVE_START: i32 = 1940;
+/// Days until recent weather shows up in the archive
+const ARCHIVE_DELAY_DAYS: i64 = 5;
+/// Days either side of a date averaged into its normal, to smooth out noise
+const NORMAL_WINDOW_DAYS: i64 = 3;
+/// Precipitation (mm) that makes a day count as wet
+const WET_DAY_MM: f64 = 1.0;
+
+/// One day of the historical record
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClimateDay {
+    pub date: NaiveDate,
+    pub temp_max: Option<f64>,
+    pub temp_min: Option<f64>,
+    pub precipitation: Option<f64>,
+}
+
+/// Parse an archive response with daily max/min temperature and precipitation
+pub fn parse_archive(json: &Value) -> Result<Vec<ClimateDay>> {
+    let daily = &json["daily"];
+    let times = daily["time"]
+        .as_array()
+        .ok_or_else(|| anyhow!("No daily data in the climate archive"))?;
+    let value = |name: &str, i: usize| daily[name][i].as_f64();
+
+    times
+        .iter()
+        .enumerate()
+        .map(|(i, time)| {
+            let date = time
+                .as_str()
+                .and_then(|time| NaiveDate::parse_from_str(time, "%Y-%m-%d").ok())
+                .ok_or_else(|| anyhow!("Invalid date in the climate archive"))?;
+            Ok(ClimateDay {
+                date,
+                temp_max: value("temperature_2m_max", i),
+                temp_min: value("temperature_2m_min", i),
+                precipitation: value("precipitation_sum", i),
+            })
+        })
+        .collect()
+}
+
+/// A record value and the year it was set
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Record {
+    pub value: f64,
+    pub year: i32,
+}
+
+/// Normals and records for one calendar day
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DayNormals {
+    pub month: u32,
+    pub day: u32,
+    pub normal_max: f64,
+    pub normal_min: f64,
+    /// Average precipitation (mm) on the day
+    pub normal_precipitation: f64,
+    pub record_max: Record,
+    pub record_min: Record,
+    /// Years with at least 1 mm of precipitation on the day
+    pub wet_years: usize,
+    pub years: usize,
+}
+
+/// Days between `date` and the same calendar day as `target`, across year ends
+fn calendar_distance(date: NaiveDate, target: NaiveDate) -> i64 {
+    (date.year() - 1..=date.year() + 1)
+        .filter_map(|year| {
+            // February 29th falls back to the 28th in other years
+            NaiveDate::from_ymd_opt(year, target.month(), target.day())
+                .or_else(|| NaiveDate::from_ymd_opt(year, target.month(), target.day() - 1))
+        })
+        .map(|day| (date - day).num_days().abs())
+        .min()
+        .unwrap_or(i64::MAX)
+}
+
+fn mean(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, count) = values.fold((0.0, 0), |(sum, count), v| (sum + v, count + 1));
+    (count > 0).then(|| sum / count as f64)
+}
+
+/// Normals and records for the calendar day of `date`
+pub fn day_normals(days: &[ClimateDay], date: NaiveDate) -> Option<DayNormals> {
+    let window: Vec<&ClimateDay> = days
+        .iter()
+        .filter(|d| calendar_distance(d.date, date) <= NORMAL_WINDOW_DAYS)
+        .collect();
+    let same_day: Vec<&ClimateDay> = days
+        .iter()
+        .filter(|d| d.date.month() == date.month() && d.date.day() == date.day())
+        .collect();
+
+    let record = |value: fn(&ClimateDay) -> Option<f64>, highest: bool| {
+        same_day
+            .iter()
+            .filter_map(|d| {
+                value(d).map(|v| Record {
+                    value: v,
+                    year: d.date.year(),
+                })
+            })
+            .reduce(|best, r| {
+                if (highest && r.value > best.value) || (!highest && r.value < best.value) {
+                    r
+                } else {
+                    best
+                }
+            })
+    };
+
+    Some(DayNormals {
+        month: date.month(),
+        day: date.day(),
+        normal_max: mean(window.iter().filter_map(|d| d.temp_max))?,
+        normal_min: mean(window.iter().filter_map(|d| d.temp_min))?,
+        normal_precipitation: mean(window.iter().filter_map(|d| d.precipitation)).unwrap_or(0.0),
+        record_max: record(|d| d.temp_max, true)?,
+        record_min: record(|d| d.temp_min, false)?,
+        wet_years: same_day
+            .iter()
+            .filter(|d| d.precipitation.is_some_and(|p| p >= WET_DAY_MM))
+            .count(),
+        years: same_day.len(),
+    })
+}
+
+/// How a forecast high compares with the normal, e.g. "Today's high of 31°
+/// is 6° above the 1991–2020 normal"
+pub fn anomaly_text(normals: &DayNormals, high: f64) -> String {
+    let difference = high.round() - normals.normal_max.round();
+    let period = format!("{}–{} normal", NORMALS_PERIOD.0, NORMALS_PERIOD.1);
+    let comparison = if difference > 0.0 {
+        format!("{:.0}° above the {}", difference, period)
+    } else if difference < 0.0 {
+        format!("{:.0}° below the {}", -difference, period)
+    } else {
+        format!("right at the {}", period)
+    };
+    let mut text = format!("Today's high of {:.0}° is {}", high, comparison);
+    if high > normals.record_max.value {
+        text.push_str(&format!(
+            ", beating the record of {:.0}° from {}",
+            normals.record_max.value, normals.record_max.year
+        ));
+    }
+    text
+}
+
+/// Average conditions for one calendar month
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MonthNormals {
+    pub month: u32,
+    pub temp_max: f64,
+    pub temp_min: f64,
+    /// Average daily precipitation (mm)
+    pub precipitation: f64,
+}
+
+/// Monthly normals from the daily record, for the months it covers
+pub fn monthly_normals(days: &[ClimateDay]) -> Vec<MonthNormals> {
+    (1..=12)
+        .filter_map(|month| {
+            let days: Vec<&ClimateDay> = days.iter().filter(|d| d.date.month() == month).collect();
+            Some(MonthNormals {
+                month,
+                temp_max: mean(days.iter().filter_map(|d| d.temp_max))?,
+                temp_min: mean(days.iter().filter_map(|d| d.temp_min))?,
+                precipitation: mean(days.iter().filter_map(|d| d.precipitation)).unwrap_or(0.0),
+            })
+        })
+        .collect()
+}
+
+/// A forecast day next to the normals for its month
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ClimateComparison {
+    pub date: NaiveDate,
+    pub temp_max: f64,
+    pub temp_min: f64,
+    pub precipitation: f64,
+    pub normal_max: f64,
+    pub normal_min: f64,
+    pub normal_precipitation: f64,
+}
+
+/// Days compared by `--mode climate`
+pub const CLIMATE_DAYS: usize = 7;
+
+/// This week's forecast next to the monthly normals, skipping days whose
+/// month has no normals
+pub fn compare_week(daily: &[DailyForecast], normals: &[MonthNormals]) -> Vec<ClimateComparison> {
+    daily
+        .iter()
+        .take(CLIMATE_DAYS)
+        .filter_map(|day| {
+            let date = day.date.date_naive();
+            let normal = normals.iter().find(|n| n.month == date.month())?;
+            Some(ClimateComparison {
+                date,
+                temp_max: day.temp_max,
+                temp_min: day.temp_min,
+                precipitation: day
+                    .precipitation
+                    .unwrap_or(day.rain.unwrap_or(0.0) + day.snow.unwrap_or(0.0)),
+                normal_max: normal.temp_max,
+                normal_min: normal.temp_min,
+                normal_precipitation: normal.precipitation,
+            })
+        })
+        .collect()
+}
+
+/// Fetches the daily record for the normals period, kept on disk for good
+#[derive(Clone)]
+pub struct ClimateService {
+    http: HttpContext,
+    cache_dir: Option<PathBuf>,
+}
+
+impl ClimateService {
+    /// Service caching in `cache_dir`, or not at all when it is `None`
+    pub fn new(http: HttpContext, cache_dir: Option<PathBuf>) -> Self {
+        Self { http, cache_dir }
+    }
+
+    /// The daily record near the location for the normals period
+    pub async fn history(&self, location: &Location) -> Result<Vec<ClimateDay>> {
+        let start = NaiveDate::from_ymd_opt(NORMALS_PERIOD.0, 1, 1).unwrap_or_default();
+        let end = NaiveDate::from_ymd_opt(NORMALS_PERIOD.1, 12, 31).unwrap_or_default();
+        self.archive(location, start, end, "").await
+    }
+
+    /// The daily record near the location for one year, up to `today` minus
+    /// the archive's delay for the current year
+    pub async fn year(
+        &self,
+        location: &Location,
+        year: i32,
+        today: NaiveDate,
+    ) -> Result<Vec<ClimateDay>> {
+        let start = NaiveDate::from_ymd_opt(year, 1, 1)
+            .filter(|start| (ARCHIVE_START..=today.year()).contains(&year) && *start < today)
+            .ok_or_else(|| anyhow!("The archive covers {} to {}", ARCHIVE_START, today.year()))?;
+        let last = NaiveDate::from_ymd_opt(year, 12, 31).unwrap_or_default();
+        let available = today - Duration::days(ARCHIVE_DELAY_DAYS);
+        if last <= available {
+            self.archive(location, start, last, &format!("_{}", year))
+                .await
+        } else if start <= available {
+            // A year in progress is not cached, it grows every day
+            self.fetch(location, start, available).await
+        } else {
+            Err(anyhow!("No archive data for {} yet", year))
+        }
+    }
+
+    /// Archive days between `start` and `end`, cached on disk under `suffix`
+    async fn archive(
+        &self,
+        location: &Location,
+        start: NaiveDate,
+        end: NaiveDate,
+        suffix: &str,
+    ) -> Result<Vec<ClimateDay>> {
+        // The archive grid is about 10 km, so nearby places share a file
+        let path = self.cache_dir.as_ref().map(|dir| {
+            dir.join(format!(
+                "{:.1}_{:.1}{}.json",
+                location.latitude, location.longitude, suffix
+            ))
+        });
+        if let Some(json) = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|text| serde_json::from_str(&text).ok())
+        {
+            return parse_archive(&json);
+        }
+
+        let json = self.request(location, start, end).await?;
+        let days = parse_archive(&json)?;
+
+        if let Some(path) = &path {
+            // Caching is best effort
+            if let Some(dir) = path.parent() {
+                let _ = fs::create_dir_all(dir);
+            }
+            let _ = fs::write(path, json.to_string());
+        }
+        Ok(days)
+    }
+
+    async fn fetch(
+        &self,
+        location: &Location,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<ClimateDay>> {
+        parse_archive(&self.request(location, start, end).await?)
+    }
+
+    async fn request(
+        &self,
+        location: &Location,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Value> {
+        let url = format!(
+            "{}/archive?latitude={:.1}&longitude={:.1}&start_date={}&end_date={}&daily=temperature_2m_max,temperature_2m_min,precipitation_sum&timezone=auto",
+            ARCHIVE_URL,
+            location.latitude,
+            location.longitude,
+            start.format("%Y-%m-%d"),
+            end.format("%Y-%m-%d")
+        );
+        let json = self
+            .http
+            .get_json(&url)
+            .await
+            .context("Could not reach the Open-Meteo climate archive")?;
+        if json["error"].as_bool() == Some(true) {
+            let reason = json["reason"].as_str().unwrap_or("Unknown error");
+            return Err(anyhow!("Open-Meteo API error: {}", reason));
+        }
+        Ok(json)
+    }
+}
@@ -0,0 +1,126 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::modules::types::{CurrentWeather, Forecast};
+
+/// Wind chill is only defined at or below this temperature (°C)
+const WIND_CHILL_MAX: f64 = 10.0;
+/// ... and above this wind speed (km/h)
+const WIND_CHILL_MIN_KMH: f64 = 4.8;
+/// Heat index is only used from this temperature (°C)
+const HEAT_INDEX_MIN: f64 = 27.0;
+/// Humidex is only reported from this temperature (°C)
+const HUMIDEX_MIN: f64 = 20.0;
+
+/// Formula behind the "feels like" temperature
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum FeelsLike {
+    /// Open-Meteo's apparent temperature
+    #[default]
+    Provider,
+    /// US National Weather Service heat index
+    HeatIndex,
+    /// Environment Canada humidex
+    Humidex,
+    /// North American wind chill index
+    WindChill,
+    /// Wind chill when cold, heat index when hot, the air temperature between
+    Auto,
+}
+
+impl FeelsLike {
+    /// Feels-like temperature (°C) from the temperature (°C), relative
+    /// humidity (%) and wind speed (m/s); `None` keeps the provider's value
+    pub fn apparent(&self, temperature: f64, humidity: f64, wind_speed: f64) -> Option<f64> {
+        match self {
+            FeelsLike::Provider => None,
+            FeelsLike::HeatIndex => Some(heat_index(temperature, humidity)),
+            FeelsLike::Humidex => Some(humidex(temperature, humidity)),
+            FeelsLike::WindChill => Some(wind_chill(temperature, wind_speed)),
+            FeelsLike::Auto => Some(if temperature <= WIND_CHILL_MAX {
+                wind_chill(temperature, wind_speed)
+            } else if temperature >= HEAT_INDEX_MIN {
+                heat_index(temperature, humidity)
+            } else {
+                temperature
+            }),
+        }
+    }
+}
+
+/// NWS heat index (°C); the air temperature below `HEAT_INDEX_MIN`
+pub fn heat_index(temperature: f64, humidity: f64) -> f64 {
+    if temperature < HEAT_INDEX_MIN {
+        return temperature;
+    }
+    let t = temperature * 9.0 / 5.0 + 32.0;
+    let rh = humidity.clamp(0.0, 100.0);
+    // Steadman's simple estimate, replaced by the Rothfusz regression in the heat
+    let simple = 0.5 * (t + 61.0 + (t - 68.0) * 1.2 + rh * 0.094);
+    let hi = if (simple + t) / 2.0 < 80.0 {
+        simple
+    } else {
+        -42.379 + 2.049_015_23 * t + 10.143_331_27 * rh
+            - 0.224_755_41 * t * rh
+            - 0.006_837_83 * t * t
+            - 0.054_817_17 * rh * rh
+            + 0.001_228_74 * t * t * rh
+            + 0.000_852_82 * t * rh * rh
+            - 0.000_001_99 * t * t * rh * rh
+    };
+    (hi - 32.0) * 5.0 / 9.0
+}
+
+/// Humidex (°C) from the vapour pressure; the air temperature below
+/// `HUMIDEX_MIN`
+pub fn humidex(temperature: f64, humidity: f64) -> f64 {
+    if temperature < HUMIDEX_MIN {
+        return temperature;
+    }
+    let saturation = 6.112 * 10f64.powf(7.5 * temperature / (237.7 + temperature));
+    let vapour_pressure = saturation * humidity.clamp(0.0, 100.0) / 100.0;
+    temperature + 0.5555 * (vapour_pressure - 10.0)
+}
+
+/// Wind chill (°C); the air temperature when it is too warm or calm for one
+pub fn wind_chill(temperature: f64, wind_speed: f64) -> f64 {
+    let kmh = wind_speed * 3.6;
+    if temperature > WIND_CHILL_MAX || kmh <= WIND_CHILL_MIN_KMH {
+        return temperature;
+    }
+    let v = kmh.powf(0.16);
+    13.12 + 0.6215 * temperature - 11.37 * v + 0.3965 * temperature * v
+}
+
+/// Recompute the current feels-like temperature with `formula`
+pub fn apply_current(weather: &mut CurrentWeather, formula: FeelsLike) {
+    if let Some(value) =
+        formula.apparent(weather.temperature, weather.humidity.0, weather.wind_speed)
+    {
+        weather.feels_like = value;
+    }
+}
+
+/// Recompute every feels-like temperature in the forecast with `formula`;
+/// daily values use the day's high and low
+pub fn apply_forecast(forecast: &mut Forecast, formula: FeelsLike) {
+    if formula == FeelsLike::Provider {
+        return;
+    }
+    if let Some(current) = &mut forecast.current {
+        apply_current(current, formula);
+    }
+    for hour in &mut forecast.hourly {
+        if let Some(value) = formula.apparent(hour.temperature, hour.humidity.0, hour.wind_speed) {
+            hour.feels_like = value;
+        }
+    }
+    for day in &mut forecast.daily {
+        let apparent = |t: f64| formula.apparent(t, day.humidity.0, day.wind_speed);
+        if let (Some(high), Some(low)) = (apparent(day.temp_max), apparent(day.temp_min)) {
+            day.feels_like_day = high;
+            day.feels_like_night = low;
+        }
+    }
+}
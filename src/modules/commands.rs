@@ -0,0 +1,978 @@
+use anyhow::{anyhow, Context, Result};
+use chrono::{Duration, Utc};
+use clap::Args;
+use colored::*;
+use futures::future::join_all;
+use futures::StreamExt;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::pin::pin;
+use std::process;
+
+use crate::modules::accuracy::{accuracy_entries, AccuracyLog};
+use crate::modules::batch::{batch_stream, fetch_batch, to_csv, BatchFormat, BatchTarget};
+use crate::modules::cache::{CachedForecast, ForecastCache};
+use crate::modules::check::Thresholds;
+use crate::modules::climate::{compare_week, monthly_normals, ClimateService};
+use crate::modules::config::{ConfigFile, EmailSettings};
+use crate::modules::diff::forecast_changes;
+use crate::modules::digest::Digest;
+use crate::modules::errors::{ErrorKind, WeatherError};
+use crate::modules::export::{export_chart, export_climate_chart, export_ics, ChartKind};
+use crate::modules::hooks::{hook_payload, rain_soon, HookEvent, Hooks};
+use crate::modules::http::HttpContext;
+use crate::modules::mail::{compose_email, send_email, sender_address, SmtpServer};
+use crate::modules::meteogram::render_meteogram;
+use crate::modules::palette::ScenePalette;
+use crate::modules::push::{forecast_alerts, threshold_alerts, WebhookFormat, WebhookPusher};
+use crate::modules::query::{format_json, format_lines, parse_fields, query_current};
+use crate::modules::reminders::{desktop_notify, ReminderSchedule};
+use crate::modules::runner::{
+    resolve_location, with_display_timezone, ForecastSource, LocationSource,
+};
+use crate::modules::speech::{spoken_summary, TtsEngine};
+use crate::modules::stats::{UsageEvent, UsageLog};
+use crate::modules::status::{status_from_forecast, write_status};
+use crate::modules::summary::narrative;
+use crate::modules::sun::{parse_offset, AlarmFormat, SunAlarm, SunEvent};
+use crate::modules::template::{parse_template, weather_scope, TemplateEngine};
+use crate::modules::tmux::tmux_line;
+use crate::modules::types::{Location, WeatherConfig};
+use crate::modules::ui::convert_to_local;
+use crate::modules::utils::to_json;
+use crate::modules::waybar::{waybar_error, waybar_output};
+use crate::modules::yearly::{report_html, report_lines, year_report};
+
+#[derive(Args)]
+pub struct CheckArgs {
+    /// Met when the precipitation chance exceeds this percentage
+    #[arg(long, value_name = "PERCENT")]
+    pub rain_above: Option<f64>,
+
+    /// Met when the temperature rises above this value (°C, or °F with imperial units)
+    #[arg(long, value_name = "DEGREES", allow_negative_numbers = true)]
+    pub temp_above: Option<f64>,
+
+    /// Met when the temperature drops below this value (°C, or °F with imperial units)
+    #[arg(long, value_name = "DEGREES", allow_negative_numbers = true)]
+    pub temp_below: Option<f64>,
+
+    /// Met when the wind speed exceeds this value, in the --wind-unit
+    #[arg(long, value_name = "SPEED")]
+    pub wind_above: Option<f64>,
+
+    /// Number of upcoming hours to check
+    #[arg(long, default_value = "24", value_parser = clap::value_parser!(u16).range(1..=168))]
+    pub hours: u16,
+
+    /// Succeed if any threshold is met instead of requiring all of them
+    #[arg(long)]
+    pub any: bool,
+
+    /// Only set the exit code, print nothing
+    #[arg(short, long)]
+    pub quiet: bool,
+}
+
+#[derive(Args)]
+pub struct PushArgs {
+    /// Webhook URL to post to; may be left out to only run the `[hooks]` commands
+    /// or write the status file
+    #[arg(long, value_name = "URL")]
+    pub webhook: Option<String>,
+
+    /// Format messages for a Slack incoming webhook
+    #[arg(long, conflicts_with = "discord")]
+    pub slack: bool,
+
+    /// Format messages for a Discord webhook
+    #[arg(long)]
+    pub discord: bool,
+
+    /// Keep running, checking for alerts every N minutes and posting a new summary each day
+    #[arg(long, value_name = "MINUTES", value_parser = clap::value_parser!(u64).range(1..))]
+    pub watch: Option<u64>,
+
+    /// Desktop notifications to reapply sunscreen and drink water while it is sunny or hot (see `[reminders]`)
+    #[arg(long, requires = "watch")]
+    pub reminders: bool,
+
+    /// Write the current weather to this file on each refresh, for status bars to read
+    #[arg(long, value_name = "PATH")]
+    pub write_status: Option<PathBuf>,
+
+    /// Write this template (e.g. "${temp}° ${condition}") to the status file instead of JSON
+    #[arg(long, value_name = "TEMPLATE", requires = "write_status")]
+    pub status_template: Option<String>,
+
+    /// Print one JSON line with the current weather per refresh, for jq or log shippers;
+    /// other messages go to stderr
+    #[arg(long)]
+    pub ndjson: bool,
+}
+
+#[derive(Args)]
+pub struct ExportArgs {
+    /// Chart to render
+    #[arg(long, value_enum, default_value = "temperature")]
+    pub chart: ChartKind,
+
+    /// Chart output file (.svg or .png)
+    #[arg(
+        short,
+        long,
+        value_name = "PATH",
+        required_unless_present_any = ["ics", "meteogram", "climate"]
+    )]
+    pub out: Option<PathBuf>,
+
+    /// Write daily forecasts as all-day calendar events to this .ics file
+    #[arg(long, value_name = "PATH")]
+    pub ics: Option<PathBuf>,
+
+    /// Write a 48-hour text meteogram to this file
+    #[arg(long, value_name = "PATH")]
+    pub meteogram: Option<PathBuf>,
+
+    /// Write this week's forecast against the 1991–2020 monthly normals to this .svg or .png file
+    #[arg(long, value_name = "PATH")]
+    pub climate: Option<PathBuf>,
+
+    /// Number of forecast days in the calendar (1-16)
+    #[arg(long, default_value = "7", value_parser = clap::value_parser!(u8).range(1..=16))]
+    pub days: u8,
+}
+
+#[derive(Args)]
+pub struct ReportArgs {
+    /// Year to summarize (1940 to this year, which covers the days so far)
+    #[arg(long)]
+    pub year: i32,
+
+    /// Also write the report as an HTML page to this file
+    #[arg(long, value_name = "PATH")]
+    pub html: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct BatchArgs {
+    /// File with one location per line; reads stdin when omitted or `-`
+    #[arg(short, long, value_name = "PATH")]
+    pub input: Option<PathBuf>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value = "csv")]
+    pub format: BatchFormat,
+
+    /// Maximum number of locations fetched at the same time
+    #[arg(long, default_value = "4", value_parser = clap::value_parser!(u16).range(1..=32))]
+    pub concurrency: u16,
+}
+
+#[derive(Args)]
+pub struct DigestArgs {
+    /// Recipient (default: `to` in the `[email]` config section)
+    #[arg(long, value_name = "ADDRESS")]
+    pub email: Option<String>,
+
+    /// Send through this SMTP server (`host` or `host:port`) instead of printing the mail;
+    /// the account and encryption are set in `[email]`
+    #[arg(long, value_name = "SERVER")]
+    pub smtp: Option<String>,
+
+    /// Sender address (default: `from` in `[email]`, the SMTP account if it is an
+    /// address, or the recipient)
+    #[arg(long, value_name = "ADDRESS")]
+    pub from: Option<String>,
+}
+
+#[derive(Args)]
+pub struct SunArgs {
+    /// Sun event to print the time of
+    #[arg(long, value_enum, default_value = "sunrise")]
+    pub next: SunEvent,
+
+    /// Shift the time, e.g. -30m for half an hour before or +1h
+    #[arg(long, value_name = "OFFSET", allow_hyphen_values = true, value_parser = parse_offset)]
+    pub offset: Option<Duration>,
+
+    /// Print seconds since 1970 or an ISO 8601 time with the location's UTC offset
+    #[arg(long, value_enum, default_value = "iso")]
+    pub format: AlarmFormat,
+}
+
+#[derive(Args)]
+pub struct TmuxArgs {
+    /// Refetch the forecast in the background once it is older than this
+    #[arg(long, default_value = "15", value_name = "MINUTES", value_parser = clap::value_parser!(u64).range(1..))]
+    pub max_age: u64,
+
+    /// Fetch the forecast into the cache without printing (used for background refreshes)
+    #[arg(long, hide = true)]
+    pub refresh: bool,
+}
+
+#[derive(Args)]
+pub struct PrefetchArgs {
+    /// Fetch in this process instead of detaching
+    #[arg(long)]
+    pub foreground: bool,
+
+    /// Give up on locations not fetched after this many seconds
+    #[arg(long, default_value = "20", value_name = "SECONDS")]
+    pub timeout: u64,
+}
+
+/// Summarize a year of archive data for the location
+pub async fn report(
+    locations: &dyn LocationSource,
+    climate: &ClimateService,
+    config: &WeatherConfig,
+    args: &ReportArgs,
+) -> Result<()> {
+    let location = resolve_location(locations, config).await?;
+    let today = Utc::now().date_naive();
+    let days = climate.year(&location, args.year, today).await?;
+    let report = year_report(&days, args.year)
+        .ok_or_else(|| anyhow!("No archive data for {} in {}", location.name, args.year))?;
+
+    if let Some(path) = &args.html {
+        std::fs::write(path, report_html(&report, &location.name))?;
+        if !config.json_output {
+            println!("🌐 HTML report saved to {}", path.display());
+        }
+    }
+    if config.json_output {
+        println!("{}", to_json(&report, config.json_compact)?);
+        return Ok(());
+    }
+
+    let lines = report_lines(&report, &location.name);
+    println!("📆 {}", lines[0].bold());
+    for line in &lines[1..] {
+        println!("   {}", line);
+    }
+    Ok(())
+}
+
+/// Returns whether the thresholds were met
+pub async fn check(
+    forecasts: &dyn ForecastSource,
+    locations: &dyn LocationSource,
+    config: &WeatherConfig,
+    args: &CheckArgs,
+) -> Result<bool> {
+    let mut thresholds = Thresholds {
+        rain_above: args.rain_above,
+        temp_above: args.temp_above,
+        temp_below: args.temp_below,
+        wind_above: args.wind_above,
+    };
+    if thresholds.is_empty() {
+        thresholds = Thresholds::from_settings(&config.thresholds, &config.display_units);
+    }
+    if thresholds.is_empty() {
+        return Err(WeatherError::new(
+            ErrorKind::InvalidInput,
+            "No thresholds given (e.g. --rain-above 50 or --temp-below 0, or [thresholds] in the config file)",
+        )
+        .into());
+    }
+
+    let location = resolve_location(locations, config).await?;
+
+    let hourly = forecasts.hourly_forecast(&location).await?;
+    let checks = thresholds.evaluate(
+        &hourly,
+        args.hours as usize,
+        Utc::now(),
+        &config.display_units,
+    )?;
+
+    if !args.quiet {
+        for check in &checks {
+            println!(
+                "{} {} (observed {:.1})",
+                if check.met { "✔" } else { "✘" },
+                check.description,
+                check.observed
+            );
+        }
+    }
+
+    Ok(if args.any {
+        checks.iter().any(|check| check.met)
+    } else {
+        checks.iter().all(|check| check.met)
+    })
+}
+
+/// Print the requested fields of the current weather
+pub async fn query(
+    forecasts: &dyn ForecastSource,
+    locations: &dyn LocationSource,
+    config: &WeatherConfig,
+    fields: &str,
+) -> Result<()> {
+    let fields = parse_fields(fields);
+    if fields.is_empty() {
+        return Err(
+            WeatherError::new(ErrorKind::InvalidInput, "No fields given to --query").into(),
+        );
+    }
+
+    let location = resolve_location(locations, config).await?;
+
+    let weather = forecasts.current_weather(&location).await?;
+    let values = query_current(&weather, &fields)?;
+
+    if config.json_output {
+        println!("{}", format_json(&values, config.json_compact)?);
+    } else {
+        println!("{}", format_lines(&values));
+    }
+
+    Ok(())
+}
+
+/// Print the spoken summary and read it aloud
+pub async fn speak(
+    forecasts: &dyn ForecastSource,
+    locations: &dyn LocationSource,
+    config: &WeatherConfig,
+) -> Result<()> {
+    // Fail before fetching anything if nothing can speak
+    let engine = TtsEngine::detect().ok_or_else(|| {
+        anyhow!("No text-to-speech program found; install espeak-ng or speech-dispatcher")
+    })?;
+    let location = resolve_location(locations, config).await?;
+    let forecast = forecasts.forecast(&location).await?;
+    let text = spoken_summary(&forecast, &location, Utc::now())
+        .ok_or_else(|| anyhow!("No current weather to speak"))?;
+    println!("{}", text);
+    engine.speak(&text)?;
+    Ok(())
+}
+
+/// Print the next sunrise or sunset, shifted by the offset
+pub async fn sun(
+    forecasts: &dyn ForecastSource,
+    locations: &dyn LocationSource,
+    config: &WeatherConfig,
+    args: &SunArgs,
+) -> Result<()> {
+    let location = resolve_location(locations, config).await?;
+    let daily = forecasts.daily_forecast(&location).await?;
+    let offset = args.offset.unwrap_or_else(Duration::zero);
+    let alarm = SunAlarm::next(&daily, args.next, offset, Utc::now())
+        .ok_or_else(|| anyhow!("No {} ahead in the forecast", args.next))?;
+    if config.json_output {
+        println!("{}", to_json(&alarm, config.json_compact)?);
+    } else {
+        println!("{}", alarm.format(args.format, location.clock_timezone()));
+    }
+    Ok(())
+}
+
+/// Compare the forecast with the one seen last time, then remember it
+pub async fn diff(
+    forecasts: &dyn ForecastSource,
+    locations: &dyn LocationSource,
+    snapshots: &ForecastCache,
+    config: &WeatherConfig,
+) -> Result<()> {
+    let location = resolve_location(locations, config).await?;
+    let forecast = forecasts.forecast(&location).await?;
+    let now = Utc::now();
+    let today = convert_to_local(&now, &location.timezone).date_naive();
+
+    let key = ForecastCache::key(config.location.as_deref());
+    let previous = snapshots.load(&key);
+    let changes = previous
+        .as_ref()
+        .map(|previous| forecast_changes(&previous.forecast.daily, &forecast.daily, today))
+        .unwrap_or_default();
+    snapshots.store(
+        &key,
+        &CachedForecast {
+            fetched: now,
+            location: location.clone(),
+            forecast,
+        },
+    )?;
+
+    let since = previous.as_ref().map(|previous| previous.fetched);
+    if config.json_output {
+        let report = serde_json::json!({ "since": since, "changes": changes });
+        println!("{}", to_json(&report, config.json_compact)?);
+        return Ok(());
+    }
+    let Some(since) = since else {
+        println!(
+            "💾 Saved the forecast for {}; run --diff again later to see what changed",
+            location.name
+        );
+        return Ok(());
+    };
+    let since = convert_to_local(&since, location.clock_timezone()).format("%a %H:%M");
+    if changes.is_empty() {
+        println!(
+            "✅ No notable changes for {} since {}",
+            location.name, since
+        );
+    } else {
+        println!("🔄 Forecast changes for {} since {}:", location.name, since);
+        for day in &changes {
+            println!("   • {}", day.summary(today));
+        }
+    }
+    Ok(())
+}
+
+/// Print one line of waybar JSON; errors are shown in the bar too
+///
+/// Returns whether the weather could be shown.
+pub async fn waybar(
+    forecasts: &dyn ForecastSource,
+    locations: &dyn LocationSource,
+    config: &WeatherConfig,
+) -> Result<bool> {
+    let output = async {
+        let location = resolve_location(locations, config).await?;
+        let forecast = forecasts.forecast(&location).await?;
+        waybar_output(&forecast, &location, Utc::now())
+            .ok_or_else(|| anyhow!("No current conditions for {}", location.name))
+    }
+    .await;
+
+    match output {
+        Ok(output) => {
+            println!("{}", serde_json::to_string(&output)?);
+            Ok(true)
+        }
+        Err(e) => {
+            println!(
+                "{}",
+                serde_json::to_string(&waybar_error(&format!("{:#}", e)))?
+            );
+            Ok(false)
+        }
+    }
+}
+
+/// Run this command again in the background with one more argument, without
+/// waiting for it
+fn spawn_detached(arg: &str) -> std::io::Result<()> {
+    process::Command::new(std::env::current_exe()?)
+        .args(std::env::args_os().skip(1))
+        .arg(arg)
+        .stdin(process::Stdio::null())
+        .stdout(process::Stdio::null())
+        .stderr(process::Stdio::null())
+        .spawn()
+        .map(|_| ())
+}
+
+/// Cache the forecasts for the default and favorite locations, printing nothing
+pub async fn prefetch(
+    forecasts: &dyn ForecastSource,
+    locations: &dyn LocationSource,
+    config: &WeatherConfig,
+    favorites: &[String],
+    args: &PrefetchArgs,
+) -> Result<()> {
+    if !args.foreground {
+        spawn_detached("--foreground")?;
+        return Ok(());
+    }
+
+    let mut queries = vec![config.location.clone()];
+    for name in favorites {
+        if !queries.contains(&Some(name.clone())) {
+            queries.push(Some(name.clone()));
+        }
+    }
+    let fetches = queries.iter().map(|query| async move {
+        let location = match query {
+            Some(name) => locations.location_by_name(name).await?,
+            None => locations.location_from_ip().await?,
+        };
+        forecasts.forecast(&location).await
+    });
+    // Locations that fail are simply fetched again on the next run
+    let _ = tokio::time::timeout(
+        std::time::Duration::from_secs(args.timeout),
+        join_all(fetches),
+    )
+    .await;
+    Ok(())
+}
+
+/// Print the tmux line from the cache, refreshing a stale forecast in a
+/// detached process so the status line never waits on the network
+pub async fn tmux(
+    forecasts: &dyn ForecastSource,
+    locations: &dyn LocationSource,
+    cache: Option<&ForecastCache>,
+    log: Option<&UsageLog>,
+    config: &WeatherConfig,
+    args: &TmuxArgs,
+) -> Result<()> {
+    let now = Utc::now();
+    let key = ForecastCache::key(config.location.as_deref());
+    let record = |location: &Location, latency_ms: Option<u64>| {
+        if let Some(log) = log {
+            let _ = log.record(&UsageEvent {
+                time: now,
+                location: location.name.clone(),
+                cached: latency_ms.is_none(),
+                latency_ms,
+            });
+        }
+    };
+
+    let cached = cache.and_then(|cache| cache.load(&key));
+    let cached = match cached {
+        Some(cached) if !args.refresh => {
+            if !cached.is_fresh(now, Duration::minutes(args.max_age as i64)) {
+                let _ = spawn_detached("--refresh");
+            }
+            record(&cached.location, None);
+            cached
+        }
+        _ => {
+            let location = resolve_location(locations, config).await?;
+            let start = std::time::Instant::now();
+            let forecast = forecasts.forecast(&location).await?;
+            record(&location, Some(start.elapsed().as_millis() as u64));
+            let cached = CachedForecast {
+                fetched: now,
+                location,
+                forecast,
+            };
+            if let Some(cache) = cache {
+                cache.store(&key, &cached)?;
+            }
+            cached
+        }
+    };
+
+    if !args.refresh {
+        let location = with_display_timezone(cached.location, config);
+        if let Some(line) = tmux_line(&cached.forecast, &location, now) {
+            println!("{}", line);
+        }
+    }
+    Ok(())
+}
+
+/// Print the morning digest mail, or send it through the SMTP server
+pub async fn digest(
+    forecasts: &dyn ForecastSource,
+    locations: &dyn LocationSource,
+    config: &WeatherConfig,
+    settings: &EmailSettings,
+    args: &DigestArgs,
+) -> Result<()> {
+    let to = args.email.clone().or(settings.to.clone());
+    let smtp = args.smtp.clone().or(settings.smtp.clone());
+    // Check the server and sender before fetching anything
+    let server = match &smtp {
+        Some(smtp) => {
+            let mut server = SmtpServer::parse(smtp, settings.security)?;
+            if let Some(username) = &settings.username {
+                let password = settings.password.as_deref().unwrap_or_default();
+                server = server.with_credentials(username, password);
+            }
+            Some(server)
+        }
+        None => None,
+    };
+    let from = sender_address(
+        args.from.as_deref().or(settings.from.as_deref()),
+        server.as_ref(),
+        to.as_deref(),
+    )?;
+
+    let location = resolve_location(locations, config).await?;
+    let forecast = forecasts.forecast(&location).await?;
+    let now = Utc::now();
+    let digest = Digest::new(
+        &forecast,
+        &location,
+        &config.thresholds,
+        now,
+        config.display_units.language,
+    );
+    if config.json_output {
+        println!("{}", to_json(&digest, config.json_compact)?);
+        return Ok(());
+    }
+
+    let message = compose_email(
+        &from,
+        to.as_deref(),
+        &digest.subject(),
+        &digest.body(&config.display_units),
+        now,
+    );
+    match (server, to) {
+        (Some(server), Some(to)) => {
+            send_email(&server, &from, &to, &message).await?;
+            eprintln!("📧 Sent the digest for {} to {}", location.name, to);
+        }
+        (Some(_), None) => {
+            return Err(WeatherError::new(
+                ErrorKind::InvalidInput,
+                "Give --email, or `to` in the [email] config section, to send the digest",
+            )
+            .into())
+        }
+        // Ready for `| sendmail -t`
+        (None, _) => print!("{}", message),
+    }
+    Ok(())
+}
+
+/// Fetch every location of the batch input and print the rows
+pub async fn batch(
+    forecasts: &dyn ForecastSource,
+    locations: &dyn LocationSource,
+    args: &BatchArgs,
+    compact: bool,
+) -> Result<()> {
+    let input = match args.input.as_deref() {
+        Some(path) if path != Path::new("-") => std::fs::read_to_string(path)
+            .with_context(|| format!("Could not read {}", path.display()))?,
+        _ => std::io::read_to_string(std::io::stdin())?,
+    };
+    let targets = BatchTarget::parse_all(&input);
+    if targets.is_empty() {
+        return Err(
+            WeatherError::new(ErrorKind::InvalidInput, "No locations in the batch input").into(),
+        );
+    }
+
+    let concurrency = args.concurrency as usize;
+    let rows = if args.format == BatchFormat::Ndjson {
+        // Print each location as soon as it arrives instead of after the batch
+        let mut stream = pin!(batch_stream(forecasts, locations, &targets, concurrency));
+        let mut rows = Vec::new();
+        while let Some(row) = stream.next().await {
+            println!("{}", serde_json::to_string(&row)?);
+            rows.push(row);
+        }
+        rows
+    } else {
+        fetch_batch(forecasts, locations, &targets, concurrency).await
+    };
+    match args.format {
+        BatchFormat::Csv => print!("{}", to_csv(&rows)),
+        BatchFormat::Json => println!("{}", to_json(&rows, compact)?),
+        BatchFormat::Ndjson => {}
+    }
+
+    let failed = rows.iter().filter(|row| row.error.is_some()).count();
+    if failed == rows.len() {
+        return Err(anyhow!("No location could be fetched"));
+    }
+    if failed > 0 {
+        eprintln!("⚠️  {} of {} locations failed", failed, rows.len());
+    }
+    Ok(())
+}
+
+/// Write the requested charts, calendar and meteogram files
+pub async fn export(
+    forecasts: &dyn ForecastSource,
+    locations: &dyn LocationSource,
+    climate: &ClimateService,
+    config: &WeatherConfig,
+    args: &ExportArgs,
+) -> Result<()> {
+    let location = resolve_location(locations, config).await?;
+
+    if let Some(out) = &args.out {
+        let now = Utc::now();
+        let hourly: Vec<_> = forecasts
+            .hourly_forecast(&location)
+            .await?
+            .into_iter()
+            .filter(|h| h.timestamp + Duration::hours(1) > now)
+            .collect();
+        // Charts cover the next 48 hours
+        let hours = hourly.len().min(48);
+        export_chart(&hourly[..hours], &location, args.chart, out)?;
+        println!("📈 Chart saved to {}", out.display());
+    }
+
+    if let Some(ics) = &args.ics {
+        let daily = forecasts.forecast_days(&location, args.days).await?.daily;
+        export_ics(&daily, &location, ics)?;
+        println!("📅 Calendar saved to {}", ics.display());
+    }
+
+    if let Some(path) = &args.meteogram {
+        let now = Utc::now();
+        let hourly: Vec<_> = forecasts
+            .hourly_forecast(&location)
+            .await?
+            .into_iter()
+            .filter(|h| h.timestamp + Duration::hours(1) > now)
+            .collect();
+        let mut text = render_meteogram(&hourly, &location, 48).join("\n");
+        text.push('\n');
+        std::fs::write(path, text)?;
+        println!("📊 Meteogram saved to {}", path.display());
+    }
+
+    if let Some(path) = &args.climate {
+        let daily = forecasts.daily_forecast(&location).await?;
+        let normals = monthly_normals(&climate.history(&location).await?);
+        export_climate_chart(&compare_week(&daily, &normals), &location, path)?;
+        println!("📅 Climate chart saved to {}", path.display());
+    }
+
+    Ok(())
+}
+
+/// Post the daily summary and, with `--watch`, keep posting alerts, running
+/// hooks, writing the status file and logging the forecast for `accuracy`
+pub async fn push(
+    forecasts: &dyn ForecastSource,
+    locations: &dyn LocationSource,
+    http: &HttpContext,
+    config: &WeatherConfig,
+    settings: &ConfigFile,
+    accuracy: Option<&AccuracyLog>,
+    args: &PushArgs,
+) -> Result<()> {
+    let format = if args.slack {
+        WebhookFormat::Slack
+    } else if args.discord {
+        WebhookFormat::Discord
+    } else {
+        WebhookFormat::Json
+    };
+    let pusher = args
+        .webhook
+        .as_ref()
+        .map(|url| WebhookPusher::new(http.clone(), url, format));
+    let hooks = Hooks::new(settings.hooks.clone());
+    let reminders = args.reminders.then(|| settings.reminders.clone());
+    let watch_only = hooks.is_empty() && args.write_status.is_none() && reminders.is_none();
+    if pusher.is_none() && !args.ndjson && (watch_only || args.watch.is_none()) {
+        return Err(WeatherError::new(
+            ErrorKind::InvalidInput,
+            "Give --webhook or --ndjson, or --watch with --write-status, --reminders or commands in the [hooks] config section",
+        )
+        .into());
+    }
+    if let Some(template) = &args.status_template {
+        parse_template(template)?;
+    }
+    let engine = TemplateEngine::new();
+
+    let location = resolve_location(locations, config).await?;
+
+    let mut summary_date = None;
+    let mut sent_alerts = HashSet::new();
+    let mut rain_until = None;
+    let mut schedule = ReminderSchedule::new();
+    let mut logged_hour = None;
+    let mut palette = None;
+    let interval = std::time::Duration::from_secs(args.watch.unwrap_or(0) * 60);
+    let mut started = false;
+
+    loop {
+        if started {
+            tokio::time::sleep(interval).await;
+        }
+        let forecast = match forecasts.forecast(&location).await {
+            Ok(forecast) => forecast,
+            Err(e) => {
+                keep_watching(started, e)?;
+                continue;
+            }
+        };
+        let today = convert_to_local(&Utc::now(), &location.timezone).date_naive();
+
+        // Hook failures are reported but never stop the watch loop
+        let fire = |event, data| {
+            let (hooks, location) = (&hooks, &location);
+            async move {
+                if let Err(e) = hooks.fire(event, location, data).await {
+                    eprintln!("⚠️  {:#}", e);
+                }
+            }
+        };
+        fire(HookEvent::Refresh, serde_json::to_value(&forecast)?).await;
+        if args.ndjson {
+            let data = serde_json::json!({
+                "current": forecast.current,
+                "today": forecast.daily.first(),
+            });
+            println!("{}", hook_payload(HookEvent::Refresh, &location, data));
+        }
+        if let Some(current) = &forecast.current {
+            let scene = ScenePalette::from_weather(current);
+            if palette.as_ref() != Some(&scene) {
+                fire(HookEvent::Palette, serde_json::to_value(&scene)?).await;
+                palette = Some(scene);
+            }
+        }
+
+        // Like hooks, a status file that can't be written doesn't stop the loop
+        if let Some(path) = &args.write_status {
+            let now = Utc::now();
+            let contents = match &args.status_template {
+                Some(template) => {
+                    engine.render(template, &mut weather_scope(&forecast, &location, now))
+                }
+                None => status_from_forecast(&forecast, &location, now)
+                    .ok_or_else(|| anyhow!("No current conditions to write"))
+                    .and_then(|status| Ok(serde_json::to_string_pretty(&status)? + "\n")),
+            };
+            if let Err(e) = contents.and_then(|contents| write_status(path, &contents)) {
+                eprintln!("⚠️  {:#}", e);
+            }
+        }
+
+        // Post the summary once per day
+        if let Some(pusher) = pusher.as_ref().filter(|_| summary_date != Some(today)) {
+            match (&forecast.current, forecast.daily.first()) {
+                (Some(current), Some(day)) => {
+                    let summary = narrative(
+                        &forecast,
+                        &location,
+                        Utc::now(),
+                        config.display_units.language,
+                    );
+                    // Posted again at the next interval when this fails
+                    match pusher
+                        .send_summary(
+                            &location,
+                            current,
+                            day,
+                            summary.as_deref(),
+                            &config.display_units,
+                        )
+                        .await
+                    {
+                        Ok(()) => {
+                            notice(
+                                args.ndjson,
+                                &format!("📤 Posted weather summary for {}", location.name),
+                            );
+                            summary_date = Some(today);
+                        }
+                        Err(e) => keep_watching(started, e)?,
+                    }
+                }
+                _ => eprintln!("{}", "No forecast data to post".bright_red()),
+            }
+        }
+
+        if args.watch.is_none() {
+            break;
+        }
+        started = true;
+
+        // Notify about severe weather and crossed thresholds in the next 12
+        // hours, once per event
+        let now = Utc::now();
+        let upcoming: Vec<_> = forecast
+            .hourly
+            .iter()
+            .filter(|h| h.timestamp + Duration::hours(1) > now)
+            .cloned()
+            .collect();
+        let mut alerts =
+            forecast_alerts(&upcoming, &location, 12, now, config.display_units.language);
+        alerts.extend(threshold_alerts(
+            &upcoming,
+            &location,
+            12,
+            &config.thresholds,
+            now,
+            config.display_units.language,
+        ));
+        for alert in alerts {
+            let key = (alert.event.clone(), alert.start);
+            if sent_alerts.contains(&key) {
+                continue;
+            }
+            if let Some(pusher) = &pusher {
+                // Like the summary, a failed alert is posted at the next interval
+                if let Err(e) = pusher.send_alert(&location, &alert).await {
+                    eprintln!("⚠️  {:#}", e);
+                    continue;
+                }
+                notice(
+                    args.ndjson,
+                    &format!("📤 Posted alert: {}", alert.description),
+                );
+            }
+            fire(HookEvent::Alert, serde_json::to_value(&alert)?).await;
+            sent_alerts.insert(key);
+        }
+
+        // Announce each rain event once, shortly before it starts; rain that
+        // starts before the announced one ends is the same event
+        if let Some(rain) = rain_soon(
+            &forecast.hourly,
+            &location,
+            &config.thresholds,
+            now,
+            config.display_units.language,
+        ) {
+            let announced = rain_until.is_some_and(|end| rain.start <= end);
+            rain_until = Some(rain.end);
+            if !announced {
+                fire(HookEvent::RainSoon, serde_json::to_value(&rain)?).await;
+            }
+        }
+
+        // Log the forecast and current conditions once an hour for
+        // `weather_man accuracy`; like hooks, failures don't stop the loop
+        let hour = now.timestamp() / 3600;
+        if let Some(log) = accuracy.filter(|_| logged_hour != Some(hour)) {
+            let entries = accuracy_entries(&forecast, &forecasts.provider(), &location.name, now);
+            match log.record(&entries) {
+                Ok(()) => logged_hour = Some(hour),
+                Err(e) => eprintln!("⚠️  {:#}", e),
+            }
+        }
+
+        // A missing notification daemon is reported but doesn't stop the loop
+        if let Some(settings) = &reminders {
+            for reminder in schedule.due(&forecast.hourly, settings, now) {
+                notice(args.ndjson, &format!("🔔 {}", reminder.message));
+                if let Err(e) = desktop_notify(&reminder.title, &reminder.message).await {
+                    eprintln!("⚠️  {:#}", e);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Report a failed fetch or post of the watch loop and keep going; the first
+/// round still fails, so e.g. a bad token is caught at startup
+fn keep_watching(started: bool, error: anyhow::Error) -> Result<()> {
+    if !started {
+        return Err(error);
+    }
+    eprintln!("⚠️  {:#}", error);
+    Ok(())
+}
+
+/// Print a progress message, to stderr when stdout carries NDJSON
+fn notice(to_stderr: bool, message: &str) {
+    if to_stderr {
+        eprintln!("{}", message);
+    } else {
+        println!("{}", message);
+    }
+}
@@ -0,0 +1,96 @@
+use serde::Serialize;
+
+use crate::modules::types::CurrentWeather;
+
+/// Providers compared when `--providers` is not given: Open-Meteo's own
+/// blend of models against ECMWF alone
+pub const DEFAULT_PROVIDERS: [&str; 2] = ["best_match", "ecmwf_ifs025"];
+
+/// Parse two comma-separated providers: `best_match` or an Open-Meteo model
+pub fn parse_providers(value: &str) -> Result<Vec<String>, String> {
+    let providers: Vec<String> = value
+        .split(',')
+        .map(|provider| provider.trim().to_lowercase())
+        .filter(|provider| !provider.is_empty())
+        .collect();
+    if providers.len() != 2 {
+        return Err("give 2 providers, e.g. best_match,gfs_seamless".to_string());
+    }
+    if let Some(provider) = providers.iter().find(|provider| {
+        !provider
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+    }) {
+        return Err(format!("'{}' is not a provider name", provider));
+    }
+    Ok(providers)
+}
+
+/// Label, unit, value and the difference that is worth highlighting
+type Field = (&'static str, &'static str, fn(&CurrentWeather) -> f64, f64);
+
+/// One value from both providers
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ComparisonRow {
+    pub label: &'static str,
+    pub unit: &'static str,
+    pub values: [f64; 2],
+    /// Second provider minus the first
+    pub delta: f64,
+    /// Whether the difference is large enough to matter
+    pub notable: bool,
+}
+
+/// Current conditions from two providers side by side
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProviderComparison {
+    pub providers: [String; 2],
+    pub conditions: [String; 2],
+    pub rows: Vec<ComparisonRow>,
+}
+
+impl ProviderComparison {
+    pub fn new(providers: [(&str, &CurrentWeather); 2]) -> Self {
+        let [(first, a), (second, b)] = providers;
+        let fields: [Field; 8] = [
+            ("Temperature", "°", |w| w.temperature, 2.0),
+            ("Feels like", "°", |w| w.feels_like, 2.0),
+            ("Humidity", "%", |w| w.humidity.0, 10.0),
+            ("Wind", "m/s", |w| w.wind_speed, 2.0),
+            (
+                "Gusts",
+                "m/s",
+                |w| w.wind_gusts.unwrap_or(w.wind_speed),
+                3.0,
+            ),
+            ("Pressure", "hPa", |w| w.pressure.0, 2.0),
+            ("Clouds", "%", |w| w.clouds.0, 25.0),
+            ("UV index", "", |w| w.uv_index, 1.0),
+        ];
+        let rows = fields
+            .into_iter()
+            .map(|(label, unit, value, threshold)| {
+                let values = [value(a), value(b)];
+                let delta = values[1] - values[0];
+                ComparisonRow {
+                    label,
+                    unit,
+                    values,
+                    delta,
+                    notable: delta.abs() >= threshold,
+                }
+            })
+            .collect();
+
+        Self {
+            providers: [first.to_string(), second.to_string()],
+            conditions: [a.main_condition.to_string(), b.main_condition.to_string()],
+            rows,
+        }
+    }
+
+    /// Whether the providers disagree on the sky or any value notably
+    pub fn disagree(&self) -> bool {
+        self.conditions[0] != self.conditions[1] || self.rows.iter().any(|row| row.notable)
+    }
+}
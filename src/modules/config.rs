@@ -0,0 +1,412 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::modules::comfort::FeelsLike;
+use crate::modules::icons::IconSet;
+use crate::modules::mail::SmtpSecurity;
+use crate::modules::sections::Section;
+
+/// Settings read from the user's `config.toml`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfigFile {
+    pub general: GeneralSettings,
+    pub tui: TuiSettings,
+    pub keybindings: KeybindingSettings,
+    pub warnings: WarningSettings,
+    pub freshness: FreshnessSettings,
+    pub thresholds: ThresholdSettings,
+    pub favorites: FavoriteSettings,
+    pub energy: EnergySettings,
+    pub api: ApiSettings,
+    pub hooks: HookSettings,
+    pub reminders: ReminderSettings,
+    pub templates: TemplateSettings,
+    pub email: EmailSettings,
+    pub events: EventSettings,
+}
+
+/// Defaults from the `[general]` section, written by the setup wizard
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GeneralSettings {
+    /// Location used when `--location` is not given
+    pub location: Option<String>,
+    /// Units used when `--units` is not given
+    pub units: Option<String>,
+    pub theme: Theme,
+    /// Whether the location may be detected from the IP address
+    pub auto_location: bool,
+    /// Feels-like formula used when `--feels-like` is not given
+    pub feels_like: Option<FeelsLike>,
+    /// Icon set used when `--icons` is not given
+    pub icons: Option<IconSet>,
+    /// Report sections printed when `--sections` is not given (all when empty)
+    pub sections: Vec<Section>,
+    /// Report sections hidden when `--hide` is not given
+    pub hide: Vec<Section>,
+}
+
+impl Default for GeneralSettings {
+    fn default() -> Self {
+        Self {
+            location: None,
+            units: None,
+            theme: Theme::default(),
+            auto_location: true,
+            feels_like: None,
+            icons: None,
+            sections: Vec::new(),
+            hide: Vec::new(),
+        }
+    }
+}
+
+/// Color scheme of the terminal output
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    /// Neon colors
+    #[default]
+    Cyberpunk,
+    /// No colors, for light terminals and screen readers
+    Monochrome,
+}
+
+/// TUI-specific settings from the `[tui]` section
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TuiSettings {
+    pub layout: TuiLayout,
+}
+
+/// How the TUI arranges its content area
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TuiLayout {
+    /// One view at a time, switched with the tab bar
+    #[default]
+    Tabs,
+    /// Scene, hourly chart and 7-day summary side by side
+    Dashboard,
+}
+
+/// Key remappings from the `[keybindings]` section, e.g. `next_day = "n"`
+///
+/// Keys are single characters or names such as `Left`, `Tab` or `F1`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct KeybindingSettings {
+    pub quit: Option<String>,
+    pub next_tab: Option<String>,
+    pub prev_tab: Option<String>,
+    pub next_day: Option<String>,
+    pub prev_day: Option<String>,
+    pub dashboard: Option<String>,
+    pub help: Option<String>,
+    pub screenshot: Option<String>,
+    pub heatmap_metric: Option<String>,
+    pub search: Option<String>,
+    pub next_match: Option<String>,
+    pub redetect: Option<String>,
+}
+
+/// Multi-day frost and heatwave thresholds from the `[warnings]` section
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WarningSettings {
+    /// Nightly minimum (°C) counted as frost
+    pub frost_below: f64,
+    /// Consecutive frost nights needed for a warning
+    pub frost_nights: usize,
+    /// Daily maximum (°C) counted as a heatwave day
+    pub heat_above: f64,
+    /// Consecutive hot days needed for a warning
+    pub heat_days: usize,
+}
+
+impl Default for WarningSettings {
+    fn default() -> Self {
+        Self {
+            frost_below: 0.0,
+            frost_nights: 2,
+            heat_above: 30.0,
+            heat_days: 3,
+        }
+    }
+}
+
+/// Age limits for cached data from the `[freshness]` section, in minutes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FreshnessSettings {
+    /// Data older than this is shown with a yellow warning
+    pub warn_after: i64,
+    /// Data older than this is shown with a red warning
+    pub stale_after: i64,
+}
+
+impl Default for FreshnessSettings {
+    fn default() -> Self {
+        Self {
+            warn_after: 30,
+            stale_after: 120,
+        }
+    }
+}
+
+/// Rooftop PV and small wind turbine from the `[energy]` section
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EnergySettings {
+    /// Peak power of the PV system (kWp)
+    pub pv_kwp: f64,
+    /// Panel tilt from horizontal (degrees)
+    pub tilt: f64,
+    /// Direction the panels face, clockwise from north (180 = south)
+    pub azimuth: f64,
+    /// Share of the rated output left after inverter, wiring and soiling losses
+    pub performance_ratio: f64,
+    /// Rated power of the wind turbine (kW)
+    pub turbine_kw: f64,
+    /// Turbine hub height above ground (m)
+    pub hub_height: f64,
+}
+
+impl Default for EnergySettings {
+    fn default() -> Self {
+        Self {
+            pv_kwp: 5.0,
+            tilt: 30.0,
+            azimuth: 180.0,
+            performance_ratio: 0.8,
+            turbine_kw: 1.0,
+            hub_height: 12.0,
+        }
+    }
+}
+
+/// API hosts from the `[api]` section, for self-hosted instances and mirrors
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ApiSettings {
+    /// Open-Meteo base URL, e.g. `http://localhost:8080/v1`
+    pub openmeteo_url: Option<String>,
+    /// Nominatim base URL used for geocoding
+    pub nominatim_url: Option<String>,
+}
+
+impl ApiSettings {
+    /// Override the configured hosts with `WEATHER_MAN_OPENMETEO_URL` and
+    /// `WEATHER_MAN_NOMINATIM_URL` when they are set
+    pub fn with_env(mut self) -> Self {
+        let var = |name| std::env::var(name).ok().filter(|v: &String| !v.is_empty());
+        if let Some(url) = var("WEATHER_MAN_OPENMETEO_URL") {
+            self.openmeteo_url = Some(url);
+        }
+        if let Some(url) = var("WEATHER_MAN_NOMINATIM_URL") {
+            self.nominatim_url = Some(url);
+        }
+        self
+    }
+}
+
+/// Shell commands from the `[hooks]` section, run with the event's JSON on stdin
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HookSettings {
+    /// Run after every forecast refresh
+    pub on_refresh: Option<String>,
+    /// Run once per rain event, shortly before it starts
+    pub on_rain_soon: Option<String>,
+    /// Run once per severe weather or threshold alert
+    pub on_alert: Option<String>,
+    /// Run when the scene colors of the weather change (see `weather_man palette`)
+    pub on_palette: Option<String>,
+}
+
+/// Reminders given by `push --watch --reminders` from the `[reminders]` section
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ReminderSettings {
+    /// UV index above which to remind about sunscreen
+    pub sunscreen_uv: f64,
+    /// Minutes between sunscreen reminders
+    pub sunscreen_every_minutes: u32,
+    /// Temperature (°C) above which to remind about drinking water
+    pub hydration_above: f64,
+    /// Minutes between hydration reminders
+    pub hydration_every_minutes: u32,
+}
+
+impl Default for ReminderSettings {
+    fn default() -> Self {
+        Self {
+            sunscreen_uv: 6.0,
+            sunscreen_every_minutes: 120,
+            hydration_above: 30.0,
+            hydration_every_minutes: 60,
+        }
+    }
+}
+
+/// Mail server for `weather_man digest` from the `[email]` section
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EmailSettings {
+    /// `host` or `host:port`, used when `--smtp` is not given
+    pub smtp: Option<String>,
+    pub security: SmtpSecurity,
+    pub username: Option<String>,
+    /// Better left out and set in `WEATHER_MAN_SMTP_PASSWORD`
+    pub password: Option<String>,
+    /// Sender address, used when `--from` is not given
+    pub from: Option<String>,
+    /// Recipient, used when `--email` is not given
+    pub to: Option<String>,
+}
+
+impl EmailSettings {
+    /// Take the password from `WEATHER_MAN_SMTP_PASSWORD` when it is set
+    pub fn with_env(mut self) -> Self {
+        if let Some(password) = std::env::var("WEATHER_MAN_SMTP_PASSWORD")
+            .ok()
+            .filter(|v| !v.is_empty())
+        {
+            self.password = Some(password);
+        }
+        self
+    }
+}
+
+/// Calendar checked by `--mode events` from the `[events]` section
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EventSettings {
+    /// iCalendar file or URL, used when `--calendar` is not given
+    pub calendar: Option<String>,
+    /// Words that mark an event as outdoors (default: picnic, hike, bbq, ...)
+    pub keywords: Vec<String>,
+}
+
+/// Custom output from the `[templates]` section, for `--mode template`
+///
+/// Expressions are Rhai, e.g. `"${temp}°, bike: ${wind < 8 && pop < 0.3}"`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TemplateSettings {
+    /// Output line with `${...}` expressions
+    pub output: Option<String>,
+    /// Alert names and the conditions that trigger them
+    pub alerts: BTreeMap<String, String>,
+}
+
+/// Personal alert thresholds from the `[thresholds]` section
+///
+/// Unset values fall back to the built-in defaults for recommendations and
+/// notifications, and are not checked by `weather_man check`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThresholdSettings {
+    /// Wind speed (m/s) worth a warning
+    pub wind_warning: Option<f64>,
+    /// UV index worth a sun protection warning
+    pub uv_warning: Option<f64>,
+    /// UV index from which sunscreen is recommended
+    pub uv_protect: Option<f64>,
+    /// Precipitation probability (0-1) worth a rain notification
+    pub rain_notify_pop: Option<f64>,
+}
+
+impl ThresholdSettings {
+    /// Wind warning threshold, Beaufort 6 (strong breeze) by default
+    pub fn wind_warning(&self) -> f64 {
+        self.wind_warning.unwrap_or(10.8)
+    }
+
+    /// UV warning threshold, the start of the "High" band by default
+    pub fn uv_warning(&self) -> f64 {
+        self.uv_warning.unwrap_or(6.0)
+    }
+
+    /// Sunscreen threshold, the start of the "Moderate" band by default
+    pub fn uv_protect(&self) -> f64 {
+        self.uv_protect.unwrap_or(3.0)
+    }
+
+    /// Rain notification threshold, a 50 % chance by default
+    pub fn rain_notify_pop(&self) -> f64 {
+        self.rain_notify_pop.unwrap_or(0.5)
+    }
+}
+
+/// Favorite locations from the `[favorites]` section
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FavoriteSettings {
+    pub locations: Vec<String>,
+}
+
+impl FavoriteSettings {
+    /// Add a location; returns false if it is already a favorite
+    pub fn add(&mut self, name: &str) -> bool {
+        let name = name.trim();
+        if name.is_empty() || self.position(name).is_some() {
+            return false;
+        }
+        self.locations.push(name.to_string());
+        true
+    }
+
+    /// Remove a location; returns false if it was not a favorite
+    pub fn remove(&mut self, name: &str) -> bool {
+        match self.position(name.trim()) {
+            Some(index) => {
+                self.locations.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.locations
+            .iter()
+            .position(|location| location.eq_ignore_ascii_case(name))
+    }
+}
+
+impl ConfigFile {
+    /// Load the config file, falling back to defaults if there is none
+    pub fn load(path: Option<&Path>) -> Result<Self> {
+        match path {
+            Some(path) if path.exists() => Self::load_from(path),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    /// Load and parse the config file at the given path
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Could not read config file {}", path.display()))?;
+        Self::parse(&contents).with_context(|| format!("Invalid config file {}", path.display()))
+    }
+
+    /// Write the config file, creating its directory if needed
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Could not create {}", dir.display()))?;
+        }
+        fs::write(path, toml::to_string_pretty(self)?)
+            .with_context(|| format!("Could not write config file {}", path.display()))
+    }
+
+    /// Parse config file contents
+    pub fn parse(contents: &str) -> Result<Self> {
+        Ok(toml::from_str(contents)?)
+    }
+}
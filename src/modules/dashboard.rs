@@ -0,0 +1,407 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use futures::future::join_all;
+use ratatui::{
+    backend::{Backend, CrosstermBackend},
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame, Terminal,
+};
+use serde::Serialize;
+use std::io;
+use std::time::{Duration, Instant};
+
+use crate::modules::runner::{ForecastSource, LocationSource};
+use crate::modules::tui::{install_panic_hook, is_interrupt, Action, KeyBindings};
+use crate::modules::types::{CurrentWeather, Location};
+use crate::modules::ui::convert_to_local;
+use crate::modules::utils::{compass_point, DisplayUnits};
+
+/// Seconds each location stays on screen in cycle view
+const CYCLE_SECS: u64 = 8;
+/// Minutes between automatic refreshes
+const REFRESH_MINUTES: u64 = 10;
+
+/// Current conditions for one favorite location, or why they are missing
+#[derive(Debug, Clone, Serialize)]
+pub struct LocationCard {
+    pub name: String,
+    pub location: Option<Location>,
+    pub weather: Option<CurrentWeather>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Fetch current conditions for all favorites concurrently
+///
+/// Locations already resolved in `previous` are reused, so a refresh only
+/// fetches the weather.
+pub async fn fetch_cards(
+    forecaster: &dyn ForecastSource,
+    locations: &dyn LocationSource,
+    names: &[String],
+    previous: &[LocationCard],
+) -> Vec<LocationCard> {
+    let fetches = names.iter().map(|name| async move {
+        let known = previous
+            .iter()
+            .find(|card| &card.name == name)
+            .and_then(|card| card.location.clone());
+        let location = match known {
+            Some(location) => location,
+            None => match locations.location_by_name(name).await {
+                Ok(location) => location,
+                Err(e) => {
+                    return LocationCard {
+                        name: name.clone(),
+                        location: None,
+                        weather: None,
+                        error: Some(format!("{:#}", e)),
+                    }
+                }
+            },
+        };
+        let (weather, error) = match forecaster.current_weather(&location).await {
+            Ok(weather) => (Some(weather), None),
+            Err(e) => (None, Some(format!("{:#}", e))),
+        };
+        LocationCard {
+            name: name.clone(),
+            location: Some(location),
+            weather,
+            error,
+        }
+    });
+    join_all(fetches).await
+}
+
+/// How the dashboard shows the favorites
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DashboardView {
+    /// All locations side by side
+    Tiles,
+    /// One location at a time, advancing automatically
+    Cycle,
+}
+
+/// Everything the dashboard draws
+pub struct DashboardState {
+    pub cards: Vec<LocationCard>,
+    pub view: DashboardView,
+    pub selected: usize,
+    pub units: DisplayUnits,
+    pub updated: DateTime<Utc>,
+}
+
+impl DashboardState {
+    pub fn new(cards: Vec<LocationCard>, units: DisplayUnits) -> Self {
+        Self {
+            cards,
+            view: DashboardView::Tiles,
+            selected: 0,
+            units,
+            updated: Utc::now(),
+        }
+    }
+
+    fn next(&mut self) {
+        if !self.cards.is_empty() {
+            self.selected = (self.selected + 1) % self.cards.len();
+        }
+    }
+
+    fn prev(&mut self) {
+        if !self.cards.is_empty() {
+            self.selected = (self.selected + self.cards.len() - 1) % self.cards.len();
+        }
+    }
+}
+
+/// Run the favorites dashboard until the user quits, refreshing every
+/// `REFRESH_MINUTES` or on `r`
+pub async fn run_dashboard(
+    forecaster: &dyn ForecastSource,
+    locations: &dyn LocationSource,
+    names: &[String],
+    cards: Vec<LocationCard>,
+    units: DisplayUnits,
+    keybindings: KeyBindings,
+) -> Result<()> {
+    let mut state = DashboardState::new(cards, units);
+
+    install_panic_hook();
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+
+    let mut last_refresh = Instant::now();
+    let mut last_advance = Instant::now();
+    let result: Result<()> = async {
+        loop {
+            terminal.draw(|f| draw_dashboard(f, &state))?;
+
+            let mut refresh = last_refresh.elapsed() >= Duration::from_secs(REFRESH_MINUTES * 60);
+            if event::poll(Duration::from_millis(250))? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind != KeyEventKind::Press {
+                        continue;
+                    }
+                    if is_interrupt(&key) {
+                        break;
+                    }
+                    if key.modifiers.contains(KeyModifiers::CONTROL) {
+                        continue;
+                    }
+                    match key.code {
+                        KeyCode::Esc => break,
+                        KeyCode::Char('t') => {
+                            state.view = match state.view {
+                                DashboardView::Tiles => DashboardView::Cycle,
+                                DashboardView::Cycle => DashboardView::Tiles,
+                            };
+                            last_advance = Instant::now();
+                        }
+                        KeyCode::Char('r') => refresh = true,
+                        code => match keybindings.action_for(code) {
+                            Some(Action::Quit) => break,
+                            Some(Action::NextTab) => {
+                                state.next();
+                                last_advance = Instant::now();
+                            }
+                            Some(Action::PrevTab) => {
+                                state.prev();
+                                last_advance = Instant::now();
+                            }
+                            _ => {}
+                        },
+                    }
+                }
+            }
+
+            if state.view == DashboardView::Cycle
+                && last_advance.elapsed() >= Duration::from_secs(CYCLE_SECS)
+            {
+                state.next();
+                last_advance = Instant::now();
+            }
+
+            if refresh {
+                state.cards = fetch_cards(forecaster, locations, names, &state.cards).await;
+                state.updated = Utc::now();
+                last_refresh = Instant::now();
+            }
+        }
+        Ok(())
+    }
+    .await;
+
+    // Restore terminal
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+
+    result
+}
+
+/// Draw the title bar, the location cards and the key help
+pub fn draw_dashboard<B: Backend>(f: &mut Frame<B>, state: &DashboardState) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(3),
+        ])
+        .split(f.size());
+
+    let view = match state.view {
+        DashboardView::Tiles => "tiles".to_string(),
+        DashboardView::Cycle => format!("{}/{}", state.selected + 1, state.cards.len()),
+    };
+    let title = Paragraph::new(Line::from(vec![
+        Span::styled(
+            "Weather Man - Favorites",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+        Span::styled(format!("[{}]", view), Style::default().fg(Color::Yellow)),
+        Span::raw(" "),
+        Span::styled(
+            format!("updated {} UTC", state.updated.format("%H:%M")),
+            Style::default().fg(Color::Gray),
+        ),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(title, chunks[0]);
+
+    match state.view {
+        DashboardView::Tiles => {
+            for (index, area) in tile_areas(chunks[1], state.cards.len())
+                .into_iter()
+                .enumerate()
+            {
+                render_card(f, state, index, area, false);
+            }
+        }
+        DashboardView::Cycle => {
+            if !state.cards.is_empty() {
+                render_card(f, state, state.selected, chunks[1], true);
+            }
+        }
+    }
+
+    let help = Paragraph::new("t: tiles/cycle | ←/→: previous/next | r: refresh | q/Esc: quit")
+        .style(Style::default().fg(Color::Gray))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .style(Style::default().fg(Color::Cyan)),
+        );
+    f.render_widget(help, chunks[2]);
+}
+
+/// Split `area` into a near-square grid with room for `count` cards
+fn tile_areas(area: Rect, count: usize) -> Vec<Rect> {
+    if count == 0 {
+        return Vec::new();
+    }
+    let columns = (count as f64).sqrt().ceil() as usize;
+    let rows = count.div_ceil(columns);
+
+    let row_areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Ratio(1, rows as u32); rows])
+        .split(area);
+    row_areas
+        .iter()
+        .flat_map(|row| {
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(vec![Constraint::Ratio(1, columns as u32); columns])
+                .split(*row)
+                .to_vec()
+        })
+        .take(count)
+        .collect()
+}
+
+/// Render one location card; the detailed card adds pressure, clouds, UV and sun times
+fn render_card<B: Backend>(
+    f: &mut Frame<B>,
+    state: &DashboardState,
+    index: usize,
+    area: Rect,
+    detailed: bool,
+) {
+    let card = &state.cards[index];
+    let border = if index == state.selected && state.view == DashboardView::Tiles {
+        Color::Yellow
+    } else {
+        Color::Cyan
+    };
+    let title = card
+        .location
+        .as_ref()
+        .map_or(card.name.clone(), |location| {
+            format!("{}, {}", location.name, location.country_code)
+        });
+
+    let lines = match (&card.weather, &card.location) {
+        (Some(weather), Some(location)) => card_lines(weather, location, state.units, detailed),
+        _ => vec![Line::from(Span::styled(
+            format!("⚠ {}", card.error.as_deref().unwrap_or("No data")),
+            Style::default().fg(Color::Red),
+        ))],
+    };
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .style(Style::default().fg(border)),
+    );
+    f.render_widget(paragraph, area);
+}
+
+fn card_lines(
+    weather: &CurrentWeather,
+    location: &Location,
+    units: DisplayUnits,
+    detailed: bool,
+) -> Vec<Line<'static>> {
+    let local = |time: &DateTime<Utc>| {
+        convert_to_local(time, location.clock_timezone())
+            .format("%H:%M")
+            .to_string()
+    };
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(
+                units.temperature.format(weather.temperature),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(" feels {}", units.temperature.format(weather.feels_like)),
+                Style::default().fg(Color::Gray),
+            ),
+        ]),
+        Line::from(format!(
+            "{} {}",
+            weather.main_condition.get_emoji(),
+            weather.main_condition
+        )),
+        Line::from(vec![
+            Span::styled(
+                format!("💧 {:.0}", weather.humidity),
+                Style::default().fg(Color::Blue),
+            ),
+            Span::raw(format!(
+                "  💨 {} {}",
+                units.wind.format(weather.wind_speed),
+                compass_point(weather.wind_direction)
+            )),
+        ]),
+        Line::from(Span::styled(
+            format!("🕒 {}", local(&Utc::now())),
+            Style::default().fg(Color::Gray),
+        )),
+    ];
+
+    if detailed {
+        lines.extend([
+            Line::from(""),
+            Line::from(format!(
+                "Pressure {}  Clouds {:.0}",
+                units.pressure.format(weather.pressure.0),
+                weather.clouds
+            )),
+            Line::from(format!("UV index {:.1}", weather.uv_index)),
+            Line::from(format!(
+                "🌅 {}  🌇 {}",
+                local(&weather.sunrise),
+                local(&weather.sunset)
+            )),
+        ]);
+    }
+
+    lines
+}
@@ -0,0 +1,92 @@
+use chrono::{Duration, NaiveDate};
+use serde::Serialize;
+
+use crate::modules::types::DailyForecast;
+
+/// Smallest change in the rain chance (0-1) worth mentioning
+pub const RAIN_CHANCE_STEP: f64 = 0.15;
+/// Smallest change in a high or low (°) worth mentioning
+pub const TEMPERATURE_STEP: f64 = 2.0;
+
+/// What changed in the forecast for one day
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DayChanges {
+    pub date: NaiveDate,
+    /// e.g. "rain chance rose from 20% to 70%", "high revised down 3°"
+    pub changes: Vec<String>,
+}
+
+impl DayChanges {
+    /// One sentence for the day, e.g. "Saturday's rain chance rose from 20% to
+    /// 70%, high revised down 3°"
+    pub fn summary(&self, today: NaiveDate) -> String {
+        let day = if self.date == today {
+            "Today".to_string()
+        } else if self.date == today + Duration::days(1) {
+            "Tomorrow".to_string()
+        } else {
+            self.date.format("%A").to_string()
+        };
+        format!("{}'s {}", day, self.changes.join(", "))
+    }
+}
+
+fn temperature_change(name: &str, before: f64, after: f64) -> Option<String> {
+    let difference = after.round() - before.round();
+    if difference.abs() < TEMPERATURE_STEP {
+        return None;
+    }
+    let direction = if difference > 0.0 { "up" } else { "down" };
+    Some(format!(
+        "{} revised {} {:.0}°",
+        name,
+        direction,
+        difference.abs()
+    ))
+}
+
+/// Notable changes between two daily forecasts, for the days from `today`
+/// that both of them cover
+pub fn forecast_changes(
+    previous: &[DailyForecast],
+    current: &[DailyForecast],
+    today: NaiveDate,
+) -> Vec<DayChanges> {
+    let date = |day: &DailyForecast| day.date.date_naive();
+
+    current
+        .iter()
+        .filter(|day| date(day) >= today)
+        .filter_map(|after| {
+            let before = previous.iter().find(|day| date(day) == date(after))?;
+            let mut changes = Vec::new();
+
+            if (after.pop - before.pop).abs() >= RAIN_CHANCE_STEP {
+                changes.push(format!(
+                    "rain chance {} from {:.0}% to {:.0}%",
+                    if after.pop > before.pop {
+                        "rose"
+                    } else {
+                        "fell"
+                    },
+                    before.pop * 100.0,
+                    after.pop * 100.0
+                ));
+            }
+            if after.main_condition != before.main_condition {
+                changes.push(format!(
+                    "outlook changed from {} to {}",
+                    before.main_condition.to_string().to_lowercase(),
+                    after.main_condition.to_string().to_lowercase()
+                ));
+            }
+            changes.extend(temperature_change("high", before.temp_max, after.temp_max));
+            changes.extend(temperature_change("low", before.temp_min, after.temp_min));
+
+            (!changes.is_empty()).then(|| DayChanges {
+                date: date(after),
+                changes,
+            })
+        })
+        .collect()
+}
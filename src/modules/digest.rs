@@ -0,0 +1,183 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::Serialize;
+
+use crate::modules::activity::{best_window, hourly_scores, BestWindow};
+use crate::modules::config::ThresholdSettings;
+use crate::modules::push::{forecast_alerts, threshold_alerts};
+use crate::modules::summary::narrative;
+use crate::modules::types::{DailyForecast, Forecast, Location, WeatherAlert};
+use crate::modules::ui::convert_to_local;
+use crate::modules::utils::{relative_time, DisplayUnits, Language};
+
+/// Hours ahead checked for alerts
+pub const DIGEST_ALERT_HOURS: usize = 24;
+/// Length of the best outdoor window suggested for today
+pub const DIGEST_WINDOW_HOURS: usize = 2;
+
+/// A morning summary of today and tomorrow, for `weather_man digest`
+#[derive(Debug, Clone, Serialize)]
+pub struct Digest {
+    pub location: String,
+    pub timezone: String,
+    /// Zone the times are written in, when not the place's own (`--tz`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_timezone: Option<String>,
+    pub date: NaiveDate,
+    /// When the digest was put together, for "in 3 hours" style times
+    pub generated_at: DateTime<Utc>,
+    /// What the next hours look like, in a sentence or two
+    pub summary: Option<String>,
+    pub today: Option<DailyForecast>,
+    pub tomorrow: Option<DailyForecast>,
+    pub alerts: Vec<WeatherAlert>,
+    /// Best hours for being outside for the rest of today
+    pub best_window: Option<BestWindow>,
+}
+
+impl Digest {
+    pub fn new(
+        forecast: &Forecast,
+        location: &Location,
+        thresholds: &ThresholdSettings,
+        now: DateTime<Utc>,
+        language: Language,
+    ) -> Self {
+        let date = convert_to_local(&now, &location.timezone).date_naive();
+        let local_date =
+            |time: &DateTime<Utc>| convert_to_local(time, &location.timezone).date_naive();
+        let day = |date: NaiveDate| {
+            forecast
+                .daily
+                .iter()
+                .find(|day| day.date.date_naive() == date)
+                .cloned()
+        };
+
+        let upcoming: Vec<_> = forecast
+            .hourly
+            .iter()
+            .filter(|h| h.timestamp + Duration::hours(1) > now)
+            .cloned()
+            .collect();
+        let mut alerts = forecast_alerts(&upcoming, location, DIGEST_ALERT_HOURS, now, language);
+        alerts.extend(threshold_alerts(
+            &upcoming,
+            location,
+            DIGEST_ALERT_HOURS,
+            thresholds,
+            now,
+            language,
+        ));
+        alerts.sort_by_key(|alert| alert.start);
+
+        let scores: Vec<_> = hourly_scores(&upcoming, &forecast.daily, location)
+            .into_iter()
+            .filter(|score| local_date(&score.timestamp) == date)
+            .collect();
+
+        Self {
+            location: location.name.clone(),
+            timezone: location.timezone.clone(),
+            display_timezone: location.display_timezone.clone(),
+            date,
+            generated_at: now,
+            summary: narrative(forecast, location, now, language),
+            today: day(date),
+            tomorrow: date.succ_opt().and_then(day),
+            alerts,
+            best_window: best_window(&scores, DIGEST_WINDOW_HOURS.min(scores.len())),
+        }
+    }
+
+    /// Mail subject, e.g. "☀️ Berlin, Thu 16 Oct: 9° to 18°"
+    pub fn subject(&self) -> String {
+        let mut subject = match &self.today {
+            Some(day) => format!(
+                "{} {}, {}: {:.0}° to {:.0}°",
+                day.main_condition.get_emoji(),
+                self.location,
+                self.date.format("%a %-d %b"),
+                day.temp_min,
+                day.temp_max
+            ),
+            None => format!("{}, {}", self.location, self.date.format("%a %-d %b")),
+        };
+        match self.alerts.len() {
+            0 => {}
+            1 => subject.push_str(" ⚠️ 1 alert"),
+            n => subject.push_str(&format!(" ⚠️ {} alerts", n)),
+        }
+        subject
+    }
+
+    /// Plain-text mail body
+    pub fn body(&self, units: &DisplayUnits) -> String {
+        let zone = self.display_timezone.as_deref().unwrap_or(&self.timezone);
+        let time = |time: &DateTime<Utc>| units.clock.format(convert_to_local(time, zone));
+        let mut lines = vec![format!("Good morning! The weather for {}:", self.location)];
+
+        if let Some(summary) = &self.summary {
+            lines.push(String::new());
+            lines.push(summary.clone());
+        }
+
+        lines.push(String::new());
+        for (label, day) in [("Today", &self.today), ("Tomorrow", &self.tomorrow)] {
+            if let Some(day) = day {
+                lines.push(format!(
+                    "{}: {} {}, {:.0}° to {:.0}°, {:.0}% chance of precipitation, wind {}",
+                    label,
+                    day.main_condition.get_emoji(),
+                    day.main_condition,
+                    day.temp_min,
+                    day.temp_max,
+                    day.pop * 100.0,
+                    units.wind.format(day.wind_speed)
+                ));
+            }
+        }
+
+        lines.push(String::new());
+        if self.alerts.is_empty() {
+            lines.push(format!(
+                "No alerts for the next {} hours.",
+                DIGEST_ALERT_HOURS
+            ));
+        } else {
+            lines.push("Alerts:".to_string());
+            for alert in &self.alerts {
+                lines.push(format!(
+                    "- {} {}–{} ({})",
+                    alert.event,
+                    time(&alert.start),
+                    time(&alert.end),
+                    relative_time(alert.start, self.generated_at, zone, units.language)
+                ));
+            }
+        }
+
+        if let Some(window) = &self.best_window {
+            lines.push(String::new());
+            lines.push(format!(
+                "Best time to be outside: {}–{} (score {:.0}/100)",
+                time(&window.start),
+                time(&window.end),
+                window.average_score
+            ));
+        }
+
+        if let Some(day) = &self.today {
+            lines.push(String::new());
+            lines.push(format!(
+                "Sunrise {}, sunset {}",
+                time(&day.sunrise),
+                time(&day.sunset)
+            ));
+        }
+
+        lines.push(String::new());
+        lines.push("-- ".to_string());
+        lines.push("weather_man".to_string());
+        lines.join("\n") + "\n"
+    }
+}
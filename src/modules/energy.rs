@@ -0,0 +1,177 @@
+use chrono::{DateTime, Datelike, Duration, Timelike, Utc};
+use serde::Serialize;
+
+use crate::modules::config::EnergySettings;
+use crate::modules::types::{HourlyForecast, Location};
+
+/// Hours of forecast covered by the energy mode
+pub const ENERGY_HOURS: usize = 48;
+/// Ground reflectance for the light reaching tilted panels
+const ALBEDO: f64 = 0.2;
+/// PV power lost per °C of cell temperature above 25 °C
+const PV_TEMPERATURE_COEFFICIENT: f64 = 0.004;
+/// Small-turbine power curve: cut-in, rated and cut-out speeds (m/s)
+const CUT_IN: f64 = 3.0;
+const RATED_SPEED: f64 = 11.0;
+const CUT_OUT: f64 = 25.0;
+
+/// Estimated production for one hour
+#[derive(Debug, Clone, Serialize)]
+pub struct EnergyHour {
+    pub timestamp: DateTime<Utc>,
+    /// Irradiance on the panel plane (W/m²)
+    pub irradiance: f64,
+    pub pv_kw: f64,
+    /// Wind speed at hub height (m/s)
+    pub hub_wind_speed: f64,
+    pub wind_kw: f64,
+}
+
+/// Hourly production estimates with totals
+#[derive(Debug, Clone, Serialize)]
+pub struct EnergyReport {
+    pub hours: Vec<EnergyHour>,
+    pub pv_kwh: f64,
+    pub wind_kwh: f64,
+}
+
+/// Solar zenith and azimuth (degrees, azimuth clockwise from north) at a time
+///
+/// Uses the NOAA approximation, accurate to a fraction of a degree.
+pub fn solar_position(time: DateTime<Utc>, latitude: f64, longitude: f64) -> (f64, f64) {
+    let day = time.ordinal0() as f64;
+    let hour = time.hour() as f64 + time.minute() as f64 / 60.0;
+    let gamma = 2.0 * std::f64::consts::PI / 365.0 * (day + (hour - 12.0) / 24.0);
+
+    let equation_of_time = 229.18
+        * (0.000075 + 0.001868 * gamma.cos()
+            - 0.032077 * gamma.sin()
+            - 0.014615 * (2.0 * gamma).cos()
+            - 0.040849 * (2.0 * gamma).sin());
+    let declination = 0.006918 - 0.399912 * gamma.cos() + 0.070257 * gamma.sin()
+        - 0.006758 * (2.0 * gamma).cos()
+        + 0.000907 * (2.0 * gamma).sin()
+        - 0.002697 * (3.0 * gamma).cos()
+        + 0.00148 * (3.0 * gamma).sin();
+
+    let solar_minutes = hour * 60.0 + equation_of_time + 4.0 * longitude;
+    let hour_angle = (solar_minutes / 4.0 - 180.0).to_radians();
+    let latitude = latitude.to_radians();
+
+    let cos_zenith = (latitude.sin() * declination.sin()
+        + latitude.cos() * declination.cos() * hour_angle.cos())
+    .clamp(-1.0, 1.0);
+    let zenith = cos_zenith.acos();
+
+    let azimuth = if zenith.sin().abs() < 1e-9 {
+        180.0
+    } else {
+        let cos_azimuth = ((latitude.sin() * cos_zenith - declination.sin())
+            / (latitude.cos() * zenith.sin()))
+        .clamp(-1.0, 1.0);
+        let angle = cos_azimuth.acos().to_degrees();
+        // Afternoon sun is west of the meridian
+        if hour_angle > 0.0 {
+            (angle + 180.0) % 360.0
+        } else {
+            (540.0 - angle) % 360.0
+        }
+    };
+
+    (zenith.to_degrees(), azimuth)
+}
+
+/// Irradiance on a tilted plane from global and direct horizontal irradiance
+///
+/// Beam light is projected onto the panel, diffuse light is treated as
+/// coming evenly from the sky, and the ground reflects `ALBEDO` of the rest.
+pub fn plane_irradiance(
+    global: f64,
+    direct: f64,
+    zenith: f64,
+    sun_azimuth: f64,
+    tilt: f64,
+    panel_azimuth: f64,
+) -> f64 {
+    let diffuse = (global - direct).max(0.0);
+    let (zenith, tilt) = (zenith.to_radians(), tilt.to_radians());
+
+    let cos_incidence = zenith.cos() * tilt.cos()
+        + zenith.sin() * tilt.sin() * (sun_azimuth - panel_azimuth).to_radians().cos();
+    // Near the horizon the projection blows up; the beam hardly matters there
+    let beam = if zenith.cos() > 0.087 && cos_incidence > 0.0 {
+        direct * cos_incidence / zenith.cos()
+    } else {
+        0.0
+    };
+    let sky = diffuse * (1.0 + tilt.cos()) / 2.0;
+    let ground = global * ALBEDO * (1.0 - tilt.cos()) / 2.0;
+
+    (beam + sky + ground).max(0.0)
+}
+
+/// PV output (kW) for an irradiance on the panel plane and the air temperature
+pub fn pv_output(irradiance: f64, air_temperature: f64, settings: &EnergySettings) -> f64 {
+    // Cells run about 3 °C warmer per 100 W/m² of sunlight
+    let cell_temperature = air_temperature + irradiance * 0.03;
+    let derating = 1.0 - PV_TEMPERATURE_COEFFICIENT * (cell_temperature - 25.0).max(0.0);
+    settings.pv_kwp * irradiance / 1000.0 * settings.performance_ratio * derating
+}
+
+/// Wind speed at hub height from the 10 m wind, using the 1/7 power law
+pub fn hub_wind_speed(wind_speed_10m: f64, hub_height: f64) -> f64 {
+    wind_speed_10m * (hub_height / 10.0).powf(1.0 / 7.0)
+}
+
+/// Small-turbine output (kW) at a hub-height wind speed
+pub fn turbine_output(wind_speed: f64, rated_kw: f64) -> f64 {
+    if !(CUT_IN..CUT_OUT).contains(&wind_speed) {
+        0.0
+    } else if wind_speed >= RATED_SPEED {
+        rated_kw
+    } else {
+        rated_kw * (wind_speed.powi(3) - CUT_IN.powi(3)) / (RATED_SPEED.powi(3) - CUT_IN.powi(3))
+    }
+}
+
+/// Estimate PV and small-wind production for each forecast hour
+pub fn energy_report(
+    hourly: &[HourlyForecast],
+    location: &Location,
+    settings: &EnergySettings,
+) -> EnergyReport {
+    let hours: Vec<EnergyHour> = hourly
+        .iter()
+        .map(|hour| {
+            // Radiation is the mean over the preceding hour; place the sun mid-hour
+            let (zenith, azimuth) = solar_position(
+                hour.timestamp - Duration::minutes(30),
+                location.latitude,
+                location.longitude,
+            );
+            let irradiance = plane_irradiance(
+                hour.shortwave_radiation.unwrap_or(0.0),
+                hour.direct_radiation.unwrap_or(0.0),
+                zenith,
+                azimuth,
+                settings.tilt,
+                settings.azimuth,
+            );
+            let hub_wind_speed = hub_wind_speed(hour.wind_speed, settings.hub_height);
+
+            EnergyHour {
+                timestamp: hour.timestamp,
+                irradiance,
+                pv_kw: pv_output(irradiance, hour.temperature, settings),
+                hub_wind_speed,
+                wind_kw: turbine_output(hub_wind_speed, settings.turbine_kw),
+            }
+        })
+        .collect();
+
+    EnergyReport {
+        pv_kwh: hours.iter().map(|h| h.pv_kw).sum(),
+        wind_kwh: hours.iter().map(|h| h.wind_kw).sum(),
+        hours,
+    }
+}
@@ -0,0 +1,236 @@
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::modules::forecaster::parse_api_time;
+use crate::modules::http::HttpContext;
+use crate::modules::types::Location;
+
+/// Open-Meteo's ensemble API
+pub const ENSEMBLE_URL: &str = "https://ensemble-api.open-meteo.com/v1";
+/// DWD ICON ensemble, 40 members with global coverage
+pub const ENSEMBLE_MODEL: &str = "icon_seamless";
+/// Days of ensemble forecast to fetch
+pub const ENSEMBLE_DAYS: usize = 7;
+/// Daily precipitation (mm) that makes a member count as wet
+const WET_DAY_MM: f64 = 1.0;
+
+/// Spread of the members' temperatures for one hour
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct EnsembleHour {
+    pub timestamp: DateTime<Utc>,
+    pub min: f64,
+    pub median: f64,
+    pub max: f64,
+}
+
+/// Spread of the members' highs, lows and precipitation for one day
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EnsembleDay {
+    pub date: NaiveDate,
+    /// Lowest, median and highest of the members' daily highs
+    pub high_min: f64,
+    pub high_median: f64,
+    pub high_max: f64,
+    /// Median of the members' daily lows
+    pub low_median: f64,
+    /// Share of members with at least 1 mm of precipitation (0-1)
+    pub precipitation_chance: f64,
+    pub precipitation_median: f64,
+    pub precipitation_max: f64,
+    pub members: usize,
+}
+
+/// Hourly and daily spread of an ensemble forecast
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Ensemble {
+    pub model: String,
+    pub hours: Vec<EnsembleHour>,
+    pub days: Vec<EnsembleDay>,
+}
+
+/// Median of the values, which must not be empty
+fn median(values: &mut [f64]) -> f64 {
+    values.sort_by(f64::total_cmp);
+    let middle = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[middle - 1] + values[middle]) / 2.0
+    } else {
+        values[middle]
+    }
+}
+
+/// The control run and every `<variable>_memberNN` series in the response
+fn members<'a>(hourly: &'a Value, variable: &str) -> Vec<&'a Vec<Value>> {
+    let Some(hourly) = hourly.as_object() else {
+        return Vec::new();
+    };
+    let prefix = format!("{}_member", variable);
+    hourly
+        .iter()
+        .filter(|(key, _)| *key == variable || key.starts_with(&prefix))
+        .filter_map(|(_, values)| values.as_array())
+        .collect()
+}
+
+/// Parse an ensemble response with hourly `temperature_2m` and `precipitation`
+pub fn parse_ensemble(json: &Value) -> Result<Ensemble> {
+    let utc_offset = json["utc_offset_seconds"].as_i64().unwrap_or(0);
+    let hourly = &json["hourly"];
+    let times: Vec<DateTime<Utc>> = hourly["time"]
+        .as_array()
+        .ok_or_else(|| anyhow!("Missing hourly time data"))?
+        .iter()
+        .map(|time| {
+            time.as_str()
+                .and_then(|time| parse_api_time(time, utc_offset))
+                .ok_or_else(|| anyhow!("Invalid time in the ensemble forecast"))
+        })
+        .collect::<Result<_>>()?;
+    let temperature = members(hourly, "temperature_2m");
+    let precipitation = members(hourly, "precipitation");
+    if temperature.is_empty() {
+        return Err(anyhow!("No ensemble members in the response"));
+    }
+
+    let hours = times
+        .iter()
+        .enumerate()
+        .filter_map(|(i, timestamp)| {
+            let mut values: Vec<f64> = temperature
+                .iter()
+                .filter_map(|m| m.get(i)?.as_f64())
+                .collect();
+            if values.is_empty() {
+                return None;
+            }
+            let median = median(&mut values);
+            Some(EnsembleHour {
+                timestamp: *timestamp,
+                min: values[0],
+                median,
+                max: values[values.len() - 1],
+            })
+        })
+        .collect();
+
+    // Days follow the location's local time, like the times in the response
+    let local_date = |i: usize| (times[i] + Duration::seconds(utc_offset)).date_naive();
+    let mut dates: Vec<NaiveDate> = (0..times.len()).map(local_date).collect();
+    dates.dedup();
+
+    let days = dates
+        .into_iter()
+        .filter_map(|date| {
+            let indices: Vec<usize> = (0..times.len())
+                .filter(|i| local_date(*i) == date)
+                .collect();
+            let member_values = |member: &Vec<Value>| -> Vec<f64> {
+                indices
+                    .iter()
+                    .filter_map(|i| member.get(*i)?.as_f64())
+                    .collect()
+            };
+
+            let (mut highs, mut lows): (Vec<f64>, Vec<f64>) = temperature
+                .iter()
+                .map(|m| member_values(m))
+                .filter(|values| !values.is_empty())
+                .map(|values| {
+                    let high = values.iter().copied().fold(f64::MIN, f64::max);
+                    let low = values.iter().copied().fold(f64::MAX, f64::min);
+                    (high, low)
+                })
+                .unzip();
+            if highs.is_empty() {
+                return None;
+            }
+            let mut totals: Vec<f64> = precipitation
+                .iter()
+                .map(|m| member_values(m).iter().sum())
+                .collect();
+
+            let high_median = median(&mut highs);
+            let wet = totals.iter().filter(|total| **total >= WET_DAY_MM).count();
+            Some(EnsembleDay {
+                date,
+                high_min: highs[0],
+                high_median,
+                high_max: highs[highs.len() - 1],
+                low_median: median(&mut lows),
+                precipitation_chance: if totals.is_empty() {
+                    0.0
+                } else {
+                    wet as f64 / totals.len() as f64
+                },
+                precipitation_median: if totals.is_empty() {
+                    0.0
+                } else {
+                    median(&mut totals)
+                },
+                precipitation_max: totals.iter().copied().fold(0.0, f64::max),
+                members: highs.len(),
+            })
+        })
+        .collect();
+
+    Ok(Ensemble {
+        model: ENSEMBLE_MODEL.to_string(),
+        hours,
+        days,
+    })
+}
+
+/// A whisker bar for the spread of a day's high on a `[lo, hi]` scale, e.g.
+/// `  ├───●──┤  `
+pub fn whisker(day: &EnsembleDay, lo: f64, hi: f64, width: usize) -> String {
+    let position = |value: f64| {
+        let span = (hi - lo).max(f64::EPSILON);
+        (((value - lo) / span) * (width - 1) as f64)
+            .round()
+            .clamp(0.0, (width - 1) as f64) as usize
+    };
+    let (start, middle, end) = (
+        position(day.high_min),
+        position(day.high_median),
+        position(day.high_max),
+    );
+    (0..width)
+        .map(|i| match i {
+            i if i == middle => '●',
+            i if i == start => '├',
+            i if i == end => '┤',
+            i if i > start && i < end => '─',
+            _ => ' ',
+        })
+        .collect()
+}
+
+/// Fetches ensemble forecasts from Open-Meteo
+pub struct EnsembleService {
+    http: HttpContext,
+}
+
+impl EnsembleService {
+    pub fn new(http: HttpContext) -> Self {
+        Self { http }
+    }
+
+    pub async fn fetch(&self, location: &Location) -> Result<Ensemble> {
+        let url = format!(
+            "{}/ensemble?latitude={}&longitude={}&hourly=temperature_2m,precipitation&models={}&timezone=auto&forecast_days={}",
+            ENSEMBLE_URL, location.latitude, location.longitude, ENSEMBLE_MODEL, ENSEMBLE_DAYS
+        );
+        let json = self
+            .http
+            .get_json(&url)
+            .await
+            .context("Could not reach the Open-Meteo ensemble API")?;
+        if json["error"].as_bool() == Some(true) {
+            let reason = json["reason"].as_str().unwrap_or("Unknown error");
+            return Err(anyhow!("Open-Meteo API error: {}", reason));
+        }
+        parse_ensemble(&json)
+    }
+}
@@ -0,0 +1,113 @@
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::error::Error;
+use strum_macros::Display;
+use thiserror::Error;
+
+/// What went wrong, reported as `error.kind` with `--json` and as the exit code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ErrorKind {
+    /// Anything not covered below
+    Other,
+    /// Bad arguments or missing input, the same code clap uses for usage errors
+    InvalidInput,
+    /// A place could not be found or the location could not be detected
+    GeocodingFailed,
+    /// A service could not be reached or did not answer in time
+    Network,
+    /// A weather service answered with an error or data that can't be read
+    Provider,
+    /// The config file, proxy or certificate settings are invalid
+    Config,
+    /// A local file could not be read or written
+    Io,
+}
+
+impl ErrorKind {
+    /// Process exit code; 130 is kept for Ctrl-C
+    pub fn exit_code(self) -> i32 {
+        match self {
+            ErrorKind::Other => 1,
+            ErrorKind::InvalidInput => 2,
+            ErrorKind::GeocodingFailed => 3,
+            ErrorKind::Network => 4,
+            ErrorKind::Provider => 5,
+            ErrorKind::Config => 6,
+            ErrorKind::Io => 7,
+        }
+    }
+
+    /// The kind of the outermost error in the chain that has a known kind
+    pub fn of(error: &anyhow::Error) -> Self {
+        error
+            .chain()
+            .find_map(Self::of_one)
+            .unwrap_or(ErrorKind::Other)
+    }
+
+    fn of_one(error: &(dyn Error + 'static)) -> Option<Self> {
+        if let Some(error) = error.downcast_ref::<WeatherError>() {
+            return Some(error.kind);
+        }
+        if let Some(error) = error.downcast_ref::<reqwest::Error>() {
+            return Some(if error.is_status() || error.is_decode() {
+                ErrorKind::Provider
+            } else {
+                ErrorKind::Network
+            });
+        }
+        if error.is::<toml::de::Error>() {
+            return Some(ErrorKind::Config);
+        }
+        if error.is::<std::io::Error>() {
+            return Some(ErrorKind::Io);
+        }
+        None
+    }
+}
+
+/// An error whose kind is known where it is raised
+#[derive(Debug, Error)]
+#[error("{message}")]
+pub struct WeatherError {
+    pub kind: ErrorKind,
+    pub message: String,
+}
+
+impl WeatherError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+/// The error and its causes on one line, like anyhow's `{:#}` but without
+/// repeating causes that an error already includes in its own message
+pub fn error_message(error: &anyhow::Error) -> String {
+    let mut message = String::new();
+    for cause in error.chain() {
+        let text = cause.to_string();
+        if message.contains(&text) {
+            continue;
+        }
+        if !message.is_empty() {
+            message.push_str(": ");
+        }
+        message.push_str(&text);
+    }
+    message
+}
+
+/// `{"error": {"kind": ..., "message": ...}}`, printed instead of the error line with `--json`
+pub fn error_json(error: &anyhow::Error) -> Value {
+    json!({
+        "error": {
+            "kind": ErrorKind::of(error),
+            "message": error_message(error),
+        }
+    })
+}
@@ -0,0 +1,306 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+use serde::Serialize;
+
+use crate::modules::config::ThresholdSettings;
+use crate::modules::http::HttpContext;
+use crate::modules::push::is_severe;
+use crate::modules::types::{HourlyForecast, WeatherCondition};
+use crate::modules::ui::{convert_from_local, convert_to_local};
+
+/// Words in a summary or description that mark an event as outdoors, used
+/// when `[events] keywords` is not set
+pub const OUTDOOR_KEYWORDS: [&str; 24] = [
+    "picnic", "hike", "hiking", "bbq", "barbecue", "grill", "garden", "park", "beach", "camping",
+    "run", "running", "jog", "bike", "cycling", "ride", "walk", "outdoor", "festival", "football",
+    "soccer", "tennis", "golf", "swim",
+];
+
+/// One VEVENT from a calendar
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CalendarEvent {
+    pub summary: String,
+    pub location: Option<String>,
+    pub description: Option<String>,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub all_day: bool,
+}
+
+impl CalendarEvent {
+    /// Whether the event probably takes place outside: it has a place that
+    /// isn't a meeting link, or one of the keywords appears in its text
+    pub fn is_outdoor(&self, keywords: &[String]) -> bool {
+        let has_place = self.location.as_deref().is_some_and(|place| {
+            let place = place.trim().to_lowercase();
+            !place.is_empty() && !place.contains("://")
+        });
+        if has_place {
+            return true;
+        }
+        let text = format!(
+            "{} {}",
+            self.summary,
+            self.description.as_deref().unwrap_or("")
+        )
+        .to_lowercase();
+        text.split(|c: char| !c.is_alphanumeric())
+            .any(|word| keywords.iter().any(|keyword| keyword == word))
+    }
+}
+
+/// Read the VEVENTs of an iCalendar document
+///
+/// Times with a `TZID` and floating times are read in `timezone`; events
+/// without a usable DTSTART are skipped.
+pub fn parse_ics(text: &str, timezone: &str) -> Vec<CalendarEvent> {
+    let mut events = Vec::new();
+    let mut current: Option<Vec<(String, String)>> = None;
+
+    for line in unfold(text) {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        match (name.to_uppercase().as_str(), value.trim()) {
+            ("BEGIN", "VEVENT") => current = Some(Vec::new()),
+            ("END", "VEVENT") => {
+                if let Some(event) = current.take().and_then(|p| event(&p, timezone)) {
+                    events.push(event);
+                }
+            }
+            _ => {
+                if let Some(properties) = current.as_mut() {
+                    properties.push((name.to_string(), value.to_string()));
+                }
+            }
+        }
+    }
+
+    events.sort_by_key(|event| event.start);
+    events
+}
+
+/// Join continuation lines, which start with a space or tab
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+fn event(properties: &[(String, String)], timezone: &str) -> Option<CalendarEvent> {
+    // The property name, e.g. DTSTART, and its parameters, e.g. TZID=Europe/Berlin
+    let find = |key: &str| {
+        properties.iter().find_map(|(name, value)| {
+            let mut parts = name.split(';');
+            parts
+                .next()
+                .filter(|n| n.eq_ignore_ascii_case(key))
+                .map(|_| (parts.collect::<Vec<_>>(), value.as_str()))
+        })
+    };
+    let text = |key: &str| find(key).map(|(_, value)| unescape(value));
+
+    let (params, value) = find("DTSTART")?;
+    let (start, all_day) = parse_time(&params, value, timezone)?;
+    let end = match find("DTEND") {
+        Some((params, value)) => parse_time(&params, value, timezone).map(|(end, _)| end),
+        None => None,
+    }
+    .filter(|end| *end > start)
+    .unwrap_or_else(|| {
+        start
+            + if all_day {
+                Duration::days(1)
+            } else {
+                Duration::hours(1)
+            }
+    });
+
+    Some(CalendarEvent {
+        summary: text("SUMMARY").unwrap_or_else(|| "Event".to_string()),
+        location: text("LOCATION").filter(|location| !location.trim().is_empty()),
+        description: text("DESCRIPTION").filter(|description| !description.trim().is_empty()),
+        start,
+        end,
+        all_day,
+    })
+}
+
+/// A DTSTART or DTEND value in UTC, and whether it is a whole day
+fn parse_time(params: &[&str], value: &str, timezone: &str) -> Option<(DateTime<Utc>, bool)> {
+    let value = value.trim();
+    if params.iter().any(|p| p.eq_ignore_ascii_case("VALUE=DATE")) || value.len() == 8 {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+        return Some((
+            convert_from_local(date.and_hms_opt(0, 0, 0)?, timezone),
+            true,
+        ));
+    }
+    if let Some(utc) = value.strip_suffix('Z') {
+        let time = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some((time.and_utc(), false));
+    }
+    let zone = params
+        .iter()
+        .find_map(|p| p.strip_prefix("TZID="))
+        .map(|zone| zone.trim_matches('"'))
+        .unwrap_or(timezone);
+    let time = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+    Some((convert_from_local(time, zone), false))
+}
+
+/// Undo the TEXT escaping of `\,`, `\;`, `\n` and `\\`
+fn unescape(value: &str) -> String {
+    let mut text = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => text.push('\n'),
+            Some(other) => text.push(other),
+            None => text.push('\\'),
+        }
+    }
+    text
+}
+
+/// Read a calendar from a file, or from an http(s) or webcal URL
+pub async fn load_calendar(source: &str, http: &HttpContext) -> Result<String> {
+    let url = match source.strip_prefix("webcal://") {
+        Some(rest) => Some(format!("https://{}", rest)),
+        None if source.starts_with("http://") || source.starts_with("https://") => {
+            Some(source.to_string())
+        }
+        None => None,
+    };
+    match url {
+        Some(url) => Ok(http
+            .get(&url)
+            .await?
+            .error_for_status()?
+            .text()
+            .await
+            .with_context(|| format!("Could not read the calendar at {}", source))?),
+        None => std::fs::read_to_string(source)
+            .with_context(|| format!("Could not read the calendar {}", source)),
+    }
+}
+
+/// The forecast for one outdoor event
+#[derive(Debug, Clone, Serialize)]
+pub struct EventOutlook {
+    pub event: CalendarEvent,
+    /// Forecast hours covered by the event
+    pub hours: usize,
+    /// Highest chance of precipitation (0-1)
+    pub max_pop: f64,
+    pub temp_min: f64,
+    pub temp_max: f64,
+    /// Strongest wind (m/s)
+    pub max_wind: f64,
+    pub condition: WeatherCondition,
+    pub warnings: Vec<String>,
+}
+
+impl EventOutlook {
+    /// One line like "Saturday 10:00 picnic: 80% rain"
+    pub fn headline(&self, timezone: &str) -> String {
+        let local = convert_to_local(&self.event.start, timezone);
+        let when = if self.event.all_day {
+            local.format("%A").to_string()
+        } else {
+            local.format("%A %H:%M").to_string()
+        };
+        let outlook = if self.warnings.is_empty() {
+            format!(
+                "{} {}, {:.0}% rain",
+                self.condition.get_emoji(),
+                self.condition,
+                self.max_pop * 100.0
+            )
+        } else {
+            self.warnings.join(", ")
+        };
+        format!("{} {}: {}", when, self.event.summary, outlook)
+    }
+}
+
+/// Outdoor events still to come within the forecast, with the weather for each
+pub fn event_outlooks(
+    events: &[CalendarEvent],
+    hourly: &[HourlyForecast],
+    keywords: &[String],
+    thresholds: &ThresholdSettings,
+    now: DateTime<Utc>,
+) -> Vec<EventOutlook> {
+    events
+        .iter()
+        .filter(|event| event.end > now && event.is_outdoor(keywords))
+        .filter_map(|event| {
+            let hours: Vec<_> = hourly
+                .iter()
+                .filter(|h| {
+                    h.timestamp + Duration::hours(1) > event.start && h.timestamp < event.end
+                })
+                .collect();
+            outlook(event, &hours, thresholds)
+        })
+        .collect()
+}
+
+fn outlook(
+    event: &CalendarEvent,
+    hours: &[&HourlyForecast],
+    thresholds: &ThresholdSettings,
+) -> Option<EventOutlook> {
+    let first = hours.first()?;
+    let max_pop = hours.iter().map(|h| h.pop).fold(0.0, f64::max);
+    let max_wind = hours.iter().map(|h| h.wind_speed).fold(0.0, f64::max);
+    let temp_min = hours.iter().map(|h| h.temperature).fold(f64::MAX, f64::min);
+    let temp_max = hours.iter().map(|h| h.temperature).fold(f64::MIN, f64::max);
+    let severe = hours.iter().find(|h| is_severe(h.main_condition));
+    let condition = severe.unwrap_or(first).main_condition;
+
+    let mut warnings = Vec::new();
+    if let Some(hour) = severe {
+        warnings.push(format!(
+            "{} {}",
+            hour.main_condition.get_emoji(),
+            hour.main_condition
+        ));
+    }
+    if max_pop >= thresholds.rain_notify_pop() {
+        warnings.push(format!("{:.0}% rain", max_pop * 100.0));
+    }
+    if max_wind >= thresholds.wind_warning() {
+        warnings.push(format!("wind up to {:.0} m/s", max_wind));
+    }
+
+    Some(EventOutlook {
+        event: event.clone(),
+        hours: hours.len(),
+        max_pop,
+        temp_min,
+        temp_max,
+        max_wind,
+        condition,
+        warnings,
+    })
+}
+
+/// The keywords from the config, or the built-in list when none are set
+pub fn outdoor_keywords(configured: &[String]) -> Vec<String> {
+    if configured.is_empty() {
+        OUTDOOR_KEYWORDS.iter().map(|k| k.to_string()).collect()
+    } else {
+        configured.iter().map(|k| k.to_lowercase()).collect()
+    }
+}
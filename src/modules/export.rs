@@ -0,0 +1,450 @@
+use crate::modules::climate::{ClimateComparison, NORMALS_PERIOD};
+use crate::modules::types::{DailyForecast, HourlyForecast, Location};
+use crate::modules::ui::convert_to_local;
+use anyhow::{anyhow, Context, Result};
+use chrono::{Duration, Utc};
+use clap::ValueEnum;
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use std::path::Path;
+
+/// Image size (width, height) in pixels for exported charts
+const CHART_SIZE: (u32, u32) = (1024, 480);
+
+/// Charts that can be exported with `weather_man export --chart`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ChartKind {
+    /// Hourly temperature and feels-like temperature
+    Temperature,
+    /// Hourly precipitation probability and amount
+    Precipitation,
+}
+
+/// Render an hourly chart to an SVG or PNG file, chosen by the file extension
+pub fn export_chart(
+    hourly_data: &[HourlyForecast],
+    location: &Location,
+    kind: ChartKind,
+    path: &Path,
+) -> Result<()> {
+    if hourly_data.is_empty() {
+        return Err(anyhow!("No hourly forecast data to chart"));
+    }
+
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase());
+
+    match extension.as_deref() {
+        Some("svg") => {
+            let root = SVGBackend::new(path, CHART_SIZE).into_drawing_area();
+            draw_chart(&root, hourly_data, location, kind)?;
+            root.present()?;
+        }
+        Some("png") => {
+            let root = BitMapBackend::new(path, CHART_SIZE).into_drawing_area();
+            draw_chart(&root, hourly_data, location, kind)?;
+            root.present()?;
+        }
+        _ => {
+            return Err(anyhow!(
+                "Unsupported chart format for {} (use .svg or .png)",
+                path.display()
+            ))
+        }
+    }
+
+    Ok(())
+}
+
+/// Draw the requested chart onto any plotters drawing area
+fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    hourly_data: &[HourlyForecast],
+    location: &Location,
+    kind: ChartKind,
+) -> Result<()>
+where
+    DB::ErrorType: 'static,
+{
+    root.fill(&WHITE)?;
+
+    let hours = hourly_data.len();
+    let hour_label = |index: &usize| {
+        hourly_data
+            .get(*index)
+            .map(|hour| {
+                convert_to_local(&hour.timestamp, location.clock_timezone())
+                    .format("%a %Hh")
+                    .to_string()
+            })
+            .unwrap_or_default()
+    };
+
+    match kind {
+        ChartKind::Temperature => {
+            let (min, max) = hourly_data
+                .iter()
+                .flat_map(|hour| [hour.temperature, hour.feels_like])
+                .fold((f64::MAX, f64::MIN), |(lo, hi), t| (lo.min(t), hi.max(t)));
+
+            let mut chart = ChartBuilder::on(root)
+                .caption(
+                    format!("Hourly Temperature - {}", location.name),
+                    ("sans-serif", 24),
+                )
+                .margin(16)
+                .x_label_area_size(40)
+                .y_label_area_size(50)
+                .build_cartesian_2d(0..hours, (min - 2.0).floor()..(max + 2.0).ceil())?;
+
+            chart
+                .configure_mesh()
+                .x_labels(12)
+                .x_label_formatter(&hour_label)
+                .y_desc("°C")
+                .draw()?;
+
+            chart
+                .draw_series(LineSeries::new(
+                    hourly_data
+                        .iter()
+                        .enumerate()
+                        .map(|(i, hour)| (i, hour.temperature)),
+                    RED.stroke_width(2),
+                ))?
+                .label("Temperature")
+                .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+
+            chart
+                .draw_series(LineSeries::new(
+                    hourly_data
+                        .iter()
+                        .enumerate()
+                        .map(|(i, hour)| (i, hour.feels_like)),
+                    BLUE.mix(0.6),
+                ))?
+                .label("Feels like")
+                .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE.mix(0.6)));
+
+            chart
+                .configure_series_labels()
+                .background_style(WHITE.mix(0.8))
+                .border_style(BLACK)
+                .draw()?;
+        }
+        ChartKind::Precipitation => {
+            let max_amount = hourly_data
+                .iter()
+                .map(|hour| hour.rain.unwrap_or(0.0) + hour.snow.unwrap_or(0.0))
+                .fold(0.0, f64::max);
+
+            let mut chart = ChartBuilder::on(root)
+                .caption(
+                    format!("Hourly Precipitation - {}", location.name),
+                    ("sans-serif", 24),
+                )
+                .margin(16)
+                .x_label_area_size(40)
+                .y_label_area_size(50)
+                .right_y_label_area_size(50)
+                .build_cartesian_2d(0..hours, 0.0..100.0)?
+                .set_secondary_coord(0..hours, 0.0..(max_amount * 1.2).max(1.0));
+
+            chart
+                .configure_mesh()
+                .x_labels(12)
+                .x_label_formatter(&hour_label)
+                .y_desc("Probability (%)")
+                .draw()?;
+
+            chart
+                .configure_secondary_axes()
+                .y_desc("Amount (mm)")
+                .draw()?;
+
+            chart
+                .draw_series(hourly_data.iter().enumerate().map(|(i, hour)| {
+                    Rectangle::new(
+                        [(i, 0.0), (i + 1, hour.pop.clamp(0.0, 1.0) * 100.0)],
+                        BLUE.mix(0.4).filled(),
+                    )
+                }))?
+                .label("Probability")
+                .legend(|(x, y)| Rectangle::new([(x, y - 5), (x + 20, y + 5)], BLUE.mix(0.4)));
+
+            chart
+                .draw_secondary_series(LineSeries::new(
+                    hourly_data
+                        .iter()
+                        .enumerate()
+                        .map(|(i, hour)| (i, hour.rain.unwrap_or(0.0) + hour.snow.unwrap_or(0.0))),
+                    BLACK.stroke_width(2),
+                ))?
+                .label("Amount")
+                .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLACK));
+
+            chart
+                .configure_series_labels()
+                .background_style(WHITE.mix(0.8))
+                .border_style(BLACK)
+                .draw()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Render this week's forecast against the monthly normals to an SVG or PNG file
+pub fn export_climate_chart(
+    days: &[ClimateComparison],
+    location: &Location,
+    path: &Path,
+) -> Result<()> {
+    if days.is_empty() {
+        return Err(anyhow!("No forecast days to compare with the normals"));
+    }
+
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase());
+
+    match extension.as_deref() {
+        Some("svg") => {
+            let root = SVGBackend::new(path, CHART_SIZE).into_drawing_area();
+            draw_climate_chart(&root, days, location)?;
+            root.present()?;
+        }
+        Some("png") => {
+            let root = BitMapBackend::new(path, CHART_SIZE).into_drawing_area();
+            draw_climate_chart(&root, days, location)?;
+            root.present()?;
+        }
+        _ => {
+            return Err(anyhow!(
+                "Unsupported chart format for {} (use .svg or .png)",
+                path.display()
+            ))
+        }
+    }
+
+    Ok(())
+}
+
+/// Forecast highs, lows and precipitation with the normals as dashed lines
+fn draw_climate_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    days: &[ClimateComparison],
+    location: &Location,
+) -> Result<()>
+where
+    DB::ErrorType: 'static,
+{
+    root.fill(&WHITE)?;
+    let root = root.titled(
+        &format!(
+            "{} - this week against the {}–{} normals",
+            location.name, NORMALS_PERIOD.0, NORMALS_PERIOD.1
+        ),
+        ("sans-serif", 24),
+    )?;
+    let (upper, lower) = root.split_vertically(CHART_SIZE.1 * 3 / 5);
+
+    let count = days.len();
+    let day_label = |index: &usize| {
+        days.get(*index)
+            .map(|day| day.date.format("%a %-d").to_string())
+            .unwrap_or_default()
+    };
+    let dashed = |points: Vec<(usize, f64)>, color: RGBColor| {
+        DashedLineSeries::new(points, 6, 4, color.stroke_width(2))
+    };
+
+    let (min, max) = days
+        .iter()
+        .flat_map(|day| [day.temp_max, day.temp_min, day.normal_max, day.normal_min])
+        .fold((f64::MAX, f64::MIN), |(lo, hi), t| (lo.min(t), hi.max(t)));
+    let mut chart = ChartBuilder::on(&upper)
+        .margin(16)
+        .x_label_area_size(30)
+        .y_label_area_size(50)
+        .build_cartesian_2d(
+            0..(count - 1).max(1),
+            (min - 2.0).floor()..(max + 2.0).ceil(),
+        )?;
+    chart
+        .configure_mesh()
+        .x_labels(count)
+        .x_label_formatter(&day_label)
+        .y_desc("°C")
+        .draw()?;
+
+    let points = |value: fn(&ClimateComparison) -> f64| -> Vec<(usize, f64)> {
+        days.iter()
+            .enumerate()
+            .map(|(i, day)| (i, value(day)))
+            .collect()
+    };
+    chart
+        .draw_series(LineSeries::new(points(|d| d.temp_max), RED.stroke_width(2)))?
+        .label("High")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+    chart
+        .draw_series(LineSeries::new(
+            points(|d| d.temp_min),
+            BLUE.stroke_width(2),
+        ))?
+        .label("Low")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+    chart
+        .draw_series(dashed(points(|d| d.normal_max), RED))?
+        .label("Normal high")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED.mix(0.5)));
+    chart
+        .draw_series(dashed(points(|d| d.normal_min), BLUE))?
+        .label("Normal low")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE.mix(0.5)));
+    chart
+        .configure_series_labels()
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+
+    let max_amount = days
+        .iter()
+        .flat_map(|day| [day.precipitation, day.normal_precipitation])
+        .fold(0.0, f64::max);
+    let mut chart = ChartBuilder::on(&lower)
+        .margin(16)
+        .x_label_area_size(30)
+        .y_label_area_size(50)
+        .build_cartesian_2d(0..count, 0.0..(max_amount * 1.2).max(1.0))?;
+    chart
+        .configure_mesh()
+        .x_labels(count)
+        .x_label_formatter(&day_label)
+        .y_desc("mm")
+        .draw()?;
+    chart
+        .draw_series(days.iter().enumerate().map(|(i, day)| {
+            Rectangle::new(
+                [(i, 0.0), (i + 1, day.precipitation)],
+                BLUE.mix(0.4).filled(),
+            )
+        }))?
+        .label("Precipitation")
+        .legend(|(x, y)| Rectangle::new([(x, y - 5), (x + 20, y + 5)], BLUE.mix(0.4)));
+    chart
+        .draw_series(days.iter().enumerate().map(|(i, day)| {
+            PathElement::new(
+                vec![
+                    (i, day.normal_precipitation),
+                    (i + 1, day.normal_precipitation),
+                ],
+                BLACK.stroke_width(2),
+            )
+        }))?
+        .label("Normal")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLACK));
+    chart
+        .configure_series_labels()
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
+/// Write daily forecasts to an iCalendar file as all-day events
+pub fn export_ics(daily_data: &[DailyForecast], location: &Location, path: &Path) -> Result<()> {
+    if daily_data.is_empty() {
+        return Err(anyhow!("No daily forecast data to export"));
+    }
+
+    std::fs::write(path, daily_to_ics(daily_data, location))
+        .with_context(|| format!("Could not write calendar to {}", path.display()))
+}
+
+/// Build an iCalendar document with one all-day VEVENT per forecast day
+pub fn daily_to_ics(daily_data: &[DailyForecast], location: &Location) -> String {
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ");
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//weather_man//Weather Forecast//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:Weather - {}", escape_ics_text(&location.name)),
+    ];
+
+    for day in daily_data {
+        let date = day.date.date_naive();
+        let summary = format!(
+            "{} {} {:.0}°/{:.0}°C",
+            day.main_condition.get_emoji(),
+            day.main_condition,
+            day.temp_min,
+            day.temp_max
+        );
+        let description = format!(
+            "Precipitation chance: {:.0}%\nWind: {:.1} m/s\nUV index: {:.1}",
+            day.pop * 100.0,
+            day.wind_speed,
+            day.uv_index
+        );
+
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!(
+            "UID:{}-{:.4}-{:.4}@weather_man",
+            date.format("%Y%m%d"),
+            location.latitude,
+            location.longitude
+        ));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.push(format!("DTSTART;VALUE=DATE:{}", date.format("%Y%m%d")));
+        lines.push(format!(
+            "DTEND;VALUE=DATE:{}",
+            (date + Duration::days(1)).format("%Y%m%d")
+        ));
+        lines.push(format!("SUMMARY:{}", escape_ics_text(&summary)));
+        lines.push(format!("DESCRIPTION:{}", escape_ics_text(&description)));
+        lines.push(format!("LOCATION:{}", escape_ics_text(&location.name)));
+        lines.push("TRANSP:TRANSPARENT".to_string());
+        lines.push("END:VEVENT".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    lines
+        .iter()
+        .map(|line| fold_ics_line(line))
+        .collect::<Vec<_>>()
+        .join("\r\n")
+        + "\r\n"
+}
+
+/// Escape characters with special meaning in iCalendar text values
+fn escape_ics_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Fold lines longer than 75 octets as required by RFC 5545
+fn fold_ics_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut octets = 0;
+
+    for c in line.chars() {
+        if octets + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            octets = 1;
+        }
+        folded.push(c);
+        octets += c.len_utf8();
+    }
+
+    folded
+}
@@ -0,0 +1,320 @@
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+
+use crate::modules::forecaster::parse_api_time;
+use crate::modules::http::HttpContext;
+use crate::modules::types::{HourlyForecast, Location, WeatherAlert};
+use crate::modules::ui::convert_to_local;
+
+/// Past days used for the precipitation deficit
+pub const DROUGHT_DAYS: u8 = 14;
+/// Days of fire weather forecast
+pub const FIRE_DAYS: u8 = 7;
+/// Evapotranspiration minus precipitation (mm) over `DROUGHT_DAYS` that
+/// counts as drought and raises the risk by one level
+pub const DROUGHT_DEFICIT_MM: f64 = 30.0;
+/// A day with this much precipitation (mm) has low fire risk
+const WET_DAY_MM: f64 = 2.0;
+/// Less than this (mm) counts as a dry day
+const DRY_DAY_MM: f64 = 1.0;
+
+/// Fosberg fire weather index (0-100) from temperature (°C), relative
+/// humidity (%) and wind speed (m/s)
+///
+/// It estimates how fast fine fuels dry out and fire spreads; it ignores
+/// the fuel itself, which is what the precipitation deficit stands in for.
+pub fn fosberg_index(temperature: f64, humidity: f64, wind_speed: f64) -> f64 {
+    let t = temperature * 9.0 / 5.0 + 32.0;
+    let h = humidity.clamp(0.0, 100.0);
+    let u = wind_speed * 2.236_936;
+
+    // Equilibrium moisture content of the fuel (%)
+    let m = if h < 10.0 {
+        0.03229 + 0.281073 * h - 0.000578 * h * t
+    } else if h < 50.0 {
+        2.22749 + 0.160107 * h - 0.01478 * t
+    } else {
+        21.0606 + 0.005565 * h * h - 0.00035 * h * t - 0.483199 * h
+    };
+    let r = (m / 30.0).max(0.0);
+    let eta = 1.0 - 2.0 * r + 1.5 * r * r - 0.5 * r * r * r;
+    (eta.max(0.0) * (1.0 + u * u).sqrt() / 0.3002).min(100.0)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FireLevel {
+    Low,
+    Moderate,
+    High,
+    Extreme,
+}
+
+impl FireLevel {
+    /// Level for the day's highest index; wet days are low and drought
+    /// raises the level by one
+    pub fn for_day(index: f64, precipitation: f64, drought: bool) -> Self {
+        if precipitation >= WET_DAY_MM {
+            return FireLevel::Low;
+        }
+        let level = match index {
+            i if i >= 50.0 => FireLevel::Extreme,
+            i if i >= 35.0 => FireLevel::High,
+            i if i >= 20.0 => FireLevel::Moderate,
+            _ => FireLevel::Low,
+        };
+        if !drought {
+            return level;
+        }
+        match level {
+            FireLevel::Low => FireLevel::Moderate,
+            FireLevel::Moderate => FireLevel::High,
+            FireLevel::High | FireLevel::Extreme => FireLevel::Extreme,
+        }
+    }
+
+    pub fn get_emoji(&self) -> &'static str {
+        match self {
+            FireLevel::Low => "🟢",
+            FireLevel::Moderate => "🟡",
+            FireLevel::High => "🟠",
+            FireLevel::Extreme => "🔴",
+        }
+    }
+}
+
+impl fmt::Display for FireLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            FireLevel::Low => "Low",
+            FireLevel::Moderate => "Moderate",
+            FireLevel::High => "High",
+            FireLevel::Extreme => "Extreme",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Fire weather for one day, from its hottest, driest and windiest hours
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FireDay {
+    pub date: NaiveDate,
+    pub max_temperature: f64,
+    pub min_humidity: f64,
+    pub max_wind_speed: f64,
+    pub precipitation: f64,
+    /// Highest hourly Fosberg index of the day
+    pub index: f64,
+    pub level: FireLevel,
+}
+
+/// Fire weather forecast with the drought indicators behind it
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FireReport {
+    /// Precipitation (mm) over the last `DROUGHT_DAYS` days
+    pub recent_precipitation: f64,
+    /// Reference evapotranspiration (mm) over the last `DROUGHT_DAYS` days
+    pub recent_evapotranspiration: f64,
+    /// Evapotranspiration minus precipitation; positive when drying out
+    pub deficit: f64,
+    /// Days in a row before today with less than 1 mm
+    pub dry_days: usize,
+    pub drought: bool,
+    pub days: Vec<FireDay>,
+}
+
+/// Temperature, humidity, wind and precipitation for one hour
+struct FireHour {
+    date: NaiveDate,
+    temperature: f64,
+    humidity: f64,
+    wind_speed: f64,
+    precipitation: f64,
+}
+
+fn fire_day(date: NaiveDate, hours: &[&FireHour], drought: bool) -> Option<FireDay> {
+    if hours.is_empty() {
+        return None;
+    }
+    let index = hours
+        .iter()
+        .map(|h| fosberg_index(h.temperature, h.humidity, h.wind_speed))
+        .fold(0.0, f64::max);
+    let precipitation = hours.iter().map(|h| h.precipitation).sum();
+    Some(FireDay {
+        date,
+        max_temperature: hours.iter().map(|h| h.temperature).fold(f64::MIN, f64::max),
+        min_humidity: hours.iter().map(|h| h.humidity).fold(f64::MAX, f64::min),
+        max_wind_speed: hours.iter().map(|h| h.wind_speed).fold(0.0, f64::max),
+        precipitation,
+        index,
+        level: FireLevel::for_day(index, precipitation, drought),
+    })
+}
+
+/// Parse an Open-Meteo response with `past_days`, hourly temperature,
+/// humidity, wind (m/s) and precipitation, and daily precipitation and
+/// evapotranspiration
+pub fn parse_fire(json: &Value, now: DateTime<Utc>) -> Result<FireReport> {
+    let utc_offset = json["utc_offset_seconds"].as_i64().unwrap_or(0);
+    let today = (now + Duration::seconds(utc_offset)).date_naive();
+    let hourly = &json["hourly"];
+    let value = |key: &str, i: usize| hourly[key].get(i).and_then(Value::as_f64);
+    let hours: Vec<FireHour> = hourly["time"]
+        .as_array()
+        .ok_or_else(|| anyhow!("Missing hourly time data"))?
+        .iter()
+        .enumerate()
+        .filter_map(|(i, time)| {
+            let timestamp = parse_api_time(time.as_str()?, utc_offset)?;
+            Some(FireHour {
+                date: (timestamp + Duration::seconds(utc_offset)).date_naive(),
+                temperature: value("temperature_2m", i)?,
+                humidity: value("relative_humidity_2m", i)?,
+                wind_speed: value("wind_speed_10m", i).unwrap_or(0.0),
+                precipitation: value("precipitation", i).unwrap_or(0.0),
+            })
+        })
+        .collect();
+
+    let daily = &json["daily"];
+    let dates: Vec<NaiveDate> = daily["time"]
+        .as_array()
+        .ok_or_else(|| anyhow!("Missing daily time data"))?
+        .iter()
+        .filter_map(|date| NaiveDate::parse_from_str(date.as_str()?, "%Y-%m-%d").ok())
+        .collect();
+    let daily_value = |key: &str, i: usize| daily[key].get(i).and_then(Value::as_f64);
+
+    let past: Vec<(f64, f64)> = dates
+        .iter()
+        .enumerate()
+        .filter(|(_, date)| **date < today)
+        .map(|(i, _)| {
+            (
+                daily_value("precipitation_sum", i).unwrap_or(0.0),
+                daily_value("et0_fao_evapotranspiration", i).unwrap_or(0.0),
+            )
+        })
+        .collect();
+    let recent_precipitation: f64 = past.iter().map(|(p, _)| p).sum();
+    let recent_evapotranspiration: f64 = past.iter().map(|(_, et0)| et0).sum();
+    let deficit = recent_evapotranspiration - recent_precipitation;
+    let dry_days = past
+        .iter()
+        .rev()
+        .take_while(|(p, _)| *p < DRY_DAY_MM)
+        .count();
+    let drought = deficit >= DROUGHT_DEFICIT_MM;
+
+    let days = dates
+        .iter()
+        .filter(|date| **date >= today)
+        .filter_map(|date| {
+            let day: Vec<&FireHour> = hours.iter().filter(|h| h.date == *date).collect();
+            fire_day(*date, &day, drought)
+        })
+        .collect();
+
+    Ok(FireReport {
+        recent_precipitation,
+        recent_evapotranspiration,
+        deficit,
+        dry_days,
+        drought,
+        days,
+    })
+}
+
+/// Alerts for runs of days with extreme fire weather in the regular forecast
+///
+/// The forecast has no precipitation history, so drought is not considered.
+pub fn fire_alerts(hourly: &[HourlyForecast], location: &Location) -> Vec<WeatherAlert> {
+    let hours: Vec<FireHour> = hourly
+        .iter()
+        .map(|h| FireHour {
+            date: convert_to_local(&h.timestamp, &location.timezone).date_naive(),
+            temperature: h.temperature,
+            humidity: h.humidity.0,
+            wind_speed: h.wind_speed,
+            precipitation: h.rain.unwrap_or(0.0) + h.snow.unwrap_or(0.0),
+        })
+        .collect();
+    let mut dates: Vec<NaiveDate> = hours.iter().map(|h| h.date).collect();
+    dates.dedup();
+
+    // (first hour, end, highest index) of each run of extreme days
+    let mut runs: Vec<(DateTime<Utc>, DateTime<Utc>, f64)> = Vec::new();
+    let mut previous: Option<NaiveDate> = None;
+    for date in dates {
+        let indices: Vec<usize> = (0..hours.len())
+            .filter(|i| hours[*i].date == date)
+            .collect();
+        let day: Vec<&FireHour> = indices.iter().map(|i| &hours[*i]).collect();
+        let Some(fire) = fire_day(date, &day, false) else {
+            continue;
+        };
+        if fire.level != FireLevel::Extreme {
+            continue;
+        }
+        let start = hourly[indices[0]].timestamp;
+        let end = hourly[indices[indices.len() - 1]].timestamp + Duration::hours(1);
+        match runs.last_mut() {
+            Some(run) if previous == date.pred_opt() => {
+                run.1 = end;
+                run.2 = run.2.max(fire.index);
+            }
+            _ => runs.push((start, end, fire.index)),
+        }
+        previous = Some(date);
+    }
+
+    runs.into_iter()
+        .map(|(start, end, index)| WeatherAlert {
+            sender: "weather_man".to_string(),
+            event: "Extreme fire weather".to_string(),
+            start,
+            end,
+            description: format!(
+                "Hot, dry and windy conditions, fire weather index up to {:.0}",
+                index
+            ),
+            tags: vec!["fire".to_string()],
+        })
+        .collect()
+}
+
+/// Fetches fire weather and the recent precipitation history from Open-Meteo
+pub struct FireService {
+    http: HttpContext,
+}
+
+impl FireService {
+    pub fn new(http: HttpContext) -> Self {
+        Self { http }
+    }
+
+    pub async fn report(&self, location: &Location) -> Result<FireReport> {
+        let url = format!(
+            "{}/forecast?latitude={}&longitude={}&hourly=temperature_2m,relative_humidity_2m,wind_speed_10m,precipitation&daily=precipitation_sum,et0_fao_evapotranspiration&wind_speed_unit=ms&timezone=auto&past_days={}&forecast_days={}",
+            self.http.endpoints().openmeteo,
+            location.latitude,
+            location.longitude,
+            DROUGHT_DAYS,
+            FIRE_DAYS
+        );
+        let json = self
+            .http
+            .get_json(&url)
+            .await
+            .context("Could not reach Open-Meteo")?;
+        if json["error"].as_bool() == Some(true) {
+            let reason = json["reason"].as_str().unwrap_or("Unknown error");
+            return Err(anyhow!("Open-Meteo API error: {}", reason));
+        }
+        parse_fire(&json, Utc::now())
+    }
+}
@@ -1,50 +1,248 @@
 use anyhow::{anyhow, Result};
-use chrono::{DateTime, Duration, Timelike, Utc};
-use reqwest::Client;
+use chrono::{DateTime, Duration, NaiveDateTime, Timelike, Utc};
 use serde_json::Value;
 use std::collections::HashMap;
-use std::time::Duration as StdDuration;
 
+use crate::modules::comfort::{apply_current, apply_forecast, FeelsLike};
+use crate::modules::errors::{ErrorKind, WeatherError};
+use crate::modules::http::HttpContext;
 use crate::modules::types::{
-    CurrentWeather, DailyForecast, Forecast, HourlyForecast, Location, WeatherCondition,
-    WeatherConfig, WeatherDescription,
+    CurrentWeather, DailyForecast, DetailLevel, Forecast, HourlyForecast, Hpa, Intensity, Location,
+    Percent, WeatherCondition, WeatherConfig, WeatherDescription,
 };
+use crate::modules::validate::{validate_current, validate_forecast};
 
 /// Open-Meteo base URL (doesn't require API key)
-const OPENMETEO_BASE_URL: &str = "https://api.open-meteo.com/v1";
+pub const OPENMETEO_BASE_URL: &str = "https://api.open-meteo.com/v1";
+/// Name forecasts are logged under by the accuracy tracking
+pub const PROVIDER: &str = "open-meteo";
+/// Days fetched for the usual forecast
+pub const FORECAST_DAYS: u8 = 7;
+/// Furthest Open-Meteo forecasts ahead
+pub const MAX_FORECAST_DAYS: u8 = 16;
+
+/// Current conditions read by the parser
+const CURRENT_VARIABLES: &[&str] = &[
+    "temperature_2m",
+    "relative_humidity_2m",
+    "apparent_temperature",
+    "is_day",
+    "rain",
+    "snowfall",
+    "weather_code",
+    "cloud_cover",
+    "surface_pressure",
+    "wind_speed_10m",
+    "wind_direction_10m",
+    "wind_gusts_10m",
+];
+/// Hourly variables the parser cannot do without
+const HOURLY_REQUIRED: &[&str] = &[
+    "temperature_2m",
+    "relative_humidity_2m",
+    "apparent_temperature",
+    "weather_code",
+    "cloud_cover",
+    "surface_pressure",
+    "wind_speed_10m",
+    "wind_direction_10m",
+];
+/// Optional hourly variables, each filling one `HourlyForecast` field
+const HOURLY_OPTIONAL: &[&str] = &[
+    "precipitation_probability",
+    "rain",
+    "snowfall",
+    "uv_index",
+    "shortwave_radiation",
+    "direct_radiation",
+    "freezing_level_height",
+    "snow_depth",
+];
+/// Variables the parsers skip, requested in full so the debug detail level
+/// can show them
+const CURRENT_RAW_ONLY: &[&str] = &["precipitation", "showers", "pressure_msl"];
+const HOURLY_RAW_ONLY: &[&str] = &["precipitation", "showers", "pressure_msl", "wind_gusts_10m"];
+const DAILY_VARIABLES: &[&str] = &[
+    "weather_code",
+    "temperature_2m_max",
+    "temperature_2m_min",
+    "apparent_temperature_max",
+    "apparent_temperature_min",
+    "sunrise",
+    "sunset",
+    "uv_index_max",
+    "precipitation_sum",
+    "rain_sum",
+    "snowfall_sum",
+    "precipitation_probability_max",
+    "wind_speed_10m_max",
+    "wind_gusts_10m_max",
+    "wind_direction_10m_dominant",
+];
+
+/// Parse a comma-separated list of Open-Meteo hourly variable names
+pub fn parse_variables(value: &str) -> Result<Vec<String>, String> {
+    let variables: Vec<String> = value
+        .split(',')
+        .map(|variable| variable.trim().to_lowercase())
+        .filter(|variable| !variable.is_empty())
+        .collect();
+    if variables.is_empty() {
+        return Err("give at least one variable, e.g. cape,freezing_level_height".to_string());
+    }
+    match variables
+        .iter()
+        .find(|v| !v.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'))
+    {
+        Some(variable) => Err(format!("'{}' is not a variable name", variable)),
+        None => Ok(variables),
+    }
+}
+
+/// Open-Meteo variables requested by forecast calls
+///
+/// A block left empty is not requested at all and parses as missing data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestVariables {
+    pub current: Vec<&'static str>,
+    pub hourly: Vec<&'static str>,
+    pub daily: Vec<&'static str>,
+    /// Hourly variables asked for with `--vars`, kept in `HourlyForecast::extra`
+    pub extra_hourly: Vec<String>,
+}
+
+impl RequestVariables {
+    /// Everything any mode can show
+    pub fn full() -> Self {
+        let mut variables = Self::none()
+            .with_current()
+            .with_hourly(HOURLY_OPTIONAL)
+            .with_daily();
+        variables.current.extend(CURRENT_RAW_ONLY);
+        variables.hourly.extend(HOURLY_RAW_ONLY);
+        variables
+    }
+
+    /// No variables; add blocks with the `with_*` methods
+    pub fn none() -> Self {
+        Self {
+            current: Vec::new(),
+            hourly: Vec::new(),
+            daily: Vec::new(),
+            extra_hourly: Vec::new(),
+        }
+    }
+
+    pub fn with_current(mut self) -> Self {
+        self.current = CURRENT_VARIABLES.to_vec();
+        self
+    }
+
+    /// Request the required hourly variables plus the given optional ones
+    pub fn with_hourly(mut self, optional: &[&'static str]) -> Self {
+        self.hourly = HOURLY_REQUIRED.iter().chain(optional).copied().collect();
+        self
+    }
+
+    pub fn with_daily(mut self) -> Self {
+        self.daily = DAILY_VARIABLES.to_vec();
+        self
+    }
+
+    /// Also request these hourly variables, when the hourly block is requested
+    pub fn with_extra_hourly(mut self, variables: Vec<String>) -> Self {
+        self.extra_hourly = variables;
+        self
+    }
+
+    /// Query string parameters for the requested blocks, e.g. `&hourly=...`
+    pub fn query(&self) -> String {
+        let mut hourly: Vec<&str> = self.hourly.clone();
+        if !hourly.is_empty() {
+            for variable in &self.extra_hourly {
+                if !hourly.contains(&variable.as_str()) {
+                    hourly.push(variable);
+                }
+            }
+        }
+        [
+            ("current", self.current.clone()),
+            ("hourly", hourly),
+            ("daily", self.daily.clone()),
+        ]
+        .iter()
+        .filter(|(_, variables)| !variables.is_empty())
+        .map(|(block, variables)| format!("&{}={}", block, variables.join(",")))
+        .collect()
+    }
+}
 
 /// Handles weather data retrieval and processing
 #[derive(Clone)]
 pub struct WeatherForecaster {
-    client: Client,
+    http: HttpContext,
     config: WeatherConfig,
+    variables: RequestVariables,
+    /// Open-Meteo model to use instead of its best match for the location
+    model: Option<String>,
     #[allow(dead_code)]
     api_keys: HashMap<String, String>,
 }
 
 impl WeatherForecaster {
-    /// Create a new weather forecaster with the given configuration
-    pub fn new(config: WeatherConfig) -> Self {
-        let client = Client::builder()
-            .timeout(StdDuration::from_secs(30))
-            .build()
-            .unwrap_or_default();
-
+    /// Create a new weather forecaster sending its requests through `http`
+    pub fn new(config: WeatherConfig, http: HttpContext) -> Self {
         let api_keys = HashMap::new();
 
         Self {
-            client,
+            http,
             config,
+            variables: RequestVariables::full(),
+            model: None,
             api_keys,
         }
     }
 
+    /// Compute feels-like temperatures with this formula
+    pub fn with_feels_like(mut self, formula: FeelsLike) -> Self {
+        self.config.feels_like = formula;
+        self
+    }
+
+    /// Only request these variables, shrinking responses on slow connections
+    pub fn with_variables(mut self, variables: RequestVariables) -> Self {
+        self.variables = variables;
+        self
+    }
+
+    /// Forecast with one Open-Meteo model; `best_match` leaves the choice to Open-Meteo
+    pub fn with_model(mut self, model: &str) -> Self {
+        self.model = (model != "best_match").then(|| model.to_string());
+        self
+    }
+
+    /// Name of the data source, e.g. `open-meteo` or `open-meteo/gfs_seamless`
+    pub fn provider(&self) -> String {
+        match &self.model {
+            Some(model) => format!("{}/{}", PROVIDER, model),
+            None => PROVIDER.to_string(),
+        }
+    }
+
+    /// `&models=...` when a model was chosen
+    fn model_query(&self) -> String {
+        match &self.model {
+            Some(model) => format!("&models={}", model),
+            None => String::new(),
+        }
+    }
+
     /// Get current weather for a location
     pub async fn get_current_weather(&self, location: &Location) -> Result<CurrentWeather> {
         self.get_openmeteo_current(location).await
     }
 
-    /// Get hourly forecast for a location (next 48 hours)
+    /// Get hourly forecast for a location (next 7 days)
     pub async fn get_hourly_forecast(&self, location: &Location) -> Result<Vec<HourlyForecast>> {
         let forecast = self.get_openmeteo_forecast(location).await?;
         Ok(forecast.hourly)
@@ -61,32 +259,66 @@ impl WeatherForecaster {
         self.get_openmeteo_forecast(location).await
     }
 
+    /// Get a complete forecast covering the given number of days (1-16)
+    pub async fn get_forecast_days(&self, location: &Location, days: u8) -> Result<Forecast> {
+        self.get_openmeteo_forecast_days(location, days.clamp(1, MAX_FORECAST_DAYS))
+            .await
+    }
+
     /// Get forecast from Open-Meteo API (no API key required)
     async fn get_openmeteo_forecast(&self, location: &Location) -> Result<Forecast> {
-        // Build URL with parameters for both hourly and daily forecasts
+        self.get_openmeteo_forecast_days(location, FORECAST_DAYS)
+            .await
+    }
+
+    /// Get forecast from Open-Meteo API for a specific number of days
+    async fn get_openmeteo_forecast_days(&self, location: &Location, days: u8) -> Result<Forecast> {
         let url = format!(
-            "{}/forecast?latitude={}&longitude={}&hourly=temperature_2m,relative_humidity_2m,apparent_temperature,precipitation_probability,precipitation,rain,showers,snowfall,weather_code,cloud_cover,pressure_msl,surface_pressure,wind_speed_10m,wind_direction_10m,wind_gusts_10m&daily=weather_code,temperature_2m_max,temperature_2m_min,apparent_temperature_max,apparent_temperature_min,sunrise,sunset,uv_index_max,precipitation_sum,rain_sum,snowfall_sum,precipitation_probability_max,wind_speed_10m_max,wind_direction_10m_dominant&timezone=auto&current=temperature_2m,relative_humidity_2m,apparent_temperature,is_day,precipitation,rain,showers,snowfall,weather_code,cloud_cover,pressure_msl,surface_pressure,wind_speed_10m,wind_direction_10m,wind_gusts_10m",
-            OPENMETEO_BASE_URL, location.latitude, location.longitude
+            "{}/forecast?latitude={}&longitude={}{}{}&timezone=auto&wind_speed_unit=ms&forecast_days={}",
+            self.http.endpoints().openmeteo,
+            location.latitude,
+            location.longitude,
+            self.variables.query(),
+            self.model_query(),
+            days
         );
 
-        let response = self.client.get(&url).send().await?;
-        let json: Value = response.json().await?;
+        let json = self.http.get_json(&url).await?;
 
         if let Some(error) = json["error"].as_bool() {
             if error {
                 let reason = json["reason"].as_str().unwrap_or("Unknown error");
-                return Err(anyhow!("Open-Meteo API error: {}", reason));
+                return Err(WeatherError::new(
+                    ErrorKind::Provider,
+                    format!("Open-Meteo API error: {}", reason),
+                )
+                .into());
             }
         }
 
-        // Parse current weather
-        let current = self.parse_openmeteo_current(&json)?;
+        let mut forecast = self.parse_openmeteo_forecast(&json)?;
+        forecast.fetched_at = Some(Utc::now());
+        Ok(forecast)
+    }
+
+    /// Parse a complete Open-Meteo forecast response
+    ///
+    /// Wind speeds and gusts are normalized to m/s using the response's unit
+    /// blocks, whatever unit the API returned them in. Implausible values are
+    /// clamped before anything is rendered.
+    pub fn parse_openmeteo_forecast(&self, json: &Value) -> Result<Forecast> {
+        // Parse current weather, unless it was not requested
+        let current = if json["current"].is_null() {
+            None
+        } else {
+            Some(self.parse_openmeteo_current(json)?)
+        };
 
         // Parse hourly forecast
-        let hourly = self.parse_openmeteo_hourly(&json)?;
+        let hourly = self.parse_openmeteo_hourly(json)?;
 
         // Parse daily forecast
-        let daily = self.parse_openmeteo_daily(&json)?;
+        let daily = self.parse_openmeteo_daily(json)?;
 
         // Get timezone offset
         let timezone_offset = json["utc_offset_seconds"].as_i64().unwrap_or(0) as i32;
@@ -95,59 +327,95 @@ impl WeatherForecaster {
         let units = self.config.units.clone();
 
         // Create the Forecast object
-        Ok(Forecast {
-            current: Some(current),
+        let mut forecast = Forecast {
+            current,
             hourly,
             daily,
             timezone_offset,
             units,
-        })
+            fetched_at: None,
+        };
+        self.report_anomalies(validate_forecast(&mut forecast));
+        apply_forecast(&mut forecast, self.config.feels_like);
+        Ok(forecast)
+    }
+
+    /// Keep raw provider values only at the debug detail level
+    fn provider_extra(&self, values: impl FnOnce() -> Value) -> Option<Value> {
+        (self.config.detail_level == DetailLevel::Debug).then(values)
+    }
+
+    /// Print corrected data anomalies at the debug detail level
+    fn report_anomalies(&self, anomalies: Vec<String>) {
+        if self.config.detail_level == DetailLevel::Debug {
+            for anomaly in anomalies {
+                eprintln!("⚠️  Data check: {}", anomaly);
+            }
+        }
     }
 
     /// Get current weather from Open-Meteo API
     async fn get_openmeteo_current(&self, location: &Location) -> Result<CurrentWeather> {
-        // Build URL with parameters
+        // Sunrise and sunset come from the first forecast day
+        let current = if self.variables.current.is_empty() {
+            CURRENT_VARIABLES
+        } else {
+            &self.variables.current
+        };
         let url = format!(
-            "{}/forecast?latitude={}&longitude={}&current=temperature_2m,relative_humidity_2m,apparent_temperature,is_day,precipitation,rain,showers,snowfall,weather_code,cloud_cover,pressure_msl,surface_pressure,wind_speed_10m,wind_direction_10m,wind_gusts_10m&daily=sunrise,sunset&timezone=auto",
-            OPENMETEO_BASE_URL, location.latitude, location.longitude
+            "{}/forecast?latitude={}&longitude={}&current={}&daily=sunrise,sunset{}&wind_speed_unit=ms&timezone=auto",
+            self.http.endpoints().openmeteo,
+            location.latitude,
+            location.longitude,
+            current.join(","),
+            self.model_query()
         );
 
-        let response = self.client.get(&url).send().await?;
-        let json: Value = response.json().await?;
+        let json = self.http.get_json(&url).await?;
 
         if let Some(error) = json["error"].as_bool() {
             if error {
                 let reason = json["reason"].as_str().unwrap_or("Unknown error");
-                return Err(anyhow!("Open-Meteo API error: {}", reason));
+                return Err(WeatherError::new(
+                    ErrorKind::Provider,
+                    format!("Open-Meteo API error: {}", reason),
+                )
+                .into());
             }
         }
 
-        self.parse_openmeteo_current(&json)
+        let mut current = self.parse_openmeteo_current(&json)?;
+        self.report_anomalies(validate_current(&mut current));
+        apply_current(&mut current, self.config.feels_like);
+        Ok(current)
     }
 
     /// Parse current weather from Open-Meteo API response
     fn parse_openmeteo_current(&self, json: &Value) -> Result<CurrentWeather> {
         // Parse current weather
         let current = &json["current"];
+        let utc_offset = json["utc_offset_seconds"].as_i64().unwrap_or(0);
         let current_time = current["time"].as_str().unwrap_or_default();
-        let timestamp = match DateTime::parse_from_rfc3339(current_time) {
-            Ok(dt) => dt.with_timezone(&Utc),
-            Err(_) => Utc::now(),
-        };
+        let timestamp = parse_api_time(current_time, utc_offset).unwrap_or_else(Utc::now);
 
         // Parse weather variables
         let temp = current["temperature_2m"].as_f64().unwrap_or(0.0);
         let feels_like = current["apparent_temperature"].as_f64().unwrap_or(0.0);
-        let humidity = current["relative_humidity_2m"].as_f64().unwrap_or(0.0) as u8;
-        let pressure = current["surface_pressure"].as_f64().unwrap_or(0.0) as u32;
-        let wind_speed = current["wind_speed_10m"].as_f64().unwrap_or(0.0);
+        let humidity = Percent(current["relative_humidity_2m"].as_f64().unwrap_or(0.0));
+        let pressure = Hpa(current["surface_pressure"].as_f64().unwrap_or(0.0));
+        let wind_factor = wind_to_ms(&json["current_units"]["wind_speed_10m"]);
+        let wind_speed = current["wind_speed_10m"].as_f64().unwrap_or(0.0) * wind_factor;
         let wind_direction = current["wind_direction_10m"].as_f64().unwrap_or(0.0) as u16;
-        let clouds = current["cloud_cover"].as_f64().unwrap_or(0.0) as u8;
+        let wind_gusts = current["wind_gusts_10m"]
+            .as_f64()
+            .map(|gusts| gusts * wind_to_ms(&json["current_units"]["wind_gusts_10m"]));
+        let clouds = Percent(current["cloud_cover"].as_f64().unwrap_or(0.0));
         let weather_code = current["weather_code"].as_f64().unwrap_or(0.0) as u32;
         let is_day = current["is_day"].as_i64().unwrap_or(1) == 1;
 
         // Create weather condition from WMO code
         let main_condition = self.wmo_code_to_condition(weather_code);
+        let intensity = self.wmo_code_to_intensity(weather_code);
 
         // Create weather description
         let description = self.get_weather_description_from_wmo(weather_code, is_day);
@@ -170,14 +438,14 @@ impl WeatherForecaster {
             .and_then(|v| v.as_str())
             .unwrap_or_default();
 
-        let sunrise = match DateTime::parse_from_rfc3339(sunrise_time) {
-            Ok(dt) => dt.with_timezone(&Utc),
-            Err(_) => timestamp, // Fallback to current time
+        let sunrise = match parse_api_time(sunrise_time, utc_offset) {
+            Some(dt) => dt,
+            None => timestamp, // Fallback to current time
         };
 
-        let sunset = match DateTime::parse_from_rfc3339(sunset_time) {
-            Ok(dt) => dt.with_timezone(&Utc),
-            Err(_) => timestamp
+        let sunset = match parse_api_time(sunset_time, utc_offset) {
+            Some(dt) => dt,
+            None => timestamp
                 .checked_add_signed(Duration::hours(12))
                 .unwrap_or(timestamp), // Fallback to 12 hours later
         };
@@ -191,8 +459,12 @@ impl WeatherForecaster {
             pressure,
             wind_speed,
             wind_direction,
+            wind_gusts,
             conditions: vec![description],
             main_condition,
+            intensity,
+            raw_code: Some(weather_code),
+            provider_extra: self.provider_extra(|| current.clone()),
             visibility: 10000, // Default to good visibility
             clouds,
             uv_index: 0.0, // Not provided by Open-Meteo basic API
@@ -207,6 +479,10 @@ impl WeatherForecaster {
     /// Parse hourly forecast from Open-Meteo API
     fn parse_openmeteo_hourly(&self, json: &Value) -> Result<Vec<HourlyForecast>> {
         let hourly = &json["hourly"];
+        if hourly.is_null() {
+            return Ok(Vec::new());
+        }
+        let utc_offset = json["utc_offset_seconds"].as_i64().unwrap_or(0);
 
         // Get time array
         let times = hourly["time"]
@@ -227,6 +503,7 @@ impl WeatherForecaster {
         let wind_speed = hourly["wind_speed_10m"]
             .as_array()
             .ok_or_else(|| anyhow!("Missing wind speed data"))?;
+        let wind_factor = wind_to_ms(&json["hourly_units"]["wind_speed_10m"]);
         let wind_direction = hourly["wind_direction_10m"]
             .as_array()
             .ok_or_else(|| anyhow!("Missing wind direction data"))?;
@@ -244,22 +521,36 @@ impl WeatherForecaster {
         let rain = hourly["rain"].as_array().unwrap_or(&empty_vec_rain);
         let empty_vec_snow = Vec::new();
         let snow = hourly["snowfall"].as_array().unwrap_or(&empty_vec_snow);
+        let empty_vec_uv = Vec::new();
+        let uv_index = hourly["uv_index"].as_array().unwrap_or(&empty_vec_uv);
+        let empty_vec_radiation = Vec::new();
+        let shortwave = hourly["shortwave_radiation"]
+            .as_array()
+            .unwrap_or(&empty_vec_radiation);
+        let direct = hourly["direct_radiation"]
+            .as_array()
+            .unwrap_or(&empty_vec_radiation);
+        let empty_vec_freezing = Vec::new();
+        let freezing_level = hourly["freezing_level_height"]
+            .as_array()
+            .unwrap_or(&empty_vec_freezing);
+        let empty_vec_depth = Vec::new();
+        let snow_depth = hourly["snow_depth"].as_array().unwrap_or(&empty_vec_depth);
 
         let mut forecasts = Vec::new();
 
-        for (i, time) in times.iter().take(48).enumerate() {
-            // Limit to 48 hours (2 days)
+        for (i, time) in times.iter().enumerate() {
             let time_str = time.as_str().unwrap_or_default();
-            let timestamp = match DateTime::parse_from_rfc3339(time_str) {
-                Ok(dt) => dt.with_timezone(&Utc),
-                Err(_) => continue, // Skip invalid timestamps
+            let timestamp = match parse_api_time(time_str, utc_offset) {
+                Some(dt) => dt,
+                None => continue, // Skip invalid timestamps
             };
 
             let temp = temps.get(i).and_then(|v| v.as_f64()).unwrap_or(0.0);
             let feels = feels_like.get(i).and_then(|v| v.as_f64()).unwrap_or(0.0);
-            let hum = humidity.get(i).and_then(|v| v.as_f64()).unwrap_or(0.0) as u8;
-            let press = pressure.get(i).and_then(|v| v.as_f64()).unwrap_or(1013.0) as u32;
-            let wind_spd = wind_speed.get(i).and_then(|v| v.as_f64()).unwrap_or(0.0);
+            let hum = Percent(humidity.get(i).and_then(|v| v.as_f64()).unwrap_or(0.0));
+            let press = Hpa(pressure.get(i).and_then(|v| v.as_f64()).unwrap_or(1013.0));
+            let wind_spd = wind_speed.get(i).and_then(|v| v.as_f64()).unwrap_or(0.0) * wind_factor;
             let wind_dir = wind_direction
                 .get(i)
                 .and_then(|v| v.as_f64())
@@ -267,7 +558,7 @@ impl WeatherForecaster {
 
             let precipitation_prob = pop.get(i).and_then(|v| v.as_f64()).unwrap_or(0.0);
             let weather_code = weather_codes.get(i).and_then(|v| v.as_f64()).unwrap_or(0.0) as u32;
-            let cloud_cover = clouds.get(i).and_then(|v| v.as_f64()).unwrap_or(0.0) as u8;
+            let cloud_cover = Percent(clouds.get(i).and_then(|v| v.as_f64()).unwrap_or(0.0));
 
             let rain_amount = rain.get(i).and_then(|v| v.as_f64());
             let snow_amount = snow.get(i).and_then(|v| v.as_f64());
@@ -278,6 +569,7 @@ impl WeatherForecaster {
 
             // Get weather condition from WMO code
             let main_condition = self.wmo_code_to_condition(weather_code);
+            let intensity = self.wmo_code_to_intensity(weather_code);
 
             // Create weather description
             let description = self.get_weather_description_from_wmo(weather_code, is_day);
@@ -292,11 +584,29 @@ impl WeatherForecaster {
                 wind_direction: wind_dir,
                 conditions: vec![description],
                 main_condition,
+                intensity,
+                raw_code: Some(weather_code),
+                provider_extra: self.provider_extra(|| row_at(hourly, i)),
+                extra: self
+                    .variables
+                    .extra_hourly
+                    .iter()
+                    .filter_map(|name| Some((name.clone(), hourly[name].get(i)?.as_f64()?)))
+                    .collect(),
                 pop: precipitation_prob / 100.0, // Convert from percentage to 0-1 scale
                 visibility: 10000,               // Default to good visibility
                 clouds: cloud_cover,
                 rain: rain_amount,
                 snow: snow_amount,
+                uv_index: uv_index.get(i).and_then(|v| v.as_f64()),
+                shortwave_radiation: shortwave.get(i).and_then(|v| v.as_f64()),
+                direct_radiation: direct.get(i).and_then(|v| v.as_f64()),
+                freezing_level: freezing_level.get(i).and_then(|v| v.as_f64()),
+                // Open-Meteo reports metres
+                snow_depth: snow_depth
+                    .get(i)
+                    .and_then(|v| v.as_f64())
+                    .map(|m| m * 100.0),
             });
         }
 
@@ -306,6 +616,10 @@ impl WeatherForecaster {
     /// Parse daily forecast from Open-Meteo API
     fn parse_openmeteo_daily(&self, json: &Value) -> Result<Vec<DailyForecast>> {
         let daily = &json["daily"];
+        if daily.is_null() {
+            return Ok(Vec::new());
+        }
+        let utc_offset = json["utc_offset_seconds"].as_i64().unwrap_or(0);
 
         // Get date array
         let dates = daily["time"]
@@ -327,12 +641,18 @@ impl WeatherForecaster {
             .as_array()
             .ok_or_else(|| anyhow!("Missing min feels like data"))?;
         let empty_vec_precip_sum = Vec::new();
-        let _precip_sum = daily["precipitation_sum"]
+        let precip_sum = daily["precipitation_sum"]
             .as_array()
             .unwrap_or(&empty_vec_precip_sum);
         let wind_speed = daily["wind_speed_10m_max"]
             .as_array()
             .ok_or_else(|| anyhow!("Missing wind speed data"))?;
+        let wind_factor = wind_to_ms(&json["daily_units"]["wind_speed_10m_max"]);
+        let gust_factor = wind_to_ms(&json["daily_units"]["wind_gusts_10m_max"]);
+        let empty_vec_gusts = Vec::new();
+        let wind_gusts = daily["wind_gusts_10m_max"]
+            .as_array()
+            .unwrap_or(&empty_vec_gusts);
         let wind_direction = daily["wind_direction_10m_dominant"]
             .as_array()
             .ok_or_else(|| anyhow!("Missing wind direction data"))?;
@@ -356,8 +676,8 @@ impl WeatherForecaster {
 
         let mut forecasts = Vec::new();
 
-        for (i, date_value) in dates.iter().take(7).enumerate() {
-            // Limit to 7 days (1 week)
+        // The API returns as many days as requested with `forecast_days`
+        for (i, date_value) in dates.iter().enumerate() {
             let date_str = date_value.as_str().unwrap_or_default();
             let date = match DateTime::parse_from_rfc3339(&format!("{}T12:00:00Z", date_str)) {
                 Ok(dt) => dt.with_timezone(&Utc),
@@ -374,14 +694,14 @@ impl WeatherForecaster {
                 .and_then(|v| v.as_str())
                 .unwrap_or_default();
 
-            let sunrise = match DateTime::parse_from_rfc3339(sunrise_str) {
-                Ok(dt) => dt.with_timezone(&Utc),
-                Err(_) => date, // Fallback to noon
+            let sunrise = match parse_api_time(sunrise_str, utc_offset) {
+                Some(dt) => dt,
+                None => date, // Fallback to noon
             };
 
-            let sunset = match DateTime::parse_from_rfc3339(sunset_str) {
-                Ok(dt) => dt.with_timezone(&Utc),
-                Err(_) => date.checked_add_signed(Duration::hours(12)).unwrap_or(date), // Fallback to 12 hours later
+            let sunset = match parse_api_time(sunset_str, utc_offset) {
+                Some(dt) => dt,
+                None => date.checked_add_signed(Duration::hours(12)).unwrap_or(date), // Fallback to 12 hours later
             };
 
             let weather_code = weather_codes.get(i).and_then(|v| v.as_f64()).unwrap_or(0.0) as u32;
@@ -390,18 +710,24 @@ impl WeatherForecaster {
             let feels_like_day = feels_max.get(i).and_then(|v| v.as_f64()).unwrap_or(0.0);
             let feels_like_night = feels_min.get(i).and_then(|v| v.as_f64()).unwrap_or(0.0);
             let pop = precip_prob.get(i).and_then(|v| v.as_f64()).unwrap_or(0.0);
-            let wind_spd = wind_speed.get(i).and_then(|v| v.as_f64()).unwrap_or(0.0);
+            let wind_spd = wind_speed.get(i).and_then(|v| v.as_f64()).unwrap_or(0.0) * wind_factor;
             let wind_dir = wind_direction
                 .get(i)
                 .and_then(|v| v.as_f64())
                 .unwrap_or(0.0) as u16;
 
+            let gusts = wind_gusts
+                .get(i)
+                .and_then(|v| v.as_f64())
+                .map(|g| g * gust_factor);
             let rain_amount = rain_sum.get(i).and_then(|v| v.as_f64());
             let snow_amount = snow_sum.get(i).and_then(|v| v.as_f64());
+            let precipitation = precip_sum.get(i).and_then(|v| v.as_f64());
             let uv = uv_index.get(i).and_then(|v| v.as_f64()).unwrap_or(0.0);
 
             // Get weather condition from WMO code
             let main_condition = self.wmo_code_to_condition(weather_code);
+            let intensity = self.wmo_code_to_intensity(weather_code);
 
             // Create weather description
             let description = self.get_weather_description_from_wmo(weather_code, true);
@@ -418,16 +744,21 @@ impl WeatherForecaster {
                 temp_max: max,
                 feels_like_day,
                 feels_like_night,
-                pressure: 1013, // Default pressure as it's not provided in daily
-                humidity: 50,   // Default humidity as it's not provided in daily
+                pressure: Hpa(1013.0), // Default pressure as it's not provided in daily
+                humidity: Percent(50.0), // Default humidity as it's not provided in daily
                 wind_speed: wind_spd,
                 wind_direction: wind_dir,
+                wind_gusts: gusts,
                 conditions: vec![description],
                 main_condition,
-                clouds: 0,        // Not provided in daily forecast
-                pop: pop / 100.0, // Convert from percentage to 0-1 scale
+                intensity,
+                raw_code: Some(weather_code),
+                provider_extra: self.provider_extra(|| row_at(daily, i)),
+                clouds: Percent(0.0), // Not provided in daily forecast
+                pop: pop / 100.0,     // Convert from percentage to 0-1 scale
                 rain: rain_amount,
                 snow: snow_amount,
+                precipitation,
                 uv_index: uv,
             });
         }
@@ -435,22 +766,44 @@ impl WeatherForecaster {
         Ok(forecasts)
     }
 
+    /// Convert WMO weather code to the strength of its condition
+    ///
+    /// Codes without an intensity grade, such as clear sky, are moderate.
+    pub fn wmo_code_to_intensity(&self, code: u32) -> Intensity {
+        match code {
+            51 | 56 | 61 | 66 | 71 | 77 | 80 | 85 => Intensity::Light, // Light or slight
+            55 | 57 | 65 | 67 | 75 | 86 | 96 => Intensity::Heavy,      // Dense, heavy or with hail
+            18 | 33..=35 => Intensity::Heavy,                          // Squalls, severe duststorm
+            82 | 99 | 19 => Intensity::Violent, // Violent showers, heavy hail, funnel cloud
+            _ => Intensity::Moderate,
+        }
+    }
+
     /// Convert WMO weather code to our internal WeatherCondition
+    ///
+    /// Open-Meteo only sends codes 0–3 and 45–99. Dust (7–9, 30–35), squalls
+    /// (18) and funnel clouds (19) come from the full WMO 4677 table for
+    /// sources that report it; with Open-Meteo those canvas scenes only appear
+    /// with `--test-charts`.
     pub fn wmo_code_to_condition(&self, code: u32) -> WeatherCondition {
         match code {
-            0 => WeatherCondition::Clear,              // Clear sky
-            1..=3 => WeatherCondition::Clouds,         // Partly cloudy
-            45 | 48 => WeatherCondition::Fog,          // Fog
-            51..=55 => WeatherCondition::Drizzle,      // Drizzle
-            56 | 57 => WeatherCondition::Drizzle,      // Freezing Drizzle
-            61..=65 => WeatherCondition::Rain,         // Rain
-            66 | 67 => WeatherCondition::Rain,         // Freezing Rain
-            71..=75 => WeatherCondition::Snow,         // Snow
-            77 => WeatherCondition::Snow,              // Snow grains
-            80..=82 => WeatherCondition::Rain,         // Rain showers
-            85..=86 => WeatherCondition::Snow,         // Snow showers
-            95 => WeatherCondition::Thunderstorm,      // Thunderstorm
-            96 | 99 => WeatherCondition::Thunderstorm, // Thunderstorm with hail
+            0 => WeatherCondition::Clear,         // Clear sky
+            1..=3 => WeatherCondition::Clouds,    // Partly cloudy
+            7..=9 => WeatherCondition::Dust,      // Dust or sand raised by wind
+            18 => WeatherCondition::Squall,       // Squalls
+            19 => WeatherCondition::Tornado,      // Funnel cloud(s)
+            30..=35 => WeatherCondition::Dust,    // Duststorm or sandstorm
+            45 | 48 => WeatherCondition::Fog,     // Fog
+            51..=55 => WeatherCondition::Drizzle, // Drizzle
+            56 | 57 => WeatherCondition::Sleet,   // Freezing Drizzle
+            61..=65 => WeatherCondition::Rain,    // Rain
+            66 | 67 => WeatherCondition::Sleet,   // Freezing Rain
+            71..=75 => WeatherCondition::Snow,    // Snow
+            77 => WeatherCondition::Snow,         // Snow grains
+            80..=82 => WeatherCondition::Rain,    // Rain showers
+            85..=86 => WeatherCondition::Snow,    // Snow showers
+            95 => WeatherCondition::Thunderstorm, // Thunderstorm
+            96 | 99 => WeatherCondition::Hail,    // Thunderstorm with hail
             _ => WeatherCondition::Unknown,
         }
     }
@@ -466,18 +819,22 @@ impl WeatherForecaster {
                 if is_day { "03d" } else { "03n" },
             ),
             3 => ("Clouds", "Overcast", if is_day { "04d" } else { "04n" }),
+            7..=9 => ("Dust", "Blowing dust or sand", "50d"),
+            18 => ("Squall", "Squalls", "50d"),
+            19 => ("Tornado", "Funnel cloud", "50d"),
+            30..=35 => ("Dust", "Duststorm or sandstorm", "50d"),
             45 => ("Fog", "Fog", "50d"),
             48 => ("Fog", "Depositing rime fog", "50d"),
             51 => ("Drizzle", "Light drizzle", "09d"),
             53 => ("Drizzle", "Moderate drizzle", "09d"),
             55 => ("Drizzle", "Dense drizzle", "09d"),
-            56 => ("Drizzle", "Light freezing drizzle", "09d"),
-            57 => ("Drizzle", "Dense freezing drizzle", "09d"),
+            56 => ("Sleet", "Light freezing drizzle", "13d"),
+            57 => ("Sleet", "Dense freezing drizzle", "13d"),
             61 => ("Rain", "Slight rain", "10d"),
             63 => ("Rain", "Moderate rain", "10d"),
             65 => ("Rain", "Heavy rain", "10d"),
-            66 => ("Rain", "Light freezing rain", "10d"),
-            67 => ("Rain", "Heavy freezing rain", "10d"),
+            66 => ("Sleet", "Light freezing rain", "13d"),
+            67 => ("Sleet", "Heavy freezing rain", "13d"),
             71 => ("Snow", "Slight snow fall", "13d"),
             73 => ("Snow", "Moderate snow fall", "13d"),
             75 => ("Snow", "Heavy snow fall", "13d"),
@@ -488,8 +845,8 @@ impl WeatherForecaster {
             85 => ("Snow", "Slight snow showers", "13d"),
             86 => ("Snow", "Heavy snow showers", "13d"),
             95 => ("Thunderstorm", "Thunderstorm", "11d"),
-            96 => ("Thunderstorm", "Thunderstorm with slight hail", "11d"),
-            99 => ("Thunderstorm", "Thunderstorm with heavy hail", "11d"),
+            96 => ("Hail", "Thunderstorm with slight hail", "11d"),
+            99 => ("Hail", "Thunderstorm with heavy hail", "11d"),
             _ => ("Unknown", "Unknown weather condition", "50d"),
         };
 
@@ -501,3 +858,42 @@ impl WeatherForecaster {
         }
     }
 }
+
+/// Parse an Open-Meteo timestamp into UTC
+///
+/// With `timezone=auto` the API returns local times without an offset
+/// (e.g. `2024-06-01T05:12`), so the response's UTC offset is applied here.
+pub fn parse_api_time(value: &str, utc_offset_seconds: i64) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M")
+        .ok()
+        .map(|naive| naive.and_utc() - Duration::seconds(utc_offset_seconds))
+}
+
+/// Factor converting a wind speed in the given Open-Meteo unit to m/s
+///
+/// A missing unit is taken as m/s, the unit every request asks for.
+fn wind_to_ms(unit: &Value) -> f64 {
+    match unit.as_str().unwrap_or("m/s") {
+        "km/h" => 1.0 / 3.6,
+        "mp/h" | "mph" => 0.447_04,
+        "kn" => 0.514_444,
+        _ => 1.0,
+    }
+}
+
+/// Values of every array in an hourly or daily block at one index
+fn row_at(block: &Value, index: usize) -> Value {
+    block
+        .as_object()
+        .map(|arrays| {
+            arrays
+                .iter()
+                .filter_map(|(key, values)| Some((key.clone(), values.get(index)?.clone())))
+                .collect::<serde_json::Map<_, _>>()
+        })
+        .map_or(Value::Null, Value::Object)
+}
@@ -0,0 +1,72 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Serialize, Serializer};
+
+use crate::modules::config::FreshnessSettings;
+use crate::modules::ui::convert_to_local;
+
+/// How old the data shown is, against the `[freshness]` limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Staleness {
+    Fresh,
+    /// Past `warn_after`; shown in yellow
+    Aging,
+    /// Past `stale_after`; shown in red
+    Stale,
+}
+
+impl Staleness {
+    pub fn of(age: Duration, settings: &FreshnessSettings) -> Self {
+        if age >= Duration::minutes(settings.stale_after) {
+            Staleness::Stale
+        } else if age >= Duration::minutes(settings.warn_after) {
+            Staleness::Aging
+        } else {
+            Staleness::Fresh
+        }
+    }
+}
+
+/// Age in words: "just now", "3 min ago", "2 h ago", "3 days ago"
+pub fn relative_age(age: Duration) -> String {
+    match age.num_minutes() {
+        minutes if minutes < 1 => "just now".to_string(),
+        minutes if minutes < 60 => format!("{} min ago", minutes),
+        minutes if minutes < 48 * 60 => format!("{} h ago", minutes / 60),
+        minutes => format!("{} days ago", minutes / (24 * 60)),
+    }
+}
+
+/// When the data was fetched and how old it is
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Freshness {
+    pub fetched: DateTime<Utc>,
+    /// Whole minutes in JSON
+    #[serde(rename = "age_minutes", serialize_with = "minutes")]
+    pub age: Duration,
+    pub staleness: Staleness,
+}
+
+fn minutes<S: Serializer>(age: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_i64(age.num_minutes())
+}
+
+impl Freshness {
+    pub fn new(fetched: DateTime<Utc>, now: DateTime<Utc>, settings: &FreshnessSettings) -> Self {
+        let age = (now - fetched).max(Duration::zero());
+        Self {
+            fetched,
+            age,
+            staleness: Staleness::of(age, settings),
+        }
+    }
+
+    /// "data as of 14:05 (3 min ago)", in the location's time zone
+    pub fn describe(&self, timezone: &str) -> String {
+        format!(
+            "data as of {} ({})",
+            convert_to_local(&self.fetched, timezone).format("%H:%M"),
+            relative_age(self.age)
+        )
+    }
+}
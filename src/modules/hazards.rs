@@ -0,0 +1,251 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::modules::http::HttpContext;
+use crate::modules::types::Location;
+
+/// USGS earthquakes of magnitude 4.5 and above from the past week
+const EARTHQUAKES_URL: &str =
+    "https://earthquake.usgs.gov/earthquakes/feed/v1.0/summary/4.5_week.geojson";
+/// NASA EONET natural events (wildfires, volcanoes, storms, floods) still open
+const EVENTS_URL: &str = "https://eonet.gsfc.nasa.gov/api/v3/events?status=open&days=30";
+/// Earthquakes this far away are still worth mentioning
+pub const EARTHQUAKE_RADIUS_KM: f64 = 1000.0;
+/// Other hazards are only listed when they are close by
+pub const EVENT_RADIUS_KM: f64 = 500.0;
+/// The feeds change slowly compared to how often the report runs
+const CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Kind of natural hazard
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HazardKind {
+    Earthquake,
+    Wildfire,
+    Volcano,
+    Storm,
+    Flood,
+    Other,
+}
+
+impl HazardKind {
+    fn from_eonet(category: &str) -> Self {
+        match category {
+            "wildfires" => HazardKind::Wildfire,
+            "volcanoes" => HazardKind::Volcano,
+            "severeStorms" => HazardKind::Storm,
+            "floods" => HazardKind::Flood,
+            _ => HazardKind::Other,
+        }
+    }
+
+    pub fn get_emoji(&self) -> &'static str {
+        match self {
+            HazardKind::Earthquake => "🌍",
+            HazardKind::Wildfire => "🔥",
+            HazardKind::Volcano => "🌋",
+            HazardKind::Storm => "🌀",
+            HazardKind::Flood => "🌊",
+            HazardKind::Other => "⚠️",
+        }
+    }
+}
+
+impl fmt::Display for HazardKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            HazardKind::Earthquake => "Earthquake",
+            HazardKind::Wildfire => "Wildfire",
+            HazardKind::Volcano => "Volcano",
+            HazardKind::Storm => "Storm",
+            HazardKind::Flood => "Flood",
+            HazardKind::Other => "Hazard",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A recent hazard near the location
+#[derive(Debug, Clone, Serialize)]
+pub struct Hazard {
+    pub kind: HazardKind,
+    pub title: String,
+    pub time: DateTime<Utc>,
+    pub distance_km: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub magnitude: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Great-circle distance between two coordinates
+pub fn distance_km(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_lon = (lon2 - lon1).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+/// Earthquakes from a USGS GeoJSON feed within `EARTHQUAKE_RADIUS_KM`
+pub fn parse_earthquakes(json: &Value, location: &Location) -> Vec<Hazard> {
+    let Some(features) = json["features"].as_array() else {
+        return Vec::new();
+    };
+    features
+        .iter()
+        .filter_map(|feature| {
+            let coordinates = feature["geometry"]["coordinates"].as_array()?;
+            let longitude = coordinates.first()?.as_f64()?;
+            let latitude = coordinates.get(1)?.as_f64()?;
+            let distance = distance_km(location.latitude, location.longitude, latitude, longitude);
+            if distance > EARTHQUAKE_RADIUS_KM {
+                return None;
+            }
+            let properties = &feature["properties"];
+            Some(Hazard {
+                kind: HazardKind::Earthquake,
+                title: properties["place"]
+                    .as_str()
+                    .unwrap_or("Unknown place")
+                    .to_string(),
+                time: DateTime::from_timestamp_millis(properties["time"].as_i64()?)?,
+                distance_km: distance,
+                magnitude: properties["mag"].as_f64(),
+                url: properties["url"].as_str().map(str::to_string),
+            })
+        })
+        .collect()
+}
+
+/// Open EONET events within `EVENT_RADIUS_KM`, placed at their latest position
+pub fn parse_events(json: &Value, location: &Location) -> Vec<Hazard> {
+    let Some(events) = json["events"].as_array() else {
+        return Vec::new();
+    };
+    events
+        .iter()
+        .filter_map(|event| {
+            // Storms move; the last point is where the event is now
+            let geometry = event["geometry"]
+                .as_array()?
+                .iter()
+                .rev()
+                .find(|g| g["type"] == "Point")?;
+            let longitude = geometry["coordinates"][0].as_f64()?;
+            let latitude = geometry["coordinates"][1].as_f64()?;
+            let distance = distance_km(location.latitude, location.longitude, latitude, longitude);
+            if distance > EVENT_RADIUS_KM {
+                return None;
+            }
+            Some(Hazard {
+                kind: HazardKind::from_eonet(event["categories"][0]["id"].as_str().unwrap_or("")),
+                title: event["title"].as_str()?.to_string(),
+                time: geometry["date"].as_str()?.parse().ok()?,
+                distance_km: distance,
+                magnitude: None,
+                url: event["sources"][0]["url"].as_str().map(str::to_string),
+            })
+        })
+        .collect()
+}
+
+/// Fetches the hazard feeds, keeping each response on disk for an hour
+#[derive(Clone)]
+pub struct HazardService {
+    http: HttpContext,
+    cache_dir: Option<PathBuf>,
+}
+
+impl HazardService {
+    /// Service caching in `cache_dir`, or not at all when it is `None`
+    pub fn new(http: HttpContext, cache_dir: Option<PathBuf>) -> Self {
+        Self { http, cache_dir }
+    }
+
+    /// Hazards near the location, newest first
+    ///
+    /// A feed that cannot be reached falls back to its last cached copy, even
+    /// a stale one, and is skipped without one.
+    pub async fn nearby(&self, location: &Location) -> Vec<Hazard> {
+        let (earthquakes, events) = tokio::join!(
+            self.feed("earthquakes", EARTHQUAKES_URL),
+            self.feed("events", EVENTS_URL)
+        );
+
+        let mut hazards = Vec::new();
+        if let Some(json) = earthquakes {
+            hazards.extend(parse_earthquakes(&json, location));
+        }
+        if let Some(json) = events {
+            hazards.extend(parse_events(&json, location));
+        }
+        hazards.sort_by_key(|hazard| std::cmp::Reverse(hazard.time));
+        hazards
+    }
+
+    async fn feed(&self, name: &str, url: &str) -> Option<Value> {
+        let path = self
+            .cache_dir
+            .as_ref()
+            .map(|dir| dir.join(format!("{}.json", name)));
+
+        if let Some(path) = &path {
+            if is_fresh(path, SystemTime::now()) {
+                if let Some(json) = read_cache(path) {
+                    return Some(json);
+                }
+            }
+        }
+
+        match self.fetch(url).await {
+            Ok(json) => {
+                if let Some(path) = &path {
+                    // Caching is best effort
+                    let _ = write_cache(path, &json);
+                }
+                Some(json)
+            }
+            Err(_) => path.as_deref().and_then(read_cache),
+        }
+    }
+
+    async fn fetch(&self, url: &str) -> Result<Value> {
+        self.http
+            .get(url)
+            .await
+            .context("Could not reach the hazard feed")?
+            .error_for_status()?
+            .json()
+            .await
+            .context("Invalid hazard feed")
+    }
+}
+
+/// Whether a cached feed was written less than an hour before `now`
+pub fn is_fresh(path: &Path, now: SystemTime) -> bool {
+    fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|modified| now.duration_since(modified).ok())
+        .is_some_and(|age| age < CACHE_TTL)
+}
+
+fn read_cache(path: &Path) -> Option<Value> {
+    serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+fn write_cache(path: &Path, json: &Value) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, json.to_string())?;
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde_json::{json, Value};
+use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use crate::modules::config::{HookSettings, ThresholdSettings};
+use crate::modules::push::threshold_alerts;
+use crate::modules::types::{HourlyForecast, Location, WeatherAlert};
+use crate::modules::utils::Language;
+
+/// Rain starting within this many hours counts as "soon"
+pub const RAIN_SOON_HOURS: i64 = 2;
+/// Hooks still running after this long are killed
+const HOOK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Events that can trigger a `[hooks]` command
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookEvent {
+    /// A new forecast was fetched
+    Refresh,
+    /// Rain is expected within `RAIN_SOON_HOURS`
+    RainSoon,
+    /// A severe weather or threshold alert was raised
+    Alert,
+    /// The scene colors changed with the weather or time of day
+    Palette,
+}
+
+impl HookEvent {
+    /// Name passed in `WEATHER_MAN_EVENT` and the payload's `event` field
+    pub fn name(&self) -> &'static str {
+        match self {
+            HookEvent::Refresh => "refresh",
+            HookEvent::RainSoon => "rain_soon",
+            HookEvent::Alert => "alert",
+            HookEvent::Palette => "palette",
+        }
+    }
+}
+
+/// Runs the user's `[hooks]` commands
+#[derive(Debug, Clone, Default)]
+pub struct Hooks {
+    settings: HookSettings,
+}
+
+impl Hooks {
+    pub fn new(settings: HookSettings) -> Self {
+        Self { settings }
+    }
+
+    /// Whether no hook is configured
+    pub fn is_empty(&self) -> bool {
+        HOOK_EVENTS
+            .iter()
+            .all(|event| self.command(*event).is_none())
+    }
+
+    fn command(&self, event: HookEvent) -> Option<&str> {
+        match event {
+            HookEvent::Refresh => self.settings.on_refresh.as_deref(),
+            HookEvent::RainSoon => self.settings.on_rain_soon.as_deref(),
+            HookEvent::Alert => self.settings.on_alert.as_deref(),
+            HookEvent::Palette => self.settings.on_palette.as_deref(),
+        }
+        .filter(|command| !command.trim().is_empty())
+    }
+
+    /// Run the hook for an event, if one is configured
+    pub async fn fire(&self, event: HookEvent, location: &Location, data: Value) -> Result<()> {
+        match self.command(event) {
+            Some(command) => run_hook(command, event, &hook_payload(event, location, data)).await,
+            None => Ok(()),
+        }
+    }
+}
+
+const HOOK_EVENTS: [HookEvent; 4] = [
+    HookEvent::Refresh,
+    HookEvent::RainSoon,
+    HookEvent::Alert,
+    HookEvent::Palette,
+];
+
+/// JSON written to a hook's stdin
+pub fn hook_payload(event: HookEvent, location: &Location, data: Value) -> Value {
+    json!({
+        "event": event.name(),
+        "time": Utc::now(),
+        "location": location,
+        "data": data,
+    })
+}
+
+/// Run a hook through the shell with the payload on stdin
+///
+/// The event name is also set in `WEATHER_MAN_EVENT`. Fails if the command
+/// exits unsuccessfully or runs longer than a minute.
+pub async fn run_hook(command: &str, event: HookEvent, payload: &Value) -> Result<()> {
+    let (shell, flag) = if cfg!(windows) {
+        ("cmd", "/C")
+    } else {
+        ("sh", "-c")
+    };
+    let mut child = Command::new(shell)
+        .arg(flag)
+        .arg(command)
+        .env("WEATHER_MAN_EVENT", event.name())
+        .stdin(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .with_context(|| format!("Could not run the {} hook", event.name()))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // Hooks that ignore stdin may exit before reading it
+        let _ = stdin.write_all(payload.to_string().as_bytes()).await;
+    }
+
+    let status = tokio::time::timeout(HOOK_TIMEOUT, child.wait())
+        .await
+        .map_err(|_| anyhow!("The {} hook timed out", event.name()))??;
+    if !status.success() {
+        return Err(anyhow!("The {} hook failed ({})", event.name(), status));
+    }
+    Ok(())
+}
+
+/// The rain alert starting within `RAIN_SOON_HOURS` of `now`, if any
+pub fn rain_soon(
+    hourly: &[HourlyForecast],
+    location: &Location,
+    thresholds: &ThresholdSettings,
+    now: DateTime<Utc>,
+    language: Language,
+) -> Option<WeatherAlert> {
+    let upcoming: Vec<HourlyForecast> = hourly
+        .iter()
+        .filter(|h| h.timestamp + Duration::hours(1) > now)
+        .cloned()
+        .collect();
+    threshold_alerts(
+        &upcoming,
+        location,
+        RAIN_SOON_HOURS as usize + 1,
+        thresholds,
+        now,
+        language,
+    )
+    .into_iter()
+    .find(|alert| {
+        alert.tags.iter().any(|tag| tag == "rain")
+            && alert.start <= now + Duration::hours(RAIN_SOON_HOURS)
+    })
+}
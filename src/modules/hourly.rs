@@ -0,0 +1,134 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Timelike, Utc};
+
+use crate::modules::forecaster::MAX_FORECAST_DAYS;
+use crate::modules::types::HourlyForecast;
+use crate::modules::ui::{convert_from_local, convert_to_local};
+
+/// Hours shown by the hourly table
+pub const HOURLY_WINDOW: usize = 24;
+
+/// Day the hourly table starts on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartDay {
+    Today,
+    Tomorrow,
+    Date(NaiveDate),
+}
+
+/// Where the hourly table starts, from `--start` or `--date`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HourlyStart {
+    /// The current hour
+    Now,
+    /// An hour of a day, local time
+    At { day: StartDay, hour: u32 },
+}
+
+/// Parse `now`, `today`, `tomorrow` or a date (YYYY-MM-DD), optionally
+/// followed by an hour such as `14`, `14:00` or `2pm`; an hour alone is today
+pub fn parse_start(value: &str) -> Result<HourlyStart, String> {
+    let value = value.trim().to_lowercase();
+    let invalid = || {
+        format!(
+            "'{}' is not a start, expected e.g. now, tomorrow, 'tomorrow 14:00' or 2024-06-01",
+            value
+        )
+    };
+    if value == "now" {
+        return Ok(HourlyStart::Now);
+    }
+
+    let mut parts = value.split_whitespace();
+    let first = parts.next().ok_or_else(invalid)?;
+    let (day, hour) = match first {
+        "today" => (StartDay::Today, parts.next()),
+        "tomorrow" => (StartDay::Tomorrow, parts.next()),
+        _ => match NaiveDate::parse_from_str(first, "%Y-%m-%d") {
+            Ok(date) => (StartDay::Date(date), parts.next()),
+            Err(_) => (StartDay::Today, Some(first)),
+        },
+    };
+    if parts.next().is_some() {
+        return Err(invalid());
+    }
+    let hour = match hour {
+        Some(hour) => parse_hour(hour).ok_or_else(invalid)?,
+        None => 0,
+    };
+    Ok(HourlyStart::At { day, hour })
+}
+
+/// `14`, `14:00` or `2pm`
+fn parse_hour(value: &str) -> Option<u32> {
+    let (number, pm) = match (value.strip_suffix("am"), value.strip_suffix("pm")) {
+        (Some(number), _) => (number, Some(false)),
+        (_, Some(number)) => (number, Some(true)),
+        _ => (value, None),
+    };
+    let hour = match NaiveTime::parse_from_str(number, "%H:%M") {
+        Ok(time) => time.hour(),
+        Err(_) => number.parse().ok()?,
+    };
+    match pm {
+        Some(pm) if (1..=12).contains(&hour) => Some(hour % 12 + if pm { 12 } else { 0 }),
+        Some(_) => None,
+        None => (hour < 24).then_some(hour),
+    }
+}
+
+/// `--date YYYY-MM-DD`, the table from midnight of that day
+pub fn parse_date(value: &str) -> Result<HourlyStart, String> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+        .map(|date| HourlyStart::At {
+            day: StartDay::Date(date),
+            hour: 0,
+        })
+        .map_err(|_| format!("'{}' is not a date, expected YYYY-MM-DD", value))
+}
+
+impl HourlyStart {
+    /// The first hour of the table, in UTC
+    pub fn resolve(&self, timezone: &str, now: DateTime<Utc>) -> DateTime<Utc> {
+        match *self {
+            HourlyStart::Now => now
+                .with_minute(0)
+                .and_then(|t| t.with_second(0))
+                .and_then(|t| t.with_nanosecond(0))
+                .unwrap_or(now),
+            HourlyStart::At { day, hour } => {
+                let today = convert_to_local(&now, timezone).date_naive();
+                let date = match day {
+                    StartDay::Today => today,
+                    StartDay::Tomorrow => today + Duration::days(1),
+                    StartDay::Date(date) => date,
+                };
+                let time = date.and_hms_opt(hour, 0, 0).unwrap_or_default();
+                convert_from_local(time, timezone)
+            }
+        }
+    }
+}
+
+/// Forecast days needed to show the window from `start`, or `None` when it
+/// lies in the past or beyond the 16 days Open-Meteo forecasts
+pub fn days_needed(start: DateTime<Utc>, timezone: &str, now: DateTime<Utc>) -> Option<u8> {
+    let today = convert_to_local(&now, timezone).date_naive();
+    let end = start + Duration::hours(HOURLY_WINDOW as i64 - 1);
+    let first = convert_to_local(&start, timezone).date_naive();
+    let last = convert_to_local(&end, timezone).date_naive();
+    if first < today || (first - today).num_days() >= MAX_FORECAST_DAYS as i64 {
+        return None;
+    }
+    let days = (last - today).num_days() + 1;
+    Some(days.min(MAX_FORECAST_DAYS as i64) as u8)
+}
+
+/// The hours of the table: `HOURLY_WINDOW` hours from `start`
+pub fn hourly_window(hourly: &[HourlyForecast], start: DateTime<Utc>) -> Vec<HourlyForecast> {
+    hourly
+        .iter()
+        .filter(|h| h.timestamp >= start)
+        .take(HOURLY_WINDOW)
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,232 @@
+use anyhow::{Context, Result};
+use reqwest::{Certificate, Client, ClientBuilder, Proxy, RequestBuilder, Response, StatusCode};
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use url::Url;
+
+use crate::modules::config::ApiSettings;
+use crate::modules::forecaster::OPENMETEO_BASE_URL;
+use crate::modules::location::NOMINATIM_BASE_URL;
+
+/// Sent with every request; Nominatim rejects requests without one
+pub const USER_AGENT: &str = concat!("weather_man/", env!("CARGO_PKG_VERSION"));
+/// Time allowed for a whole request, including reading the body
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(20);
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How often failed GET requests are retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Attempts in total, including the first
+    pub attempts: u32,
+    /// Wait before the first retry, doubled for each further one
+    pub backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Wait before retry number `retry` (0 for the first retry)
+    pub fn delay(&self, retry: u32) -> Duration {
+        self.backoff * 2u32.saturating_pow(retry)
+    }
+
+    /// Whether a response status is worth another attempt
+    pub fn should_retry(status: StatusCode) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            attempts: 3,
+            backoff: Duration::from_millis(500),
+        }
+    }
+}
+
+/// Base URLs of the APIs that can be self-hosted or mirrored
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Endpoints {
+    /// Open-Meteo, without a trailing slash (e.g. `https://api.open-meteo.com/v1`)
+    pub openmeteo: String,
+    /// Nominatim, without a trailing slash
+    pub nominatim: String,
+}
+
+impl Endpoints {
+    /// Endpoints from the `[api]` settings, falling back to the public hosts
+    pub fn from_settings(settings: &ApiSettings) -> Result<Self> {
+        let base = |value: &Option<String>, default: &str, name: &str| match value {
+            Some(url) => parse_base_url(url).with_context(|| format!("Invalid {} URL", name)),
+            None => Ok(default.to_string()),
+        };
+        Ok(Self {
+            openmeteo: base(&settings.openmeteo_url, OPENMETEO_BASE_URL, "Open-Meteo")?,
+            nominatim: base(&settings.nominatim_url, NOMINATIM_BASE_URL, "Nominatim")?,
+        })
+    }
+}
+
+impl Default for Endpoints {
+    fn default() -> Self {
+        Self {
+            openmeteo: OPENMETEO_BASE_URL.to_string(),
+            nominatim: NOMINATIM_BASE_URL.to_string(),
+        }
+    }
+}
+
+/// Check an API base URL and strip its trailing slash
+///
+/// IPv6 hosts go in brackets, e.g. `http://[::1]:8080/v1`.
+pub fn parse_base_url(value: &str) -> Result<String> {
+    let url = Url::parse(value.trim()).with_context(|| format!("'{}' is not a URL", value))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        anyhow::bail!("'{}' must start with http:// or https://", value);
+    }
+    if url.host().is_none() {
+        anyhow::bail!("'{}' has no host", value);
+    }
+    if url.query().is_some() || url.fragment().is_some() {
+        anyhow::bail!("'{}' must not contain a query or fragment", value);
+    }
+    Ok(url.as_str().trim_end_matches('/').to_string())
+}
+
+/// HTTP client shared by all services, so keep-alive connections, the user
+/// agent, timeouts and the retry policy are set up in one place
+///
+/// Cloning is cheap and shares the connection pool.
+#[derive(Clone)]
+pub struct HttpContext {
+    client: Client,
+    retry: RetryPolicy,
+    endpoints: Endpoints,
+}
+
+impl HttpContext {
+    pub fn new(timeout: Duration, retry: RetryPolicy) -> Self {
+        let client = client_builder(timeout).build().unwrap_or_default();
+
+        Self {
+            client,
+            retry,
+            endpoints: Endpoints::default(),
+        }
+    }
+
+    /// Context for networks that need an explicit proxy or trust a custom CA
+    ///
+    /// Without `proxy`, the HTTPS_PROXY, HTTP_PROXY and NO_PROXY environment
+    /// variables apply. `ca_cert` is a PEM bundle or a DER certificate added to
+    /// the system roots, e.g. for TLS-intercepting firewalls.
+    pub fn with_network(proxy: Option<&str>, ca_cert: Option<&Path>) -> Result<Self> {
+        let mut builder = client_builder(DEFAULT_TIMEOUT);
+        if let Some(proxy) = proxy {
+            builder = builder.proxy(
+                Proxy::all(proxy).with_context(|| format!("Invalid proxy URL '{}'", proxy))?,
+            );
+        }
+        if let Some(path) = ca_cert {
+            for certificate in read_certificates(path)? {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+
+        Ok(Self {
+            client: builder
+                .build()
+                .context("Could not set up the HTTP client")?,
+            retry: RetryPolicy::default(),
+            endpoints: Endpoints::default(),
+        })
+    }
+
+    /// Send API requests to these hosts instead of the public ones
+    pub fn with_endpoints(mut self, endpoints: Endpoints) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
+    pub fn endpoints(&self) -> &Endpoints {
+        &self.endpoints
+    }
+
+    /// The underlying client, for requests that should not be retried
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// GET a URL, retrying connection errors, timeouts, 429 and 5xx responses
+    ///
+    /// The last attempt's response is returned whatever its status.
+    pub async fn get(&self, url: &str) -> reqwest::Result<Response> {
+        let mut retry = 0;
+        loop {
+            let result = self.client.get(url).send().await;
+            let retryable = match &result {
+                Ok(response) => RetryPolicy::should_retry(response.status()),
+                Err(e) => e.is_connect() || e.is_timeout(),
+            };
+            if !retryable || retry + 1 >= self.retry.attempts {
+                return result;
+            }
+            tokio::time::sleep(self.retry.delay(retry)).await;
+            retry += 1;
+        }
+    }
+
+    /// GET a URL and parse the body as JSON
+    pub async fn get_json(&self, url: &str) -> Result<Value> {
+        self.get(url)
+            .await?
+            .json()
+            .await
+            .with_context(|| format!("Invalid JSON from {}", host(url)))
+    }
+
+    /// Start a POST request; posts are not retried
+    pub fn post(&self, url: &str) -> RequestBuilder {
+        self.client.post(url)
+    }
+}
+
+impl Default for HttpContext {
+    fn default() -> Self {
+        Self::new(DEFAULT_TIMEOUT, RetryPolicy::default())
+    }
+}
+
+fn client_builder(timeout: Duration) -> ClientBuilder {
+    Client::builder()
+        .user_agent(USER_AGENT)
+        .timeout(timeout)
+        .connect_timeout(CONNECT_TIMEOUT.min(timeout))
+}
+
+/// Certificates from a PEM bundle, or a single DER certificate
+pub fn read_certificates(path: &Path) -> Result<Vec<Certificate>> {
+    let bytes = fs::read(path)
+        .with_context(|| format!("Could not read CA certificate {}", path.display()))?;
+    let certificates = if bytes.starts_with(b"-----BEGIN") {
+        Certificate::from_pem_bundle(&bytes)
+    } else {
+        Certificate::from_der(&bytes).map(|certificate| vec![certificate])
+    }
+    .with_context(|| format!("Invalid CA certificate {}", path.display()))?;
+
+    if certificates.is_empty() {
+        anyhow::bail!("No certificates found in {}", path.display());
+    }
+    Ok(certificates)
+}
+
+/// Host part of a URL, for error messages without query parameters
+fn host(url: &str) -> &str {
+    url.split("://")
+        .nth(1)
+        .and_then(|rest| rest.split('/').next())
+        .unwrap_or(url)
+}
@@ -0,0 +1,75 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::modules::types::WeatherCondition;
+
+/// Glyphs used for weather conditions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum IconSet {
+    /// Color emoji; their width varies between terminals
+    #[default]
+    Emoji,
+    /// Weather Icons glyphs from a Nerd Font, one cell wide
+    Nerdfont,
+    /// Plain ASCII characters, one cell wide
+    Ascii,
+}
+
+impl IconSet {
+    /// Icon for a weather condition
+    pub fn condition(&self, condition: WeatherCondition) -> &'static str {
+        match self {
+            IconSet::Emoji => condition.get_emoji(),
+            IconSet::Nerdfont => nerdfont(condition),
+            IconSet::Ascii => ascii(condition),
+        }
+    }
+}
+
+/// Glyphs from the Nerd Fonts weather range (nf-weather-*)
+fn nerdfont(condition: WeatherCondition) -> &'static str {
+    match condition {
+        WeatherCondition::Clear => "\u{e30d}",        // day_sunny
+        WeatherCondition::Clouds => "\u{e312}",       // cloudy
+        WeatherCondition::Rain => "\u{e318}",         // rain
+        WeatherCondition::Drizzle => "\u{e31b}",      // sprinkle
+        WeatherCondition::Thunderstorm => "\u{e31d}", // thunderstorm
+        WeatherCondition::Snow => "\u{e31a}",         // snow
+        WeatherCondition::Sleet => "\u{e316}",        // rain_mix
+        WeatherCondition::Hail => "\u{e314}",         // hail
+        WeatherCondition::Mist
+        | WeatherCondition::Fog
+        | WeatherCondition::Smoke
+        | WeatherCondition::Haze
+        | WeatherCondition::Dust
+        | WeatherCondition::Sand
+        | WeatherCondition::Ash => "\u{e313}", // fog
+        WeatherCondition::Squall => "\u{e311}",       // cloudy_windy
+        WeatherCondition::Tornado => "\u{e310}",      // cloudy_gusts
+        WeatherCondition::Unknown => "\u{f128}",      // nf-fa-question
+    }
+}
+
+fn ascii(condition: WeatherCondition) -> &'static str {
+    match condition {
+        WeatherCondition::Clear => "o",
+        WeatherCondition::Clouds => "=",
+        WeatherCondition::Rain => "/",
+        WeatherCondition::Drizzle => ",",
+        WeatherCondition::Thunderstorm => "!",
+        WeatherCondition::Snow => "*",
+        WeatherCondition::Sleet => "%",
+        WeatherCondition::Hail => ":",
+        WeatherCondition::Mist
+        | WeatherCondition::Fog
+        | WeatherCondition::Smoke
+        | WeatherCondition::Haze
+        | WeatherCondition::Dust
+        | WeatherCondition::Sand
+        | WeatherCondition::Ash => "~",
+        WeatherCondition::Squall => ">",
+        WeatherCondition::Tornado => "@",
+        WeatherCondition::Unknown => "?",
+    }
+}
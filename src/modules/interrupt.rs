@@ -0,0 +1,31 @@
+use anyhow::{Context, Result};
+use crossterm::terminal::disable_raw_mode;
+use std::io::{IsTerminal, Write};
+
+/// Exit code after Ctrl-C, as shells report a SIGINT (128 + 2)
+pub const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// Escape sequences undoing what spinners, prompts and colored output may
+/// leave behind: reset colors, show the cursor and clear the current line
+pub const TERMINAL_RESET: &str = "\x1b[0m\x1b[?25h\r\x1b[2K";
+
+/// Exit cleanly on Ctrl-C during fetches and animations, restoring the
+/// terminal first; the TUI reads Ctrl-C as a key and restores it on drop
+pub fn install_interrupt_handler() -> Result<()> {
+    ctrlc::set_handler(|| {
+        restore_terminal();
+        eprintln!("Interrupted");
+        std::process::exit(INTERRUPTED_EXIT_CODE);
+    })
+    .context("Could not install the Ctrl-C handler")
+}
+
+/// Put the terminal back into its normal state
+pub fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let mut stderr = std::io::stderr();
+    if stderr.is_terminal() {
+        let _ = stderr.write_all(TERMINAL_RESET.as_bytes());
+        let _ = stderr.flush();
+    }
+}
@@ -0,0 +1,90 @@
+use crate::modules::activity::{best_window, BestWindow, HourScore};
+use crate::modules::types::HourlyForecast;
+use serde::Serialize;
+use std::fmt;
+
+/// Hours of forecast considered for drying
+pub const LAUNDRY_HOURS: usize = 12;
+/// Length of the hanging window to look for
+pub const HANGING_HOURS: usize = 3;
+
+/// Overall verdict for drying laundry outdoors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum DryingVerdict {
+    Great,
+    Good,
+    Slow,
+    Indoors,
+}
+
+impl DryingVerdict {
+    fn from_score(score: f64) -> Self {
+        match score {
+            s if s >= 70.0 => DryingVerdict::Great,
+            s if s >= 50.0 => DryingVerdict::Good,
+            s if s >= 30.0 => DryingVerdict::Slow,
+            _ => DryingVerdict::Indoors,
+        }
+    }
+
+    pub fn get_emoji(&self) -> &'static str {
+        match self {
+            DryingVerdict::Great => "🌞",
+            DryingVerdict::Good => "👕",
+            DryingVerdict::Slow => "🐌",
+            DryingVerdict::Indoors => "🏠",
+        }
+    }
+}
+
+impl fmt::Display for DryingVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            DryingVerdict::Great => "Great drying weather",
+            DryingVerdict::Good => "Good enough to hang outside",
+            DryingVerdict::Slow => "Slow drying, hang early or under cover",
+            DryingVerdict::Indoors => "Dry indoors today",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// Drying outlook for the next hours
+#[derive(Debug, Clone, Serialize)]
+pub struct LaundryReport {
+    pub verdict: DryingVerdict,
+    pub best_window: Option<BestWindow>,
+    pub scores: Vec<HourScore>,
+}
+
+/// Drying score from 0 (won't dry) to 100 for one hour
+///
+/// Dry air gives up to 50 points (0.5 per % below 100 % humidity), warmth up to
+/// 25 (0–30 °C) and wind up to 25 (0–8 m/s); the sum is scaled by the chance
+/// that it stays dry.
+pub fn drying_score(hour: &HourlyForecast) -> u8 {
+    let dryness = (100.0 - hour.humidity.0).clamp(0.0, 100.0) * 0.5;
+    let warmth = hour.temperature.clamp(0.0, 30.0) / 30.0 * 25.0;
+    let breeze = hour.wind_speed.clamp(0.0, 8.0) / 8.0 * 25.0;
+
+    ((dryness + warmth + breeze) * (1.0 - hour.pop.clamp(0.0, 1.0))).round() as u8
+}
+
+/// Score the next `LAUNDRY_HOURS` hours and pick the best hanging window
+pub fn laundry_report(hourly_data: &[HourlyForecast]) -> LaundryReport {
+    let scores: Vec<HourScore> = hourly_data
+        .iter()
+        .take(LAUNDRY_HOURS)
+        .map(|hour| HourScore {
+            timestamp: hour.timestamp,
+            score: drying_score(hour),
+        })
+        .collect();
+    let best = best_window(&scores, HANGING_HOURS.min(scores.len()));
+
+    LaundryReport {
+        verdict: DryingVerdict::from_score(best.as_ref().map_or(0.0, |w| w.average_score)),
+        best_window: best,
+        scores,
+    }
+}
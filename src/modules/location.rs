@@ -1,70 +1,200 @@
 use anyhow::Result;
-use reqwest::Client;
+use futures::stream::{FuturesUnordered, StreamExt};
 use serde_json::Value;
-use std::time::Duration;
-
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+use crate::modules::errors::{ErrorKind, WeatherError};
+use crate::modules::http::HttpContext;
+use crate::modules::privacy::coarse_location;
+use crate::modules::timezones::timezone_at;
 use crate::modules::types::Location;
 
+/// Public Nominatim instance used for geocoding
+pub const NOMINATIM_BASE_URL: &str = "https://nominatim.openstreetmap.org";
+/// Nominatim's usage policy allows at most one request per second
+const NOMINATIM_INTERVAL: Duration = Duration::from_secs(1);
+
+/// IP geolocation services, asked concurrently
+pub const IP_SERVICES: [&str; 4] = [
+    "https://ipapi.co/json/",
+    "https://ipinfo.io/json",
+    "https://freegeoip.app/json/",
+    "https://extreme-ip-lookup.com/json/",
+];
+
+/// How the location shown was chosen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocationOrigin {
+    /// Given with --location or in the config file
+    Pinned,
+    /// Detected from the IP address
+    Detected,
+}
+
+impl std::fmt::Display for LocationOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            LocationOrigin::Pinned => "pinned",
+            LocationOrigin::Detected => "auto-detected",
+        })
+    }
+}
+
+/// Token bucket limiting how often a service is called
+#[derive(Debug, Clone)]
+pub struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    interval: Duration,
+    last: Instant,
+}
+
+impl TokenBucket {
+    /// Full bucket of `capacity` tokens, refilled by one every `interval`
+    pub fn new(capacity: u32, interval: Duration, now: Instant) -> Self {
+        Self {
+            capacity: capacity as f64,
+            tokens: capacity as f64,
+            interval,
+            last: now,
+        }
+    }
+
+    /// Take a token at `now`, returning how long to wait before using it
+    ///
+    /// Tokens taken from an empty bucket are owed, so back-to-back callers
+    /// wait one interval longer each.
+    pub fn take(&mut self, now: Instant) -> Duration {
+        let elapsed = now.saturating_duration_since(self.last);
+        self.tokens =
+            (self.tokens + elapsed.as_secs_f64() / self.interval.as_secs_f64()).min(self.capacity);
+        self.last = now.max(self.last);
+        self.tokens -= 1.0;
+
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            self.interval.mul_f64(-self.tokens)
+        }
+    }
+}
+
+/// Geocoding state shared by clones of a `LocationService`
+struct Geocoder {
+    bucket: TokenBucket,
+    /// Locations by lowercased query
+    cache: HashMap<String, Location>,
+}
+
 /// Handles location detection and queries
 #[derive(Clone)]
 pub struct LocationService {
-    client: Client,
+    http: HttpContext,
+    geocoder: Arc<Mutex<Geocoder>>,
+    /// Never detect the location from the IP address, look up timezones
+    /// offline only and round the coordinates returned
+    privacy: bool,
 }
 
 impl LocationService {
-    /// Create a new location service
-    pub fn new() -> Self {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(10))
-            .build()
-            .unwrap_or_default();
-
-        Self { client }
+    /// Create a location service sending its requests through `http`
+    pub fn new(http: HttpContext) -> Self {
+        Self {
+            http,
+            geocoder: Arc::new(Mutex::new(Geocoder {
+                bucket: TokenBucket::new(1, NOMINATIM_INTERVAL, Instant::now()),
+                cache: HashMap::new(),
+            })),
+            privacy: false,
+        }
+    }
+
+    /// Keep lookups private, for shared environments
+    pub fn with_privacy(mut self, enabled: bool) -> Self {
+        self.privacy = enabled;
+        self
     }
 
     /// Get location from user's IP address
     pub async fn get_location_from_ip(&self) -> Result<Location> {
-        // Try multiple IP geolocation services for redundancy
-        let services = vec![
-            "https://ipapi.co/json/",
-            "https://ipinfo.io/json",
-            "https://freegeoip.app/json/",
-            "https://extreme-ip-lookup.com/json/",
-        ];
-
-        for service_url in services {
-            match self.client.get(service_url).send().await {
-                Ok(response) => {
-                    if let Ok(json) = response.json::<Value>().await {
-                        if let Some(location) = self.parse_location_from_json(json) {
-                            return Ok(location);
-                        }
-                    }
-                }
-                Err(_) => continue,
+        if self.privacy {
+            return Err(WeatherError::new(
+                ErrorKind::InvalidInput,
+                "IP-based location is disabled in privacy mode; use --location",
+            )
+            .into());
+        }
+        self.get_location_from_services(&IP_SERVICES).await
+    }
+
+    /// Ask all IP geolocation services at once and take the first usable answer
+    ///
+    /// Requests still in flight are dropped, so one slow service no longer
+    /// holds up detection until it times out.
+    pub async fn get_location_from_services(&self, services: &[&str]) -> Result<Location> {
+        let mut pending: FuturesUnordered<_> = services
+            .iter()
+            .map(|url| self.ask_ip_service(url))
+            .collect();
+
+        while let Some(answer) = pending.next().await {
+            if let Some(location) = answer {
+                return Ok(location);
             }
         }
 
-        // Fallback to a default location if all services fail
-        Err(anyhow::anyhow!("Could not detect location from IP address"))
+        Err(WeatherError::new(
+            ErrorKind::GeocodingFailed,
+            "Could not detect location from IP address",
+        )
+        .into())
+    }
+
+    async fn ask_ip_service(&self, url: &str) -> Option<Location> {
+        let response = self.http.client().get(url).send().await.ok()?;
+        let json = response.json::<Value>().await.ok()?;
+        self.parse_location_from_json(json)
     }
 
     /// Get location by name (city, address, etc)
+    ///
+    /// Lookups are cached and run one at a time, within Nominatim's rate limit.
     pub async fn get_location_by_name(&self, location_name: &str) -> Result<Location> {
+        let key = location_name.trim().to_lowercase();
+        let mut geocoder = self.geocoder.lock().await;
+        if let Some(location) = geocoder.cache.get(&key) {
+            return Ok(location.clone());
+        }
+
+        let mut location = self.geocode(&mut geocoder.bucket, location_name).await?;
+        if self.privacy {
+            location = coarse_location(location);
+        }
+        geocoder.cache.insert(key, location.clone());
+        Ok(location)
+    }
+
+    /// GET a Nominatim URL once the rate limit allows it
+    async fn nominatim(&self, bucket: &mut TokenBucket, url: &str) -> Result<Value> {
+        let wait = bucket.take(Instant::now());
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+        Ok(self.http.get(url).await?.json().await?)
+    }
+
+    async fn geocode(&self, bucket: &mut TokenBucket, location_name: &str) -> Result<Location> {
         // Use OpenStreetMap/Nominatim for geocoding
         let url = format!(
-            "https://nominatim.openstreetmap.org/search?q={}&format=json&limit=1",
+            "{}/search?q={}&format=json&limit=1&addressdetails=1",
+            self.http.endpoints().nominatim,
             urlencoding::encode(location_name)
         );
 
-        let response = self
-            .client
-            .get(&url)
-            .header("User-Agent", "weather_man/0.0.6")
-            .send()
-            .await?;
-
-        let json: Value = response.json().await?;
+        let json = self.nominatim(bucket, &url).await?;
 
         if let Some(place) = json.as_array().and_then(|arr| arr.first()) {
             let lat = place["lat"]
@@ -80,36 +210,40 @@ impl LocationService {
                 .unwrap_or("Unknown")
                 .to_string();
 
+            // The address details usually make reverse geocoding unnecessary
+            if let Some(location) = self.location_from_search(place, lat, lon, &name) {
+                return Ok(location);
+            }
+
             // Get more details using reverse geocoding
-            return self.get_detailed_location(lat, lon, Some(name)).await;
+            return self
+                .get_detailed_location(bucket, lat, lon, Some(name))
+                .await;
         }
 
-        Err(anyhow::anyhow!(
-            "Could not find location: {}",
-            location_name
-        ))
+        Err(WeatherError::new(
+            ErrorKind::GeocodingFailed,
+            format!("Could not find location: {}", location_name),
+        )
+        .into())
     }
 
     /// Get detailed location info from coordinates
     async fn get_detailed_location(
         &self,
+        bucket: &mut TokenBucket,
         lat: f64,
         lon: f64,
         name_override: Option<String>,
     ) -> Result<Location> {
         let url = format!(
-            "https://nominatim.openstreetmap.org/reverse?lat={}&lon={}&format=json",
-            lat, lon
+            "{}/reverse?lat={}&lon={}&format=json",
+            self.http.endpoints().nominatim,
+            lat,
+            lon
         );
 
-        let response = self
-            .client
-            .get(&url)
-            .header("User-Agent", "weather_man/0.0.6")
-            .send()
-            .await?;
-
-        let json: Value = response.json().await?;
+        let json = self.nominatim(bucket, &url).await?;
 
         let address = &json["address"];
 
@@ -129,8 +263,7 @@ impl LocationService {
         let state = address["state"].as_str().map(|s| s.to_string());
         let region = address["region"].as_str().map(|s| s.to_string());
 
-        // Get timezone from coordinates
-        let timezone = self.get_timezone(lat, lon).await?;
+        let timezone = timezone_at(lat, lon);
 
         Ok(Location {
             name: name_override.unwrap_or_else(|| city.to_string()),
@@ -141,26 +274,37 @@ impl LocationService {
             timezone,
             region,
             state,
+            display_timezone: None,
         })
     }
 
-    /// Get timezone from coordinates
-    async fn get_timezone(&self, lat: f64, lon: f64) -> Result<String> {
-        let url = format!(
-            "http://api.geonames.org/timezoneJSON?lat={}&lng={}&username=weather_man",
-            lat, lon
-        );
-
-        if let Ok(response) = self.client.get(&url).send().await {
-            if let Ok(json) = response.json::<Value>().await {
-                if let Some(tz) = json["timezoneId"].as_str() {
-                    return Ok(tz.to_string());
-                }
-            }
-        }
+    /// Location from a search result's address details, skipping reverse
+    /// geocoding
+    ///
+    /// `None` when the country is missing. The timezone is looked up offline.
+    fn location_from_search(
+        &self,
+        place: &Value,
+        lat: f64,
+        lon: f64,
+        name: &str,
+    ) -> Option<Location> {
+        let address = &place["address"];
+        let country = address["country"].as_str()?;
+        let country_code = address["country_code"].as_str()?;
+        let timezone = timezone_at(lat, lon);
 
-        // Fallback to a simple timezone estimation
-        Ok("UTC".to_string())
+        Some(Location {
+            name: name.to_string(),
+            country: country.to_string(),
+            country_code: country_code.to_uppercase(),
+            latitude: lat,
+            longitude: lon,
+            timezone,
+            region: address["region"].as_str().map(|s| s.to_string()),
+            state: address["state"].as_str().map(|s| s.to_string()),
+            display_timezone: None,
+        })
     }
 
     /// Parse location from various IP geolocation service responses
@@ -204,7 +348,10 @@ impl LocationService {
             .or_else(|| json["regionName"].as_str())
             .map(|s| s.to_string());
 
-        let timezone = json["timezone"].as_str().unwrap_or("UTC").to_string();
+        let timezone = match json["timezone"].as_str() {
+            Some(timezone) => timezone.to_string(),
+            None => timezone_at(latitude, longitude),
+        };
 
         Some(Location {
             name: city.to_string(),
@@ -215,12 +362,13 @@ impl LocationService {
             timezone,
             region,
             state: None,
+            display_timezone: None,
         })
     }
 }
 
 impl Default for LocationService {
     fn default() -> Self {
-        Self::new()
+        Self::new(HttpContext::default())
     }
 }
@@ -0,0 +1,310 @@
+use anyhow::{anyhow, Context, Result};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio_native_tls::{native_tls, TlsConnector};
+
+use crate::modules::errors::{ErrorKind, WeatherError};
+
+/// Longest wait for the connection or any reply of the mail server
+const SMTP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How the connection to the mail server is encrypted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpSecurity {
+    /// Upgrade a plain connection with STARTTLS (port 587)
+    #[default]
+    Starttls,
+    /// TLS from the start (port 465)
+    Tls,
+    /// No encryption, for a relay on the local network (port 25)
+    None,
+}
+
+impl SmtpSecurity {
+    pub fn default_port(self) -> u16 {
+        match self {
+            SmtpSecurity::Starttls => 587,
+            SmtpSecurity::Tls => 465,
+            SmtpSecurity::None => 25,
+        }
+    }
+}
+
+/// Mail server and account used to send the digest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SmtpServer {
+    pub host: String,
+    pub port: u16,
+    pub security: SmtpSecurity,
+    /// Account name and password for AUTH PLAIN, if the server needs them
+    pub credentials: Option<(String, String)>,
+}
+
+impl SmtpServer {
+    /// Parse `host` or `host:port`, using the security's usual port when none is given
+    pub fn parse(server: &str, security: SmtpSecurity) -> Result<Self> {
+        let (host, port) = match server.rsplit_once(':') {
+            Some((host, port)) => {
+                let port = port.parse().map_err(|_| {
+                    WeatherError::new(
+                        ErrorKind::InvalidInput,
+                        format!("'{}' is not a valid SMTP port", port),
+                    )
+                })?;
+                (host, port)
+            }
+            None => (server, security.default_port()),
+        };
+        if host.is_empty() {
+            return Err(
+                WeatherError::new(ErrorKind::InvalidInput, "No SMTP server host given").into(),
+            );
+        }
+        Ok(Self {
+            host: host.to_string(),
+            port,
+            security,
+            credentials: None,
+        })
+    }
+
+    pub fn with_credentials(mut self, username: &str, password: &str) -> Self {
+        self.credentials = Some((username.to_string(), password.to_string()));
+        self
+    }
+}
+
+/// The sender for the digest: `from` when given, else the SMTP account when
+/// it is a mail address, else the recipient
+///
+/// Fails when none of them is an address, since servers reject a bare name
+/// in `MAIL FROM`.
+pub fn sender_address(
+    from: Option<&str>,
+    server: Option<&SmtpServer>,
+    to: Option<&str>,
+) -> Result<String> {
+    let account = server
+        .and_then(|server| server.credentials.as_ref())
+        .map(|(username, _)| username.as_str())
+        .filter(|username| username.contains('@'));
+    let sender = from.or(account).or(to).ok_or_else(|| {
+        WeatherError::new(
+            ErrorKind::InvalidInput,
+            "Give --from, or `from` in the [email] config section, as the sender",
+        )
+    })?;
+    if !address(sender).contains('@') {
+        return Err(WeatherError::new(
+            ErrorKind::InvalidInput,
+            format!("'{}' is not a mail address to send from", sender),
+        )
+        .into());
+    }
+    Ok(sender.to_string())
+}
+
+/// A plain-text UTF-8 mail in Internet Message Format, with LF line endings
+/// as `sendmail -t` expects
+pub fn compose_email(
+    from: &str,
+    to: Option<&str>,
+    subject: &str,
+    body: &str,
+    now: DateTime<Utc>,
+) -> String {
+    let mut message = format!("From: {}\n", from);
+    if let Some(to) = to {
+        message.push_str(&format!("To: {}\n", to));
+    }
+    message.push_str(&format!("Subject: {}\n", encode_header(subject)));
+    message.push_str(&format!("Date: {}\n", now.to_rfc2822()));
+    message.push_str(&format!(
+        "Message-ID: <{}.{:08x}@weather_man>\n",
+        now.timestamp(),
+        rand::random::<u32>()
+    ));
+    message.push_str("MIME-Version: 1.0\n");
+    message.push_str("Content-Type: text/plain; charset=utf-8\n");
+    message.push_str("Content-Transfer-Encoding: 8bit\n\n");
+    message.push_str(body);
+    if !body.ends_with('\n') {
+        message.push('\n');
+    }
+    message
+}
+
+/// RFC 2047 encoded word for header values that are not plain ASCII
+pub fn encode_header(value: &str) -> String {
+    if value.is_ascii() {
+        value.to_string()
+    } else {
+        format!("=?UTF-8?B?{}?=", STANDARD.encode(value))
+    }
+}
+
+/// Send a composed mail through an SMTP server
+pub async fn send_email(server: &SmtpServer, from: &str, to: &str, message: &str) -> Result<()> {
+    let connect = TcpStream::connect((server.host.as_str(), server.port));
+    let tcp = match tokio::time::timeout(SMTP_TIMEOUT, connect).await {
+        Ok(Ok(tcp)) => tcp,
+        Ok(Err(e)) => {
+            return Err(WeatherError::new(
+                ErrorKind::Network,
+                format!(
+                    "Could not connect to {}:{}: {}",
+                    server.host, server.port, e
+                ),
+            )
+            .into())
+        }
+        Err(_) => {
+            return Err(WeatherError::new(
+                ErrorKind::Network,
+                format!("Timed out connecting to {}", server.host),
+            )
+            .into())
+        }
+    };
+
+    match server.security {
+        SmtpSecurity::Tls => {
+            let tls = tls_connector()?.connect(&server.host, tcp).await?;
+            let mut session = SmtpSession::new(tls);
+            session.reply(220).await?;
+            session.deliver(server, from, to, message).await
+        }
+        SmtpSecurity::Starttls => {
+            let mut session = SmtpSession::new(tcp);
+            session.reply(220).await?;
+            session.ehlo().await?;
+            session.command("STARTTLS", 220).await?;
+            let tcp = session.stream.into_inner();
+            let tls = tls_connector()?.connect(&server.host, tcp).await?;
+            SmtpSession::new(tls)
+                .deliver(server, from, to, message)
+                .await
+        }
+        SmtpSecurity::None => {
+            let mut session = SmtpSession::new(tcp);
+            session.reply(220).await?;
+            session.deliver(server, from, to, message).await
+        }
+    }
+}
+
+fn tls_connector() -> Result<TlsConnector> {
+    Ok(native_tls::TlsConnector::new()?.into())
+}
+
+/// One conversation with the mail server
+struct SmtpSession<S> {
+    stream: BufReader<S>,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> SmtpSession<S> {
+    fn new(stream: S) -> Self {
+        Self {
+            stream: BufReader::new(stream),
+        }
+    }
+
+    /// Greet the server, log in if there are credentials and hand over the mail
+    async fn deliver(
+        &mut self,
+        server: &SmtpServer,
+        from: &str,
+        to: &str,
+        message: &str,
+    ) -> Result<()> {
+        self.ehlo().await?;
+        if let Some((username, password)) = &server.credentials {
+            let token = STANDARD.encode(format!("\0{}\0{}", username, password));
+            self.command(&format!("AUTH PLAIN {}", token), 235)
+                .await
+                .context("The mail server did not accept the login")?;
+        }
+        self.command(&format!("MAIL FROM:<{}>", address(from)), 250)
+            .await?;
+        self.command(&format!("RCPT TO:<{}>", address(to)), 250)
+            .await?;
+        self.command("DATA", 354).await?;
+        self.command(&dot_stuff(message), 250).await?;
+        self.command("QUIT", 221).await
+    }
+
+    async fn ehlo(&mut self) -> Result<()> {
+        self.command("EHLO localhost", 250).await
+    }
+
+    /// Send a line and wait for the expected reply code
+    async fn command(&mut self, line: &str, expected: u16) -> Result<()> {
+        self.stream
+            .write_all(format!("{}\r\n", line).as_bytes())
+            .await?;
+        self.stream.flush().await?;
+        self.reply(expected).await
+    }
+
+    /// Read a possibly multi-line reply and check its code
+    async fn reply(&mut self, expected: u16) -> Result<()> {
+        let mut text = Vec::new();
+        loop {
+            let mut line = String::new();
+            let read = tokio::time::timeout(SMTP_TIMEOUT, self.stream.read_line(&mut line))
+                .await
+                .map_err(|_| {
+                    WeatherError::new(ErrorKind::Network, "Timed out waiting for the mail server")
+                })??;
+            if read == 0 {
+                return Err(anyhow!("The mail server closed the connection"));
+            }
+            let line = line.trim_end();
+            let code: u16 = line
+                .get(..3)
+                .and_then(|code| code.parse().ok())
+                .unwrap_or(0);
+            text.push(line.get(4..).unwrap_or("").to_string());
+            // "250-" continues a reply, "250 " ends it
+            if line.as_bytes().get(3) != Some(&b'-') {
+                if code != expected {
+                    return Err(WeatherError::new(
+                        ErrorKind::Provider,
+                        format!("Mail server error {}: {}", code, text.join(" ")),
+                    )
+                    .into());
+                }
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// The address in `Name <user@example.com>`, or the whole value
+fn address(mailbox: &str) -> &str {
+    match (mailbox.find('<'), mailbox.rfind('>')) {
+        (Some(start), Some(end)) if start < end => &mailbox[start + 1..end],
+        _ => mailbox.trim(),
+    }
+}
+
+/// The message body for DATA: CRLF line endings, lines starting with a dot
+/// doubled, ending with the lone dot
+pub fn dot_stuff(message: &str) -> String {
+    let mut data = String::new();
+    for line in message.lines() {
+        if line.starts_with('.') {
+            data.push('.');
+        }
+        data.push_str(line);
+        data.push_str("\r\n");
+    }
+    data.push('.');
+    data
+}
@@ -0,0 +1,159 @@
+use crate::modules::types::{HourlyForecast, Location};
+use crate::modules::ui::convert_to_local;
+use crate::modules::utils::wind_barb;
+use chrono::Timelike;
+
+/// Lines used for the temperature curve
+const TEMP_ROWS: usize = 8;
+/// Lines used for the precipitation bars
+const PRECIP_ROWS: usize = 3;
+/// Width of the row labels on the left
+const LABEL_WIDTH: usize = 8;
+
+/// Render a text meteogram with one column per hour
+///
+/// From top to bottom: temperature curve, precipitation bars (mm), cloud-cover
+/// band, wind barbs and speed, and the time axis in local time.
+pub fn render_meteogram(
+    hourly_data: &[HourlyForecast],
+    location: &Location,
+    hours: usize,
+) -> Vec<String> {
+    let data: Vec<&HourlyForecast> = hourly_data.iter().take(hours).collect();
+    let mut lines = vec![format!(
+        "Meteogram: {}, {} (next {}h)",
+        location.name,
+        location.country_code,
+        data.len()
+    )];
+
+    if data.is_empty() {
+        lines.push("No hourly forecast data available".to_string());
+        return lines;
+    }
+
+    // Temperature curve
+    let min = data.iter().map(|h| h.temperature).fold(f64::MAX, f64::min);
+    let max = data.iter().map(|h| h.temperature).fold(f64::MIN, f64::max);
+    let range = (max - min).max(1.0);
+    let levels: Vec<usize> = data
+        .iter()
+        .map(|h| (((h.temperature - min) / range) * (TEMP_ROWS - 1) as f64).round() as usize)
+        .collect();
+
+    for row in (0..TEMP_ROWS).rev() {
+        let label = match row {
+            r if r == TEMP_ROWS - 1 => format!("{:>5.0}°C", min + range),
+            0 => format!("{:>5.0}°C", min),
+            _ => String::new(),
+        };
+        let cells: String = levels
+            .iter()
+            .map(|level| if *level == row { '•' } else { ' ' })
+            .collect();
+        lines.push(format!("{:<w$}│{}", label, cells, w = LABEL_WIDTH - 1));
+    }
+
+    // Precipitation bars, each line holding eight eighths
+    const EIGHTHS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let amounts: Vec<f64> = data
+        .iter()
+        .map(|h| h.rain.unwrap_or(0.0) + h.snow.unwrap_or(0.0))
+        .collect();
+    let scale = amounts.iter().cloned().fold(1.0, f64::max);
+    for row in (0..PRECIP_ROWS).rev() {
+        let label = match row {
+            r if r == PRECIP_ROWS - 1 => format!("{:>4.1}mm", scale),
+            _ => String::new(),
+        };
+        let cells: String = amounts
+            .iter()
+            .map(|amount| {
+                let eighths = (amount / scale * (PRECIP_ROWS * 8) as f64).round() as usize;
+                EIGHTHS[eighths.saturating_sub(row * 8).min(8)]
+            })
+            .collect();
+        lines.push(format!("{:<w$}│{}", label, cells, w = LABEL_WIDTH - 1));
+    }
+
+    // Cloud cover band
+    let band: String = data
+        .iter()
+        .map(|h| match h.clouds.0 {
+            c if c < 12.5 => ' ',
+            c if c < 37.5 => '░',
+            c if c < 62.5 => '▒',
+            c if c < 87.5 => '▓',
+            _ => '█',
+        })
+        .collect();
+    lines.push(format!("{:<w$}│{}", "Clouds", band, w = LABEL_WIDTH - 1));
+
+    // Wind barbs and speed every third hour
+    lines.push(format!(
+        "{:<w$}│{}",
+        "Wind",
+        sparse_labels(&data, 3, |h| wind_barb(h.wind_speed, h.wind_direction)),
+        w = LABEL_WIDTH - 1
+    ));
+    lines.push(format!(
+        "{:<w$}│{}",
+        "m/s",
+        sparse_labels(&data, 3, |h| format!("{:.0}", h.wind_speed)),
+        w = LABEL_WIDTH - 1
+    ));
+
+    // Time axis
+    let local: Vec<_> = data
+        .iter()
+        .map(|h| convert_to_local(&h.timestamp, location.clock_timezone()))
+        .collect();
+    let mut ticks = String::new();
+    let mut hour_labels = vec![' '; data.len()];
+    let mut day_labels = vec![' '; data.len()];
+    for (i, time) in local.iter().enumerate() {
+        ticks.push(if time.hour() == 0 { '┼' } else { '─' });
+        if time.hour() % 6 == 0 {
+            write_at(&mut hour_labels, i, &format!("{:02}", time.hour()));
+        }
+        if time.hour() == 0 || i == 0 {
+            write_at(&mut day_labels, i, &time.format("%a %d").to_string());
+        }
+    }
+    lines.push(format!("{}┴{}", "─".repeat(LABEL_WIDTH - 1), ticks));
+    lines.push(format!(
+        "{}{}",
+        " ".repeat(LABEL_WIDTH),
+        hour_labels.into_iter().collect::<String>()
+    ));
+    lines.push(format!(
+        "{}{}",
+        " ".repeat(LABEL_WIDTH),
+        day_labels.into_iter().collect::<String>()
+    ));
+
+    lines
+        .into_iter()
+        .map(|line| line.trim_end().to_string())
+        .collect()
+}
+
+/// A row with a label every `step` columns
+fn sparse_labels(
+    data: &[&HourlyForecast],
+    step: usize,
+    label: impl Fn(&HourlyForecast) -> String,
+) -> String {
+    let mut row = vec![' '; data.len()];
+    for (i, hour) in data.iter().enumerate().step_by(step) {
+        write_at(&mut row, i, &label(hour));
+    }
+    row.into_iter().collect()
+}
+
+/// Write text into a row of cells, clipped at the end of the row
+fn write_at(row: &mut [char], start: usize, text: &str) {
+    for (cell, c) in row.iter_mut().skip(start).zip(text.chars()) {
+        *cell = c;
+    }
+}
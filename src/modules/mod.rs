@@ -1,10 +1,79 @@
 // Modules for the weather_man project
+pub mod accumulation;
+pub mod accuracy;
+pub mod activity;
+pub mod agriculture;
+pub mod art;
+pub mod aviation;
+pub mod batch;
+pub mod big;
+pub mod cache;
 pub mod canvas;
+pub mod check;
+pub mod climate;
+pub mod comfort;
+pub mod commands;
+pub mod compare;
+pub mod config;
+pub mod dashboard;
+pub mod diff;
+pub mod digest;
+pub mod energy;
+pub mod ensemble;
+pub mod errors;
+pub mod events;
+pub mod export;
+pub mod fire;
 pub mod forecaster;
+pub mod freshness;
+pub mod hazards;
+pub mod hooks;
+pub mod hourly;
+pub mod http;
+pub mod icons;
+pub mod interrupt;
+pub mod laundry;
 pub mod location;
+pub mod mail;
+pub mod meteogram;
+pub mod models;
+pub mod pager;
+pub mod palette;
+pub mod paths;
+pub mod privacy;
+pub mod profile;
+pub mod progress;
+pub mod push;
+pub mod query;
+pub mod radar;
+pub mod reminders;
+pub mod risk;
+pub mod roads;
+pub mod runner;
+pub mod safety;
+pub mod screenshot;
+pub mod search;
+pub mod sections;
+pub mod setup;
+pub mod speech;
+pub mod stats;
+pub mod status;
+pub mod summary;
+pub mod sun;
+pub mod table;
+pub mod template;
+pub mod timezones;
+pub mod tmux;
 pub mod tui;
 pub mod types;
 pub mod ui;
 pub mod utils;
+pub mod uv;
+pub mod validate;
+pub mod warnings;
+pub mod waybar;
+pub mod widget;
+pub mod winter;
+pub mod yearly;
 
 // We don't need to re-export types as they're already accessible via the modules
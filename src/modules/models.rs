@@ -0,0 +1,163 @@
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::modules::forecaster::parse_api_time;
+use crate::modules::http::HttpContext;
+use crate::modules::types::Location;
+
+/// Models compared when `--models` is not given: ECMWF, DWD ICON and NOAA GFS
+pub const DEFAULT_MODELS: [&str; 3] = ["ecmwf_ifs025", "icon_seamless", "gfs_seamless"];
+/// Hours compared by `--mode models`
+pub const MODEL_HOURS: usize = 48;
+/// Temperature spread (°) between models that counts as a disagreement
+pub const DISAGREEMENT: f64 = 3.0;
+
+/// Parse a comma-separated list of 2-3 Open-Meteo model names
+pub fn parse_models(value: &str) -> Result<Vec<String>, String> {
+    let models: Vec<String> = value
+        .split(',')
+        .map(|model| model.trim().to_lowercase())
+        .filter(|model| !model.is_empty())
+        .collect();
+    if !(2..=3).contains(&models.len()) {
+        return Err("give 2 or 3 models, e.g. ecmwf_ifs025,icon_seamless".to_string());
+    }
+    if let Some(model) = models
+        .iter()
+        .find(|model| !model.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'))
+    {
+        return Err(format!("'{}' is not a model name", model));
+    }
+    Ok(models)
+}
+
+/// One model's hourly values; `None` where the model has no data
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ModelSeries {
+    pub model: String,
+    pub temperature: Vec<Option<f64>>,
+    pub precipitation: Vec<Option<f64>>,
+    pub wind_speed: Vec<Option<f64>>,
+}
+
+/// The same hours from several models
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ModelComparison {
+    pub times: Vec<DateTime<Utc>>,
+    pub models: Vec<ModelSeries>,
+}
+
+impl ModelComparison {
+    /// Only the `hours` hours from the one containing `now`
+    pub fn upcoming(mut self, now: DateTime<Utc>, hours: usize) -> Self {
+        let past = self
+            .times
+            .iter()
+            .take_while(|time| **time + Duration::hours(1) <= now)
+            .count();
+        fn keep<T>(values: &mut Vec<T>, past: usize, hours: usize) {
+            values.drain(..past.min(values.len()));
+            values.truncate(hours);
+        }
+        keep(&mut self.times, past, hours);
+        for model in &mut self.models {
+            keep(&mut model.temperature, past, hours);
+            keep(&mut model.precipitation, past, hours);
+            keep(&mut model.wind_speed, past, hours);
+        }
+        self
+    }
+
+    /// Difference between the warmest and coldest model at hour `index`
+    pub fn temperature_spread(&self, index: usize) -> Option<f64> {
+        let values: Vec<f64> = self
+            .models
+            .iter()
+            .filter_map(|m| *m.temperature.get(index)?)
+            .collect();
+        if values.len() < 2 {
+            return None;
+        }
+        let max = values.iter().copied().fold(f64::MIN, f64::max);
+        let min = values.iter().copied().fold(f64::MAX, f64::min);
+        Some(max - min)
+    }
+}
+
+/// Parse a forecast response requested with several `models`, where each
+/// variable comes back suffixed with the model name
+pub fn parse_model_comparison(json: &Value, models: &[String]) -> Result<ModelComparison> {
+    let utc_offset = json["utc_offset_seconds"].as_i64().unwrap_or(0);
+    let hourly = &json["hourly"];
+    let times: Vec<DateTime<Utc>> = hourly["time"]
+        .as_array()
+        .ok_or_else(|| anyhow!("Missing hourly time data"))?
+        .iter()
+        .filter_map(|time| parse_api_time(time.as_str()?, utc_offset))
+        .collect();
+
+    let series = |variable: &str, model: &str| -> Vec<Option<f64>> {
+        let values = &hourly[format!("{}_{}", variable, model)];
+        (0..times.len()).map(|i| values[i].as_f64()).collect()
+    };
+
+    let models = models
+        .iter()
+        .map(|model| ModelSeries {
+            model: model.clone(),
+            temperature: series("temperature_2m", model),
+            precipitation: series("precipitation", model),
+            wind_speed: series("wind_speed_10m", model),
+        })
+        .collect::<Vec<_>>();
+    if models
+        .iter()
+        .all(|m| m.temperature.iter().all(Option::is_none))
+    {
+        return Err(anyhow!(
+            "None of the models returned data for this location"
+        ));
+    }
+
+    Ok(ModelComparison { times, models })
+}
+
+/// Fetches the same hourly variables from several models
+pub struct ModelsService {
+    http: HttpContext,
+}
+
+impl ModelsService {
+    pub fn new(http: HttpContext) -> Self {
+        Self { http }
+    }
+
+    /// The next `MODEL_HOURS` hours from each model
+    pub async fn compare(
+        &self,
+        location: &Location,
+        models: &[String],
+        now: DateTime<Utc>,
+    ) -> Result<ModelComparison> {
+        let url = format!(
+            "{}/forecast?latitude={}&longitude={}&hourly=temperature_2m,precipitation,wind_speed_10m&models={}&wind_speed_unit=ms&timezone=auto&forecast_days=3",
+            self.http.endpoints().openmeteo,
+            location.latitude,
+            location.longitude,
+            models.join(",")
+        );
+        let json = self
+            .http
+            .get_json(&url)
+            .await
+            .context("Could not reach Open-Meteo")?;
+        if json["error"].as_bool() == Some(true) {
+            let reason = json["reason"].as_str().unwrap_or("Unknown error");
+            return Err(anyhow!("Open-Meteo API error: {}", reason));
+        }
+
+        Ok(parse_model_comparison(&json, models)?.upcoming(now, MODEL_HOURS))
+    }
+}
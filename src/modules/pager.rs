@@ -0,0 +1,93 @@
+use anyhow::{Context, Result};
+use std::ffi::OsString;
+use std::io::{self, Write};
+use std::process::{Child, Command, Stdio};
+
+/// Used when neither `WEATHER_MAN_PAGER` nor `PAGER` is set
+const DEFAULT_PAGER: &str = "less";
+/// Options for less unless `LESS` is set: quit if the report fits on one
+/// screen, keep colors and leave the report on the screen, as git does
+const DEFAULT_LESS: &str = "FRX";
+
+/// Pager command from `WEATHER_MAN_PAGER`, else `PAGER`, else less;
+/// `None` when set to an empty string or `cat`
+pub fn pager_command() -> Option<String> {
+    pager_command_with(|var| std::env::var_os(var))
+}
+
+/// Like `pager_command`, reading the environment through `env`
+pub fn pager_command_with(env: impl Fn(&str) -> Option<OsString>) -> Option<String> {
+    let command = env("WEATHER_MAN_PAGER")
+        .or_else(|| env("PAGER"))
+        .map(|value| value.to_string_lossy().trim().to_string())
+        .unwrap_or_else(|| DEFAULT_PAGER.to_string());
+    (!command.is_empty() && command != "cat").then_some(command)
+}
+
+/// Sends everything printed to stdout through a pager until dropped, when
+/// stdout is restored and the user has quit the pager
+pub struct Pager {
+    child: Child,
+    #[cfg(unix)]
+    saved_stdout: std::os::unix::io::RawFd,
+}
+
+impl Pager {
+    /// Start the pager; `None` where stdout cannot be redirected (non-Unix)
+    #[cfg(unix)]
+    pub fn start(command: &str) -> Result<Option<Pager>> {
+        use std::os::unix::io::AsRawFd;
+
+        io::stdout().flush()?;
+        let mut child = Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .env(
+                "LESS",
+                std::env::var_os("LESS").unwrap_or_else(|| DEFAULT_LESS.into()),
+            )
+            .stdin(Stdio::piped())
+            .spawn()
+            .with_context(|| format!("Could not start the pager '{}'", command))?;
+        let pipe = child.stdin.take().context("Pager has no input")?;
+
+        // SAFETY: plain file descriptor calls; fd 1 is swapped for the pipe
+        // and the original kept to restore it
+        let saved_stdout = unsafe { libc::dup(libc::STDOUT_FILENO) };
+        if saved_stdout < 0 || unsafe { libc::dup2(pipe.as_raw_fd(), libc::STDOUT_FILENO) } < 0 {
+            let _ = child.kill();
+            return Err(io::Error::last_os_error()).context("Could not redirect stdout");
+        }
+        // Quitting the pager early ends the report quietly, like `git log`
+        unsafe { libc::signal(libc::SIGPIPE, libc::SIG_DFL) };
+
+        Ok(Some(Pager {
+            child,
+            saved_stdout,
+        }))
+    }
+
+    #[cfg(not(unix))]
+    pub fn start(_command: &str) -> Result<Option<Pager>> {
+        Ok(None)
+    }
+
+    /// Close the pager's input and wait for the user to quit it
+    pub fn finish(self) {
+        drop(self);
+    }
+}
+
+impl Drop for Pager {
+    fn drop(&mut self) {
+        let _ = io::stdout().flush();
+        #[cfg(unix)]
+        // SAFETY: restores the fd 1 saved in `start`, which closes the pipe
+        unsafe {
+            libc::signal(libc::SIGPIPE, libc::SIG_IGN);
+            libc::dup2(self.saved_stdout, libc::STDOUT_FILENO);
+            libc::close(self.saved_stdout);
+        }
+        let _ = self.child.wait();
+    }
+}
@@ -0,0 +1,90 @@
+use ratatui::style::Color;
+use serde::Serialize;
+
+use crate::modules::canvas::{ground_color, sky_color};
+use crate::modules::types::{CurrentWeather, WeatherCondition};
+
+/// The dominant colors of the weather scene, as `#rrggbb`, for scripts that
+/// match a terminal background or wallpaper to the weather
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScenePalette {
+    pub condition: String,
+    pub is_day: bool,
+    /// Top of the sky, the scene's main color
+    pub sky: String,
+    pub horizon: String,
+    pub ground: String,
+    /// Sun, moon, lightning or precipitation
+    pub accent: String,
+}
+
+impl ScenePalette {
+    pub fn new(condition: &WeatherCondition, temperature: f64, is_day: bool) -> Self {
+        let accent = match condition {
+            WeatherCondition::Clear if is_day => Color::Yellow,
+            WeatherCondition::Clear => Color::White,
+            WeatherCondition::Thunderstorm | WeatherCondition::Squall => Color::LightYellow,
+            WeatherCondition::Rain | WeatherCondition::Drizzle | WeatherCondition::Sleet => {
+                Color::LightBlue
+            }
+            WeatherCondition::Snow | WeatherCondition::Hail => Color::White,
+            WeatherCondition::Clouds if is_day => Color::LightYellow,
+            _ => Color::Gray,
+        };
+
+        Self {
+            condition: condition.to_string(),
+            is_day,
+            sky: hex(sky_color(condition, is_day, temperature, 0.99)),
+            horizon: hex(sky_color(condition, is_day, temperature, 0.0)),
+            ground: hex(ground_color(condition, true)),
+            accent: hex(accent),
+        }
+    }
+
+    /// The palette for current conditions, day between sunrise and sunset
+    pub fn from_weather(weather: &CurrentWeather) -> Self {
+        let is_day = weather.sunrise <= weather.timestamp && weather.timestamp < weather.sunset;
+        Self::new(&weather.main_condition, weather.temperature, is_day)
+    }
+
+    /// `NAME=value` lines for `eval` or `source` in a shell
+    pub fn to_env(&self) -> String {
+        [
+            ("WEATHER_MAN_CONDITION", self.condition.as_str()),
+            ("WEATHER_MAN_IS_DAY", if self.is_day { "1" } else { "0" }),
+            ("WEATHER_MAN_SKY", self.sky.as_str()),
+            ("WEATHER_MAN_HORIZON", self.horizon.as_str()),
+            ("WEATHER_MAN_GROUND", self.ground.as_str()),
+            ("WEATHER_MAN_ACCENT", self.accent.as_str()),
+        ]
+        .iter()
+        .map(|(name, value)| format!("{}='{}'\n", name, value))
+        .collect()
+    }
+}
+
+/// `#rrggbb` for a terminal color, using the xterm values for named colors
+pub fn hex(color: Color) -> String {
+    let (r, g, b) = match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Black => (0, 0, 0),
+        Color::Red => (205, 0, 0),
+        Color::Green => (0, 205, 0),
+        Color::Yellow => (205, 205, 0),
+        Color::Blue => (0, 0, 238),
+        Color::Magenta => (205, 0, 205),
+        Color::Cyan => (0, 205, 205),
+        Color::Gray => (229, 229, 229),
+        Color::DarkGray => (127, 127, 127),
+        Color::LightRed => (255, 0, 0),
+        Color::LightGreen => (0, 255, 0),
+        Color::LightYellow => (255, 255, 0),
+        Color::LightBlue => (92, 92, 255),
+        Color::LightMagenta => (255, 0, 255),
+        Color::LightCyan => (0, 255, 255),
+        Color::White => (255, 255, 255),
+        Color::Reset | Color::Indexed(_) => (0, 0, 0),
+    };
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
@@ -0,0 +1,78 @@
+use serde::Serialize;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+/// Directory name used under the config and cache directories
+const APP_DIR: &str = "weather_man";
+
+/// Where the config file and the caches are kept
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Paths {
+    pub config_file: Option<PathBuf>,
+    pub cache_dir: Option<PathBuf>,
+}
+
+impl Paths {
+    /// Paths from `--config`/`--cache-dir`, else `XDG_CONFIG_HOME`/`XDG_CACHE_HOME`,
+    /// else the platform defaults (e.g. `~/Library/Caches` on macOS)
+    pub fn resolve(config_file: Option<PathBuf>, cache_dir: Option<PathBuf>) -> Self {
+        Self::resolve_with(config_file, cache_dir, |var| std::env::var_os(var))
+    }
+
+    /// Like `resolve`, reading the environment through `env`
+    pub fn resolve_with(
+        config_file: Option<PathBuf>,
+        cache_dir: Option<PathBuf>,
+        env: impl Fn(&str) -> Option<OsString>,
+    ) -> Self {
+        Self {
+            config_file: config_file.or_else(|| {
+                base_dir(env("XDG_CONFIG_HOME"), dirs::config_dir)
+                    .map(|dir| dir.join(APP_DIR).join("config.toml"))
+            }),
+            cache_dir: cache_dir.or_else(|| {
+                base_dir(env("XDG_CACHE_HOME"), dirs::cache_dir).map(|dir| dir.join(APP_DIR))
+            }),
+        }
+    }
+
+    /// Cached forecasts, one file per location
+    pub fn forecasts_dir(&self) -> Option<PathBuf> {
+        self.cache_dir.as_ref().map(|dir| dir.join("forecasts"))
+    }
+
+    /// Cached hazard feeds
+    pub fn hazards_dir(&self) -> Option<PathBuf> {
+        self.cache_dir.as_ref().map(|dir| dir.join("hazards"))
+    }
+
+    /// The forecast seen on the last `--diff`, one file per location
+    pub fn snapshots_dir(&self) -> Option<PathBuf> {
+        self.cache_dir.as_ref().map(|dir| dir.join("snapshots"))
+    }
+
+    /// Historical daily data for the climate normals
+    pub fn climate_dir(&self) -> Option<PathBuf> {
+        self.cache_dir.as_ref().map(|dir| dir.join("climate"))
+    }
+
+    /// Local log of forecast requests read by `weather_man stats`
+    pub fn usage_log(&self) -> Option<PathBuf> {
+        self.cache_dir.as_ref().map(|dir| dir.join("usage.jsonl"))
+    }
+
+    /// Forecasts and observations from `push --watch` read by `weather_man accuracy`
+    pub fn accuracy_log(&self) -> Option<PathBuf> {
+        self.cache_dir
+            .as_ref()
+            .map(|dir| dir.join("accuracy.jsonl"))
+    }
+}
+
+/// The XDG directory if set, else the platform default
+fn base_dir(xdg: Option<OsString>, default: fn() -> Option<PathBuf>) -> Option<PathBuf> {
+    // The XDG spec says relative paths are invalid and must be ignored
+    xdg.map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(default)
+}
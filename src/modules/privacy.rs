@@ -0,0 +1,40 @@
+use serde_json::Value;
+
+use crate::modules::types::Location;
+
+/// Decimal places kept of coordinates with `--privacy`; one decimal is
+/// about 11 km, enough for a forecast but not to find a house
+pub const COORDINATE_DECIMALS: i32 = 1;
+
+/// JSON keys holding coordinates
+const COORDINATE_KEYS: [&str; 4] = ["latitude", "longitude", "lat", "lon"];
+
+pub fn round_coordinate(value: f64) -> f64 {
+    let scale = 10f64.powi(COORDINATE_DECIMALS);
+    (value * scale).round() / scale
+}
+
+/// The location with its coordinates rounded
+pub fn coarse_location(mut location: Location) -> Location {
+    location.latitude = round_coordinate(location.latitude);
+    location.longitude = round_coordinate(location.longitude);
+    location
+}
+
+/// Round every coordinate in a JSON document, however deeply nested
+pub fn redact_coordinates(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, field) in map.iter_mut() {
+                match field.as_f64() {
+                    Some(coordinate) if COORDINATE_KEYS.contains(&key.as_str()) => {
+                        *field = round_coordinate(coordinate).into();
+                    }
+                    _ => redact_coordinates(field),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_coordinates),
+        _ => {}
+    }
+}
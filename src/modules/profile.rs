@@ -0,0 +1,241 @@
+use crate::modules::types::{CurrentWeather, Location, WeatherCondition};
+use crate::modules::ui::convert_to_local;
+use crate::modules::utils::{beaufort, beaufort_description, compass_point};
+use chrono::Duration;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// Activity the recommendations are tailored to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ActivityProfile {
+    #[default]
+    Default,
+    Cycling,
+    Running,
+    Motorcycle,
+    Photography,
+}
+
+impl ActivityProfile {
+    pub fn get_emoji(&self) -> &'static str {
+        match self {
+            ActivityProfile::Default => "💡",
+            ActivityProfile::Cycling => "🚴",
+            ActivityProfile::Running => "🏃",
+            ActivityProfile::Motorcycle => "🏍️",
+            ActivityProfile::Photography => "📷",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ActivityProfile::Default => "General",
+            ActivityProfile::Cycling => "Cycling",
+            ActivityProfile::Running => "Running",
+            ActivityProfile::Motorcycle => "Motorcycle",
+            ActivityProfile::Photography => "Photography",
+        }
+    }
+}
+
+/// Profile-specific advice for the current weather; empty for the default profile
+pub fn profile_recommendations(
+    profile: ActivityProfile,
+    weather: &CurrentWeather,
+    location: &Location,
+) -> Vec<String> {
+    match profile {
+        ActivityProfile::Default => Vec::new(),
+        ActivityProfile::Cycling => cycling(weather),
+        ActivityProfile::Running => running(weather),
+        ActivityProfile::Motorcycle => motorcycle(weather),
+        ActivityProfile::Photography => photography(weather, location),
+    }
+}
+
+/// Gust speed, falling back to the mean wind when gusts are not reported
+fn gusts(weather: &CurrentWeather) -> f64 {
+    weather.wind_gusts.unwrap_or(weather.wind_speed)
+}
+
+/// Compass points a route must run along to take the wind side-on
+fn crosswind_routes(direction: u16) -> String {
+    format!(
+        "{}/{}",
+        compass_point(direction.wrapping_add(90) % 360),
+        compass_point(direction.wrapping_add(270) % 360)
+    )
+}
+
+fn is_wet(condition: WeatherCondition) -> bool {
+    matches!(
+        condition,
+        WeatherCondition::Rain
+            | WeatherCondition::Drizzle
+            | WeatherCondition::Thunderstorm
+            | WeatherCondition::Snow
+            | WeatherCondition::Sleet
+            | WeatherCondition::Hail
+    )
+}
+
+fn cycling(weather: &CurrentWeather) -> Vec<String> {
+    let mut advice = Vec::new();
+    let force = beaufort(weather.wind_speed);
+    let gusts = gusts(weather);
+
+    if force >= 5 {
+        advice.push(format!(
+            "{:.0} m/s {} from the {}: ride out into the wind and come home with it behind you.",
+            weather.wind_speed,
+            beaufort_description(force),
+            compass_point(weather.wind_direction)
+        ));
+    } else if force <= 2 {
+        advice.push("Light wind: a good day for a fast ride.".to_string());
+    }
+
+    if gusts >= 10.0 || gusts - weather.wind_speed >= 5.0 {
+        advice.push(format!(
+            "Crosswind gusts up to {:.0} m/s: hold the bars firmly on bridges and open roads heading {}.",
+            gusts,
+            crosswind_routes(weather.wind_direction)
+        ));
+    }
+
+    if is_wet(weather.main_condition) {
+        advice
+            .push("Wet roads: brake earlier and avoid painted lines and metal covers.".to_string());
+    }
+
+    if weather.feels_like < 5.0 {
+        advice.push("Cold on the bike: full-finger gloves and overshoes.".to_string());
+    }
+
+    advice
+}
+
+fn running(weather: &CurrentWeather) -> Vec<String> {
+    let mut advice = Vec::new();
+
+    if weather.feels_like >= 30.0 {
+        advice.push("Heat stress risk: shorten the run or move it to early morning.".to_string());
+    } else if weather.feels_like >= 25.0 {
+        advice.push("Warm: slow your pace and carry water.".to_string());
+    } else if weather.feels_like < 0.0 {
+        advice.push("Freezing: layer up and watch for slippery paths.".to_string());
+    }
+
+    if weather.humidity.0 >= 80.0 && weather.temperature >= 18.0 {
+        advice.push("Humid air: expect a higher heart rate at your usual pace.".to_string());
+    }
+
+    if weather.uv_index >= 6.0 {
+        advice.push("High UV: wear sunscreen and a cap.".to_string());
+    }
+
+    if beaufort(weather.wind_speed) >= 6 {
+        advice.push(format!(
+            "Strong wind from the {}: start into it so it helps you home.",
+            compass_point(weather.wind_direction)
+        ));
+    }
+
+    if advice.is_empty() {
+        advice.push("Good running conditions.".to_string());
+    }
+
+    advice
+}
+
+fn motorcycle(weather: &CurrentWeather) -> Vec<String> {
+    let mut advice = Vec::new();
+    let gusts = gusts(weather);
+
+    if gusts >= 13.0 {
+        advice.push(format!(
+            "Gusts up to {:.0} m/s: risky riding, especially heading {} and when passing trucks.",
+            gusts,
+            crosswind_routes(weather.wind_direction)
+        ));
+    }
+
+    if is_wet(weather.main_condition) {
+        advice.push("Wet roads: reduced grip, keep lean angles and throttle smooth.".to_string());
+    }
+
+    if weather.temperature < 3.0 {
+        advice.push("Near freezing: watch for ice on bridges and shaded corners.".to_string());
+    } else if weather.feels_like < 10.0 {
+        advice.push("Wind chill at speed is severe: wear thermal layers.".to_string());
+    }
+
+    if matches!(
+        weather.main_condition,
+        WeatherCondition::Fog | WeatherCondition::Mist
+    ) || weather.visibility < 1000
+    {
+        advice.push("Poor visibility: use dipped headlights and hi-vis gear.".to_string());
+    }
+
+    if advice.is_empty() {
+        advice.push("Good riding conditions.".to_string());
+    }
+
+    advice
+}
+
+fn photography(weather: &CurrentWeather, location: &Location) -> Vec<String> {
+    let mut advice = Vec::new();
+    let local = |time| convert_to_local(&time, location.clock_timezone()).format("%H:%M");
+
+    // Golden hour: the first hour after sunrise and the last before sunset
+    let golden = if weather.timestamp < weather.sunrise + Duration::hours(1) {
+        Some((
+            "Morning",
+            weather.sunrise,
+            weather.sunrise + Duration::hours(1),
+        ))
+    } else if weather.timestamp < weather.sunset {
+        Some((
+            "Evening",
+            weather.sunset - Duration::hours(1),
+            weather.sunset,
+        ))
+    } else {
+        None
+    };
+    if let Some((label, start, end)) = golden {
+        advice.push(format!(
+            "{} golden hour: {}–{}.",
+            label,
+            local(start),
+            local(end)
+        ));
+    }
+
+    advice.push(
+        match weather.clouds.0 {
+            c if c <= 20.0 => "Clear sky: harsh light at midday, great for stars after dark.",
+            c if c <= 70.0 => {
+                "Broken clouds: good chance of dramatic colors at sunrise and sunset."
+            }
+            _ => "Overcast: soft, even light for portraits and details.",
+        }
+        .to_string(),
+    );
+
+    if matches!(
+        weather.main_condition,
+        WeatherCondition::Fog | WeatherCondition::Mist
+    ) {
+        advice.push("Fog: moody landscapes and layered depth.".to_string());
+    }
+
+    if is_wet(weather.main_condition) {
+        advice.push("Protect your gear from the wet; look for reflections.".to_string());
+    }
+
+    advice
+}
@@ -0,0 +1,78 @@
+use indicatif::{ProgressBar, ProgressStyle};
+use std::fmt;
+use std::io::IsTerminal;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// What the report is waiting for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    /// Detecting the location from the IP address
+    Locating,
+    /// Looking up a location given by name
+    Geocoding,
+    Forecast,
+    /// Hazards and climate records for the full report
+    Alerts,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            Stage::Locating => "Locating you",
+            Stage::Geocoding => "Finding the location",
+            Stage::Forecast => "Fetching the forecast",
+            Stage::Alerts => "Checking alerts",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// Spinner on stderr naming the current stage; cleared before output is printed
+///
+/// Does nothing when disabled or when stderr is not a terminal.
+pub struct Progress {
+    enabled: bool,
+    spinner: Mutex<Option<ProgressBar>>,
+}
+
+impl Progress {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled: enabled && std::io::stderr().is_terminal(),
+            spinner: Mutex::new(None),
+        }
+    }
+
+    /// Show the spinner for a stage, starting it if needed
+    pub fn stage(&self, stage: Stage) {
+        if !self.enabled {
+            return;
+        }
+        let mut spinner = self.spinner.lock().unwrap_or_else(|e| e.into_inner());
+        spinner
+            .get_or_insert_with(|| {
+                let bar = ProgressBar::new_spinner().with_style(
+                    ProgressStyle::with_template("{spinner:.cyan} {msg}…")
+                        .expect("valid spinner template"),
+                );
+                bar.enable_steady_tick(Duration::from_millis(100));
+                bar
+            })
+            .set_message(stage.to_string());
+    }
+
+    /// Remove the spinner from the screen
+    pub fn clear(&self) {
+        let mut spinner = self.spinner.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(bar) = spinner.take() {
+            bar.finish_and_clear();
+        }
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
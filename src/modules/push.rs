@@ -0,0 +1,274 @@
+use crate::modules::config::ThresholdSettings;
+use crate::modules::http::HttpContext;
+use crate::modules::types::{
+    CurrentWeather, DailyForecast, HourlyForecast, Location, WeatherAlert, WeatherCondition,
+};
+use crate::modules::ui::convert_to_local;
+use crate::modules::utils::{relative_time, DisplayUnits, Language};
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+
+/// Payload flavour expected by the receiving webhook
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WebhookFormat {
+    /// Plain JSON with the raw weather data
+    Json,
+    /// Slack incoming webhook with markdown blocks
+    Slack,
+    /// Discord webhook with an embed
+    Discord,
+}
+
+/// Posts weather summaries and alerts to a webhook
+pub struct WebhookPusher {
+    http: HttpContext,
+    url: String,
+    format: WebhookFormat,
+}
+
+impl WebhookPusher {
+    /// Create a pusher for the given webhook URL
+    pub fn new(http: HttpContext, url: &str, format: WebhookFormat) -> Self {
+        Self {
+            http,
+            url: url.to_string(),
+            format,
+        }
+    }
+
+    /// Post today's weather summary
+    pub async fn send_summary(
+        &self,
+        location: &Location,
+        current: &CurrentWeather,
+        today: &DailyForecast,
+        summary: Option<&str>,
+        units: &DisplayUnits,
+    ) -> Result<()> {
+        self.post(summary_payload(
+            self.format,
+            location,
+            current,
+            today,
+            summary,
+            units,
+        ))
+        .await
+    }
+
+    /// Post a weather alert notification
+    pub async fn send_alert(&self, location: &Location, alert: &WeatherAlert) -> Result<()> {
+        self.post(alert_payload(self.format, location, alert)).await
+    }
+
+    async fn post(&self, payload: Value) -> Result<()> {
+        let response = self.http.post(&self.url).json(&payload).send().await?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!("Webhook returned HTTP {}", response.status()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Build the webhook payload for a daily summary
+pub fn summary_payload(
+    format: WebhookFormat,
+    location: &Location,
+    current: &CurrentWeather,
+    today: &DailyForecast,
+    summary: Option<&str>,
+    units: &DisplayUnits,
+) -> Value {
+    let title = format!("Weather for {}, {}", location.name, location.country_code);
+    let bold = |text: &str| match format {
+        WebhookFormat::Discord => format!("**{}**", text),
+        _ => format!("*{}*", text),
+    };
+    let lines = [
+        format!(
+            "{} {}, {} to {}",
+            today.main_condition.get_emoji(),
+            bold(&today.main_condition.to_string()),
+            units.temperature.format(today.temp_min),
+            units.temperature.format(today.temp_max)
+        ),
+        format!(
+            "Now: {:.1}{} (feels like {:.1}{}), humidity {}, wind {}",
+            units.temperature.convert(current.temperature),
+            units.temperature.label(),
+            units.temperature.convert(current.feels_like),
+            units.temperature.label(),
+            current.humidity,
+            units.wind.format(current.wind_speed)
+        ),
+        format!("Precipitation chance: {:.0}%", today.pop * 100.0),
+        format!("UV index: {:.1}", today.uv_index),
+    ];
+    let body = summary
+        .into_iter()
+        .map(str::to_string)
+        .chain(lines)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    match format {
+        WebhookFormat::Json => json!({
+            "type": "summary",
+            "summary": summary,
+            "location": location,
+            "current": current,
+            "today": today,
+        }),
+        WebhookFormat::Slack => slack_message(&title, &body),
+        WebhookFormat::Discord => discord_message(&title, &body, 0x00bcd4),
+    }
+}
+
+/// Build the webhook payload for an alert
+pub fn alert_payload(format: WebhookFormat, location: &Location, alert: &WeatherAlert) -> Value {
+    let title = format!("⚠️ {} - {}", alert.event, location.name);
+
+    match format {
+        WebhookFormat::Json => json!({
+            "type": "alert",
+            "location": location,
+            "alert": alert,
+        }),
+        WebhookFormat::Slack => slack_message(&title, &alert.description),
+        WebhookFormat::Discord => discord_message(&title, &alert.description, 0xff5722),
+    }
+}
+
+fn slack_message(title: &str, body: &str) -> Value {
+    json!({
+        "text": format!("{}\n{}", title, body),
+        "blocks": [
+            { "type": "header", "text": { "type": "plain_text", "text": title } },
+            { "type": "section", "text": { "type": "mrkdwn", "text": body } },
+        ],
+    })
+}
+
+fn discord_message(title: &str, body: &str, color: u32) -> Value {
+    json!({
+        "embeds": [
+            { "title": title, "description": body, "color": color },
+        ],
+    })
+}
+
+/// Derive alerts from severe conditions in the upcoming hours of the forecast
+///
+/// Consecutive hours with the same severe condition are merged into one alert.
+pub fn forecast_alerts(
+    hourly_data: &[HourlyForecast],
+    location: &Location,
+    hours: usize,
+    now: DateTime<Utc>,
+    language: Language,
+) -> Vec<WeatherAlert> {
+    merge_hours(hourly_data, location, hours, now, language, |hour| {
+        let condition = hour.main_condition;
+        is_severe(condition).then(|| {
+            (
+                condition.to_string(),
+                format!("{:?}", condition).to_lowercase(),
+            )
+        })
+    })
+}
+
+/// Derive alerts from the user's wind and rain thresholds in the upcoming hours
+pub fn threshold_alerts(
+    hourly_data: &[HourlyForecast],
+    location: &Location,
+    hours: usize,
+    thresholds: &ThresholdSettings,
+    now: DateTime<Utc>,
+    language: Language,
+) -> Vec<WeatherAlert> {
+    let mut alerts = merge_hours(hourly_data, location, hours, now, language, |hour| {
+        (hour.wind_speed >= thresholds.wind_warning())
+            .then(|| ("Strong wind".to_string(), "wind".to_string()))
+    });
+    alerts.extend(merge_hours(
+        hourly_data,
+        location,
+        hours,
+        now,
+        language,
+        |hour| {
+            (hour.pop >= thresholds.rain_notify_pop())
+                .then(|| ("Rain likely".to_string(), "rain".to_string()))
+        },
+    ));
+    alerts.sort_by_key(|alert| alert.start);
+    alerts
+}
+
+/// Merge consecutive hours with the same event into one alert each
+///
+/// `event` returns the event name and tag for an hour, or `None` if nothing
+/// is worth reporting. Descriptions say how far ahead the alert starts, in
+/// `language`.
+fn merge_hours(
+    hourly_data: &[HourlyForecast],
+    location: &Location,
+    hours: usize,
+    now: DateTime<Utc>,
+    language: Language,
+    event: impl Fn(&HourlyForecast) -> Option<(String, String)>,
+) -> Vec<WeatherAlert> {
+    let mut alerts: Vec<WeatherAlert> = Vec::new();
+    let mut previous: Option<String> = None;
+
+    for hour in hourly_data.iter().take(hours) {
+        let Some((name, tag)) = event(hour) else {
+            previous = None;
+            continue;
+        };
+
+        match alerts.last_mut() {
+            Some(alert) if previous.as_ref() == Some(&name) => {
+                alert.end = hour.timestamp + chrono::Duration::hours(1);
+            }
+            _ => alerts.push(WeatherAlert {
+                sender: "weather_man".to_string(),
+                event: name.clone(),
+                start: hour.timestamp,
+                end: hour.timestamp + chrono::Duration::hours(1),
+                description: String::new(),
+                tags: vec![tag],
+            }),
+        }
+        previous = Some(name);
+    }
+
+    for alert in &mut alerts {
+        alert.description = format!(
+            "{} expected from {} to {} ({})",
+            alert.event,
+            convert_to_local(&alert.start, location.clock_timezone()).format("%a %H:%M"),
+            convert_to_local(&alert.end, location.clock_timezone()).format("%a %H:%M"),
+            relative_time(alert.start, now, location.clock_timezone(), language)
+        );
+    }
+
+    alerts
+}
+
+/// Conditions worth a push notification
+pub fn is_severe(condition: WeatherCondition) -> bool {
+    matches!(
+        condition,
+        WeatherCondition::Thunderstorm
+            | WeatherCondition::Hail
+            | WeatherCondition::Sleet
+            | WeatherCondition::Snow
+            | WeatherCondition::Squall
+            | WeatherCondition::Tornado
+    )
+}
@@ -0,0 +1,76 @@
+use crate::modules::types::CurrentWeather;
+use crate::modules::utils::to_json;
+use anyhow::{anyhow, Result};
+use serde_json::{Map, Value};
+
+/// Short names accepted by `--query` in addition to the full field names
+const ALIASES: [(&str, &str); 10] = [
+    ("temp", "temperature"),
+    ("feels", "feels_like"),
+    ("wind", "wind_speed"),
+    ("wind_dir", "wind_direction"),
+    ("gusts", "wind_gusts"),
+    ("condition", "main_condition"),
+    ("uv", "uv_index"),
+    ("rain", "rain_last_hour"),
+    ("snow", "snow_last_hour"),
+    ("aqi", "air_quality_index"),
+];
+
+/// Split a comma-separated field list such as `temperature,humidity`
+pub fn parse_fields(spec: &str) -> Vec<String> {
+    spec.split(',')
+        .map(|field| field.trim().to_lowercase())
+        .filter(|field| !field.is_empty())
+        .collect()
+}
+
+/// Look up the requested fields of the current weather, keeping the requested names
+pub fn query_current(weather: &CurrentWeather, fields: &[String]) -> Result<Vec<(String, Value)>> {
+    let data = serde_json::to_value(weather)?;
+    let object = data
+        .as_object()
+        .ok_or_else(|| anyhow!("Current weather is not an object"))?;
+
+    fields
+        .iter()
+        .map(|field| {
+            let key = ALIASES
+                .iter()
+                .find(|(alias, _)| alias == field)
+                .map_or(field.as_str(), |(_, name)| name);
+
+            object
+                .get(key)
+                .map(|value| (field.clone(), value.clone()))
+                .ok_or_else(|| {
+                    let mut valid: Vec<&str> = object.keys().map(String::as_str).collect();
+                    valid.extend(ALIASES.iter().map(|(alias, _)| *alias));
+                    anyhow!(
+                        "Unknown field '{}'. Valid fields: {}",
+                        field,
+                        valid.join(", ")
+                    )
+                })
+        })
+        .collect()
+}
+
+/// One raw value per line, with strings unquoted and missing values left empty
+pub fn format_lines(values: &[(String, Value)]) -> String {
+    values
+        .iter()
+        .map(|(_, value)| match value {
+            Value::String(text) => text.clone(),
+            Value::Null => String::new(),
+            other => other.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A JSON object of the requested fields, on one line when `compact`
+pub fn format_json(values: &[(String, Value)], compact: bool) -> serde_json::Result<String> {
+    let object: Map<String, Value> = values.iter().cloned().collect();
+    to_json(&object, compact)
+}
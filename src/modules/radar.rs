@@ -0,0 +1,246 @@
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use colored::*;
+use futures::future::join_all;
+use serde::Serialize;
+use serde_json::Value;
+use std::f64::consts::PI;
+
+use crate::modules::http::HttpContext;
+use crate::modules::types::Location;
+
+const FRAMES_URL: &str = "https://api.rainviewer.com/public/weather-maps.json";
+/// Tile zoom level; RainViewer serves radar tiles up to zoom 7
+const ZOOM: u8 = 6;
+const TILE_SIZE: i64 = 256;
+/// Universal Blue color scheme, with smoothing and snow colors
+const TILE_STYLE: &str = "2/1_1";
+/// Radar window around the location, in tile pixels
+pub const WINDOW_WIDTH: usize = 256;
+pub const WINDOW_HEIGHT: usize = 192;
+/// Rendered map size; each character shows two pixel rows
+pub const MAP_COLUMNS: usize = 64;
+pub const MAP_ROWS: usize = 24;
+
+/// One radar scan published by RainViewer
+#[derive(Debug, Clone, Serialize)]
+pub struct RadarFrame {
+    pub time: DateTime<Utc>,
+    pub path: String,
+}
+
+/// RGBA pixels of the radar window around a location
+#[derive(Debug, Clone)]
+pub struct RadarImage {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<[u8; 4]>,
+}
+
+impl RadarImage {
+    /// Fully transparent image
+    pub fn empty(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![[0; 4]; width * height],
+        }
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> Option<[u8; 4]> {
+        let pixel = *self.pixels.get(y * self.width + x)?;
+        (pixel[3] > 0).then_some(pixel)
+    }
+}
+
+/// Parse the frame list, keeping past scans from the last hour
+pub fn parse_frames(json: &Value, now: DateTime<Utc>) -> Result<(String, Vec<RadarFrame>)> {
+    let host = json["host"]
+        .as_str()
+        .ok_or_else(|| anyhow!("Radar frame list has no host"))?
+        .to_string();
+    let frames = json["radar"]["past"]
+        .as_array()
+        .ok_or_else(|| anyhow!("Radar frame list has no past frames"))?
+        .iter()
+        .filter_map(|frame| {
+            Some(RadarFrame {
+                time: DateTime::from_timestamp(frame["time"].as_i64()?, 0)?,
+                path: frame["path"].as_str()?.to_string(),
+            })
+        })
+        .filter(|frame| frame.time >= now - Duration::hours(1))
+        .collect();
+    Ok((host, frames))
+}
+
+/// Web Mercator pixel position of a coordinate at the given zoom
+pub fn pixel_position(latitude: f64, longitude: f64, zoom: u8) -> (f64, f64) {
+    let scale = TILE_SIZE as f64 * 2f64.powi(zoom as i32);
+    let latitude = latitude.clamp(-85.05, 85.05).to_radians();
+    let x = (longitude + 180.0) / 360.0 * scale;
+    let y = (1.0 - (latitude.tan() + 1.0 / latitude.cos()).ln() / PI) / 2.0 * scale;
+    (x, y)
+}
+
+/// Fetches RainViewer radar frames and tiles
+#[derive(Clone)]
+pub struct RadarService {
+    http: HttpContext,
+}
+
+impl RadarService {
+    pub fn new(http: HttpContext) -> Self {
+        Self { http }
+    }
+
+    /// Tile host and the radar frames of the last hour, oldest first
+    pub async fn frames(&self) -> Result<(String, Vec<RadarFrame>)> {
+        let json: Value = self
+            .http
+            .get(FRAMES_URL)
+            .await
+            .context("Could not reach the radar service")?
+            .json()
+            .await?;
+        parse_frames(&json, Utc::now())
+    }
+
+    /// Radar window centered on the location for one frame
+    ///
+    /// The window usually spans up to four tiles, which are fetched
+    /// concurrently; tiles that fail to load stay transparent.
+    pub async fn window(&self, host: &str, frame: &RadarFrame, location: &Location) -> RadarImage {
+        let (x, y) = pixel_position(location.latitude, location.longitude, ZOOM);
+        let left = x as i64 - WINDOW_WIDTH as i64 / 2;
+        let top = y as i64 - WINDOW_HEIGHT as i64 / 2;
+        let tiles_per_side = 1i64 << ZOOM;
+
+        let mut requests = Vec::new();
+        for tile_y in
+            top.div_euclid(TILE_SIZE)..=(top + WINDOW_HEIGHT as i64 - 1).div_euclid(TILE_SIZE)
+        {
+            if !(0..tiles_per_side).contains(&tile_y) {
+                continue;
+            }
+            for tile_x in
+                left.div_euclid(TILE_SIZE)..=(left + WINDOW_WIDTH as i64 - 1).div_euclid(TILE_SIZE)
+            {
+                let url = format!(
+                    "{}{}/{}/{}/{}/{}/{}.png",
+                    host,
+                    frame.path,
+                    TILE_SIZE,
+                    ZOOM,
+                    tile_x.rem_euclid(tiles_per_side),
+                    tile_y,
+                    TILE_STYLE
+                );
+                requests.push(async move { ((tile_x, tile_y), self.tile(&url).await) });
+            }
+        }
+
+        let mut window = RadarImage::empty(WINDOW_WIDTH, WINDOW_HEIGHT);
+        for ((tile_x, tile_y), tile) in join_all(requests).await {
+            let Ok(tile) = tile else {
+                continue;
+            };
+            for row in 0..tile.height {
+                let wy = tile_y * TILE_SIZE + row as i64 - top;
+                if !(0..WINDOW_HEIGHT as i64).contains(&wy) {
+                    continue;
+                }
+                for column in 0..tile.width {
+                    let wx = tile_x * TILE_SIZE + column as i64 - left;
+                    if (0..WINDOW_WIDTH as i64).contains(&wx) {
+                        window.pixels[wy as usize * WINDOW_WIDTH + wx as usize] =
+                            tile.pixels[row * tile.width + column];
+                    }
+                }
+            }
+        }
+        window
+    }
+
+    async fn tile(&self, url: &str) -> Result<RadarImage> {
+        let response = self.http.get(url).await?.error_for_status()?;
+        decode_png(&response.bytes().await?)
+    }
+}
+
+impl Default for RadarService {
+    fn default() -> Self {
+        Self::new(HttpContext::default())
+    }
+}
+
+/// Decode a PNG tile into RGBA pixels
+pub fn decode_png(bytes: &[u8]) -> Result<RadarImage> {
+    let mut decoder = png::Decoder::new(bytes);
+    decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+    let mut reader = decoder.read_info()?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer)?;
+    let data = &buffer[..info.buffer_size()];
+
+    let pixels = match info.color_type {
+        png::ColorType::Rgba => data
+            .chunks_exact(4)
+            .map(|p| [p[0], p[1], p[2], p[3]])
+            .collect(),
+        png::ColorType::Rgb => data
+            .chunks_exact(3)
+            .map(|p| [p[0], p[1], p[2], 255])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => data
+            .chunks_exact(2)
+            .map(|p| [p[0], p[0], p[0], p[1]])
+            .collect(),
+        png::ColorType::Grayscale => data.iter().map(|&g| [g, g, g, 255]).collect(),
+        png::ColorType::Indexed => return Err(anyhow!("Unexpanded palette image")),
+    };
+
+    Ok(RadarImage {
+        width: info.width as usize,
+        height: info.height as usize,
+        pixels,
+    })
+}
+
+/// Render the radar window as colored half-block characters with the location
+/// marked in the center
+pub fn render_radar(image: &RadarImage, columns: usize, rows: usize) -> Vec<String> {
+    let sample = |column: usize, pixel_row: usize| {
+        image.pixel(
+            column * image.width / columns,
+            pixel_row * image.height / (rows * 2),
+        )
+    };
+
+    (0..rows)
+        .map(|row| {
+            (0..columns)
+                .map(|column| {
+                    if row == rows / 2 && column == columns / 2 {
+                        return "◉".bright_white().bold().to_string();
+                    }
+                    match (sample(column, row * 2), sample(column, row * 2 + 1)) {
+                        (Some(top), Some(bottom)) => "▀"
+                            .truecolor(top[0], top[1], top[2])
+                            .on_truecolor(bottom[0], bottom[1], bottom[2])
+                            .to_string(),
+                        (Some(top), None) => "▀".truecolor(top[0], top[1], top[2]).to_string(),
+                        (None, Some(bottom)) => {
+                            "▄".truecolor(bottom[0], bottom[1], bottom[2]).to_string()
+                        }
+                        // Faint grid so the map extent stays visible without rain
+                        (None, None) if row % 3 == 0 && column % 4 == 0 => {
+                            "·".bright_black().to_string()
+                        }
+                        (None, None) => " ".to_string(),
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
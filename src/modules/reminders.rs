@@ -0,0 +1,109 @@
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use tokio::process::Command;
+
+use crate::modules::config::ReminderSettings;
+use crate::modules::types::HourlyForecast;
+
+/// Something to remind the user of while the weather calls for it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReminderKind {
+    Sunscreen,
+    Hydration,
+}
+
+/// A reminder ready to be shown
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reminder {
+    pub kind: ReminderKind,
+    pub title: String,
+    pub message: String,
+}
+
+/// Tracks when each reminder was last given, so they repeat at their
+/// configured interval while the condition lasts
+#[derive(Debug, Clone, Default)]
+pub struct ReminderSchedule {
+    last: HashMap<ReminderKind, DateTime<Utc>>,
+}
+
+impl ReminderSchedule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reminders due at `now` for the hour containing it
+    pub fn due(
+        &mut self,
+        hourly: &[HourlyForecast],
+        settings: &ReminderSettings,
+        now: DateTime<Utc>,
+    ) -> Vec<Reminder> {
+        let Some(hour) = hourly
+            .iter()
+            .find(|h| h.timestamp <= now && now < h.timestamp + Duration::hours(1))
+        else {
+            return Vec::new();
+        };
+
+        let mut reminders = Vec::new();
+        if let Some(uv) = hour.uv_index.filter(|uv| *uv > settings.sunscreen_uv) {
+            reminders.push(Reminder {
+                kind: ReminderKind::Sunscreen,
+                title: "Reapply sunscreen".to_string(),
+                message: format!("UV index is {:.0}; reapply sunscreen and seek shade", uv),
+            });
+        }
+        if hour.temperature > settings.hydration_above {
+            reminders.push(Reminder {
+                kind: ReminderKind::Hydration,
+                title: "Drink some water".to_string(),
+                message: format!("It's {:.0}°C; have a glass of water", hour.temperature),
+            });
+        }
+
+        reminders.retain(|reminder| {
+            let every = Duration::minutes(match reminder.kind {
+                ReminderKind::Sunscreen => settings.sunscreen_every_minutes,
+                ReminderKind::Hydration => settings.hydration_every_minutes,
+            } as i64);
+            let due = self
+                .last
+                .get(&reminder.kind)
+                .is_none_or(|last| now - *last >= every);
+            if due {
+                self.last.insert(reminder.kind, now);
+            }
+            due
+        });
+        reminders
+    }
+}
+
+/// Show a desktop notification with `notify-send`, or `osascript` on macOS
+pub async fn desktop_notify(title: &str, message: &str) -> Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        let mut command = Command::new("osascript");
+        command.arg("-e").arg(format!(
+            "display notification {:?} with title {:?}",
+            message, title
+        ));
+        command
+    } else {
+        let mut command = Command::new("notify-send");
+        command
+            .arg("--app-name=weather_man")
+            .arg(title)
+            .arg(message);
+        command
+    };
+    let status = command
+        .status()
+        .await
+        .context("Could not show a desktop notification")?;
+    if !status.success() {
+        return Err(anyhow!("Desktop notification failed with {}", status));
+    }
+    Ok(())
+}
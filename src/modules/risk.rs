@@ -0,0 +1,148 @@
+use crate::modules::types::{DailyForecast, HourlyForecast, Hpa, Location, WeatherCondition};
+use crate::modules::ui::convert_to_local;
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::fmt;
+
+/// Overall severe-weather risk for a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum RiskLevel {
+    Low,
+    Moderate,
+    High,
+    Severe,
+}
+
+impl RiskLevel {
+    pub fn get_emoji(&self) -> &'static str {
+        match self {
+            RiskLevel::Low => "🟢",
+            RiskLevel::Moderate => "🟡",
+            RiskLevel::High => "🟠",
+            RiskLevel::Severe => "🔴",
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            RiskLevel::Low => RiskLevel::Moderate,
+            RiskLevel::Moderate => RiskLevel::High,
+            _ => RiskLevel::Severe,
+        }
+    }
+}
+
+impl fmt::Display for RiskLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            RiskLevel::Low => "Low",
+            RiskLevel::Moderate => "Moderate",
+            RiskLevel::High => "High",
+            RiskLevel::Severe => "Severe",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// Risk assessment for one forecast day with the signals behind it
+#[derive(Debug, Clone, Serialize)]
+pub struct DayRisk {
+    pub date: NaiveDate,
+    pub level: RiskLevel,
+    pub reasons: Vec<String>,
+}
+
+/// Combine thunderstorms, gusts, precipitation totals and pressure drops into a
+/// risk level per day
+///
+/// Each signal has its own level; the day takes the highest one, raised by one
+/// step when two or more signals are at least High.
+pub fn assess_risk(
+    daily_data: &[DailyForecast],
+    hourly_data: &[HourlyForecast],
+    location: &Location,
+) -> Vec<DayRisk> {
+    daily_data
+        .iter()
+        .map(|day| {
+            let date = day.date.date_naive();
+            let hours: Vec<&HourlyForecast> = hourly_data
+                .iter()
+                .filter(|h| convert_to_local(&h.timestamp, &location.timezone).date_naive() == date)
+                .collect();
+
+            let mut signals: Vec<(RiskLevel, String)> = Vec::new();
+
+            // Convective weather, from the daily code or any hour of the day
+            let conditions = std::iter::once(day.main_condition)
+                .chain(hours.iter().map(|h| h.main_condition))
+                .collect::<Vec<_>>();
+            if conditions.contains(&WeatherCondition::Tornado) {
+                signals.push((RiskLevel::Severe, "Tornado conditions".to_string()));
+            } else if conditions.contains(&WeatherCondition::Hail) {
+                signals.push((RiskLevel::High, "Thunderstorms with hail".to_string()));
+            } else if conditions.contains(&WeatherCondition::Thunderstorm) {
+                signals.push((RiskLevel::High, "Thunderstorms forecast".to_string()));
+            }
+
+            let gusts = day.wind_gusts.unwrap_or(day.wind_speed);
+            let gust_level = match gusts {
+                g if g >= 25.0 => Some(RiskLevel::Severe),
+                g if g >= 20.0 => Some(RiskLevel::High),
+                g if g >= 14.0 => Some(RiskLevel::Moderate),
+                _ => None,
+            };
+            if let Some(level) = gust_level {
+                signals.push((level, format!("Gusts up to {:.0} m/s", gusts)));
+            }
+
+            let total = day
+                .precipitation
+                .unwrap_or(day.rain.unwrap_or(0.0) + day.snow.unwrap_or(0.0));
+            let rain_level = match total {
+                t if t >= 50.0 => Some(RiskLevel::Severe),
+                t if t >= 30.0 => Some(RiskLevel::High),
+                t if t >= 15.0 => Some(RiskLevel::Moderate),
+                _ => None,
+            };
+            if let Some(level) = rain_level {
+                signals.push((level, format!("{:.0} mm of precipitation", total)));
+            }
+
+            // Largest pressure fall over any three hours
+            let drop = hours
+                .windows(4)
+                .map(|w| w[0].pressure.0 - w[3].pressure.0)
+                .fold(0.0, f64::max);
+            let pressure_level = match drop {
+                d if d >= 8.0 => Some(RiskLevel::Severe),
+                d if d >= 5.0 => Some(RiskLevel::High),
+                d if d >= 3.0 => Some(RiskLevel::Moderate),
+                _ => None,
+            };
+            if let Some(level) = pressure_level {
+                signals.push((level, format!("Pressure falling {} in 3 hours", Hpa(drop))));
+            }
+
+            let mut level = signals
+                .iter()
+                .map(|(level, _)| *level)
+                .max()
+                .unwrap_or(RiskLevel::Low);
+            if signals
+                .iter()
+                .filter(|(l, _)| *l >= RiskLevel::High)
+                .count()
+                >= 2
+            {
+                level = level.next();
+            }
+
+            DayRisk {
+                date,
+                level,
+                reasons: signals.into_iter().map(|(_, reason)| reason).collect(),
+            }
+        })
+        .collect()
+}
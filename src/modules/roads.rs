@@ -0,0 +1,220 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::fmt;
+
+use crate::modules::types::HourlyForecast;
+
+/// Hours of forecast covered by the roads mode
+pub const ROAD_HOURS: usize = 24;
+/// Length of each driving window
+pub const BLOCK_HOURS: usize = 3;
+/// Mountain passes are assumed to lie below this altitude (m)
+const PASS_ALTITUDE: f64 = 1000.0;
+/// Precipitation (mm) in an hour that leaves the road wet
+const WET_MM: f64 = 0.1;
+/// Hours a road stays wet after precipitation stops
+const DRYING_HOURS: usize = 3;
+
+/// Likelihood of a road hazard
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Risk {
+    Low,
+    Moderate,
+    High,
+}
+
+/// Driving verdict for a time block
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RoadVerdict {
+    Go,
+    Caution,
+    Avoid,
+}
+
+impl RoadVerdict {
+    pub fn get_emoji(&self) -> &'static str {
+        match self {
+            RoadVerdict::Go => "🟢",
+            RoadVerdict::Caution => "🟡",
+            RoadVerdict::Avoid => "🔴",
+        }
+    }
+}
+
+impl fmt::Display for RoadVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            RoadVerdict::Go => "Go",
+            RoadVerdict::Caution => "Caution",
+            RoadVerdict::Avoid => "Avoid",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// Road conditions for one driving window
+#[derive(Debug, Clone, Serialize)]
+pub struct RoadBlock {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub verdict: RoadVerdict,
+    pub black_ice: Risk,
+    pub aquaplaning: Risk,
+    /// Fresh snow over the block (cm, as reported by Open-Meteo)
+    pub snowfall: f64,
+    pub min_temperature: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_freezing_level: Option<f64>,
+    /// Deepest snow on the ground during the block (cm)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_snow_depth: Option<f64>,
+    pub reasons: Vec<String>,
+}
+
+fn precipitation(hour: &HourlyForecast) -> f64 {
+    hour.rain.unwrap_or(0.0) + hour.snow.unwrap_or(0.0)
+}
+
+/// Black ice risk for the hour at `index`, looking back a few hours for
+/// precipitation that may still be on the road
+pub fn black_ice_risk(hourly: &[HourlyForecast], index: usize) -> Risk {
+    let hour = &hourly[index];
+    let wet = hourly[index.saturating_sub(DRYING_HOURS)..=index]
+        .iter()
+        .any(|h| precipitation(h) >= WET_MM);
+    // Frost and freezing fog glaze the road without any precipitation
+    let humid = hour.humidity.0 >= 90.0;
+
+    match hour.temperature {
+        t if t <= 0.0 && hour.rain.unwrap_or(0.0) >= WET_MM => Risk::High,
+        t if t <= 0.0 && wet => Risk::High,
+        t if t <= 0.0 && humid => Risk::Moderate,
+        // Road surfaces cool below the air temperature on clear nights
+        t if t <= 2.0 && (wet || humid) => Risk::Moderate,
+        _ => Risk::Low,
+    }
+}
+
+/// Aquaplaning risk from the rain rate (mm/h)
+pub fn aquaplaning_risk(hour: &HourlyForecast) -> Risk {
+    if hour.temperature <= 0.0 {
+        return Risk::Low;
+    }
+    match hour.rain.unwrap_or(0.0) {
+        r if r >= 8.0 => Risk::High,
+        r if r >= 4.0 => Risk::Moderate,
+        _ => Risk::Low,
+    }
+}
+
+/// Split the hours into `BLOCK_HOURS` driving windows and rate each one
+///
+/// `hourly` should start at the first hour to rate; `history` holds the hours
+/// before it, so precipitation that just ended still counts as a wet road.
+pub fn road_blocks(history: &[HourlyForecast], hourly: &[HourlyForecast]) -> Vec<RoadBlock> {
+    let all: Vec<HourlyForecast> = history
+        .iter()
+        .rev()
+        .take(DRYING_HOURS)
+        .rev()
+        .chain(hourly.iter().take(ROAD_HOURS))
+        .cloned()
+        .collect();
+    let offset = all.len() - hourly.len().min(ROAD_HOURS);
+
+    (offset..all.len())
+        .step_by(BLOCK_HOURS)
+        .map(|start| {
+            let range = start..(start + BLOCK_HOURS).min(all.len());
+            let hours = &all[range.clone()];
+
+            let black_ice = range
+                .clone()
+                .map(|i| black_ice_risk(&all, i))
+                .max()
+                .unwrap_or(Risk::Low);
+            let aquaplaning = hours
+                .iter()
+                .map(aquaplaning_risk)
+                .max()
+                .unwrap_or(Risk::Low);
+            let snowfall: f64 = hours.iter().filter_map(|h| h.snow).sum();
+            let min_temperature = hours
+                .iter()
+                .map(|h| h.temperature)
+                .fold(f64::INFINITY, f64::min);
+            let min_freezing_level = hours
+                .iter()
+                .filter_map(|h| h.freezing_level)
+                .min_by(f64::total_cmp);
+            let max_snow_depth = hours
+                .iter()
+                .filter_map(|h| h.snow_depth)
+                .max_by(f64::total_cmp);
+            let wet = hours.iter().any(|h| precipitation(h) >= WET_MM);
+
+            let mut reasons = Vec::new();
+            let mut verdict = RoadVerdict::Go;
+            let mut raise = |level: RoadVerdict, reason: String| {
+                if level == RoadVerdict::Avoid || verdict == RoadVerdict::Go {
+                    verdict = level;
+                }
+                reasons.push(reason);
+            };
+
+            if hours
+                .iter()
+                .any(|h| h.temperature <= 0.0 && h.rain.unwrap_or(0.0) >= WET_MM)
+            {
+                raise(RoadVerdict::Avoid, "Freezing rain".to_string());
+            } else if black_ice == Risk::High {
+                raise(RoadVerdict::Avoid, "Black ice likely".to_string());
+            } else if black_ice == Risk::Moderate {
+                raise(RoadVerdict::Caution, "Possible black ice".to_string());
+            }
+            match aquaplaning {
+                Risk::High => raise(
+                    RoadVerdict::Avoid,
+                    "Heavy rain, high aquaplaning risk".to_string(),
+                ),
+                Risk::Moderate => raise(
+                    RoadVerdict::Caution,
+                    "Heavy rain, aquaplaning possible".to_string(),
+                ),
+                Risk::Low => {}
+            }
+            if snowfall >= 5.0 {
+                raise(
+                    RoadVerdict::Avoid,
+                    format!("{:.0} cm of fresh snow", snowfall),
+                );
+            } else if snowfall >= 1.0 {
+                raise(
+                    RoadVerdict::Caution,
+                    format!("{:.0} cm of fresh snow", snowfall),
+                );
+            }
+            if let Some(level) = min_freezing_level.filter(|l| wet && *l < PASS_ALTITUDE) {
+                raise(
+                    RoadVerdict::Caution,
+                    format!("Snow line at {:.0} m, passes may be icy", level),
+                );
+            }
+
+            RoadBlock {
+                start: hours[0].timestamp,
+                end: hours[hours.len() - 1].timestamp + Duration::hours(1),
+                verdict,
+                black_ice,
+                aquaplaning,
+                snowfall,
+                min_temperature,
+                min_freezing_level,
+                max_snow_depth,
+                reasons,
+            }
+        })
+        .collect()
+}
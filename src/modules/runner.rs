@@ -0,0 +1,1611 @@
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Utc};
+use colored::*;
+use futures::future::{join_all, BoxFuture};
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+use crate::modules::accumulation::{accumulation, Accumulation};
+use crate::modules::activity::{
+    best_window, hourly_scores, parse_duration_hours, BestWindow, HourScore,
+};
+use crate::modules::agriculture::{AgricultureReport, AgricultureService};
+use crate::modules::aviation::{aviation_report, AviationReport};
+use crate::modules::climate::{
+    compare_week, day_normals, monthly_normals, ClimateComparison, ClimateService, DayNormals,
+};
+use crate::modules::compare::ProviderComparison;
+use crate::modules::config::ConfigFile;
+use crate::modules::dashboard::{fetch_cards, run_dashboard, LocationCard};
+use crate::modules::energy::{energy_report, EnergyReport, ENERGY_HOURS};
+use crate::modules::ensemble::{Ensemble, EnsembleService};
+use crate::modules::errors::{ErrorKind, WeatherError};
+use crate::modules::events::{
+    event_outlooks, load_calendar, outdoor_keywords, parse_ics, EventOutlook,
+};
+use crate::modules::fire::{fire_alerts, FireReport, FireService};
+use crate::modules::forecaster::{
+    RequestVariables, WeatherForecaster, MAX_FORECAST_DAYS, PROVIDER,
+};
+use crate::modules::freshness::Freshness;
+use crate::modules::hazards::{Hazard, HazardService};
+use crate::modules::hourly::{days_needed, hourly_window, HourlyStart};
+use crate::modules::http::HttpContext;
+use crate::modules::laundry::{laundry_report, LaundryReport};
+use crate::modules::location::{LocationOrigin, LocationService};
+use crate::modules::models::{ModelComparison, ModelsService, DEFAULT_MODELS};
+use crate::modules::pager::{pager_command, Pager};
+use crate::modules::privacy::redact_coordinates;
+use crate::modules::progress::{Progress, Stage};
+use crate::modules::radar::{RadarFrame, RadarImage, RadarService};
+use crate::modules::risk::{assess_risk, DayRisk};
+use crate::modules::roads::{road_blocks, RoadBlock};
+use crate::modules::sections::Section;
+use crate::modules::summary::narrative;
+use crate::modules::sun::{sun_times, SunTimes};
+use crate::modules::template::{weather_scope, TemplateEngine, TemplateReport};
+use crate::modules::tui::{
+    run_climate_chart, run_ensemble_chart, run_models_chart, KeyBindings, TuiExit, WeatherTui,
+};
+use crate::modules::types::{
+    CurrentWeather, DailyForecast, DetailLevel, Forecast, HourlyForecast, Location, WeatherAlert,
+    WeatherConfig,
+};
+use crate::modules::ui::{convert_to_local, WeatherUI};
+use crate::modules::utils::{to_json, DisplayUnits};
+use crate::modules::uv::{uv_plan, UvPlan};
+use crate::modules::warnings::multi_day_warnings;
+use crate::modules::widget::{widget_from_forecast, Widget, WIDGET_HEIGHT, WIDGET_WIDTH};
+use crate::modules::winter::{winter_report, WinterReport};
+
+/// Display mode selected with `--mode`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Current,
+    Forecast,
+    Hourly,
+    Daily,
+    Full,
+    Interactive,
+    Canvas,
+    Art,
+    Big,
+    Sun,
+    BestWindow,
+    Laundry,
+    Favorites,
+    Radar,
+    Uv,
+    Energy,
+    Agriculture,
+    Aviation,
+    Roads,
+    Template,
+    Climate,
+    Ensemble,
+    Models,
+    Compare,
+    Fire,
+    Events,
+    Widget,
+}
+
+impl Mode {
+    pub const ALL: [Mode; 27] = [
+        Mode::Current,
+        Mode::Forecast,
+        Mode::Hourly,
+        Mode::Daily,
+        Mode::Full,
+        Mode::Interactive,
+        Mode::Canvas,
+        Mode::Art,
+        Mode::Big,
+        Mode::Sun,
+        Mode::BestWindow,
+        Mode::Laundry,
+        Mode::Favorites,
+        Mode::Radar,
+        Mode::Uv,
+        Mode::Energy,
+        Mode::Agriculture,
+        Mode::Aviation,
+        Mode::Roads,
+        Mode::Template,
+        Mode::Climate,
+        Mode::Ensemble,
+        Mode::Models,
+        Mode::Compare,
+        Mode::Fire,
+        Mode::Events,
+        Mode::Widget,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Mode::Current => "current",
+            Mode::Forecast => "forecast",
+            Mode::Hourly => "hourly",
+            Mode::Daily => "daily",
+            Mode::Full => "full",
+            Mode::Interactive => "interactive",
+            Mode::Canvas => "canvas",
+            Mode::Art => "art",
+            Mode::Big => "big",
+            Mode::Sun => "sun",
+            Mode::BestWindow => "best-window",
+            Mode::Laundry => "laundry",
+            Mode::Favorites => "favorites",
+            Mode::Radar => "radar",
+            Mode::Uv => "uv",
+            Mode::Energy => "energy",
+            Mode::Agriculture => "agriculture",
+            Mode::Aviation => "aviation",
+            Mode::Roads => "roads",
+            Mode::Template => "template",
+            Mode::Climate => "climate",
+            Mode::Ensemble => "ensemble",
+            Mode::Models => "models",
+            Mode::Compare => "compare",
+            Mode::Fire => "fire",
+            Mode::Events => "events",
+            Mode::Widget => "widget",
+        }
+    }
+
+    /// Open-Meteo variables the mode needs, for `--minimal`
+    ///
+    /// Reports followed by the interactive charts need everything.
+    pub fn minimal_variables(&self, with_charts: bool) -> RequestVariables {
+        let none = RequestVariables::none();
+        match self {
+            _ if with_charts && self.is_report() => RequestVariables::full(),
+            Mode::Current | Mode::Big | Mode::Aviation | Mode::Favorites | Mode::Compare => {
+                none.with_current()
+            }
+            Mode::Hourly => none.with_hourly(&["precipitation_probability", "rain", "snowfall"]),
+            Mode::Daily | Mode::Sun => none.with_daily(),
+            Mode::BestWindow => none
+                .with_hourly(&["precipitation_probability"])
+                .with_daily(),
+            Mode::Laundry => none.with_hourly(&["precipitation_probability"]),
+            Mode::Events => none.with_hourly(&["precipitation_probability"]),
+            Mode::Uv => none.with_hourly(&["uv_index"]),
+            Mode::Energy => none.with_hourly(&["shortwave_radiation", "direct_radiation"]),
+            Mode::Roads => {
+                none.with_hourly(&["rain", "snowfall", "freezing_level_height", "snow_depth"])
+            }
+            Mode::Climate => none.with_daily(),
+            // Other modes show everything or use their own services
+            _ => RequestVariables::full(),
+        }
+    }
+
+    /// Whether the mode opens with the banner and location box and ends with
+    /// the interactive weather view
+    fn is_report(&self) -> bool {
+        matches!(
+            self,
+            Mode::Current | Mode::Forecast | Mode::Hourly | Mode::Daily | Mode::Full
+        )
+    }
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Mode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Mode::ALL
+            .into_iter()
+            .find(|mode| mode.name() == s)
+            .ok_or_else(|| {
+                let names: Vec<_> = Mode::ALL.iter().map(Mode::name).collect();
+                anyhow!("Invalid mode '{}'. Valid modes: {}", s, names.join(", "))
+            })
+    }
+}
+
+/// Source of weather data, implemented by `WeatherForecaster`
+pub trait ForecastSource: Send + Sync {
+    fn current_weather<'a>(
+        &'a self,
+        location: &'a Location,
+    ) -> BoxFuture<'a, Result<CurrentWeather>>;
+    fn hourly_forecast<'a>(
+        &'a self,
+        location: &'a Location,
+    ) -> BoxFuture<'a, Result<Vec<HourlyForecast>>>;
+    fn daily_forecast<'a>(
+        &'a self,
+        location: &'a Location,
+    ) -> BoxFuture<'a, Result<Vec<DailyForecast>>>;
+    fn forecast<'a>(&'a self, location: &'a Location) -> BoxFuture<'a, Result<Forecast>>;
+    /// A forecast covering at least the given number of days, for sources
+    /// that can look further ahead than the usual week
+    fn forecast_days<'a>(
+        &'a self,
+        location: &'a Location,
+        _days: u8,
+    ) -> BoxFuture<'a, Result<Forecast>> {
+        self.forecast(location)
+    }
+    /// When the data served for a location was fetched, if it can be older
+    /// than the request (e.g. from a cache)
+    fn fetched_at(&self, _location: &Location) -> Option<DateTime<Utc>> {
+        None
+    }
+    /// Provider and model of the forecasts, as logged for `weather_man accuracy`
+    fn provider(&self) -> String {
+        PROVIDER.to_string()
+    }
+}
+
+impl ForecastSource for WeatherForecaster {
+    fn current_weather<'a>(
+        &'a self,
+        location: &'a Location,
+    ) -> BoxFuture<'a, Result<CurrentWeather>> {
+        Box::pin(self.get_current_weather(location))
+    }
+
+    fn hourly_forecast<'a>(
+        &'a self,
+        location: &'a Location,
+    ) -> BoxFuture<'a, Result<Vec<HourlyForecast>>> {
+        Box::pin(self.get_hourly_forecast(location))
+    }
+
+    fn daily_forecast<'a>(
+        &'a self,
+        location: &'a Location,
+    ) -> BoxFuture<'a, Result<Vec<DailyForecast>>> {
+        Box::pin(self.get_daily_forecast(location))
+    }
+
+    fn forecast<'a>(&'a self, location: &'a Location) -> BoxFuture<'a, Result<Forecast>> {
+        Box::pin(self.get_forecast(location))
+    }
+
+    fn forecast_days<'a>(
+        &'a self,
+        location: &'a Location,
+        days: u8,
+    ) -> BoxFuture<'a, Result<Forecast>> {
+        Box::pin(self.get_forecast_days(location, days))
+    }
+
+    fn provider(&self) -> String {
+        WeatherForecaster::provider(self)
+    }
+}
+
+/// Source of locations, implemented by `LocationService`
+pub trait LocationSource: Send + Sync {
+    fn location_by_name<'a>(&'a self, name: &'a str) -> BoxFuture<'a, Result<Location>>;
+    fn location_from_ip(&self) -> BoxFuture<'_, Result<Location>>;
+}
+
+impl LocationSource for LocationService {
+    fn location_by_name<'a>(&'a self, name: &'a str) -> BoxFuture<'a, Result<Location>> {
+        Box::pin(self.get_location_by_name(name))
+    }
+
+    fn location_from_ip(&self) -> BoxFuture<'_, Result<Location>> {
+        Box::pin(self.get_location_from_ip())
+    }
+}
+
+/// The configured location, or the one detected from the IP address
+pub async fn resolve_location(
+    locations: &dyn LocationSource,
+    config: &WeatherConfig,
+) -> Result<Location> {
+    let location = match &config.location {
+        Some(name) => locations.location_by_name(name).await?,
+        None => locations.location_from_ip().await?,
+    };
+    Ok(with_display_timezone(location, config))
+}
+
+/// The location with the `--tz` zone to show its clock times in
+pub fn with_display_timezone(mut location: Location, config: &WeatherConfig) -> Location {
+    location.display_timezone = config.timezone.clone();
+    location
+}
+
+/// Terminal output of the display modes, implemented by `WeatherUI`
+pub trait WeatherView: Send + Sync {
+    fn show_welcome_banner(&self) -> Result<()>;
+    fn show_location_info(&self, location: &Location) -> Result<()>;
+    fn show_current_weather(&self, weather: &CurrentWeather, location: &Location) -> Result<()>;
+    fn show_hourly_forecast(&self, forecast: &[HourlyForecast], location: &Location) -> Result<()>;
+    fn show_daily_forecast(
+        &self,
+        forecast: &[DailyForecast],
+        location: &Location,
+        warnings: &[WeatherAlert],
+    ) -> Result<()>;
+    fn show_forecast(
+        &self,
+        forecast: &Forecast,
+        location: &Location,
+        warnings: &[WeatherAlert],
+    ) -> Result<()>;
+    fn show_risk_summary(&self, risks: &[DayRisk]) -> Result<()>;
+    fn show_freshness(&self, freshness: &Freshness, location: &Location) -> Result<()>;
+    fn show_weather_recommendations(
+        &self,
+        weather: &CurrentWeather,
+        location: &Location,
+        settings: &WeatherConfig,
+    ) -> Result<()>;
+    fn show_current_debug(&self, weather: &CurrentWeather) -> Result<()>;
+    fn show_hourly_debug(&self, forecast: &[HourlyForecast], location: &Location) -> Result<()>;
+    fn show_daily_debug(&self, forecast: &[DailyForecast]) -> Result<()>;
+    fn show_sun_times(&self, sun: &[SunTimes], location: &Location) -> Result<()>;
+    fn show_best_window(
+        &self,
+        scores: &[HourScore],
+        best: Option<&BestWindow>,
+        hours: usize,
+        location: &Location,
+    ) -> Result<()>;
+    fn show_laundry(&self, report: &LaundryReport, location: &Location) -> Result<()>;
+    fn show_hazards(&self, hazards: &[Hazard], location: &Location) -> Result<()>;
+    fn show_day_normals(&self, normals: &DayNormals, high: Option<f64>) -> Result<()>;
+    fn show_accumulation(&self, accumulation: &Accumulation, location: &Location) -> Result<()>;
+    fn show_winter(&self, report: &WinterReport, location: &Location) -> Result<()>;
+    fn show_climate_week(&self, week: &[ClimateComparison], location: &Location) -> Result<()>;
+    fn show_ensemble(&self, ensemble: &Ensemble, location: &Location) -> Result<()>;
+    fn show_extra_variables(&self, forecast: &[HourlyForecast], location: &Location) -> Result<()>;
+    fn show_models(&self, comparison: &ModelComparison, location: &Location) -> Result<()>;
+    fn show_provider_comparison(
+        &self,
+        comparison: &ProviderComparison,
+        location: &Location,
+    ) -> Result<()>;
+    fn show_uv_plan(&self, plan: &UvPlan, location: &Location) -> Result<()>;
+    fn show_summary(&self, summary: &str) -> Result<()>;
+    fn show_template(&self, report: &TemplateReport) -> Result<()>;
+    fn show_energy(&self, report: &EnergyReport, location: &Location) -> Result<()>;
+    fn show_roads(&self, blocks: &[RoadBlock], location: &Location) -> Result<()>;
+    fn show_agriculture(&self, report: &AgricultureReport, location: &Location) -> Result<()>;
+    fn show_fire(&self, report: &FireReport, location: &Location) -> Result<()>;
+    fn show_events(&self, outlooks: &[EventOutlook], location: &Location) -> Result<()>;
+    fn show_aviation(
+        &self,
+        report: &AviationReport,
+        weather: &CurrentWeather,
+        location: &Location,
+    ) -> Result<()>;
+    fn show_radar(&self, frames: &[(RadarFrame, RadarImage)], location: &Location) -> Result<()>;
+    fn show_big_display(&self, weather: &CurrentWeather, location: &Location) -> Result<()>;
+    fn show_widget(&self, widget: &Widget, width: usize, height: usize) -> Result<()>;
+    fn show_art_report(&self, forecast: &Forecast, location: &Location) -> Result<()>;
+    /// Run the full-screen weather view until the user quits
+    #[allow(clippy::too_many_arguments)]
+    fn show_canvas(
+        &self,
+        hourly: Vec<HourlyForecast>,
+        daily: Vec<DailyForecast>,
+        location: Location,
+        origin: LocationOrigin,
+        fetched_at: Option<DateTime<Utc>>,
+        favorites: Vec<LocationCard>,
+        status: Option<String>,
+        config: &WeatherConfig,
+        settings: &ConfigFile,
+    ) -> Result<TuiExit>;
+    fn show_interactive_menu(&self, show_charts: bool) -> Result<String>;
+    fn prompt_for_location(&self) -> Result<String>;
+    fn prompt_for_units(&self) -> Result<String>;
+    /// A copy of this view displaying values in other units
+    fn with_display_units(&self, units: DisplayUnits) -> Arc<dyn WeatherView>;
+}
+
+impl WeatherView for WeatherUI {
+    fn show_welcome_banner(&self) -> Result<()> {
+        WeatherUI::show_welcome_banner(self)
+    }
+
+    fn show_location_info(&self, location: &Location) -> Result<()> {
+        WeatherUI::show_location_info(self, location)
+    }
+
+    fn show_current_weather(&self, weather: &CurrentWeather, location: &Location) -> Result<()> {
+        WeatherUI::show_current_weather(self, weather, location)
+    }
+
+    fn show_hourly_forecast(&self, forecast: &[HourlyForecast], location: &Location) -> Result<()> {
+        WeatherUI::show_hourly_forecast(self, forecast, location)
+    }
+
+    fn show_daily_forecast(
+        &self,
+        forecast: &[DailyForecast],
+        location: &Location,
+        warnings: &[WeatherAlert],
+    ) -> Result<()> {
+        WeatherUI::show_daily_forecast(self, forecast, location, warnings)
+    }
+
+    fn show_forecast(
+        &self,
+        forecast: &Forecast,
+        location: &Location,
+        warnings: &[WeatherAlert],
+    ) -> Result<()> {
+        WeatherUI::show_forecast(self, forecast, location, warnings)
+    }
+
+    fn show_freshness(&self, freshness: &Freshness, location: &Location) -> Result<()> {
+        WeatherUI::show_freshness(self, freshness, location)
+    }
+
+    fn show_risk_summary(&self, risks: &[DayRisk]) -> Result<()> {
+        WeatherUI::show_risk_summary(self, risks)
+    }
+
+    fn show_weather_recommendations(
+        &self,
+        weather: &CurrentWeather,
+        location: &Location,
+        settings: &WeatherConfig,
+    ) -> Result<()> {
+        WeatherUI::show_weather_recommendations(self, weather, location, settings)
+    }
+
+    fn show_current_debug(&self, weather: &CurrentWeather) -> Result<()> {
+        WeatherUI::show_current_debug(self, weather)
+    }
+
+    fn show_hourly_debug(&self, forecast: &[HourlyForecast], location: &Location) -> Result<()> {
+        WeatherUI::show_hourly_debug(self, forecast, location)
+    }
+
+    fn show_daily_debug(&self, forecast: &[DailyForecast]) -> Result<()> {
+        WeatherUI::show_daily_debug(self, forecast)
+    }
+
+    fn show_sun_times(&self, sun: &[SunTimes], location: &Location) -> Result<()> {
+        WeatherUI::show_sun_times(self, sun, location)
+    }
+
+    fn show_best_window(
+        &self,
+        scores: &[HourScore],
+        best: Option<&BestWindow>,
+        hours: usize,
+        location: &Location,
+    ) -> Result<()> {
+        WeatherUI::show_best_window(self, scores, best, hours, location)
+    }
+
+    fn show_laundry(&self, report: &LaundryReport, location: &Location) -> Result<()> {
+        WeatherUI::show_laundry(self, report, location)
+    }
+
+    fn show_hazards(&self, hazards: &[Hazard], location: &Location) -> Result<()> {
+        WeatherUI::show_hazards(self, hazards, location)
+    }
+
+    fn show_day_normals(&self, normals: &DayNormals, high: Option<f64>) -> Result<()> {
+        WeatherUI::show_day_normals(self, normals, high)
+    }
+
+    fn show_accumulation(&self, accumulation: &Accumulation, location: &Location) -> Result<()> {
+        WeatherUI::show_accumulation(self, accumulation, location)
+    }
+
+    fn show_winter(&self, report: &WinterReport, location: &Location) -> Result<()> {
+        WeatherUI::show_winter(self, report, location)
+    }
+
+    fn show_climate_week(&self, week: &[ClimateComparison], location: &Location) -> Result<()> {
+        WeatherUI::show_climate_week(self, week, location)
+    }
+
+    fn show_ensemble(&self, ensemble: &Ensemble, location: &Location) -> Result<()> {
+        WeatherUI::show_ensemble(self, ensemble, location)
+    }
+
+    fn show_extra_variables(&self, forecast: &[HourlyForecast], location: &Location) -> Result<()> {
+        WeatherUI::show_extra_variables(self, forecast, location)
+    }
+
+    fn show_models(&self, comparison: &ModelComparison, location: &Location) -> Result<()> {
+        WeatherUI::show_models(self, comparison, location)
+    }
+
+    fn show_provider_comparison(
+        &self,
+        comparison: &ProviderComparison,
+        location: &Location,
+    ) -> Result<()> {
+        WeatherUI::show_provider_comparison(self, comparison, location)
+    }
+
+    fn show_uv_plan(&self, plan: &UvPlan, location: &Location) -> Result<()> {
+        WeatherUI::show_uv_plan(self, plan, location)
+    }
+
+    fn show_summary(&self, summary: &str) -> Result<()> {
+        WeatherUI::show_summary(self, summary)
+    }
+
+    fn show_template(&self, report: &TemplateReport) -> Result<()> {
+        WeatherUI::show_template(self, report)
+    }
+
+    fn show_energy(&self, report: &EnergyReport, location: &Location) -> Result<()> {
+        WeatherUI::show_energy(self, report, location)
+    }
+
+    fn show_roads(&self, blocks: &[RoadBlock], location: &Location) -> Result<()> {
+        WeatherUI::show_roads(self, blocks, location)
+    }
+
+    fn show_agriculture(&self, report: &AgricultureReport, location: &Location) -> Result<()> {
+        WeatherUI::show_agriculture(self, report, location)
+    }
+
+    fn show_fire(&self, report: &FireReport, location: &Location) -> Result<()> {
+        WeatherUI::show_fire(self, report, location)
+    }
+
+    fn show_events(&self, outlooks: &[EventOutlook], location: &Location) -> Result<()> {
+        WeatherUI::show_events(self, outlooks, location)
+    }
+
+    fn show_aviation(
+        &self,
+        report: &AviationReport,
+        weather: &CurrentWeather,
+        location: &Location,
+    ) -> Result<()> {
+        WeatherUI::show_aviation(self, report, weather, location)
+    }
+
+    fn show_radar(&self, frames: &[(RadarFrame, RadarImage)], location: &Location) -> Result<()> {
+        WeatherUI::show_radar(self, frames, location)
+    }
+
+    fn show_big_display(&self, weather: &CurrentWeather, location: &Location) -> Result<()> {
+        WeatherUI::show_big_display(self, weather, location)
+    }
+
+    fn show_widget(&self, widget: &Widget, width: usize, height: usize) -> Result<()> {
+        WeatherUI::show_widget(self, widget, width, height)
+    }
+
+    fn show_art_report(&self, forecast: &Forecast, location: &Location) -> Result<()> {
+        WeatherUI::show_art_report(self, forecast, location)
+    }
+
+    fn show_canvas(
+        &self,
+        hourly: Vec<HourlyForecast>,
+        daily: Vec<DailyForecast>,
+        location: Location,
+        origin: LocationOrigin,
+        fetched_at: Option<DateTime<Utc>>,
+        favorites: Vec<LocationCard>,
+        status: Option<String>,
+        config: &WeatherConfig,
+        settings: &ConfigFile,
+    ) -> Result<TuiExit> {
+        // Clear screen for clean TUI transition
+        print!("\x1B[2J\x1B[1;1H");
+        std::io::Write::flush(&mut std::io::stdout()).unwrap_or(());
+
+        let mut tui = WeatherTui::new(hourly, daily, location, config.clone(), settings)?
+            .with_origin(origin)
+            .with_fetched_at(fetched_at.unwrap_or_else(Utc::now))
+            .with_favorites(favorites)
+            .with_status(status);
+        tui.run()
+    }
+
+    fn show_interactive_menu(&self, show_charts: bool) -> Result<String> {
+        WeatherUI::show_interactive_menu(self, show_charts)
+    }
+
+    fn prompt_for_location(&self) -> Result<String> {
+        WeatherUI::prompt_for_location(self)
+    }
+
+    fn prompt_for_units(&self) -> Result<String> {
+        WeatherUI::prompt_for_units(self)
+    }
+
+    fn with_display_units(&self, units: DisplayUnits) -> Arc<dyn WeatherView> {
+        Arc::new(self.clone().with_units(units))
+    }
+}
+
+/// Runs the display modes against injected data sources and output
+#[derive(Clone)]
+pub struct Runner {
+    forecaster: Arc<dyn ForecastSource>,
+    locations: Arc<dyn LocationSource>,
+    ui: Arc<dyn WeatherView>,
+    config: WeatherConfig,
+    settings: ConfigFile,
+    duration: String,
+    http: HttpContext,
+    hazards: Option<HazardService>,
+    climate: Option<ClimateService>,
+    runway: Option<f64>,
+    models: Vec<String>,
+    /// The two sources compared by the compare mode, with their names
+    providers: Vec<(String, Arc<dyn ForecastSource>)>,
+    widget_size: (usize, usize),
+    /// iCalendar file or URL checked by the events mode
+    calendar: Option<String>,
+    /// First hour of the hourly table, from the start of the forecast when unset
+    hourly_start: Option<HourlyStart>,
+    pager: bool,
+    privacy: bool,
+    /// Location looked up by the first mode, reused by later ones and clones
+    resolved: Arc<Mutex<Option<ResolvedLocation>>>,
+}
+
+/// A location lookup remembered by the runner
+#[derive(Clone)]
+struct ResolvedLocation {
+    /// The `--location` it was resolved for, `None` when detected
+    query: Option<String>,
+    location: Location,
+    origin: LocationOrigin,
+}
+
+impl Runner {
+    pub fn new(
+        forecaster: Arc<dyn ForecastSource>,
+        locations: Arc<dyn LocationSource>,
+        ui: Arc<dyn WeatherView>,
+        config: WeatherConfig,
+    ) -> Self {
+        Self {
+            forecaster,
+            locations,
+            ui,
+            config,
+            settings: ConfigFile::default(),
+            duration: "2h".to_string(),
+            http: HttpContext::default(),
+            hazards: None,
+            climate: None,
+            runway: None,
+            models: DEFAULT_MODELS.iter().map(|m| m.to_string()).collect(),
+            providers: Vec::new(),
+            widget_size: (WIDGET_WIDTH, WIDGET_HEIGHT),
+            calendar: None,
+            hourly_start: None,
+            pager: false,
+            privacy: false,
+            resolved: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Warnings, favorites and key bindings from the config file
+    pub fn with_settings(mut self, settings: ConfigFile) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Window length searched by the best-window mode (e.g. 2h, 90m)
+    pub fn with_duration(mut self, duration: &str) -> Self {
+        self.duration = duration.to_string();
+        self
+    }
+
+    /// HTTP context for the radar and agriculture requests
+    pub fn with_http(mut self, http: HttpContext) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// Append nearby earthquakes and other hazards to the full report
+    pub fn with_hazards(mut self, hazards: HazardService) -> Self {
+        self.hazards = Some(hazards);
+        self
+    }
+
+    /// Append today's climate normals and records to the full report
+    pub fn with_climate(mut self, climate: ClimateService) -> Self {
+        self.climate = Some(climate);
+        self
+    }
+
+    /// Runway heading (degrees) for the crosswind components of the aviation mode
+    pub fn with_runway(mut self, heading: f64) -> Self {
+        self.runway = Some(heading);
+        self
+    }
+
+    /// Models compared by the models mode
+    pub fn with_models(mut self, models: Vec<String>) -> Self {
+        self.models = models;
+        self
+    }
+
+    /// Sources compared side by side by the compare mode
+    pub fn with_providers(mut self, providers: Vec<(String, Arc<dyn ForecastSource>)>) -> Self {
+        self.providers = providers;
+        self
+    }
+
+    /// Card size (columns, rows) of the widget mode
+    pub fn with_widget_size(mut self, width: usize, height: usize) -> Self {
+        self.widget_size = (width, height);
+        self
+    }
+
+    /// Calendar checked by the events mode instead of `[events] calendar`
+    pub fn with_calendar(mut self, calendar: &str) -> Self {
+        self.calendar = Some(calendar.to_string());
+        self
+    }
+
+    /// Start the hourly table at another hour or day, fetching further ahead if needed
+    pub fn with_hourly_start(mut self, start: HourlyStart) -> Self {
+        self.hourly_start = Some(start);
+        self
+    }
+
+    /// Page the text reports through `$PAGER`
+    pub fn with_pager(mut self, enabled: bool) -> Self {
+        self.pager = enabled;
+        self
+    }
+
+    /// Round coordinates in JSON output
+    pub fn with_privacy(mut self, enabled: bool) -> Self {
+        self.privacy = enabled;
+        self
+    }
+
+    /// Run a display mode
+    pub async fn run(&self, mode: Mode) -> Result<()> {
+        match mode {
+            Mode::Interactive => return self.run_interactive().await,
+            Mode::Canvas => return self.run_canvas().await,
+            Mode::Favorites => return self.run_favorites().await,
+            _ => {}
+        }
+
+        let json = self.config.json_output;
+        if mode.is_report() && !json {
+            self.ui.show_welcome_banner()?;
+        }
+
+        let progress = Progress::new(mode.is_report() && !json);
+        progress.stage(match self.config.location {
+            Some(_) => Stage::Geocoding,
+            None => Stage::Locating,
+        });
+        let location = self.location().await?.location;
+        progress.clear();
+        let pager = match pager_command() {
+            Some(command) if self.pager && mode.is_report() && !json => Pager::start(&command)?,
+            _ => None,
+        };
+        // The spinner would draw over the pager, which owns the terminal now
+        let progress = if pager.is_some() {
+            Progress::new(false)
+        } else {
+            progress
+        };
+        if mode.is_report() && !json {
+            self.ui.show_location_info(&location)?;
+        }
+
+        match mode {
+            Mode::Current => self.current(&location, &progress).await?,
+            Mode::Forecast => self.forecast(&location, &progress).await?,
+            Mode::Hourly => self.hourly(&location, &progress).await?,
+            Mode::Daily => self.daily(&location, &progress).await?,
+            Mode::Full => self.full(&location, &progress).await?,
+            Mode::Art => {
+                let forecast = self.forecaster.forecast(&location).await?;
+                if json {
+                    self.print_json(&forecast)?;
+                } else {
+                    self.ui.show_art_report(&forecast, &location)?;
+                }
+            }
+            Mode::Big => {
+                let weather = self.forecaster.current_weather(&location).await?;
+                if json {
+                    self.print_json(&weather)?;
+                } else {
+                    self.ui.show_big_display(&weather, &location)?;
+                }
+            }
+            Mode::Widget => {
+                let forecast = self.forecaster.forecast(&location).await?;
+                let widget =
+                    widget_from_forecast(&forecast, &location, self.config.icons, Utc::now())
+                        .ok_or_else(|| anyhow!("No current conditions for the widget"))?;
+                if json {
+                    self.print_json(&widget)?;
+                } else {
+                    let (width, height) = self.widget_size;
+                    self.ui.show_widget(&widget, width, height)?;
+                }
+            }
+            Mode::Sun => {
+                let daily = self.forecaster.daily_forecast(&location).await?;
+                let sun = sun_times(&daily);
+                if json {
+                    self.print_json(&sun)?;
+                } else {
+                    self.ui.show_sun_times(&sun, &location)?;
+                }
+            }
+            Mode::BestWindow => self.best_window(&location).await?,
+            Mode::Laundry => self.laundry(&location).await?,
+            Mode::Events => self.events(&location).await?,
+            Mode::Radar => self.radar(&location).await?,
+            Mode::Uv => self.uv(&location).await?,
+            Mode::Energy => self.energy(&location).await?,
+            Mode::Roads => self.roads(&location).await?,
+            Mode::Template => self.template(&location).await?,
+            Mode::Climate => self.climate(&location).await?,
+            Mode::Models => {
+                let comparison = ModelsService::new(self.http.clone())
+                    .compare(&location, &self.models, Utc::now())
+                    .await?;
+                if json {
+                    self.print_json(&comparison)?;
+                } else {
+                    self.ui.show_models(&comparison, &location)?;
+                    if !self.config.no_charts {
+                        run_models_chart(&comparison, &location)?;
+                    }
+                }
+            }
+            Mode::Compare => {
+                let comparison = self.compare_providers(&location).await?;
+                if json {
+                    self.print_json(&comparison)?;
+                } else {
+                    self.ui.show_provider_comparison(&comparison, &location)?;
+                }
+            }
+            Mode::Ensemble => {
+                let ensemble = EnsembleService::new(self.http.clone())
+                    .fetch(&location)
+                    .await?;
+                if json {
+                    self.print_json(&ensemble)?;
+                } else {
+                    self.ui.show_ensemble(&ensemble, &location)?;
+                    if !self.config.no_charts {
+                        run_ensemble_chart(&ensemble, &location)?;
+                    }
+                }
+            }
+            Mode::Aviation => {
+                let weather = self.forecaster.current_weather(&location).await?;
+                let report = aviation_report(&weather, self.runway);
+                if json {
+                    self.print_json(&report)?;
+                } else {
+                    self.ui.show_aviation(&report, &weather, &location)?;
+                }
+            }
+            Mode::Agriculture => {
+                let report = AgricultureService::new(self.http.clone())
+                    .report(&location)
+                    .await?;
+                if json {
+                    self.print_json(&report)?;
+                } else {
+                    self.ui.show_agriculture(&report, &location)?;
+                }
+            }
+            Mode::Fire => {
+                let report = FireService::new(self.http.clone())
+                    .report(&location)
+                    .await?;
+                if json {
+                    self.print_json(&report)?;
+                } else {
+                    self.ui.show_fire(&report, &location)?;
+                }
+            }
+            Mode::Interactive | Mode::Canvas | Mode::Favorites => {
+                unreachable!("handled above")
+            }
+        }
+
+        if mode.is_report() && !json {
+            if let Some(freshness) = self.freshness() {
+                self.ui.show_freshness(&freshness, &location)?;
+            }
+        }
+
+        // Follow the text reports with the interactive weather view
+        if let Some(pager) = pager {
+            pager.finish();
+        }
+        if mode.is_report() && !json && !self.config.no_charts {
+            println!("\n🌤️  Loading interactive weather view...");
+            if let Err(e) = self.run_canvas().await {
+                eprintln!("⚠️  Weather view unavailable: {}", e);
+                eprintln!("💡 Try running with --no-charts for text-only output");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Render the weather view off-screen and save it to `path`
+    pub async fn screenshot(&self, path: &Path) -> Result<()> {
+        let (hourly, daily, location) = self.canvas_data().await?;
+        WeatherTui::screenshot(
+            hourly,
+            daily,
+            location,
+            self.config.clone(),
+            &self.settings,
+            path,
+        )?;
+        println!("📸 Screenshot saved to {}", path.display());
+        Ok(())
+    }
+
+    /// Frost and heatwave warnings plus extreme fire weather, by start time
+    fn warnings(
+        &self,
+        daily: &[DailyForecast],
+        hourly: &[HourlyForecast],
+        location: &Location,
+    ) -> Vec<WeatherAlert> {
+        let mut warnings = multi_day_warnings(daily, &self.settings.warnings);
+        warnings.extend(fire_alerts(hourly, location));
+        warnings.sort_by_key(|alert| alert.start);
+        warnings
+    }
+
+    /// Accumulation, winter and risk panels that follow the daily forecast
+    fn show_outlook_panels(
+        &self,
+        daily: &[DailyForecast],
+        hourly: &[HourlyForecast],
+        location: &Location,
+    ) -> Result<()> {
+        let now = Utc::now();
+        if let Some(accumulation) =
+            accumulation(hourly, now).filter(|_| self.shows(Section::Accumulation))
+        {
+            self.ui.show_accumulation(&accumulation, location)?;
+        }
+        if let Some(winter) =
+            winter_report(hourly, location, now).filter(|_| self.shows(Section::Winter))
+        {
+            self.ui.show_winter(&winter, location)?;
+        }
+        if self.shows(Section::Risk) {
+            self.ui
+                .show_risk_summary(&assess_risk(daily, hourly, location))?;
+        }
+        Ok(())
+    }
+
+    fn shows(&self, section: Section) -> bool {
+        self.config.sections.shows(section)
+    }
+
+    fn is_debug(&self) -> bool {
+        self.config.detail_level == DetailLevel::Debug
+    }
+
+    async fn current(&self, location: &Location, progress: &Progress) -> Result<()> {
+        progress.stage(Stage::Forecast);
+        let weather = self.forecaster.current_weather(location).await?;
+        if self.config.json_output {
+            return self.print_json(&weather);
+        }
+        // The summary needs the hourly forecast, so it is left out without one
+        let summary = match self.shows(Section::Summary) {
+            true => self
+                .forecaster
+                .forecast(location)
+                .await
+                .ok()
+                .and_then(|forecast| {
+                    narrative(
+                        &forecast,
+                        location,
+                        Utc::now(),
+                        self.config.display_units.language,
+                    )
+                }),
+            false => None,
+        };
+        progress.clear();
+        if let Some(summary) = summary {
+            self.ui.show_summary(&summary)?;
+        }
+        if self.shows(Section::Current) {
+            self.ui.show_current_weather(&weather, location)?;
+        }
+        if self.shows(Section::Recommendations) {
+            self.ui
+                .show_weather_recommendations(&weather, location, &self.config)?;
+        }
+        if self.is_debug() {
+            self.ui.show_current_debug(&weather)?;
+        }
+        Ok(())
+    }
+
+    async fn forecast(&self, location: &Location, progress: &Progress) -> Result<()> {
+        progress.stage(Stage::Forecast);
+        let forecast = self.forecaster.forecast(location).await?;
+        progress.clear();
+        let warnings = self.warnings(&forecast.daily, &forecast.hourly, location);
+        if self.config.json_output {
+            let mut output = serde_json::to_value(&forecast)?;
+            output["alerts"] = serde_json::to_value(&warnings)?;
+            output["risk"] =
+                serde_json::to_value(assess_risk(&forecast.daily, &forecast.hourly, location))?;
+            output["accumulation"] =
+                serde_json::to_value(accumulation(&forecast.hourly, Utc::now()))?;
+            if let Some(winter) = winter_report(&forecast.hourly, location, Utc::now()) {
+                output["winter"] = serde_json::to_value(winter)?;
+            }
+            return self.print_json(&output);
+        }
+        self.ui.show_forecast(&forecast, location, &warnings)?;
+        if self.is_debug() {
+            self.ui.show_daily_debug(&forecast.daily)?;
+        }
+        Ok(())
+    }
+
+    async fn hourly(&self, location: &Location, progress: &Progress) -> Result<()> {
+        progress.stage(Stage::Forecast);
+        let hourly = match &self.hourly_start {
+            Some(start) => {
+                let now = Utc::now();
+                let from = start.resolve(&location.timezone, now);
+                let days = days_needed(from, &location.timezone, now).ok_or_else(|| {
+                    WeatherError::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "No hourly forecast for {}; give a start from now to {} days ahead",
+                            convert_to_local(&from, &location.timezone).format("%Y-%m-%d %H:%M"),
+                            MAX_FORECAST_DAYS - 1
+                        ),
+                    )
+                })?;
+                let forecast = self.forecaster.forecast_days(location, days).await?;
+                hourly_window(&forecast.hourly, from)
+            }
+            None => self.forecaster.hourly_forecast(location).await?,
+        };
+        progress.clear();
+        if self.config.json_output {
+            return self.print_json(&hourly);
+        }
+        self.ui.show_hourly_forecast(&hourly, location)?;
+        if self.config.detail_level >= DetailLevel::Detailed {
+            self.ui.show_extra_variables(&hourly, location)?;
+        }
+        if self.is_debug() {
+            self.ui.show_hourly_debug(&hourly, location)?;
+        }
+        Ok(())
+    }
+
+    async fn daily(&self, location: &Location, progress: &Progress) -> Result<()> {
+        // Hourly data is needed for the risk assessment
+        progress.stage(Stage::Forecast);
+        let forecast = self.forecaster.forecast(location).await?;
+        progress.clear();
+        if self.config.json_output {
+            return self.print_json(&forecast.daily);
+        }
+        let warnings = self.warnings(&forecast.daily, &forecast.hourly, location);
+        if self.shows(Section::Summary) {
+            if let Some(summary) = narrative(
+                &forecast,
+                location,
+                Utc::now(),
+                self.config.display_units.language,
+            ) {
+                self.ui.show_summary(&summary)?;
+            }
+        }
+        self.ui
+            .show_daily_forecast(&forecast.daily, location, &warnings)?;
+        self.show_outlook_panels(&forecast.daily, &forecast.hourly, location)?;
+        if self.is_debug() {
+            self.ui.show_daily_debug(&forecast.daily)?;
+        }
+        Ok(())
+    }
+
+    async fn full(&self, location: &Location, progress: &Progress) -> Result<()> {
+        progress.stage(Stage::Forecast);
+        let current = self.forecaster.current_weather(location).await?;
+        let hourly = self.forecaster.hourly_forecast(location).await?;
+        let daily = self.forecaster.daily_forecast(location).await?;
+        let warnings = self.warnings(&daily, &hourly, location);
+        if self.hazards.is_some() || self.climate.is_some() {
+            progress.stage(Stage::Alerts);
+        }
+        let hazards = match &self.hazards {
+            Some(service) => Some(service.nearby(location).await),
+            None => None,
+        };
+        let normals = match &self.climate {
+            Some(service) => match service.history(location).await {
+                Ok(days) => {
+                    let today = convert_to_local(&Utc::now(), &location.timezone).date_naive();
+                    day_normals(&days, today)
+                }
+                Err(e) => {
+                    eprintln!("⚠️  Climate normals unavailable: {:#}", e);
+                    None
+                }
+            },
+            None => None,
+        };
+        progress.clear();
+
+        if self.config.json_output {
+            let mut report = serde_json::json!({
+                "current": current,
+                "hourly": hourly,
+                "daily": daily,
+                "sun": sun_times(&daily),
+                "alerts": warnings,
+                "risk": assess_risk(&daily, &hourly, location),
+                "accumulation": accumulation(&hourly, Utc::now()),
+            });
+            if let Some(hazards) = &hazards {
+                report["hazards"] = serde_json::to_value(hazards)?;
+            }
+            if let Some(normals) = &normals {
+                report["normals"] = serde_json::to_value(normals)?;
+            }
+            if let Some(winter) = winter_report(&hourly, location, Utc::now()) {
+                report["winter"] = serde_json::to_value(winter)?;
+            }
+            return self.print_json(&report);
+        }
+
+        if self.shows(Section::Current) {
+            self.ui.show_current_weather(&current, location)?;
+            self.pause();
+        }
+        if self.shows(Section::Hourly) {
+            self.ui.show_hourly_forecast(&hourly, location)?;
+            if self.config.detail_level >= DetailLevel::Detailed {
+                self.ui.show_extra_variables(&hourly, location)?;
+            }
+            self.pause();
+        }
+        self.ui.show_daily_forecast(&daily, location, &warnings)?;
+        self.show_outlook_panels(&daily, &hourly, location)?;
+        if self.shows(Section::Recommendations) {
+            self.ui
+                .show_weather_recommendations(&current, location, &self.config)?;
+        }
+        if let Some(hazards) = hazards.as_ref().filter(|_| self.shows(Section::Hazards)) {
+            self.ui.show_hazards(hazards, location)?;
+        }
+        if let Some(normals) = normals.as_ref().filter(|_| self.shows(Section::Normals)) {
+            self.ui
+                .show_day_normals(normals, daily.first().map(|day| day.temp_max))?;
+        }
+        if self.is_debug() {
+            self.ui.show_current_debug(&current)?;
+            self.ui.show_daily_debug(&daily)?;
+        }
+        Ok(())
+    }
+
+    async fn best_window(&self, location: &Location) -> Result<()> {
+        let hours = parse_duration_hours(&self.duration)?;
+        let forecast = self.forecaster.forecast(location).await?;
+        let now = Utc::now();
+        let today = convert_to_local(&now, &location.timezone).date_naive();
+
+        // Remaining hours of today, starting with the current one
+        let scores: Vec<_> = hourly_scores(&forecast.hourly, &forecast.daily, location)
+            .into_iter()
+            .filter(|s| s.timestamp + chrono::Duration::hours(1) > now)
+            .filter(|s| convert_to_local(&s.timestamp, &location.timezone).date_naive() == today)
+            .collect();
+        let best = best_window(&scores, hours);
+
+        if self.config.json_output {
+            return self.print_json(&serde_json::json!({
+                "hours": hours,
+                "best_window": best,
+                "scores": scores,
+            }));
+        }
+        self.ui
+            .show_best_window(&scores, best.as_ref(), hours, location)
+    }
+
+    async fn laundry(&self, location: &Location) -> Result<()> {
+        let hourly = self.forecaster.hourly_forecast(location).await?;
+        // The forecast starts at local midnight; only look ahead from the current hour
+        let now = Utc::now();
+        let upcoming: Vec<_> = hourly
+            .into_iter()
+            .filter(|h| h.timestamp + chrono::Duration::hours(1) > now)
+            .collect();
+        let report = laundry_report(&upcoming);
+
+        if self.config.json_output {
+            return self.print_json(&report);
+        }
+        self.ui.show_laundry(&report, location)
+    }
+
+    async fn events(&self, location: &Location) -> Result<()> {
+        let source = self
+            .calendar
+            .as_ref()
+            .or(self.settings.events.calendar.as_ref())
+            .ok_or_else(|| {
+                WeatherError::new(
+                    ErrorKind::InvalidInput,
+                    "No calendar; give --calendar or set calendar in the [events] config section",
+                )
+            })?;
+        let calendar = load_calendar(source, &self.http).await?;
+        let events = parse_ics(&calendar, &location.timezone);
+        let hourly = self.forecaster.hourly_forecast(location).await?;
+        let outlooks = event_outlooks(
+            &events,
+            &hourly,
+            &outdoor_keywords(&self.settings.events.keywords),
+            &self.settings.thresholds,
+            Utc::now(),
+        );
+
+        if self.config.json_output {
+            return self.print_json(&outlooks);
+        }
+        self.ui.show_events(&outlooks, location)
+    }
+
+    async fn uv(&self, location: &Location) -> Result<()> {
+        let hourly = self.forecaster.hourly_forecast(location).await?;
+        let today = convert_to_local(&Utc::now(), &location.timezone).date_naive();
+        let today_hours: Vec<_> = hourly
+            .into_iter()
+            .filter(|h| convert_to_local(&h.timestamp, &location.timezone).date_naive() == today)
+            .collect();
+        let thresholds = &self.settings.thresholds;
+        let plan = uv_plan(
+            &today_hours,
+            thresholds.uv_protect(),
+            thresholds.uv_warning(),
+        );
+
+        if self.config.json_output {
+            return self.print_json(&plan);
+        }
+        self.ui.show_uv_plan(&plan, location)
+    }
+
+    async fn template(&self, location: &Location) -> Result<()> {
+        let settings = &self.settings.templates;
+        if settings.output.is_none() && settings.alerts.is_empty() {
+            return Err(anyhow!(
+                "No template set; use --template or add output to the [templates] config section"
+            ));
+        }
+        let forecast = self.forecaster.forecast(location).await?;
+        let mut scope = weather_scope(&forecast, location, Utc::now());
+        let report = TemplateEngine::new().report(settings, &mut scope)?;
+
+        if self.config.json_output {
+            return self.print_json(&report);
+        }
+        self.ui.show_template(&report)
+    }
+
+    async fn energy(&self, location: &Location) -> Result<()> {
+        let hourly = self.forecaster.hourly_forecast(location).await?;
+        let now = Utc::now();
+        let upcoming: Vec<_> = hourly
+            .into_iter()
+            .filter(|h| h.timestamp + chrono::Duration::hours(1) > now)
+            .take(ENERGY_HOURS)
+            .collect();
+        let report = energy_report(&upcoming, location, &self.settings.energy);
+
+        if self.config.json_output {
+            return self.print_json(&report);
+        }
+        self.ui.show_energy(&report, location)
+    }
+
+    async fn roads(&self, location: &Location) -> Result<()> {
+        let hourly = self.forecaster.hourly_forecast(location).await?;
+        let now = Utc::now();
+        let (history, upcoming): (Vec<_>, Vec<_>) = hourly
+            .into_iter()
+            .partition(|h| h.timestamp + chrono::Duration::hours(1) <= now);
+        let blocks = road_blocks(&history, &upcoming);
+
+        if self.config.json_output {
+            return self.print_json(&blocks);
+        }
+        self.ui.show_roads(&blocks, location)
+    }
+
+    async fn climate(&self, location: &Location) -> Result<()> {
+        let service = self
+            .climate
+            .as_ref()
+            .ok_or_else(|| anyhow!("The climate mode needs the climate archive"))?;
+        let daily = self.forecaster.daily_forecast(location).await?;
+        let normals = monthly_normals(&service.history(location).await?);
+        let week = compare_week(&daily, &normals);
+
+        if self.config.json_output {
+            return self.print_json(&week);
+        }
+        if self.config.no_charts {
+            return self.ui.show_climate_week(&week, location);
+        }
+        run_climate_chart(&week, location)
+    }
+
+    async fn radar(&self, location: &Location) -> Result<()> {
+        let radar = RadarService::new(self.http.clone());
+        let (host, frames) = radar.frames().await?;
+        if frames.is_empty() {
+            return Err(anyhow!("No radar frames from the last hour"));
+        }
+        if self.config.json_output {
+            return self.print_json(&serde_json::json!({
+                "host": host,
+                "frames": frames,
+            }));
+        }
+
+        let images = join_all(
+            frames
+                .iter()
+                .map(|frame| radar.window(&host, frame, location)),
+        )
+        .await;
+        let frames: Vec<_> = frames.into_iter().zip(images).collect();
+        self.ui.show_radar(&frames, location)
+    }
+
+    /// The configured location, or the one detected from the IP address,
+    /// looked up once and then reused
+    async fn location(&self) -> Result<ResolvedLocation> {
+        let mut resolved = self.resolved.lock().await;
+        match resolved.as_ref() {
+            Some(known) if known.query == self.config.location => Ok(known.clone()),
+            _ => {
+                let location = resolve_location(self.locations.as_ref(), &self.config).await?;
+                let known = ResolvedLocation {
+                    query: self.config.location.clone(),
+                    location,
+                    origin: match self.config.location {
+                        Some(_) => LocationOrigin::Pinned,
+                        None => LocationOrigin::Detected,
+                    },
+                };
+                *resolved = Some(known.clone());
+                Ok(known)
+            }
+        }
+    }
+
+    /// Detect the location from the IP address again, replacing a pinned
+    /// or previously detected one
+    async fn redetect_location(&self) -> Result<()> {
+        let location = self
+            .locations
+            .location_from_ip()
+            .await
+            .context("Could not detect your location")?;
+        *self.resolved.lock().await = Some(ResolvedLocation {
+            query: self.config.location.clone(),
+            location: with_display_timezone(location, &self.config),
+            origin: LocationOrigin::Detected,
+        });
+        Ok(())
+    }
+
+    async fn canvas_data(&self) -> Result<(Vec<HourlyForecast>, Vec<DailyForecast>, Location)> {
+        let location = self.location().await?.location;
+        let hourly = self.forecaster.hourly_forecast(&location).await?;
+        let daily = self.forecaster.daily_forecast(&location).await?;
+        Ok((hourly, daily, location))
+    }
+
+    async fn run_canvas(&self) -> Result<()> {
+        // Favorites are marked on the map tab
+        let favorites = fetch_cards(
+            self.forecaster.as_ref(),
+            self.locations.as_ref(),
+            &self.settings.favorites.locations,
+            &[],
+        )
+        .await;
+
+        // A failed redetect is shown in the view rather than leaving it
+        let mut status = None;
+        loop {
+            let (hourly, daily, location) = self.canvas_data().await?;
+            let origin = self.location().await?.origin;
+            let fetched_at = self.forecaster.fetched_at(&location);
+            let exit = self.ui.show_canvas(
+                hourly,
+                daily,
+                location,
+                origin,
+                fetched_at,
+                favorites.clone(),
+                status.take(),
+                &self.config,
+                &self.settings,
+            )?;
+            match exit {
+                TuiExit::Quit => return Ok(()),
+                TuiExit::Redetect => {
+                    println!("📍 Detecting your location...");
+                    if let Err(e) = self.redetect_location().await {
+                        status = Some(format!("{:#}", e));
+                    }
+                }
+            }
+        }
+    }
+
+    async fn run_favorites(&self) -> Result<()> {
+        let names = &self.settings.favorites.locations;
+        if names.is_empty() {
+            return Err(anyhow!(
+                "No favorite locations yet; add some with `weather_man favorites add <LOCATION>`"
+            ));
+        }
+
+        let cards = fetch_cards(
+            self.forecaster.as_ref(),
+            self.locations.as_ref(),
+            names,
+            &[],
+        )
+        .await;
+        if self.config.json_output {
+            return self.print_json(&cards);
+        }
+
+        let keybindings = KeyBindings::from_settings(&self.settings.keybindings)?;
+        run_dashboard(
+            self.forecaster.as_ref(),
+            self.locations.as_ref(),
+            names,
+            cards,
+            self.config.display_units,
+            keybindings,
+        )
+        .await
+    }
+
+    async fn run_interactive(&self) -> Result<()> {
+        self.ui.show_welcome_banner()?;
+
+        // Loop until exit
+        loop {
+            let choice = self.ui.show_interactive_menu(!self.config.no_charts)?;
+
+            match choice.as_str() {
+                "current" => {
+                    // Clear terminal first for clean output
+                    print!("\x1B[2J\x1B[1;1H");
+                    Box::pin(self.run(Mode::Current)).await?;
+                }
+                "hourly" => Box::pin(self.run(Mode::Hourly)).await?,
+                "daily" => Box::pin(self.run(Mode::Daily)).await?,
+                "full" => Box::pin(self.run(Mode::Full)).await?,
+                "change_location" => {
+                    let mut runner = self.clone();
+                    runner.config.location = Some(self.ui.prompt_for_location()?);
+                    Box::pin(runner.run(Mode::Full)).await?;
+                }
+                "change_units" => {
+                    let units = self.ui.prompt_for_units()?;
+                    let mut runner = self.clone();
+                    runner.config.display_units = DisplayUnits::for_system(&units);
+                    runner.config.units = units;
+                    runner.ui = self.ui.with_display_units(runner.config.display_units);
+                    Box::pin(runner.run(Mode::Full)).await?;
+                }
+                "canvas" => self.run_canvas().await?,
+                "exit" => break,
+                _ => eprintln!("{}", "Invalid option selected!".bright_red()),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Current conditions from both providers, fetched at the same time
+    async fn compare_providers(&self, location: &Location) -> Result<ProviderComparison> {
+        let [(first, a), (second, b)] = self.providers.as_slice() else {
+            bail!("The compare mode needs two providers, e.g. --providers best_match,gfs_seamless");
+        };
+        let (a, b) = futures::try_join!(
+            async {
+                a.current_weather(location)
+                    .await
+                    .with_context(|| format!("Could not fetch {}", first))
+            },
+            async {
+                b.current_weather(location)
+                    .await
+                    .with_context(|| format!("Could not fetch {}", second))
+            },
+        )?;
+        Ok(ProviderComparison::new([(first, &a), (second, &b)]))
+    }
+
+    /// Print JSON output, with coordinates rounded in privacy mode
+    ///
+    /// Objects get a `freshness` entry saying when the data for the current
+    /// location was fetched; lists keep their shape.
+    fn print_json<T: serde::Serialize>(&self, value: &T) -> Result<()> {
+        let mut json = serde_json::to_value(value)?;
+        if let (Some(object), Some(freshness)) = (json.as_object_mut(), self.freshness()) {
+            object.insert("freshness".to_string(), serde_json::to_value(freshness)?);
+        }
+        if self.privacy {
+            redact_coordinates(&mut json);
+        }
+        println!("{}", to_json(&json, self.config.json_compact)?);
+        Ok(())
+    }
+
+    /// Age of the data for the location the report is about, once it is known
+    fn freshness(&self) -> Option<Freshness> {
+        let resolved = self.resolved.try_lock().ok()?;
+        let location = &resolved.as_ref()?.location;
+        let fetched = self
+            .forecaster
+            .fetched_at(location)
+            .unwrap_or_else(Utc::now);
+        Some(Freshness::new(
+            fetched,
+            Utc::now(),
+            &self.settings.freshness,
+        ))
+    }
+
+    /// Short pause between report sections when animations are enabled
+    fn pause(&self) {
+        if self.config.animation_enabled {
+            std::thread::sleep(Duration::from_millis(800));
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::modules::types::{CurrentWeather, DetailLevel, WeatherCondition};
+
+/// Estimated asphalt temperature (°C) above which pavement burns paws
+const HOT_PAVEMENT: f64 = 50.0;
+/// Air temperature (°C) above which a parked car becomes dangerous in sunshine
+const HOT_CAR: f64 = 20.0;
+
+/// Estimated sun-exposed asphalt temperature
+///
+/// Asphalt in full sun runs roughly 25 °C above air temperature; clouds cut
+/// that by up to 70 % and there is no gain at night.
+pub fn pavement_temperature(weather: &CurrentWeather) -> f64 {
+    if !is_daytime(weather) {
+        return weather.temperature;
+    }
+    let sun = 1.0 - weather.clouds.fraction().min(1.0) * 0.7;
+    weather.temperature + 25.0 * sun
+}
+
+/// Whether the ground may be frozen after recent precipitation
+pub fn black_ice_risk(weather: &CurrentWeather) -> bool {
+    let recent_precipitation = weather.rain_last_hour.unwrap_or(0.0) > 0.0
+        || weather.snow_last_hour.unwrap_or(0.0) > 0.0
+        || matches!(
+            weather.main_condition,
+            WeatherCondition::Rain
+                | WeatherCondition::Drizzle
+                | WeatherCondition::Sleet
+                | WeatherCondition::Snow
+        );
+    (-3.0..=2.0).contains(&weather.temperature) && recent_precipitation
+}
+
+/// Pet, car and road safety warnings
+///
+/// Black ice is always reported; parked-car warnings need the standard detail
+/// level and pavement temperatures the detailed one.
+pub fn safety_warnings(weather: &CurrentWeather, detail: DetailLevel) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    if black_ice_risk(weather) {
+        warnings.push(format!(
+            "🧊 Black ice risk: {:.0}°C after recent precipitation. Drive and walk carefully on bridges and shaded paths.",
+            weather.temperature
+        ));
+    }
+
+    if detail >= DetailLevel::Standard && is_daytime(weather) && weather.temperature >= HOT_CAR {
+        warnings.push(format!(
+            "🚗 Never leave pets or children in a parked car: inside can reach {:.0}°C within an hour.",
+            weather.temperature + 20.0
+        ));
+    }
+
+    if detail >= DetailLevel::Detailed {
+        let pavement = pavement_temperature(weather);
+        if pavement >= HOT_PAVEMENT {
+            warnings.push(format!(
+                "🐕 Pavement may reach {:.0}°C, too hot for paws. Walk dogs early or on grass (7-second hand test).",
+                pavement
+            ));
+        }
+    }
+
+    warnings
+}
+
+fn is_daytime(weather: &CurrentWeather) -> bool {
+    weather.timestamp >= weather.sunrise && weather.timestamp < weather.sunset
+}
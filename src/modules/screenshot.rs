@@ -0,0 +1,260 @@
+use anyhow::{Context, Result};
+use chrono::Local;
+use font8x8::{UnicodeFonts, BASIC_FONTS, BLOCK_FONTS, BOX_FONTS, LATIN_FONTS};
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use unicode_width::UnicodeWidthStr;
+
+/// Pixel size of one terminal cell in PNG screenshots
+const CELL_WIDTH: usize = 8;
+const CELL_HEIGHT: usize = 16;
+
+/// Save a rendered frame, choosing PNG or ANSI text from the file extension
+pub fn save_buffer(buffer: &Buffer, path: &Path) -> Result<()> {
+    let is_png = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("png"));
+
+    if is_png {
+        save_png(buffer, path)
+    } else {
+        std::fs::write(path, buffer_to_ansi(buffer))
+            .with_context(|| format!("Could not write screenshot to {}", path.display()))
+    }
+}
+
+/// Default file name for screenshots taken from inside the TUI
+pub fn default_screenshot_path() -> PathBuf {
+    PathBuf::from(format!(
+        "weather_man_{}.txt",
+        Local::now().format("%Y%m%d_%H%M%S")
+    ))
+}
+
+/// Convert a rendered frame to text with ANSI color escape sequences
+pub fn buffer_to_ansi(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let mut output = String::new();
+
+    for y in area.top()..area.bottom() {
+        let mut current_style = None;
+        let mut skip = 0;
+
+        for x in area.left()..area.right() {
+            // Wide characters already cover the following cells
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+
+            let cell = buffer.get(x, y);
+            let style = (cell.fg, cell.bg, cell.modifier);
+            if current_style != Some(style) {
+                output.push_str(&sgr_sequence(cell.fg, cell.bg, cell.modifier));
+                current_style = Some(style);
+            }
+
+            output.push_str(&cell.symbol);
+            skip = cell.symbol.width().saturating_sub(1);
+        }
+
+        output.push_str("\x1b[0m\n");
+    }
+
+    output
+}
+
+/// Convert a rendered frame to plain text, one line per row without
+/// trailing spaces
+#[allow(dead_code)]
+pub fn buffer_to_text(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let mut output = String::new();
+
+    for y in area.top()..area.bottom() {
+        let mut line = String::new();
+        let mut skip = 0;
+        for x in area.left()..area.right() {
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            let cell = buffer.get(x, y);
+            line.push_str(&cell.symbol);
+            skip = cell.symbol.width().saturating_sub(1);
+        }
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Build the SGR escape sequence for a cell style
+fn sgr_sequence(fg: Color, bg: Color, modifier: Modifier) -> String {
+    let mut codes = vec!["0".to_string()];
+
+    if modifier.contains(Modifier::BOLD) {
+        codes.push("1".to_string());
+    }
+    if modifier.contains(Modifier::ITALIC) {
+        codes.push("3".to_string());
+    }
+    if modifier.contains(Modifier::UNDERLINED) {
+        codes.push("4".to_string());
+    }
+
+    codes.push(ansi_color_code(fg, false));
+    codes.push(ansi_color_code(bg, true));
+
+    format!("\x1b[{}m", codes.join(";"))
+}
+
+/// ANSI color parameter for a foreground or background color
+fn ansi_color_code(color: Color, background: bool) -> String {
+    let base = if background { 10 } else { 0 };
+    let code = match color {
+        Color::Reset => return (39 + base).to_string(),
+        Color::Black => 30,
+        Color::Red => 31,
+        Color::Green => 32,
+        Color::Yellow => 33,
+        Color::Blue => 34,
+        Color::Magenta => 35,
+        Color::Cyan => 36,
+        Color::Gray => 37,
+        Color::DarkGray => 90,
+        Color::LightRed => 91,
+        Color::LightGreen => 92,
+        Color::LightYellow => 93,
+        Color::LightBlue => 94,
+        Color::LightMagenta => 95,
+        Color::LightCyan => 96,
+        Color::White => 97,
+        Color::Indexed(i) => return format!("{};5;{}", 38 + base, i),
+        Color::Rgb(r, g, b) => return format!("{};2;{};{};{}", 38 + base, r, g, b),
+    };
+    (code + base).to_string()
+}
+
+/// Render a frame to a PNG using an embedded 8x8 bitmap font
+fn save_png(buffer: &Buffer, path: &Path) -> Result<()> {
+    let area = buffer.area;
+    let width = area.width as usize * CELL_WIDTH;
+    let height = area.height as usize * CELL_HEIGHT;
+    let mut pixels = vec![0u8; width * height * 3];
+
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            let cell = buffer.get(x, y);
+            let fg = color_to_rgb(cell.fg, (204, 204, 204));
+            let bg = color_to_rgb(cell.bg, (0, 0, 0));
+            let origin_x = (x - area.left()) as usize * CELL_WIDTH;
+            let origin_y = (y - area.top()) as usize * CELL_HEIGHT;
+
+            let mask = cell_mask(cell.symbol.chars().next().unwrap_or(' '));
+            for (py, row) in mask.iter().enumerate() {
+                for px in 0..CELL_WIDTH {
+                    let color = if row & (1 << px) != 0 { fg } else { bg };
+                    let offset = ((origin_y + py) * width + origin_x + px) * 3;
+                    pixels[offset..offset + 3].copy_from_slice(&[color.0, color.1, color.2]);
+                }
+            }
+        }
+    }
+
+    let file = File::create(path)
+        .with_context(|| format!("Could not create screenshot {}", path.display()))?;
+    let mut encoder = png::Encoder::new(BufWriter::new(file), width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(&pixels)?;
+
+    Ok(())
+}
+
+/// Pixel mask (one byte per row, LSB leftmost) for a character in a cell
+fn cell_mask(c: char) -> [u8; CELL_HEIGHT] {
+    let mut mask = [0u8; CELL_HEIGHT];
+
+    // Braille patterns are how the canvas draws, so rasterize their dots directly
+    if ('\u{2800}'..='\u{28FF}').contains(&c) {
+        let dots = c as u32 - 0x2800;
+        let positions = [
+            (0x01, 0, 0),
+            (0x02, 0, 1),
+            (0x04, 0, 2),
+            (0x40, 0, 3),
+            (0x08, 1, 0),
+            (0x10, 1, 1),
+            (0x20, 1, 2),
+            (0x80, 1, 3),
+        ];
+        for (bit, column, row) in positions {
+            if dots & bit != 0 {
+                let bits = if column == 0 {
+                    0b0000_0110
+                } else {
+                    0b0110_0000
+                };
+                mask[row * 4 + 1] |= bits;
+                mask[row * 4 + 2] |= bits;
+            }
+        }
+        return mask;
+    }
+
+    let glyph = BASIC_FONTS
+        .get(c)
+        .or_else(|| LATIN_FONTS.get(c))
+        .or_else(|| BOX_FONTS.get(c))
+        .or_else(|| BLOCK_FONTS.get(c));
+
+    match glyph {
+        // Stretch the 8x8 glyph to the taller cell
+        Some(rows) => {
+            for (i, row) in rows.iter().enumerate() {
+                mask[i * 2] = *row;
+                mask[i * 2 + 1] = *row;
+            }
+        }
+        // Emoji and other unsupported symbols become a small filled square
+        None if !c.is_whitespace() => {
+            for row in mask.iter_mut().take(12).skip(4) {
+                *row = 0b0111_1110;
+            }
+        }
+        None => {}
+    }
+
+    mask
+}
+
+/// Approximate RGB values for terminal colors
+fn color_to_rgb(color: Color, default: (u8, u8, u8)) -> (u8, u8, u8) {
+    match color {
+        Color::Reset => default,
+        Color::Black => (0, 0, 0),
+        Color::Red => (205, 49, 49),
+        Color::Green => (13, 188, 121),
+        Color::Yellow => (229, 229, 16),
+        Color::Blue => (36, 114, 200),
+        Color::Magenta => (188, 63, 188),
+        Color::Cyan => (17, 168, 205),
+        Color::Gray => (204, 204, 204),
+        Color::DarkGray => (118, 118, 118),
+        Color::LightRed => (241, 76, 76),
+        Color::LightGreen => (35, 209, 139),
+        Color::LightYellow => (245, 245, 67),
+        Color::LightBlue => (59, 142, 234),
+        Color::LightMagenta => (214, 112, 214),
+        Color::LightCyan => (41, 184, 219),
+        Color::White => (255, 255, 255),
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(i) => (i, i, i),
+    }
+}
@@ -0,0 +1,71 @@
+use crate::modules::types::{DailyForecast, HourlyForecast, Location, WeatherDescription};
+use crate::modules::ui::convert_to_local;
+
+/// Chance of precipitation from which a day or hour also matches "rain"
+const RAIN_CHANCE: f64 = 0.5;
+
+/// Words a forecast day can be found by, e.g.
+/// "saturday sat 01 june 2024-06-01 cloudy"
+pub fn day_text(day: &DailyForecast) -> String {
+    let date = day.date;
+    let mut text = format!(
+        "{} {} {}",
+        date.format("%A %a %d %B %Y-%m-%d"),
+        day.main_condition,
+        descriptions(&day.conditions)
+    );
+    if day.pop >= RAIN_CHANCE {
+        text.push_str(" rain");
+    }
+    text.to_lowercase()
+}
+
+/// Words a forecast hour can be found by, e.g. "18:00 saturday sat cloudy"
+pub fn hour_text(hour: &HourlyForecast, location: &Location) -> String {
+    let time = convert_to_local(&hour.timestamp, location.clock_timezone());
+    let mut text = format!(
+        "{} {} {}",
+        time.format("%H:%M %A %a"),
+        hour.main_condition,
+        descriptions(&hour.conditions)
+    );
+    if hour.pop >= RAIN_CHANCE || hour.rain.is_some_and(|rain| rain > 0.0) {
+        text.push_str(" rain");
+    }
+    text.to_lowercase()
+}
+
+fn descriptions(conditions: &[WeatherDescription]) -> String {
+    conditions
+        .iter()
+        .map(|c| c.description.as_str())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Whether every word of the query appears in the text, ignoring case;
+/// an empty query matches nothing
+pub fn matches(text: &str, query: &str) -> bool {
+    let query = query.to_lowercase();
+    let mut words = query.split_whitespace().peekable();
+    words.peek().is_some() && words.all(|word| text.contains(word))
+}
+
+/// Indexes of the days matching a query
+pub fn matching_days(days: &[DailyForecast], query: &str) -> Vec<usize> {
+    days.iter()
+        .enumerate()
+        .filter(|(_, day)| matches(&day_text(day), query))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Indexes of the hours matching a query
+pub fn matching_hours(hours: &[HourlyForecast], location: &Location, query: &str) -> Vec<usize> {
+    hours
+        .iter()
+        .enumerate()
+        .filter(|(_, hour)| matches(&hour_text(hour, location), query))
+        .map(|(i, _)| i)
+        .collect()
+}
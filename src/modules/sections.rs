@@ -0,0 +1,65 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// A block of the text reports that can be shown or hidden
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, ValueEnum,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Section {
+    /// One-paragraph summary of the day ahead
+    Summary,
+    /// Current conditions
+    Current,
+    /// Hour-by-hour table
+    Hourly,
+    /// Frost, heatwave and fire weather warnings
+    Alerts,
+    /// Day-by-day forecast boxes
+    Daily,
+    /// Temperature outlook for the week
+    Trends,
+    /// Activity suggestions per day
+    Activities,
+    /// Detailed forecast for the next five days
+    Details,
+    /// Sunrise and sunset times
+    Astro,
+    /// Precipitation totals for the next 6, 12 and 24 hours
+    Accumulation,
+    /// Snow depth and snowfall level
+    Winter,
+    /// Weather risk summary
+    Risk,
+    /// Clothing and activity recommendations for the current weather
+    Recommendations,
+    /// Natural hazards nearby (with --hazards)
+    Hazards,
+    /// Climate normals and records (with --normals)
+    Normals,
+}
+
+/// Which sections the reports print; all of them by default
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sections {
+    hidden: BTreeSet<Section>,
+}
+
+impl Sections {
+    /// Only `show` (every section when empty), minus `hide`
+    pub fn new(show: &[Section], hide: &[Section]) -> Self {
+        let hidden = Section::value_variants()
+            .iter()
+            .copied()
+            .filter(|section| {
+                (!show.is_empty() && !show.contains(section)) || hide.contains(section)
+            })
+            .collect();
+        Self { hidden }
+    }
+
+    pub fn shows(&self, section: Section) -> bool {
+        !self.hidden.contains(&section)
+    }
+}
@@ -0,0 +1,87 @@
+use anyhow::Result;
+use colored::*;
+use dialoguer::{theme::ColorfulTheme, Confirm, Input, Select};
+use std::path::Path;
+
+use crate::modules::config::{ConfigFile, GeneralSettings, Theme};
+
+/// Whether the setup wizard should run before anything else: no config file
+/// yet and someone at the terminal to answer
+pub fn needs_setup(path: &Path) -> bool {
+    !path.exists() && console::user_attended()
+}
+
+/// Ask for the default location, units, theme and whether IP-based location
+/// is allowed, then save them to `path`
+///
+/// Other sections of an existing config file are kept.
+pub fn run_setup_wizard(path: &Path) -> Result<ConfigFile> {
+    let theme = ColorfulTheme::default();
+    let mut config = if path.exists() {
+        ConfigFile::load_from(path)?
+    } else {
+        ConfigFile::default()
+    };
+
+    println!("{}", "⚙️  Weather Man setup".bright_cyan().bold());
+    println!(
+        "Answer a few questions to set your defaults; change them later in {}\n",
+        path.display()
+    );
+
+    let location: String = Input::with_theme(&theme)
+        .with_prompt("Default location (leave empty to detect it each time)")
+        .with_initial_text(config.general.location.clone().unwrap_or_default())
+        .allow_empty(true)
+        .interact_text()?;
+    let location = location.trim();
+
+    let units = ["metric", "imperial", "standard"];
+    let current_units = config.general.units.as_deref().unwrap_or("metric");
+    let units_index = Select::with_theme(&theme)
+        .with_prompt("Units")
+        .items(&[
+            "Metric (°C, m/s)",
+            "Imperial (°F, mph)",
+            "Standard (K, m/s)",
+        ])
+        .default(units.iter().position(|u| *u == current_units).unwrap_or(0))
+        .interact()?;
+
+    let themes = [Theme::Cyberpunk, Theme::Monochrome];
+    let theme_index = Select::with_theme(&theme)
+        .with_prompt("Theme")
+        .items(&["Cyberpunk (neon colors)", "Monochrome (no colors)"])
+        .default(
+            themes
+                .iter()
+                .position(|t| *t == config.general.theme)
+                .unwrap_or(0),
+        )
+        .interact()?;
+
+    let auto_location = Confirm::with_theme(&theme)
+        .with_prompt("Allow detecting your location from your IP address?")
+        .default(config.general.auto_location)
+        .interact()?;
+
+    config.general = GeneralSettings {
+        location: (!location.is_empty()).then(|| location.to_string()),
+        units: Some(units[units_index].to_string()),
+        theme: themes[theme_index],
+        auto_location,
+        feels_like: config.general.feels_like,
+        icons: config.general.icons,
+        sections: config.general.sections.clone(),
+        hide: config.general.hide.clone(),
+    };
+    config.save_to(path)?;
+
+    println!("✅ Saved settings to {}", path.display());
+    if config.general.location.is_none() && !auto_location {
+        println!("💡 Without a default location, pass --location each time");
+    }
+    println!();
+
+    Ok(config)
+}
@@ -0,0 +1,116 @@
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use crate::modules::summary::narrative;
+use crate::modules::types::{Forecast, Location};
+use crate::modules::utils::Language;
+
+/// A text-to-speech program found on this system
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TtsEngine {
+    /// macOS `say`
+    Say,
+    /// `espeak-ng`, or the older `espeak`
+    Espeak(&'static str),
+    /// Speech Dispatcher's `spd-say`
+    SpdSay,
+    /// Windows SAPI through PowerShell
+    Sapi,
+}
+
+impl TtsEngine {
+    /// The first engine available on this system
+    pub fn detect() -> Option<Self> {
+        Self::detect_with(std::env::consts::OS, on_path)
+    }
+
+    /// Pick an engine for `os`, using `available` to check for programs
+    pub fn detect_with(os: &str, available: impl Fn(&str) -> bool) -> Option<Self> {
+        let candidates: &[TtsEngine] = match os {
+            "macos" => &[TtsEngine::Say],
+            "windows" => &[TtsEngine::Sapi],
+            _ => &[
+                TtsEngine::Espeak("espeak-ng"),
+                TtsEngine::Espeak("espeak"),
+                TtsEngine::SpdSay,
+            ],
+        };
+        candidates
+            .iter()
+            .copied()
+            .find(|engine| available(engine.program()))
+    }
+
+    pub fn program(&self) -> &'static str {
+        match self {
+            TtsEngine::Say => "say",
+            TtsEngine::Espeak(program) => program,
+            TtsEngine::SpdSay => "spd-say",
+            TtsEngine::Sapi => "powershell",
+        }
+    }
+
+    /// Speak the text, waiting until it has been said
+    pub fn speak(&self, text: &str) -> Result<()> {
+        let mut command = Command::new(self.program());
+        match self {
+            TtsEngine::Say | TtsEngine::Espeak(_) => command.arg(text),
+            TtsEngine::SpdSay => command.arg("--wait").arg(text),
+            // The text goes through stdin so it needs no PowerShell quoting
+            TtsEngine::Sapi => command.args([
+                "-NoProfile",
+                "-Command",
+                "Add-Type -AssemblyName System.Speech; \
+                 (New-Object System.Speech.Synthesis.SpeechSynthesizer).Speak([Console]::In.ReadToEnd())",
+            ]),
+        };
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .spawn()
+            .with_context(|| format!("Could not run {}", self.program()))?;
+        if let (TtsEngine::Sapi, Some(mut stdin)) = (self, child.stdin.take()) {
+            stdin.write_all(text.as_bytes())?;
+        }
+        let status = child.wait()?;
+        if !status.success() {
+            return Err(anyhow!("{} failed ({})", self.program(), status));
+        }
+        Ok(())
+    }
+}
+
+/// Whether a program is in one of the `PATH` directories
+fn on_path(program: &str) -> bool {
+    let Some(paths) = std::env::var_os("PATH") else {
+        return false;
+    };
+    std::env::split_paths(&paths).any(|dir| {
+        let path = dir.join(program);
+        path.is_file() || path.with_extension("exe").is_file()
+    })
+}
+
+/// The current weather followed by the narrative summary, for reading aloud
+pub fn spoken_summary(
+    forecast: &Forecast,
+    location: &Location,
+    now: DateTime<Utc>,
+) -> Option<String> {
+    let current = forecast.current.as_ref()?;
+    let mut text = format!(
+        "In {} it is {} and {:.0} degrees, feeling like {:.0}.",
+        location.name,
+        current.main_condition.to_string().to_lowercase(),
+        current.temperature,
+        current.feels_like
+    );
+    // The rest of the text is English, and so is the voice
+    if let Some(summary) = narrative(forecast, location, now, Language::English) {
+        text.push(' ');
+        text.push_str(&summary.replace('°', " degrees"));
+    }
+    Some(text)
+}
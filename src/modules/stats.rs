@@ -0,0 +1,126 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::modules::status::write_status;
+
+/// The log is trimmed to its newest events once it grows past this size
+const MAX_LOG_BYTES: u64 = 512 * 1024;
+/// Events kept when the log is trimmed
+const KEEP_EVENTS: usize = 2000;
+/// Locations listed in the stats
+pub const TOP_LOCATIONS: usize = 5;
+
+/// One forecast request, answered from the cache or by the API
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UsageEvent {
+    pub time: DateTime<Utc>,
+    pub location: String,
+    pub cached: bool,
+    /// How long the API took; `None` for cache hits
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+}
+
+/// Forecast requests logged on this machine only, one JSON object per line
+#[derive(Debug, Clone)]
+pub struct UsageLog {
+    path: PathBuf,
+}
+
+impl UsageLog {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Append an event, trimming the log when it gets large
+    pub fn record(&self, event: &UsageEvent) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Could not open {}", self.path.display()))?;
+        writeln!(file, "{}", serde_json::to_string(event)?)?;
+
+        if file.metadata()?.len() > MAX_LOG_BYTES {
+            let events = self.read()?;
+            let start = events.len().saturating_sub(KEEP_EVENTS);
+            let mut lines = String::new();
+            for event in &events[start..] {
+                lines.push_str(&serde_json::to_string(event)?);
+                lines.push('\n');
+            }
+            write_status(&self.path, &lines)?;
+        }
+        Ok(())
+    }
+
+    /// All logged events, oldest first; unreadable lines are skipped
+    pub fn read(&self) -> Result<Vec<UsageEvent>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let contents = fs::read_to_string(&self.path)
+            .with_context(|| format!("Could not read {}", self.path.display()))?;
+        Ok(contents
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect())
+    }
+}
+
+/// Summary of the usage log
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UsageStats {
+    pub since: Option<DateTime<Utc>>,
+    pub requests: usize,
+    /// Requests answered from the cache, i.e. API calls saved
+    pub cache_hits: usize,
+    pub api_calls: usize,
+    pub hit_rate: f64,
+    pub average_latency_ms: Option<u64>,
+    pub slowest_latency_ms: Option<u64>,
+    /// Most requested locations with their request counts
+    pub top_locations: Vec<(String, usize)>,
+}
+
+impl UsageStats {
+    pub fn from_events(events: &[UsageEvent]) -> Self {
+        let cache_hits = events.iter().filter(|event| event.cached).count();
+        let latencies: Vec<u64> = events.iter().filter_map(|event| event.latency_ms).collect();
+
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for event in events {
+            *counts.entry(event.location.as_str()).or_default() += 1;
+        }
+        let mut top_locations: Vec<(String, usize)> = counts
+            .into_iter()
+            .map(|(location, count)| (location.to_string(), count))
+            .collect();
+        top_locations.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        top_locations.truncate(TOP_LOCATIONS);
+
+        UsageStats {
+            since: events.iter().map(|event| event.time).min(),
+            requests: events.len(),
+            cache_hits,
+            api_calls: events.len() - cache_hits,
+            hit_rate: if events.is_empty() {
+                0.0
+            } else {
+                cache_hits as f64 / events.len() as f64
+            },
+            average_latency_ms: (!latencies.is_empty())
+                .then(|| latencies.iter().sum::<u64>() / latencies.len() as u64),
+            slowest_latency_ms: latencies.iter().max().copied(),
+            top_locations,
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+use crate::modules::types::{Forecast, Location};
+
+/// Snapshot of the weather written for status bars to read
+#[derive(Debug, Clone, Serialize)]
+pub struct Status {
+    pub updated: DateTime<Utc>,
+    pub location: String,
+    /// Short line for the bar, e.g. "⛅ 21°"
+    pub text: String,
+    pub icon: String,
+    pub condition: String,
+    pub temperature: f64,
+    pub feels_like: f64,
+    pub humidity: f64,
+    pub wind_speed: f64,
+    /// Precipitation probability (0-1) of the next hour
+    pub pop: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub today_min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub today_max: Option<f64>,
+}
+
+/// Build the status from a forecast; `None` without current conditions
+pub fn status_from_forecast(
+    forecast: &Forecast,
+    location: &Location,
+    now: DateTime<Utc>,
+) -> Option<Status> {
+    let current = forecast.current.as_ref()?;
+    let icon = current.main_condition.get_emoji().to_string();
+    let today = forecast.daily.first();
+
+    Some(Status {
+        updated: now,
+        location: location.name.clone(),
+        text: format!("{} {:.0}°", icon, current.temperature),
+        icon,
+        condition: current.main_condition.to_string(),
+        temperature: current.temperature,
+        feels_like: current.feels_like,
+        humidity: current.humidity.0,
+        wind_speed: current.wind_speed,
+        pop: forecast
+            .hourly
+            .iter()
+            .find(|h| h.timestamp + Duration::hours(1) > now)
+            .map(|h| h.pop)
+            .unwrap_or(0.0),
+        today_min: today.map(|d| d.temp_min),
+        today_max: today.map(|d| d.temp_max),
+    })
+}
+
+/// Replace the file's contents in one step, so readers never see half of it
+pub fn write_status(path: &Path, contents: &str) -> Result<()> {
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir).with_context(|| format!("Could not create {}", dir.display()))?;
+    }
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| anyhow!("{} is not a file path", path.display()))?;
+    let mut temporary = file_name.to_os_string();
+    temporary.push(".tmp");
+    let temporary = path.with_file_name(temporary);
+
+    fs::write(&temporary, contents)
+        .with_context(|| format!("Could not write {}", temporary.display()))?;
+    fs::rename(&temporary, path).with_context(|| format!("Could not write {}", path.display()))
+}
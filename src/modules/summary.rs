@@ -0,0 +1,188 @@
+use chrono::{DateTime, Duration, Timelike, Utc};
+
+use crate::modules::types::{Forecast, HourlyForecast, Location, WeatherCondition};
+use crate::modules::ui::convert_to_local;
+use crate::modules::utils::{relative_time, Language};
+
+/// Hours ahead the summary describes
+pub const SUMMARY_HOURS: usize = 12;
+/// Wind from this speed (m/s) counts as gusty
+const GUSTY_MS: f64 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Precip {
+    Drizzle,
+    Rain,
+    Thunder,
+    Snow,
+    Sleet,
+}
+
+impl Precip {
+    fn noun(&self) -> &'static str {
+        match self {
+            Precip::Drizzle => "Drizzle",
+            Precip::Rain => "Rain",
+            Precip::Thunder => "Thunderstorms",
+            Precip::Snow => "Snow",
+            Precip::Sleet => "Sleet",
+        }
+    }
+
+    /// Verb ending for the noun: "Rain arrives", "Thunderstorms arrive"
+    fn s(&self) -> &'static str {
+        if *self == Precip::Thunder {
+            ""
+        } else {
+            "s"
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sky {
+    Clear,
+    PartlyCloudy,
+    Cloudy,
+    Fog,
+    Wet(Precip),
+}
+
+fn sky(hour: &HourlyForecast) -> Sky {
+    match hour.main_condition {
+        WeatherCondition::Clear => Sky::Clear,
+        WeatherCondition::Clouds if hour.clouds.0 < 60.0 => Sky::PartlyCloudy,
+        WeatherCondition::Clouds | WeatherCondition::Unknown => Sky::Cloudy,
+        WeatherCondition::Drizzle => Sky::Wet(Precip::Drizzle),
+        WeatherCondition::Rain | WeatherCondition::Squall => Sky::Wet(Precip::Rain),
+        WeatherCondition::Thunderstorm | WeatherCondition::Tornado => Sky::Wet(Precip::Thunder),
+        WeatherCondition::Snow => Sky::Wet(Precip::Snow),
+        WeatherCondition::Sleet | WeatherCondition::Hail => Sky::Wet(Precip::Sleet),
+        WeatherCondition::Mist
+        | WeatherCondition::Fog
+        | WeatherCondition::Haze
+        | WeatherCondition::Smoke
+        | WeatherCondition::Dust
+        | WeatherCondition::Sand
+        | WeatherCondition::Ash => Sky::Fog,
+    }
+}
+
+fn is_wet(sky: Sky) -> bool {
+    matches!(sky, Sky::Wet(_))
+}
+
+/// Whether a change to `to` is worth mentioning, and how
+fn transition(from: Sky, to: Sky) -> Option<&'static str> {
+    match (from, to) {
+        (Sky::Fog, Sky::Fog) => None,
+        (Sky::Fog, _) => Some("fog lifting"),
+        (Sky::Cloudy, Sky::Clear | Sky::PartlyCloudy) => Some("clearing"),
+        (Sky::Clear | Sky::PartlyCloudy, Sky::Cloudy) => Some("clouding over"),
+        (_, Sky::Fog) => Some("turning foggy"),
+        _ => None,
+    }
+}
+
+/// A short paragraph about the next hours, e.g. "Cloudy start, clearing by
+/// noon with a high of 23°. Rain arrives after 18:00 with gusty winds."
+pub fn narrative(
+    forecast: &Forecast,
+    location: &Location,
+    now: DateTime<Utc>,
+    language: Language,
+) -> Option<String> {
+    let hours: Vec<&HourlyForecast> = forecast
+        .hourly
+        .iter()
+        .filter(|h| h.timestamp + Duration::hours(1) > now)
+        .take(SUMMARY_HOURS)
+        .collect();
+    let first = hours.first()?;
+    let skies: Vec<Sky> = hours.iter().map(|h| sky(h)).collect();
+    let start = skies[0];
+
+    let time = |index: usize| {
+        let local = convert_to_local(&hours[index].timestamp, location.clock_timezone());
+        match local.hour() {
+            0 => "midnight".to_string(),
+            12 => "noon".to_string(),
+            hour => format!("{:02}:00", hour),
+        }
+    };
+    let high = forecast
+        .daily
+        .first()
+        .map(|day| day.temp_max)
+        .unwrap_or_else(|| hours.iter().map(|h| h.temperature).fold(f64::MIN, f64::max));
+
+    let mut opening = match start {
+        Sky::Clear => {
+            let hour = convert_to_local(&first.timestamp, &location.timezone).hour();
+            if (7..19).contains(&hour) {
+                "Sunny"
+            } else {
+                "Clear"
+            }
+            .to_string()
+        }
+        Sky::PartlyCloudy => "Partly cloudy".to_string(),
+        Sky::Cloudy => "Cloudy".to_string(),
+        Sky::Fog => "Foggy".to_string(),
+        Sky::Wet(precip) => precip.noun().to_string(),
+    };
+
+    // When it stops raining, or the sky changes on a dry start
+    let mut dried = None;
+    if let Sky::Wet(_) = start {
+        dried = skies.iter().position(|sky| !is_wet(*sky));
+        match dried {
+            Some(index) => opening.push_str(&format!(" at first, drying out by {}", time(index))),
+            None => opening.push_str(" throughout"),
+        }
+    } else {
+        opening.push_str(" start");
+        let change = skies
+            .iter()
+            .enumerate()
+            .filter(|(_, sky)| !is_wet(**sky))
+            .find_map(|(index, sky)| transition(start, *sky).map(|phrase| (index, phrase)));
+        if let Some((index, phrase)) = change {
+            opening.push_str(&format!(", {} by {}", phrase, time(index)));
+        }
+    }
+    let mut text = format!("{} with a high of {:.0}°.", opening, high);
+
+    let gusty = hours.iter().any(|h| h.wind_speed >= GUSTY_MS);
+    let wet_from = match (start, dried) {
+        (Sky::Wet(_), None) => None,
+        (Sky::Wet(_), Some(dry)) => skies[dry..]
+            .iter()
+            .position(|sky| is_wet(*sky))
+            .map(|index| index + dry),
+        _ => skies.iter().position(|sky| is_wet(*sky)),
+    };
+    match wet_from.map(|index| (index, skies[index])) {
+        Some((index, Sky::Wet(precip))) => {
+            let verb = if dried.is_some() { "return" } else { "arrive" };
+            text.push_str(&format!(
+                " {} {}{} after {} ({}){}.",
+                precip.noun(),
+                verb,
+                precip.s(),
+                time(index),
+                relative_time(
+                    hours[index].timestamp,
+                    now,
+                    location.clock_timezone(),
+                    language
+                ),
+                if gusty { " with gusty winds" } else { "" }
+            ));
+        }
+        _ if gusty => text.push_str(" Gusty winds at times."),
+        _ if !is_wet(start) => text.push_str(" Staying dry."),
+        _ => {}
+    }
+    Some(text)
+}
@@ -0,0 +1,145 @@
+use crate::modules::types::DailyForecast;
+use crate::modules::ui::convert_to_local;
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, SecondsFormat, Utc};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+/// Sun times and day length for one forecast day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SunTimes {
+    pub date: NaiveDate,
+    pub sunrise: DateTime<Utc>,
+    pub sunset: DateTime<Utc>,
+    /// Midpoint between sunrise and sunset
+    pub solar_noon: DateTime<Utc>,
+    pub day_length_minutes: i64,
+    /// How much earlier (negative) or later the sun rises the next day
+    pub next_sunrise_delta_minutes: Option<i64>,
+    /// How much shorter (negative) or longer the next day is
+    pub next_day_length_delta_minutes: Option<i64>,
+}
+
+/// Compute sun times for every day of a daily forecast
+pub fn sun_times(daily_data: &[DailyForecast]) -> Vec<SunTimes> {
+    daily_data
+        .iter()
+        .enumerate()
+        .map(|(i, day)| {
+            let day_length = day.sunset - day.sunrise;
+            let next = daily_data.get(i + 1);
+
+            SunTimes {
+                date: day.date.date_naive(),
+                sunrise: day.sunrise,
+                sunset: day.sunset,
+                solar_noon: day.sunrise + day_length / 2,
+                day_length_minutes: day_length.num_minutes(),
+                next_sunrise_delta_minutes: next
+                    .map(|n| (n.sunrise - day.sunrise - Duration::days(1)).num_minutes()),
+                next_day_length_delta_minutes: next
+                    .map(|n| (n.sunset - n.sunrise - day_length).num_minutes()),
+            }
+        })
+        .collect()
+}
+
+/// Format a length of time in minutes as `13h 42m`
+pub fn format_minutes(minutes: i64) -> String {
+    format!("{}h {:02}m", minutes / 60, minutes % 60)
+}
+
+/// Format a change in minutes as `+2m`/`-1m`
+pub fn format_delta(minutes: i64) -> String {
+    format!("{:+}m", minutes)
+}
+
+/// Sun event an alarm is set for by `weather_man sun --next`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ValueEnum, Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum SunEvent {
+    Sunrise,
+    Sunset,
+}
+
+/// How `weather_man sun` prints the alarm time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum AlarmFormat {
+    /// Seconds since 1970, e.g. for `systemd-run --on-calendar=@...`
+    Epoch,
+    /// ISO 8601 with the location's UTC offset
+    #[default]
+    Iso,
+}
+
+/// Parse an offset from the sun event such as `-30m`, `+1h` or `15` (minutes)
+pub fn parse_offset(value: &str) -> Result<Duration, String> {
+    let value = value.trim().to_lowercase();
+    let (sign, rest) = match value.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, value.strip_prefix('+').unwrap_or(&value)),
+    };
+    let (number, unit) = if let Some(number) = rest.strip_suffix('h') {
+        (number, 60)
+    } else {
+        (rest.strip_suffix('m').unwrap_or(rest), 1)
+    };
+    let amount: i64 = number
+        .trim()
+        .parse()
+        .map_err(|_| format!("'{}' is not an offset, expected e.g. -30m or +1h", value))?;
+    Ok(Duration::minutes(sign * amount * unit))
+}
+
+/// The time of the next sunrise or sunset, moved by an offset
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SunAlarm {
+    pub event: SunEvent,
+    /// When the sun rises or sets
+    pub sun_time: DateTime<Utc>,
+    pub offset_minutes: i64,
+    /// The sun time plus the offset
+    pub time: DateTime<Utc>,
+    pub epoch: i64,
+}
+
+impl SunAlarm {
+    /// The first sunrise or sunset whose alarm time is still ahead of `now`
+    pub fn next(
+        daily_data: &[DailyForecast],
+        event: SunEvent,
+        offset: Duration,
+        now: DateTime<Utc>,
+    ) -> Option<Self> {
+        daily_data
+            .iter()
+            .map(|day| match event {
+                SunEvent::Sunrise => day.sunrise,
+                SunEvent::Sunset => day.sunset,
+            })
+            .find(|sun_time| *sun_time + offset > now)
+            .map(|sun_time| Self {
+                event,
+                sun_time,
+                offset_minutes: offset.num_minutes(),
+                time: sun_time + offset,
+                epoch: (sun_time + offset).timestamp(),
+            })
+    }
+
+    /// The alarm time alone, for `at`, systemd timers and scripts
+    pub fn format(&self, format: AlarmFormat, timezone: &str) -> String {
+        match format {
+            AlarmFormat::Epoch => self.epoch.to_string(),
+            AlarmFormat::Iso => {
+                let offset = (convert_to_local(&self.time, timezone) - self.time).num_seconds();
+                let zone = FixedOffset::east_opt(offset as i32)
+                    .unwrap_or_else(|| FixedOffset::east_opt(0).unwrap());
+                self.time
+                    .with_timezone(&zone)
+                    .to_rfc3339_opts(SecondsFormat::Secs, false)
+            }
+        }
+    }
+}
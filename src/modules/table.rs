@@ -0,0 +1,142 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Horizontal alignment of a table column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+/// Box-drawn table whose cells are padded by display width, so emoji, CJK
+/// names and degree signs keep the borders straight
+///
+/// Cells may contain ANSI colors; escape sequences take no width.
+#[derive(Debug, Clone)]
+pub struct Table {
+    columns: Vec<(usize, Align)>,
+}
+
+impl Table {
+    /// Table with the given content width and alignment per column; each
+    /// cell gets a space on either side
+    pub fn new(columns: &[(usize, Align)]) -> Self {
+        Self {
+            columns: columns.to_vec(),
+        }
+    }
+
+    pub fn top(&self) -> String {
+        self.rule('┌', '┬', '┐')
+    }
+
+    pub fn separator(&self) -> String {
+        self.rule('├', '┼', '┤')
+    }
+
+    pub fn bottom(&self) -> String {
+        self.rule('└', '┴', '┘')
+    }
+
+    /// A row of cells; missing cells are left blank and long ones truncated
+    pub fn row<S: AsRef<str>>(&self, cells: &[S]) -> String {
+        let mut line = String::from("│");
+        for (i, (width, align)) in self.columns.iter().enumerate() {
+            let cell = cells.get(i).map_or("", |c| c.as_ref());
+            line.push(' ');
+            line.push_str(&pad(cell, *width, *align));
+            line.push_str(" │");
+        }
+        line
+    }
+
+    fn rule(&self, left: char, middle: char, right: char) -> String {
+        let segments: Vec<String> = self
+            .columns
+            .iter()
+            .map(|(width, _)| "─".repeat(width + 2))
+            .collect();
+        format!("{}{}{}", left, segments.join(&middle.to_string()), right)
+    }
+}
+
+/// Pieces of text: ANSI escape sequences (`true`) and the plain text between them
+fn segments(text: &str) -> Vec<(bool, &str)> {
+    let mut segments = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('\x1b') {
+        if start > 0 {
+            segments.push((false, &rest[..start]));
+        }
+        // An escape runs up to and including its final letter
+        let end = rest[start + 1..]
+            .find(|c: char| c.is_ascii_alphabetic())
+            .map_or(rest.len(), |i| start + 1 + i + 1);
+        segments.push((true, &rest[start..end]));
+        rest = &rest[end..];
+    }
+    if !rest.is_empty() {
+        segments.push((false, rest));
+    }
+    segments
+}
+
+/// Display width of text, ignoring ANSI escape sequences
+///
+/// Measured per string rather than per character, so an emoji with its
+/// presentation selector (☀️, 🌧️) counts as the two columns it takes.
+pub fn display_width(text: &str) -> usize {
+    segments(text)
+        .into_iter()
+        .filter(|(escape, _)| !escape)
+        .map(|(_, plain)| plain.width())
+        .sum()
+}
+
+/// Truncate text to a display width, ending with "…" when cut
+///
+/// Text is only cut between grapheme clusters, never inside an emoji.
+pub fn truncate(text: &str, width: usize) -> String {
+    if display_width(text) <= width {
+        return text.to_string();
+    }
+    let mut result = String::new();
+    let mut used = 0;
+    let mut colored = false;
+    'segments: for (escape, piece) in segments(text) {
+        if escape {
+            // Keep colors intact, they take no room
+            result.push_str(piece);
+            colored = true;
+            continue;
+        }
+        for grapheme in piece.graphemes(true) {
+            let w = grapheme.width();
+            if used + w + 1 > width {
+                break 'segments;
+            }
+            result.push_str(grapheme);
+            used += w;
+        }
+    }
+    if width > 0 {
+        result.push('…');
+    }
+    if colored {
+        result.push_str("\x1b[0m");
+    }
+    result
+}
+
+/// Pad or truncate text to exactly `width` display columns
+pub fn pad(text: &str, width: usize, align: Align) -> String {
+    let text = truncate(text, width);
+    let space = width.saturating_sub(display_width(&text));
+    let (left, right) = match align {
+        Align::Left => (0, space),
+        Align::Right => (space, 0),
+        Align::Center => (space / 2, space - space / 2),
+    };
+    format!("{}{}{}", " ".repeat(left), text, " ".repeat(right))
+}
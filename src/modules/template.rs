@@ -0,0 +1,225 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, Timelike, Utc};
+use rhai::{Array, Dynamic, Engine, Map, Scope};
+use serde::Serialize;
+
+use crate::modules::config::TemplateSettings;
+use crate::modules::types::{Forecast, Location};
+use crate::modules::ui::convert_to_local;
+
+/// One piece of a template: literal text or a `${...}` expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Segment {
+    Text(String),
+    Expr(String),
+}
+
+/// Split a template into text and `${...}` expressions
+///
+/// Braces inside an expression are balanced, so Rhai map literals (`#{...}`)
+/// work; a literal `$` needs no escaping unless followed by `{`.
+pub fn parse_template(template: &str) -> Result<Vec<Segment>> {
+    let mut segments = Vec::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find("${") {
+        if start > 0 {
+            segments.push(Segment::Text(rest[..start].to_string()));
+        }
+        let body = &rest[start + 2..];
+        let mut depth = 0;
+        let end = body
+            .char_indices()
+            .find(|(_, c)| match c {
+                '{' => {
+                    depth += 1;
+                    false
+                }
+                '}' if depth == 0 => true,
+                '}' => {
+                    depth -= 1;
+                    false
+                }
+                _ => false,
+            })
+            .map(|(i, _)| i)
+            .ok_or_else(|| anyhow!("Unclosed '${{' in template"))?;
+        let expr = body[..end].trim();
+        if expr.is_empty() {
+            return Err(anyhow!("Empty '${{}}' in template"));
+        }
+        segments.push(Segment::Expr(expr.to_string()));
+        rest = &body[end + 1..];
+    }
+    if !rest.is_empty() {
+        segments.push(Segment::Text(rest.to_string()));
+    }
+    Ok(segments)
+}
+
+/// Show a script value: whole numbers without decimals, others with one
+fn display(value: &Dynamic) -> String {
+    if value.is_unit() {
+        "-".to_string()
+    } else if let Ok(number) = value.as_float() {
+        if number.fract() == 0.0 {
+            format!("{:.0}", number)
+        } else {
+            format!("{:.1}", number)
+        }
+    } else {
+        value.to_string()
+    }
+}
+
+fn optional(value: Option<f64>) -> Dynamic {
+    value.map(Dynamic::from).unwrap_or(Dynamic::UNIT)
+}
+
+/// Variables the templates can use, in metric units
+///
+/// Current conditions are plain variables (`temp`, `wind`, `pop`, ...);
+/// `hourly` and `daily` hold the upcoming hours and days as maps.
+pub fn weather_scope(
+    forecast: &Forecast,
+    location: &Location,
+    now: DateTime<Utc>,
+) -> Scope<'static> {
+    let mut scope = Scope::new();
+    scope.push("location", location.name.clone());
+
+    let upcoming: Vec<_> = forecast
+        .hourly
+        .iter()
+        .filter(|h| h.timestamp + Duration::hours(1) > now)
+        .collect();
+    let next_hour = upcoming.first();
+
+    if let Some(current) = &forecast.current {
+        scope.push("temp", current.temperature);
+        scope.push("feels_like", current.feels_like);
+        scope.push("humidity", current.humidity.0);
+        scope.push("pressure", current.pressure.0);
+        scope.push("wind", current.wind_speed);
+        scope.push_dynamic("gusts", optional(current.wind_gusts));
+        scope.push("wind_dir", current.wind_direction as i64);
+        scope.push("clouds", current.clouds.0);
+        scope.push("uv", current.uv_index);
+        scope.push("visibility", current.visibility as i64);
+        scope.push("condition", current.main_condition.to_string());
+    }
+    scope.push("pop", next_hour.map(|h| h.pop).unwrap_or(0.0));
+    scope.push("rain", next_hour.and_then(|h| h.rain).unwrap_or(0.0));
+    if let Some(today) = forecast.daily.first() {
+        scope.push("today_min", today.temp_min);
+        scope.push("today_max", today.temp_max);
+        scope.push("today_pop", today.pop);
+    }
+
+    let hourly: Array = upcoming
+        .iter()
+        .map(|h| {
+            let mut hour = Map::new();
+            let local = convert_to_local(&h.timestamp, location.clock_timezone());
+            hour.insert("time".into(), local.to_rfc3339().into());
+            hour.insert("hour".into(), (local.hour() as i64).into());
+            hour.insert("temp".into(), h.temperature.into());
+            hour.insert("pop".into(), h.pop.into());
+            hour.insert("wind".into(), h.wind_speed.into());
+            hour.insert("rain".into(), h.rain.unwrap_or(0.0).into());
+            hour.insert("condition".into(), h.main_condition.to_string().into());
+            hour.into()
+        })
+        .collect();
+    scope.push("hourly", hourly);
+
+    let daily: Array = forecast
+        .daily
+        .iter()
+        .map(|d| {
+            let mut day = Map::new();
+            let date = d.date.date_naive();
+            day.insert("date".into(), date.to_string().into());
+            day.insert("min".into(), d.temp_min.into());
+            day.insert("max".into(), d.temp_max.into());
+            day.insert("pop".into(), d.pop.into());
+            day.insert("precipitation".into(), optional(d.precipitation));
+            day.insert("condition".into(), d.main_condition.to_string().into());
+            day.into()
+        })
+        .collect();
+    scope.push("daily", daily);
+
+    scope
+}
+
+/// Rendered `[templates]` output and the alert conditions that hold
+#[derive(Debug, Clone, Serialize)]
+pub struct TemplateReport {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    pub alerts: Vec<String>,
+}
+
+/// Evaluates template expressions with a sandboxed Rhai engine
+pub struct TemplateEngine {
+    engine: Engine,
+}
+
+impl TemplateEngine {
+    pub fn new() -> Self {
+        let mut engine = Engine::new();
+        // Keep runaway expressions from hanging the CLI
+        engine.set_max_operations(100_000);
+        engine.set_max_expr_depths(32, 32);
+        engine.set_max_string_size(10_000);
+        engine.set_max_array_size(1_000);
+        Self { engine }
+    }
+
+    fn eval(&self, expr: &str, scope: &mut Scope) -> Result<Dynamic> {
+        self.engine
+            .eval_expression_with_scope::<Dynamic>(scope, expr)
+            .map_err(|e| anyhow!("Error in '{}': {}", expr, e))
+    }
+
+    /// Replace every `${...}` in the template with its value
+    pub fn render(&self, template: &str, scope: &mut Scope) -> Result<String> {
+        parse_template(template)?
+            .into_iter()
+            .map(|segment| match segment {
+                Segment::Text(text) => Ok(text),
+                Segment::Expr(expr) => self.eval(&expr, scope).map(|value| display(&value)),
+            })
+            .collect()
+    }
+
+    /// Evaluate an alert condition, which must give true or false
+    pub fn condition(&self, expr: &str, scope: &mut Scope) -> Result<bool> {
+        self.eval(expr, scope)?
+            .as_bool()
+            .map_err(|kind| anyhow!("'{}' gives {}, not true or false", expr, kind))
+    }
+
+    /// Render the configured output and check the configured alerts
+    pub fn report(&self, settings: &TemplateSettings, scope: &mut Scope) -> Result<TemplateReport> {
+        let output = settings
+            .output
+            .as_deref()
+            .map(|template| self.render(template, scope))
+            .transpose()?;
+        let mut alerts = Vec::new();
+        for (name, expr) in &settings.alerts {
+            if self.condition(expr, scope)? {
+                alerts.push(name.clone());
+            }
+        }
+        Ok(TemplateReport { output, alerts })
+    }
+}
+
+impl Default for TemplateEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
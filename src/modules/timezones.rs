@@ -0,0 +1,44 @@
+use chrono_tz::Tz;
+use std::sync::OnceLock;
+use tzf_rs::DefaultFinder;
+
+/// Timezone boundaries bundled with tzf-rs, loaded on first use
+fn finder() -> &'static DefaultFinder {
+    static FINDER: OnceLock<DefaultFinder> = OnceLock::new();
+    FINDER.get_or_init(DefaultFinder::new)
+}
+
+/// IANA timezone for coordinates, looked up offline
+///
+/// The zone whose boundary contains the point, or a whole-hour `Etc/GMT`
+/// zone from the longitude where no boundary does or chrono-tz does not
+/// know the name.
+pub fn timezone_at(lat: f64, lon: f64) -> String {
+    let zone = finder().get_tz_name(lon, lat);
+    if zone.parse::<Tz>().is_ok() {
+        zone.to_string()
+    } else {
+        nautical_zone(lon)
+    }
+}
+
+/// `Etc/GMT` zone for a longitude; the sign is inverted by POSIX convention
+fn nautical_zone(lon: f64) -> String {
+    let hours = (lon / 15.0).round() as i32;
+    match hours {
+        0 => "UTC".to_string(),
+        hours if hours > 0 => format!("Etc/GMT-{}", hours.min(12)),
+        hours => format!("Etc/GMT+{}", (-hours).min(12)),
+    }
+}
+
+/// Check a `--tz` value against the IANA timezone database
+pub fn parse_timezone(value: &str) -> Result<String, String> {
+    match value.parse::<Tz>() {
+        Ok(tz) => Ok(tz.name().to_string()),
+        Err(_) => Err(format!(
+            "unknown timezone '{}', expected e.g. Europe/Paris, America/New_York, UTC or Etc/GMT-3",
+            value
+        )),
+    }
+}
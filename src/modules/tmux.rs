@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+
+use crate::modules::status::status_from_forecast;
+use crate::modules::types::{Forecast, Location};
+
+/// tmux colour for a temperature in °C: blue when freezing up to red when hot
+pub fn temperature_colour(celsius: f64) -> u8 {
+    match celsius {
+        t if t < 0.0 => 39,
+        t if t < 10.0 => 45,
+        t if t < 20.0 => 114,
+        t if t < 28.0 => 214,
+        _ => 196,
+    }
+}
+
+/// Icon and temperature with tmux format codes, e.g. `#[fg=colour214]☀️ 24°#[default]`
+pub fn tmux_line(forecast: &Forecast, location: &Location, now: DateTime<Utc>) -> Option<String> {
+    let status = status_from_forecast(forecast, location, now)?;
+    Some(format!(
+        "#[fg=colour{}]{}#[default]",
+        temperature_colour(status.temperature),
+        status.text
+    ))
+}
@@ -1,24 +1,56 @@
+use crate::modules::climate::{ClimateComparison, NORMALS_PERIOD};
+use crate::modules::config::{ConfigFile, FreshnessSettings, KeybindingSettings, TuiLayout};
+use crate::modules::dashboard::LocationCard;
+use crate::modules::ensemble::Ensemble;
+use crate::modules::freshness::{Freshness, Staleness};
+use crate::modules::icons::IconSet;
+use crate::modules::location::LocationOrigin;
+use crate::modules::meteogram::render_meteogram;
+use crate::modules::models::ModelComparison;
+use crate::modules::screenshot::{buffer_to_text, default_screenshot_path, save_buffer};
+use crate::modules::search::{matching_days, matching_hours};
 use crate::modules::types::{
-    DailyForecast, HourlyForecast, Location, WeatherCondition, WeatherConfig,
+    DailyForecast, HourlyForecast, Location, WeatherAlert, WeatherCondition, WeatherConfig,
 };
 use crate::modules::ui::convert_to_local;
-use anyhow::Result;
+use crate::modules::warnings::{alert_covers, multi_day_warnings};
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Datelike, NaiveDate, Timelike, Utc};
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind,
+        KeyModifiers,
+    },
     execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    terminal::{
+        disable_raw_mode, enable_raw_mode, is_raw_mode_enabled, EnterAlternateScreen,
+        LeaveAlternateScreen,
+    },
 };
 
 use ratatui::{
-    backend::CrosstermBackend,
+    backend::{Backend, CrosstermBackend, TestBackend},
     layout::{Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
+    symbols,
     text::{Line, Span, Text},
-    widgets::{Block, BorderType, Borders, Paragraph, Tabs, Wrap},
+    widgets::{
+        canvas::{Canvas, Line as CanvasLine, Map, MapResolution},
+        Axis, Block, BorderType, Borders, Chart, Clear, Dataset, GraphType, Paragraph, Sparkline,
+        Tabs, Wrap,
+    },
     Terminal,
 };
+use std::collections::{BTreeMap, HashMap};
 use std::io;
 use std::io::Stdout;
+use std::path::Path;
+use std::sync::Once;
+
+/// Terminal size (columns, rows) used when rendering headless screenshots
+const SCREENSHOT_SIZE: (u16, u16) = (160, 48);
+/// Hours covered by the meteogram tab
+const METEOGRAM_HOURS: usize = 48;
 
 /// Enum representing the available tabs in the TUI
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
@@ -26,6 +58,9 @@ pub enum TuiTab {
     CurrentWeather,
     WeatherForecast,
     Calendar,
+    Heatmap,
+    Meteogram,
+    Map,
 }
 
 impl TuiTab {
@@ -33,15 +68,21 @@ impl TuiTab {
         match self {
             TuiTab::CurrentWeather => TuiTab::WeatherForecast,
             TuiTab::WeatherForecast => TuiTab::Calendar,
-            TuiTab::Calendar => TuiTab::CurrentWeather,
+            TuiTab::Calendar => TuiTab::Heatmap,
+            TuiTab::Heatmap => TuiTab::Meteogram,
+            TuiTab::Meteogram => TuiTab::Map,
+            TuiTab::Map => TuiTab::CurrentWeather,
         }
     }
 
     fn prev(&self) -> Self {
         match self {
-            TuiTab::CurrentWeather => TuiTab::Calendar,
+            TuiTab::CurrentWeather => TuiTab::Map,
             TuiTab::WeatherForecast => TuiTab::CurrentWeather,
             TuiTab::Calendar => TuiTab::WeatherForecast,
+            TuiTab::Heatmap => TuiTab::Calendar,
+            TuiTab::Meteogram => TuiTab::Heatmap,
+            TuiTab::Map => TuiTab::Meteogram,
         }
     }
 
@@ -50,215 +91,821 @@ impl TuiTab {
             TuiTab::CurrentWeather => "Current Weather",
             TuiTab::WeatherForecast => "Weather Forecast",
             TuiTab::Calendar => "Weather Calendar",
+            TuiTab::Heatmap => "Weekly Heatmap",
+            TuiTab::Meteogram => "Meteogram",
+            TuiTab::Map => "World Map",
+        }
+    }
+}
+
+/// Value shown in the heatmap tab
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum HeatmapMetric {
+    Precipitation,
+    Temperature,
+    Humidity,
+}
+
+impl HeatmapMetric {
+    /// The metric shown after this one
+    pub fn next(self) -> Self {
+        match self {
+            HeatmapMetric::Precipitation => HeatmapMetric::Temperature,
+            HeatmapMetric::Temperature => HeatmapMetric::Humidity,
+            HeatmapMetric::Humidity => HeatmapMetric::Precipitation,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            HeatmapMetric::Precipitation => "Precipitation probability",
+            HeatmapMetric::Temperature => "Temperature",
+            HeatmapMetric::Humidity => "Humidity",
+        }
+    }
+
+    fn value(self, hour: &HourlyForecast) -> f64 {
+        match self {
+            HeatmapMetric::Precipitation => hour.pop * 100.0,
+            HeatmapMetric::Temperature => hour.temperature,
+            HeatmapMetric::Humidity => hour.humidity.0,
+        }
+    }
+
+    /// Map a value onto the metric's color scale
+    pub fn color(self, value: f64) -> Color {
+        let stops: &[(f64, (u8, u8, u8))] = match self {
+            HeatmapMetric::Precipitation => &[(0.0, (40, 40, 50)), (100.0, (30, 110, 255))],
+            HeatmapMetric::Humidity => &[(0.0, (120, 90, 40)), (100.0, (0, 170, 170))],
+            HeatmapMetric::Temperature => &[
+                (-10.0, (60, 60, 255)),
+                (5.0, (0, 190, 220)),
+                (15.0, (40, 200, 80)),
+                (25.0, (240, 210, 40)),
+                (35.0, (230, 40, 30)),
+            ],
+        };
+
+        let (first, last) = (stops[0], stops[stops.len() - 1]);
+        if value <= first.0 {
+            let (r, g, b) = first.1;
+            return Color::Rgb(r, g, b);
+        }
+
+        for pair in stops.windows(2) {
+            let ((low, from), (high, to)) = (pair[0], pair[1]);
+            if value <= high {
+                let t = (value - low) / (high - low);
+                let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+                return Color::Rgb(mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2));
+            }
+        }
+
+        let (r, g, b) = last.1;
+        Color::Rgb(r, g, b)
+    }
+}
+
+/// Visible part of the world map tab: a center in degrees and a zoom factor
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct MapView {
+    pub longitude: f64,
+    pub latitude: f64,
+    /// 1 shows the whole world, each step in doubles the scale
+    pub zoom: f64,
+}
+
+impl Default for MapView {
+    fn default() -> Self {
+        Self {
+            longitude: 0.0,
+            latitude: 0.0,
+            zoom: 1.0,
+        }
+    }
+}
+
+impl MapView {
+    const MAX_ZOOM: f64 = 32.0;
+
+    /// Move the center by a tenth of the visible width or height per step
+    pub fn pan(&mut self, dx: f64, dy: f64) {
+        let (width, height) = (360.0 / self.zoom, 180.0 / self.zoom);
+        self.longitude = (self.longitude + dx * width / 10.0).clamp(-180.0, 180.0);
+        self.latitude = (self.latitude + dy * height / 10.0).clamp(-90.0, 90.0);
+    }
+
+    pub fn zoom_in(&mut self) {
+        self.zoom = (self.zoom * 2.0).min(Self::MAX_ZOOM);
+    }
+
+    pub fn zoom_out(&mut self) {
+        self.zoom = (self.zoom / 2.0).max(1.0);
+    }
+
+    /// Visible longitude range
+    pub fn x_bounds(&self) -> [f64; 2] {
+        let half = 180.0 / self.zoom;
+        [self.longitude - half, self.longitude + half]
+    }
+
+    /// Visible latitude range
+    pub fn y_bounds(&self) -> [f64; 2] {
+        let half = 90.0 / self.zoom;
+        [self.latitude - half, self.latitude + half]
+    }
+}
+
+/// Color for a weather condition in the calendar and map tabs
+fn condition_color(condition: WeatherCondition) -> Color {
+    match condition {
+        WeatherCondition::Clear => Color::Yellow,
+        WeatherCondition::Clouds => Color::Gray,
+        WeatherCondition::Rain | WeatherCondition::Drizzle => Color::Blue,
+        WeatherCondition::Thunderstorm | WeatherCondition::Hail => Color::Magenta,
+        WeatherCondition::Snow => Color::White,
+        WeatherCondition::Sleet => Color::LightBlue,
+        _ => Color::Gray,
+    }
+}
+
+/// User-triggerable TUI actions that can be remapped in the config file
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Action {
+    Quit,
+    NextTab,
+    PrevTab,
+    NextDay,
+    PrevDay,
+    ToggleDashboard,
+    ToggleHelp,
+    Screenshot,
+    CycleHeatmap,
+    Search,
+    NextMatch,
+    Redetect,
+}
+
+impl Action {
+    const ALL: [Action; 12] = [
+        Action::NextTab,
+        Action::PrevTab,
+        Action::NextDay,
+        Action::PrevDay,
+        Action::ToggleDashboard,
+        Action::CycleHeatmap,
+        Action::Search,
+        Action::NextMatch,
+        Action::Redetect,
+        Action::ToggleHelp,
+        Action::Screenshot,
+        Action::Quit,
+    ];
+
+    fn description(self) -> &'static str {
+        match self {
+            Action::Quit => "Quit",
+            Action::NextTab => "Next tab",
+            Action::PrevTab => "Previous tab",
+            Action::NextDay => "Next day (forecast tab)",
+            Action::PrevDay => "Previous day (forecast tab)",
+            Action::ToggleDashboard => "Toggle dashboard layout",
+            Action::ToggleHelp => "Show this help",
+            Action::Screenshot => "Save screenshot of the current view",
+            Action::CycleHeatmap => "Switch heatmap metric (heatmap tab)",
+            Action::Search => "Search hours or days (current and forecast tabs)",
+            Action::NextMatch => "Next matching day (forecast tab)",
+            Action::Redetect => "Detect the location from your IP address again",
+        }
+    }
+}
+
+/// Keys `TuiState::handle_key` handles before the remappable bindings: tab
+/// numbers, day and map navigation, map zoom and opening a day
+///
+/// Left and Right stay bound to the tab actions by default, switching tabs
+/// wherever they do not move the calendar or the map.
+const RESERVED_KEYS: [KeyCode; 14] = [
+    KeyCode::Char('1'),
+    KeyCode::Char('2'),
+    KeyCode::Char('3'),
+    KeyCode::Char('4'),
+    KeyCode::Char('5'),
+    KeyCode::Char('6'),
+    KeyCode::Char('+'),
+    KeyCode::Char('='),
+    KeyCode::Char('-'),
+    KeyCode::Left,
+    KeyCode::Right,
+    KeyCode::Up,
+    KeyCode::Down,
+    KeyCode::Enter,
+];
+
+/// Mapping from keys to actions, built from defaults and `[keybindings]` overrides
+#[derive(Clone, Debug)]
+pub struct KeyBindings {
+    bindings: Vec<(Action, Vec<KeyCode>)>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            bindings: vec![
+                (Action::Quit, vec![KeyCode::Char('q')]),
+                (Action::NextTab, vec![KeyCode::Right, KeyCode::Tab]),
+                (Action::PrevTab, vec![KeyCode::Left, KeyCode::BackTab]),
+                (Action::NextDay, vec![KeyCode::Char('j')]),
+                (Action::PrevDay, vec![KeyCode::Char('k')]),
+                (Action::ToggleDashboard, vec![KeyCode::Char('d')]),
+                (Action::ToggleHelp, vec![KeyCode::Char('?')]),
+                (Action::Screenshot, vec![KeyCode::Char('s')]),
+                (Action::CycleHeatmap, vec![KeyCode::Char('m')]),
+                (Action::Search, vec![KeyCode::Char('/')]),
+                (Action::NextMatch, vec![KeyCode::Char('n')]),
+                (Action::Redetect, vec![KeyCode::Char('r')]),
+            ],
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Apply the user's remappings on top of the default bindings
+    pub fn from_settings(settings: &KeybindingSettings) -> Result<Self> {
+        let defaults = Self::default();
+        let mut keybindings = defaults.clone();
+        let overrides = [
+            (Action::Quit, &settings.quit),
+            (Action::NextTab, &settings.next_tab),
+            (Action::PrevTab, &settings.prev_tab),
+            (Action::NextDay, &settings.next_day),
+            (Action::PrevDay, &settings.prev_day),
+            (Action::ToggleDashboard, &settings.dashboard),
+            (Action::ToggleHelp, &settings.help),
+            (Action::Screenshot, &settings.screenshot),
+            (Action::CycleHeatmap, &settings.heatmap_metric),
+            (Action::Search, &settings.search),
+            (Action::NextMatch, &settings.next_match),
+            (Action::Redetect, &settings.redetect),
+        ];
+
+        for (action, key) in overrides {
+            if let Some(key) = key {
+                let code = parse_key(key)?;
+                if RESERVED_KEYS.contains(&code) && defaults.action_for(code) != Some(action) {
+                    return Err(anyhow!(
+                        "Key {} in [keybindings] is reserved for tab, day and map navigation",
+                        key
+                    ));
+                }
+                if let Some((_, keys)) = keybindings.bindings.iter_mut().find(|(a, _)| *a == action)
+                {
+                    *keys = vec![code];
+                }
+            }
         }
+
+        Ok(keybindings)
+    }
+
+    /// Look up the action bound to a key
+    pub fn action_for(&self, code: KeyCode) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find(|(_, keys)| keys.contains(&code))
+            .map(|(action, _)| *action)
+    }
+
+    /// Human-readable label for the keys bound to an action
+    fn label(&self, action: Action) -> String {
+        self.bindings
+            .iter()
+            .find(|(a, _)| *a == action)
+            .map(|(_, keys)| keys.iter().map(key_label).collect::<Vec<_>>().join(", "))
+            .unwrap_or_default()
+    }
+}
+
+/// Whether a key is Ctrl-C, which raw mode delivers as a key instead of SIGINT
+pub fn is_interrupt(key: &KeyEvent) -> bool {
+    key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL)
+}
+
+/// Parse a key name from the config file (`"j"`, `"Left"`, `"F2"`, ...)
+pub fn parse_key(key: &str) -> Result<KeyCode> {
+    let mut chars = key.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Ok(KeyCode::Char(c));
+    }
+
+    let code = match key.to_lowercase().as_str() {
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "enter" => KeyCode::Enter,
+        "space" => KeyCode::Char(' '),
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        other => match other.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+            Some(n) if (1..=12).contains(&n) => KeyCode::F(n),
+            _ => return Err(anyhow!("Unknown key in [keybindings]: {}", key)),
+        },
+    };
+
+    Ok(code)
+}
+
+/// Display name for a key code
+fn key_label(code: &KeyCode) -> String {
+    match code {
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Right => "→".to_string(),
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::BackTab => "Shift+Tab".to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        other => format!("{:?}", other),
     }
 }
 
-struct UiState {
+/// Everything the weather tabs show, independent of any terminal
+///
+/// Other ratatui apps can keep one of these, feed it key presses with
+/// `handle_key` and draw it with `render` or `render_content`.
+pub struct TuiState {
     active_tab: TuiTab,
     hourly_data: Vec<HourlyForecast>,
     daily_data: Vec<DailyForecast>,
     location: Location,
     config: WeatherConfig,
+    selected_day: usize,
+    calendar_cursor: NaiveDate,
+    layout: TuiLayout,
+    keybindings: KeyBindings,
+    /// Screens opened on top of the tabs, innermost last; Esc closes one
+    screens: Vec<Screen>,
+    /// Search filtering the hourly strip and jumping between forecast days
+    search: String,
+    /// Whether the location was given or detected, shown in the title bar
+    origin: LocationOrigin,
+    /// When the forecast was fetched, shown with its age in the title bar
+    fetched_at: DateTime<Utc>,
+    freshness: FreshnessSettings,
+    status_message: Option<String>,
+    heatmap_metric: HeatmapMetric,
+    /// Multi-day frost and heatwave warnings for the daily forecast
+    warnings: Vec<WeatherAlert>,
+    map_view: MapView,
+    /// Favorite locations marked on the map tab
+    favorites: Vec<LocationCard>,
 }
 
-/// The main TUI application state
-pub struct WeatherTui {
-    state: UiState,
-    terminal: Terminal<CrosstermBackend<Stdout>>,
+/// A level opened on top of the tabs
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Screen {
+    /// The selected forecast day on its own, opened with Enter
+    Day,
+    /// The keybinding overlay
+    Help,
+    /// Typing a search after `/`
+    Search,
 }
 
-impl WeatherTui {
-    /// Create a new TUI with the provided weather data
+/// What a key press asks of the program driving the TUI
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum KeyOutcome {
+    /// The state was updated (or the key ignored); draw again
+    Handled,
+    /// Leave the weather view
+    Quit,
+    /// Save the last drawn frame
+    Screenshot,
+    /// Leave the weather view to detect the location again
+    Redetect,
+}
+
+/// Why the weather view was left
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TuiExit {
+    Quit,
+    /// The user asked for the location to be detected again
+    Redetect,
+}
+
+impl TuiState {
+    /// Build the initial state, applying settings from the config file
     pub fn new(
         hourly_data: Vec<HourlyForecast>,
         daily_data: Vec<DailyForecast>,
         location: Location,
         config: WeatherConfig,
+        file_config: &ConfigFile,
     ) -> Result<Self> {
-        // Setup terminal properly
-        enable_raw_mode()?;
-        let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-        let backend = CrosstermBackend::new(stdout);
-        let terminal = Terminal::new(backend)?;
+        let keybindings = KeyBindings::from_settings(&file_config.keybindings)?;
+
+        let calendar_cursor = daily_data
+            .first()
+            .map(|day| day.date.date_naive())
+            .unwrap_or_else(|| Utc::now().date_naive());
+
+        let warnings = multi_day_warnings(&daily_data, &file_config.warnings);
+        let origin = match config.location {
+            Some(_) => LocationOrigin::Pinned,
+            None => LocationOrigin::Detected,
+        };
 
-        let state = UiState {
+        Ok(Self {
             active_tab: TuiTab::CurrentWeather,
             hourly_data,
             daily_data,
             location,
             config,
-        };
+            selected_day: 0,
+            calendar_cursor,
+            layout: file_config.tui.layout,
+            keybindings,
+            screens: Vec::new(),
+            search: String::new(),
+            origin,
+            fetched_at: Utc::now(),
+            freshness: file_config.freshness.clone(),
+            status_message: None,
+            heatmap_metric: HeatmapMetric::Precipitation,
+            warnings,
+            map_view: MapView::default(),
+            favorites: Vec::new(),
+        })
+    }
 
-        Ok(Self { state, terminal })
+    /// Mark favorite locations on the map tab
+    pub fn set_favorites(&mut self, favorites: Vec<LocationCard>) {
+        self.favorites = favorites;
     }
 
-    /// Run the TUI application
-    pub fn run(&mut self) -> Result<()> {
-        loop {
-            // Clone the active tab before drawing to avoid borrowing issues
-            let active_tab = self.state.active_tab;
-            let hourly_data = self.state.hourly_data.clone();
-            let daily_data = self.state.daily_data.clone();
-            let location = self.state.location.clone();
-            let config = self.state.config.clone();
-
-            self.terminal.draw(|f| {
-                let chunks = Layout::default()
-                    .direction(Direction::Vertical)
-                    .margin(1)
-                    .constraints(
-                        [
-                            Constraint::Length(3), // Title
-                            Constraint::Length(3), // Tabs
-                            Constraint::Min(0),    // Content
-                            Constraint::Length(3), // Help
-                        ]
-                        .as_ref(),
-                    )
-                    .split(f.size());
+    /// Show whether the location was pinned or detected
+    pub fn set_origin(&mut self, origin: LocationOrigin) {
+        self.origin = origin;
+    }
 
-                // Render title
-                let units_text = match config.units.as_str() {
-                    "metric" => "°C",
-                    "imperial" => "°F",
-                    _ => "K",
-                };
+    /// Show the age of the data, e.g. when it was served from the cache
+    pub fn set_fetched_at(&mut self, fetched_at: DateTime<Utc>) {
+        self.fetched_at = fetched_at;
+    }
 
-                let title = Paragraph::new(Text::from(vec![Line::from(vec![
-                    Span::styled(
-                        format!("Weather Man - {}", location.name),
-                        Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                    Span::raw(" "),
-                    Span::styled(
-                        format!("[{}, {}]", location.country, location.country_code),
-                        Style::default().fg(Color::Gray),
-                    ),
-                    Span::raw(" "),
-                    Span::styled(
-                        format!("({})", units_text),
-                        Style::default().fg(Color::Yellow),
-                    ),
-                ])]))
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .border_type(BorderType::Rounded)
-                        .style(Style::default().fg(Color::Cyan)),
-                );
+    /// How old the data is now
+    pub fn freshness(&self) -> Freshness {
+        Freshness::new(self.fetched_at, Utc::now(), &self.freshness)
+    }
 
-                f.render_widget(title, chunks[0]);
+    pub fn active_tab(&self) -> TuiTab {
+        self.active_tab
+    }
 
-                // Render tabs
-                let titles = [
-                    TuiTab::CurrentWeather,
-                    TuiTab::WeatherForecast,
-                    TuiTab::Calendar,
-                ]
-                .iter()
-                .map(|t| {
-                    let (first, rest) = t.to_string().split_at(1);
-                    Line::from(vec![
-                        Span::styled(
-                            first,
-                            Style::default()
-                                .fg(Color::Yellow)
-                                .add_modifier(Modifier::UNDERLINED),
-                        ),
-                        Span::styled(rest, Style::default().fg(Color::White)),
-                    ])
-                })
-                .collect::<Vec<_>>();
-
-                let tabs = Tabs::new(titles)
-                    .block(
-                        Block::default()
-                            .borders(Borders::ALL)
-                            .border_type(BorderType::Rounded)
-                            .title("Tabs")
-                            .style(Style::default().fg(Color::Cyan)),
-                    )
-                    .select(match active_tab {
-                        TuiTab::CurrentWeather => 0,
-                        TuiTab::WeatherForecast => 1,
-                        TuiTab::Calendar => 2,
-                    })
-                    .style(Style::default().fg(Color::White))
-                    .highlight_style(
-                        Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::BOLD),
-                    );
-
-                f.render_widget(tabs, chunks[1]);
-
-                // Render content based on selected tab
-                match active_tab {
-                    TuiTab::CurrentWeather => {
-                        use crate::modules::canvas::render_current_weather_canvas;
-                        render_current_weather_canvas(&hourly_data, f, chunks[2]);
-                    }
-                    TuiTab::WeatherForecast => {
-                        use crate::modules::canvas::render_forecast_canvas;
-                        render_forecast_canvas(&daily_data, f, chunks[2]);
-                    }
-                    TuiTab::Calendar => {
-                        render_weather_calendar(&daily_data, &location, f, chunks[2]);
+    /// The innermost open screen, `None` on the tabs themselves
+    pub fn screen(&self) -> Option<Screen> {
+        self.screens.last().copied()
+    }
+
+    /// Titles from the active tab down to the innermost screen
+    pub fn breadcrumbs(&self) -> Vec<String> {
+        let mut crumbs = vec![match self.layout {
+            TuiLayout::Tabs => self.active_tab.to_string().to_string(),
+            TuiLayout::Dashboard => "Dashboard".to_string(),
+        }];
+        for screen in &self.screens {
+            crumbs.push(match screen {
+                Screen::Day => self
+                    .daily_data
+                    .get(self.selected_day)
+                    .map(|day| day.date.format("%A %d %B").to_string())
+                    .unwrap_or_else(|| "Day".to_string()),
+                Screen::Help => "Help".to_string(),
+                Screen::Search => "Search".to_string(),
+            });
+        }
+        crumbs
+    }
+
+    /// Switch to a tab, as its number key does, closing any open screens
+    /// and the search
+    pub fn select_tab(&mut self, tab: TuiTab) {
+        self.screens.clear();
+        self.search.clear();
+        self.active_tab = tab;
+    }
+
+    /// The current search, empty when not searching
+    pub fn search(&self) -> &str {
+        &self.search
+    }
+
+    /// Days matching the search
+    fn matching_days(&self) -> Vec<usize> {
+        let days = &self.daily_data[..self.daily_data.len().min(7)];
+        matching_days(days, &self.search)
+    }
+
+    /// Select the first matching day from `start` on, wrapping around
+    fn jump_to_match(&mut self, start: usize) {
+        let matches = self.matching_days();
+        if let Some(&day) = matches
+            .iter()
+            .find(|&&day| day >= start)
+            .or(matches.first())
+        {
+            self.selected_day = day;
+        }
+    }
+
+    /// Apply a key typed into the search prompt
+    fn handle_search_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Char(c) => self.search.push(c),
+            KeyCode::Backspace => {
+                self.search.pop();
+            }
+            // Keep the search and go back to browsing
+            KeyCode::Enter => {
+                self.screens.pop();
+                return;
+            }
+            KeyCode::Esc => {
+                self.search.clear();
+                self.screens.pop();
+                return;
+            }
+            _ => return,
+        }
+        if self.active_tab == TuiTab::WeatherForecast {
+            self.jump_to_match(self.selected_day);
+        }
+    }
+
+    /// Open a screen on top of the current one
+    fn open(&mut self, screen: Screen) {
+        if self.screen() != Some(screen) {
+            self.screens.push(screen);
+        }
+    }
+
+    /// Whether a tab is showing, with no screen on top of it
+    fn on_tab(&self, tab: TuiTab) -> bool {
+        self.screens.is_empty() && self.layout == TuiLayout::Tabs && self.active_tab == tab
+    }
+
+    /// Switch between the tabbed and dashboard layouts
+    pub fn set_layout(&mut self, layout: TuiLayout) {
+        self.layout = layout;
+    }
+
+    /// Show a message in place of the key hints until the next key press
+    pub fn set_status(&mut self, message: String) {
+        self.status_message = Some(message);
+    }
+
+    /// Apply a key press
+    ///
+    /// Raw mode delivers Ctrl-C as a key, which quits; other Ctrl
+    /// combinations are ignored rather than read as their letter.
+    pub fn handle_key(&mut self, key: impl Into<KeyEvent>) -> KeyOutcome {
+        let key = key.into();
+        if is_interrupt(&key) {
+            return KeyOutcome::Quit;
+        }
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            return KeyOutcome::Handled;
+        }
+        let code = key.code;
+
+        // Status messages only last until the next key press
+        self.status_message = None;
+
+        // Any key dismisses the help overlay
+        if self.screen() == Some(Screen::Help) {
+            self.screens.pop();
+            return KeyOutcome::Handled;
+        }
+
+        if self.screen() == Some(Screen::Search) {
+            self.handle_search_key(code);
+            return KeyOutcome::Handled;
+        }
+
+        match code {
+            // Back a level, leaving the weather view from the tabs
+            KeyCode::Esc => {
+                if self.screens.pop().is_none() {
+                    if self.search.is_empty() {
+                        return KeyOutcome::Quit;
                     }
+                    self.search.clear();
+                }
+            }
+            KeyCode::Left | KeyCode::Right | KeyCode::Up | KeyCode::Down
+                if self.on_tab(TuiTab::Calendar) =>
+            {
+                let days = match code {
+                    KeyCode::Left => -1,
+                    KeyCode::Right => 1,
+                    KeyCode::Up => -7,
+                    _ => 7,
+                };
+                self.calendar_cursor += chrono::Duration::days(days);
+            }
+            KeyCode::Left | KeyCode::Right | KeyCode::Up | KeyCode::Down
+                if self.on_tab(TuiTab::Map) =>
+            {
+                let (dx, dy) = match code {
+                    KeyCode::Left => (-1.0, 0.0),
+                    KeyCode::Right => (1.0, 0.0),
+                    KeyCode::Up => (0.0, 1.0),
+                    _ => (0.0, -1.0),
+                };
+                self.map_view.pan(dx, dy);
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') if self.on_tab(TuiTab::Map) => {
+                self.map_view.zoom_in();
+            }
+            KeyCode::Char('-') if self.on_tab(TuiTab::Map) => {
+                self.map_view.zoom_out();
+            }
+            KeyCode::Enter if self.on_tab(TuiTab::Calendar) => {
+                let forecast_days = forecast_days_by_date(&self.daily_data);
+                if let Some((index, _)) = forecast_days.get(&self.calendar_cursor) {
+                    self.selected_day = *index;
+                    self.open(Screen::Day);
+                }
+            }
+            KeyCode::Enter if self.on_tab(TuiTab::WeatherForecast) => {
+                if self.selected_day < self.daily_data.len() {
+                    self.open(Screen::Day);
+                }
+            }
+            KeyCode::Char('1') => {
+                self.select_tab(TuiTab::CurrentWeather);
+            }
+            KeyCode::Char('2') => {
+                self.select_tab(TuiTab::WeatherForecast);
+            }
+            KeyCode::Char('3') => {
+                self.select_tab(TuiTab::Calendar);
+            }
+            KeyCode::Char('4') => {
+                self.select_tab(TuiTab::Heatmap);
+            }
+            KeyCode::Char('5') => {
+                self.select_tab(TuiTab::Meteogram);
+            }
+            KeyCode::Char('6') => {
+                self.select_tab(TuiTab::Map);
+            }
+            code => match self.keybindings.action_for(code) {
+                Some(Action::Quit) => {
+                    return KeyOutcome::Quit;
+                }
+                Some(Action::NextTab) => {
+                    self.select_tab(self.active_tab.next());
+                }
+                Some(Action::PrevTab) => {
+                    self.select_tab(self.active_tab.prev());
+                }
+                Some(Action::ToggleDashboard) if self.screens.is_empty() => {
+                    self.set_layout(match self.layout {
+                        TuiLayout::Tabs => TuiLayout::Dashboard,
+                        TuiLayout::Dashboard => TuiLayout::Tabs,
+                    });
+                }
+                Some(Action::NextDay) if self.browses_days() => {
+                    let last_day = self.daily_data.len().min(7).saturating_sub(1);
+                    self.selected_day = (self.selected_day + 1).min(last_day);
+                }
+                Some(Action::PrevDay) if self.browses_days() => {
+                    self.selected_day = self.selected_day.saturating_sub(1);
+                }
+                Some(Action::ToggleHelp) => {
+                    self.open(Screen::Help);
                 }
+                Some(Action::CycleHeatmap) if self.on_tab(TuiTab::Heatmap) => {
+                    self.heatmap_metric = self.heatmap_metric.next();
+                }
+                Some(Action::Search)
+                    if self.on_tab(TuiTab::CurrentWeather)
+                        || self.on_tab(TuiTab::WeatherForecast) =>
+                {
+                    self.search.clear();
+                    self.open(Screen::Search);
+                }
+                Some(Action::NextMatch) if self.on_tab(TuiTab::WeatherForecast) => {
+                    self.jump_to_match(self.selected_day + 1);
+                }
+                Some(Action::Screenshot) => {
+                    return KeyOutcome::Screenshot;
+                }
+                Some(Action::Redetect) => {
+                    return KeyOutcome::Redetect;
+                }
+                _ => {}
+            },
+        }
 
-                // Render help
-                let help_text = Text::from(vec![Line::from(vec![
-                    Span::styled("Keys: ", Style::default().fg(Color::Cyan)),
-                    Span::styled("←/→", Style::default().fg(Color::Yellow)),
-                    Span::raw(" Switch tabs | "),
-                    Span::styled("1-3", Style::default().fg(Color::Yellow)),
-                    Span::raw(" Select tab | "),
-                    Span::styled("q", Style::default().fg(Color::Yellow)),
-                    Span::raw(" Quit | "),
-                    Span::styled("ESC", Style::default().fg(Color::Yellow)),
-                    Span::raw(" Exit weather view"),
-                ])]);
-
-                let help = Paragraph::new(help_text)
-                    .block(
-                        Block::default()
-                            .borders(Borders::ALL)
-                            .border_type(BorderType::Rounded)
-                            .style(Style::default().fg(Color::Cyan)),
-                    )
-                    .wrap(Wrap { trim: true });
+        KeyOutcome::Handled
+    }
+
+    /// Whether j/k move between days: on the forecast tab or a day screen
+    fn browses_days(&self) -> bool {
+        self.on_tab(TuiTab::WeatherForecast) || self.screen() == Some(Screen::Day)
+    }
+}
+
+/// Terminal driver for `TuiState`: sets up the terminal, draws and reads keys
+///
+/// Drives the real terminal by default; `WeatherTui::headless` renders into
+/// an in-memory buffer instead, for tests.
+pub struct WeatherTui<B: Backend = CrosstermBackend<Stdout>> {
+    state: TuiState,
+    terminal: Terminal<B>,
+    /// Whether raw mode and the alternate screen must be undone on drop
+    owns_terminal: bool,
+}
+
+impl WeatherTui {
+    /// Create a new TUI with the provided weather data
+    pub fn new(
+        hourly_data: Vec<HourlyForecast>,
+        daily_data: Vec<DailyForecast>,
+        location: Location,
+        config: WeatherConfig,
+        settings: &ConfigFile,
+    ) -> Result<Self> {
+        // Apply user settings before the terminal switches to raw mode
+        let state = TuiState::new(hourly_data, daily_data, location, config, settings)?;
+
+        // Setup terminal properly
+        install_panic_hook();
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        let backend = CrosstermBackend::new(stdout);
+        let terminal = Terminal::new(backend)?;
+
+        Ok(Self {
+            state,
+            terminal,
+            owns_terminal: true,
+        })
+    }
+
+    /// Render the TUI once off-screen and save it as an ANSI text dump or PNG
+    pub fn screenshot(
+        hourly_data: Vec<HourlyForecast>,
+        daily_data: Vec<DailyForecast>,
+        location: Location,
+        config: WeatherConfig,
+        settings: &ConfigFile,
+        path: &Path,
+    ) -> Result<()> {
+        let mut tui = WeatherTui::headless(
+            hourly_data,
+            daily_data,
+            location,
+            config,
+            settings,
+            SCREENSHOT_SIZE.0,
+            SCREENSHOT_SIZE.1,
+        )?;
+        let state = &tui.state;
+        let frame = tui.terminal.draw(|f| render(state, f))?;
+        save_buffer(frame.buffer, path)
+    }
 
-                f.render_widget(help, chunks[3]);
-            })?;
+    /// Run the TUI application until the user leaves it
+    pub fn run(&mut self) -> Result<TuiExit> {
+        let exit = loop {
+            let state = &self.state;
+            let frame = self.terminal.draw(|f| render(state, f))?;
+            let last_frame = frame.buffer.clone();
 
             match event::read()? {
-                Event::Key(key) => {
-                    if key.kind == KeyEventKind::Press {
-                        match key.code {
-                            KeyCode::Char('q') | KeyCode::Esc => {
-                                break;
-                            }
-                            KeyCode::Right | KeyCode::Tab => {
-                                self.state.active_tab = self.state.active_tab.next();
-                            }
-                            KeyCode::Left | KeyCode::BackTab => {
-                                self.state.active_tab = self.state.active_tab.prev();
-                            }
-                            KeyCode::Char('1') => {
-                                self.state.active_tab = TuiTab::CurrentWeather;
-                            }
-                            KeyCode::Char('2') => {
-                                self.state.active_tab = TuiTab::WeatherForecast;
-                            }
-                            KeyCode::Char('3') => {
-                                self.state.active_tab = TuiTab::Calendar;
-                            }
-                            _ => {}
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    match self.state.handle_key(key) {
+                        KeyOutcome::Handled => {}
+                        KeyOutcome::Quit => break TuiExit::Quit,
+                        KeyOutcome::Redetect => break TuiExit::Redetect,
+                        KeyOutcome::Screenshot => {
+                            let path = default_screenshot_path();
+                            self.state
+                                .set_status(match save_buffer(&last_frame, &path) {
+                                    Ok(()) => format!("Screenshot saved to {}", path.display()),
+                                    Err(e) => format!("Screenshot failed: {:#}", e),
+                                });
                         }
                     }
                 }
@@ -266,7 +913,7 @@ impl WeatherTui {
                     // Ignore other events
                 }
             }
-        }
+        };
 
         // Restore terminal
         disable_raw_mode()?;
@@ -277,79 +924,1206 @@ impl WeatherTui {
         )?;
         self.terminal.show_cursor()?;
 
-        Ok(())
+        Ok(exit)
     }
-
-    // The UI drawing methods have been moved into the run() function to avoid borrowing issues
 }
 
-/// Render a weather calendar showing conditions for a range of dates
-fn render_weather_calendar<B: ratatui::backend::Backend>(
-    daily_data: &[DailyForecast],
-    location: &Location,
-    frame: &mut ratatui::Frame<B>,
-    area: ratatui::layout::Rect,
-) {
-    // Create a simple text-based calendar view
-    let mut calendar_text = Vec::new();
+impl WeatherTui<TestBackend> {
+    /// Create a TUI drawing into an off-screen buffer of the given size,
+    /// leaving the real terminal alone
+    pub fn headless(
+        hourly_data: Vec<HourlyForecast>,
+        daily_data: Vec<DailyForecast>,
+        location: Location,
+        config: WeatherConfig,
+        settings: &ConfigFile,
+        width: u16,
+        height: u16,
+    ) -> Result<Self> {
+        let state = TuiState::new(hourly_data, daily_data, location, config, settings)?;
+        let terminal = Terminal::new(TestBackend::new(width, height))?;
 
-    calendar_text.push(Line::from(vec![Span::styled(
-        "7-Day Weather Calendar",
-        Style::default()
-            .fg(Color::Cyan)
-            .add_modifier(Modifier::BOLD),
-    )]));
-    calendar_text.push(Line::from(vec![Span::raw("")]));
+        Ok(Self {
+            state,
+            terminal,
+            owns_terminal: false,
+        })
+    }
 
-    // Show next 7 days with weather info
-    for day in daily_data.iter().take(7) {
-        let local_date = convert_to_local(&day.date, &location.timezone);
-        let weekday = local_date.format("%A").to_string();
-        let date_str = local_date.format("%m/%d").to_string();
-
-        let condition_emoji = day.main_condition.get_emoji();
-        let color = match day.main_condition {
-            WeatherCondition::Clear => Color::Yellow,
-            WeatherCondition::Clouds => Color::Gray,
-            WeatherCondition::Rain | WeatherCondition::Drizzle => Color::Blue,
-            WeatherCondition::Thunderstorm => Color::Magenta,
-            WeatherCondition::Snow => Color::White,
-            _ => Color::Gray,
+    /// Draw the current view and return it as plain text
+    #[allow(dead_code)]
+    pub fn render(&mut self) -> Result<String> {
+        let state = &self.state;
+        let frame = self.terminal.draw(|f| render(state, f))?;
+        Ok(buffer_to_text(frame.buffer))
+    }
+}
+
+impl<B: Backend> WeatherTui<B> {
+    /// Mark favorite locations on the map tab
+    pub fn with_favorites(mut self, favorites: Vec<LocationCard>) -> Self {
+        self.state.set_favorites(favorites);
+        self
+    }
+
+    /// Open with a status message, e.g. why the last action failed
+    pub fn with_status(mut self, message: Option<String>) -> Self {
+        if let Some(message) = message {
+            self.state.set_status(message);
+        }
+        self
+    }
+
+    /// Show whether the location was pinned or detected
+    pub fn with_origin(mut self, origin: LocationOrigin) -> Self {
+        self.state.set_origin(origin);
+        self
+    }
+
+    /// Show the age of the data, e.g. when it was served from the cache
+    pub fn with_fetched_at(mut self, fetched_at: DateTime<Utc>) -> Self {
+        self.state.set_fetched_at(fetched_at);
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn state(&self) -> &TuiState {
+        &self.state
+    }
+
+    #[allow(dead_code)]
+    pub fn state_mut(&mut self) -> &mut TuiState {
+        &mut self.state
+    }
+}
+
+/// Render a 24-hour strip with per-hour icons, temperatures and precipitation bars
+///
+/// With a search, shows the matching hours of the whole forecast instead.
+fn render_hourly_timeline<B: ratatui::backend::Backend>(
+    hourly_data: &[HourlyForecast],
+    location: &Location,
+    icons: IconSet,
+    search: &str,
+    frame: &mut ratatui::Frame<B>,
+    area: ratatui::layout::Rect,
+) {
+    const COLUMN_WIDTH: usize = 5;
+    const POP_BARS: [&str; 8] = ["▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"];
+
+    // Fit as many hours as the strip can hold, up to a full day
+    let inner_width = area.width.saturating_sub(2) as usize;
+    let (title, shown, time_style) = if search.is_empty() {
+        (
+            "Next 24 Hours".to_string(),
+            (0..hourly_data.len()).take(24).collect::<Vec<_>>(),
+            Style::default().fg(Color::Gray),
+        )
+    } else {
+        let matches = matching_hours(hourly_data, location, search);
+        (
+            format!("Hours matching \"{}\" ({})", search, matches.len()),
+            matches,
+            Style::default().fg(Color::Black).bg(Color::Yellow),
+        )
+    };
+    let hours = shown
+        .iter()
+        .map(|&i| &hourly_data[i])
+        .take(inner_width / COLUMN_WIDTH);
+
+    let mut time_row = Vec::new();
+    let mut icon_row = Vec::new();
+    let mut temp_row = Vec::new();
+    let mut bar_row = Vec::new();
+    let mut pop_row = Vec::new();
+
+    for hour in hours {
+        let local_time = convert_to_local(&hour.timestamp, location.clock_timezone());
+        let temp_color = match hour.temperature as i32 {
+            t if t > 30 => Color::Red,
+            t if t > 20 => Color::Yellow,
+            t if t > 10 => Color::Green,
+            t if t > 0 => Color::LightBlue,
+            _ => Color::Blue,
+        };
+        let pop_percent = (hour.pop.clamp(0.0, 1.0) * 100.0) as u8;
+        let bar_index = ((hour.pop.clamp(0.0, 1.0) * 7.0).round()) as usize;
+        let bar = if pop_percent == 0 {
+            "  ".to_string()
+        } else {
+            POP_BARS[bar_index].repeat(2)
         };
 
-        let pop_percent = (day.pop * 100.0) as u8;
+        time_row.push(Span::styled(
+            format!(
+                "{:^width$}",
+                local_time.format("%Hh").to_string(),
+                width = COLUMN_WIDTH
+            ),
+            time_style,
+        ));
+        icon_row.push(Span::raw(format!(
+            " {:<width$}",
+            icons.condition(hour.main_condition),
+            width = COLUMN_WIDTH - 2
+        )));
+        temp_row.push(Span::styled(
+            format!(
+                "{:^width$}",
+                format!("{:.0}°", hour.temperature),
+                width = COLUMN_WIDTH
+            ),
+            Style::default().fg(temp_color),
+        ));
+        bar_row.push(Span::styled(
+            format!("{:^width$}", bar, width = COLUMN_WIDTH),
+            Style::default().fg(Color::Blue),
+        ));
+        pop_row.push(Span::styled(
+            format!(
+                "{:^width$}",
+                format!("{}%", pop_percent),
+                width = COLUMN_WIDTH
+            ),
+            Style::default().fg(Color::LightBlue),
+        ));
+    }
 
-        calendar_text.push(Line::from(vec![
-            Span::styled(format!("{:9}", weekday), Style::default().fg(Color::Cyan)),
-            Span::raw(" "),
-            Span::styled(date_str, Style::default().fg(Color::Gray)),
-            Span::raw("  "),
-            Span::styled(condition_emoji, Style::default()),
-            Span::raw(" "),
+    let strip = Paragraph::new(vec![
+        Line::from(time_row),
+        Line::from(icon_row),
+        Line::from(temp_row),
+        Line::from(bar_row),
+        Line::from(pop_row),
+    ])
+    .block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Cyan)),
+    );
+
+    frame.render_widget(strip, area);
+}
+
+/// Render the full TUI (title, tabs, content and help) for the given state
+pub fn render<B: Backend>(state: &TuiState, f: &mut ratatui::Frame<B>) {
+    let active_tab = state.active_tab();
+    let location = &state.location;
+    let config = &state.config;
+    let layout = state.layout;
+    let keybindings = &state.keybindings;
+    let freshness = state.freshness();
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints(
+            [
+                Constraint::Length(3), // Title
+                Constraint::Length(3), // Tabs
+                Constraint::Min(0),    // Content
+                Constraint::Length(3), // Help
+            ]
+            .as_ref(),
+        )
+        .split(f.size());
+
+    // Render title
+    let units_text = config.display_units.temperature.label();
+
+    let title = Paragraph::new(Text::from(vec![Line::from(vec![
+        Span::styled(
+            format!("Weather Man - {}", location.name),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+        Span::styled(
+            format!("[{}, {}]", location.country, location.country_code),
+            Style::default().fg(Color::Gray),
+        ),
+        Span::raw(" "),
+        Span::styled(
+            format!("({})", units_text),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::styled(
+            match state.origin {
+                LocationOrigin::Pinned => "  📌 pinned",
+                LocationOrigin::Detected => "  📍 auto-detected",
+            },
+            Style::default().fg(Color::Gray),
+        ),
+        Span::styled(
+            format!("  {}", state.breadcrumbs().join(" › ")),
+            Style::default().fg(Color::Gray),
+        ),
+        // Last, so a narrow terminal cuts the age rather than the breadcrumbs
+        Span::styled(
+            format!("  🕒 {}", freshness.describe(location.clock_timezone())),
+            Style::default().fg(match freshness.staleness {
+                Staleness::Fresh => Color::Gray,
+                Staleness::Aging => Color::Yellow,
+                Staleness::Stale => Color::Red,
+            }),
+        ),
+    ])]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .style(Style::default().fg(Color::Cyan)),
+    );
+
+    f.render_widget(title, chunks[0]);
+
+    // Render tabs
+    let titles = [
+        TuiTab::CurrentWeather,
+        TuiTab::WeatherForecast,
+        TuiTab::Calendar,
+        TuiTab::Heatmap,
+        TuiTab::Meteogram,
+        TuiTab::Map,
+    ]
+    .iter()
+    .map(|t| {
+        let (first, rest) = t.to_string().split_at(1);
+        Line::from(vec![
+            Span::styled(
+                first,
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::UNDERLINED),
+            ),
+            Span::styled(rest, Style::default().fg(Color::White)),
+        ])
+    })
+    .collect::<Vec<_>>();
+
+    let tabs = Tabs::new(titles)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(match layout {
+                    TuiLayout::Tabs => "Tabs",
+                    TuiLayout::Dashboard => "Tabs (dashboard active, d to exit)",
+                })
+                .style(Style::default().fg(Color::Cyan)),
+        )
+        .select(match active_tab {
+            TuiTab::CurrentWeather => 0,
+            TuiTab::WeatherForecast => 1,
+            TuiTab::Calendar => 2,
+            TuiTab::Heatmap => 3,
+            TuiTab::Meteogram => 4,
+            TuiTab::Map => 5,
+        })
+        .style(Style::default().fg(Color::White))
+        .highlight_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    f.render_widget(tabs, chunks[1]);
+
+    render_content(state, f, chunks[2]);
+
+    // Render help, or the search prompt or latest status message in its place
+    let key_style = Style::default().fg(Color::Yellow);
+    let help_text = match &state.status_message {
+        Some(message) => Text::from(Line::from(Span::styled(
+            message.clone(),
+            Style::default().fg(Color::Green),
+        ))),
+        None if state.screen() == Some(Screen::Search) => Text::from(Line::from(vec![
+            Span::styled("Search: ", Style::default().fg(Color::Cyan)),
+            Span::raw(format!("{}▏", state.search())),
+            Span::raw("  (e.g. sat, rain, 18:) "),
+            Span::styled("Enter", key_style),
+            Span::raw(" Keep | "),
+            Span::styled("ESC", key_style),
+            Span::raw(" Cancel"),
+        ])),
+        None if !state.search().is_empty() => Text::from(Line::from(vec![
+            Span::styled(
+                format!("Search \"{}\": ", state.search()),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(keybindings.label(Action::NextMatch), key_style),
+            Span::raw(" Next match | "),
+            Span::styled(keybindings.label(Action::Search), key_style),
+            Span::raw(" New search | "),
+            Span::styled("ESC", key_style),
+            Span::raw(" Clear"),
+        ])),
+        None => Text::from(vec![Line::from(vec![
+            Span::styled("Keys: ", Style::default().fg(Color::Cyan)),
+            Span::styled(
+                format!(
+                    "{}/{}",
+                    keybindings.label(Action::PrevTab),
+                    keybindings.label(Action::NextTab)
+                ),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::raw(" Switch tabs | "),
+            Span::styled("1-6", Style::default().fg(Color::Yellow)),
+            Span::raw(" Select tab | "),
+            Span::styled(
+                keybindings.label(Action::ToggleHelp),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::raw(" Help | "),
+            Span::styled(
+                keybindings.label(Action::Quit),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::raw(" Quit | "),
+            Span::styled("ESC", Style::default().fg(Color::Yellow)),
+            Span::raw(if state.screens.is_empty() {
+                " Exit weather view"
+            } else {
+                " Back"
+            }),
+        ])]),
+    };
+
+    let help = Paragraph::new(help_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .style(Style::default().fg(Color::Cyan)),
+        )
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(help, chunks[3]);
+
+    if state.screen() == Some(Screen::Help) {
+        render_help_overlay(keybindings, f);
+    }
+}
+
+/// Render the active tab, the dashboard or an open day screen into an area
+/// of the frame
+///
+/// Lets other ratatui apps embed the weather view without the title, tab
+/// bar and key hints.
+pub fn render_content<B: Backend>(
+    state: &TuiState,
+    f: &mut ratatui::Frame<B>,
+    area: ratatui::layout::Rect,
+) {
+    let hourly_data = &state.hourly_data;
+    let daily_data = &state.daily_data;
+    let location = &state.location;
+    let config = &state.config;
+
+    if state.screens.contains(&Screen::Day) {
+        render_day_detail(
+            daily_data,
+            hourly_data,
+            state.selected_day,
+            location,
+            config,
+            f,
+            area,
+        );
+    } else if state.layout == TuiLayout::Dashboard {
+        render_dashboard(hourly_data, daily_data, location, config, f, area);
+    } else {
+        match state.active_tab {
+            TuiTab::CurrentWeather => {
+                use crate::modules::canvas::render_current_weather_canvas;
+                let content = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints(
+                        [
+                            Constraint::Min(0),    // Scene
+                            Constraint::Length(7), // Hourly timeline strip
+                        ]
+                        .as_ref(),
+                    )
+                    .split(area);
+                render_current_weather_canvas(hourly_data, f, content[0]);
+                render_hourly_timeline(
+                    hourly_data,
+                    location,
+                    config.icons,
+                    &state.search,
+                    f,
+                    content[1],
+                );
+            }
+            TuiTab::WeatherForecast => {
+                use crate::modules::canvas::render_forecast_canvas;
+                let content = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints(
+                        [
+                            Constraint::Min(0),     // Day tiles
+                            Constraint::Length(10), // Selected day detail
+                        ]
+                        .as_ref(),
+                    )
+                    .split(area);
+                render_forecast_canvas(
+                    daily_data,
+                    Some(state.selected_day),
+                    &state.matching_days(),
+                    f,
+                    content[0],
+                );
+                render_day_detail(
+                    daily_data,
+                    hourly_data,
+                    state.selected_day,
+                    location,
+                    config,
+                    f,
+                    content[1],
+                );
+            }
+            TuiTab::Calendar => {
+                render_weather_calendar(
+                    daily_data,
+                    location,
+                    config.icons,
+                    &state.warnings,
+                    state.calendar_cursor,
+                    f,
+                    area,
+                );
+            }
+            TuiTab::Heatmap => {
+                render_heatmap(hourly_data, location, state.heatmap_metric, f, area);
+            }
+            TuiTab::Meteogram => {
+                let lines: Vec<Line> = render_meteogram(hourly_data, location, METEOGRAM_HOURS)
+                    .into_iter()
+                    .skip(1)
+                    .map(Line::from)
+                    .collect();
+                let meteogram = Paragraph::new(lines).block(
+                    Block::default()
+                        .title(format!("Meteogram - next {}h", METEOGRAM_HOURS))
+                        .borders(Borders::ALL)
+                        .style(Style::default().fg(Color::Cyan)),
+                );
+                f.render_widget(meteogram, area);
+            }
+            TuiTab::Map => {
+                render_world_map(state, f, area);
+            }
+        }
+    }
+}
+
+/// Render a centered modal listing every keybinding
+fn render_help_overlay<B: ratatui::backend::Backend>(
+    keybindings: &KeyBindings,
+    frame: &mut ratatui::Frame<B>,
+) {
+    let key_style = Style::default().fg(Color::Yellow);
+    let mut lines = vec![Line::from(vec![Span::styled(
+        "Keybindings",
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    )])];
+    lines.push(Line::from(""));
+
+    for action in Action::ALL {
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:>14}  ", keybindings.label(action)), key_style),
+            Span::raw(action.description()),
+        ]));
+    }
+
+    for (keys, description) in [
+        ("1-6", "Jump to tab"),
+        ("← → ↑ ↓", "Move day (calendar tab), pan (map tab)"),
+        ("+ -", "Zoom in and out (map tab)"),
+        ("Enter", "Open day details (calendar and forecast tabs)"),
+        ("Esc", "Back, or exit weather view"),
+    ] {
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:>14}  ", keys), key_style),
+            Span::raw(description),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Press any key to close",
+        Style::default().fg(Color::Gray),
+    )));
+
+    // Fit the popup to its contents plus the border
+    let area = frame.size();
+    let content_width = lines.iter().map(Line::width).max().unwrap_or(0) as u16;
+    let width = (content_width + 2).min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let popup = ratatui::layout::Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    );
+
+    let help = Paragraph::new(lines).block(
+        Block::default()
+            .title("Help")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .style(Style::default().fg(Color::Cyan)),
+    );
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(help, popup);
+}
+
+/// Render the world map with condition-colored markers for the active
+/// location and the favorites
+fn render_world_map<B: ratatui::backend::Backend>(
+    state: &TuiState,
+    frame: &mut ratatui::Frame<B>,
+    area: ratatui::layout::Rect,
+) {
+    // Conditions for the current hour, falling back to the first forecast hour
+    let now = Utc::now();
+    let current = state
+        .hourly_data
+        .iter()
+        .find(|h| h.timestamp <= now && now < h.timestamp + chrono::Duration::hours(1))
+        .or(state.hourly_data.first());
+
+    let mut markers: Vec<(f64, f64, String, Color)> = state
+        .favorites
+        .iter()
+        .filter_map(|card| {
+            let location = card.location.as_ref()?;
+            let (label, color) = match &card.weather {
+                Some(weather) => (
+                    format!("{} {:.0}°", location.name, weather.temperature),
+                    condition_color(weather.main_condition),
+                ),
+                None => (location.name.clone(), Color::DarkGray),
+            };
+            Some((location.longitude, location.latitude, label, color))
+        })
+        .collect();
+    // The active location is drawn last so it stays on top
+    let location = &state.location;
+    markers.push(match current {
+        Some(hour) => (
+            location.longitude,
+            location.latitude,
+            format!("{} {:.0}°", location.name, hour.temperature),
+            condition_color(hour.main_condition),
+        ),
+        None => (
+            location.longitude,
+            location.latitude,
+            location.name.clone(),
+            Color::White,
+        ),
+    });
+
+    let view = state.map_view;
+    let map = Canvas::default()
+        .block(
+            Block::default()
+                .title(format!(
+                    "World Map - {:.0}°, {:.0}° x{} (arrows pan, +/- zoom)",
+                    view.latitude, view.longitude, view.zoom
+                ))
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::Cyan)),
+        )
+        .marker(symbols::Marker::Braille)
+        .x_bounds(view.x_bounds())
+        .y_bounds(view.y_bounds())
+        .paint(move |ctx| {
+            ctx.draw(&Map {
+                color: Color::DarkGray,
+                resolution: MapResolution::High,
+            });
+            ctx.layer();
+            for (longitude, latitude, label, color) in &markers {
+                ctx.print(
+                    *longitude,
+                    *latitude,
+                    Line::from(vec![
+                        Span::styled("● ", Style::default().fg(*color)),
+                        Span::styled(
+                            label.clone(),
+                            Style::default().fg(*color).add_modifier(Modifier::BOLD),
+                        ),
+                    ]),
+                );
+            }
+        });
+
+    frame.render_widget(map, area);
+}
+
+/// Render the dashboard layout with scene, hourly chart and 7-day summary side by side
+fn render_dashboard<B: ratatui::backend::Backend>(
+    hourly_data: &[HourlyForecast],
+    daily_data: &[DailyForecast],
+    location: &Location,
+    config: &WeatherConfig,
+    frame: &mut ratatui::Frame<B>,
+    area: ratatui::layout::Rect,
+) {
+    use crate::modules::canvas::render_current_weather_canvas;
+
+    // Wide terminals get three columns, narrower ones stack the panes
+    let direction = if area.width >= 120 {
+        Direction::Horizontal
+    } else {
+        Direction::Vertical
+    };
+    let panes = Layout::default()
+        .direction(direction)
+        .constraints(
+            [
+                Constraint::Percentage(40), // Current scene
+                Constraint::Percentage(35), // Hourly chart
+                Constraint::Percentage(25), // 7-day summary
+            ]
+            .as_ref(),
+        )
+        .split(area);
+
+    render_current_weather_canvas(hourly_data, frame, panes[0]);
+    render_hourly_temperature_chart(hourly_data, location, config, frame, panes[1]);
+    render_week_summary(daily_data, config.icons, frame, panes[2]);
+}
+
+/// Render a line chart of the next 24 hours of temperature
+fn render_hourly_temperature_chart<B: ratatui::backend::Backend>(
+    hourly_data: &[HourlyForecast],
+    location: &Location,
+    config: &WeatherConfig,
+    frame: &mut ratatui::Frame<B>,
+    area: ratatui::layout::Rect,
+) {
+    let temp_unit = config.display_units.temperature;
+    let points: Vec<(f64, f64)> = hourly_data
+        .iter()
+        .take(24)
+        .enumerate()
+        .map(|(i, h)| (i as f64, temp_unit.convert(h.temperature)))
+        .collect();
+
+    let (min_temp, max_temp) = points
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), (_, t)| {
+            (lo.min(*t), hi.max(*t))
+        });
+    let (min_temp, max_temp) = if points.is_empty() {
+        (0.0, 1.0)
+    } else {
+        ((min_temp - 1.0).floor(), (max_temp + 1.0).ceil())
+    };
+
+    let hour_label = |index: usize| {
+        hourly_data
+            .get(index)
+            .map(|h| {
+                convert_to_local(&h.timestamp, location.clock_timezone())
+                    .format("%H:%M")
+                    .to_string()
+            })
+            .unwrap_or_default()
+    };
+    let last_index = points.len().saturating_sub(1);
+
+    let datasets = vec![Dataset::default()
+        .name(format!("Temperature ({})", temp_unit.label()))
+        .marker(symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(Color::Yellow))
+        .data(&points)];
+
+    let chart = Chart::new(datasets)
+        .block(
+            Block::default()
+                .title("Next 24 Hours")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::Cyan)),
+        )
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, last_index.max(1) as f64])
+                .labels(vec![
+                    Span::raw(hour_label(0)),
+                    Span::raw(hour_label(last_index / 2)),
+                    Span::raw(hour_label(last_index)),
+                ]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds([min_temp, max_temp])
+                .labels(vec![
+                    Span::raw(format!("{:.0}{}", min_temp, temp_unit.label())),
+                    Span::raw(format!("{:.0}{}", max_temp, temp_unit.label())),
+                ]),
+        );
+
+    frame.render_widget(chart, area);
+}
+
+/// Render a 7-day x 24-hour heatmap of the selected metric
+///
+/// Each cell is drawn with upper half blocks so the empty lower halves keep the
+/// day rows visually apart.
+fn render_heatmap<B: ratatui::backend::Backend>(
+    hourly_data: &[HourlyForecast],
+    location: &Location,
+    metric: HeatmapMetric,
+    frame: &mut ratatui::Frame<B>,
+    area: ratatui::layout::Rect,
+) {
+    const LABEL_WIDTH: usize = 7;
+
+    let inner_width = area.width.saturating_sub(2) as usize;
+    let cell_width = (inner_width.saturating_sub(LABEL_WIDTH) / 24).clamp(1, 4);
+    let label_style = Style::default().fg(Color::Cyan);
+
+    // Group values by local day and hour
+    let mut grid: BTreeMap<NaiveDate, [Option<f64>; 24]> = BTreeMap::new();
+    for hour in hourly_data {
+        let local = convert_to_local(&hour.timestamp, &location.timezone);
+        grid.entry(local.date_naive()).or_insert([None; 24])[local.hour() as usize] =
+            Some(metric.value(hour));
+    }
+
+    let mut axis = " ".repeat(LABEL_WIDTH);
+    for hour in (0..24).step_by(3) {
+        axis.push_str(&format!(
+            "{:<width$}",
+            format!("{:02}", hour),
+            width = cell_width * 3
+        ));
+    }
+    let mut lines = vec![Line::from(Span::styled(
+        axis,
+        Style::default().fg(Color::Gray),
+    ))];
+
+    for (date, hours) in grid.iter().take(7) {
+        let mut spans = vec![Span::styled(
+            format!("{:<width$}", date.format("%a %d"), width = LABEL_WIDTH),
+            label_style,
+        )];
+        for value in hours {
+            spans.push(match value {
+                Some(value) => Span::styled(
+                    "▀".repeat(cell_width),
+                    Style::default().fg(metric.color(*value)),
+                ),
+                None => Span::raw(" ".repeat(cell_width)),
+            });
+        }
+        lines.push(Line::from(spans));
+    }
+
+    if grid.is_empty() {
+        lines.push(Line::from("No hourly forecast data available"));
+    }
+
+    // Legend with the color scale
+    let (low, high, unit) = match metric {
+        HeatmapMetric::Precipitation | HeatmapMetric::Humidity => (0.0, 100.0, "%"),
+        HeatmapMetric::Temperature => (-10.0, 35.0, "°C"),
+    };
+    let mut legend = vec![Span::styled(
+        format!(
+            "{:<width$}",
+            format!("{}{}", low, unit),
+            width = LABEL_WIDTH
+        ),
+        Style::default().fg(Color::Gray),
+    )];
+    for step in 0..=10 {
+        let value = low + (high - low) * step as f64 / 10.0;
+        legend.push(Span::styled(
+            "▀".repeat(cell_width),
+            Style::default().fg(metric.color(value)),
+        ));
+    }
+    legend.push(Span::styled(
+        format!(" {}{}", high, unit),
+        Style::default().fg(Color::Gray),
+    ));
+    lines.push(Line::from(""));
+    lines.push(Line::from(legend));
+
+    let heatmap = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!("Weekly Heatmap - {}", metric.label()))
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Cyan)),
+    );
+
+    frame.render_widget(heatmap, area);
+}
+
+/// Render a compact list of the next 7 days
+fn render_week_summary<B: ratatui::backend::Backend>(
+    daily_data: &[DailyForecast],
+    icons: IconSet,
+    frame: &mut ratatui::Frame<B>,
+    area: ratatui::layout::Rect,
+) {
+    let lines: Vec<Line> = daily_data
+        .iter()
+        .take(7)
+        .map(|day| {
+            let local_date = day.date;
+            Line::from(vec![
+                Span::styled(
+                    local_date.format("%a %d").to_string(),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::raw(" "),
+                Span::raw(icons.condition(day.main_condition)),
+                Span::raw(" "),
+                Span::styled(
+                    format!("{:>3}°/{:<3}°", day.temp_min as i32, day.temp_max as i32),
+                    Style::default().fg(Color::White),
+                ),
+                Span::styled(
+                    format!(" {:>3}%", (day.pop * 100.0) as u8),
+                    Style::default().fg(Color::Blue),
+                ),
+            ])
+        })
+        .collect();
+
+    let summary = Paragraph::new(lines).block(
+        Block::default()
+            .title("7-Day Summary")
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Cyan)),
+    );
+
+    frame.render_widget(summary, area);
+}
+
+/// Render the detail pane for the day selected in the forecast tab
+fn render_day_detail<B: ratatui::backend::Backend>(
+    daily_data: &[DailyForecast],
+    hourly_data: &[HourlyForecast],
+    selected_day: usize,
+    location: &Location,
+    config: &WeatherConfig,
+    frame: &mut ratatui::Frame<B>,
+    area: ratatui::layout::Rect,
+) {
+    let Some(day) = daily_data.get(selected_day) else {
+        let empty = Paragraph::new("No forecast data available").block(
+            Block::default()
+                .title("Day Detail")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::Cyan)),
+        );
+        frame.render_widget(empty, area);
+        return;
+    };
+
+    let temp_unit = config.display_units.temperature;
+    let wind_unit = config.display_units.wind;
+    let local_date = day.date;
+    let sunrise = convert_to_local(&day.sunrise, location.clock_timezone());
+    let sunset = convert_to_local(&day.sunset, location.clock_timezone());
+
+    let panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(45), Constraint::Percentage(55)].as_ref())
+        .split(area);
+
+    let label = Style::default().fg(Color::Gray);
+    let detail_text = vec![
+        Line::from(vec![
             Span::styled(
-                format!("{}", day.main_condition),
-                Style::default().fg(color),
+                local_date.format("%A %d %B").to_string(),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
             ),
             Span::raw("  "),
+            Span::raw(config.icons.condition(day.main_condition)),
+            Span::raw(" "),
+            Span::raw(day.main_condition.to_string()),
+        ]),
+        Line::from(vec![
+            Span::styled("Temp:    ", label),
             Span::styled(
-                format!("{}°-{}°C", day.temp_min as i32, day.temp_max as i32),
+                format!(
+                    "{} / {}",
+                    temp_unit.format(day.temp_max),
+                    temp_unit.format(day.temp_min)
+                ),
                 Style::default().fg(Color::White),
             ),
-            Span::raw("  "),
+        ]),
+        Line::from(vec![
+            Span::styled("Sun:     ", label),
             Span::styled(
-                format!("{}%", pop_percent),
+                format!(
+                    "↑ {}  ↓ {}",
+                    sunrise.format("%H:%M"),
+                    sunset.format("%H:%M")
+                ),
+                Style::default().fg(Color::Yellow),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Wind:    ", label),
+            Span::raw(format!(
+                "{} from {}°",
+                wind_unit.format(day.wind_speed),
+                day.wind_direction
+            )),
+        ]),
+        Line::from(vec![
+            Span::styled("UV:      ", label),
+            Span::raw(format!("{:.1}", day.uv_index)),
+        ]),
+        Line::from(vec![
+            Span::styled("Precip:  ", label),
+            Span::styled(
+                format!("{}%", (day.pop * 100.0) as u8),
                 Style::default().fg(Color::Blue),
             ),
-        ]));
+        ]),
+    ];
+
+    let detail = Paragraph::new(detail_text).block(
+        Block::default()
+            .title("Day Detail")
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Cyan)),
+    );
+    frame.render_widget(detail, panes[0]);
+
+    // Hourly temperatures that fall on the selected local date
+    let day_hours: Vec<&HourlyForecast> = hourly_data
+        .iter()
+        .filter(|h| {
+            convert_to_local(&h.timestamp, &location.timezone).date_naive()
+                == local_date.date_naive()
+        })
+        .collect();
+
+    let chart_block = Block::default()
+        .title("Hourly Temperature")
+        .borders(Borders::ALL)
+        .style(Style::default().fg(Color::Cyan));
+
+    if day_hours.is_empty() {
+        let placeholder = Paragraph::new("Hourly data is only available for the next 48 hours")
+            .block(chart_block)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(placeholder, panes[1]);
+        return;
+    }
+
+    // Sparkline needs unsigned values, so shift temperatures above the day's minimum
+    let min_temp = day_hours
+        .iter()
+        .map(|h| h.temperature)
+        .fold(f64::INFINITY, f64::min);
+    let values: Vec<u64> = day_hours
+        .iter()
+        .map(|h| ((h.temperature - min_temp) * 10.0).round() as u64 + 1)
+        .collect();
+
+    let sparkline = Sparkline::default()
+        .block(chart_block)
+        .data(&values)
+        .style(Style::default().fg(Color::Yellow));
+    frame.render_widget(sparkline, panes[1]);
+}
+
+/// Map each local forecast date to its daily forecast
+fn forecast_days_by_date(
+    daily_data: &[DailyForecast],
+) -> HashMap<NaiveDate, (usize, &DailyForecast)> {
+    daily_data
+        .iter()
+        .enumerate()
+        .map(|(i, day)| {
+            let date = day.date.date_naive();
+            (date, (i, day))
+        })
+        .collect()
+}
+
+/// Render a month-grid weather calendar with a selectable day cursor
+fn render_weather_calendar<B: ratatui::backend::Backend>(
+    daily_data: &[DailyForecast],
+    location: &Location,
+    icons: IconSet,
+    warnings: &[WeatherAlert],
+    cursor: NaiveDate,
+    frame: &mut ratatui::Frame<B>,
+    area: ratatui::layout::Rect,
+) {
+    let forecast_days = forecast_days_by_date(daily_data);
+    let cell_width = ((area.width.saturating_sub(2) / 7) as usize).clamp(6, 14);
+
+    let mut calendar_text = Vec::new();
+
+    calendar_text.push(Line::from(vec![Span::styled(
+        cursor.format("%B %Y").to_string(),
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    )]));
+    calendar_text.push(Line::from(vec![Span::raw("")]));
+
+    // Weekday header
+    calendar_text.push(Line::from(
+        ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]
+            .iter()
+            .map(|d| {
+                Span::styled(
+                    format!("{:^width$}", d, width = cell_width),
+                    Style::default().fg(Color::Gray),
+                )
+            })
+            .collect::<Vec<_>>(),
+    ));
+
+    // Start the grid on the Monday on or before the first of the month
+    let first_of_month = cursor.with_day(1).unwrap_or(cursor);
+    let grid_start = first_of_month
+        - chrono::Duration::days(first_of_month.weekday().num_days_from_monday() as i64);
+
+    let mut week_start = grid_start;
+    while week_start.month() == cursor.month() || week_start == grid_start {
+        let mut number_row = Vec::new();
+        let mut icon_row = Vec::new();
+        let mut temp_row = Vec::new();
+
+        for offset in 0..7 {
+            let date = week_start + chrono::Duration::days(offset);
+            let in_month = date.month() == cursor.month();
+            let forecast = forecast_days.get(&date).map(|(_, day)| *day);
+
+            let mut style = match (in_month, forecast.is_some()) {
+                (false, _) => Style::default().fg(Color::DarkGray),
+                (true, true) => Style::default().fg(Color::White),
+                (true, false) => Style::default().fg(Color::Gray),
+            };
+            if date == cursor {
+                style = style.bg(Color::Blue).add_modifier(Modifier::BOLD);
+            }
+
+            number_row.push(Span::styled(
+                format!("{:^width$}", date.day(), width = cell_width),
+                style,
+            ));
+
+            match forecast {
+                Some(day) => {
+                    let color = condition_color(day.main_condition);
+                    icon_row.push(Span::styled(
+                        format!(
+                            "{:^width$}",
+                            icons.condition(day.main_condition),
+                            width = cell_width - 1
+                        ),
+                        style.fg(color),
+                    ));
+                    // Days under a frost or heatwave warning get colored temperatures
+                    let warning = warnings
+                        .iter()
+                        .find(|alert| alert_covers(alert, date, location));
+                    let temp_style = match warning {
+                        Some(alert) if alert.tags.iter().any(|t| t == "frost") => {
+                            style.fg(Color::LightBlue).add_modifier(Modifier::BOLD)
+                        }
+                        Some(_) => style.fg(Color::LightRed).add_modifier(Modifier::BOLD),
+                        None => style,
+                    };
+                    temp_row.push(Span::styled(
+                        format!(
+                            "{:^width$}",
+                            format!("{}°/{}°", day.temp_min as i32, day.temp_max as i32),
+                            width = cell_width
+                        ),
+                        temp_style,
+                    ));
+                }
+                None => {
+                    icon_row.push(Span::styled(" ".repeat(cell_width), style));
+                    temp_row.push(Span::styled(" ".repeat(cell_width), style));
+                }
+            }
+        }
+
+        calendar_text.push(Line::from(number_row));
+        calendar_text.push(Line::from(icon_row));
+        calendar_text.push(Line::from(temp_row));
+
+        week_start += chrono::Duration::days(7);
     }
 
     calendar_text.push(Line::from(vec![Span::raw("")]));
-    calendar_text.push(Line::from(vec![
-        Span::styled("Legend: ", Style::default().fg(Color::Gray)),
-        Span::styled("Temperature Range", Style::default().fg(Color::White)),
-        Span::raw(" | "),
-        Span::styled("Rain %", Style::default().fg(Color::Blue)),
-    ]));
+
+    // Summary of the day under the cursor
+    match forecast_days.get(&cursor) {
+        Some((_, day)) => calendar_text.push(Line::from(vec![
+            Span::styled(
+                cursor.format("%A %d %B: ").to_string(),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::raw(format!(
+                "{} {}, {}°-{}°, {}% rain",
+                icons.condition(day.main_condition),
+                day.main_condition,
+                day.temp_min as i32,
+                day.temp_max as i32,
+                (day.pop * 100.0) as u8
+            )),
+            Span::styled("  (Enter for details)", Style::default().fg(Color::Gray)),
+        ])),
+        None => calendar_text.push(Line::from(vec![Span::styled(
+            format!("{}: no forecast data", cursor.format("%A %d %B")),
+            Style::default().fg(Color::Gray),
+        )])),
+    }
+
+    for alert in warnings {
+        let color = if alert.tags.iter().any(|t| t == "frost") {
+            Color::LightBlue
+        } else {
+            Color::LightRed
+        };
+        calendar_text.push(Line::from(vec![Span::styled(
+            format!(
+                "⚠ {} from {}: {}",
+                alert.event,
+                convert_to_local(&alert.start, &location.timezone).format("%a %d %b"),
+                alert.description
+            ),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )]));
+    }
 
     let calendar = Paragraph::new(calendar_text)
         .block(
@@ -363,15 +2137,401 @@ fn render_weather_calendar<B: ratatui::backend::Backend>(
     frame.render_widget(calendar, area);
 }
 
-impl Drop for WeatherTui {
+/// Show this week's forecast over the monthly normals until q or Esc is pressed
+pub fn run_climate_chart(days: &[ClimateComparison], location: &Location) -> Result<()> {
+    if days.is_empty() {
+        return Err(anyhow!("No forecast days to compare with the normals"));
+    }
+    run_static_view(|f| draw_climate_chart(f, days, location))
+}
+
+/// Show the ensemble's temperature spread until q or Esc is pressed
+pub fn run_ensemble_chart(ensemble: &Ensemble, location: &Location) -> Result<()> {
+    if ensemble.hours.is_empty() {
+        return Err(anyhow!("No ensemble hours to chart"));
+    }
+    run_static_view(|f| draw_ensemble_chart(f, ensemble, location))
+}
+
+/// Draw a view that only reacts to q and Esc, which close it
+fn run_static_view(draw: impl Fn(&mut ratatui::Frame<CrosstermBackend<Stdout>>)) -> Result<()> {
+    install_panic_hook();
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+
+    let result = (|| -> Result<()> {
+        loop {
+            terminal.draw(&draw)?;
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press
+                    && (is_interrupt(&key) || matches!(key.code, KeyCode::Char('q') | KeyCode::Esc))
+                {
+                    return Ok(());
+                }
+            }
+        }
+    })();
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+    result
+}
+
+/// Temperature and precipitation charts with the normals drawn as dotted lines
+fn draw_climate_chart<B: ratatui::backend::Backend>(
+    f: &mut ratatui::Frame<B>,
+    days: &[ClimateComparison],
+    location: &Location,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(60),
+            Constraint::Min(8),
+            Constraint::Length(1),
+        ])
+        .split(f.size());
+
+    let series = |value: fn(&ClimateComparison) -> f64| -> Vec<(f64, f64)> {
+        days.iter()
+            .enumerate()
+            .map(|(i, day)| (i as f64, value(day)))
+            .collect()
+    };
+    let temp_max = series(|d| d.temp_max);
+    let temp_min = series(|d| d.temp_min);
+    let normal_max = series(|d| d.normal_max);
+    let normal_min = series(|d| d.normal_min);
+    let precipitation = series(|d| d.precipitation);
+    let normal_precipitation = series(|d| d.normal_precipitation);
+
+    let last = (days.len() - 1).max(1) as f64;
+    let day_labels = || {
+        let label = |i: usize| Span::raw(days[i].date.format("%a %-d").to_string());
+        vec![label(0), label(days.len() / 2), label(days.len() - 1)]
+    };
+    let bounds = |points: &[&[(f64, f64)]], floor: Option<f64>| {
+        let (lo, hi) = points
+            .iter()
+            .flat_map(|series| series.iter().map(|(_, v)| *v))
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+                (lo.min(v), hi.max(v))
+            });
+        let lo = floor.unwrap_or((lo - 2.0).floor());
+        [lo, (hi + 2.0).ceil().max(lo + 1.0)]
+    };
+
+    let temp_bounds = bounds(&[&temp_max, &temp_min, &normal_max, &normal_min], None);
+    let line = |name: &str, data, color, marker| {
+        Dataset::default()
+            .name(name.to_string())
+            .marker(marker)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(color))
+            .data(data)
+    };
+    let temperature = Chart::new(vec![
+        line("High", &temp_max, Color::Red, symbols::Marker::Braille),
+        line("Low", &temp_min, Color::Blue, symbols::Marker::Braille),
+        line(
+            "Normal high",
+            &normal_max,
+            Color::LightRed,
+            symbols::Marker::Dot,
+        ),
+        line(
+            "Normal low",
+            &normal_min,
+            Color::LightBlue,
+            symbols::Marker::Dot,
+        ),
+    ])
+    .block(
+        Block::default()
+            .title(format!(
+                "{} - this week against the {}–{} normals",
+                location.name, NORMALS_PERIOD.0, NORMALS_PERIOD.1
+            ))
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Cyan)),
+    )
+    .x_axis(
+        Axis::default()
+            .style(Style::default().fg(Color::Gray))
+            .bounds([0.0, last])
+            .labels(day_labels()),
+    )
+    .y_axis(
+        Axis::default()
+            .style(Style::default().fg(Color::Gray))
+            .bounds(temp_bounds)
+            .labels(vec![
+                Span::raw(format!("{:.0}°C", temp_bounds[0])),
+                Span::raw(format!("{:.0}°C", temp_bounds[1])),
+            ]),
+    );
+    f.render_widget(temperature, chunks[0]);
+
+    let precip_bounds = bounds(&[&precipitation, &normal_precipitation], Some(0.0));
+    let rain = Chart::new(vec![
+        line(
+            "Precipitation",
+            &precipitation,
+            Color::Cyan,
+            symbols::Marker::Braille,
+        ),
+        line(
+            "Normal",
+            &normal_precipitation,
+            Color::Gray,
+            symbols::Marker::Dot,
+        ),
+    ])
+    .block(
+        Block::default()
+            .title("Daily precipitation (mm)")
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Cyan)),
+    )
+    .x_axis(
+        Axis::default()
+            .style(Style::default().fg(Color::Gray))
+            .bounds([0.0, last])
+            .labels(day_labels()),
+    )
+    .y_axis(
+        Axis::default()
+            .style(Style::default().fg(Color::Gray))
+            .bounds(precip_bounds)
+            .labels(vec![
+                Span::raw("0".to_string()),
+                Span::raw(format!("{:.0}", precip_bounds[1])),
+            ]),
+    );
+    f.render_widget(rain, chunks[1]);
+
+    f.render_widget(
+        Paragraph::new("Dotted lines are the monthly normals · q/Esc to quit")
+            .style(Style::default().fg(Color::DarkGray)),
+        chunks[2],
+    );
+}
+
+/// Show each model's temperature on one chart until q or Esc is pressed
+pub fn run_models_chart(comparison: &ModelComparison, location: &Location) -> Result<()> {
+    if comparison.times.is_empty() {
+        return Err(anyhow!("No model hours to chart"));
+    }
+    run_static_view(|f| draw_models_chart(f, comparison, location))
+}
+
+/// Overlaid temperature lines, one colour per model
+fn draw_models_chart<B: ratatui::backend::Backend>(
+    f: &mut ratatui::Frame<B>,
+    comparison: &ModelComparison,
+    location: &Location,
+) {
+    const COLORS: [Color; 3] = [Color::Yellow, Color::Cyan, Color::Magenta];
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(10), Constraint::Length(1)])
+        .split(f.size());
+
+    let series: Vec<Vec<(f64, f64)>> = comparison
+        .models
+        .iter()
+        .map(|model| {
+            model
+                .temperature
+                .iter()
+                .enumerate()
+                .filter_map(|(i, t)| Some((i as f64, (*t)?)))
+                .collect()
+        })
+        .collect();
+    let (lo, hi) = series
+        .iter()
+        .flatten()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), (_, t)| {
+            (lo.min(*t), hi.max(*t))
+        });
+    let (lo, hi) = ((lo - 1.0).floor(), (hi + 1.0).ceil());
+    let last = comparison.times.len().saturating_sub(1);
+    let hour_label = |index: usize| {
+        Span::raw(
+            convert_to_local(&comparison.times[index], location.clock_timezone())
+                .format("%a %H:%M")
+                .to_string(),
+        )
+    };
+
+    let datasets = comparison
+        .models
+        .iter()
+        .zip(&series)
+        .zip(COLORS.iter().cycle())
+        .map(|((model, points), color)| {
+            Dataset::default()
+                .name(model.model.clone())
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(*color))
+                .data(points)
+        })
+        .collect();
+    let chart = Chart::new(datasets)
+        .block(
+            Block::default()
+                .title(format!("{} - temperature by model", location.name))
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::Cyan)),
+        )
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, last.max(1) as f64])
+                .labels(vec![hour_label(0), hour_label(last / 2), hour_label(last)]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds([lo, hi])
+                .labels(vec![
+                    Span::raw(format!("{:.0}°C", lo)),
+                    Span::raw(format!("{:.0}°C", hi)),
+                ]),
+        );
+    f.render_widget(chart, chunks[0]);
+
+    f.render_widget(
+        Paragraph::new("Where the lines part, the models disagree · q/Esc to quit")
+            .style(Style::default().fg(Color::DarkGray)),
+        chunks[1],
+    );
+}
+
+/// The ensemble median over a shaded band from the lowest to the highest member
+fn draw_ensemble_chart<B: ratatui::backend::Backend>(
+    f: &mut ratatui::Frame<B>,
+    ensemble: &Ensemble,
+    location: &Location,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(10), Constraint::Length(1)])
+        .split(f.size());
+
+    let hours = &ensemble.hours;
+    let (lo, hi) = hours
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), h| {
+            (lo.min(h.min), hi.max(h.max))
+        });
+    let (lo, hi) = ((lo - 1.0).floor(), (hi + 1.0).ceil());
+    let last = (hours.len() - 1).max(1) as f64;
+    let labels: Vec<(f64, String)> = hours
+        .iter()
+        .enumerate()
+        .filter(|(_, h)| convert_to_local(&h.timestamp, &location.timezone).hour() == 12)
+        .map(|(i, h)| {
+            let local = convert_to_local(&h.timestamp, &location.timezone);
+            (i as f64, local.format("%a").to_string())
+        })
+        .collect();
+
+    let canvas = Canvas::default()
+        .block(
+            Block::default()
+                .title(format!(
+                    "{} - temperature spread of {} ensemble members ({:.0}°C to {:.0}°C)",
+                    location.name,
+                    ensemble.days.first().map_or(0, |d| d.members),
+                    lo,
+                    hi
+                ))
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::Cyan)),
+        )
+        .marker(symbols::Marker::Braille)
+        .x_bounds([0.0, last])
+        .y_bounds([lo, hi])
+        .paint(move |ctx| {
+            // Vertical strokes at quarter-hour steps shade the band
+            for (i, pair) in hours.windows(2).enumerate() {
+                let (a, b) = (&pair[0], &pair[1]);
+                for step in 0..4 {
+                    let t = step as f64 / 4.0;
+                    ctx.draw(&CanvasLine {
+                        x1: i as f64 + t,
+                        y1: a.min + (b.min - a.min) * t,
+                        x2: i as f64 + t,
+                        y2: a.max + (b.max - a.max) * t,
+                        color: Color::DarkGray,
+                    });
+                }
+            }
+            ctx.layer();
+            for (i, pair) in hours.windows(2).enumerate() {
+                ctx.draw(&CanvasLine {
+                    x1: i as f64,
+                    y1: pair[0].median,
+                    x2: (i + 1) as f64,
+                    y2: pair[1].median,
+                    color: Color::Yellow,
+                });
+            }
+            for (x, label) in &labels {
+                ctx.print(
+                    *x,
+                    lo,
+                    Span::styled(label.clone(), Style::default().fg(Color::Gray)),
+                );
+            }
+        });
+    f.render_widget(canvas, chunks[0]);
+
+    f.render_widget(
+        Paragraph::new("Yellow: median · grey band: lowest to highest member · q/Esc to quit")
+            .style(Style::default().fg(Color::DarkGray)),
+        chunks[1],
+    );
+}
+
+/// Leave raw mode and the alternate screen before a panic message is printed,
+/// so it is readable and the shell usable; installed once, on the first TUI
+pub fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            // Only while a TUI is up; every TUI runs in raw mode
+            if is_raw_mode_enabled().unwrap_or(false) {
+                let _ = disable_raw_mode();
+                let _ = execute!(
+                    io::stdout(),
+                    LeaveAlternateScreen,
+                    DisableMouseCapture,
+                    crossterm::cursor::Show
+                );
+            }
+            previous(info);
+        }));
+    });
+}
+
+impl<B: Backend> Drop for WeatherTui<B> {
     fn drop(&mut self) {
-        // Restore terminal on drop
+        if !self.owns_terminal {
+            return;
+        }
+
+        // Restore terminal on drop; only the crossterm backend owns it,
+        // and that writes to stdout
         let _ = disable_raw_mode();
-        let _ = execute!(
-            self.terminal.backend_mut(),
-            LeaveAlternateScreen,
-            DisableMouseCapture
-        );
+        let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture);
         let _ = self.terminal.show_cursor();
 
         // Print a newline to ensure the terminal is in a good state
@@ -1,5 +1,12 @@
+use crate::modules::comfort::FeelsLike;
+use crate::modules::config::ThresholdSettings;
+use crate::modules::icons::IconSet;
+use crate::modules::profile::ActivityProfile;
+use crate::modules::sections::Sections;
+use crate::modules::utils::DisplayUnits;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 use strum::EnumString;
 use strum_macros::Display;
@@ -10,9 +17,27 @@ pub struct WeatherConfig {
     pub units: String,
     pub location: Option<String>,
     pub json_output: bool,
+    /// Print JSON on a single line instead of indented
+    #[serde(default)]
+    pub json_compact: bool,
     pub animation_enabled: bool,
     pub detail_level: DetailLevel,
     pub no_charts: bool,
+    #[serde(default)]
+    pub profile: ActivityProfile,
+    #[serde(default)]
+    pub thresholds: ThresholdSettings,
+    #[serde(default)]
+    pub display_units: DisplayUnits,
+    #[serde(default)]
+    pub feels_like: FeelsLike,
+    #[serde(default)]
+    pub icons: IconSet,
+    #[serde(default)]
+    pub sections: Sections,
+    /// Zone to show times in instead of the location's (`--tz`)
+    #[serde(default)]
+    pub timezone: Option<String>,
 }
 
 impl Default for WeatherConfig {
@@ -21,9 +46,17 @@ impl Default for WeatherConfig {
             units: "metric".to_string(),
             location: None,
             json_output: false,
+            json_compact: false,
             animation_enabled: true,
             detail_level: DetailLevel::Standard,
             no_charts: false,
+            profile: ActivityProfile::default(),
+            thresholds: ThresholdSettings::default(),
+            display_units: DisplayUnits::default(),
+            feels_like: FeelsLike::default(),
+            icons: IconSet::default(),
+            sections: Sections::default(),
+            timezone: None,
         }
     }
 }
@@ -52,6 +85,8 @@ pub enum WeatherCondition {
     Drizzle,
     Thunderstorm,
     Snow,
+    Sleet,
+    Hail,
     Mist,
     Fog,
     Smoke,
@@ -79,6 +114,8 @@ impl WeatherCondition {
             "drizzle" => WeatherCondition::Drizzle,
             "thunderstorm" => WeatherCondition::Thunderstorm,
             "snow" => WeatherCondition::Snow,
+            "sleet" => WeatherCondition::Sleet,
+            "hail" => WeatherCondition::Hail,
             "mist" => WeatherCondition::Mist,
             "fog" => WeatherCondition::Fog,
             "smoke" => WeatherCondition::Smoke,
@@ -100,6 +137,8 @@ impl WeatherCondition {
             WeatherCondition::Drizzle => "🌦️",
             WeatherCondition::Thunderstorm => "⛈️",
             WeatherCondition::Snow => "❄️",
+            WeatherCondition::Sleet => "🌨️",
+            WeatherCondition::Hail => "🧊",
             WeatherCondition::Mist => "🌫️",
             WeatherCondition::Fog => "🌫️",
             WeatherCondition::Smoke => "🌫️",
@@ -123,6 +162,8 @@ impl fmt::Display for WeatherCondition {
             WeatherCondition::Drizzle => "Drizzle",
             WeatherCondition::Thunderstorm => "Thunderstorm",
             WeatherCondition::Snow => "Snowy",
+            WeatherCondition::Sleet => "Sleet",
+            WeatherCondition::Hail => "Hail",
             WeatherCondition::Mist => "Misty",
             WeatherCondition::Fog => "Foggy",
             WeatherCondition::Smoke => "Smoky",
@@ -138,6 +179,44 @@ impl fmt::Display for WeatherCondition {
     }
 }
 
+/// Strength of a weather condition, such as light drizzle or violent showers
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum Intensity {
+    Light,
+    #[default]
+    Moderate,
+    Heavy,
+    Violent,
+}
+
+impl Intensity {
+    /// Scale a particle count for this intensity, from half for light to
+    /// double for violent
+    pub fn scale(&self, count: usize) -> usize {
+        let factor = match self {
+            Intensity::Light => 0.5,
+            Intensity::Moderate => 1.0,
+            Intensity::Heavy => 1.5,
+            Intensity::Violent => 2.0,
+        };
+        ((count as f64 * factor).round() as usize).max(1)
+    }
+}
+
+impl fmt::Display for Intensity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Intensity::Light => "Light",
+            Intensity::Moderate => "Moderate",
+            Intensity::Heavy => "Heavy",
+            Intensity::Violent => "Violent",
+        };
+        write!(f, "{}", s)
+    }
+}
+
 /// Represents location information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Location {
@@ -149,6 +228,18 @@ pub struct Location {
     pub timezone: String,
     pub region: Option<String>,
     pub state: Option<String>,
+    /// Zone to show clock times in instead of `timezone` (`--tz`); days are
+    /// always counted in the place's own zone. Only set for one run, so it
+    /// is never saved with a cached forecast.
+    #[serde(skip)]
+    pub display_timezone: Option<String>,
+}
+
+impl Location {
+    /// Zone clock times are shown in
+    pub fn clock_timezone(&self) -> &str {
+        self.display_timezone.as_deref().unwrap_or(&self.timezone)
+    }
 }
 
 impl Default for Location {
@@ -162,24 +253,66 @@ impl Default for Location {
             timezone: "UTC".to_string(),
             region: None,
             state: None,
+            display_timezone: None,
         }
     }
 }
 
+/// Pressure in hectopascals
+///
+/// Displays in hPa; `PressureUnit` converts to the user's display unit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Hpa(pub f64);
+
+impl fmt::Display for Hpa {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.*} hPa", f.precision().unwrap_or(0), self.0)
+    }
+}
+
+/// Percentage from 0 to 100, such as humidity or cloud cover
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Percent(pub f64);
+
+impl Percent {
+    /// Fraction from 0 to 1
+    pub fn fraction(&self) -> f64 {
+        self.0 / 100.0
+    }
+}
+
+impl fmt::Display for Percent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.*}%", f.precision().unwrap_or(0), self.0)
+    }
+}
+
 /// Represents current weather data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CurrentWeather {
     pub timestamp: DateTime<Utc>,
     pub temperature: f64,
     pub feels_like: f64,
-    pub humidity: u8,
-    pub pressure: u32,
+    pub humidity: Percent,
+    pub pressure: Hpa,
     pub wind_speed: f64,
     pub wind_direction: u16,
+    #[serde(default)]
+    pub wind_gusts: Option<f64>,
     pub conditions: Vec<WeatherDescription>,
     pub main_condition: WeatherCondition,
+    #[serde(default)]
+    pub intensity: Intensity,
+    /// Provider weather code (WMO for Open-Meteo) behind `main_condition`
+    #[serde(default, skip_serializing)]
+    pub raw_code: Option<u32>,
+    /// Raw provider values, only kept at the debug detail level
+    #[serde(default, skip_serializing)]
+    pub provider_extra: Option<serde_json::Value>,
     pub visibility: u32,
-    pub clouds: u8,
+    pub clouds: Percent,
     pub uv_index: f64,
     pub sunrise: DateTime<Utc>,
     pub sunset: DateTime<Utc>,
@@ -188,6 +321,35 @@ pub struct CurrentWeather {
     pub air_quality_index: Option<u8>,
 }
 
+/// Calm, clear weather at the Unix epoch, for building conditions field by field
+impl Default for CurrentWeather {
+    fn default() -> Self {
+        Self {
+            timestamp: DateTime::<Utc>::default(),
+            temperature: 0.0,
+            feels_like: 0.0,
+            humidity: Percent(0.0),
+            pressure: Hpa(1013.0),
+            wind_speed: 0.0,
+            wind_direction: 0,
+            wind_gusts: None,
+            conditions: Vec::new(),
+            main_condition: WeatherCondition::Clear,
+            intensity: Intensity::default(),
+            raw_code: None,
+            provider_extra: None,
+            visibility: 10000,
+            clouds: Percent(0.0),
+            uv_index: 0.0,
+            sunrise: DateTime::<Utc>::default(),
+            sunset: DateTime::<Utc>::default(),
+            rain_last_hour: None,
+            snow_last_hour: None,
+            air_quality_index: None,
+        }
+    }
+}
+
 /// Represents detailed weather description
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WeatherDescription {
@@ -203,22 +365,80 @@ pub struct HourlyForecast {
     pub timestamp: DateTime<Utc>,
     pub temperature: f64,
     pub feels_like: f64,
-    pub humidity: u8,
-    pub pressure: u32,
+    pub humidity: Percent,
+    pub pressure: Hpa,
     pub wind_speed: f64,
     pub wind_direction: u16,
     pub conditions: Vec<WeatherDescription>,
     pub main_condition: WeatherCondition,
+    #[serde(default)]
+    pub intensity: Intensity,
+    /// Provider weather code (WMO for Open-Meteo) behind `main_condition`
+    #[serde(default, skip_serializing)]
+    pub raw_code: Option<u32>,
+    /// Raw provider values, only kept at the debug detail level
+    #[serde(default, skip_serializing)]
+    pub provider_extra: Option<serde_json::Value>,
+    /// Variables requested with `--vars`, by Open-Meteo name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: BTreeMap<String, f64>,
     pub pop: f64, // Probability of precipitation
     pub visibility: u32,
-    pub clouds: u8,
+    pub clouds: Percent,
     pub rain: Option<f64>,
     pub snow: Option<f64>,
+    #[serde(default)]
+    pub uv_index: Option<f64>,
+    /// Global horizontal irradiance (W/m²), mean over the preceding hour
+    #[serde(default)]
+    pub shortwave_radiation: Option<f64>,
+    /// Direct part of `shortwave_radiation` on a horizontal plane (W/m²)
+    #[serde(default)]
+    pub direct_radiation: Option<f64>,
+    /// Height of the 0 °C level above sea level (m)
+    #[serde(default)]
+    pub freezing_level: Option<f64>,
+    /// Snow on the ground (cm)
+    #[serde(default)]
+    pub snow_depth: Option<f64>,
+}
+
+/// A calm, clear hour at the Unix epoch, for building forecasts field by field
+impl Default for HourlyForecast {
+    fn default() -> Self {
+        Self {
+            timestamp: DateTime::<Utc>::default(),
+            temperature: 0.0,
+            feels_like: 0.0,
+            humidity: Percent(0.0),
+            pressure: Hpa(1013.0),
+            wind_speed: 0.0,
+            wind_direction: 0,
+            conditions: Vec::new(),
+            main_condition: WeatherCondition::Clear,
+            intensity: Intensity::default(),
+            raw_code: None,
+            provider_extra: None,
+            extra: BTreeMap::new(),
+            pop: 0.0,
+            visibility: 10000,
+            clouds: Percent(0.0),
+            rain: None,
+            snow: None,
+            uv_index: None,
+            shortwave_radiation: None,
+            direct_radiation: None,
+            freezing_level: None,
+            snow_depth: None,
+        }
+    }
 }
 
 /// Represents daily forecast data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DailyForecast {
+    /// The calendar day at 12:00 UTC; use `date_naive()`, not `convert_to_local`,
+    /// which moves it to the next day at offsets above +12
     pub date: DateTime<Utc>,
     pub sunrise: DateTime<Utc>,
     pub sunset: DateTime<Utc>,
@@ -230,19 +450,68 @@ pub struct DailyForecast {
     pub temp_max: f64,
     pub feels_like_day: f64,
     pub feels_like_night: f64,
-    pub pressure: u32,
-    pub humidity: u8,
+    pub pressure: Hpa,
+    pub humidity: Percent,
     pub wind_speed: f64,
     pub wind_direction: u16,
+    /// Strongest gust of the day
+    #[serde(default)]
+    pub wind_gusts: Option<f64>,
     pub conditions: Vec<WeatherDescription>,
     pub main_condition: WeatherCondition,
-    pub clouds: u8,
+    #[serde(default)]
+    pub intensity: Intensity,
+    /// Provider weather code (WMO for Open-Meteo) behind `main_condition`
+    #[serde(default, skip_serializing)]
+    pub raw_code: Option<u32>,
+    /// Raw provider values, only kept at the debug detail level
+    #[serde(default, skip_serializing)]
+    pub provider_extra: Option<serde_json::Value>,
+    pub clouds: Percent,
     pub pop: f64,
     pub rain: Option<f64>,
     pub snow: Option<f64>,
+    /// Total precipitation (rain, showers and snow water equivalent) in mm
+    #[serde(default)]
+    pub precipitation: Option<f64>,
     pub uv_index: f64,
 }
 
+/// A calm, clear, dry day at the Unix epoch, for building forecasts field by field
+impl Default for DailyForecast {
+    fn default() -> Self {
+        Self {
+            date: DateTime::<Utc>::default(),
+            sunrise: DateTime::<Utc>::default(),
+            sunset: DateTime::<Utc>::default(),
+            temp_morning: 0.0,
+            temp_day: 0.0,
+            temp_evening: 0.0,
+            temp_night: 0.0,
+            temp_min: 0.0,
+            temp_max: 0.0,
+            feels_like_day: 0.0,
+            feels_like_night: 0.0,
+            pressure: Hpa(1013.0),
+            humidity: Percent(0.0),
+            wind_speed: 0.0,
+            wind_direction: 0,
+            wind_gusts: None,
+            conditions: Vec::new(),
+            main_condition: WeatherCondition::Clear,
+            intensity: Intensity::default(),
+            raw_code: None,
+            provider_extra: None,
+            clouds: Percent(0.0),
+            pop: 0.0,
+            rain: None,
+            snow: None,
+            precipitation: None,
+            uv_index: 0.0,
+        }
+    }
+}
+
 /// Represents a complete weather forecast
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Forecast {
@@ -251,9 +520,13 @@ pub struct Forecast {
     pub daily: Vec<DailyForecast>,
     pub timezone_offset: i32,
     pub units: String,
+    /// When the data was downloaded; older than now when served from the cache
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fetched_at: Option<DateTime<Utc>>,
 }
 
 /// Represents air quality data
+#[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AirQuality {
     pub aqi: u8,    // 1-5 scale (1: Good, 2: Fair, 3: Moderate, 4: Poor, 5: Very Poor)
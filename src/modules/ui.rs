@@ -1,5 +1,8 @@
 use anyhow::Result;
-use chrono::{DateTime, Datelike, Timelike, Utc, Weekday};
+use chrono::{
+    DateTime, Datelike, NaiveDate, NaiveDateTime, Offset, TimeZone, Timelike, Utc, Weekday,
+};
+use chrono_tz::Tz;
 use colored::*;
 use console::Term;
 use dialoguer::{theme::ColorfulTheme, Input, Select};
@@ -7,17 +10,47 @@ use dialoguer::{theme::ColorfulTheme, Input, Select};
 use std::thread::sleep;
 use std::time::Duration as StdDuration;
 
+use crate::modules::accumulation::{accumulation_text, Accumulation};
+use crate::modules::activity::{BestWindow, HourScore};
+use crate::modules::agriculture::{moisture_description, AgricultureReport};
+use crate::modules::aviation::{AviationReport, Side};
+use crate::modules::climate::{anomaly_text, ClimateComparison, DayNormals, NORMALS_PERIOD};
+use crate::modules::compare::ProviderComparison;
+use crate::modules::energy::EnergyReport;
+use crate::modules::ensemble::{whisker, Ensemble};
+use crate::modules::events::EventOutlook;
+use crate::modules::fire::{FireLevel, FireReport, DROUGHT_DAYS};
+use crate::modules::freshness::{Freshness, Staleness};
+use crate::modules::hazards::Hazard;
+use crate::modules::icons::IconSet;
+use crate::modules::laundry::LaundryReport;
+use crate::modules::models::{ModelComparison, DISAGREEMENT};
+use crate::modules::profile::profile_recommendations;
+use crate::modules::radar::{RadarFrame, RadarImage};
+use crate::modules::risk::{assess_risk, DayRisk, RiskLevel};
+use crate::modules::roads::{RoadBlock, RoadVerdict};
+use crate::modules::safety::safety_warnings;
+use crate::modules::sections::{Section, Sections};
+use crate::modules::sun::SunTimes;
+use crate::modules::table::{Align, Table};
+use crate::modules::template::TemplateReport;
 use crate::modules::types::{
-    CurrentWeather, DailyForecast, Forecast, HourlyForecast, Location, WeatherCondition,
-    WeatherConfig,
+    CurrentWeather, DailyForecast, Forecast, HourlyForecast, Intensity, Location, WeatherAlert,
+    WeatherCondition, WeatherConfig,
 };
-// use crate::modules::utils::*;
+use crate::modules::utils::{beaufort, beaufort_description, wind_barb, DisplayUnits, TempUnit};
+use crate::modules::uv::{UvLevel, UvPlan, UvWindow};
+use crate::modules::widget::Widget;
+use crate::modules::winter::{WinterReport, WINTER_HOURS};
 
 /// Handles UI rendering and animations
 #[derive(Clone)]
 pub struct WeatherUI {
     animation_enabled: bool,
     json_output: bool,
+    units: DisplayUnits,
+    icons: IconSet,
+    sections: Sections,
     term: Term,
 }
 
@@ -27,10 +60,39 @@ impl WeatherUI {
         Self {
             animation_enabled,
             json_output,
+            units: DisplayUnits::default(),
+            icons: IconSet::default(),
+            sections: Sections::default(),
             term: Term::stdout(),
         }
     }
 
+    /// Display wind, pressure and precipitation in the given units
+    pub fn with_units(mut self, units: DisplayUnits) -> Self {
+        self.units = units;
+        self
+    }
+
+    /// Show weather conditions with the given icon set
+    pub fn with_icons(mut self, icons: IconSet) -> Self {
+        self.icons = icons;
+        self
+    }
+
+    /// Leave out the sections the user hid
+    pub fn with_sections(mut self, sections: Sections) -> Self {
+        self.sections = sections;
+        self
+    }
+
+    /// Icon for a condition, padded to the two cells an emoji takes
+    fn icon(&self, condition: WeatherCondition) -> String {
+        match self.icons {
+            IconSet::Emoji => condition.get_emoji().to_string(),
+            icons => format!("{} ", icons.condition(condition)),
+        }
+    }
+
     /// Show welcome banner
     pub fn show_welcome_banner(&self) -> Result<()> {
         if self.json_output {
@@ -55,16 +117,6 @@ impl WeatherUI {
         Ok(())
     }
 
-    /// Show animation when connecting to weather services
-    /// Show connecting message
-    pub fn show_connecting_animation(&self) -> Result<()> {
-        if !self.json_output {
-            println!("Fetching weather data...");
-            println!();
-        }
-        Ok(())
-    }
-
     /// Display current weather information
     pub fn show_current_weather(
         &self,
@@ -90,10 +142,10 @@ impl WeatherUI {
         }
 
         // Format local time based on location's timezone
-        let local_time = format_local_time(&weather.timestamp, &location.timezone);
+        let local_time = format_local_time(&weather.timestamp, location.clock_timezone());
 
         // Get the main weather information
-        let emoji = weather.main_condition.get_emoji();
+        let emoji = self.icon(weather.main_condition);
         let conditions = if let Some(desc) = weather.conditions.first() {
             desc.description.to_title_case()
         } else {
@@ -101,11 +153,7 @@ impl WeatherUI {
         };
 
         // Format temperatures based on units
-        let temp_unit = if self.config().units == "imperial" {
-            "°F"
-        } else {
-            "°C"
-        };
+        let temp_unit = self.units.temperature;
 
         // Location and time
         println!(
@@ -118,7 +166,7 @@ impl WeatherUI {
             "🕓 {}: {} ({})",
             "Local Time".bold(),
             local_time,
-            location.timezone
+            location.clock_timezone()
         );
         println!();
 
@@ -128,10 +176,10 @@ impl WeatherUI {
         println!(
             "🌡️ {}: {:.1}{} (Feels like: {:.1}{})",
             "Temperature".bold(),
-            weather.temperature,
-            temp_unit,
-            weather.feels_like,
-            temp_unit
+            temp_unit.convert(weather.temperature),
+            temp_unit.label(),
+            temp_unit.convert(weather.feels_like),
+            temp_unit.label()
         );
 
         if self.animation_enabled {
@@ -139,31 +187,32 @@ impl WeatherUI {
         }
 
         // Wind info
-        let wind_unit = if self.config().units == "imperial" {
-            "mph"
-        } else {
-            "m/s"
-        };
         let wind_direction = get_wind_direction_arrow(weather.wind_direction);
+        let force = beaufort(weather.wind_speed);
         println!(
-            "💨 {}: {:.1} {} {}",
+            "💨 {}: {} {} (Bft {}, {})",
             "Wind".bold(),
-            weather.wind_speed,
-            wind_unit,
-            wind_direction
+            self.units.wind.format(weather.wind_speed),
+            wind_direction,
+            force,
+            beaufort_description(force)
         );
 
         // Humidity and pressure
-        println!("💧 {}: {}%", "Humidity".bold(), weather.humidity);
-        println!("🔄 {}: {} hPa", "Pressure".bold(), weather.pressure);
+        println!("💧 {}: {}", "Humidity".bold(), weather.humidity);
+        println!(
+            "🔄 {}: {}",
+            "Pressure".bold(),
+            self.units.pressure.format(weather.pressure.0)
+        );
 
         if self.animation_enabled {
             sleep(StdDuration::from_millis(300));
         }
 
         // Sunrise and sunset
-        let sunrise = format_local_time(&weather.sunrise, &location.timezone);
-        let sunset = format_local_time(&weather.sunset, &location.timezone);
+        let sunrise = format_local_time(&weather.sunrise, location.clock_timezone());
+        let sunset = format_local_time(&weather.sunset, location.clock_timezone());
         println!("🌅 {}: {}", "Sunrise".bold(), sunrise);
         println!("🌇 {}: {}", "Sunset".bold(), sunset);
 
@@ -179,11 +228,19 @@ impl WeatherUI {
 
         // Precipitation if available
         if let Some(rain) = weather.rain_last_hour {
-            println!("🌧️ {}: {:.1} mm (last hour)", "Rain".bold(), rain);
+            println!(
+                "🌧️ {}: {} (last hour)",
+                "Rain".bold(),
+                self.units.precip.format(rain)
+            );
         }
 
         if let Some(snow) = weather.snow_last_hour {
-            println!("❄️ {}: {:.1} mm (last hour)", "Snow".bold(), snow);
+            println!(
+                "❄️ {}: {} (last hour)",
+                "Snow".bold(),
+                self.units.precip.format(snow)
+            );
         }
 
         println!();
@@ -218,32 +275,45 @@ impl WeatherUI {
 
         // Limit to next 24 hours for display
         let hours_to_show = std::cmp::min(forecast.len(), 24);
-        let temp_unit = if self.config().units == "imperial" {
-            "°F"
-        } else {
-            "°C"
-        };
+        let temp_unit = self.units.temperature;
 
         // Get current hour for highlighting
         let now = Utc::now();
         let current_hour = now.hour();
 
         // Print table header
-        println!("┌────────┬───────────┬────────┬─────────┬────────┬─────────┐");
-        println!("│  Hour  │  Weather  │  Temp  │  Precip │  Wind  │ Humidity│");
-        println!("├────────┼───────────┼────────┼─────────┼────────┼─────────┤");
+        let table = Table::new(&[
+            (5, Align::Center),
+            (13, Align::Left),
+            (7, Align::Right),
+            (6, Align::Right),
+            (11, Align::Left),
+            (8, Align::Right),
+        ]);
+        println!("{}", table.top());
+        println!(
+            "{}",
+            table.row(&[
+                "Hour".to_string(),
+                "Weather".to_string(),
+                "Temp".to_string(),
+                "Precip".to_string(),
+                format!("Wind {}", self.units.wind.label()),
+                "Humidity".to_string(),
+            ])
+        );
+        println!("{}", table.separator());
 
         for (i, hour) in forecast.iter().take(hours_to_show).enumerate() {
             // Convert to local time
             let hour_dt = convert_to_local(&hour.timestamp, &location.timezone);
             let hour_num = hour_dt.hour();
-            let local_time = format_hour_only(&hour.timestamp, &location.timezone);
-            let emoji = hour.main_condition.get_emoji();
+            let local_time = format_hour_only(&hour.timestamp, location.clock_timezone());
+            let emoji = self.icon(hour.main_condition);
 
-            // Format conditions description
+            // Format conditions description; the table truncates it
             let conditions = if let Some(desc) = hour.conditions.first() {
-                desc.description.to_title_case()[..std::cmp::min(8, desc.description.len())]
-                    .to_string()
+                desc.description.to_title_case()
             } else {
                 hour.main_condition.to_string()
             };
@@ -257,39 +327,33 @@ impl WeatherUI {
 
             // Wind information
             let wind_info = if hour.wind_speed > 0.0 {
-                let wind_dir = get_wind_direction_arrow(hour.wind_direction);
-                format!("{:.1} {}", hour.wind_speed, wind_dir)
+                format!(
+                    "{:.1} {}",
+                    self.units.wind.convert(hour.wind_speed),
+                    wind_barb(hour.wind_speed, hour.wind_direction)
+                )
             } else {
                 "Calm".to_string()
             };
 
+            let row = table.row(&[
+                local_time,
+                format!("{} {}", emoji, conditions),
+                format!(
+                    "{:.1}{}",
+                    temp_unit.convert(hour.temperature),
+                    temp_unit.label()
+                ),
+                precip,
+                wind_info,
+                hour.humidity.to_string(),
+            ]);
+
             // Highlight current hour
             let line = if hour_num == current_hour {
-                format!(
-                    "│{:^8}│ {:<2} {:<7} │ {:.1}{:<3} │ {:<7} │ {:<6} │ {:<7} │",
-                    local_time.bold(),
-                    emoji,
-                    conditions,
-                    hour.temperature,
-                    temp_unit,
-                    precip,
-                    wind_info,
-                    format!("{}%", hour.humidity)
-                )
-                .bright_yellow()
+                row.bright_yellow().bold()
             } else {
-                format!(
-                    "│{:^8}│ {:<2} {:<7} │ {:.1}{:<3} │ {:<7} │ {:<6} │ {:<7} │",
-                    local_time,
-                    emoji,
-                    conditions,
-                    hour.temperature,
-                    temp_unit,
-                    precip,
-                    wind_info,
-                    format!("{}%", hour.humidity)
-                )
-                .normal()
+                row.normal()
             };
 
             println!("{}", line);
@@ -299,17 +363,29 @@ impl WeatherUI {
             }
         }
 
-        println!("└────────┴───────────┴────────┴─────────┴────────┴─────────┘");
+        println!("{}", table.bottom());
         println!();
         Ok(())
     }
 
-    /// Display daily forecast
+    /// Display daily forecast, led by any multi-day frost or heatwave warnings
     pub fn show_daily_forecast(
         &self,
         forecast: &[DailyForecast],
         location: &Location,
+        warnings: &[WeatherAlert],
     ) -> Result<()> {
+        let parts = [
+            Section::Alerts,
+            Section::Daily,
+            Section::Trends,
+            Section::Activities,
+            Section::Details,
+        ];
+        if !parts.iter().any(|part| self.sections.shows(*part)) {
+            return Ok(());
+        }
+
         println!(
             "{}",
             "╔═══════════════════════════════════════════════════╗".bright_cyan()
@@ -324,17 +400,60 @@ impl WeatherUI {
         );
         println!();
 
+        let warnings = if self.sections.shows(Section::Alerts) {
+            warnings
+        } else {
+            &[]
+        };
+        for warning in warnings {
+            let icon = if warning.tags.iter().any(|t| t == "frost") {
+                "❄️"
+            } else {
+                "🔥"
+            };
+            let line = format!(
+                "{} {} {}–{}: {}",
+                icon,
+                warning.event.to_uppercase(),
+                convert_to_local(&warning.start, &location.timezone).format("%a %d %b"),
+                convert_to_local(
+                    &(warning.end - chrono::Duration::days(1)),
+                    &location.timezone
+                )
+                .format("%a %d %b"),
+                warning.description
+            );
+            println!("{}", line.bright_red().bold());
+        }
+        if !warnings.is_empty() {
+            println!();
+        }
+
         if forecast.is_empty() {
             println!("No daily forecast data available.");
             return Ok(());
         }
 
-        let temp_unit = if self.config().units == "imperial" {
-            "°F"
-        } else {
-            "°C"
-        };
+        let temp_unit = self.units.temperature;
+
+        if self.sections.shows(Section::Daily) {
+            self.show_day_boxes(forecast, temp_unit);
+        }
+        if self.sections.shows(Section::Trends) {
+            self.show_temperature_outlook(forecast, temp_unit);
+        }
+        if self.sections.shows(Section::Activities) {
+            self.show_activities(forecast);
+        }
+        if self.sections.shows(Section::Details) {
+            self.show_detailed_forecast(forecast, location, temp_unit);
+        }
+        println!();
+        Ok(())
+    }
 
+    /// Day-by-day boxes with the temperatures, precipitation and humidity
+    fn show_day_boxes(&self, forecast: &[DailyForecast], temp_unit: TempUnit) {
         // Next Days Forecast - Enhanced visualization
         println!("{}", "📊 NEXT DAYS AT A GLANCE".bold().bright_cyan());
         println!();
@@ -350,12 +469,12 @@ impl WeatherUI {
                 format_weekday(&day.date)
             };
 
-            let emoji = day.main_condition.get_emoji();
-            let date_str = format_date_short(&day.date, &location.timezone);
+            let emoji = self.icon(day.main_condition);
+            let date_str = format_date_short(&day.date);
 
             // Format temperatures
-            let temp_high = format!("{:.0}{}", day.temp_max, temp_unit);
-            let temp_low = format!("{:.0}{}", day.temp_min, temp_unit);
+            let temp_high = temp_unit.format(day.temp_max);
+            let temp_low = temp_unit.format(day.temp_min);
 
             // Precipitation percentage
             let precip = if day.pop > 0.0 {
@@ -365,13 +484,17 @@ impl WeatherUI {
             };
 
             // Format humidity
-            let humidity = format!("{}%", day.humidity);
+            let humidity = day.humidity.to_string();
 
             // Print box header
-            println!("┌─────────────────────────────────────────────────┐");
+            let card = Table::new(&[(47, Align::Left)]);
+            println!("{}", card.top());
 
             // Print forecast with color highlighting based on conditions
-            println!("│ {} {} {:<36}│", day_name.bold(), emoji, date_str);
+            println!(
+                "{}",
+                card.row(&[format!("{} {} {}", day_name.bold(), emoji, date_str)])
+            );
 
             // Get weather description
             let weather_desc = if let Some(desc) = day.conditions.first() {
@@ -380,33 +503,31 @@ impl WeatherUI {
                 day.main_condition.to_string()
             };
 
-            // Print details in a clean format
-            match day.main_condition {
+            // Print details, highlighting rain and sunshine
+            let (temp_high, precip) = match day.main_condition {
                 WeatherCondition::Rain
                 | WeatherCondition::Drizzle
-                | WeatherCondition::Thunderstorm => {
-                    println!("│  Weather: {:<40}│", weather_desc);
-                    println!("│  Temp: {} / {:<36}│", temp_high, temp_low);
-                    println!("│  Precipitation: {:<31}│", precip.bright_blue());
-                    println!("│  Humidity: {:<36}│", humidity);
-                }
-                WeatherCondition::Clear => {
-                    println!("│  Weather: {:<40}│", weather_desc);
-                    println!("│  Temp: {} / {:<36}│", temp_high.bright_yellow(), temp_low);
-                    println!("│  Precipitation: {:<31}│", precip);
-                    println!("│  Humidity: {:<36}│", humidity);
-                }
-                _ => {
-                    println!("│  Weather: {:<40}│", weather_desc);
-                    println!("│  Temp: {} / {:<36}│", temp_high, temp_low);
-                    println!("│  Precipitation: {:<31}│", precip);
-                    println!("│  Humidity: {:<36}│", humidity);
-                }
+                | WeatherCondition::Sleet
+                | WeatherCondition::Thunderstorm
+                | WeatherCondition::Hail => (temp_high.normal(), precip.bright_blue()),
+                WeatherCondition::Clear => (temp_high.bright_yellow(), precip.normal()),
+                _ => (temp_high.normal(), precip.normal()),
+            };
+            for line in [
+                format!(" Weather: {}", weather_desc),
+                format!(" Temp: {} / {}", temp_high, temp_low),
+                format!(" Precipitation: {}", precip),
+                format!(" Humidity: {}", humidity),
+            ] {
+                println!("{}", card.row(&[line]));
             }
-            println!("└─────────────────────────────────────────────────┘");
+            println!("{}", card.bottom());
         }
         println!();
+    }
 
+    /// Temperature outlook for the week
+    fn show_temperature_outlook(&self, forecast: &[DailyForecast], temp_unit: TempUnit) {
         // Add temperature summary and activity forecast
         println!(
             "{}",
@@ -440,12 +561,18 @@ impl WeatherUI {
             };
 
             println!(
-                "  • {:<12} {:<9} {:.0}{} / {:.0}{}",
-                label, temp_indicator, day.temp_max, temp_unit, day.temp_min, temp_unit
+                "  • {:<12} {:<9} {} / {}",
+                label,
+                temp_indicator,
+                temp_unit.format(day.temp_max),
+                temp_unit.format(day.temp_min)
             );
         }
         println!();
+    }
 
+    /// Activity suggestions for the next three days
+    fn show_activities(&self, forecast: &[DailyForecast]) {
         // Add activity recommendations in a simpler format
         println!(
             "{}",
@@ -463,13 +590,17 @@ impl WeatherUI {
                 format_weekday(&day.date).to_uppercase()
             };
 
-            println!("  {} ({})", day_name.bold(), day.main_condition.get_emoji());
+            println!("  {} ({})", day_name.bold(), self.icon(day.main_condition));
 
             // Best activities based on weather
             let temp_avg = (day.temp_max + day.temp_min) / 2.0;
             let is_rainy = matches!(
                 day.main_condition,
-                WeatherCondition::Rain | WeatherCondition::Drizzle | WeatherCondition::Thunderstorm
+                WeatherCondition::Rain
+                    | WeatherCondition::Drizzle
+                    | WeatherCondition::Sleet
+                    | WeatherCondition::Thunderstorm
+                    | WeatherCondition::Hail
             );
             let is_clear = matches!(day.main_condition, WeatherCondition::Clear);
 
@@ -492,7 +623,15 @@ impl WeatherUI {
 
             println!();
         }
+    }
 
+    /// Detailed forecast for the next five days
+    fn show_detailed_forecast(
+        &self,
+        forecast: &[DailyForecast],
+        location: &Location,
+        temp_unit: TempUnit,
+    ) {
         // Show detailed view for today and tomorrow
         println!("{}", "🔍 DETAILED FORECAST:".bold().bright_cyan());
         println!();
@@ -508,33 +647,29 @@ impl WeatherUI {
                 format_weekday(&day.date)
             };
 
-            let emoji = day.main_condition.get_emoji();
-            let date_str = format_date_short(&day.date, &location.timezone);
+            let emoji = self.icon(day.main_condition);
+            let date_str = format_date_short(&day.date);
 
             // Create a header box for each day
-            println!("┌───────────────────────────────────────────────────┐");
+            let header = Table::new(&[(15, Align::Left), (31, Align::Left)]);
+            println!("{}", header.top());
             println!(
-                "│ {:<15} {} {:<26}│",
-                day_name.bold().bright_cyan(),
-                emoji,
-                date_str
+                "{}",
+                header.row(&[
+                    day_name.bold().bright_cyan().to_string(),
+                    format!("{} {}", emoji, date_str),
+                ])
             );
-            println!("└───────────────────────────────────────────────────┘");
+            println!("{}", header.bottom());
 
             // Temperature range with visualization
             println!(
-                "   🌡️ {}/{}: {:.0}{} / {:.0}{} {}",
+                "   🌡️ {}/{}: {} / {} {}",
                 "High".bold(),
                 "Low".bold(),
-                day.temp_max,
-                temp_unit,
-                day.temp_min,
-                temp_unit,
-                get_temp_range_bar(
-                    day.temp_min,
-                    day.temp_max,
-                    self.config().units == "imperial"
-                )
+                temp_unit.format(day.temp_max),
+                temp_unit.format(day.temp_min),
+                get_temp_range_bar(day.temp_min, day.temp_max)
             );
 
             // Weather description
@@ -551,10 +686,12 @@ impl WeatherUI {
             );
 
             // Sunrise and sunset
-            let sunrise = format_local_time(&day.sunrise, &location.timezone);
-            let sunset = format_local_time(&day.sunset, &location.timezone);
-            println!("   🌅 {}: {}", "Sunrise".bold(), sunrise);
-            println!("   🌇 {}: {}", "Sunset".bold(), sunset);
+            if self.sections.shows(Section::Astro) {
+                let sunrise = format_local_time(&day.sunrise, location.clock_timezone());
+                let sunset = format_local_time(&day.sunset, location.clock_timezone());
+                println!("   🌅 {}: {}", "Sunrise".bold(), sunrise);
+                println!("   🌇 {}: {}", "Sunset".bold(), sunset);
+            }
 
             // Precipitation
             if day.pop > 0.0 {
@@ -575,22 +712,16 @@ impl WeatherUI {
             }
 
             // Wind info
-            let wind_unit = if self.config().units == "imperial" {
-                "mph"
-            } else {
-                "m/s"
-            };
             let wind_direction = get_wind_direction_arrow(day.wind_direction);
             println!(
-                "   💨 {}: {:.1} {} {}",
+                "   💨 {}: {} {}",
                 "Wind".bold(),
-                day.wind_speed,
-                wind_unit,
+                self.units.wind.format(day.wind_speed),
                 wind_direction
             );
 
             // Humidity info
-            println!("   💧 {}: {}%", "Humidity".bold(), day.humidity);
+            println!("   💧 {}: {}", "Humidity".bold(), day.humidity);
 
             // UV index
             let uv_display = match day.uv_index as u32 {
@@ -602,163 +733,313 @@ impl WeatherUI {
             };
             println!("   ☀️ {}: {}", "UV Index".bold(), uv_display);
 
-            // Daily recommendations based on conditions
-            let temp_avg = (day.temp_max + day.temp_min) / 2.0;
+            if self.sections.shows(Section::Activities) {
+                self.show_day_outlook(day);
+            }
 
-            // Activity recommendations based on weather and temperature
-            println!("   🔮 {}: ", "Outlook".bold());
-
-            match day.main_condition {
-                WeatherCondition::Rain | WeatherCondition::Drizzle => {
-                    if day.pop > 0.7 {
-                        println!(
-                            "      ☔ {}",
-                            "Heavy rain expected. Plan for indoor activities.".bright_blue()
-                        );
-                        println!(
-                            "      🏠 {}",
-                            "Recommended: Movies, museums, shopping, or home cooking."
-                                .bright_blue()
-                        );
-                    } else {
-                        println!(
-                            "      ☔ {}",
-                            "Light rain expected. Bring an umbrella if going out.".bright_blue()
-                        );
-                        println!(
-                            "      🏠 {}",
-                            "Recommended: Quick errands, covered venues, or indoor sports."
-                                .bright_blue()
-                        );
-                    }
-                }
-                WeatherCondition::Thunderstorm => {
-                    println!(
-                        "      ⛈️ {}",
-                        "Thunderstorms expected. Stay safe indoors.".bright_red()
-                    );
+            println!();
+
+            if self.animation_enabled {
+                sleep(StdDuration::from_millis(300));
+            }
+        }
+    }
+
+    /// Activity and sun protection outlook for one day
+    fn show_day_outlook(&self, day: &DailyForecast) {
+        // Daily recommendations based on conditions
+        let temp_avg = (day.temp_max + day.temp_min) / 2.0;
+
+        // Activity recommendations based on weather and temperature
+        println!("   🔮 {}: ", "Outlook".bold());
+
+        match day.main_condition {
+            WeatherCondition::Rain | WeatherCondition::Drizzle => {
+                if day.pop > 0.7 {
                     println!(
-                        "      ⚠️ {}",
-                        "Not recommended: Any outdoor activities or travel if avoidable."
-                            .bright_red()
+                        "      ☔ {}",
+                        "Heavy rain expected. Plan for indoor activities.".bright_blue()
                     );
                     println!(
                         "      🏠 {}",
-                        "Recommended: Home activities, reading, cooking, or gaming.".bright_red()
+                        "Recommended: Movies, museums, shopping, or home cooking.".bright_blue()
                     );
-                }
-                WeatherCondition::Snow => {
+                } else {
                     println!(
-                        "      ❄️ {}",
-                        "Snowy conditions. Prepare for potential travel disruptions.".bright_blue()
+                        "      ☔ {}",
+                        "Light rain expected. Bring an umbrella if going out.".bright_blue()
                     );
                     println!(
-                        "      ⚠️ {}",
-                        "Not recommended: Long trips or driving if inexperienced on snow."
+                        "      🏠 {}",
+                        "Recommended: Quick errands, covered venues, or indoor sports."
                             .bright_blue()
                     );
+                }
+            }
+            WeatherCondition::Thunderstorm | WeatherCondition::Hail => {
+                println!(
+                    "      ⛈️ {}",
+                    "Thunderstorms expected. Stay safe indoors.".bright_red()
+                );
+                println!(
+                    "      ⚠️ {}",
+                    "Not recommended: Any outdoor activities or travel if avoidable.".bright_red()
+                );
+                println!(
+                    "      🏠 {}",
+                    "Recommended: Home activities, reading, cooking, or gaming.".bright_red()
+                );
+            }
+            WeatherCondition::Snow => {
+                println!(
+                    "      ❄️ {}",
+                    "Snowy conditions. Prepare for potential travel disruptions.".bright_blue()
+                );
+                println!(
+                    "      ⚠️ {}",
+                    "Not recommended: Long trips or driving if inexperienced on snow."
+                        .bright_blue()
+                );
+                println!(
+                    "      🏂 {}",
+                    "Recommended: Snow sports if conditions permit, or cozy indoor activities."
+                        .bright_blue()
+                );
+            }
+            WeatherCondition::Clear => {
+                if temp_avg > 25.0 {
                     println!(
-                        "      🏂 {}",
-                        "Recommended: Snow sports if conditions permit, or cozy indoor activities."
-                            .bright_blue()
+                        "      ☀️ {}",
+                        "Clear and warm! Perfect for outdoor activities.".green()
                     );
-                }
-                WeatherCondition::Clear => {
-                    if temp_avg > 25.0 {
-                        println!(
-                            "      ☀️ {}",
-                            "Clear and warm! Perfect for outdoor activities.".green()
-                        );
-                        println!(
-                            "      🏊 {}",
-                            "Recommended: Swimming, beach visits, park outings, or outdoor dining."
-                                .green()
-                        );
-                    } else if temp_avg < 10.0 {
-                        println!(
-                            "      ☀️ {}",
-                            "Clear but cool. Good for active outdoor activities.".green()
-                        );
-                        println!("      🏃 {}", "Recommended: Hiking, running, cycling, or sightseeing with warm clothing.".green());
-                    } else {
-                        println!(
-                            "      ☀️ {}",
-                            "Perfect weather conditions. Ideal for almost any outdoor activity."
-                                .green()
-                        );
-                        println!("      🌳 {}", "Recommended: Parks, hiking, cycling, outdoor sports, or dining al fresco.".green());
-                    }
-                }
-                WeatherCondition::Clouds => {
                     println!(
-                        "      ☁️ {}",
-                        "Cloudy but pleasant. Good for outdoor activities without direct sun."
-                            .bright_blue()
+                        "      🏊 {}",
+                        "Recommended: Swimming, beach visits, park outings, or outdoor dining."
+                            .green()
                     );
-                    println!("      🚶 {}", "Recommended: Walking tours, shopping districts, light hikes, or photography.".bright_blue());
-                }
-                WeatherCondition::Fog | WeatherCondition::Mist => {
+                } else if temp_avg < 10.0 {
                     println!(
-                        "      🌫️ {}",
-                        "Foggy conditions. Be cautious while driving or in unfamiliar areas."
-                            .yellow()
+                        "      ☀️ {}",
+                        "Clear but cool. Good for active outdoor activities.".green()
                     );
                     println!(
-                        "      ⚠️ {}",
-                        "Not recommended: Activities requiring good visibility or long drives."
-                            .yellow()
+                        "      🏃 {}",
+                        "Recommended: Hiking, running, cycling, or sightseeing with warm clothing."
+                            .green()
                     );
+                } else {
                     println!(
-                        "      🏙️ {}",
-                        "Recommended: City exploration, museums, or atmospheric photography."
-                            .yellow()
+                        "      ☀️ {}",
+                        "Perfect weather conditions. Ideal for almost any outdoor activity."
+                            .green()
                     );
-                }
-                _ => {
                     println!(
-                        "      📋 {}",
-                        "Check local forecasts for specific activity recommendations.".normal()
+                        "      🌳 {}",
+                        "Recommended: Parks, hiking, cycling, outdoor sports, or dining al fresco."
+                            .green()
                     );
                 }
             }
-
-            // UV index specific advice
-            if day.uv_index > 7.0 {
+            WeatherCondition::Clouds => {
+                println!(
+                    "      ☁️ {}",
+                    "Cloudy but pleasant. Good for outdoor activities without direct sun."
+                        .bright_blue()
+                );
+                println!(
+                    "      🚶 {}",
+                    "Recommended: Walking tours, shopping districts, light hikes, or photography."
+                        .bright_blue()
+                );
+            }
+            WeatherCondition::Fog | WeatherCondition::Mist => {
+                println!(
+                    "      🌫️ {}",
+                    "Foggy conditions. Be cautious while driving or in unfamiliar areas.".yellow()
+                );
+                println!(
+                    "      ⚠️ {}",
+                    "Not recommended: Activities requiring good visibility or long drives."
+                        .yellow()
+                );
                 println!(
-                    "      🧴 {}",
-                    "Very high UV index! Sunscreen and protective clothing essential."
-                        .bright_yellow()
+                    "      🏙️ {}",
+                    "Recommended: City exploration, museums, or atmospheric photography.".yellow()
                 );
-            } else if day.uv_index > 5.0 {
+            }
+            _ => {
                 println!(
-                    "      🧴 {}",
-                    "High UV index. Wear sunscreen and seek shade during midday hours.".yellow()
+                    "      📋 {}",
+                    "Check local forecasts for specific activity recommendations.".normal()
                 );
             }
+        }
 
-            println!();
+        // UV index specific advice
+        if day.uv_index > 7.0 {
+            println!(
+                "      🧴 {}",
+                "Very high UV index! Sunscreen and protective clothing essential.".bright_yellow()
+            );
+        } else if day.uv_index > 5.0 {
+            println!(
+                "      🧴 {}",
+                "High UV index. Wear sunscreen and seek shade during midday hours.".yellow()
+            );
+        }
+    }
 
-            if self.animation_enabled {
-                sleep(StdDuration::from_millis(300));
+    /// Display the per-day severe weather risk with the reasons behind it
+    pub fn show_risk_summary(&self, risks: &[DayRisk]) -> Result<()> {
+        println!(
+            "{}",
+            "╔═══════════════════════════════════════════════════╗".bright_cyan()
+        );
+        println!(
+            "{}",
+            "║            ⚠️  SEVERE WEATHER RISK ⚠️              ║".bright_cyan()
+        );
+        println!(
+            "{}",
+            "╚═══════════════════════════════════════════════════╝".bright_cyan()
+        );
+        println!();
+
+        for risk in risks {
+            let level = format!("{:<8}", risk.level.to_string());
+            let level = match risk.level {
+                RiskLevel::Low => level.green(),
+                RiskLevel::Moderate => level.yellow(),
+                RiskLevel::High => level.bright_red(),
+                RiskLevel::Severe => level.bright_red().bold(),
+            };
+            println!(
+                "{} {:<10} {} {}",
+                risk.level.get_emoji(),
+                risk.date.format("%a %d %b").to_string(),
+                level,
+                risk.reasons.join("; ")
+            );
+        }
+        println!();
+
+        Ok(())
+    }
+
+    /// Print the provider code and raw values behind the current conditions
+    pub fn show_current_debug(&self, weather: &CurrentWeather) -> Result<()> {
+        println!("{}", "🔍 Provider data".bold());
+        print_provider_debug(
+            "now",
+            weather.raw_code,
+            weather.main_condition,
+            weather.intensity,
+            weather.provider_extra.as_ref(),
+        );
+        println!();
+        Ok(())
+    }
+
+    /// Print the `--vars` values for the next 24 hours, one column per variable
+    pub fn show_extra_variables(
+        &self,
+        forecast: &[HourlyForecast],
+        location: &Location,
+    ) -> Result<()> {
+        let names: Vec<&String> = forecast
+            .iter()
+            .flat_map(|hour| hour.extra.keys())
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        if names.is_empty() {
+            return Ok(());
+        }
+
+        println!("{}", "🧪 Extra variables".bold());
+        let widths: Vec<usize> = names.iter().map(|name| name.len().max(8)).collect();
+        let mut header = format!("{:<6}", "Time");
+        for (name, width) in names.iter().zip(&widths) {
+            header.push_str(&format!("  {:>width$}", name, width = width));
+        }
+        println!("{}", header.bold());
+        for hour in forecast.iter().take(24) {
+            let mut row = format!(
+                "{:<6}",
+                format_hour_only(&hour.timestamp, location.clock_timezone())
+            );
+            for (name, width) in names.iter().zip(&widths) {
+                let value = hour
+                    .extra
+                    .get(*name)
+                    .map(|v| format!("{}", (v * 10.0).round() / 10.0))
+                    .unwrap_or_else(|| "–".to_string());
+                row.push_str(&format!("  {:>width$}", value, width = width));
             }
+            println!("{}", row);
+        }
+        println!();
+        Ok(())
+    }
+
+    /// Print the provider codes and raw values behind the next 24 hours
+    pub fn show_hourly_debug(
+        &self,
+        forecast: &[HourlyForecast],
+        location: &Location,
+    ) -> Result<()> {
+        println!("{}", "🔍 Provider data".bold());
+        for hour in forecast.iter().take(24) {
+            print_provider_debug(
+                &format_hour_only(&hour.timestamp, location.clock_timezone()),
+                hour.raw_code,
+                hour.main_condition,
+                hour.intensity,
+                hour.provider_extra.as_ref(),
+            );
         }
+        println!();
+        Ok(())
+    }
 
+    /// Print the provider codes and raw values behind each forecast day
+    pub fn show_daily_debug(&self, forecast: &[DailyForecast]) -> Result<()> {
+        println!("{}", "🔍 Provider data".bold());
+        for day in forecast {
+            print_provider_debug(
+                &day.date.format("%a %d").to_string(),
+                day.raw_code,
+                day.main_condition,
+                day.intensity,
+                day.provider_extra.as_ref(),
+            );
+        }
         println!();
         Ok(())
     }
 
     /// Display full forecast (combines current, hourly, and daily)
-    pub fn show_forecast(&self, forecast: &Forecast, location: &Location) -> Result<()> {
+    pub fn show_forecast(
+        &self,
+        forecast: &Forecast,
+        location: &Location,
+        warnings: &[WeatherAlert],
+    ) -> Result<()> {
         if let Some(current) = &forecast.current {
-            self.show_current_weather(current, location)?;
+            if self.sections.shows(Section::Current) {
+                self.show_current_weather(current, location)?;
+            }
         }
 
-        if !forecast.hourly.is_empty() {
+        if !forecast.hourly.is_empty() && self.sections.shows(Section::Hourly) {
             self.show_hourly_forecast(&forecast.hourly, location)?;
         }
 
         if !forecast.daily.is_empty() {
-            self.show_daily_forecast(&forecast.daily, location)?;
+            self.show_daily_forecast(&forecast.daily, location, warnings)?;
+            if self.sections.shows(Section::Risk) {
+                self.show_risk_summary(&assess_risk(&forecast.daily, &forecast.hourly, location))?;
+            }
         }
 
         Ok(())
@@ -813,8 +1094,35 @@ impl WeatherUI {
         Ok(())
     }
 
-    /// Show weather recommendations based on conditions
-    pub fn show_weather_recommendations(&self, weather: &CurrentWeather) -> Result<()> {
+    /// Show when the data was fetched, warning when cached data is getting old
+    pub fn show_freshness(&self, freshness: &Freshness, location: &Location) -> Result<()> {
+        let text = freshness.describe(location.clock_timezone());
+        match freshness.staleness {
+            Staleness::Fresh => println!("{}", format!("🕒 {}", text).dimmed()),
+            Staleness::Aging => println!(
+                "{}",
+                format!("⚠️  Cached {}; it may be out of date", text).yellow()
+            ),
+            Staleness::Stale => println!(
+                "{}",
+                format!(
+                    "⚠️  Cached {} is out of date; use --no-cache to refresh",
+                    text
+                )
+                .red()
+            ),
+        }
+        Ok(())
+    }
+
+    /// Show weather recommendations based on conditions, safety warnings for the
+    /// detail level and advice for the activity profile
+    pub fn show_weather_recommendations(
+        &self,
+        weather: &CurrentWeather,
+        location: &Location,
+        settings: &WeatherConfig,
+    ) -> Result<()> {
         println!(
             "{}",
             "╔═══════════════════════════════════════════════════╗".bright_cyan()
@@ -852,14 +1160,13 @@ impl WeatherUI {
         // General recommendation based on temperature
         let _temp = weather.temperature;
         let feels_like = weather.feels_like;
-        let is_imperial = self.config().units == "imperial";
 
-        // Temperature thresholds (adjusted for units)
-        let very_cold = if is_imperial { 32.0 } else { 0.0 };
-        let cold = if is_imperial { 50.0 } else { 10.0 };
-        let mild = if is_imperial { 68.0 } else { 20.0 };
-        let warm = if is_imperial { 77.0 } else { 25.0 };
-        let hot = if is_imperial { 86.0 } else { 30.0 };
+        // Temperature thresholds in °C, the unit temperatures are stored in
+        let very_cold = 0.0;
+        let cold = 10.0;
+        let mild = 20.0;
+        let warm = 25.0;
+        let hot = 30.0;
 
         // Clothing/comfort recommendations based on time of day and temperature
         if feels_like < very_cold {
@@ -916,7 +1223,7 @@ impl WeatherUI {
 
         // UV index recommendations - only relevant during daylight hours
         if !is_night {
-            if weather.uv_index > 5.0 {
+            if weather.uv_index >= settings.thresholds.uv_warning() {
                 println!(
                     "🧴 {}",
                     "High UV levels! Wear sunscreen, hat and sunglasses.".bright_yellow()
@@ -931,26 +1238,81 @@ impl WeatherUI {
 
         // Weather-specific recommendations adjusted for time of day
         match weather.main_condition {
-            WeatherCondition::Rain | WeatherCondition::Drizzle => {
+            WeatherCondition::Rain | WeatherCondition::Drizzle => match weather.intensity {
+                Intensity::Light => {
+                    println!(
+                        "🌂 {}",
+                        format!(
+                            "Light {} this {}. A light jacket or small umbrella will do.",
+                            weather.main_condition.to_string().to_lowercase(),
+                            time_of_day
+                        )
+                        .bright_blue()
+                    );
+                }
+                Intensity::Moderate => {
+                    println!(
+                        "☔ {}",
+                        format!(
+                            "Rainy {} conditions. Bring an umbrella or raincoat.",
+                            time_of_day
+                        )
+                        .bright_blue()
+                    );
+                }
+                Intensity::Heavy => {
+                    println!(
+                        "☔ {}",
+                        format!(
+                            "Heavy rain this {}. Full waterproofs, and expect spray and puddles on roads.",
+                            time_of_day
+                        )
+                        .bright_blue()
+                        .bold()
+                    );
+                }
+                Intensity::Violent => {
+                    println!(
+                        "🌊 {}",
+                        format!(
+                            "Violent downpours this {}. Postpone travel if you can and stay clear of flooded roads.",
+                            time_of_day
+                        )
+                        .bright_red()
+                        .bold()
+                    );
+                }
+            },
+            WeatherCondition::Thunderstorm | WeatherCondition::Hail => {
                 println!(
-                    "☔ {}",
+                    "⛈️ {}",
                     format!(
-                        "Rainy {} conditions. Bring an umbrella or raincoat.",
+                        "Thunderstorms in the area this {}. Seek shelter and avoid open spaces.",
                         time_of_day
                     )
-                    .bright_blue()
+                    .bright_red()
                 );
             }
-            WeatherCondition::Thunderstorm => {
+            WeatherCondition::Snow if weather.intensity >= Intensity::Heavy => {
                 println!(
-                    "⛈️ {}",
+                    "❄️ {}",
                     format!(
-                        "Thunderstorms in the area this {}. Seek shelter and avoid open spaces.",
+                        "Heavy snow this {}. Avoid driving unless necessary and expect delays.",
                         time_of_day
                     )
                     .bright_red()
                 );
             }
+            WeatherCondition::Snow if weather.intensity == Intensity::Light => {
+                println!(
+                    "❄️ {}",
+                    format!(
+                        "Light snow this {}. Dress warmly and watch for slippery paths.",
+                        time_of_day
+                    )
+                    .bright_blue()
+                );
+            }
             WeatherCondition::Snow => {
                 println!(
                     "❄️ {}",
@@ -969,79 +1331,1379 @@ impl WeatherUI {
                     );
                 } else {
                     println!(
-                        "🌫️ {}",
-                        "Reduced visibility due to fog. Drive carefully.".yellow()
+                        "🌫️ {}",
+                        "Reduced visibility due to fog. Drive carefully.".yellow()
+                    );
+                }
+            }
+            WeatherCondition::Clear => {
+                if is_night {
+                    println!(
+                        "🌙 {}",
+                        "Clear night sky. Great for stargazing!".bright_blue()
+                    );
+                } else if weather.temperature > warm {
+                    println!(
+                        "☀️ {}",
+                        format!(
+                            "Clear and warm {}. Great for outdoor activities!",
+                            time_of_day
+                        )
+                        .green()
+                    );
+                } else {
+                    println!(
+                        "☀️ {}",
+                        format!("Clear {} skies. Enjoy the weather!", time_of_day).green()
+                    );
+                }
+            }
+            WeatherCondition::Clouds => {
+                if is_night {
+                    println!(
+                        "☁️ {}",
+                        "Cloudy night. No stargazing tonight.".bright_blue()
+                    );
+                } else {
+                    println!(
+                        "☁️ {}",
+                        format!(
+                            "Cloudy {} conditions. Good for outdoor activities without direct sun.",
+                            time_of_day
+                        )
+                        .bright_blue()
+                    );
+                }
+            }
+            _ => {}
+        }
+
+        // Wind recommendations from the wind warning threshold, scaled by Beaufort force
+        match beaufort(weather.wind_speed) {
+            _ if weather.wind_speed < settings.thresholds.wind_warning() => {}
+            force if force >= 10 => {
+                println!(
+                    "💨 {}",
+                    format!(
+                        "{} this {}. Stay indoors and away from trees and windows.",
+                        beaufort_description(force).to_title_case(),
+                        time_of_day
+                    )
+                    .bright_red()
+                    .bold()
+                );
+            }
+            force if force >= 8 => {
+                println!(
+                    "💨 {}",
+                    format!(
+                        "{} this {}. Avoid exposed areas and postpone cycling or boating.",
+                        beaufort_description(force).to_title_case(),
+                        time_of_day
+                    )
+                    .bright_red()
+                );
+            }
+            _ => {
+                println!(
+                    "💨 {}",
+                    format!(
+                        "Strong winds this {}. Secure loose objects and be careful outdoors.",
+                        time_of_day
+                    )
+                    .yellow()
+                );
+            }
+        }
+
+        for warning in safety_warnings(weather, settings.detail_level) {
+            println!("{}", warning.bright_red());
+        }
+
+        let profile = settings.profile;
+        let advice = profile_recommendations(profile, weather, location);
+        if !advice.is_empty() {
+            println!();
+            println!(
+                "{} {}",
+                profile.get_emoji(),
+                format!("{} profile", profile.label()).bold()
+            );
+            for line in advice {
+                println!("  • {}", line.bright_white());
+            }
+        }
+
+        // Show interactive weather canvas scene
+        if self.animation_enabled && !self.json_output {
+            println!("\n🎨 Weather Scene Visualization");
+            if let Err(e) = self.show_weather_canvas_scene(weather) {
+                println!("⚠️  Weather canvas unavailable: {}", e);
+            }
+        }
+
+        println!();
+        Ok(())
+    }
+
+    /// Display sunrise, sunset, solar noon and day length for the coming days
+    pub fn show_sun_times(&self, sun: &[SunTimes], location: &Location) -> Result<()> {
+        use crate::modules::sun::{format_delta, format_minutes};
+
+        println!(
+            "{}",
+            "╔═══════════════════════════════════════════════════╗".bright_cyan()
+        );
+        println!(
+            "{}",
+            "║                🌅 SUN & DAYLIGHT 🌇                ║".bright_cyan()
+        );
+        println!(
+            "{}",
+            "╚═══════════════════════════════════════════════════╝".bright_cyan()
+        );
+        println!();
+
+        let Some(today) = sun.first() else {
+            println!("No sun data available");
+            return Ok(());
+        };
+
+        println!(
+            "🌅 {}: {}",
+            "Sunrise".bold(),
+            format_local_time(&today.sunrise, location.clock_timezone())
+        );
+        println!(
+            "☀️ {}: {}",
+            "Solar noon".bold(),
+            format_local_time(&today.solar_noon, location.clock_timezone())
+        );
+        println!(
+            "🌇 {}: {}",
+            "Sunset".bold(),
+            format_local_time(&today.sunset, location.clock_timezone())
+        );
+        println!(
+            "⏱️ {}: {}",
+            "Day length".bold(),
+            format_minutes(today.day_length_minutes)
+        );
+        if let (Some(sunrise_delta), Some(length_delta)) = (
+            today.next_sunrise_delta_minutes,
+            today.next_day_length_delta_minutes,
+        ) {
+            println!(
+                "📆 {}: sunrise {}, day length {}",
+                "Tomorrow".bold(),
+                format_delta(sunrise_delta),
+                format_delta(length_delta)
+            );
+        }
+        println!();
+
+        println!(
+            "{:<12} {:>8} {:>10} {:>8} {:>10}",
+            "Date".bold(),
+            "Sunrise".bold(),
+            "Solar noon".bold(),
+            "Sunset".bold(),
+            "Length".bold()
+        );
+        for day in sun {
+            println!(
+                "{:<12} {:>8} {:>10} {:>8} {:>10}",
+                day.date.format("%a %d %b").to_string(),
+                format_local_time(&day.sunrise, location.clock_timezone()).yellow(),
+                format_local_time(&day.solar_noon, location.clock_timezone()),
+                format_local_time(&day.sunset, location.clock_timezone()).bright_red(),
+                format_minutes(day.day_length_minutes)
+            );
+        }
+        println!();
+
+        Ok(())
+    }
+
+    /// Display today's hourly activity scores and the best window of the given length
+    pub fn show_best_window(
+        &self,
+        scores: &[HourScore],
+        best: Option<&BestWindow>,
+        hours: usize,
+        location: &Location,
+    ) -> Result<()> {
+        use crate::modules::activity::sparkline;
+
+        println!(
+            "{}",
+            "╔═══════════════════════════════════════════════════╗".bright_cyan()
+        );
+        println!(
+            "{}",
+            "║              🏃 OUTDOOR ACTIVITY 🏃               ║".bright_cyan()
+        );
+        println!(
+            "{}",
+            "╚═══════════════════════════════════════════════════╝".bright_cyan()
+        );
+        println!();
+
+        let (Some(first), Some(last)) = (scores.first(), scores.last()) else {
+            println!("No hourly forecast data left today");
+            return Ok(());
+        };
+
+        println!(
+            "{} {} {}",
+            format_local_time(&first.timestamp, location.clock_timezone()),
+            sparkline(scores).bright_green(),
+            format_local_time(&last.timestamp, location.clock_timezone())
+        );
+        println!();
+
+        match best {
+            Some(window) => println!(
+                "✅ {}: {}–{} (score {:.0}/100)",
+                format!("Best {}-hour window today", hours).bold(),
+                format_local_time(&window.start, location.clock_timezone()),
+                format_local_time(&window.end, location.clock_timezone()),
+                window.average_score
+            ),
+            None => println!("No {}-hour window left today", hours),
+        }
+        println!();
+
+        Ok(())
+    }
+
+    /// Display the drying verdict, hourly drying scores and best hanging window
+    pub fn show_laundry(&self, report: &LaundryReport, location: &Location) -> Result<()> {
+        use crate::modules::activity::sparkline;
+        use crate::modules::laundry::HANGING_HOURS;
+
+        println!(
+            "{}",
+            "╔═══════════════════════════════════════════════════╗".bright_cyan()
+        );
+        println!(
+            "{}",
+            "║               👕 LAUNDRY FORECAST 👕              ║".bright_cyan()
+        );
+        println!(
+            "{}",
+            "╚═══════════════════════════════════════════════════╝".bright_cyan()
+        );
+        println!();
+
+        println!(
+            "{} {}",
+            report.verdict.get_emoji(),
+            report.verdict.to_string().bold()
+        );
+
+        if let (Some(first), Some(last)) = (report.scores.first(), report.scores.last()) {
+            println!(
+                "{} {} {}",
+                format_local_time(&first.timestamp, location.clock_timezone()),
+                sparkline(&report.scores).bright_green(),
+                format_local_time(&last.timestamp, location.clock_timezone())
+            );
+        }
+
+        if let Some(window) = &report.best_window {
+            println!(
+                "🧺 {}: {}–{} (drying score {:.0}/100)",
+                format!("Best {}-hour hanging window", HANGING_HOURS).bold(),
+                format_local_time(&window.start, location.clock_timezone()),
+                format_local_time(&window.end, location.clock_timezone()),
+                window.average_score
+            );
+        }
+        println!();
+
+        Ok(())
+    }
+
+    /// Display when sun protection is needed today, with a shaded UV timeline
+    pub fn show_uv_plan(&self, plan: &UvPlan, location: &Location) -> Result<()> {
+        println!(
+            "{}",
+            "╔═══════════════════════════════════════════════════╗".bright_cyan()
+        );
+        println!(
+            "{}",
+            "║             🧴 SUN PROTECTION PLANNER 🧴          ║".bright_cyan()
+        );
+        println!(
+            "{}",
+            "╚═══════════════════════════════════════════════════╝".bright_cyan()
+        );
+        println!();
+
+        let time = |t: &DateTime<Utc>| format_local_time(t, location.clock_timezone());
+        let spans = |windows: &[UvWindow]| {
+            windows
+                .iter()
+                .map(|w| format!("{}–{}", time(&w.start), time(&w.end)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        if plan.hours.is_empty() {
+            println!("No hourly UV forecast available for today");
+            println!();
+            return Ok(());
+        }
+        if !plan.avoid.is_empty() {
+            println!(
+                "⛱️  {} {}",
+                "Avoid direct sun".bright_red().bold(),
+                spans(&plan.avoid)
+            );
+        }
+        match plan.protect.first() {
+            Some(first) => println!(
+                "🧴 {} {} (until {})",
+                "SPF recommended from".yellow().bold(),
+                time(&first.start),
+                time(&plan.protect[plan.protect.len() - 1].end)
+            ),
+            None => println!("✅ {}", "No sun protection needed today".green()),
+        }
+        if let Some(peak) = &plan.peak {
+            println!(
+                "☀️  Peak UV {:.1} at {}",
+                peak.uv_index,
+                time(&peak.timestamp)
+            );
+        }
+        println!();
+
+        // Daylight part of the day, two columns per hour
+        let first = plan.hours.iter().position(|h| h.uv_index > 0.0);
+        let last = plan.hours.iter().rposition(|h| h.uv_index > 0.0);
+        if let (Some(first), Some(last)) = (first, last) {
+            let shown = &plan.hours[first.saturating_sub(1)..(last + 2).min(plan.hours.len())];
+            let bar: String = shown
+                .iter()
+                .map(|hour| match hour.level {
+                    UvLevel::Avoid => "██".bright_red().to_string(),
+                    UvLevel::Protect => "▓▓".yellow().to_string(),
+                    UvLevel::Low => "░░".green().to_string(),
+                })
+                .collect();
+            let labels: String = shown
+                .iter()
+                .enumerate()
+                .map(|(index, hour)| {
+                    let label =
+                        convert_to_local(&hour.timestamp, location.clock_timezone()).format("%H");
+                    if index % 3 == 0 {
+                        format!("{:<2}", label)
+                    } else {
+                        "  ".to_string()
+                    }
+                })
+                .collect();
+            println!("{}", bar);
+            println!("{}", labels.bright_black());
+            println!(
+                "{} low  {} SPF (UV ≥ {:.0})  {} avoid (UV ≥ {:.0})",
+                "░░".green(),
+                "▓▓".yellow(),
+                plan.protect_threshold,
+                "██".bright_red(),
+                plan.avoid_threshold
+            );
+        }
+        println!();
+
+        Ok(())
+    }
+
+    /// Display the go/caution/avoid verdict for each driving window
+    pub fn show_roads(&self, blocks: &[RoadBlock], location: &Location) -> Result<()> {
+        println!(
+            "{}",
+            "╔═══════════════════════════════════════════════════╗".bright_cyan()
+        );
+        println!(
+            "{}",
+            "║              🚗 ROAD CONDITIONS 24H 🚗            ║".bright_cyan()
+        );
+        println!(
+            "{}",
+            "╚═══════════════════════════════════════════════════╝".bright_cyan()
+        );
+        println!();
+
+        let temp_unit = self.units.temperature;
+        for block in blocks {
+            let verdict = format!("{:<8}", block.verdict.to_string());
+            let verdict = match block.verdict {
+                RoadVerdict::Go => verdict.green(),
+                RoadVerdict::Caution => verdict.yellow(),
+                RoadVerdict::Avoid => verdict.bright_red(),
+            }
+            .bold();
+            println!(
+                "{} {}–{}  {} min {}{}{}",
+                block.verdict.get_emoji(),
+                convert_to_local(&block.start, location.clock_timezone()).format("%a %H:%M"),
+                format_local_time(&block.end, location.clock_timezone()),
+                verdict,
+                temp_unit.format(block.min_temperature),
+                if block.snowfall > 0.0 {
+                    format!("  ❄️ {:.1} cm", block.snowfall)
+                } else {
+                    String::new()
+                },
+                match block.max_snow_depth.filter(|d| *d >= 1.0) {
+                    Some(depth) => format!("  ⛄ {:.0} cm lying", depth),
+                    None => String::new(),
+                }
+            );
+            for reason in &block.reasons {
+                println!("      {} {}", "•".bright_black(), reason);
+            }
+        }
+        if blocks.is_empty() {
+            println!("No hourly forecast available");
+        }
+        println!();
+
+        Ok(())
+    }
+
+    /// Display estimated PV and small-wind production hour by hour
+    pub fn show_energy(&self, report: &EnergyReport, location: &Location) -> Result<()> {
+        println!(
+            "{}",
+            "╔═══════════════════════════════════════════════════╗".bright_cyan()
+        );
+        println!(
+            "{}",
+            "║              ⚡ HOME ENERGY FORECAST ⚡            ║".bright_cyan()
+        );
+        println!(
+            "{}",
+            "╚═══════════════════════════════════════════════════╝".bright_cyan()
+        );
+        println!();
+
+        let peak = report
+            .hours
+            .iter()
+            .map(|h| h.pv_kw.max(h.wind_kw))
+            .fold(0.0, f64::max);
+        let bar = |kw: f64| {
+            let width = if peak > 0.0 {
+                (kw / peak * 20.0).round() as usize
+            } else {
+                0
+            };
+            "█".repeat(width)
+        };
+
+        println!(
+            "{:<11} {:>7} {:>8}  {:<20} {:>9} {:>8}",
+            "Time".bold(),
+            "W/m²".bold(),
+            "PV kW".bold(),
+            "",
+            "Wind m/s".bold(),
+            "Wind kW".bold()
+        );
+        let mut last_day = None;
+        for hour in &report.hours {
+            let local = convert_to_local(&hour.timestamp, location.clock_timezone());
+            let day = local.format("%a").to_string();
+            let label = if last_day.as_ref() != Some(&day) {
+                format!("{} {}", day, local.format("%H:%M"))
+            } else {
+                format!("    {}", local.format("%H:%M"))
+            };
+            last_day = Some(day);
+
+            println!(
+                "{:<11} {:>7.0} {:>8.2}  {:<20} {:>9.1} {:>8.2} {}",
+                label,
+                hour.irradiance,
+                hour.pv_kw,
+                bar(hour.pv_kw).yellow(),
+                hour.hub_wind_speed,
+                hour.wind_kw,
+                bar(hour.wind_kw).bright_cyan()
+            );
+        }
+
+        println!();
+        println!(
+            "☀️  Solar: {} over the next {} hours",
+            format!("{:.1} kWh", report.pv_kwh).yellow().bold(),
+            report.hours.len()
+        );
+        println!(
+            "🌬️  Wind:  {} over the next {} hours",
+            format!("{:.1} kWh", report.wind_kwh).bright_cyan().bold(),
+            report.hours.len()
+        );
+        println!(
+            "{}",
+            "Estimates from forecast irradiance and wind; set your system in [energy]"
+                .bright_black()
+        );
+        println!();
+
+        Ok(())
+    }
+
+    /// Display pressure and density altitude, cloud base and runway wind components
+    pub fn show_aviation(
+        &self,
+        report: &AviationReport,
+        weather: &CurrentWeather,
+        location: &Location,
+    ) -> Result<()> {
+        use crate::modules::utils::WindUnit;
+
+        println!(
+            "{}",
+            "╔═══════════════════════════════════════════════════╗".bright_cyan()
+        );
+        println!(
+            "{}",
+            "║               ✈️  AVIATION CHECK ✈️                ║".bright_cyan()
+        );
+        println!(
+            "{}",
+            "╚═══════════════════════════════════════════════════╝".bright_cyan()
+        );
+        println!(
+            "📍 {}  {}",
+            location.name.bold(),
+            format_local_time(&weather.timestamp, location.clock_timezone())
+        );
+        println!();
+
+        // Temperatures stay in °C, as in METARs
+        println!(
+            "🌡️  Temperature {:.0}°C  Dew point {:.0}°C  Station pressure {}",
+            weather.temperature,
+            report.dew_point,
+            self.units.pressure.format(weather.pressure.0)
+        );
+        println!(
+            "📏 Pressure altitude: {}",
+            format!("{:.0} ft", report.pressure_altitude_ft).bold()
+        );
+        let density = format!("{:.0} ft", report.density_altitude_ft).bold();
+        let density = if report.density_altitude_ft - report.pressure_altitude_ft > 2000.0 {
+            density.bright_red()
+        } else {
+            density.normal()
+        };
+        println!("🛫 Density altitude:  {}", density);
+        println!(
+            "☁️  Estimated cloud base: {:.0} ft AGL",
+            report.cloud_base_ft
+        );
+
+        let knots = |speed: f64| WindUnit::Kn.format(speed);
+        println!(
+            "💨 Wind {:03}° {}{}",
+            weather.wind_direction,
+            knots(weather.wind_speed),
+            weather
+                .wind_gusts
+                .map(|gust| format!(", gusts {}", knots(gust)))
+                .unwrap_or_default()
+        );
+
+        if let Some(runway) = &report.runway {
+            let along = if runway.headwind >= 0.0 {
+                format!("Headwind {}", knots(runway.headwind)).green()
+            } else {
+                format!("Tailwind {}", knots(-runway.headwind)).bright_red()
+            };
+            let side = match runway.crosswind_from {
+                Side::Left => "from the left",
+                Side::Right => "from the right",
+            };
+            println!();
+            println!("🛬 Runway heading {:03.0}°", runway.heading);
+            println!("   {}", along);
+            println!(
+                "   Crosswind {} {}{}",
+                knots(runway.crosswind).bold(),
+                side,
+                runway
+                    .gust_crosswind
+                    .map(|gust| format!(" (gusting {})", knots(gust)))
+                    .unwrap_or_default()
+            );
+        } else {
+            println!();
+            println!(
+                "{}",
+                "💡 Add --runway 27 (or a heading like 270) for crosswind components"
+                    .bright_black()
+            );
+        }
+        println!();
+        println!(
+            "{}",
+            "Forecast model data, not an official observation; check METARs before flight"
+                .bright_black()
+        );
+        println!();
+
+        Ok(())
+    }
+
+    /// Print the rendered `[templates]` output, then the alerts that hold
+    ///
+    /// No decoration, so the output can be used in status bars and scripts.
+    /// Display the narrative summary at the top of a report
+    pub fn show_summary(&self, summary: &str) -> Result<()> {
+        println!("📝 {}", summary.italic());
+        println!();
+        Ok(())
+    }
+
+    pub fn show_template(&self, report: &TemplateReport) -> Result<()> {
+        if let Some(output) = &report.output {
+            println!("{}", output);
+        }
+        for alert in &report.alerts {
+            println!("🔔 {}", alert.bright_yellow());
+        }
+        Ok(())
+    }
+
+    /// Display the fire weather index per day and the drought indicators
+    /// Display the forecast for upcoming outdoor events from the calendar
+    pub fn show_events(&self, outlooks: &[EventOutlook], location: &Location) -> Result<()> {
+        println!(
+            "{}",
+            "╔═══════════════════════════════════════════════════╗".bright_cyan()
+        );
+        println!(
+            "{}",
+            "║               📅 EVENT FORECAST 📅                ║".bright_cyan()
+        );
+        println!(
+            "{}",
+            "╚═══════════════════════════════════════════════════╝".bright_cyan()
+        );
+        println!("📍 {}", location.name.bold());
+        println!();
+
+        if outlooks.is_empty() {
+            println!("No outdoor events within the forecast.");
+            println!();
+            return Ok(());
+        }
+
+        for outlook in outlooks {
+            let headline = outlook.headline(location.clock_timezone());
+            if outlook.warnings.is_empty() {
+                println!("✅ {}", headline.green());
+            } else {
+                println!("⚠️  {}", headline.bright_yellow().bold());
+            }
+            let mut details = format!(
+                "   {:.0}° to {:.0}°, wind up to {}",
+                outlook.temp_min,
+                outlook.temp_max,
+                self.units.wind.format(outlook.max_wind)
+            );
+            if !outlook.event.all_day {
+                details.push_str(&format!(
+                    ", until {}",
+                    format_local_time(&outlook.event.end, location.clock_timezone())
+                ));
+            }
+            println!("{}", details.dimmed());
+            if let Some(place) = &outlook.event.location {
+                println!("   📍 {}", place.dimmed());
+            }
+        }
+        println!();
+
+        Ok(())
+    }
+
+    pub fn show_fire(&self, report: &FireReport, location: &Location) -> Result<()> {
+        println!(
+            "{}",
+            "╔═══════════════════════════════════════════════════╗".bright_cyan()
+        );
+        println!(
+            "{}",
+            "║               🔥 FIRE WEATHER 🔥                  ║".bright_cyan()
+        );
+        println!(
+            "{}",
+            "╚═══════════════════════════════════════════════════╝".bright_cyan()
+        );
+        println!("📍 {}", location.name.bold());
+        println!();
+
+        println!("{}", format!("Last {} days", DROUGHT_DAYS).bold());
+        println!(
+            "💧 {} rain against {} evapotranspiration",
+            self.units.precip.format(report.recent_precipitation),
+            self.units.precip.format(report.recent_evapotranspiration)
+        );
+        let deficit = format!(
+            "Precipitation deficit: {}",
+            self.units.precip.format(report.deficit.max(0.0))
+        );
+        if report.drought {
+            println!(
+                "🏜️  {} (drought, risk raised one level)",
+                deficit.bright_red()
+            );
+        } else {
+            println!("🌱 {}", deficit);
+        }
+        if report.dry_days > 0 {
+            println!("☀️  {} days since the last rain", report.dry_days);
+        }
+        println!();
+
+        println!(
+            "{:<12} {:>6} {:>7} {:>8} {:>9} {:>6}  {}",
+            "Day".bold(),
+            "Max".bold(),
+            "Min RH".bold(),
+            "Wind".bold(),
+            "Precip".bold(),
+            "FFWI".bold(),
+            "Risk".bold()
+        );
+        for day in &report.days {
+            let level = format!("{}", day.level);
+            let level = match day.level {
+                FireLevel::Low => level.green(),
+                FireLevel::Moderate => level.yellow(),
+                FireLevel::High => level.bright_yellow(),
+                FireLevel::Extreme => level.bright_red().bold(),
+            };
+            println!(
+                "{:<12} {:>6} {:>6.0}% {:>8} {:>9} {:>6.0}  {} {}",
+                day.date.format("%a %b %d"),
+                format!("{:.0}°", day.max_temperature),
+                day.min_humidity,
+                self.units.wind.format(day.max_wind_speed),
+                self.units.precip.format(day.precipitation),
+                day.index,
+                day.level.get_emoji(),
+                level
+            );
+        }
+        if report
+            .days
+            .iter()
+            .any(|day| day.level == FireLevel::Extreme)
+        {
+            println!();
+            println!(
+                "🚫 {}",
+                "Extreme fire weather ahead: avoid open fires, barbecues and sparks outdoors"
+                    .bright_red()
+            );
+        }
+        println!();
+
+        Ok(())
+    }
+
+    /// Display soil moisture, the daily water balance and leaf wetness
+    pub fn show_agriculture(&self, report: &AgricultureReport, location: &Location) -> Result<()> {
+        println!(
+            "{}",
+            "╔═══════════════════════════════════════════════════╗".bright_cyan()
+        );
+        println!(
+            "{}",
+            "║              🌾 AGRICULTURE REPORT 🌾             ║".bright_cyan()
+        );
+        println!(
+            "{}",
+            "╚═══════════════════════════════════════════════════╝".bright_cyan()
+        );
+        println!("📍 {}", location.name.bold());
+        println!();
+
+        println!("{}", "Soil moisture now".bold());
+        for layer in &report.soil {
+            match layer.moisture {
+                Some(moisture) => {
+                    let bar = "█".repeat((moisture / 0.5 * 20.0).round().clamp(0.0, 20.0) as usize);
+                    println!(
+                        "  {:<9} {:>5.2} m³/m³ {:<20} {}",
+                        layer.depth,
+                        moisture,
+                        bar.blue(),
+                        moisture_description(moisture)
                     );
                 }
+                None => println!("  {:<9} {}", layer.depth, "-".bright_black()),
             }
-            WeatherCondition::Clear => {
-                if is_night {
-                    println!(
-                        "🌙 {}",
-                        "Clear night sky. Great for stargazing!".bright_blue()
-                    );
-                } else if weather.temperature > warm {
-                    println!(
-                        "☀️ {}",
-                        format!(
-                            "Clear and warm {}. Great for outdoor activities!",
-                            time_of_day
-                        )
-                        .green()
-                    );
-                } else {
-                    println!(
-                        "☀️ {}",
-                        format!("Clear {} skies. Enjoy the weather!", time_of_day).green()
-                    );
-                }
+        }
+        if let Some(temperature) = report.soil_temperature {
+            println!(
+                "  Soil temperature at 6 cm: {:.1}{}",
+                self.units.temperature.convert(temperature),
+                self.units.temperature.label()
+            );
+        }
+        println!();
+
+        println!(
+            "{:<12} {:>8} {:>9} {:>9} {:>10}",
+            "Day".bold(),
+            "ET0 mm".bold(),
+            "Rain mm".bold(),
+            "Balance".bold(),
+            "Leaf wet".bold()
+        );
+        for day in &report.days {
+            let balance = format!("{:+.1}", day.water_balance);
+            let balance = if day.water_balance < 0.0 {
+                balance.bright_red()
+            } else {
+                balance.green()
+            };
+            println!(
+                "{:<12} {:>8.1} {:>9.1} {:>9} {:>9}h",
+                day.date.format("%a %b %d"),
+                day.et0,
+                day.precipitation,
+                balance,
+                day.leaf_wet_hours
+            );
+        }
+        println!();
+
+        if report.irrigation_need > 0.0 {
+            println!(
+                "💧 {} {:.0} mm over the next {} days to cover evapotranspiration",
+                "Irrigation need:".bold(),
+                report.irrigation_need,
+                report.days.len()
+            );
+        } else {
+            println!("✅ Forecast rain covers evapotranspiration; no irrigation needed");
+        }
+        if report.days.iter().any(|day| day.leaf_wet_hours >= 10) {
+            println!("🍂 Long leaf wetness periods ahead raise the risk of fungal disease");
+        }
+        println!();
+
+        Ok(())
+    }
+
+    /// Display the rolling precipitation totals for the next hours
+    pub fn show_accumulation(
+        &self,
+        accumulation: &Accumulation,
+        location: &Location,
+    ) -> Result<()> {
+        let Some(text) = accumulation_text(accumulation, location, self.units.precip) else {
+            return Ok(());
+        };
+        println!("🌧️  {}", text);
+        let windows: Vec<String> = accumulation
+            .windows
+            .iter()
+            .map(|w| format!("{}h: {}", w.hours, self.units.precip.format(w.total)))
+            .collect();
+        println!("   {}", windows.join(" · ").dimmed());
+        println!();
+        Ok(())
+    }
+
+    /// Display snow on the ground, expected snowfall and the freezing and
+    /// snowfall levels per day
+    pub fn show_winter(&self, report: &WinterReport, location: &Location) -> Result<()> {
+        println!("{}", "❄️  Winter".bold());
+        if let Some(depth) = report.snow_depth.filter(|d| *d > 0.0) {
+            println!("⛄ {:.0} cm of snow on the ground", depth);
+        }
+        if let Some(first) = &report.first_snow {
+            println!(
+                "🌨️  Snow from {}, {:.1} cm in the next {}h",
+                convert_to_local(first, location.clock_timezone()).format("%a %H:%M"),
+                report.snowfall,
+                WINTER_HOURS
+            );
+        }
+        println!(
+            "{:<11} {:>8} {:>8} {:>10} {:>11}",
+            "Day".bold(),
+            "Snow".bold(),
+            "Depth".bold(),
+            "0°C level".bold(),
+            "Snow level".bold()
+        );
+        let metres = |value: Option<f64>| {
+            value
+                .map(|v| format!("{:.0} m", v))
+                .unwrap_or_else(|| "–".to_string())
+        };
+        for day in &report.days {
+            println!(
+                "{:<11} {:>8} {:>8} {:>10} {:>11}",
+                day.date.format("%a %b %d"),
+                format!("{:.1} cm", day.snowfall),
+                day.snow_depth
+                    .map(|d| format!("{:.0} cm", d))
+                    .unwrap_or_else(|| "–".to_string()),
+                metres(day.min_freezing_level),
+                metres(day.min_snowfall_level)
+            );
+        }
+        println!();
+        Ok(())
+    }
+
+    /// Display the climate normals and records for today
+    pub fn show_day_normals(&self, normals: &DayNormals, high: Option<f64>) -> Result<()> {
+        let date = NaiveDate::from_ymd_opt(2000, normals.month, normals.day)
+            .map(|date| date.format("%B %-d").to_string())
+            .unwrap_or_default();
+        let title = format!(
+            "📅 CLIMATE FOR {} ({}–{})",
+            date.to_uppercase(),
+            NORMALS_PERIOD.0,
+            NORMALS_PERIOD.1
+        );
+        println!("{}", title.bright_cyan().bold());
+        println!("{}", "─".repeat(title.chars().count()).bright_cyan());
+        println!(
+            "Normal:  high {:.0}° / low {:.0}°, {:.1} mm precipitation",
+            normals.normal_max, normals.normal_min, normals.normal_precipitation
+        );
+        println!(
+            "Records: high {:.0}° ({}), low {:.0}° ({})",
+            normals.record_max.value,
+            normals.record_max.year,
+            normals.record_min.value,
+            normals.record_min.year
+        );
+        println!("Wet on {} of {} years", normals.wet_years, normals.years);
+        if let Some(high) = high {
+            println!("{}", anomaly_text(normals, high).bold());
+        }
+        println!(
+            "{}",
+            "(source: Open-Meteo historical weather)".bright_black()
+        );
+        println!();
+        Ok(())
+    }
+
+    /// Display the models side by side every 3 hours, marking disagreements
+    pub fn show_models(&self, comparison: &ModelComparison, location: &Location) -> Result<()> {
+        let title = format!("🔀 MODEL COMPARISON - {}", location.name.to_uppercase());
+        println!("{}", title.bright_cyan().bold());
+        println!("{}", "─".repeat(title.chars().count()).bright_cyan());
+
+        let widths: Vec<usize> = comparison
+            .models
+            .iter()
+            .map(|m| m.model.len().max(13))
+            .collect();
+        let mut header = format!("{:<10}", "Time");
+        for (model, width) in comparison.models.iter().zip(&widths) {
+            header.push_str(&format!("  {:<width$}", model.model, width = width));
+        }
+        header.push_str("  Spread");
+        println!("{}", header.bold());
+
+        for (i, time) in comparison.times.iter().enumerate().step_by(3) {
+            let local = convert_to_local(time, location.clock_timezone());
+            let mut row = format!("{:<10}", local.format("%a %H:%M").to_string());
+            for (model, width) in comparison.models.iter().zip(&widths) {
+                let cell = match (model.temperature[i], model.precipitation[i]) {
+                    (Some(t), Some(p)) => format!("{:>4.0}° {:>4.1} mm", t, p),
+                    (Some(t), None) => format!("{:>4.0}°", t),
+                    _ => "   –".to_string(),
+                };
+                row.push_str(&format!("  {:<width$}", cell, width = width));
             }
-            WeatherCondition::Clouds => {
-                if is_night {
-                    println!(
-                        "☁️ {}",
-                        "Cloudy night. No stargazing tonight.".bright_blue()
-                    );
-                } else {
-                    println!(
-                        "☁️ {}",
-                        format!(
-                            "Cloudy {} conditions. Good for outdoor activities without direct sun.",
-                            time_of_day
-                        )
-                        .bright_blue()
-                    );
+            match comparison.temperature_spread(i) {
+                Some(spread) if spread >= DISAGREEMENT => {
+                    println!("{}  {}", row, format!("{:>4.0}° ⚠", spread).bright_red())
                 }
+                Some(spread) => println!("{}  {:>4.0}°", row, spread),
+                None => println!("{}", row),
+            }
+        }
+        println!(
+            "{}",
+            format!("(⚠ models differ by {:.0}° or more)", DISAGREEMENT).bright_black()
+        );
+        println!();
+        Ok(())
+    }
+
+    /// Display current conditions from two providers with their differences,
+    /// highlighting the ones large enough to matter
+    pub fn show_provider_comparison(
+        &self,
+        comparison: &ProviderComparison,
+        location: &Location,
+    ) -> Result<()> {
+        let title = format!("⚖️  PROVIDER COMPARISON - {}", location.name.to_uppercase());
+        println!("{}", title.bright_cyan().bold());
+        println!("{}", "─".repeat(title.chars().count()).bright_cyan());
+
+        let [first, second] = &comparison.providers;
+        let width = first.len().max(second.len()).max(12);
+        println!(
+            "{}",
+            format!(
+                "{:<12}  {:>width$}  {:>width$}  {:>8}",
+                "",
+                first,
+                second,
+                "Δ",
+                width = width
+            )
+            .bold()
+        );
+
+        let [sky_a, sky_b] = &comparison.conditions;
+        let sky = format!(
+            "{:<12}  {:>width$}  {:>width$}",
+            "Sky",
+            sky_a,
+            sky_b,
+            width = width
+        );
+        if sky_a == sky_b {
+            println!("{}", sky);
+        } else {
+            println!("{}  {}", sky, format!("{:>8}", "≠").bright_yellow().bold());
+        }
+
+        for row in &comparison.rows {
+            let line = format!(
+                "{:<12}  {:>width$}  {:>width$}",
+                row.label,
+                format!("{:.1}{}", row.values[0], row.unit),
+                format!("{:.1}{}", row.values[1], row.unit),
+                width = width
+            );
+            let delta = format!("{:>+8.1}", row.delta);
+            if row.notable {
+                println!("{}  {}", line, delta.bright_yellow().bold());
+            } else {
+                println!("{}  {}", line, delta.bright_black());
             }
-            _ => {}
         }
 
-        // Wind recommendations
-        if weather.wind_speed > 10.0 {
+        let verdict = if comparison.disagree() {
+            "The providers disagree on the highlighted values".bright_yellow()
+        } else {
+            "The providers agree".bright_green()
+        };
+        println!("{}", verdict);
+        println!();
+        Ok(())
+    }
+
+    /// Display the ensemble spread per day with whisker bars for the high
+    pub fn show_ensemble(&self, ensemble: &Ensemble, location: &Location) -> Result<()> {
+        const WHISKER_WIDTH: usize = 30;
+        let title = format!(
+            "🎲 FORECAST CONFIDENCE - {} ({} ensemble)",
+            location.name.to_uppercase(),
+            ensemble.model
+        );
+        println!("{}", title.bright_cyan().bold());
+        println!("{}", "─".repeat(title.chars().count()).bright_cyan());
+
+        let lo = ensemble
+            .days
+            .iter()
+            .map(|d| d.high_min)
+            .fold(f64::INFINITY, f64::min);
+        let hi = ensemble
+            .days
+            .iter()
+            .map(|d| d.high_max)
+            .fold(f64::NEG_INFINITY, f64::max);
+        for day in &ensemble.days {
+            let spread = day.high_max - day.high_min;
+            let bar = whisker(day, lo, hi, WHISKER_WIDTH);
+            let bar = if spread >= 6.0 {
+                bar.bright_red()
+            } else if spread >= 3.0 {
+                bar.yellow()
+            } else {
+                bar.bright_green()
+            };
             println!(
-                "💨 {}",
-                format!(
-                    "Strong winds this {}. Secure loose objects and be careful outdoors.",
-                    time_of_day
-                )
-                .yellow()
+                "{:<10} {:>4.0}° {} {:<4} high {:.0}° (low {:.0}°)  rain {:>3.0}% ({:.1}–{:.1} mm)",
+                day.date.format("%a %-d %b").to_string(),
+                day.high_min,
+                bar,
+                format!("{:.0}°", day.high_max),
+                day.high_median,
+                day.low_median,
+                day.precipitation_chance * 100.0,
+                day.precipitation_median,
+                day.precipitation_max
             );
         }
+        println!(
+            "{}",
+            "(whiskers: lowest to highest member high, ● median; wider means less certain)"
+                .bright_black()
+        );
+        println!();
+        Ok(())
+    }
 
-        // Show interactive weather canvas scene
-        if self.animation_enabled && !self.json_output {
-            println!("\n🎨 Weather Scene Visualization");
-            if let Err(e) = self.show_weather_canvas_scene(weather) {
-                println!("⚠️  Weather canvas unavailable: {}", e);
+    /// Display this week's forecast next to the monthly normals
+    pub fn show_climate_week(&self, week: &[ClimateComparison], location: &Location) -> Result<()> {
+        let title = format!(
+            "📅 THIS WEEK VS {}–{} NORMALS - {}",
+            NORMALS_PERIOD.0,
+            NORMALS_PERIOD.1,
+            location.name.to_uppercase()
+        );
+        println!("{}", title.bright_cyan().bold());
+        println!("{}", "─".repeat(title.chars().count()).bright_cyan());
+        println!(
+            "{}",
+            format!(
+                "{:<10} {:>6} {:>8} {:>6} {:>8} {:>9} {:>8}",
+                "Day", "High", "Normal", "Low", "Normal", "Precip", "Normal"
+            )
+            .bold()
+        );
+        for day in week {
+            let anomaly = day.temp_max - day.normal_max;
+            let high = format!("{:>5.0}°", day.temp_max);
+            let high = if anomaly >= 3.0 {
+                high.bright_red()
+            } else if anomaly <= -3.0 {
+                high.bright_blue()
+            } else {
+                high.normal()
+            };
+            println!(
+                "{:<10} {} {:>7.0}° {:>5.0}° {:>7.0}° {:>6.1} mm {:>5.1} mm",
+                day.date.format("%a %-d %b").to_string(),
+                high,
+                day.normal_max,
+                day.temp_min,
+                day.normal_min,
+                day.precipitation,
+                day.normal_precipitation
+            );
+        }
+        println!();
+        Ok(())
+    }
+
+    /// Display recent earthquakes and other hazards near the location
+    pub fn show_hazards(&self, hazards: &[Hazard], location: &Location) -> Result<()> {
+        println!("{}", "⚠️  NEARBY HAZARDS".bright_yellow().bold());
+        println!("{}", "─────────────────".bright_yellow());
+
+        if hazards.is_empty() {
+            println!("✅ No recent earthquakes or other hazards nearby");
+        }
+        for hazard in hazards {
+            let magnitude = hazard
+                .magnitude
+                .map(|m| format!("M{:.1} ", m).bright_red().bold().to_string())
+                .unwrap_or_default();
+            println!(
+                "{} {}{} {}",
+                hazard.kind.get_emoji(),
+                magnitude,
+                hazard.kind.to_string().bold(),
+                hazard.title
+            );
+            println!(
+                "   {} · {:.0} km away",
+                convert_to_local(&hazard.time, location.clock_timezone()).format("%a %b %d %H:%M"),
+                hazard.distance_km
+            );
+        }
+        println!("{}", "(sources: USGS, NASA EONET)".bright_black());
+        println!();
+
+        Ok(())
+    }
+
+    /// Display radar frames around the location, looping through them a few
+    /// times when animations are enabled
+    pub fn show_radar(
+        &self,
+        frames: &[(RadarFrame, RadarImage)],
+        location: &Location,
+    ) -> Result<()> {
+        use crate::modules::radar::{render_radar, MAP_COLUMNS, MAP_ROWS};
+
+        println!(
+            "{}",
+            "╔═══════════════════════════════════════════════════╗".bright_cyan()
+        );
+        println!(
+            "{}",
+            "║            📡 PRECIPITATION RADAR 📡              ║".bright_cyan()
+        );
+        println!(
+            "{}",
+            "╚═══════════════════════════════════════════════════╝".bright_cyan()
+        );
+        println!("◉ {}   (radar data: RainViewer)", location.name.bold());
+        println!();
+
+        let (loops, shown) = if self.animation_enabled {
+            (3, frames)
+        } else {
+            (1, &frames[frames.len().saturating_sub(1)..])
+        };
+
+        for pass in 0..loops {
+            for (index, (frame, image)) in shown.iter().enumerate() {
+                if pass > 0 || index > 0 {
+                    // Redraw the map in place
+                    print!("\x1B[{}A", MAP_ROWS + 1);
+                }
+                for line in render_radar(image, MAP_COLUMNS, MAP_ROWS) {
+                    println!("{}", line);
+                }
+                println!(
+                    "🕒 {}  {}/{}\x1B[K",
+                    format_local_time(&frame.time, location.clock_timezone()),
+                    index + 1,
+                    shown.len()
+                );
+                std::io::Write::flush(&mut std::io::stdout())?;
+                if self.animation_enabled {
+                    sleep(StdDuration::from_millis(500));
+                }
+            }
+        }
+        println!();
+
+        Ok(())
+    }
+
+    /// Print the widget card, uncolored so it can be embedded anywhere
+    pub fn show_widget(&self, widget: &Widget, width: usize, height: usize) -> Result<()> {
+        for line in widget.render(width, height) {
+            println!("{}", line);
+        }
+        Ok(())
+    }
+
+    /// Display the current temperature and condition in large block letters
+    pub fn show_big_display(&self, weather: &CurrentWeather, location: &Location) -> Result<()> {
+        use crate::modules::big::{big_text_width, render_big_text};
+
+        let (_, width) = self.term.size();
+        let width = width as usize;
+        let temp_unit = self.units.temperature;
+        let temp_text = temp_unit.format(weather.temperature);
+        let condition_text = weather.main_condition.to_string().to_uppercase();
+
+        // Pick the largest scale that still fits the terminal
+        let fitting_scale = |text: &str| {
+            [4, 2, 1]
+                .into_iter()
+                .find(|scale| big_text_width(text, *scale) <= width)
+        };
+
+        self.term.clear_screen()?;
+        println!();
+
+        match fitting_scale(&temp_text) {
+            Some(scale) => {
+                let padding = " ".repeat((width - big_text_width(&temp_text, scale)) / 2);
+                for line in render_big_text(&temp_text, scale) {
+                    let line = format!("{}{}", padding, line);
+                    let colored_line = match weather.temperature as i32 {
+                        t if t > 30 => line.red(),
+                        t if t > 20 => line.yellow(),
+                        t if t > 10 => line.green(),
+                        t if t > 0 => line.bright_blue(),
+                        _ => line.blue(),
+                    };
+                    println!("{}", colored_line.bold());
+                }
+            }
+            None => println!("{}", temp_text.bold()),
+        }
+
+        println!();
+
+        match fitting_scale(&condition_text).filter(|scale| *scale <= 2) {
+            Some(scale) => {
+                let padding = " ".repeat((width - big_text_width(&condition_text, scale)) / 2);
+                for line in render_big_text(&condition_text, scale) {
+                    println!("{}{}", padding, line.bright_cyan());
+                }
             }
+            None => println!(
+                "{:^width$}",
+                format!("{} {}", self.icon(weather.main_condition), condition_text),
+                width = width
+            ),
         }
 
         println!();
+        println!(
+            "{:^width$}",
+            format!(
+                "{} · feels like {} · {}",
+                location.name,
+                temp_unit.format(weather.feels_like),
+                format_local_time(&weather.timestamp, location.clock_timezone())
+            ),
+            width = width
+        );
+        println!();
+
+        Ok(())
+    }
+
+    /// Display a wttr.in-style ASCII art report with a day-part outlook
+    pub fn show_art_report(&self, forecast: &Forecast, location: &Location) -> Result<()> {
+        let Some(current) = &forecast.current else {
+            return Err(anyhow::anyhow!("No current weather data available"));
+        };
+
+        println!(
+            "{}",
+            crate::modules::art::render_art_report(
+                location,
+                current,
+                &forecast.hourly,
+                &forecast.daily,
+                3
+            )
+        );
+        println!();
+
         Ok(())
     }
 
     /// Display weather canvas scene in terminal
     pub fn show_weather_canvas_scene(&self, weather: &CurrentWeather) -> Result<()> {
+        use crate::modules::tui::install_panic_hook;
         use crossterm::{
             event::{self, Event, KeyCode, KeyEventKind},
             execute,
@@ -1063,6 +2725,7 @@ impl WeatherUI {
         }
 
         // Setup terminal for canvas display
+        install_panic_hook();
         enable_raw_mode()?;
         let mut stdout = io::stdout();
         execute!(stdout, EnterAlternateScreen)?;
@@ -1079,6 +2742,7 @@ impl WeatherUI {
 
             crate::modules::canvas::render_weather_canvas(
                 &weather.main_condition,
+                weather.intensity,
                 weather.temperature,
                 weather.humidity,
                 weather.wind_speed,
@@ -1216,10 +2880,9 @@ fn format_weekday(date: &DateTime<Utc>) -> String {
     .to_string()
 }
 
-/// Format a date to short form
-fn format_date_short(date: &DateTime<Utc>, timezone: &str) -> String {
-    let local_time = convert_to_local(date, timezone);
-    format!("{}/{}", local_time.month(), local_time.day())
+/// Format a forecast day's date to short form
+fn format_date_short(date: &DateTime<Utc>) -> String {
+    format!("{}/{}", date.month(), date.day())
 }
 
 // Removed unused function
@@ -1248,36 +2911,38 @@ fn format_hour_only(time: &DateTime<Utc>, timezone: &str) -> String {
 
 /// Convert UTC time to local time in the specified timezone
 pub fn convert_to_local(time: &DateTime<Utc>, timezone: &str) -> DateTime<Utc> {
-    // This is a simplified version - in a real app, use a proper timezone library
-    // For now, we'll parse the timezone offset from the timezone string
-    let hours_offset = match timezone {
-        // Common US timezones
-        "America/New_York" | "EST" | "EDT" => -5,
-        "America/Chicago" | "CST" | "CDT" => -6,
-        "America/Denver" | "MST" | "MDT" => -7,
-        "America/Los_Angeles" | "PST" | "PDT" => -8,
-        "America/Anchorage" | "AKST" | "AKDT" => -9,
-        "Pacific/Honolulu" | "HST" => -10,
-        // European timezones
-        "Europe/London" | "GMT" | "BST" => 0,
-        "Europe/Paris" | "Europe/Berlin" | "Europe/Rome" | "CET" | "CEST" => 1,
-        "Europe/Athens" | "Europe/Istanbul" | "EET" | "EEST" => 2,
-        // Asian timezones
-        "Asia/Dubai" => 4,
-        "Asia/Kolkata" | "IST" => 5,
-        "Asia/Shanghai" | "Asia/Singapore" => 8,
-        "Asia/Tokyo" | "JST" => 9,
-        // Australian timezones
-        "Australia/Sydney" | "AEST" | "AEDT" => 10,
-        // Default to UTC if timezone is unknown
-        _ => 0,
-    };
+    // Default to UTC if timezone is unknown
+    *time + utc_offset(timezone, time).unwrap_or_else(chrono::Duration::zero)
+}
+
+/// Offset of an IANA timezone from UTC at the given time, daylight saving
+/// time included; `None` for names chrono-tz does not know
+pub fn utc_offset(timezone: &str, time: &DateTime<Utc>) -> Option<chrono::Duration> {
+    let tz: Tz = timezone.parse().ok()?;
+    let offset = tz.offset_from_utc_datetime(&time.naive_utc()).fix();
+    Some(chrono::Duration::seconds(offset.local_minus_utc() as i64))
+}
 
-    *time + chrono::Duration::hours(hours_offset)
+/// The UTC time of a wall-clock time in the timezone, the inverse of `convert_to_local`
+///
+/// A time skipped by the spring DST change is read with the offset before it.
+pub fn convert_from_local(time: NaiveDateTime, timezone: &str) -> DateTime<Utc> {
+    let Ok(tz) = timezone.parse::<Tz>() else {
+        return time.and_utc();
+    };
+    let hour = chrono::Duration::hours(1);
+    tz.from_local_datetime(&time)
+        .earliest()
+        .map(|local| local.with_timezone(&Utc))
+        .or_else(|| {
+            let earlier = tz.from_local_datetime(&(time - hour)).earliest()?;
+            Some(earlier.with_timezone(&Utc) + hour)
+        })
+        .unwrap_or_else(|| time.and_utc())
 }
 
 /// Get wind direction as an arrow
-fn get_wind_direction_arrow(degrees: u16) -> &'static str {
+pub fn get_wind_direction_arrow(degrees: u16) -> &'static str {
     match degrees {
         337..=360 | 0..=22 => "↓", // N
         23..=67 => "↙",            // NE
@@ -1295,14 +2960,33 @@ fn get_wind_direction_arrow(degrees: u16) -> &'static str {
 // Function has been removed as it's no longer used
 
 /// Create a temperature range bar
-fn get_temp_range_bar(min: f64, max: f64, is_imperial: bool) -> ColoredString {
+/// One line with the provider code and how it was classified, followed by
+/// the raw values when they were kept
+fn print_provider_debug(
+    label: &str,
+    raw_code: Option<u32>,
+    condition: WeatherCondition,
+    intensity: Intensity,
+    extra: Option<&serde_json::Value>,
+) {
+    let code = raw_code.map_or("-".to_string(), |code| code.to_string());
+    println!(
+        "   {:<8} WMO {:<3} → {} ({})",
+        label,
+        code,
+        condition,
+        intensity.to_string().to_lowercase()
+    );
+    if let Some(extra) = extra {
+        println!("            {}", extra.to_string().dimmed());
+    }
+}
+
+/// Bar colored by a day's temperature range in °C
+fn get_temp_range_bar(min: f64, max: f64) -> ColoredString {
     let range = "────────────";
 
-    let (very_cold, cold, mild, _warm, hot) = if is_imperial {
-        (32.0, 50.0, 68.0, 77.0, 86.0)
-    } else {
-        (0.0, 10.0, 20.0, 25.0, 30.0)
-    };
+    let (very_cold, cold, mild, hot) = (0.0, 10.0, 20.0, 30.0);
 
     if max < very_cold {
         range.bright_blue()
@@ -1350,17 +3034,3 @@ impl TitleCase for str {
         self.to_string().to_title_case()
     }
 }
-
-impl WeatherUI {
-    /// Get configuration for the UI
-    fn config(&self) -> WeatherConfig {
-        WeatherConfig {
-            units: "metric".to_string(),
-            location: None,
-            json_output: self.json_output,
-            animation_enabled: self.animation_enabled,
-            detail_level: crate::modules::types::DetailLevel::Standard,
-            no_charts: false,
-        }
-    }
-}
@@ -1 +1,520 @@
-// This module is kept as a placeholder for utility functions
+// Small rendering helpers shared by the text and TUI views
+use crate::modules::ui::{convert_to_local, get_wind_direction_arrow};
+use chrono::{DateTime, Datelike, Timelike, Utc};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// Knots per metre per second
+const KNOTS_PER_MS: f64 = 1.943_844;
+
+/// Pennants, full barbs and half barbs of a wind barb
+///
+/// Speeds are rounded to the nearest 5 knots; a pennant is 50 kt, a full
+/// barb 10 kt and a half barb 5 kt. Below 5 kt the wind is drawn as calm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindBarb {
+    pub pennants: u32,
+    pub full: u32,
+    pub half: u32,
+}
+
+impl WindBarb {
+    /// Build a barb from a wind speed in m/s
+    pub fn from_speed(speed_ms: f64) -> Self {
+        let knots = (speed_ms.max(0.0) * KNOTS_PER_MS / 5.0).round() as u32 * 5;
+        WindBarb {
+            pennants: knots / 50,
+            full: knots % 50 / 10,
+            half: knots % 10 / 5,
+        }
+    }
+
+    /// Whether the wind is below 5 knots
+    pub fn is_calm(&self) -> bool {
+        self.pennants == 0 && self.full == 0 && self.half == 0
+    }
+}
+
+/// Compact text wind barb: flow arrow followed by `▲` pennants, `╱` full and
+/// `╴` half barbs, or `○` when calm
+pub fn wind_barb(speed_ms: f64, direction: u16) -> String {
+    let barb = WindBarb::from_speed(speed_ms);
+    if barb.is_calm() {
+        return "○".to_string();
+    }
+
+    let mut text = get_wind_direction_arrow(direction).to_string();
+    text.extend(std::iter::repeat_n('▲', barb.pennants as usize));
+    text.extend(std::iter::repeat_n('╱', barb.full as usize));
+    text.extend(std::iter::repeat_n('╴', barb.half as usize));
+    text
+}
+
+/// Lower wind speed bounds (m/s) of Beaufort forces 1-12
+const BEAUFORT_BOUNDS: [f64; 12] = [
+    0.5, 1.6, 3.4, 5.5, 8.0, 10.8, 13.9, 17.2, 20.8, 24.5, 28.5, 32.7,
+];
+
+/// Beaufort force (0-12) for a wind speed in m/s
+pub fn beaufort(speed_ms: f64) -> u8 {
+    BEAUFORT_BOUNDS
+        .iter()
+        .take_while(|bound| speed_ms >= **bound)
+        .count() as u8
+}
+
+/// Descriptive label of a Beaufort force, such as "fresh breeze"
+pub fn beaufort_description(force: u8) -> &'static str {
+    match force {
+        0 => "calm",
+        1 => "light air",
+        2 => "light breeze",
+        3 => "gentle breeze",
+        4 => "moderate breeze",
+        5 => "fresh breeze",
+        6 => "strong breeze",
+        7 => "near gale",
+        8 => "gale",
+        9 => "strong gale",
+        10 => "storm",
+        11 => "violent storm",
+        _ => "hurricane force",
+    }
+}
+
+/// Eight-point compass name for a direction in degrees, such as "NE"
+pub fn compass_point(degrees: u16) -> &'static str {
+    const POINTS: [&str; 8] = ["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
+    POINTS[((degrees as usize % 360) * 2 + 45) / 90 % 8]
+}
+
+/// Unit for displaying temperatures, which are stored in °C
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TempUnit {
+    #[default]
+    C,
+    F,
+}
+
+impl TempUnit {
+    /// Convert a temperature in °C to this unit
+    pub fn convert(&self, celsius: f64) -> f64 {
+        match self {
+            TempUnit::C => celsius,
+            TempUnit::F => celsius * 9.0 / 5.0 + 32.0,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TempUnit::C => "°C",
+            TempUnit::F => "°F",
+        }
+    }
+
+    /// Format a temperature in °C to whole degrees, e.g. "72°F"
+    pub fn format(&self, celsius: f64) -> String {
+        format!("{:.0}{}", self.convert(celsius), self.label())
+    }
+}
+
+/// Unit for displaying wind speeds, which are stored in m/s
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum WindUnit {
+    #[default]
+    Ms,
+    Kmh,
+    Mph,
+    Kn,
+}
+
+impl WindUnit {
+    /// Convert a speed in m/s to this unit
+    pub fn convert(&self, speed_ms: f64) -> f64 {
+        match self {
+            WindUnit::Ms => speed_ms,
+            WindUnit::Kmh => speed_ms * 3.6,
+            WindUnit::Mph => speed_ms * 2.236_936,
+            WindUnit::Kn => speed_ms * KNOTS_PER_MS,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            WindUnit::Ms => "m/s",
+            WindUnit::Kmh => "km/h",
+            WindUnit::Mph => "mph",
+            WindUnit::Kn => "kn",
+        }
+    }
+
+    /// Format a speed in m/s, e.g. "12.6 km/h"
+    pub fn format(&self, speed_ms: f64) -> String {
+        format!("{:.1} {}", self.convert(speed_ms), self.label())
+    }
+}
+
+/// Unit for displaying pressures, which are stored in hPa
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum PressureUnit {
+    #[default]
+    Hpa,
+    Inhg,
+    Mmhg,
+}
+
+impl PressureUnit {
+    /// Convert a pressure in hPa to this unit
+    pub fn convert(&self, pressure_hpa: f64) -> f64 {
+        match self {
+            PressureUnit::Hpa => pressure_hpa,
+            PressureUnit::Inhg => pressure_hpa * 0.029_53,
+            PressureUnit::Mmhg => pressure_hpa * 0.750_062,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PressureUnit::Hpa => "hPa",
+            PressureUnit::Inhg => "inHg",
+            PressureUnit::Mmhg => "mmHg",
+        }
+    }
+
+    /// Format a pressure in hPa, e.g. "29.92 inHg"
+    pub fn format(&self, pressure_hpa: f64) -> String {
+        match self {
+            PressureUnit::Inhg => format!("{:.2} {}", self.convert(pressure_hpa), self.label()),
+            _ => format!("{:.0} {}", self.convert(pressure_hpa), self.label()),
+        }
+    }
+}
+
+/// Unit for displaying precipitation amounts, which are stored in mm
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum PrecipUnit {
+    #[default]
+    Mm,
+    In,
+}
+
+impl PrecipUnit {
+    /// Convert an amount in mm to this unit
+    pub fn convert(&self, amount_mm: f64) -> f64 {
+        match self {
+            PrecipUnit::Mm => amount_mm,
+            PrecipUnit::In => amount_mm / 25.4,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PrecipUnit::Mm => "mm",
+            PrecipUnit::In => "in",
+        }
+    }
+
+    /// Format an amount in mm, e.g. "0.12 in"
+    pub fn format(&self, amount_mm: f64) -> String {
+        match self {
+            PrecipUnit::Mm => format!("{:.1} {}", amount_mm, self.label()),
+            PrecipUnit::In => format!("{:.2} {}", self.convert(amount_mm), self.label()),
+        }
+    }
+}
+
+/// Display units for temperature, wind, pressure and precipitation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplayUnits {
+    pub temperature: TempUnit,
+    pub wind: WindUnit,
+    pub pressure: PressureUnit,
+    pub precip: PrecipUnit,
+    pub clock: ClockStyle,
+    pub language: Language,
+}
+
+impl DisplayUnits {
+    /// Defaults for a unit system: °F, mph, inHg and inches for `imperial`,
+    /// °C, m/s, hPa and mm otherwise
+    pub fn for_system(units: &str) -> Self {
+        if units == "imperial" {
+            DisplayUnits {
+                temperature: TempUnit::F,
+                wind: WindUnit::Mph,
+                pressure: PressureUnit::Inhg,
+                precip: PrecipUnit::In,
+                clock: ClockStyle::default(),
+                language: Language::default(),
+            }
+        } else {
+            DisplayUnits::default()
+        }
+    }
+}
+
+/// 24-hour or 12-hour clock times, following the locale
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ClockStyle {
+    #[default]
+    #[serde(rename = "24h")]
+    H24,
+    #[serde(rename = "12h")]
+    H12,
+}
+
+impl ClockStyle {
+    /// The 12-hour clock for locales that use it (en_US, en_CA, en_AU, ...), else 24-hour
+    pub fn for_locale(locale: &str) -> Self {
+        match locale_tag(locale).as_str() {
+            "en_US" | "en_CA" | "en_AU" | "en_NZ" | "en_PH" | "en_IN" | "es_US" | "es_MX"
+            | "hi_IN" => ClockStyle::H12,
+            _ => ClockStyle::H24,
+        }
+    }
+
+    /// From `LC_ALL`, `LC_TIME` or `LANG`, the first one set
+    pub fn from_env() -> Self {
+        env_locale("LC_TIME")
+            .map(|locale| Self::for_locale(&locale))
+            .unwrap_or_default()
+    }
+
+    /// `14:05` or `2:05 PM` for a time already converted to local time
+    pub fn format(self, local: DateTime<Utc>) -> String {
+        match self {
+            ClockStyle::H24 => local.format("%H:%M").to_string(),
+            ClockStyle::H12 => local.format("%-I:%M %p").to_string(),
+        }
+    }
+}
+
+/// `de_DE` for `de_DE.UTF-8`, `de_DE@euro` or `de-DE`
+fn locale_tag(locale: &str) -> String {
+    locale
+        .split(['.', '@'])
+        .next()
+        .unwrap_or("")
+        .replace('-', "_")
+}
+
+/// The locale for a category from `LC_ALL`, the category or `LANG`, the first one set
+fn env_locale(category: &str) -> Option<String> {
+    ["LC_ALL", category, "LANG"]
+        .iter()
+        .find_map(|name| std::env::var(name).ok().filter(|value| !value.is_empty()))
+}
+
+/// Language of the times written in words, following the locale; English
+/// for locales without their own phrases
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    English,
+    German,
+    French,
+    Spanish,
+}
+
+/// Part of the day a time falls in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DayPart {
+    Morning,
+    Afternoon,
+    Evening,
+    Night,
+}
+
+impl Language {
+    pub fn for_locale(locale: &str) -> Self {
+        match locale_tag(locale).split('_').next().unwrap_or("") {
+            "de" => Language::German,
+            "fr" => Language::French,
+            "es" => Language::Spanish,
+            _ => Language::English,
+        }
+    }
+
+    /// From `LC_ALL`, `LC_MESSAGES` or `LANG`, the first one set
+    pub fn from_env() -> Self {
+        env_locale("LC_MESSAGES")
+            .map(|locale| Self::for_locale(&locale))
+            .unwrap_or_default()
+    }
+
+    fn now(self) -> &'static str {
+        match self {
+            Language::English => "now",
+            Language::German => "jetzt",
+            Language::French => "maintenant",
+            Language::Spanish => "ahora",
+        }
+    }
+
+    fn minutes(self, n: i64) -> String {
+        let one = n == 1;
+        match self {
+            Language::English => format!("in {} minute{}", n, if one { "" } else { "s" }),
+            Language::German => format!("in {} Minute{}", n, if one { "" } else { "n" }),
+            Language::French => format!("dans {} minute{}", n, if one { "" } else { "s" }),
+            Language::Spanish => format!("en {} minuto{}", n, if one { "" } else { "s" }),
+        }
+    }
+
+    fn hours(self, n: i64) -> String {
+        let one = n == 1;
+        match self {
+            Language::English => format!("in {} hour{}", n, if one { "" } else { "s" }),
+            Language::German => format!("in {} Stunde{}", n, if one { "" } else { "n" }),
+            Language::French => format!("dans {} heure{}", n, if one { "" } else { "s" }),
+            Language::Spanish => format!("en {} hora{}", n, if one { "" } else { "s" }),
+        }
+    }
+
+    fn part(self, part: DayPart) -> &'static str {
+        use DayPart::*;
+        match (self, part) {
+            (Language::English, Morning) => "morning",
+            (Language::English, Afternoon) => "afternoon",
+            (Language::English, Evening) => "evening",
+            (Language::English, Night) => "night",
+            (Language::German, Morning) => "Morgen",
+            (Language::German, Afternoon) => "Nachmittag",
+            (Language::German, Evening) => "Abend",
+            (Language::German, Night) => "Nacht",
+            (Language::French, Morning) => "matin",
+            (Language::French, Afternoon) => "après-midi",
+            (Language::French, Evening) => "soir",
+            (Language::French, Night) => "nuit",
+            (Language::Spanish, Morning) => "mañana",
+            (Language::Spanish, Afternoon | Evening) => "tarde",
+            (Language::Spanish, Night) => "noche",
+        }
+    }
+
+    /// "this evening", "tonight"
+    fn today(self, part: DayPart) -> String {
+        match (self, part) {
+            (Language::English, DayPart::Night) => "tonight".to_string(),
+            (Language::English, _) => format!("this {}", self.part(part)),
+            (Language::German, _) => format!("heute {}", self.part(part)),
+            (Language::French, DayPart::Afternoon) => "cet après-midi".to_string(),
+            (Language::French, DayPart::Night) => "cette nuit".to_string(),
+            (Language::French, _) => format!("ce {}", self.part(part)),
+            (Language::Spanish, _) => format!("esta {}", self.part(part)),
+        }
+    }
+
+    /// "tomorrow morning"
+    fn tomorrow(self, part: DayPart) -> String {
+        match (self, part) {
+            (Language::English, _) => format!("tomorrow {}", self.part(part)),
+            (Language::German, DayPart::Morning) => "morgen früh".to_string(),
+            (Language::German, _) => format!("morgen {}", self.part(part)),
+            (Language::French, _) => format!("demain {}", self.part(part)),
+            (Language::Spanish, _) => format!("mañana por la {}", self.part(part)),
+        }
+    }
+
+    /// "Saturday afternoon"
+    fn weekday(self, local: DateTime<Utc>, part: DayPart) -> String {
+        let index = local.weekday().num_days_from_monday() as usize;
+        match self {
+            Language::English => format!("{} {}", local.format("%A"), self.part(part)),
+            // One word: "Samstagnachmittag"
+            Language::German => {
+                let names = [
+                    "Montag",
+                    "Dienstag",
+                    "Mittwoch",
+                    "Donnerstag",
+                    "Freitag",
+                    "Samstag",
+                    "Sonntag",
+                ];
+                format!("{}{}", names[index], self.part(part).to_lowercase())
+            }
+            Language::French => {
+                let names = [
+                    "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+                ];
+                format!("{} {}", names[index], self.part(part))
+            }
+            Language::Spanish => {
+                let names = [
+                    "lunes",
+                    "martes",
+                    "miércoles",
+                    "jueves",
+                    "viernes",
+                    "sábado",
+                    "domingo",
+                ];
+                format!("el {} por la {}", names[index], self.part(part))
+            }
+        }
+    }
+
+    /// "on Mon 12 Jun", for times a week or more away
+    fn date(self, local: DateTime<Utc>) -> String {
+        match self {
+            Language::English => format!("on {}", local.format("%a %-d %b")),
+            Language::German => format!("am {}", local.format("%-d.%-m.")),
+            Language::French => format!("le {}", local.format("%-d/%m")),
+            Language::Spanish => format!("el {}", local.format("%-d/%m")),
+        }
+    }
+}
+
+/// A time in words, relative to now: "in 20 minutes", "in 3 hours", "this
+/// evening", "tomorrow morning", "Saturday afternoon" or "on Mon 12 Jun",
+/// in the given language
+pub fn relative_time(
+    time: DateTime<Utc>,
+    now: DateTime<Utc>,
+    timezone: &str,
+    language: Language,
+) -> String {
+    let minutes = (time - now).num_minutes();
+    if minutes <= 0 {
+        return language.now().to_string();
+    }
+    if minutes < 60 {
+        return language.minutes(minutes);
+    }
+    if minutes < 6 * 60 {
+        return language.hours((minutes + 30) / 60);
+    }
+
+    let local = convert_to_local(&time, timezone);
+    let today = convert_to_local(&now, timezone).date_naive();
+    let part = match local.hour() {
+        5..=11 => DayPart::Morning,
+        12..=16 => DayPart::Afternoon,
+        17..=20 => DayPart::Evening,
+        _ => DayPart::Night,
+    };
+    match (local.date_naive() - today).num_days() {
+        0 => language.today(part),
+        // The small hours still belong to tonight
+        1 if local.hour() < 5 => language.today(DayPart::Night),
+        1 => language.tomorrow(part),
+        2..=6 => language.weekday(local, part),
+        _ => language.date(local),
+    }
+}
+
+/// Serialize JSON output, on one line with `--json-compact`
+pub fn to_json<T: Serialize + ?Sized>(value: &T, compact: bool) -> serde_json::Result<String> {
+    if compact {
+        serde_json::to_string(value)
+    } else {
+        serde_json::to_string_pretty(value)
+    }
+}
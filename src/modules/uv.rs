@@ -0,0 +1,125 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
+use crate::modules::types::HourlyForecast;
+
+/// How much sun protection an hour needs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UvLevel {
+    Low,
+    Protect,
+    Avoid,
+}
+
+/// A span of time during which the UV index stays at or above a threshold
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UvWindow {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+/// One hour of the timeline
+#[derive(Debug, Clone, Serialize)]
+pub struct UvHour {
+    pub timestamp: DateTime<Utc>,
+    pub uv_index: f64,
+    pub level: UvLevel,
+}
+
+/// Sun protection advice for one day
+#[derive(Debug, Clone, Serialize)]
+pub struct UvPlan {
+    pub protect_threshold: f64,
+    pub avoid_threshold: f64,
+    /// When sunscreen is recommended
+    pub protect: Vec<UvWindow>,
+    /// When direct sun is best avoided
+    pub avoid: Vec<UvWindow>,
+    pub peak: Option<UvHour>,
+    pub hours: Vec<UvHour>,
+}
+
+/// Plan sun protection from hourly UV values
+///
+/// Values are interpolated linearly between hours, so windows start and end
+/// at the minute the UV index is expected to cross a threshold. Hours without
+/// a UV value are left out.
+pub fn uv_plan(hourly: &[HourlyForecast], protect: f64, avoid: f64) -> UvPlan {
+    let level = |uv: f64| match uv {
+        uv if uv >= avoid => UvLevel::Avoid,
+        uv if uv >= protect => UvLevel::Protect,
+        _ => UvLevel::Low,
+    };
+    let hours: Vec<UvHour> = hourly
+        .iter()
+        .filter_map(|hour| {
+            let uv_index = hour.uv_index?;
+            Some(UvHour {
+                timestamp: hour.timestamp,
+                uv_index,
+                level: level(uv_index),
+            })
+        })
+        .collect();
+
+    let peak = hours
+        .iter()
+        .filter(|hour| hour.uv_index > 0.0)
+        .max_by(|a, b| a.uv_index.total_cmp(&b.uv_index))
+        .cloned();
+
+    UvPlan {
+        protect_threshold: protect,
+        avoid_threshold: avoid,
+        protect: windows_above(&hours, protect),
+        avoid: windows_above(&hours, avoid),
+        peak,
+        hours,
+    }
+}
+
+/// Spans where the interpolated UV index is at or above `threshold`
+pub fn windows_above(hours: &[UvHour], threshold: f64) -> Vec<UvWindow> {
+    let mut windows = Vec::new();
+    let mut start = None;
+
+    for (index, hour) in hours.iter().enumerate() {
+        let above = hour.uv_index >= threshold;
+        match (start, above) {
+            (None, true) => {
+                start = Some(match index.checked_sub(1).map(|i| &hours[i]) {
+                    Some(previous) => crossing(previous, hour, threshold),
+                    None => hour.timestamp,
+                });
+            }
+            (Some(from), false) => {
+                windows.push(UvWindow {
+                    start: from,
+                    end: crossing(&hours[index - 1], hour, threshold),
+                });
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let (Some(from), Some(last)) = (start, hours.last()) {
+        windows.push(UvWindow {
+            start: from,
+            end: last.timestamp + Duration::hours(1),
+        });
+    }
+    windows
+}
+
+/// Minute between two hours at which the UV index reaches `threshold`
+fn crossing(from: &UvHour, to: &UvHour, threshold: f64) -> DateTime<Utc> {
+    let span = to.uv_index - from.uv_index;
+    let fraction = if span == 0.0 {
+        0.0
+    } else {
+        ((threshold - from.uv_index) / span).clamp(0.0, 1.0)
+    };
+    let minutes = ((to.timestamp - from.timestamp).num_minutes() as f64 * fraction).round();
+    from.timestamp + Duration::minutes(minutes as i64)
+}
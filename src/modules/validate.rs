@@ -0,0 +1,208 @@
+use crate::modules::types::{CurrentWeather, DailyForecast, Forecast, HourlyForecast, Percent};
+
+/// Plausible air temperatures in °C; anything outside is an API glitch
+const TEMP_RANGE: (f64, f64) = (-90.0, 60.0);
+/// Plausible wind speeds in m/s
+const WIND_RANGE: (f64, f64) = (0.0, 120.0);
+
+/// Sanity-check a parsed forecast, clamping implausible values and dropping
+/// out-of-order entries
+///
+/// Returns a description of every anomaly that was corrected.
+pub fn validate_forecast(forecast: &mut Forecast) -> Vec<String> {
+    let mut anomalies = Vec::new();
+    if let Some(current) = forecast.current.as_mut() {
+        anomalies.extend(validate_current(current));
+    }
+    anomalies.extend(validate_hourly(&mut forecast.hourly));
+    anomalies.extend(validate_daily(&mut forecast.daily));
+    anomalies
+}
+
+/// Clamp implausible current-weather values
+pub fn validate_current(weather: &mut CurrentWeather) -> Vec<String> {
+    let mut anomalies = Vec::new();
+    let context = "current";
+    clamp(
+        &mut weather.temperature,
+        TEMP_RANGE,
+        context,
+        "temperature",
+        &mut anomalies,
+    );
+    clamp(
+        &mut weather.feels_like,
+        TEMP_RANGE,
+        context,
+        "feels-like temperature",
+        &mut anomalies,
+    );
+    clamp(
+        &mut weather.wind_speed,
+        WIND_RANGE,
+        context,
+        "wind speed",
+        &mut anomalies,
+    );
+    clamp(
+        &mut weather.uv_index,
+        (0.0, 20.0),
+        context,
+        "UV index",
+        &mut anomalies,
+    );
+    clamp_percent(&mut weather.humidity, context, "humidity", &mut anomalies);
+    clamp_percent(&mut weather.clouds, context, "cloud cover", &mut anomalies);
+    anomalies
+}
+
+/// Clamp implausible hourly values and drop hours that are not after the previous one
+pub fn validate_hourly(hourly_data: &mut Vec<HourlyForecast>) -> Vec<String> {
+    let mut anomalies = Vec::new();
+
+    let mut previous = None;
+    hourly_data.retain(|hour| {
+        let in_order = previous.is_none_or(|prev| hour.timestamp > prev);
+        if in_order {
+            previous = Some(hour.timestamp);
+        } else {
+            anomalies.push(format!(
+                "hourly {}: out of order, dropped",
+                hour.timestamp.format("%Y-%m-%d %H:%M")
+            ));
+        }
+        in_order
+    });
+
+    for hour in hourly_data.iter_mut() {
+        let context = format!("hourly {}", hour.timestamp.format("%Y-%m-%d %H:%M"));
+        clamp(
+            &mut hour.temperature,
+            TEMP_RANGE,
+            &context,
+            "temperature",
+            &mut anomalies,
+        );
+        clamp(
+            &mut hour.feels_like,
+            TEMP_RANGE,
+            &context,
+            "feels-like temperature",
+            &mut anomalies,
+        );
+        clamp(
+            &mut hour.wind_speed,
+            WIND_RANGE,
+            &context,
+            "wind speed",
+            &mut anomalies,
+        );
+        clamp(
+            &mut hour.pop,
+            (0.0, 1.0),
+            &context,
+            "precipitation chance",
+            &mut anomalies,
+        );
+        clamp_percent(&mut hour.humidity, &context, "humidity", &mut anomalies);
+        clamp_percent(&mut hour.clouds, &context, "cloud cover", &mut anomalies);
+    }
+
+    anomalies
+}
+
+/// Clamp implausible daily values, fix swapped minimum and maximum and drop
+/// days that are not after the previous one
+pub fn validate_daily(daily_data: &mut Vec<DailyForecast>) -> Vec<String> {
+    let mut anomalies = Vec::new();
+
+    let mut previous = None;
+    daily_data.retain(|day| {
+        let in_order = previous.is_none_or(|prev| day.date > prev);
+        if in_order {
+            previous = Some(day.date);
+        } else {
+            anomalies.push(format!(
+                "daily {}: out of order, dropped",
+                day.date.format("%Y-%m-%d")
+            ));
+        }
+        in_order
+    });
+
+    for day in daily_data.iter_mut() {
+        let context = format!("daily {}", day.date.format("%Y-%m-%d"));
+        clamp(
+            &mut day.temp_max,
+            TEMP_RANGE,
+            &context,
+            "maximum temperature",
+            &mut anomalies,
+        );
+        clamp(
+            &mut day.temp_min,
+            TEMP_RANGE,
+            &context,
+            "minimum temperature",
+            &mut anomalies,
+        );
+        if day.temp_min > day.temp_max {
+            anomalies.push(format!(
+                "{}: minimum {:.1}°C above maximum {:.1}°C, swapped",
+                context, day.temp_min, day.temp_max
+            ));
+            std::mem::swap(&mut day.temp_min, &mut day.temp_max);
+        }
+        clamp(
+            &mut day.wind_speed,
+            WIND_RANGE,
+            &context,
+            "wind speed",
+            &mut anomalies,
+        );
+        clamp(
+            &mut day.pop,
+            (0.0, 1.0),
+            &context,
+            "precipitation chance",
+            &mut anomalies,
+        );
+        clamp(
+            &mut day.uv_index,
+            (0.0, 20.0),
+            &context,
+            "UV index",
+            &mut anomalies,
+        );
+        clamp_percent(&mut day.humidity, &context, "humidity", &mut anomalies);
+        clamp_percent(&mut day.clouds, &context, "cloud cover", &mut anomalies);
+    }
+
+    anomalies
+}
+
+/// Clamp a value into `range`, replacing non-finite values with the lower bound
+fn clamp(
+    value: &mut f64,
+    range: (f64, f64),
+    context: &str,
+    name: &str,
+    anomalies: &mut Vec<String>,
+) {
+    let clamped = if value.is_finite() {
+        value.clamp(range.0, range.1)
+    } else {
+        range.0
+    };
+    if clamped != *value {
+        anomalies.push(format!(
+            "{}: {} {} out of range, clamped to {}",
+            context, name, value, clamped
+        ));
+        *value = clamped;
+    }
+}
+
+fn clamp_percent(value: &mut Percent, context: &str, name: &str, anomalies: &mut Vec<String>) {
+    clamp(&mut value.0, (0.0, 100.0), context, name, anomalies);
+}
@@ -0,0 +1,82 @@
+use crate::modules::config::WarningSettings;
+use crate::modules::types::{DailyForecast, Location, WeatherAlert};
+use crate::modules::ui::convert_to_local;
+use chrono::{Duration, NaiveDate};
+
+/// Frost and heatwave warnings for runs of consecutive days in the daily forecast
+///
+/// Frost needs `frost_nights` nights in a row with a minimum below
+/// `frost_below`; a heatwave needs `heat_days` days in a row with a maximum
+/// above `heat_above`. Each run becomes one alert spanning those days.
+pub fn multi_day_warnings(
+    daily_data: &[DailyForecast],
+    settings: &WarningSettings,
+) -> Vec<WeatherAlert> {
+    let mut alerts = Vec::new();
+
+    for run in runs(daily_data, |day| day.temp_min < settings.frost_below) {
+        if run.len() >= settings.frost_nights {
+            let lowest = run.iter().map(|d| d.temp_min).fold(f64::MAX, f64::min);
+            alerts.push(alert(
+                "Frost warning",
+                run,
+                format!(
+                    "{} consecutive nights below {:.0}°C, lowest {:.0}°C",
+                    run.len(),
+                    settings.frost_below,
+                    lowest
+                ),
+                "frost",
+            ));
+        }
+    }
+
+    for run in runs(daily_data, |day| day.temp_max > settings.heat_above) {
+        if run.len() >= settings.heat_days {
+            let highest = run.iter().map(|d| d.temp_max).fold(f64::MIN, f64::max);
+            alerts.push(alert(
+                "Heatwave warning",
+                run,
+                format!(
+                    "{} consecutive days above {:.0}°C, highest {:.0}°C",
+                    run.len(),
+                    settings.heat_above,
+                    highest
+                ),
+                "heat",
+            ));
+        }
+    }
+
+    alerts.sort_by_key(|alert| alert.start);
+    alerts
+}
+
+/// Whether a local date falls inside an alert
+pub fn alert_covers(alert: &WeatherAlert, date: NaiveDate, location: &Location) -> bool {
+    let start = convert_to_local(&alert.start, &location.timezone).date_naive();
+    let end = convert_to_local(&alert.end, &location.timezone).date_naive();
+    date >= start && date < end
+}
+
+/// Maximal runs of consecutive days matching a predicate
+fn runs(
+    daily_data: &[DailyForecast],
+    matches: impl Fn(&DailyForecast) -> bool,
+) -> Vec<&[DailyForecast]> {
+    daily_data
+        .split(|day| !matches(day))
+        .filter(|run| !run.is_empty())
+        .collect()
+}
+
+fn alert(event: &str, run: &[DailyForecast], description: String, tag: &str) -> WeatherAlert {
+    WeatherAlert {
+        sender: "weather_man".to_string(),
+        event: event.to_string(),
+        start: run[0].date,
+        end: run[run.len() - 1].date + Duration::days(1),
+        description,
+        tags: vec![tag.to_string()],
+    }
+}
@@ -0,0 +1,57 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::modules::status::status_from_forecast;
+use crate::modules::types::{Forecast, Location};
+
+/// Days listed in the tooltip
+pub const TOOLTIP_DAYS: usize = 3;
+
+/// The object a waybar `custom` module with `"return-type": "json"` reads
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WaybarOutput {
+    pub text: String,
+    pub tooltip: String,
+    /// CSS class: the condition (`clear`, `rain`, ...) or `error`
+    pub class: String,
+}
+
+/// Bar text, a tooltip with the next days and the condition as class
+pub fn waybar_output(
+    forecast: &Forecast,
+    location: &Location,
+    now: DateTime<Utc>,
+) -> Option<WaybarOutput> {
+    let status = status_from_forecast(forecast, location, now)?;
+    let condition = forecast.current.as_ref()?.main_condition;
+
+    let mut tooltip = format!(
+        "{}: {}, {:.0}° (feels {:.0}°)",
+        location.name, status.condition, status.temperature, status.feels_like
+    );
+    for day in forecast.daily.iter().take(TOOLTIP_DAYS) {
+        tooltip.push_str(&format!(
+            "\n{}  {} {:.0}° / {:.0}°  {:.0}%",
+            day.date.format("%a"),
+            day.main_condition.get_emoji(),
+            day.temp_min,
+            day.temp_max,
+            day.pop * 100.0
+        ));
+    }
+
+    Some(WaybarOutput {
+        text: status.text,
+        tooltip,
+        class: format!("{:?}", condition).to_lowercase(),
+    })
+}
+
+/// Shown in the bar instead of the weather when it can't be fetched
+pub fn waybar_error(error: &str) -> WaybarOutput {
+    WaybarOutput {
+        text: "⚠️".to_string(),
+        tooltip: error.to_string(),
+        class: "error".to_string(),
+    }
+}
@@ -0,0 +1,96 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::modules::icons::IconSet;
+use crate::modules::status::status_from_forecast;
+use crate::modules::table::{pad, Align};
+use crate::modules::types::{Forecast, Location};
+
+/// Default card size in terminal columns and rows
+pub const WIDGET_WIDTH: usize = 30;
+pub const WIDGET_HEIGHT: usize = 10;
+/// Smallest card that still fits the temperature and a border
+pub const WIDGET_MIN_WIDTH: usize = 12;
+pub const WIDGET_MIN_HEIGHT: usize = 3;
+
+/// What the widget card shows
+#[derive(Debug, Clone, Serialize)]
+pub struct Widget {
+    pub location: String,
+    pub icon: String,
+    pub condition: String,
+    pub temperature: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub high: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub low: Option<f64>,
+    /// Chance of rain today (0-1), or in the next hour without a daily forecast
+    pub rain_chance: f64,
+}
+
+/// Build the widget from a forecast; `None` without current conditions
+pub fn widget_from_forecast(
+    forecast: &Forecast,
+    location: &Location,
+    icons: IconSet,
+    now: DateTime<Utc>,
+) -> Option<Widget> {
+    let status = status_from_forecast(forecast, location, now)?;
+    let condition = forecast.current.as_ref()?.main_condition;
+    Some(Widget {
+        location: status.location,
+        icon: icons.condition(condition).to_string(),
+        condition: status.condition,
+        temperature: status.temperature,
+        high: status.today_max,
+        low: status.today_min,
+        rain_chance: forecast.daily.first().map_or(status.pop, |d| d.pop),
+    })
+}
+
+impl Widget {
+    /// Render a bordered card of exactly `width` columns by `height` rows
+    ///
+    /// Lines that do not fit are dropped from the bottom, spacing first.
+    pub fn render(&self, width: usize, height: usize) -> Vec<String> {
+        let width = width.max(WIDGET_MIN_WIDTH);
+        let height = height.max(WIDGET_MIN_HEIGHT);
+        let inner = width - 4;
+        let rows = height - 2;
+
+        let mut lines = vec![
+            Some(format!("{} {:.0}°", self.icon, self.temperature)),
+            Some(self.location.clone()),
+            None,
+            Some(self.condition.clone()),
+            None,
+        ];
+        if let (Some(high), Some(low)) = (self.high, self.low) {
+            lines.push(Some(format!("↑ {:.0}°  ↓ {:.0}°", high, low)));
+        }
+        lines.push(Some(format!("☔ {:.0}%", self.rain_chance * 100.0)));
+
+        while lines.len() > rows {
+            match lines.iter().rposition(Option::is_none) {
+                Some(blank) => lines.remove(blank),
+                None => lines.pop().flatten(),
+            };
+        }
+        let top = (rows - lines.len()) / 2;
+        let body = std::iter::repeat_n(None, top)
+            .chain(lines)
+            .chain(std::iter::repeat(None))
+            .take(rows)
+            .map(|line| {
+                format!(
+                    "│ {} │",
+                    pad(line.as_deref().unwrap_or(""), inner, Align::Left)
+                )
+            });
+
+        std::iter::once(format!("╭{}╮", "─".repeat(width - 2)))
+            .chain(body)
+            .chain(std::iter::once(format!("╰{}╯", "─".repeat(width - 2))))
+            .collect()
+    }
+}
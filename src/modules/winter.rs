@@ -0,0 +1,103 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::Serialize;
+
+use crate::modules::types::{HourlyForecast, Location, WeatherCondition};
+use crate::modules::ui::convert_to_local;
+
+/// Snow usually reaches the ground this far (m) below the 0 °C level
+pub const SNOW_LEVEL_OFFSET: f64 = 300.0;
+/// Hours ahead checked for snow
+pub const WINTER_HOURS: usize = 72;
+/// Snow depth (cm) that counts as a snow cover
+const COVER_CM: f64 = 1.0;
+
+/// Lowest altitude (m) snow is expected to reach for a freezing level
+pub fn snowfall_level(freezing_level: f64) -> f64 {
+    (freezing_level - SNOW_LEVEL_OFFSET).max(0.0)
+}
+
+/// Snow and freezing level for one local day
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WinterDay {
+    pub date: NaiveDate,
+    /// Fresh snow (cm)
+    pub snowfall: f64,
+    /// Deepest snow on the ground (cm)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snow_depth: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_freezing_level: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_snowfall_level: Option<f64>,
+}
+
+/// Snow on the ground and in the forecast for the next `WINTER_HOURS` hours
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WinterReport {
+    /// Snow on the ground now (cm)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snow_depth: Option<f64>,
+    /// Fresh snow over all the hours (cm)
+    pub snowfall: f64,
+    /// First hour with snow falling
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_snow: Option<DateTime<Utc>>,
+    pub days: Vec<WinterDay>,
+}
+
+fn snowing(hour: &HourlyForecast) -> bool {
+    hour.snow.unwrap_or(0.0) > 0.0 || hour.main_condition == WeatherCondition::Snow
+}
+
+fn min(values: impl Iterator<Item = f64>) -> Option<f64> {
+    values.min_by(f64::total_cmp)
+}
+
+/// The winter report, or `None` when no snow is falling or lying in the next
+/// `WINTER_HOURS` hours
+pub fn winter_report(
+    hourly: &[HourlyForecast],
+    location: &Location,
+    now: DateTime<Utc>,
+) -> Option<WinterReport> {
+    let hours: Vec<&HourlyForecast> = hourly
+        .iter()
+        .filter(|h| h.timestamp + Duration::hours(1) > now)
+        .take(WINTER_HOURS)
+        .collect();
+    let first_snow = hours.iter().find(|h| snowing(h)).map(|h| h.timestamp);
+    let snow_depth = hours.first().and_then(|h| h.snow_depth);
+    if first_snow.is_none() && snow_depth.unwrap_or(0.0) < COVER_CM {
+        return None;
+    }
+
+    let local_date =
+        |h: &HourlyForecast| convert_to_local(&h.timestamp, &location.timezone).date_naive();
+    let mut dates: Vec<NaiveDate> = hours.iter().map(|h| local_date(h)).collect();
+    dates.dedup();
+    let days = dates
+        .into_iter()
+        .map(|date| {
+            let day: Vec<&&HourlyForecast> =
+                hours.iter().filter(|h| local_date(h) == date).collect();
+            let min_freezing_level = min(day.iter().filter_map(|h| h.freezing_level));
+            WinterDay {
+                date,
+                snowfall: day.iter().filter_map(|h| h.snow).sum(),
+                snow_depth: day
+                    .iter()
+                    .filter_map(|h| h.snow_depth)
+                    .max_by(f64::total_cmp),
+                min_freezing_level,
+                min_snowfall_level: min_freezing_level.map(snowfall_level),
+            }
+        })
+        .collect();
+
+    Some(WinterReport {
+        snow_depth,
+        snowfall: hours.iter().filter_map(|h| h.snow).sum(),
+        first_snow,
+        days,
+    })
+}
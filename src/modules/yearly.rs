@@ -0,0 +1,207 @@
+use chrono::{Datelike, NaiveDate};
+use serde::Serialize;
+
+use crate::modules::climate::ClimateDay;
+
+/// Base temperature (°C) for heating and cooling degree days
+pub const DEGREE_DAY_BASE: f64 = 18.0;
+
+/// A day that stood out, with its value
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct DayValue {
+    pub date: NaiveDate,
+    pub value: f64,
+}
+
+/// Precipitation total (mm) for one month
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct MonthTotal {
+    pub month: u32,
+    pub precipitation: f64,
+}
+
+/// Summary of a year of daily observations
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct YearReport {
+    pub year: i32,
+    /// First and last day with data, the year so far for the current year
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub mean_temperature: f64,
+    pub hottest_day: DayValue,
+    pub coldest_day: DayValue,
+    pub wettest_day: DayValue,
+    pub wettest_month: MonthTotal,
+    pub driest_month: MonthTotal,
+    pub total_precipitation: f64,
+    /// Days with at least 1 mm of precipitation
+    pub wet_days: usize,
+    pub heating_degree_days: f64,
+    pub cooling_degree_days: f64,
+}
+
+/// Summarize the days of `year`, ignoring any others
+pub fn year_report(days: &[ClimateDay], year: i32) -> Option<YearReport> {
+    let days: Vec<&ClimateDay> = days.iter().filter(|d| d.date.year() == year).collect();
+
+    let extreme = |value: fn(&ClimateDay) -> Option<f64>, highest: bool| {
+        days.iter()
+            .filter_map(|d| {
+                value(d).map(|v| DayValue {
+                    date: d.date,
+                    value: v,
+                })
+            })
+            .reduce(|best, d| {
+                if (highest && d.value > best.value) || (!highest && d.value < best.value) {
+                    d
+                } else {
+                    best
+                }
+            })
+    };
+
+    // Daily means from the max and min, as the archive's own mean would
+    // need another request
+    let means: Vec<f64> = days
+        .iter()
+        .filter_map(|d| Some((d.temp_max? + d.temp_min?) / 2.0))
+        .collect();
+    if means.is_empty() {
+        return None;
+    }
+
+    let months: Vec<MonthTotal> = (1..=12)
+        .filter_map(|month| {
+            let amounts: Vec<f64> = days
+                .iter()
+                .filter(|d| d.date.month() == month)
+                .filter_map(|d| d.precipitation)
+                .collect();
+            (!amounts.is_empty()).then(|| MonthTotal {
+                month,
+                precipitation: amounts.iter().sum(),
+            })
+        })
+        .collect();
+    let by_precipitation =
+        |a: &&MonthTotal, b: &&MonthTotal| a.precipitation.total_cmp(&b.precipitation);
+
+    Some(YearReport {
+        year,
+        start: days.first()?.date,
+        end: days.last()?.date,
+        mean_temperature: means.iter().sum::<f64>() / means.len() as f64,
+        hottest_day: extreme(|d| d.temp_max, true)?,
+        coldest_day: extreme(|d| d.temp_min, false)?,
+        wettest_day: extreme(|d| d.precipitation, true)?,
+        wettest_month: *months.iter().max_by(by_precipitation)?,
+        driest_month: *months.iter().min_by(by_precipitation)?,
+        total_precipitation: months.iter().map(|m| m.precipitation).sum(),
+        wet_days: days
+            .iter()
+            .filter(|d| d.precipitation.is_some_and(|p| p >= 1.0))
+            .count(),
+        heating_degree_days: means.iter().map(|t| (DEGREE_DAY_BASE - t).max(0.0)).sum(),
+        cooling_degree_days: means.iter().map(|t| (t - DEGREE_DAY_BASE).max(0.0)).sum(),
+    })
+}
+
+/// English name of a month number
+pub fn month_name(month: u32) -> String {
+    NaiveDate::from_ymd_opt(2000, month, 1)
+        .map(|date| date.format("%B").to_string())
+        .unwrap_or_default()
+}
+
+/// The report as plain text lines
+pub fn report_lines(report: &YearReport, location: &str) -> Vec<String> {
+    let day = |d: &DayValue| d.date.format("%-d %B").to_string();
+    vec![
+        format!("{} in {}", location, report.year),
+        format!(
+            "Period:           {} to {}",
+            report.start.format("%Y-%m-%d"),
+            report.end.format("%Y-%m-%d")
+        ),
+        format!("Mean temperature: {:.1}°C", report.mean_temperature),
+        format!(
+            "Hottest day:      {} ({:.1}°C)",
+            day(&report.hottest_day),
+            report.hottest_day.value
+        ),
+        format!(
+            "Coldest day:      {} ({:.1}°C)",
+            day(&report.coldest_day),
+            report.coldest_day.value
+        ),
+        format!(
+            "Wettest day:      {} ({:.1} mm)",
+            day(&report.wettest_day),
+            report.wettest_day.value
+        ),
+        format!(
+            "Wettest month:    {} ({:.0} mm)",
+            month_name(report.wettest_month.month),
+            report.wettest_month.precipitation
+        ),
+        format!(
+            "Driest month:     {} ({:.0} mm)",
+            month_name(report.driest_month.month),
+            report.driest_month.precipitation
+        ),
+        format!(
+            "Precipitation:    {:.0} mm on {} wet days",
+            report.total_precipitation, report.wet_days
+        ),
+        format!(
+            "Degree days:      {:.0} heating, {:.0} cooling (base {:.0}°C)",
+            report.heating_degree_days, report.cooling_degree_days, DEGREE_DAY_BASE
+        ),
+    ]
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A standalone HTML page with the report
+pub fn report_html(report: &YearReport, location: &str) -> String {
+    let lines = report_lines(report, location);
+    let rows: String = lines[1..]
+        .iter()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| {
+            format!(
+                "      <tr><th>{}</th><td>{}</td></tr>\n",
+                escape_html(name),
+                escape_html(value.trim())
+            )
+        })
+        .collect();
+    format!(
+        "<!DOCTYPE html>
+<html lang=\"en\">
+<head>
+  <meta charset=\"utf-8\">
+  <title>{title}</title>
+  <style>
+    body {{ font-family: sans-serif; margin: 2em; }}
+    th {{ text-align: left; padding-right: 2em; }}
+  </style>
+</head>
+<body>
+  <h1>{title}</h1>
+  <table>
+{rows}  </table>
+  <p><small>Data: Open-Meteo historical weather</small></p>
+</body>
+</html>
+",
+        title = escape_html(&lines[0]),
+        rows = rows
+    )
+}
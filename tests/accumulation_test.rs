@@ -0,0 +1,58 @@
+mod common;
+
+use chrono::{Duration, TimeZone, Utc};
+use common::{location, series};
+use weather_man::modules::accumulation::{accumulation, accumulation_text};
+use weather_man::modules::utils::PrecipUnit;
+
+#[test]
+fn test_accumulation_windows() {
+    // An hour of history, then rain from 14:00 to 20:00 and a little at 22:00
+    let mut rain = vec![0.0; 36];
+    rain[0] = 5.0;
+    for amount in rain.iter_mut().take(20).skip(14) {
+        *amount = 1.5;
+    }
+    rain[22] = 2.0;
+    let mut hours = series(&rain, |h, mm| h.rain = Some(mm));
+    hours[20].snow = Some(1.0);
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 1, 30, 0).unwrap();
+
+    let result = accumulation(&hours, now).unwrap();
+    let totals: Vec<(usize, f64)> = result.windows.iter().map(|w| (w.hours, w.total)).collect();
+    // From 01:00: 6h ends at 07:00, 12h at 13:00, 24h at 01:00 tomorrow
+    assert_eq!(totals, vec![(6, 0.0), (12, 0.0), (24, 12.0)]);
+    assert_eq!(result.longest().unwrap().snow, 1.0);
+
+    let peak = result.peak.unwrap();
+    assert_eq!(
+        peak.start,
+        Utc.with_ymd_and_hms(2024, 6, 1, 14, 0, 0).unwrap()
+    );
+    assert_eq!(
+        peak.end,
+        Utc.with_ymd_and_hms(2024, 6, 1, 20, 0, 0).unwrap()
+    );
+    assert_eq!(peak.total, 9.0);
+
+    assert_eq!(
+        accumulation_text(&result, &location(), PrecipUnit::Mm).unwrap(),
+        "12.0 mm expected in the next 24h (9.0 mm between 14:00–20:00)"
+    );
+}
+
+#[test]
+fn test_accumulation_dry_and_empty() {
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+    let dry = series(&[0.0; 20], |h, mm| h.rain = Some(mm));
+    let result = accumulation(&dry, now).unwrap();
+    assert!(result.peak.is_none());
+    assert_eq!(
+        accumulation_text(&result, &location(), PrecipUnit::Mm).unwrap(),
+        "No precipitation expected in the next 24h"
+    );
+
+    let later = now + Duration::days(2);
+    let wet = series(&[1.0; 24], |h, mm| h.rain = Some(mm));
+    assert!(accumulation(&wet, later).is_none());
+}
@@ -0,0 +1,99 @@
+use chrono::{Duration, TimeZone, Utc};
+use weather_man::modules::accuracy::{
+    accuracy_entries, accuracy_report, AccuracyEntry, AccuracyLog, LEAD_HOURS,
+};
+use weather_man::modules::forecaster::WeatherForecaster;
+use weather_man::modules::http::HttpContext;
+use weather_man::modules::types::WeatherConfig;
+
+fn forecast(provider: &str, hour: u32, temperature: f64, pop: f64) -> AccuracyEntry {
+    let valid = Utc.with_ymd_and_hms(2024, 6, 1, hour, 0, 0).unwrap();
+    AccuracyEntry::Forecast {
+        provider: provider.to_string(),
+        location: "Berlin".to_string(),
+        issued: valid - Duration::hours(6),
+        valid,
+        temperature,
+        pop,
+    }
+}
+
+fn observed(hour: u32, temperature: f64, precipitation: bool) -> AccuracyEntry {
+    AccuracyEntry::Observed {
+        location: "Berlin".to_string(),
+        time: Utc.with_ymd_and_hms(2024, 6, 1, hour, 0, 0).unwrap(),
+        temperature,
+        precipitation,
+    }
+}
+
+#[test]
+fn test_accuracy_report_per_provider() {
+    let entries = vec![
+        forecast("open-meteo", 10, 20.0, 0.2),
+        forecast("open-meteo", 11, 23.0, 0.9),
+        forecast("other", 10, 24.0, 0.5),
+        // Never observed, so not scored
+        forecast("other", 15, 30.0, 0.0),
+        observed(10, 21.0, false),
+        observed(11, 22.0, true),
+    ];
+    let report = accuracy_report(&entries);
+    assert_eq!(report.len(), 2);
+
+    let best = &report[0];
+    assert_eq!(best.provider, "open-meteo");
+    assert_eq!(best.verified, 2);
+    assert!((best.temperature_mae - 1.0).abs() < 1e-9);
+    // ((0.2 - 0)² + (0.9 - 1)²) / 2
+    assert!((best.brier_score - 0.025).abs() < 1e-9);
+
+    assert_eq!(report[1].provider, "other");
+    assert_eq!(report[1].verified, 1);
+    assert!((report[1].temperature_mae - 3.0).abs() < 1e-9);
+    assert!((report[1].brier_score - 0.25).abs() < 1e-9);
+}
+
+#[test]
+fn test_entries_for_a_refresh() {
+    let json = serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    let forecast = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default())
+        .parse_openmeteo_forecast(&json)
+        .unwrap();
+    let first = forecast.hourly[0].timestamp;
+    let entries = accuracy_entries(&forecast, "open-meteo", "Berlin", first);
+
+    // Only hours after the current one are forecasts
+    let forecasts: Vec<_> = entries
+        .iter()
+        .filter_map(|entry| match entry {
+            AccuracyEntry::Forecast { valid, .. } => Some(*valid),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(forecasts, vec![forecast.hourly[1].timestamp]);
+    assert!(forecasts
+        .iter()
+        .all(|valid| *valid <= first + Duration::hours(LEAD_HOURS)));
+    assert!(matches!(
+        entries.last(),
+        Some(AccuracyEntry::Observed { location, .. }) if location == "Berlin"
+    ));
+}
+
+#[test]
+fn test_accuracy_log_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let log = AccuracyLog::new(dir.path().join("accuracy.jsonl"));
+    assert!(log.read().unwrap().is_empty());
+
+    let entries = vec![
+        forecast("open-meteo", 10, 20.0, 0.2),
+        observed(10, 21.0, false),
+    ];
+    log.record(&entries).unwrap();
+    log.record(&entries[..1]).unwrap();
+    let read = log.read().unwrap();
+    assert_eq!(read.len(), 3);
+    assert_eq!(read[..2], entries[..]);
+}
@@ -0,0 +1,63 @@
+mod common;
+
+use chrono::{Duration, TimeZone, Utc};
+use common::hour;
+use weather_man::modules::activity::{
+    activity_score, best_window, parse_duration_hours, sparkline, HourScore,
+};
+use weather_man::modules::types::HourlyForecast;
+
+#[test]
+fn test_activity_score_penalties() {
+    let mild = HourlyForecast {
+        temperature: 15.0,
+        feels_like: 15.0,
+        ..hour(0)
+    };
+    assert_eq!(activity_score(&mild, 3.0), 100);
+    // 5 °C too warm (15) and a 50% chance of rain (15)
+    let warm_and_wet = HourlyForecast {
+        temperature: 25.0,
+        pop: 0.5,
+        ..hour(0)
+    };
+    assert_eq!(activity_score(&warm_and_wet, 3.0), 70);
+    // UV 8 costs 9 points
+    assert_eq!(activity_score(&mild, 8.0), 91);
+    let hot_and_pouring = HourlyForecast {
+        temperature: 45.0,
+        pop: 1.0,
+        ..hour(0)
+    };
+    assert_eq!(activity_score(&hot_and_pouring, 11.0), 15);
+}
+
+#[test]
+fn test_best_window() {
+    let start = Utc.with_ymd_and_hms(2024, 6, 1, 6, 0, 0).unwrap();
+    let scores: Vec<HourScore> = [40, 90, 80, 95, 30]
+        .iter()
+        .enumerate()
+        .map(|(i, score)| HourScore {
+            timestamp: start + Duration::hours(i as i64),
+            score: *score,
+        })
+        .collect();
+
+    let best = best_window(&scores, 2).unwrap();
+    assert_eq!(best.start, start + Duration::hours(2));
+    assert_eq!(best.end, start + Duration::hours(4));
+    assert_eq!(best.average_score, 87.5);
+    assert!(best_window(&scores, 6).is_none());
+
+    assert_eq!(sparkline(&scores).chars().count(), 5);
+}
+
+#[test]
+fn test_parse_duration_hours() {
+    assert_eq!(parse_duration_hours("2h").unwrap(), 2);
+    assert_eq!(parse_duration_hours("90m").unwrap(), 2);
+    assert_eq!(parse_duration_hours("3").unwrap(), 3);
+    assert!(parse_duration_hours("0h").is_err());
+    assert!(parse_duration_hours("soon").is_err());
+}
@@ -0,0 +1,59 @@
+use chrono::{TimeZone, Utc};
+use serde_json::json;
+use weather_man::modules::agriculture::{moisture_description, parse_agriculture};
+
+fn response() -> serde_json::Value {
+    json!({
+        "utc_offset_seconds": 7200,
+        "hourly": {
+            "time": ["2024-06-01T00:00", "2024-06-01T01:00", "2024-06-02T00:00"],
+            "et0_fao_evapotranspiration": [0.01, 0.02, 0.0],
+            "soil_moisture_0_to_1cm": [0.18, 0.17, 0.31],
+            "soil_moisture_1_to_3cm": [0.2, 0.2, 0.3],
+            "soil_moisture_3_to_9cm": [0.24, 0.24, 0.29],
+            "soil_moisture_9_to_27cm": [0.27, 0.27, 0.28],
+            "soil_moisture_27_to_81cm": [null, null, null],
+            "soil_temperature_6cm": [14.2, 13.9, 15.0],
+            "leaf_wetness_probability": [80, 40, 95]
+        },
+        "daily": {
+            "time": ["2024-06-01", "2024-06-02"],
+            "et0_fao_evapotranspiration": [4.5, 2.0],
+            "precipitation_sum": [0.5, 6.0]
+        }
+    })
+}
+
+#[test]
+fn test_current_soil_layers() {
+    // 01:30 local is 23:30 UTC the day before
+    let now = Utc.with_ymd_and_hms(2024, 5, 31, 23, 30, 0).unwrap();
+    let report = parse_agriculture(&response(), now).unwrap();
+
+    assert_eq!(report.soil.len(), 5);
+    assert_eq!(report.soil[0].depth, "0-1 cm");
+    assert_eq!(report.soil[0].moisture, Some(0.17));
+    assert_eq!(report.soil[4].moisture, None);
+    assert_eq!(report.soil_temperature, Some(13.9));
+    assert_eq!(report.hourly.len(), 3);
+}
+
+#[test]
+fn test_water_balance_and_leaf_wetness() {
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+    let report = parse_agriculture(&response(), now).unwrap();
+
+    assert_eq!(report.days.len(), 2);
+    assert!((report.days[0].water_balance + 4.0).abs() < 1e-9);
+    assert!((report.days[1].water_balance - 4.0).abs() < 1e-9);
+    assert_eq!(report.days[0].leaf_wet_hours, 1);
+    assert_eq!(report.days[1].leaf_wet_hours, 1);
+    assert_eq!(report.irrigation_need, 0.0);
+}
+
+#[test]
+fn test_moisture_description() {
+    assert_eq!(moisture_description(0.05), "Very dry");
+    assert_eq!(moisture_description(0.25), "Moist");
+    assert_eq!(moisture_description(0.45), "Saturated");
+}
@@ -1,5 +1,6 @@
 // Note: Using mockito with tokio can cause runtime conflicts in tests
-use weather_man::modules::forecaster::WeatherForecaster;
+use weather_man::modules::forecaster::{parse_variables, RequestVariables, WeatherForecaster};
+use weather_man::modules::http::HttpContext;
 use weather_man::modules::types::WeatherConfig;
 
 // This test is disabled due to tokio runtime conflicts
@@ -26,7 +27,7 @@ fn test_forecast_api() {
 fn test_weather_condition_mapping() {
     // Create a forecaster to access the mapping methods
     let config = WeatherConfig::default();
-    let forecaster = WeatherForecaster::new(config);
+    let forecaster = WeatherForecaster::new(config, HttpContext::default());
 
     // Test WMO code to condition mappings
     let clear = forecaster.wmo_code_to_condition(0);
@@ -50,6 +51,27 @@ fn test_weather_condition_mapping() {
         weather_man::modules::types::WeatherCondition::Thunderstorm
     );
 
+    let sleet = forecaster.wmo_code_to_condition(66);
+    assert_eq!(sleet, weather_man::modules::types::WeatherCondition::Sleet);
+
+    let hail = forecaster.wmo_code_to_condition(99);
+    assert_eq!(hail, weather_man::modules::types::WeatherCondition::Hail);
+
+    let dust = forecaster.wmo_code_to_condition(31);
+    assert_eq!(dust, weather_man::modules::types::WeatherCondition::Dust);
+
+    let squall = forecaster.wmo_code_to_condition(18);
+    assert_eq!(
+        squall,
+        weather_man::modules::types::WeatherCondition::Squall
+    );
+
+    let tornado = forecaster.wmo_code_to_condition(19);
+    assert_eq!(
+        tornado,
+        weather_man::modules::types::WeatherCondition::Tornado
+    );
+
     // Test weather description generation
     let desc_clear = forecaster.get_weather_description_from_wmo(0, true);
     assert_eq!(desc_clear.main, "Clear");
@@ -64,3 +86,170 @@ fn test_weather_condition_mapping() {
     let desc_clear_night = forecaster.get_weather_description_from_wmo(0, false);
     assert_eq!(desc_clear_night.icon, "01n");
 }
+
+#[test]
+fn test_parse_forecast_normalizes_wind_to_ms() {
+    let forecaster = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default());
+    let json: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    let forecast = forecaster.parse_openmeteo_forecast(&json).unwrap();
+
+    let current = forecast.current.unwrap();
+    assert!((current.wind_speed - 5.0).abs() < 1e-9);
+    assert!((current.wind_gusts.unwrap() - 10.0).abs() < 1e-9);
+
+    assert!((forecast.hourly[0].wind_speed - 5.0).abs() < 1e-9);
+    assert!((forecast.hourly[1].wind_speed - 2.0).abs() < 1e-9);
+
+    assert!((forecast.daily[0].wind_speed - 7.0).abs() < 1e-9);
+    assert!((forecast.daily[0].wind_gusts.unwrap() - 15.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_parse_forecast_keeps_ms_values() {
+    let forecaster = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default());
+    let mut json: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    // The units requested from the API
+    json["current_units"]["wind_speed_10m"] = "m/s".into();
+    json["hourly_units"] = serde_json::json!({});
+
+    let forecast = forecaster.parse_openmeteo_forecast(&json).unwrap();
+    assert_eq!(forecast.current.unwrap().wind_speed, 18.0);
+    assert_eq!(forecast.hourly[1].wind_speed, 7.2);
+}
+
+#[test]
+fn test_parse_forecast_keeps_all_sixteen_days() {
+    let forecaster = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default());
+    let mut json: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    // Stretch the one fixture day to the 16 days of `forecast_days=16`
+    let first = chrono::NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+    let daily = json["daily"].as_object_mut().unwrap();
+    for (name, values) in daily.iter_mut() {
+        let value = values[0].clone();
+        *values = (0..16)
+            .map(|day| match name.as_str() {
+                "time" => (first + chrono::Duration::days(day))
+                    .format("%Y-%m-%d")
+                    .to_string()
+                    .into(),
+                _ => value.clone(),
+            })
+            .collect();
+    }
+
+    let forecast = forecaster.parse_openmeteo_forecast(&json).unwrap();
+    assert_eq!(forecast.daily.len(), 16);
+    assert_eq!(
+        forecast.daily[15].date.date_naive(),
+        first + chrono::Duration::days(15)
+    );
+}
+
+#[test]
+fn test_weather_intensity_mapping() {
+    use weather_man::modules::types::Intensity;
+
+    let forecaster = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default());
+    assert_eq!(forecaster.wmo_code_to_intensity(80), Intensity::Light);
+    assert_eq!(forecaster.wmo_code_to_intensity(81), Intensity::Moderate);
+    assert_eq!(forecaster.wmo_code_to_intensity(82), Intensity::Violent);
+    assert_eq!(forecaster.wmo_code_to_intensity(65), Intensity::Heavy);
+    assert_eq!(forecaster.wmo_code_to_intensity(0), Intensity::Moderate);
+
+    assert_eq!(Intensity::Light.scale(45), 23);
+    assert_eq!(Intensity::Violent.scale(45), 90);
+}
+
+#[test]
+fn test_provider_payload_kept_at_debug_detail() {
+    use weather_man::modules::types::DetailLevel;
+
+    let json: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+
+    let forecast = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default())
+        .parse_openmeteo_forecast(&json)
+        .unwrap();
+    assert_eq!(forecast.hourly[1].raw_code, Some(3));
+    assert!(forecast.hourly[1].provider_extra.is_none());
+
+    let debug = WeatherForecaster::new(
+        WeatherConfig {
+            detail_level: DetailLevel::Debug,
+            ..WeatherConfig::default()
+        },
+        HttpContext::default(),
+    );
+    let forecast = debug.parse_openmeteo_forecast(&json).unwrap();
+    let extra = forecast.hourly[1].provider_extra.as_ref().unwrap();
+    assert_eq!(extra["weather_code"], 3);
+    assert_eq!(extra["wind_speed_10m"], 7.2);
+    assert_eq!(
+        forecast.daily[0].provider_extra.as_ref().unwrap()["uv_index_max"],
+        6.1
+    );
+
+    // Not part of normal serialization
+    let serialized = serde_json::to_value(&forecast.hourly[1]).unwrap();
+    assert!(serialized.get("raw_code").is_none());
+    assert!(serialized.get("provider_extra").is_none());
+}
+
+#[test]
+fn test_parse_forecast_without_unrequested_blocks() {
+    let forecaster = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default());
+    let mut json: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    // What a --minimal request for an hourly-only mode returns
+    let object = json.as_object_mut().unwrap();
+    object.remove("current");
+    object.remove("daily");
+
+    let forecast = forecaster.parse_openmeteo_forecast(&json).unwrap();
+    assert!(forecast.current.is_none());
+    assert!(forecast.daily.is_empty());
+    assert_eq!(forecast.hourly.len(), 2);
+}
+
+#[test]
+fn test_extra_hourly_variables() {
+    assert_eq!(
+        parse_variables(" CAPE, freezing_level_height,").unwrap(),
+        vec!["cape", "freezing_level_height"]
+    );
+    assert!(parse_variables(",").is_err());
+    assert!(parse_variables("cape&daily=x").is_err());
+
+    let variables = RequestVariables::full()
+        .with_extra_hourly(vec!["cape".to_string(), "temperature_2m".to_string()]);
+    let query = variables.query();
+    let hourly = query.split('&').find(|p| p.starts_with("hourly=")).unwrap();
+    assert!(hourly.ends_with(",cape"));
+    assert_eq!(hourly.matches("temperature_2m,").count(), 1);
+
+    // Nothing to attach them to without an hourly block
+    let current_only = RequestVariables::none()
+        .with_current()
+        .with_extra_hourly(vec!["cape".to_string()]);
+    assert!(!current_only.query().contains("cape"));
+
+    let mut json: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    json["hourly"]["cape"] = serde_json::json!([120.0, null]);
+    let forecast = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default())
+        .with_variables(RequestVariables::full().with_extra_hourly(vec!["cape".to_string()]))
+        .parse_openmeteo_forecast(&json)
+        .unwrap();
+    assert_eq!(forecast.hourly[0].extra.get("cape"), Some(&120.0));
+    assert!(forecast.hourly[1].extra.is_empty());
+
+    let serialized = serde_json::to_value(&forecast.hourly[0]).unwrap();
+    assert_eq!(serialized["extra"]["cape"], 120.0);
+    assert!(serde_json::to_value(&forecast.hourly[1])
+        .unwrap()
+        .get("extra")
+        .is_none());
+}
@@ -0,0 +1,82 @@
+mod common;
+
+use chrono::{Duration, TimeZone, Utc};
+use common::{current, day_at};
+use unicode_width::UnicodeWidthStr;
+use weather_man::modules::art::{get_weather_ascii_art, render_art_report};
+use weather_man::modules::types::{
+    CurrentWeather, DailyForecast, Location, Percent, WeatherCondition,
+};
+
+#[test]
+fn test_ascii_art_is_uniform_width() {
+    let conditions = [
+        "clear",
+        "clouds",
+        "rain",
+        "drizzle",
+        "thunderstorm",
+        "snow",
+        "sleet",
+        "hail",
+        "mist",
+        "fog",
+        "dust",
+        "squall",
+        "tornado",
+        "unknown",
+    ];
+
+    for name in conditions {
+        let condition = WeatherCondition::from_str(name);
+        for is_day in [true, false] {
+            for line in get_weather_ascii_art(condition, is_day) {
+                assert_eq!(line.width(), 13, "{} art line {:?}", name, line);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_art_report_outlook_tables() {
+    colored::control::set_override(false);
+
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+    let location = Location {
+        name: "Test City".to_string(),
+        country_code: "TC".to_string(),
+        timezone: "UTC".to_string(),
+        ..Location::default()
+    };
+    let current = CurrentWeather {
+        temperature: 21.0,
+        feels_like: 19.0,
+        wind_direction: 90,
+        clouds: Percent(0.0),
+        ..current()
+    };
+    let daily: Vec<DailyForecast> = (0..5)
+        .map(|i| DailyForecast {
+            feels_like_day: 19.0,
+            feels_like_night: 8.0,
+            main_condition: WeatherCondition::Rain,
+            clouds: Percent(80.0),
+            pop: 0.6,
+            rain: Some(3.0),
+            ..day_at(now + Duration::days(i))
+        })
+        .collect();
+
+    let report = render_art_report(&location, &current, &[], &daily, 3);
+
+    assert!(report.starts_with("Weather report: Test City, TC"));
+    assert!(report.contains("+21(+19) °C"));
+    assert_eq!(report.matches("Sat 01 Jun").count(), 1);
+    assert_eq!(report.matches("┌─────────────┐").count(), 3);
+    assert!(report.contains("+9(+8) °C"));
+
+    // Every table row lines up with the borders
+    for line in report.lines().filter(|line| line.starts_with('│')) {
+        assert_eq!(line.width(), 125, "{:?}", line);
+    }
+}
@@ -0,0 +1,50 @@
+use weather_man::modules::aviation::{
+    density_altitude, dew_point, parse_runway, pressure_altitude, runway_wind, Side,
+};
+
+#[test]
+fn test_parse_runway() {
+    assert_eq!(parse_runway("27").unwrap(), 270.0);
+    assert_eq!(parse_runway("09L").unwrap(), 90.0);
+    assert_eq!(parse_runway("095").unwrap(), 95.0);
+    assert_eq!(parse_runway("360").unwrap(), 0.0);
+    assert!(parse_runway("37").is_err());
+    assert!(parse_runway("north").is_err());
+}
+
+#[test]
+fn test_altitudes_in_standard_atmosphere() {
+    assert!(pressure_altitude(1013.25).abs() < 1.0);
+    // 5000 ft in the standard atmosphere
+    let pressure_altitude = pressure_altitude(843.1);
+    assert!(
+        (pressure_altitude - 5000.0).abs() < 20.0,
+        "{}",
+        pressure_altitude
+    );
+
+    // ISA sea level with dry air has a density altitude of zero
+    let sea_level = density_altitude(1013.25, 15.0, 0.0);
+    assert!(sea_level.abs() < 30.0, "{}", sea_level);
+    // Hot and humid days perform like higher airfields
+    assert!(density_altitude(1013.25, 35.0, 80.0) > 2000.0);
+}
+
+#[test]
+fn test_dew_point() {
+    assert!((dew_point(20.0, 100.0) - 20.0).abs() < 0.1);
+    assert!((dew_point(20.0, 50.0) - 9.3).abs() < 0.2);
+}
+
+#[test]
+fn test_runway_wind_components() {
+    let wind = runway_wind(270.0, 300.0, 10.0, Some(16.0));
+    assert!((wind.headwind - 8.66).abs() < 0.01);
+    assert!((wind.crosswind - 5.0).abs() < 0.01);
+    assert_eq!(wind.crosswind_from, Side::Right);
+    assert!((wind.gust_crosswind.unwrap() - 8.0).abs() < 0.01);
+
+    let tailwind = runway_wind(90.0, 290.0, 10.0, None);
+    assert!(tailwind.headwind < 0.0);
+    assert_eq!(tailwind.crosswind_from, Side::Left);
+}
@@ -0,0 +1,190 @@
+use anyhow::{anyhow, Result};
+use futures::future::BoxFuture;
+use futures::StreamExt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use weather_man::modules::batch::{
+    batch_stream, fetch_batch, to_csv, BatchRow, BatchTarget, CSV_HEADER,
+};
+use weather_man::modules::forecaster::WeatherForecaster;
+use weather_man::modules::http::HttpContext;
+use weather_man::modules::runner::{ForecastSource, LocationSource};
+use weather_man::modules::types::{
+    CurrentWeather, DailyForecast, Forecast, HourlyForecast, Location, WeatherConfig,
+};
+
+/// Serves the Open-Meteo fixture and tracks how many fetches overlap
+struct FixtureForecast {
+    forecast: Forecast,
+    running: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+impl FixtureForecast {
+    fn new() -> Self {
+        let json = serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+        let forecast = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default())
+            .parse_openmeteo_forecast(&json)
+            .unwrap();
+        Self {
+            forecast,
+            running: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl ForecastSource for FixtureForecast {
+    fn current_weather<'a>(
+        &'a self,
+        _location: &'a Location,
+    ) -> BoxFuture<'a, Result<CurrentWeather>> {
+        Box::pin(async { Err(anyhow!("unused")) })
+    }
+
+    fn hourly_forecast<'a>(
+        &'a self,
+        _location: &'a Location,
+    ) -> BoxFuture<'a, Result<Vec<HourlyForecast>>> {
+        Box::pin(async { Err(anyhow!("unused")) })
+    }
+
+    fn daily_forecast<'a>(
+        &'a self,
+        _location: &'a Location,
+    ) -> BoxFuture<'a, Result<Vec<DailyForecast>>> {
+        Box::pin(async { Err(anyhow!("unused")) })
+    }
+
+    fn forecast<'a>(&'a self, _location: &'a Location) -> BoxFuture<'a, Result<Forecast>> {
+        Box::pin(async {
+            let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(running, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            Ok(self.forecast.clone())
+        })
+    }
+}
+
+/// Knows every place except "Atlantis"
+struct Gazetteer;
+
+impl LocationSource for Gazetteer {
+    fn location_by_name<'a>(&'a self, name: &'a str) -> BoxFuture<'a, Result<Location>> {
+        Box::pin(async move {
+            if name == "Atlantis" {
+                return Err(anyhow!("Could not find location: {}", name));
+            }
+            Ok(Location {
+                name: name.to_string(),
+                ..Location::default()
+            })
+        })
+    }
+
+    fn location_from_ip(&self) -> BoxFuture<'_, Result<Location>> {
+        Box::pin(async { Err(anyhow!("offline")) })
+    }
+}
+
+#[test]
+fn test_parse_batch_input() {
+    let targets = BatchTarget::parse_all(
+        "# sites\nBerlin\n\n52.52,13.405\n -33.87 151.21 \nWashington, DC\n95,10\n",
+    );
+    assert_eq!(
+        targets,
+        vec![
+            BatchTarget::Name("Berlin".to_string()),
+            BatchTarget::Coordinates {
+                latitude: 52.52,
+                longitude: 13.405
+            },
+            BatchTarget::Coordinates {
+                latitude: -33.87,
+                longitude: 151.21
+            },
+            BatchTarget::Name("Washington, DC".to_string()),
+            // Out of range, so looked up by name
+            BatchTarget::Name("95,10".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_fetch_batch_bounded_and_ordered() {
+    let forecaster = FixtureForecast::new();
+    let targets: Vec<BatchTarget> = (0..8)
+        .map(|i| BatchTarget::Name(format!("Site {}", i)))
+        .chain([BatchTarget::Name("Atlantis".to_string())])
+        .collect();
+
+    let rows = fetch_batch(&forecaster, &Gazetteer, &targets, 3).await;
+
+    assert_eq!(rows.len(), 9);
+    assert_eq!(forecaster.peak.load(Ordering::SeqCst), 3);
+    for (i, row) in rows[..8].iter().enumerate() {
+        assert_eq!(row.input, format!("Site {}", i));
+        assert!(row.current.is_some() && row.today.is_some());
+        assert!(row.error.is_none());
+    }
+    assert!(rows[8].error.as_deref().unwrap().contains("Atlantis"));
+}
+
+#[tokio::test]
+async fn test_batch_stream_ndjson_lines() {
+    let forecaster = FixtureForecast::new();
+    let targets = BatchTarget::parse_all("Washington, DC\nAtlantis\n");
+    let lines: Vec<String> = batch_stream(&forecaster, &Gazetteer, &targets, 2)
+        .map(|row| serde_json::to_string(&row).unwrap())
+        .collect()
+        .await;
+
+    assert_eq!(lines.len(), 2);
+    assert!(lines.iter().all(|line| !line.contains('\n')));
+    let first: serde_json::Value = serde_json::from_str(&lines[0]).unwrap();
+    assert_eq!(first["input"], "Washington, DC");
+    assert!(first["current"]["temperature"].is_number());
+    let second: serde_json::Value = serde_json::from_str(&lines[1]).unwrap();
+    assert!(second["error"].as_str().unwrap().contains("Atlantis"));
+}
+
+#[tokio::test]
+async fn test_batch_csv() {
+    let forecaster = FixtureForecast::new();
+    let targets = BatchTarget::parse_all("Washington, DC\n10.5,-20.25\nAtlantis\n");
+    let csv = to_csv(&fetch_batch(&forecaster, &Gazetteer, &targets, 2).await);
+
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], CSV_HEADER);
+    assert!(lines[1].starts_with("\"Washington, DC\",\"Washington, DC\",Unknown,"));
+    assert!(lines[2].starts_with("\"10.5,-20.25\",\"10.5000, -20.2500\",,10.5000,-20.2500,"));
+    assert!(lines[3].starts_with("Atlantis,,,"));
+    assert!(lines[3].ends_with(",Could not find location: Atlantis"));
+    let columns = CSV_HEADER.split(',').count();
+    assert_eq!(lines[3].split(',').count(), columns);
+}
+
+#[test]
+fn test_batch_csv_extra_columns() {
+    let row = |input: &str, extra: &[(&str, f64)]| BatchRow {
+        input: input.to_string(),
+        location: None,
+        current: None,
+        today: None,
+        extra: extra.iter().map(|(k, v)| (k.to_string(), *v)).collect(),
+        error: None,
+    };
+    let csv = to_csv(&[
+        row("Oslo", &[("cape", 12.0)]),
+        row("Bergen", &[("freezing_level_height", 1850.0)]),
+    ]);
+
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(
+        lines[0],
+        format!("{},cape,freezing_level_height", CSV_HEADER)
+    );
+    assert!(lines[1].ends_with(",12.0,"));
+    assert!(lines[2].ends_with(",,1850.0"));
+}
@@ -0,0 +1,21 @@
+use weather_man::modules::big::{big_text_width, render_big_text};
+
+#[test]
+fn test_render_big_text() {
+    let small = render_big_text("21°C", 1);
+    assert_eq!(small.len(), 4);
+    assert!(small.iter().all(|line| line.chars().count() <= 32));
+    assert!(small.iter().any(|line| line.contains('█')));
+
+    let large = render_big_text("21°C", 2);
+    assert_eq!(large.len(), 8);
+    assert!(large.iter().all(|line| line.chars().count() <= 64));
+
+    assert_eq!(big_text_width("21°C", 1), 32);
+    assert_eq!(big_text_width("21°C", 4), 128);
+}
+
+#[test]
+fn test_render_big_text_blank_for_spaces() {
+    assert!(render_big_text("   ", 1).iter().all(|line| line.is_empty()));
+}
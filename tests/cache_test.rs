@@ -0,0 +1,173 @@
+use anyhow::{anyhow, Result};
+use chrono::{Duration, Utc};
+use futures::future::BoxFuture;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use weather_man::modules::cache::{CachingSource, ForecastCache};
+use weather_man::modules::forecaster::WeatherForecaster;
+use weather_man::modules::http::HttpContext;
+use weather_man::modules::runner::{resolve_location, ForecastSource, LocationSource};
+use weather_man::modules::stats::UsageLog;
+use weather_man::modules::types::{
+    CurrentWeather, DailyForecast, Forecast, HourlyForecast, Location, WeatherConfig,
+};
+
+fn fixture() -> Forecast {
+    let json = serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    WeatherForecaster::new(WeatherConfig::default(), HttpContext::default())
+        .parse_openmeteo_forecast(&json)
+        .unwrap()
+}
+
+/// Counts forecast and location requests
+#[derive(Default)]
+struct Counting {
+    forecasts: AtomicUsize,
+    lookups: AtomicUsize,
+}
+
+impl ForecastSource for Counting {
+    fn current_weather<'a>(
+        &'a self,
+        _location: &'a Location,
+    ) -> BoxFuture<'a, Result<CurrentWeather>> {
+        Box::pin(async { Err(anyhow!("unused")) })
+    }
+
+    fn hourly_forecast<'a>(
+        &'a self,
+        _location: &'a Location,
+    ) -> BoxFuture<'a, Result<Vec<HourlyForecast>>> {
+        Box::pin(async { Err(anyhow!("unused")) })
+    }
+
+    fn daily_forecast<'a>(
+        &'a self,
+        _location: &'a Location,
+    ) -> BoxFuture<'a, Result<Vec<DailyForecast>>> {
+        Box::pin(async { Err(anyhow!("unused")) })
+    }
+
+    fn forecast<'a>(&'a self, _location: &'a Location) -> BoxFuture<'a, Result<Forecast>> {
+        self.forecasts.fetch_add(1, Ordering::SeqCst);
+        Box::pin(async { Ok(fixture()) })
+    }
+}
+
+impl LocationSource for Counting {
+    fn location_by_name<'a>(&'a self, name: &'a str) -> BoxFuture<'a, Result<Location>> {
+        self.lookups.fetch_add(1, Ordering::SeqCst);
+        Box::pin(async move {
+            Ok(Location {
+                name: name.to_string(),
+                latitude: 52.52,
+                longitude: 13.41,
+                ..Location::default()
+            })
+        })
+    }
+
+    fn location_from_ip(&self) -> BoxFuture<'_, Result<Location>> {
+        self.location_by_name("Detected")
+    }
+}
+
+#[tokio::test]
+async fn test_caching_source_reuses_fresh_forecasts() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = ForecastCache::new(dir.path().to_path_buf());
+    let inner = Arc::new(Counting::default());
+
+    // The first run fetches and caches
+    let source = CachingSource::new(inner.clone(), inner.clone(), cache.clone());
+    let location = source.location_by_name("Berlin").await.unwrap();
+    source.forecast(&location).await.unwrap();
+    assert_eq!(inner.forecasts.load(Ordering::SeqCst), 1);
+
+    // A later run needs neither the geocoder nor the forecast API
+    let source = CachingSource::new(inner.clone(), inner.clone(), cache.clone());
+    let location = source.location_by_name("Berlin").await.unwrap();
+    let weather = source.current_weather(&location).await.unwrap();
+    assert_eq!(weather.temperature, 21.4);
+    assert_eq!(source.daily_forecast(&location).await.unwrap().len(), 1);
+    let fetched = source.fetched_at(&location).unwrap();
+    let forecast = source.forecast(&location).await.unwrap();
+    assert_eq!(forecast.fetched_at, Some(fetched));
+    assert_eq!(inner.lookups.load(Ordering::SeqCst), 1);
+    assert_eq!(inner.forecasts.load(Ordering::SeqCst), 1);
+
+    // Stale entries are fetched again
+    let key = ForecastCache::key(Some("Berlin"));
+    let mut stale = cache.load(&key).unwrap();
+    stale.fetched = Utc::now() - Duration::hours(1);
+    cache.store(&key, &stale).unwrap();
+    let source = CachingSource::new(inner.clone(), inner.clone(), cache.clone());
+    let location = source.location_by_name("Berlin").await.unwrap();
+    source.forecast(&location).await.unwrap();
+    assert_eq!(inner.lookups.load(Ordering::SeqCst), 2);
+    assert_eq!(inner.forecasts.load(Ordering::SeqCst), 2);
+    assert!(cache.load(&key).unwrap().fetched > stale.fetched);
+}
+
+#[tokio::test]
+async fn test_timezone_override_is_not_cached() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = ForecastCache::new(dir.path().to_path_buf());
+    let inner = Arc::new(Counting::default());
+    let config = |timezone: Option<&str>| WeatherConfig {
+        location: Some("Berlin".to_string()),
+        timezone: timezone.map(str::to_string),
+        ..WeatherConfig::default()
+    };
+
+    // A run with --tz caches the forecast
+    let source = CachingSource::new(inner.clone(), inner.clone(), cache.clone());
+    let location = resolve_location(&source, &config(Some("Asia/Tokyo")))
+        .await
+        .unwrap();
+    assert_eq!(location.clock_timezone(), "Asia/Tokyo");
+    source.forecast(&location).await.unwrap();
+    let cached = cache.load(&ForecastCache::key(Some("Berlin"))).unwrap();
+    assert_eq!(cached.location.timezone, "UTC");
+    assert_eq!(cached.location.display_timezone, None);
+
+    // The next run without it gets the place's own zone back
+    let source = CachingSource::new(inner.clone(), inner.clone(), cache.clone());
+    let location = resolve_location(&source, &config(None)).await.unwrap();
+    assert_eq!(inner.lookups.load(Ordering::SeqCst), 1);
+    assert_eq!(location.display_timezone, None);
+    assert_eq!(location.clock_timezone(), "UTC");
+}
+
+#[tokio::test]
+async fn test_unresolved_locations_are_not_cached() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = ForecastCache::new(dir.path().to_path_buf());
+    let inner = Arc::new(Counting::default());
+    let source = CachingSource::new(inner.clone(), inner.clone(), cache);
+
+    let location = Location::default();
+    source.forecast(&location).await.unwrap();
+    source.forecast(&location).await.unwrap();
+    assert_eq!(inner.forecasts.load(Ordering::SeqCst), 2);
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+}
+
+#[tokio::test]
+async fn test_requests_are_logged() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = ForecastCache::new(dir.path().join("forecasts"));
+    let log = UsageLog::new(dir.path().join("usage.jsonl"));
+    let inner = Arc::new(Counting::default());
+    let source = CachingSource::new(inner.clone(), inner.clone(), cache).with_log(log.clone());
+
+    let location = source.location_by_name("Berlin").await.unwrap();
+    source.forecast(&location).await.unwrap();
+    source.forecast(&location).await.unwrap();
+
+    let events = log.read().unwrap();
+    assert_eq!(events.len(), 2);
+    assert!(!events[0].cached && events[0].latency_ms.is_some());
+    assert!(events[1].cached && events[1].latency_ms.is_none());
+    assert_eq!(events[1].location, "Berlin");
+}
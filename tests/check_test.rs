@@ -0,0 +1,110 @@
+mod common;
+
+use chrono::Duration;
+use common::{hourly, series, start};
+use weather_man::modules::check::Thresholds;
+use weather_man::modules::config::ThresholdSettings;
+use weather_man::modules::utils::{DisplayUnits, WindUnit};
+
+fn metric() -> DisplayUnits {
+    DisplayUnits::default()
+}
+
+#[test]
+fn test_thresholds_within_window() {
+    let data = series(
+        &[(3.0, 0.1), (1.0, 0.3), (-2.0, 0.7), (4.0, 0.2)],
+        |h, (temperature, pop)| {
+            h.temperature = temperature;
+            h.pop = pop;
+        },
+    );
+    let thresholds = Thresholds {
+        rain_above: Some(50.0),
+        temp_below: Some(0.0),
+        ..Thresholds::default()
+    };
+
+    let checks = thresholds.evaluate(&data, 4, start(), &metric()).unwrap();
+    assert_eq!(checks.len(), 2);
+    assert!(checks.iter().all(|check| check.met));
+    assert_eq!(checks[1].observed, -2.0);
+
+    // The freezing, rainy hour falls outside a two-hour window
+    let checks = thresholds.evaluate(&data, 2, start(), &metric()).unwrap();
+    assert!(checks.iter().all(|check| !check.met));
+}
+
+#[test]
+fn test_thresholds_skip_past_hours() {
+    // Open-Meteo's hours start at local midnight: frost at 02:00, rain at 21:00
+    let mut data = hourly(24);
+    data[2].temperature = -3.0;
+    data[21].pop = 0.8;
+    let thresholds = Thresholds {
+        rain_above: Some(50.0),
+        temp_below: Some(0.0),
+        ..Thresholds::default()
+    };
+
+    // At 20:30 the next two hours are 20:00 and 21:00, not 00:00 and 01:00
+    let now = start() + Duration::minutes(20 * 60 + 30);
+    let checks = thresholds.evaluate(&data, 2, now, &metric()).unwrap();
+    assert!(checks[0].met);
+    assert!(!checks[1].met);
+    assert_eq!(checks[1].observed, 20.0);
+
+    // Past the last hour there is nothing left to check
+    assert!(thresholds
+        .evaluate(&data, 2, start() + Duration::hours(24), &metric())
+        .is_err());
+}
+
+#[test]
+fn test_thresholds_need_data() {
+    let thresholds = Thresholds {
+        temp_above: Some(30.0),
+        ..Thresholds::default()
+    };
+    assert!(!thresholds.is_empty());
+    assert!(Thresholds::default().is_empty());
+    assert!(thresholds.evaluate(&[], 24, start(), &metric()).is_err());
+}
+
+#[test]
+fn test_thresholds_in_display_units() {
+    // 10 m/s is 36 km/h and 22.4 mph; -2 °C is 28.4 °F
+    let data = series(&[3.0, -2.0], |h, temperature| {
+        h.temperature = temperature;
+        h.wind_speed = 10.0;
+    });
+    let thresholds = Thresholds {
+        temp_below: Some(30.0),
+        wind_above: Some(20.0),
+        ..Thresholds::default()
+    };
+
+    let imperial = DisplayUnits::for_system("imperial");
+    let checks = thresholds.evaluate(&data, 2, start(), &imperial).unwrap();
+    assert!(checks.iter().all(|check| check.met));
+    assert_eq!(checks[0].description, "temperature below 30.0°F");
+    assert_eq!(checks[1].description, "wind above 20.0 mph");
+    assert!((checks[1].observed - 22.37).abs() < 0.01);
+
+    // In m/s the same wind threshold is not met
+    let checks = thresholds.evaluate(&data, 2, start(), &metric()).unwrap();
+    assert_eq!(checks[1].description, "wind above 20.0 m/s");
+    assert!(!checks[1].met);
+
+    // The config file's wind warning is in m/s whatever the display unit
+    let settings = ThresholdSettings {
+        wind_warning: Some(10.0),
+        ..ThresholdSettings::default()
+    };
+    let kmh = DisplayUnits {
+        wind: WindUnit::Kmh,
+        ..metric()
+    };
+    let from_config = Thresholds::from_settings(&settings, &kmh);
+    assert_eq!(from_config.wind_above, Some(36.0));
+}
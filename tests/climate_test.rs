@@ -0,0 +1,111 @@
+use chrono::NaiveDate;
+use serde_json::json;
+use weather_man::modules::climate::{
+    anomaly_text, compare_week, day_normals, monthly_normals, parse_archive, ClimateDay,
+};
+use weather_man::modules::forecaster::WeatherForecaster;
+use weather_man::modules::http::HttpContext;
+use weather_man::modules::types::WeatherConfig;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+/// A week around June 1st for each year, warmer and wetter in later years
+fn history() -> Vec<ClimateDay> {
+    (1991..=2020)
+        .flat_map(|year| {
+            let offset = (year - 1991) as f64 / 10.0;
+            (28..=31)
+                .map(move |day| date(year, 5, day))
+                .chain((1..=4).map(move |day| date(year, 6, day)))
+                .map(move |date| ClimateDay {
+                    date,
+                    temp_max: Some(20.0 + offset),
+                    temp_min: Some(10.0 + offset),
+                    precipitation: Some(if year % 3 == 0 { 4.0 } else { 0.0 }),
+                })
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_archive() {
+    let json = json!({
+        "daily": {
+            "time": ["1991-06-01", "1991-06-02"],
+            "temperature_2m_max": [22.5, null],
+            "temperature_2m_min": [11.0, 12.0],
+            "precipitation_sum": [0.0, 3.2]
+        }
+    });
+    let days = parse_archive(&json).unwrap();
+    assert_eq!(days.len(), 2);
+    assert_eq!(days[0].date, date(1991, 6, 1));
+    assert_eq!(days[0].temp_max, Some(22.5));
+    assert_eq!(days[1].temp_max, None);
+    assert_eq!(days[1].precipitation, Some(3.2));
+
+    assert!(parse_archive(&json!({})).is_err());
+}
+
+#[test]
+fn test_day_normals() {
+    let normals = day_normals(&history(), date(2024, 6, 1)).unwrap();
+    assert_eq!((normals.month, normals.day), (6, 1));
+    assert_eq!(normals.years, 30);
+    assert!((normals.normal_max - 21.45).abs() < 1e-9);
+    assert!((normals.normal_min - 11.45).abs() < 1e-9);
+    assert_eq!(normals.record_max.year, 2020);
+    assert!((normals.record_max.value - 22.9).abs() < 1e-9);
+    assert_eq!(normals.record_min.year, 1991);
+    assert_eq!(normals.record_min.value, 10.0);
+    // 1992, 1995, ..., 2019
+    assert_eq!(normals.wet_years, 10);
+
+    // No history for the date
+    assert!(day_normals(&history(), date(2024, 12, 1)).is_none());
+}
+
+#[test]
+fn test_anomaly_text() {
+    let normals = day_normals(&history(), date(2024, 6, 1)).unwrap();
+    assert_eq!(
+        anomaly_text(&normals, 25.0),
+        "Today's high of 25° is 4° above the 1991–2020 normal, beating the record of 23° from 2020"
+    );
+    assert_eq!(
+        anomaly_text(&normals, 18.0),
+        "Today's high of 18° is 3° below the 1991–2020 normal"
+    );
+    assert_eq!(
+        anomaly_text(&normals, 21.2),
+        "Today's high of 21° is right at the 1991–2020 normal"
+    );
+}
+
+#[test]
+fn test_monthly_normals_and_week() {
+    let normals = monthly_normals(&history());
+    // The history only covers late May and early June
+    assert_eq!(
+        normals.iter().map(|n| n.month).collect::<Vec<_>>(),
+        vec![5, 6]
+    );
+    let june = &normals[1];
+    assert!((june.temp_max - 21.45).abs() < 1e-9);
+    assert!((june.precipitation - 4.0 / 3.0).abs() < 1e-9);
+
+    let json = serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    let forecast = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default())
+        .parse_openmeteo_forecast(&json)
+        .unwrap();
+    let week = compare_week(&forecast.daily, &normals);
+    assert_eq!(week.len(), 1);
+    assert_eq!(week[0].date, date(2024, 6, 1));
+    assert_eq!(week[0].temp_max, 23.5);
+    assert_eq!(week[0].normal_max, june.temp_max);
+
+    // Days without normals for their month are left out
+    assert!(compare_week(&forecast.daily, &normals[..1]).is_empty());
+}
@@ -0,0 +1,67 @@
+use weather_man::modules::comfort::{apply_forecast, heat_index, humidex, wind_chill, FeelsLike};
+use weather_man::modules::forecaster::WeatherForecaster;
+use weather_man::modules::http::HttpContext;
+use weather_man::modules::types::{Forecast, WeatherConfig};
+
+fn fixture(config: WeatherConfig) -> Forecast {
+    let json = serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    WeatherForecaster::new(config, HttpContext::default())
+        .parse_openmeteo_forecast(&json)
+        .unwrap()
+}
+
+#[test]
+fn test_formulas() {
+    // NWS table: 90 °F at 70% is 106 °F
+    assert!((heat_index(32.2, 70.0) - 41.1).abs() < 0.5);
+    // Environment Canada table: 30 °C at 70% is a humidex of 41
+    assert!((humidex(30.0, 70.0) - 40.9).abs() < 0.5);
+    // Environment Canada table: -10 °C in a 20 km/h wind is -18
+    assert!((wind_chill(-10.0, 20.0 / 3.6) - -17.9).abs() < 0.2);
+
+    // Outside their range the formulas leave the air temperature alone
+    assert_eq!(heat_index(20.0, 90.0), 20.0);
+    assert_eq!(humidex(15.0, 90.0), 15.0);
+    assert_eq!(wind_chill(15.0, 10.0), 15.0);
+    assert_eq!(wind_chill(-5.0, 1.0), -5.0);
+}
+
+#[test]
+fn test_auto_formula() {
+    let auto = FeelsLike::Auto;
+    assert_eq!(
+        auto.apparent(-10.0, 80.0, 20.0 / 3.6),
+        Some(wind_chill(-10.0, 20.0 / 3.6))
+    );
+    assert_eq!(auto.apparent(32.2, 70.0, 3.0), Some(heat_index(32.2, 70.0)));
+    assert_eq!(auto.apparent(18.0, 70.0, 8.0), Some(18.0));
+    assert_eq!(FeelsLike::Provider.apparent(32.2, 70.0, 3.0), None);
+}
+
+#[test]
+fn test_feels_like_applied_to_forecast() {
+    let provider = fixture(WeatherConfig::default());
+    let windy = fixture(WeatherConfig {
+        feels_like: FeelsLike::WindChill,
+        ..WeatherConfig::default()
+    });
+
+    // The fixture's day is too warm for wind chill, so it is the temperature
+    let day = &windy.daily[0];
+    assert_eq!(day.feels_like_day, day.temp_max);
+    assert_eq!(day.feels_like_night, day.temp_min);
+    for (hour, original) in windy.hourly.iter().zip(&provider.hourly) {
+        assert_eq!(
+            hour.feels_like,
+            wind_chill(original.temperature, original.wind_speed)
+        );
+    }
+
+    // Provider values stay as parsed
+    let mut unchanged = provider.clone();
+    apply_forecast(&mut unchanged, FeelsLike::Provider);
+    assert_eq!(
+        unchanged.current.as_ref().unwrap().feels_like,
+        provider.current.as_ref().unwrap().feels_like
+    );
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use anyhow::{anyhow, Result};
+use chrono::{Duration, Timelike, Utc};
+use common::{location, series_from};
+use futures::future::BoxFuture;
+use weather_man::modules::commands::{check, waybar, CheckArgs};
+use weather_man::modules::runner::{ForecastSource, LocationSource};
+use weather_man::modules::types::{
+    CurrentWeather, DailyForecast, Forecast, HourlyForecast, Location, WeatherConfig,
+};
+
+/// Serves the given hours; there is no current weather
+struct HoursOnly(Vec<HourlyForecast>);
+
+impl ForecastSource for HoursOnly {
+    fn current_weather<'a>(
+        &'a self,
+        _location: &'a Location,
+    ) -> BoxFuture<'a, Result<CurrentWeather>> {
+        Box::pin(async { Err(anyhow!("no current data")) })
+    }
+
+    fn hourly_forecast<'a>(
+        &'a self,
+        _location: &'a Location,
+    ) -> BoxFuture<'a, Result<Vec<HourlyForecast>>> {
+        Box::pin(async { Ok(self.0.clone()) })
+    }
+
+    fn daily_forecast<'a>(
+        &'a self,
+        _location: &'a Location,
+    ) -> BoxFuture<'a, Result<Vec<DailyForecast>>> {
+        Box::pin(async { Ok(Vec::new()) })
+    }
+
+    fn forecast<'a>(&'a self, _location: &'a Location) -> BoxFuture<'a, Result<Forecast>> {
+        Box::pin(async {
+            Ok(Forecast {
+                current: None,
+                hourly: self.0.clone(),
+                daily: Vec::new(),
+                timezone_offset: 0,
+                units: "metric".to_string(),
+                fetched_at: None,
+            })
+        })
+    }
+}
+
+/// Every name resolves to Berlin
+struct Berlin;
+
+impl LocationSource for Berlin {
+    fn location_by_name<'a>(&'a self, _name: &'a str) -> BoxFuture<'a, Result<Location>> {
+        Box::pin(async { Ok(location()) })
+    }
+
+    fn location_from_ip(&self) -> BoxFuture<'_, Result<Location>> {
+        Box::pin(async { Ok(location()) })
+    }
+}
+
+fn config() -> WeatherConfig {
+    WeatherConfig {
+        location: Some("Berlin".to_string()),
+        ..WeatherConfig::default()
+    }
+}
+
+fn args(rain_above: Option<f64>, temp_above: Option<f64>, any: bool) -> CheckArgs {
+    CheckArgs {
+        rain_above,
+        temp_above,
+        temp_below: None,
+        wind_above: None,
+        hours: 6,
+        any,
+        quiet: true,
+    }
+}
+
+/// Six hours from the current one; rain is likely in the third
+fn upcoming() -> HoursOnly {
+    let now = Utc::now();
+    let first = now - Duration::minutes(now.minute() as i64);
+    HoursOnly(series_from(
+        first,
+        &[0.0, 0.1, 0.8, 0.2, 0.0, 0.0],
+        |h, pop| h.pop = pop,
+    ))
+}
+
+#[tokio::test]
+async fn test_check_thresholds() {
+    let forecasts = upcoming();
+
+    let rain = args(Some(50.0), None, false);
+    assert!(check(&forecasts, &Berlin, &config(), &rain).await.unwrap());
+
+    let heat = args(None, Some(30.0), false);
+    assert!(!check(&forecasts, &Berlin, &config(), &heat).await.unwrap());
+
+    let both = args(Some(50.0), Some(30.0), false);
+    assert!(!check(&forecasts, &Berlin, &config(), &both).await.unwrap());
+
+    let either = args(Some(50.0), Some(30.0), true);
+    assert!(check(&forecasts, &Berlin, &config(), &either)
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn test_check_without_thresholds() {
+    let none = args(None, None, false);
+    assert!(check(&upcoming(), &Berlin, &config(), &none).await.is_err());
+}
+
+#[tokio::test]
+async fn test_waybar_without_current_weather() {
+    assert!(!waybar(&upcoming(), &Berlin, &config()).await.unwrap());
+}
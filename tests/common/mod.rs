@@ -0,0 +1,116 @@
+// Each test binary uses only some of these fixtures
+#![allow(dead_code)]
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use weather_man::modules::types::{
+    CurrentWeather, DailyForecast, HourlyForecast, Location, Percent, WeatherCondition,
+};
+
+/// Midnight UTC on 1 June 2024, where fixture hours start
+pub fn start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap()
+}
+
+/// A mild, dry, clear hour `offset` hours after `start()`
+pub fn hour(offset: i64) -> HourlyForecast {
+    hour_at(start() + Duration::hours(offset))
+}
+
+/// A mild, dry, clear hour at `timestamp`
+pub fn hour_at(timestamp: DateTime<Utc>) -> HourlyForecast {
+    HourlyForecast {
+        timestamp,
+        temperature: 20.0,
+        feels_like: 20.0,
+        humidity: Percent(60.0),
+        wind_speed: 3.0,
+        wind_direction: 270,
+        main_condition: WeatherCondition::Clear,
+        pop: 0.0,
+        clouds: Percent(10.0),
+        ..Default::default()
+    }
+}
+
+/// `count` consecutive hours from `start()`
+pub fn hourly(count: usize) -> Vec<HourlyForecast> {
+    (0..count as i64).map(hour).collect()
+}
+
+/// Consecutive hours from `first`, one per value, each adjusted by `set`
+pub fn series_from<T: Copy>(
+    first: DateTime<Utc>,
+    values: &[T],
+    set: impl Fn(&mut HourlyForecast, T),
+) -> Vec<HourlyForecast> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let mut hour = hour_at(first + Duration::hours(i as i64));
+            set(&mut hour, *value);
+            hour
+        })
+        .collect()
+}
+
+/// Consecutive hours from `start()`, one per value, each adjusted by `set`
+pub fn series<T: Copy>(values: &[T], set: impl Fn(&mut HourlyForecast, T)) -> Vec<HourlyForecast> {
+    series_from(start(), values, set)
+}
+
+/// A mild, dry, clear day `offset` days after `start()`
+pub fn day(offset: i64) -> DailyForecast {
+    day_at(start() + Duration::days(offset) + Duration::hours(12))
+}
+
+/// A mild, dry, clear day dated `date`, which providers put at 12:00 UTC,
+/// with sunrise at 05:00 and sunset at 21:00
+pub fn day_at(date: DateTime<Utc>) -> DailyForecast {
+    DailyForecast {
+        date,
+        sunrise: date - Duration::hours(7),
+        sunset: date + Duration::hours(9),
+        temp_morning: 12.0,
+        temp_day: 20.0,
+        temp_evening: 16.0,
+        temp_night: 9.0,
+        temp_min: 9.0,
+        temp_max: 21.0,
+        feels_like_day: 20.0,
+        feels_like_night: 9.0,
+        humidity: Percent(60.0),
+        wind_speed: 3.0,
+        wind_direction: 270,
+        clouds: Percent(10.0),
+        uv_index: 5.0,
+        ..Default::default()
+    }
+}
+
+/// Mild, dry, clear weather at 12:00 on the day of `start()`, with sunrise
+/// at 05:00 and sunset at 21:00
+pub fn current() -> CurrentWeather {
+    let noon = start() + Duration::hours(12);
+    CurrentWeather {
+        timestamp: noon,
+        temperature: 20.0,
+        feels_like: 20.0,
+        humidity: Percent(60.0),
+        wind_speed: 3.0,
+        wind_direction: 270,
+        clouds: Percent(10.0),
+        uv_index: 5.0,
+        sunrise: noon - Duration::hours(7),
+        sunset: noon + Duration::hours(9),
+        ..Default::default()
+    }
+}
+
+/// A location named Berlin at the default coordinates, in UTC
+pub fn location() -> Location {
+    Location {
+        name: "Berlin".to_string(),
+        ..Location::default()
+    }
+}
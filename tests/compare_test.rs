@@ -0,0 +1,52 @@
+mod common;
+
+use common::current;
+use weather_man::modules::compare::{parse_providers, ProviderComparison};
+use weather_man::modules::types::WeatherCondition;
+
+#[test]
+fn test_parse_providers() {
+    assert_eq!(
+        parse_providers("best_match, GFS_Seamless").unwrap(),
+        vec!["best_match", "gfs_seamless"]
+    );
+    assert!(parse_providers("best_match").is_err());
+    assert!(parse_providers("a,b,c").is_err());
+    assert!(parse_providers("best_match,gfs&x=1").is_err());
+}
+
+#[test]
+fn test_same_conditions_agree() {
+    let weather = current();
+    let comparison = ProviderComparison::new([("a", &weather), ("b", &weather)]);
+    assert_eq!(comparison.providers, ["a", "b"]);
+    assert!(comparison.rows.iter().all(|row| row.delta == 0.0));
+    assert!(!comparison.disagree());
+}
+
+#[test]
+fn test_large_deltas_are_notable() {
+    let a = current();
+    let mut b = current();
+    b.temperature += 3.0;
+    b.wind_speed += 1.0;
+
+    let comparison = ProviderComparison::new([("a", &a), ("b", &b)]);
+    let row = |label| {
+        comparison
+            .rows
+            .iter()
+            .find(|row| row.label == label)
+            .unwrap()
+    };
+    assert!((row("Temperature").delta - 3.0).abs() < 1e-9);
+    assert!(row("Temperature").notable);
+    assert!(!row("Wind").notable);
+    assert!(comparison.disagree());
+
+    b.temperature = a.temperature;
+    b.main_condition = WeatherCondition::Thunderstorm;
+    let comparison = ProviderComparison::new([("a", &a), ("b", &b)]);
+    assert_eq!(comparison.conditions[1], "Thunderstorm");
+    assert!(comparison.disagree());
+}
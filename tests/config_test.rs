@@ -0,0 +1,70 @@
+use weather_man::modules::check::Thresholds;
+use weather_man::modules::config::{ConfigFile, Theme, TuiLayout};
+use weather_man::modules::utils::DisplayUnits;
+
+#[test]
+fn test_config_file_defaults() {
+    let config = ConfigFile::parse("").unwrap();
+    assert_eq!(config.tui.layout, TuiLayout::Tabs);
+}
+
+#[test]
+fn test_config_file_tui_layout() {
+    let config = ConfigFile::parse("[tui]\nlayout = \"dashboard\"\n").unwrap();
+    assert_eq!(config.tui.layout, TuiLayout::Dashboard);
+
+    assert!(ConfigFile::parse("[tui]\nlayout = \"mosaic\"\n").is_err());
+}
+
+#[test]
+fn test_config_file_thresholds() {
+    let defaults = ConfigFile::parse("").unwrap().thresholds;
+    assert_eq!(defaults.uv_warning(), 6.0);
+    assert!(Thresholds::from_settings(&defaults, &DisplayUnits::default()).is_empty());
+
+    let config =
+        ConfigFile::parse("[thresholds]\nwind_warning = 12.0\nrain_notify_pop = 0.3\n").unwrap();
+    assert_eq!(config.thresholds.wind_warning(), 12.0);
+    let thresholds = Thresholds::from_settings(&config.thresholds, &DisplayUnits::default());
+    assert_eq!(thresholds.wind_above, Some(12.0));
+    assert_eq!(thresholds.rain_above, Some(30.0));
+
+    assert!(ConfigFile::parse("[thresholds]\nuv = 6\n").is_err());
+}
+
+#[test]
+fn test_config_file_general_round_trip() {
+    let defaults = ConfigFile::parse("").unwrap().general;
+    assert!(defaults.auto_location);
+    assert_eq!(defaults.theme, Theme::Cyberpunk);
+
+    let mut config = ConfigFile::parse("[tui]\nlayout = \"dashboard\"\n").unwrap();
+    config.general.location = Some("Lisbon".to_string());
+    config.general.theme = Theme::Monochrome;
+    config.general.auto_location = false;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("weather_man").join("config.toml");
+    config.save_to(&path).unwrap();
+
+    let saved = ConfigFile::load_from(&path).unwrap();
+    assert_eq!(saved.general.location.as_deref(), Some("Lisbon"));
+    assert_eq!(saved.general.theme, Theme::Monochrome);
+    assert!(!saved.general.auto_location);
+    assert_eq!(saved.tui.layout, TuiLayout::Dashboard);
+}
+
+#[test]
+fn test_favorites_add_and_remove() {
+    let mut config = ConfigFile::parse("[favorites]\nlocations = [\"Berlin\"]\n").unwrap();
+    let favorites = &mut config.favorites;
+
+    assert!(favorites.add("Lisbon"));
+    assert!(!favorites.add("berlin"));
+    assert!(!favorites.add("  "));
+    assert_eq!(favorites.locations, vec!["Berlin", "Lisbon"]);
+
+    assert!(favorites.remove("BERLIN"));
+    assert!(!favorites.remove("Paris"));
+    assert_eq!(favorites.locations, vec!["Lisbon"]);
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use common::current;
+use ratatui::{backend::TestBackend, Terminal};
+use weather_man::modules::dashboard::{draw_dashboard, DashboardState, LocationCard};
+use weather_man::modules::types::{CurrentWeather, Location};
+use weather_man::modules::utils::DisplayUnits;
+
+fn card(name: &str, temperature: f64) -> LocationCard {
+    LocationCard {
+        name: name.to_string(),
+        location: Some(Location {
+            name: name.to_string(),
+            country_code: "XX".to_string(),
+            timezone: "UTC".to_string(),
+            ..Location::default()
+        }),
+        weather: Some(CurrentWeather {
+            temperature,
+            feels_like: temperature - 2.0,
+            ..current()
+        }),
+        error: None,
+    }
+}
+
+fn render(state: &DashboardState) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+    let frame = terminal.draw(|f| draw_dashboard(f, state)).unwrap();
+    frame
+        .buffer
+        .content()
+        .iter()
+        .map(|cell| cell.symbol.as_str())
+        .collect()
+}
+
+#[test]
+fn test_dashboard_tiles_every_location() {
+    let failed = LocationCard {
+        name: "Atlantis".to_string(),
+        location: None,
+        weather: None,
+        error: Some("Location not found".to_string()),
+    };
+    let state = DashboardState::new(
+        vec![card("Berlin", 21.4), card("Oslo", -3.0), failed],
+        DisplayUnits::default(),
+    );
+
+    let screen = render(&state);
+    assert!(screen.contains("Berlin, XX"));
+    assert!(screen.contains("Oslo, XX"));
+    assert!(screen.contains("21°C"));
+    assert!(screen.contains("-3°C"));
+    assert!(screen.contains("Location not found"));
+}
+
+#[test]
+fn test_dashboard_converts_to_fahrenheit() {
+    let state = DashboardState::new(
+        vec![card("Phoenix", 35.0)],
+        DisplayUnits::for_system("imperial"),
+    );
+
+    let screen = render(&state);
+    assert!(screen.contains("95°F"));
+    assert!(screen.contains("feels 91°F"));
+    assert!(!screen.contains("°C"));
+}
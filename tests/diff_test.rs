@@ -0,0 +1,48 @@
+mod common;
+
+use chrono::NaiveDate;
+use weather_man::modules::diff::forecast_changes;
+use weather_man::modules::types::{DailyForecast, WeatherCondition};
+
+/// Cloudy days from 2024-06-01 (a Saturday) to 2024-06-03
+fn daily() -> Vec<DailyForecast> {
+    (0..3)
+        .map(|i| DailyForecast {
+            main_condition: WeatherCondition::Clouds,
+            pop: 0.2,
+            ..common::day(i)
+        })
+        .collect()
+}
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 6, day).unwrap()
+}
+
+#[test]
+fn test_forecast_changes() {
+    let previous = daily();
+    let mut current = daily();
+    current[0].temp_max -= 1.0;
+    current[1].pop = 0.7;
+    current[1].temp_max -= 3.0;
+    current[2].main_condition = WeatherCondition::Rain;
+    current[2].temp_min += 2.0;
+
+    let changes = forecast_changes(&previous, &current, date(1));
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].date, date(2));
+    assert_eq!(
+        changes[0].summary(date(1)),
+        "Tomorrow's rain chance rose from 20% to 70%, high revised down 3°"
+    );
+    assert_eq!(
+        changes[1].summary(date(1)),
+        "Monday's outlook changed from cloudy to rainy, low revised up 2°"
+    );
+
+    // Past days and days missing from the old forecast are skipped
+    let changes = forecast_changes(&previous[..2], &current, date(3));
+    assert!(changes.is_empty());
+    assert!(forecast_changes(&previous, &previous, date(1)).is_empty());
+}
@@ -0,0 +1,167 @@
+mod common;
+
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use common::location;
+use weather_man::modules::config::ThresholdSettings;
+use weather_man::modules::digest::Digest;
+use weather_man::modules::forecaster::WeatherForecaster;
+use weather_man::modules::http::HttpContext;
+use weather_man::modules::runner::with_display_timezone;
+use weather_man::modules::types::{DailyForecast, Forecast, WeatherCondition, WeatherConfig};
+use weather_man::modules::utils::{DisplayUnits, Language};
+
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 6, 1, 6, 0, 0).unwrap()
+}
+
+/// The fixture stretched to 24 hours from 06:00 and two days, with
+/// thunderstorms from 15:00 to 17:00
+fn forecast() -> Forecast {
+    let json = serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    let mut forecast = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default())
+        .parse_openmeteo_forecast(&json)
+        .unwrap();
+    let hour = forecast.hourly[0].clone();
+    forecast.hourly = (0..24)
+        .map(|i| {
+            let mut hour = hour.clone();
+            hour.timestamp = now() + Duration::hours(i);
+            hour.temperature = 15.0;
+            hour.pop = 0.0;
+            if (9..11).contains(&i) {
+                hour.main_condition = WeatherCondition::Thunderstorm;
+                hour.pop = 0.9;
+            }
+            hour
+        })
+        .collect();
+    let today = forecast.daily[0].clone();
+    forecast.daily = vec![
+        today.clone(),
+        DailyForecast {
+            date: today.date + Duration::days(1),
+            main_condition: WeatherCondition::Rain,
+            pop: 0.8,
+            ..today
+        },
+    ];
+    forecast
+}
+
+#[test]
+fn test_digest_contents() {
+    let digest = Digest::new(
+        &forecast(),
+        &location(),
+        &ThresholdSettings::default(),
+        now(),
+        Language::English,
+    );
+
+    assert_eq!(digest.date, NaiveDate::from_ymd_opt(2024, 6, 1).unwrap());
+    assert!(digest.today.is_some());
+    assert_eq!(
+        digest.tomorrow.as_ref().unwrap().main_condition,
+        WeatherCondition::Rain
+    );
+    assert!(digest
+        .alerts
+        .iter()
+        .any(|alert| alert.start == now() + Duration::hours(9)));
+    // The storm keeps the best window away from the afternoon
+    let window = digest.best_window.as_ref().unwrap();
+    assert_eq!(window.end - window.start, Duration::hours(2));
+    assert!(
+        window.end <= now() + Duration::hours(9) || window.start >= now() + Duration::hours(11)
+    );
+}
+
+#[test]
+fn test_digest_text() {
+    let digest = Digest::new(
+        &forecast(),
+        &location(),
+        &ThresholdSettings::default(),
+        now(),
+        Language::English,
+    );
+
+    let subject = digest.subject();
+    assert!(subject.contains("Berlin, Sat 1 Jun"));
+    assert!(subject.contains("⚠️"));
+
+    let body = digest.body(&DisplayUnits::default());
+    assert!(body.starts_with("Good morning! The weather for Berlin:"));
+    assert!(body.contains("\nToday: "));
+    assert!(body.contains("\nTomorrow: 🌧"));
+    assert!(body.contains("80% chance of precipitation"));
+    assert!(body.contains("Alerts:\n- "));
+    assert!(body.contains("15:00–17:00"));
+    assert!(body.contains("Best time to be outside: "));
+    assert!(body.ends_with("-- \nweather_man\n"));
+}
+
+#[test]
+fn test_digest_without_alerts() {
+    let mut forecast = forecast();
+    for hour in &mut forecast.hourly {
+        hour.main_condition = WeatherCondition::Clear;
+        hour.pop = 0.0;
+    }
+    let digest = Digest::new(
+        &forecast,
+        &location(),
+        &ThresholdSettings::default(),
+        now(),
+        Language::English,
+    );
+
+    assert!(digest.alerts.is_empty());
+    assert!(!digest.subject().contains("alert"));
+    assert!(digest
+        .body(&DisplayUnits::default())
+        .contains("No alerts for the next 24 hours."));
+}
+
+#[test]
+fn test_digest_timezone_override() {
+    let config = WeatherConfig {
+        timezone: Some("America/Los_Angeles".to_string()),
+        ..WeatherConfig::default()
+    };
+    let thresholds = ThresholdSettings::default();
+    let home = Digest::new(
+        &forecast(),
+        &location(),
+        &thresholds,
+        now(),
+        Language::English,
+    );
+    let away = Digest::new(
+        &forecast(),
+        &with_display_timezone(location(), &config),
+        &thresholds,
+        now(),
+        Language::English,
+    );
+
+    // 06:00 UTC is still 31 May in Los Angeles, but the days and hours are
+    // those of the place
+    assert_eq!(away.date, home.date);
+    assert_eq!(
+        away.today.as_ref().map(|d| d.date),
+        home.today.as_ref().map(|d| d.date)
+    );
+    let starts = |digest: &Digest| digest.alerts.iter().map(|a| a.start).collect::<Vec<_>>();
+    assert_eq!(starts(&away), starts(&home));
+    assert_eq!(
+        away.best_window.as_ref().map(|w| w.start),
+        home.best_window.as_ref().map(|w| w.start)
+    );
+
+    // Only the clock changes
+    let units = DisplayUnits::default();
+    assert!(home.body(&units).contains("15:00–17:00"));
+    assert!(away.body(&units).contains("08:00–10:00"));
+    assert!(!away.body(&units).contains("15:00–17:00"));
+}
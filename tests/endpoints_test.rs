@@ -0,0 +1,116 @@
+use mockito::Matcher;
+use weather_man::modules::config::{ApiSettings, ConfigFile};
+use weather_man::modules::forecaster::{WeatherForecaster, OPENMETEO_BASE_URL};
+use weather_man::modules::http::{parse_base_url, Endpoints, HttpContext};
+use weather_man::modules::location::NOMINATIM_BASE_URL;
+use weather_man::modules::types::{Location, WeatherConfig};
+
+fn forecaster_for(base_url: &str) -> WeatherForecaster {
+    let endpoints = Endpoints::from_settings(&ApiSettings {
+        openmeteo_url: Some(base_url.to_string()),
+        nominatim_url: None,
+    })
+    .unwrap();
+    WeatherForecaster::new(
+        WeatherConfig::default(),
+        HttpContext::default().with_endpoints(endpoints),
+    )
+}
+
+#[test]
+fn test_default_endpoints() {
+    let endpoints = Endpoints::from_settings(&ApiSettings::default()).unwrap();
+    assert_eq!(endpoints.openmeteo, OPENMETEO_BASE_URL);
+    assert_eq!(endpoints.nominatim, NOMINATIM_BASE_URL);
+}
+
+#[test]
+fn test_config_api_section() {
+    let config = ConfigFile::parse(
+        r#"
+[api]
+openmeteo_url = "https://meteo.example.org/v1/"
+nominatim_url = "http://[::1]:8080"
+"#,
+    )
+    .unwrap();
+    let endpoints = Endpoints::from_settings(&config.api).unwrap();
+    assert_eq!(endpoints.openmeteo, "https://meteo.example.org/v1");
+    assert_eq!(endpoints.nominatim, "http://[::1]:8080");
+}
+
+#[test]
+fn test_invalid_base_urls() {
+    assert!(parse_base_url("meteo.example.org/v1").is_err());
+    assert!(parse_base_url("ftp://meteo.example.org").is_err());
+    assert!(parse_base_url("https://meteo.example.org/v1?key=1").is_err());
+    assert!(Endpoints::from_settings(&ApiSettings {
+        openmeteo_url: None,
+        nominatim_url: Some("not a url".to_string()),
+    })
+    .is_err());
+}
+
+#[tokio::test]
+async fn test_forecast_from_self_hosted_instance() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/v1/forecast")
+        .match_query(Matcher::UrlEncoded("forecast_days".into(), "7".into()))
+        .with_header("content-type", "application/json")
+        .with_body(include_str!("fixtures/openmeteo_kmh.json"))
+        .create_async()
+        .await;
+
+    let forecaster = forecaster_for(&format!("{}/v1/", server.url()));
+    let forecast = forecaster.get_forecast(&Location::default()).await.unwrap();
+
+    mock.assert_async().await;
+    assert!(forecast.current.is_some());
+    assert!(!forecast.hourly.is_empty());
+}
+
+#[tokio::test]
+async fn test_self_hosted_api_error() {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("GET", Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error": true, "reason": "Cannot initialize WeatherVariable"}"#)
+        .create_async()
+        .await;
+
+    let forecaster = forecaster_for(&server.url());
+    let error = forecaster
+        .get_current_weather(&Location::default())
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Cannot initialize"));
+}
+
+#[tokio::test]
+async fn test_forecast_from_chosen_model() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/v1/forecast")
+        .match_query(Matcher::UrlEncoded("models".into(), "gfs_seamless".into()))
+        .with_header("content-type", "application/json")
+        .with_body(include_str!("fixtures/openmeteo_kmh.json"))
+        .create_async()
+        .await;
+
+    let forecaster = forecaster_for(&format!("{}/v1", server.url())).with_model("gfs_seamless");
+    assert_eq!(forecaster.provider(), "open-meteo/gfs_seamless");
+    forecaster
+        .get_current_weather(&Location::default())
+        .await
+        .unwrap();
+    mock.assert_async().await;
+
+    assert_eq!(
+        forecaster_for(&server.url())
+            .with_model("best_match")
+            .provider(),
+        "open-meteo"
+    );
+}
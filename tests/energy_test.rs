@@ -0,0 +1,50 @@
+use chrono::{TimeZone, Utc};
+use weather_man::modules::config::EnergySettings;
+use weather_man::modules::energy::{
+    hub_wind_speed, plane_irradiance, pv_output, solar_position, turbine_output,
+};
+
+#[test]
+fn test_solar_position_at_noon_and_morning() {
+    // London around the March equinox: sun due south, about 38° high at noon
+    let noon = Utc.with_ymd_and_hms(2024, 3, 20, 12, 7, 0).unwrap();
+    let (zenith, azimuth) = solar_position(noon, 51.5, 0.0);
+    assert!((zenith - 51.5).abs() < 1.5, "zenith {}", zenith);
+    assert!((azimuth - 180.0).abs() < 2.0, "azimuth {}", azimuth);
+
+    let morning = Utc.with_ymd_and_hms(2024, 3, 20, 8, 0, 0).unwrap();
+    let (_, azimuth) = solar_position(morning, 51.5, 0.0);
+    assert!((90.0..150.0).contains(&azimuth), "azimuth {}", azimuth);
+}
+
+#[test]
+fn test_plane_irradiance() {
+    // Flat panels see exactly the horizontal irradiance
+    let flat = plane_irradiance(600.0, 400.0, 40.0, 180.0, 0.0, 180.0);
+    assert!((flat - 600.0).abs() < 1e-6);
+
+    // Tilting toward a low winter sun gains, facing away loses
+    let south = plane_irradiance(300.0, 250.0, 70.0, 180.0, 45.0, 180.0);
+    let north = plane_irradiance(300.0, 250.0, 70.0, 180.0, 45.0, 0.0);
+    assert!(south > 300.0);
+    assert!(north < 100.0);
+}
+
+#[test]
+fn test_pv_output_scales_with_system_size() {
+    let settings = EnergySettings::default();
+    // 1000 W/m² on a cool day: rated power less system losses, slightly derated
+    let kw = pv_output(1000.0, 0.0, &settings);
+    assert!(kw > 3.9 && kw < 4.0, "{}", kw);
+    assert_eq!(pv_output(0.0, 20.0, &settings), 0.0);
+}
+
+#[test]
+fn test_turbine_power_curve() {
+    assert_eq!(turbine_output(2.0, 1.0), 0.0);
+    assert_eq!(turbine_output(12.0, 1.0), 1.0);
+    assert_eq!(turbine_output(30.0, 1.0), 0.0);
+    let partial = turbine_output(7.0, 1.0);
+    assert!(partial > 0.1 && partial < 0.3, "{}", partial);
+    assert!(hub_wind_speed(5.0, 20.0) > 5.0);
+}
@@ -0,0 +1,57 @@
+use chrono::NaiveDate;
+use serde_json::json;
+use weather_man::modules::ensemble::{parse_ensemble, whisker};
+
+/// Two days of six-hourly steps: a control run and two members
+fn response() -> serde_json::Value {
+    json!({
+        "utc_offset_seconds": 7200,
+        "hourly": {
+            "time": [
+                "2024-06-01T00:00", "2024-06-01T06:00", "2024-06-01T12:00", "2024-06-01T18:00",
+                "2024-06-02T00:00", "2024-06-02T06:00", "2024-06-02T12:00", "2024-06-02T18:00"
+            ],
+            "temperature_2m": [12.0, 14.0, 20.0, 16.0, 13.0, 15.0, 22.0, 17.0],
+            "temperature_2m_member01": [11.0, 13.0, 18.0, 15.0, 12.0, 14.0, 17.0, 15.0],
+            "temperature_2m_member02": [13.0, 15.0, 21.0, 17.0, 14.0, 16.0, 27.0, 18.0],
+            "precipitation": [0.0, 0.0, 0.0, 0.0, 0.0, 2.0, 1.0, 0.0],
+            "precipitation_member01": [0.0, 0.0, 0.5, 0.0, 0.0, 0.0, 0.0, 0.0],
+            "precipitation_member02": [0.0, 0.0, 0.0, 0.0, 4.0, 3.0, 0.0, 0.0]
+        }
+    })
+}
+
+#[test]
+fn test_parse_ensemble() {
+    let ensemble = parse_ensemble(&response()).unwrap();
+
+    assert_eq!(ensemble.hours.len(), 8);
+    let noon = &ensemble.hours[2];
+    assert_eq!(noon.timestamp.to_rfc3339(), "2024-06-01T10:00:00+00:00");
+    assert_eq!((noon.min, noon.median, noon.max), (18.0, 20.0, 21.0));
+
+    assert_eq!(ensemble.days.len(), 2);
+    let day = &ensemble.days[1];
+    assert_eq!(day.date, NaiveDate::from_ymd_opt(2024, 6, 2).unwrap());
+    assert_eq!(day.members, 3);
+    assert_eq!(
+        (day.high_min, day.high_median, day.high_max),
+        (17.0, 22.0, 27.0)
+    );
+    assert_eq!(day.low_median, 13.0);
+    assert!((day.precipitation_chance - 2.0 / 3.0).abs() < 1e-9);
+    assert_eq!(day.precipitation_median, 3.0);
+    assert_eq!(day.precipitation_max, 7.0);
+    assert_eq!(ensemble.days[0].precipitation_chance, 0.0);
+
+    assert!(parse_ensemble(&json!({ "hourly": { "time": [] } })).is_err());
+}
+
+#[test]
+fn test_whisker() {
+    let ensemble = parse_ensemble(&response()).unwrap();
+    let day = &ensemble.days[1];
+    // 17 to 27 on a 12 to 32 scale
+    assert_eq!(whisker(day, 12.0, 32.0, 21), "     ├────●────┤     ");
+    assert_eq!(whisker(day, 17.0, 27.0, 5), "├─●─┤");
+}
@@ -0,0 +1,107 @@
+use anyhow::Context;
+use std::collections::HashSet;
+use weather_man::modules::config::ApiSettings;
+use weather_man::modules::errors::{error_json, error_message, ErrorKind, WeatherError};
+use weather_man::modules::http::{Endpoints, HttpContext};
+use weather_man::modules::location::LocationService;
+
+const KINDS: [ErrorKind; 7] = [
+    ErrorKind::Other,
+    ErrorKind::InvalidInput,
+    ErrorKind::GeocodingFailed,
+    ErrorKind::Network,
+    ErrorKind::Provider,
+    ErrorKind::Config,
+    ErrorKind::Io,
+];
+
+#[test]
+fn test_exit_codes_are_distinct() {
+    let codes: HashSet<i32> = KINDS.iter().map(|kind| kind.exit_code()).collect();
+    assert_eq!(codes.len(), KINDS.len());
+    assert!(codes.iter().all(|code| *code != 0 && *code != 130));
+    assert_eq!(ErrorKind::Other.exit_code(), 1);
+    assert_eq!(ErrorKind::InvalidInput.exit_code(), 2);
+}
+
+#[test]
+fn test_kind_found_through_context() {
+    let error = anyhow::Error::from(WeatherError::new(
+        ErrorKind::GeocodingFailed,
+        "Could not find location: Atlantis",
+    ))
+    .context("Could not fetch the forecast");
+    assert_eq!(ErrorKind::of(&error), ErrorKind::GeocodingFailed);
+
+    let io = std::fs::read_to_string("/nonexistent/weather_man.toml")
+        .context("Could not read the config")
+        .unwrap_err();
+    assert_eq!(ErrorKind::of(&io), ErrorKind::Io);
+
+    let toml = toml::from_str::<toml::Value>("units = ").unwrap_err();
+    assert_eq!(ErrorKind::of(&toml.into()), ErrorKind::Config);
+
+    assert_eq!(ErrorKind::of(&anyhow::anyhow!("odd")), ErrorKind::Other);
+}
+
+#[test]
+fn test_error_json() {
+    let error = anyhow::Error::from(WeatherError::new(
+        ErrorKind::InvalidInput,
+        "No fields given to --query",
+    ));
+    assert_eq!(
+        error_json(&error),
+        serde_json::json!({
+            "error": { "kind": "invalid_input", "message": "No fields given to --query" }
+        })
+    );
+}
+
+#[test]
+fn test_error_message_skips_repeated_causes() {
+    let error = anyhow::anyhow!("connect: dns error")
+        .context("error sending request: connect: dns error")
+        .context("Could not fetch Berlin");
+    assert_eq!(
+        error_message(&error),
+        "Could not fetch Berlin: error sending request: connect: dns error"
+    );
+}
+
+#[tokio::test]
+async fn test_unknown_place_is_geocoding_failed() {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("GET", "/search")
+        .match_query(mockito::Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    let endpoints = Endpoints::from_settings(&ApiSettings {
+        openmeteo_url: None,
+        nominatim_url: Some(server.url()),
+    })
+    .unwrap();
+    let service = LocationService::new(HttpContext::default().with_endpoints(endpoints));
+    let error = service.get_location_by_name("Atlantis").await.unwrap_err();
+
+    assert_eq!(ErrorKind::of(&error), ErrorKind::GeocodingFailed);
+    assert_eq!(error.to_string(), "Could not find location: Atlantis");
+}
+
+#[tokio::test]
+async fn test_unreachable_service_is_network() {
+    // Nothing listens on port 9 of localhost, so the connection is refused
+    let endpoints = Endpoints::from_settings(&ApiSettings {
+        openmeteo_url: None,
+        nominatim_url: Some("http://127.0.0.1:9".to_string()),
+    })
+    .unwrap();
+    let service = LocationService::new(HttpContext::default().with_endpoints(endpoints));
+    let error = service.get_location_by_name("Berlin").await.unwrap_err();
+
+    assert_eq!(ErrorKind::of(&error), ErrorKind::Network);
+}
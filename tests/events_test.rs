@@ -0,0 +1,139 @@
+mod common;
+
+use chrono::{Duration, TimeZone, Utc};
+use common::hourly;
+use weather_man::modules::config::ThresholdSettings;
+use weather_man::modules::events::{event_outlooks, outdoor_keywords, parse_ics, CalendarEvent};
+use weather_man::modules::types::WeatherCondition;
+
+const CALENDAR: &str = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+BEGIN:VEVENT\r
+SUMMARY:Picnic\r
+DTSTART;TZID=Europe/Berlin:20240601T100000\r
+DTEND;TZID=Europe/Berlin:20240601T130000\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+SUMMARY:Team sync\r
+LOCATION:https://meet.example.com/abc\r
+DTSTART:20240601T060000Z\r
+DTEND:20240601T063000Z\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+SUMMARY:Dinner with Anna\\, Ben\r
+LOCATION:Biergarten am\r
+  See\r
+DTSTART:20240601T170000\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+SUMMARY:Holiday\r
+DTSTART;VALUE=DATE:20240602\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+#[test]
+fn test_parse_ics() {
+    let events = parse_ics(CALENDAR, "Europe/Berlin");
+    assert_eq!(events.len(), 4);
+
+    let sync = &events[0];
+    assert_eq!(sync.summary, "Team sync");
+    assert_eq!(
+        sync.start,
+        Utc.with_ymd_and_hms(2024, 6, 1, 6, 0, 0).unwrap()
+    );
+
+    // TZID times are converted to UTC
+    let picnic = &events[1];
+    assert_eq!(picnic.summary, "Picnic");
+    assert_eq!(
+        picnic.start,
+        Utc.with_ymd_and_hms(2024, 6, 1, 8, 0, 0).unwrap()
+    );
+    assert_eq!(
+        picnic.end,
+        Utc.with_ymd_and_hms(2024, 6, 1, 11, 0, 0).unwrap()
+    );
+    assert!(!picnic.all_day);
+
+    // Escapes, folded lines, floating times and a default length of an hour
+    let dinner = &events[2];
+    assert_eq!(dinner.summary, "Dinner with Anna, Ben");
+    assert_eq!(dinner.location.as_deref(), Some("Biergarten am See"));
+    assert_eq!(
+        dinner.start,
+        Utc.with_ymd_and_hms(2024, 6, 1, 15, 0, 0).unwrap()
+    );
+    assert_eq!(dinner.end - dinner.start, Duration::hours(1));
+
+    let holiday = &events[3];
+    assert!(holiday.all_day);
+    assert_eq!(holiday.end - holiday.start, Duration::days(1));
+}
+
+#[test]
+fn test_outdoor_events() {
+    let events = parse_ics(CALENDAR, "Europe/Berlin");
+    let keywords = outdoor_keywords(&[]);
+    let outdoor: Vec<_> = events
+        .iter()
+        .filter(|event| event.is_outdoor(&keywords))
+        .map(|event| event.summary.as_str())
+        .collect();
+    // A keyword or a place, but not a meeting link
+    assert_eq!(outdoor, ["Picnic", "Dinner with Anna, Ben"]);
+
+    let event = CalendarEvent {
+        summary: "Office party".to_string(),
+        ..events[0].clone()
+    };
+    assert!(event.is_outdoor(&outdoor_keywords(&["Party".to_string()])));
+}
+
+#[test]
+fn test_event_outlooks_warn_about_rain() {
+    let events = parse_ics(CALENDAR, "Europe/Berlin");
+    // Cloudy all day, with rain at 10:00 UTC and a thunderstorm at 15:00
+    let mut hours = hourly(24);
+    for hour in &mut hours {
+        hour.main_condition = WeatherCondition::Clouds;
+        hour.pop = 0.1;
+    }
+    hours[10].main_condition = WeatherCondition::Rain;
+    hours[10].pop = 0.8;
+    hours[15].main_condition = WeatherCondition::Thunderstorm;
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 6, 0, 0).unwrap();
+
+    let outlooks = event_outlooks(
+        &events,
+        &hours,
+        &outdoor_keywords(&[]),
+        &ThresholdSettings::default(),
+        now,
+    );
+    assert_eq!(outlooks.len(), 2);
+
+    let picnic = &outlooks[0];
+    assert_eq!(picnic.hours, 3);
+    assert_eq!(picnic.max_pop, 0.8);
+    assert_eq!(
+        picnic.headline("Europe/Berlin"),
+        "Saturday 10:00 Picnic: 80% rain"
+    );
+
+    let dinner = &outlooks[1];
+    assert_eq!(dinner.condition, WeatherCondition::Thunderstorm);
+    assert_eq!(dinner.warnings.len(), 1);
+
+    // Events that are over are left out
+    let later = now + Duration::hours(12);
+    let outlooks = event_outlooks(
+        &events,
+        &hours,
+        &outdoor_keywords(&[]),
+        &ThresholdSettings::default(),
+        later,
+    );
+    assert!(outlooks.is_empty());
+}
@@ -0,0 +1,131 @@
+mod common;
+
+use chrono::{Duration, Utc};
+use tempfile::tempdir;
+use weather_man::modules::export::{export_chart, ChartKind};
+use weather_man::modules::types::{HourlyForecast, Location, Percent, WeatherCondition};
+
+fn sample_hourly() -> Vec<HourlyForecast> {
+    (0..24)
+        .map(|i| HourlyForecast {
+            timestamp: Utc::now() + Duration::hours(i),
+            temperature: 10.0 + i as f64 * 0.5,
+            feels_like: 9.0 + i as f64 * 0.5,
+            wind_direction: 180,
+            main_condition: WeatherCondition::Rain,
+            pop: i as f64 / 24.0,
+            clouds: Percent(80.0),
+            rain: Some(i as f64 * 0.1),
+            ..common::hour(0)
+        })
+        .collect()
+}
+
+#[test]
+fn test_export_chart_svg_and_png() {
+    let dir = tempdir().unwrap();
+    let hourly = sample_hourly();
+    let location = Location::default();
+
+    let svg_path = dir.path().join("temp.svg");
+    export_chart(&hourly, &location, ChartKind::Temperature, &svg_path).unwrap();
+    let svg = std::fs::read_to_string(&svg_path).unwrap();
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.contains("Hourly Temperature"));
+
+    let png_path = dir.path().join("precip.png");
+    export_chart(&hourly, &location, ChartKind::Precipitation, &png_path).unwrap();
+    let png = std::fs::read(&png_path).unwrap();
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+}
+
+#[test]
+fn test_export_chart_rejects_bad_input() {
+    let dir = tempdir().unwrap();
+    let location = Location::default();
+
+    let unsupported = dir.path().join("chart.gif");
+    assert!(export_chart(
+        &sample_hourly(),
+        &location,
+        ChartKind::Temperature,
+        &unsupported
+    )
+    .is_err());
+
+    let svg_path = dir.path().join("empty.svg");
+    assert!(export_chart(&[], &location, ChartKind::Temperature, &svg_path).is_err());
+}
+
+#[test]
+fn test_daily_to_ics() {
+    use weather_man::modules::export::daily_to_ics;
+    use weather_man::modules::types::DailyForecast;
+
+    let now = Utc::now();
+    let days: Vec<DailyForecast> = (0..3)
+        .map(|i| DailyForecast {
+            temp_morning: 8.0,
+            temp_day: 14.0,
+            temp_evening: 11.0,
+            temp_night: 6.0,
+            temp_min: 6.0,
+            temp_max: 14.0,
+            main_condition: WeatherCondition::Rain,
+            clouds: Percent(90.0),
+            pop: 0.8,
+            rain: Some(5.0),
+            ..common::day_at(now + Duration::days(i))
+        })
+        .collect();
+    let location = Location {
+        name: "Berlin, Germany".to_string(),
+        timezone: "UTC".to_string(),
+        ..Location::default()
+    };
+
+    let ics = daily_to_ics(&days, &location);
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    assert_eq!(ics.matches("BEGIN:VEVENT").count(), 3);
+    assert!(ics.contains(&format!(
+        "DTSTART;VALUE=DATE:{}",
+        now.date_naive().format("%Y%m%d")
+    )));
+    assert!(ics.contains("Rainy 6°/14°C"));
+    assert!(ics.contains("LOCATION:Berlin\\, Germany"));
+    assert!(ics.lines().all(|line| line.len() <= 76));
+}
+
+#[test]
+fn test_export_climate_chart() {
+    use chrono::NaiveDate;
+    use weather_man::modules::climate::ClimateComparison;
+    use weather_man::modules::export::export_climate_chart;
+
+    let days: Vec<ClimateComparison> = (0..7)
+        .map(|i| ClimateComparison {
+            date: NaiveDate::from_ymd_opt(2024, 6, 1 + i).unwrap(),
+            temp_max: 24.0 + i as f64,
+            temp_min: 12.0,
+            precipitation: if i % 2 == 0 { 3.0 } else { 0.0 },
+            normal_max: 21.0,
+            normal_min: 11.0,
+            normal_precipitation: 1.8,
+        })
+        .collect();
+    let location = Location {
+        name: "Berlin".to_string(),
+        ..Location::default()
+    };
+
+    let dir = tempdir().unwrap();
+    let svg_path = dir.path().join("climate.svg");
+    export_climate_chart(&days, &location, &svg_path).unwrap();
+    let svg = std::fs::read_to_string(&svg_path).unwrap();
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.contains("Berlin - this week against the 1991–2020 normals"));
+    assert!(svg.contains("Normal high"));
+
+    assert!(export_climate_chart(&[], &location, &svg_path).is_err());
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use common::series;
+use serde_json::json;
+use weather_man::modules::fire::{fire_alerts, fosberg_index, parse_fire, FireLevel};
+use weather_man::modules::types::{HourlyForecast, Location, Percent};
+
+#[test]
+fn test_fosberg_index() {
+    // Hot, dry and windy
+    let severe = fosberg_index(35.0, 10.0, 10.0);
+    assert!((severe - 63.3).abs() < 0.5, "{}", severe);
+    // Mild, humid and calm
+    assert!(fosberg_index(15.0, 80.0, 1.0) < 5.0);
+    // Wind alone raises the index, capped at 100
+    assert!(fosberg_index(20.0, 30.0, 8.0) > fosberg_index(20.0, 30.0, 2.0));
+    assert_eq!(fosberg_index(45.0, 2.0, 40.0), 100.0);
+}
+
+#[test]
+fn test_fire_level() {
+    assert_eq!(FireLevel::for_day(55.0, 0.0, false), FireLevel::Extreme);
+    assert_eq!(FireLevel::for_day(40.0, 0.0, false), FireLevel::High);
+    assert_eq!(FireLevel::for_day(40.0, 0.0, true), FireLevel::Extreme);
+    assert_eq!(FireLevel::for_day(10.0, 0.0, true), FireLevel::Moderate);
+    // Rain on the day keeps the risk low, drought or not
+    assert_eq!(FireLevel::for_day(60.0, 5.0, true), FireLevel::Low);
+}
+
+#[test]
+fn test_parse_fire() {
+    // Three past days and two forecast days, 4 hours each, in UTC+2
+    let dates = [
+        "2024-07-01",
+        "2024-07-02",
+        "2024-07-03",
+        "2024-07-04",
+        "2024-07-05",
+    ];
+    let times: Vec<String> = dates
+        .iter()
+        .flat_map(|date| (0..4).map(move |h| format!("{}T{:02}:00", date, h * 6)))
+        .collect();
+    let humidity: Vec<f64> = (0..20).map(|i| if i >= 12 { 12.0 } else { 60.0 }).collect();
+    let json = json!({
+        "utc_offset_seconds": 7200,
+        "hourly": {
+            "time": times,
+            "temperature_2m": vec![34.0; 20],
+            "relative_humidity_2m": humidity,
+            "wind_speed_10m": vec![9.0; 20],
+            "precipitation": vec![0.0; 20],
+        },
+        "daily": {
+            "time": dates,
+            "precipitation_sum": [6.0, 0.0, 0.5, 0.0, 0.0],
+            "et0_fao_evapotranspiration": [12.0, 14.0, 16.5, 6.0, 6.0],
+        }
+    });
+    let now = Utc.with_ymd_and_hms(2024, 7, 4, 8, 0, 0).unwrap();
+
+    let report = parse_fire(&json, now).unwrap();
+    assert_eq!(report.recent_precipitation, 6.5);
+    assert_eq!(report.recent_evapotranspiration, 42.5);
+    assert_eq!(report.deficit, 36.0);
+    assert!(report.drought);
+    assert_eq!(report.dry_days, 2);
+
+    assert_eq!(report.days.len(), 2);
+    let today = &report.days[0];
+    assert_eq!(today.date, NaiveDate::from_ymd_opt(2024, 7, 4).unwrap());
+    assert_eq!(today.min_humidity, 12.0);
+    assert_eq!(today.level, FireLevel::Extreme);
+}
+
+#[test]
+fn test_fire_alerts() {
+    let location = Location {
+        timezone: "UTC".to_string(),
+        ..Location::default()
+    };
+    // Extreme on days 1 and 2, humid on day 3, extreme again on day 4
+    let humidity: Vec<f64> = (0..96)
+        .map(|i| if (48..72).contains(&i) { 90.0 } else { 8.0 })
+        .collect();
+    let hot_and_windy = |h: &mut HourlyForecast, humidity| {
+        h.temperature = 36.0;
+        h.humidity = Percent(humidity);
+        h.wind_speed = 10.0;
+    };
+    let hourly = series(&humidity, hot_and_windy);
+
+    let alerts = fire_alerts(&hourly, &location);
+    assert_eq!(alerts.len(), 2);
+    assert_eq!(alerts[0].event, "Extreme fire weather");
+    assert_eq!(alerts[0].tags, vec!["fire"]);
+    assert_eq!(alerts[0].start, hourly[0].timestamp);
+    assert_eq!(alerts[0].end, hourly[48].timestamp);
+    assert_eq!(alerts[1].start, hourly[72].timestamp);
+
+    let mild = series(&[70.0; 24], hot_and_windy);
+    assert!(fire_alerts(&mild, &location).is_empty());
+}
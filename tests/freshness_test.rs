@@ -0,0 +1,71 @@
+use chrono::{Duration, TimeZone, Utc};
+use weather_man::modules::config::FreshnessSettings;
+use weather_man::modules::freshness::{relative_age, Freshness, Staleness};
+
+#[test]
+fn test_staleness_thresholds() {
+    let settings = FreshnessSettings::default();
+    assert_eq!(
+        Staleness::of(Duration::minutes(5), &settings),
+        Staleness::Fresh
+    );
+    assert_eq!(
+        Staleness::of(Duration::minutes(30), &settings),
+        Staleness::Aging
+    );
+    assert_eq!(
+        Staleness::of(Duration::minutes(120), &settings),
+        Staleness::Stale
+    );
+
+    let strict = FreshnessSettings {
+        warn_after: 1,
+        stale_after: 5,
+    };
+    assert_eq!(
+        Staleness::of(Duration::minutes(5), &strict),
+        Staleness::Stale
+    );
+}
+
+#[test]
+fn test_relative_age() {
+    assert_eq!(relative_age(Duration::seconds(20)), "just now");
+    assert_eq!(relative_age(Duration::minutes(3)), "3 min ago");
+    assert_eq!(relative_age(Duration::minutes(150)), "2 h ago");
+    assert_eq!(relative_age(Duration::days(3)), "3 days ago");
+}
+
+#[test]
+fn test_describe_in_local_time() {
+    let fetched = Utc.with_ymd_and_hms(2024, 6, 1, 12, 5, 0).unwrap();
+    let now = fetched + Duration::minutes(3);
+    let freshness = Freshness::new(fetched, now, &FreshnessSettings::default());
+    assert_eq!(freshness.staleness, Staleness::Fresh);
+    assert_eq!(
+        freshness.describe("Europe/Berlin"),
+        "data as of 14:05 (3 min ago)"
+    );
+
+    // Clock skew never gives a negative age
+    let freshness = Freshness::new(now, fetched, &FreshnessSettings::default());
+    assert_eq!(freshness.age, Duration::zero());
+}
+
+#[test]
+fn test_freshness_json() {
+    let fetched = Utc.with_ymd_and_hms(2024, 6, 1, 12, 5, 0).unwrap();
+    let freshness = Freshness::new(
+        fetched,
+        fetched + Duration::minutes(45),
+        &FreshnessSettings::default(),
+    );
+    assert_eq!(
+        serde_json::to_value(&freshness).unwrap(),
+        serde_json::json!({
+            "fetched": "2024-06-01T12:05:00Z",
+            "age_minutes": 45,
+            "staleness": "aging"
+        })
+    );
+}
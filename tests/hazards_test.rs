@@ -0,0 +1,82 @@
+use serde_json::json;
+use std::fs;
+use std::time::{Duration, SystemTime};
+use weather_man::modules::hazards::{
+    distance_km, is_fresh, parse_earthquakes, parse_events, HazardKind,
+};
+use weather_man::modules::types::Location;
+
+fn tokyo() -> Location {
+    Location {
+        name: "Tokyo".to_string(),
+        latitude: 35.68,
+        longitude: 139.69,
+        timezone: "Asia/Tokyo".to_string(),
+        ..Location::default()
+    }
+}
+
+#[test]
+fn test_distance_km() {
+    assert!(distance_km(35.68, 139.69, 35.68, 139.69) < 0.001);
+    // Tokyo to Osaka is about 400 km
+    let distance = distance_km(35.68, 139.69, 34.69, 135.50);
+    assert!((390.0..410.0).contains(&distance), "{}", distance);
+}
+
+#[test]
+fn test_earthquakes_filtered_by_distance() {
+    let feed = json!({
+        "features": [
+            {
+                "properties": {"mag": 5.8, "place": "near the coast of Honshu", "time": 1_700_000_000_000i64, "url": "https://example.com/quake"},
+                "geometry": {"coordinates": [141.0, 37.0, 30.0]}
+            },
+            {
+                "properties": {"mag": 6.1, "place": "Chile", "time": 1_700_000_000_000i64},
+                "geometry": {"coordinates": [-71.0, -33.0, 10.0]}
+            }
+        ]
+    });
+
+    let hazards = parse_earthquakes(&feed, &tokyo());
+    assert_eq!(hazards.len(), 1);
+    assert_eq!(hazards[0].kind, HazardKind::Earthquake);
+    assert_eq!(hazards[0].magnitude, Some(5.8));
+    assert_eq!(hazards[0].title, "near the coast of Honshu");
+}
+
+#[test]
+fn test_events_use_latest_position() {
+    let feed = json!({
+        "events": [{
+            "title": "Typhoon Example",
+            "categories": [{"id": "severeStorms"}],
+            "sources": [{"url": "https://example.com/storm"}],
+            "geometry": [
+                {"type": "Point", "date": "2023-09-01T00:00:00Z", "coordinates": [150.0, 20.0]},
+                {"type": "Point", "date": "2023-09-03T00:00:00Z", "coordinates": [139.0, 34.0]}
+            ]
+        }]
+    });
+
+    let hazards = parse_events(&feed, &tokyo());
+    assert_eq!(hazards.len(), 1);
+    assert_eq!(hazards[0].kind, HazardKind::Storm);
+    assert_eq!(hazards[0].time.to_rfc3339(), "2023-09-03T00:00:00+00:00");
+    assert!(hazards[0].distance_km < 500.0);
+}
+
+#[test]
+fn test_cache_freshness() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("earthquakes.json");
+    assert!(!is_fresh(&path, SystemTime::now()));
+
+    fs::write(&path, "{}").unwrap();
+    assert!(is_fresh(&path, SystemTime::now()));
+    assert!(!is_fresh(
+        &path,
+        SystemTime::now() + Duration::from_secs(2 * 60 * 60)
+    ));
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use chrono::{Duration, TimeZone, Utc};
+use common::{location, series_from};
+use serde_json::json;
+use weather_man::modules::config::{ConfigFile, HookSettings, ThresholdSettings};
+use weather_man::modules::hooks::{hook_payload, rain_soon, run_hook, HookEvent, Hooks};
+use weather_man::modules::utils::Language;
+
+#[test]
+fn test_hooks_config() {
+    let config = ConfigFile::parse(
+        r#"
+[hooks]
+on_rain_soon = "notify-send 'Rain soon'"
+on_refresh = "~/bin/log-weather.sh"
+"#,
+    )
+    .unwrap();
+    assert_eq!(
+        config.hooks.on_rain_soon.as_deref(),
+        Some("notify-send 'Rain soon'")
+    );
+    assert!(!Hooks::new(config.hooks).is_empty());
+    assert!(Hooks::new(HookSettings::default()).is_empty());
+    assert!(ConfigFile::parse("[hooks]\non_sunrise = \"x\"").is_err());
+}
+
+#[test]
+fn test_rain_soon_window() {
+    let start = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+    let hourly = series_from(start, &[0.1, 0.2, 0.7, 0.8, 0.3], |h, pop| h.pop = pop);
+    let thresholds = ThresholdSettings::default();
+
+    let rain = rain_soon(
+        &hourly,
+        &location(),
+        &thresholds,
+        start + Duration::minutes(30),
+        Language::English,
+    )
+    .unwrap();
+    assert_eq!(rain.start, start + Duration::hours(2));
+
+    // Three hours ahead is not soon yet
+    assert!(rain_soon(
+        &hourly,
+        &location(),
+        &thresholds,
+        start - Duration::hours(2),
+        Language::English,
+    )
+    .is_none());
+
+    // Ongoing rain still counts, from the current hour
+    let ongoing = rain_soon(
+        &hourly,
+        &location(),
+        &thresholds,
+        start + Duration::hours(3),
+        Language::English,
+    )
+    .unwrap();
+    assert_eq!(ongoing.start, start + Duration::hours(3));
+}
+
+#[test]
+fn test_hook_payload() {
+    let payload = hook_payload(HookEvent::RainSoon, &location(), json!({"pop": 0.8}));
+    assert_eq!(payload["event"], "rain_soon");
+    assert_eq!(payload["location"]["name"], "Berlin");
+    assert_eq!(payload["data"]["pop"], 0.8);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_run_hook_gets_payload_on_stdin() {
+    let dir = tempfile::tempdir().unwrap();
+    let out = dir.path().join("out.json");
+    let command = format!(
+        "cat > '{}'; echo \"$WEATHER_MAN_EVENT\" >> '{}'",
+        out.display(),
+        out.display()
+    );
+    let payload = json!({"event": "refresh"});
+
+    run_hook(&command, HookEvent::Refresh, &payload)
+        .await
+        .unwrap();
+    assert_eq!(
+        std::fs::read_to_string(&out).unwrap(),
+        "{\"event\":\"refresh\"}refresh\n"
+    );
+
+    let err = run_hook("exit 3", HookEvent::Alert, &payload)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("alert hook failed"));
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use common::hour;
+use weather_man::modules::hourly::{
+    days_needed, hourly_window, parse_date, parse_start, HourlyStart, StartDay, HOURLY_WINDOW,
+};
+
+#[test]
+fn test_parse_start() {
+    assert_eq!(parse_start("now"), Ok(HourlyStart::Now));
+    assert_eq!(
+        parse_start("Tomorrow"),
+        Ok(HourlyStart::At {
+            day: StartDay::Tomorrow,
+            hour: 0
+        })
+    );
+    assert_eq!(
+        parse_start("tomorrow 14:00"),
+        Ok(HourlyStart::At {
+            day: StartDay::Tomorrow,
+            hour: 14
+        })
+    );
+    assert_eq!(
+        parse_start("2pm"),
+        Ok(HourlyStart::At {
+            day: StartDay::Today,
+            hour: 14
+        })
+    );
+    let date = NaiveDate::from_ymd_opt(2024, 6, 12).unwrap();
+    assert_eq!(
+        parse_start("2024-06-12 12am"),
+        Ok(HourlyStart::At {
+            day: StartDay::Date(date),
+            hour: 0
+        })
+    );
+    assert_eq!(
+        parse_date("2024-06-12"),
+        Ok(HourlyStart::At {
+            day: StartDay::Date(date),
+            hour: 0
+        })
+    );
+
+    assert!(parse_start("tomorrow 25").is_err());
+    assert!(parse_start("next week").is_err());
+    assert!(parse_date("12.06.2024").is_err());
+}
+
+#[test]
+fn test_resolve_start() {
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 9, 41, 12).unwrap();
+    assert_eq!(
+        HourlyStart::Now.resolve("UTC", now),
+        Utc.with_ymd_and_hms(2024, 6, 1, 9, 0, 0).unwrap()
+    );
+    // 14:00 in Berlin is 12:00 UTC in summer
+    let start = parse_start("tomorrow 14:00").unwrap();
+    assert_eq!(
+        start.resolve("Europe/Berlin", now),
+        Utc.with_ymd_and_hms(2024, 6, 2, 12, 0, 0).unwrap()
+    );
+}
+
+#[test]
+fn test_days_needed() {
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 9, 0, 0).unwrap();
+    let at = |day: u32, hour: u32| Utc.with_ymd_and_hms(2024, 6, day, hour, 0, 0).unwrap();
+
+    assert_eq!(days_needed(at(1, 0), "UTC", now), Some(1));
+    // Tomorrow afternoon runs into the day after
+    assert_eq!(days_needed(at(2, 14), "UTC", now), Some(3));
+    assert_eq!(days_needed(at(16, 0), "UTC", now), Some(16));
+    assert_eq!(days_needed(at(17, 0), "UTC", now), None);
+    assert_eq!(
+        days_needed(
+            Utc.with_ymd_and_hms(2024, 5, 31, 12, 0, 0).unwrap(),
+            "UTC",
+            now
+        ),
+        None
+    );
+}
+
+#[test]
+fn test_hourly_window() {
+    let hourly: Vec<_> = (0..72).map(hour).collect();
+    let start = Utc.with_ymd_and_hms(2024, 6, 2, 14, 0, 0).unwrap();
+
+    let window = hourly_window(&hourly, start);
+    assert_eq!(window.len(), HOURLY_WINDOW);
+    assert_eq!(window[0].timestamp, start);
+
+    // Near the end of the forecast the window is shorter
+    let late = Utc.with_ymd_and_hms(2024, 6, 3, 20, 0, 0).unwrap();
+    assert_eq!(hourly_window(&hourly, late).len(), 4);
+}
@@ -0,0 +1,53 @@
+use reqwest::StatusCode;
+use std::fs;
+use std::time::Duration;
+use weather_man::modules::http::{read_certificates, HttpContext, RetryPolicy, USER_AGENT};
+
+#[test]
+fn test_retry_backoff_doubles() {
+    let policy = RetryPolicy::default();
+    assert_eq!(policy.attempts, 3);
+    assert_eq!(policy.delay(0), Duration::from_millis(500));
+    assert_eq!(policy.delay(1), Duration::from_secs(1));
+    assert_eq!(policy.delay(2), Duration::from_secs(2));
+}
+
+#[test]
+fn test_retry_only_transient_statuses() {
+    assert!(RetryPolicy::should_retry(StatusCode::TOO_MANY_REQUESTS));
+    assert!(RetryPolicy::should_retry(StatusCode::BAD_GATEWAY));
+    assert!(RetryPolicy::should_retry(StatusCode::SERVICE_UNAVAILABLE));
+    assert!(!RetryPolicy::should_retry(StatusCode::OK));
+    assert!(!RetryPolicy::should_retry(StatusCode::NOT_FOUND));
+    assert!(!RetryPolicy::should_retry(StatusCode::BAD_REQUEST));
+}
+
+#[test]
+fn test_user_agent_has_version() {
+    assert_eq!(
+        USER_AGENT,
+        format!("weather_man/{}", env!("CARGO_PKG_VERSION"))
+    );
+}
+
+#[test]
+fn test_network_options() {
+    assert!(HttpContext::with_network(None, None).is_ok());
+    assert!(HttpContext::with_network(Some("http://proxy.example:3128"), None).is_ok());
+    assert!(HttpContext::with_network(Some("not a url"), None).is_err());
+}
+
+#[test]
+fn test_ca_cert_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let missing = dir.path().join("missing.pem");
+    assert!(read_certificates(&missing).is_err());
+
+    let empty = dir.path().join("empty.pem");
+    fs::write(&empty, "-----BEGIN CERTIFICATE-----\n").unwrap();
+    assert!(read_certificates(&empty).is_err());
+
+    let garbage = dir.path().join("garbage.der");
+    fs::write(&garbage, [0u8, 1, 2, 3]).unwrap();
+    assert!(HttpContext::with_network(None, Some(&garbage)).is_err());
+}
@@ -0,0 +1,48 @@
+use unicode_width::UnicodeWidthStr;
+use weather_man::modules::icons::IconSet;
+use weather_man::modules::types::WeatherCondition;
+
+const CONDITIONS: [WeatherCondition; 18] = [
+    WeatherCondition::Clear,
+    WeatherCondition::Clouds,
+    WeatherCondition::Rain,
+    WeatherCondition::Drizzle,
+    WeatherCondition::Thunderstorm,
+    WeatherCondition::Snow,
+    WeatherCondition::Sleet,
+    WeatherCondition::Hail,
+    WeatherCondition::Mist,
+    WeatherCondition::Fog,
+    WeatherCondition::Smoke,
+    WeatherCondition::Haze,
+    WeatherCondition::Dust,
+    WeatherCondition::Sand,
+    WeatherCondition::Ash,
+    WeatherCondition::Squall,
+    WeatherCondition::Tornado,
+    WeatherCondition::Unknown,
+];
+
+#[test]
+fn test_single_cell_icon_sets() {
+    for condition in CONDITIONS {
+        for icons in [IconSet::Nerdfont, IconSet::Ascii] {
+            let icon = icons.condition(condition);
+            assert_eq!(icon.chars().count(), 1, "{:?} {:?}", icons, condition);
+            assert_eq!(icon.width(), 1, "{:?} {:?}", icons, condition);
+        }
+        assert!(IconSet::Ascii.condition(condition).is_ascii());
+        assert_eq!(IconSet::Emoji.condition(condition), condition.get_emoji());
+    }
+    assert_eq!(
+        IconSet::Nerdfont.condition(WeatherCondition::Clear),
+        "\u{e30d}"
+    );
+}
+
+#[test]
+fn test_icon_set_from_config() {
+    let config: weather_man::modules::config::ConfigFile =
+        toml::from_str("[general]\nicons = \"nerdfont\"\n").unwrap();
+    assert_eq!(config.general.icons, Some(IconSet::Nerdfont));
+}
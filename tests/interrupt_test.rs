@@ -0,0 +1,14 @@
+use weather_man::modules::interrupt::{restore_terminal, INTERRUPTED_EXIT_CODE, TERMINAL_RESET};
+
+#[test]
+fn test_interrupt_exit_code_matches_sigint() {
+    assert_eq!(INTERRUPTED_EXIT_CODE, 128 + 2);
+}
+
+#[test]
+fn test_terminal_reset_shows_cursor_and_resets_colors() {
+    assert!(TERMINAL_RESET.contains("\x1b[0m"));
+    assert!(TERMINAL_RESET.contains("\x1b[?25h"));
+    // Safe to call without a terminal or raw mode
+    restore_terminal();
+}
@@ -0,0 +1,46 @@
+mod common;
+
+use chrono::Duration;
+use common::{hour, series_from, start};
+use weather_man::modules::laundry::{drying_score, laundry_report, DryingVerdict};
+use weather_man::modules::types::{HourlyForecast, Percent};
+
+#[test]
+fn test_drying_score() {
+    // 20 (humidity 60%) + 20 (24 °C) + 12.5 (4 m/s)
+    let warm = HourlyForecast {
+        temperature: 24.0,
+        wind_speed: 4.0,
+        ..hour(0)
+    };
+    assert_eq!(drying_score(&warm), 53);
+    assert_eq!(drying_score(&HourlyForecast { pop: 1.0, ..warm }), 0);
+}
+
+#[test]
+fn test_laundry_report_picks_dry_afternoon() {
+    // Humid and showery from 06:00, except for a dry spell from 12:00 to 14:00
+    let weather: Vec<(f64, f64)> = (0..24)
+        .map(|i| match i {
+            6..=8 => (20.0, 0.0),
+            _ => (90.0, 0.6),
+        })
+        .collect();
+    let warm_breeze = |h: &mut HourlyForecast, (humidity, pop)| {
+        h.temperature = 24.0;
+        h.humidity = Percent(humidity);
+        h.wind_speed = 4.0;
+        h.pop = pop;
+    };
+    let first = start() + Duration::hours(6);
+    let hourly = series_from(first, &weather, warm_breeze);
+
+    let report = laundry_report(&hourly);
+    assert_eq!(report.scores.len(), 12);
+    let window = report.best_window.unwrap();
+    assert_eq!(window.start, hourly[6].timestamp);
+    assert_eq!(report.verdict, DryingVerdict::Great);
+
+    let rainy = series_from(first, &[(90.0, 0.9); 12], warm_breeze);
+    assert_eq!(laundry_report(&rainy).verdict, DryingVerdict::Indoors);
+}
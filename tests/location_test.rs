@@ -0,0 +1,183 @@
+use std::time::{Duration, Instant};
+use weather_man::modules::config::ApiSettings;
+use weather_man::modules::http::{Endpoints, HttpContext};
+use weather_man::modules::location::{LocationService, TokenBucket};
+
+#[test]
+fn test_token_bucket_spaces_bursts() {
+    let start = Instant::now();
+    let mut bucket = TokenBucket::new(1, Duration::from_secs(1), start);
+
+    assert_eq!(bucket.take(start), Duration::ZERO);
+    assert_eq!(bucket.take(start), Duration::from_secs(1));
+    // The second caller's token is owed, so a third one waits for two intervals
+    assert_eq!(bucket.take(start), Duration::from_secs(2));
+}
+
+#[test]
+fn test_token_bucket_refills() {
+    let start = Instant::now();
+    let mut bucket = TokenBucket::new(2, Duration::from_secs(1), start);
+
+    assert_eq!(bucket.take(start), Duration::ZERO);
+    assert_eq!(bucket.take(start), Duration::ZERO);
+    let wait = bucket.take(start + Duration::from_millis(400));
+    assert!((wait.as_secs_f64() - 0.6).abs() < 1e-6);
+
+    // Idle time refills up to the capacity, not beyond
+    let later = start + Duration::from_secs(60);
+    assert_eq!(bucket.take(later), Duration::ZERO);
+    assert_eq!(bucket.take(later), Duration::ZERO);
+    assert_eq!(bucket.take(later), Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn test_ip_services_race_first_answer_wins() {
+    // Accepts connections but never answers, like a service timing out
+    let silent = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let silent_url = format!("http://{}/json", silent.local_addr().unwrap());
+
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("GET", "/broken")
+        .with_body("not json")
+        .create_async()
+        .await;
+    server
+        .mock("GET", "/json")
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"city": "Berlin", "country_name": "Germany", "country_code": "de",
+                "latitude": 52.52, "longitude": 13.4, "timezone": "Europe/Berlin"}"#,
+        )
+        .create_async()
+        .await;
+    let broken_url = format!("{}/broken", server.url());
+    let answer_url = format!("{}/json", server.url());
+
+    let service = LocationService::new(HttpContext::default());
+    let started = Instant::now();
+    let location = service
+        .get_location_from_services(&[&silent_url, &broken_url, &answer_url])
+        .await
+        .unwrap();
+    assert_eq!(location.name, "Berlin");
+    assert_eq!(location.country_code, "DE");
+    assert!(started.elapsed() < Duration::from_secs(5));
+
+    let error = service
+        .get_location_from_services(&[&broken_url])
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Could not detect location"));
+}
+
+#[tokio::test]
+async fn test_geocode_skips_reverse_lookup_with_address_details() {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("GET", "/search")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "addressdetails".into(),
+            "1".into(),
+        ))
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"[{"lat": "52.52", "lon": "13.40", "display_name": "Berlin, Deutschland",
+                 "address": {"city": "Berlin", "state": "Berlin", "country": "Deutschland",
+                             "country_code": "de"}}]"#,
+        )
+        .create_async()
+        .await;
+    let forecast = server
+        .mock("GET", "/v1/forecast")
+        .match_query(mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+    let reverse = server
+        .mock("GET", "/reverse")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let endpoints = Endpoints::from_settings(&ApiSettings {
+        openmeteo_url: Some(format!("{}/v1", server.url())),
+        nominatim_url: Some(server.url()),
+    })
+    .unwrap();
+    let service = LocationService::new(HttpContext::default().with_endpoints(endpoints));
+    let location = service.get_location_by_name("Berlin").await.unwrap();
+
+    reverse.assert_async().await;
+    forecast.assert_async().await;
+    assert_eq!(location.name, "Berlin, Deutschland");
+    assert_eq!(location.country_code, "DE");
+    assert_eq!(location.state.as_deref(), Some("Berlin"));
+    assert_eq!(location.timezone, "Europe/Berlin");
+}
+
+#[tokio::test]
+async fn test_privacy_mode_stays_offline() {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("GET", "/search")
+        .match_query(mockito::Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"[{"lat": "52.5244", "lon": "13.4105", "display_name": "Berlin",
+                 "address": {"country": "Deutschland", "country_code": "de"}}]"#,
+        )
+        .create_async()
+        .await;
+    let timezone = server
+        .mock("GET", "/v1/forecast")
+        .match_query(mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let endpoints = Endpoints::from_settings(&ApiSettings {
+        openmeteo_url: Some(format!("{}/v1", server.url())),
+        nominatim_url: Some(server.url()),
+    })
+    .unwrap();
+    let service =
+        LocationService::new(HttpContext::default().with_endpoints(endpoints)).with_privacy(true);
+    let location = service.get_location_by_name("Berlin").await.unwrap();
+
+    timezone.assert_async().await;
+    assert_eq!(location.timezone, "Europe/Berlin");
+    assert_eq!((location.latitude, location.longitude), (52.5, 13.4));
+
+    let error = service.get_location_from_ip().await.unwrap_err();
+    assert!(error.to_string().contains("privacy mode"));
+}
+
+#[tokio::test]
+async fn test_privacy_mode_resolves_us_timezone() {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("GET", "/search")
+        .match_query(mockito::Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"[{"lat": "31.7619", "lon": "-106.4850", "display_name": "El Paso, Texas",
+                 "address": {"city": "El Paso", "state": "Texas", "country": "United States",
+                             "country_code": "us"}}]"#,
+        )
+        .create_async()
+        .await;
+
+    let endpoints = Endpoints::from_settings(&ApiSettings {
+        openmeteo_url: Some(format!("{}/v1", server.url())),
+        nominatim_url: Some(server.url()),
+    })
+    .unwrap();
+    let service =
+        LocationService::new(HttpContext::default().with_endpoints(endpoints)).with_privacy(true);
+    let location = service.get_location_by_name("El Paso").await.unwrap();
+
+    assert_eq!(location.timezone, "America/Denver");
+    assert_eq!((location.latitude, location.longitude), (31.8, -106.5));
+}
@@ -0,0 +1,160 @@
+use chrono::{TimeZone, Utc};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use weather_man::modules::mail::{
+    compose_email, dot_stuff, encode_header, send_email, sender_address, SmtpSecurity, SmtpServer,
+};
+
+#[test]
+fn test_compose_email() {
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 6, 0, 0).unwrap();
+    let message = compose_email(
+        "weather@example.com",
+        Some("me@example.com"),
+        "☀️ Berlin",
+        "Sunny all day",
+        now,
+    );
+
+    assert!(message.starts_with("From: weather@example.com\nTo: me@example.com\n"));
+    assert!(message.contains("Subject: =?UTF-8?B?"));
+    assert!(message.contains("Date: Sat, 1 Jun 2024 06:00:00 +0000\n"));
+    assert!(message.contains("Content-Type: text/plain; charset=utf-8\n"));
+    assert!(message.ends_with("\n\nSunny all day\n"));
+
+    let without_recipient = compose_email("a@example.com", None, "Hi", "Body\n", now);
+    assert!(!without_recipient.contains("To:"));
+}
+
+#[test]
+fn test_encode_header() {
+    assert_eq!(encode_header("Berlin"), "Berlin");
+    assert_eq!(encode_header("Köln"), "=?UTF-8?B?S8O2bG4=?=");
+}
+
+#[test]
+fn test_dot_stuff() {
+    assert_eq!(dot_stuff("a\n.b\nc\n"), "a\r\n..b\r\nc\r\n.");
+}
+
+#[test]
+fn test_smtp_server_parse() {
+    let server = SmtpServer::parse("smtp.example.com", SmtpSecurity::Starttls).unwrap();
+    assert_eq!(
+        (server.host.as_str(), server.port),
+        ("smtp.example.com", 587)
+    );
+    let server = SmtpServer::parse("mail.local:2525", SmtpSecurity::None).unwrap();
+    assert_eq!((server.host.as_str(), server.port), ("mail.local", 2525));
+    assert_eq!(
+        SmtpServer::parse("smtp:465", SmtpSecurity::Tls)
+            .unwrap()
+            .port,
+        465
+    );
+    assert!(SmtpServer::parse("smtp.example.com:mail", SmtpSecurity::Tls).is_err());
+    assert!(SmtpServer::parse(":25", SmtpSecurity::None).is_err());
+}
+
+#[test]
+fn test_sender_address() {
+    let server = SmtpServer::parse("smtp.example.com", SmtpSecurity::Starttls).unwrap();
+    let account = server.clone().with_credentials("me@example.com", "secret");
+    let login = server.with_credentials("me", "secret");
+
+    let from = sender_address(Some("Weather <w@example.com>"), Some(&account), None).unwrap();
+    assert_eq!(from, "Weather <w@example.com>");
+    assert_eq!(
+        sender_address(None, Some(&account), Some("you@example.com")).unwrap(),
+        "me@example.com"
+    );
+    // A login name that is not an address falls through to the recipient
+    assert_eq!(
+        sender_address(None, Some(&login), Some("you@example.com")).unwrap(),
+        "you@example.com"
+    );
+
+    assert!(sender_address(None, Some(&login), None).is_err());
+    assert!(sender_address(Some("weather_man"), None, Some("you@example.com")).is_err());
+}
+
+#[tokio::test]
+async fn test_send_email_conversation() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut stream = BufReader::new(stream);
+        let mut transcript = Vec::new();
+        stream.write_all(b"220 test ESMTP\r\n").await.unwrap();
+        let mut in_data = false;
+        loop {
+            let mut line = String::new();
+            if stream.read_line(&mut line).await.unwrap() == 0 {
+                break;
+            }
+            let line = line.trim_end().to_string();
+            let reply: &[u8] = if in_data {
+                if line == "." {
+                    in_data = false;
+                    b"250 queued\r\n"
+                } else {
+                    b""
+                }
+            } else if line.starts_with("EHLO") {
+                b"250-test\r\n250 AUTH PLAIN\r\n"
+            } else if line.starts_with("AUTH PLAIN") {
+                b"235 ok\r\n"
+            } else if line == "DATA" {
+                in_data = true;
+                b"354 go ahead\r\n"
+            } else if line == "QUIT" {
+                b"221 bye\r\n"
+            } else {
+                b"250 ok\r\n"
+            };
+            transcript.push(line);
+            stream.write_all(reply).await.unwrap();
+        }
+        transcript
+    });
+
+    let smtp = SmtpServer::parse(&format!("127.0.0.1:{}", port), SmtpSecurity::None)
+        .unwrap()
+        .with_credentials("me", "secret");
+    send_email(
+        &smtp,
+        "Weather <weather@example.com>",
+        "me@example.com",
+        "Subject: Hi\n\n.hidden\nSunny\n",
+    )
+    .await
+    .unwrap();
+
+    let transcript = server.await.unwrap();
+    assert_eq!(transcript[0], "EHLO localhost");
+    // base64 of "\0me\0secret"
+    assert_eq!(transcript[1], "AUTH PLAIN AG1lAHNlY3JldA==");
+    assert_eq!(transcript[2], "MAIL FROM:<weather@example.com>");
+    assert_eq!(transcript[3], "RCPT TO:<me@example.com>");
+    assert_eq!(
+        &transcript[4..],
+        ["DATA", "Subject: Hi", "", "..hidden", "Sunny", ".", "QUIT"]
+    );
+}
+
+#[tokio::test]
+async fn test_send_email_rejected() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        stream.write_all(b"554 no service here\r\n").await.unwrap();
+    });
+
+    let smtp = SmtpServer::parse(&format!("127.0.0.1:{}", port), SmtpSecurity::None).unwrap();
+    let error = send_email(&smtp, "a@example.com", "b@example.com", "Hi\n")
+        .await
+        .unwrap_err();
+    assert_eq!(error.to_string(), "Mail server error 554: no service here");
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use common::hourly;
+use weather_man::modules::meteogram::render_meteogram;
+use weather_man::modules::types::{Location, Percent, WeatherCondition};
+
+#[test]
+fn test_meteogram_layout() {
+    let location = Location {
+        name: "Berlin".to_string(),
+        timezone: "UTC".to_string(),
+        ..Location::default()
+    };
+    // Overcast and drizzling, with one heavy shower at 05:00
+    let mut hours = hourly(72);
+    for (i, hour) in hours.iter_mut().enumerate() {
+        hour.temperature = 10.0 + (i % 24) as f64 / 2.0;
+        hour.main_condition = WeatherCondition::Rain;
+        hour.clouds = Percent(100.0);
+        hour.rain = Some(0.0);
+    }
+    hours[5].rain = Some(2.0);
+    let lines = render_meteogram(&hours, &location, 48);
+
+    assert!(lines[0].contains("next 48h"));
+    // Title, 8 temperature rows, 3 precipitation rows, clouds, two wind rows, three axis rows
+    assert_eq!(lines.len(), 18);
+
+    let clouds = lines.iter().find(|l| l.starts_with("Clouds")).unwrap();
+    assert_eq!(clouds.chars().filter(|c| *c == '█').count(), 48);
+
+    // The only rainy hour fills the whole precipitation column
+    assert!(lines[9].contains("2.0mm"));
+    assert_eq!(lines[9].chars().filter(|c| *c == '█').count(), 1);
+
+    assert!(lines[17].contains("Sat 01") && lines[17].contains("Sun 02"));
+}
+
+#[test]
+fn test_meteogram_without_data() {
+    let lines = render_meteogram(&[], &Location::default(), 48);
+    assert_eq!(lines.len(), 2);
+}
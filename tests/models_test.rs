@@ -0,0 +1,60 @@
+use chrono::{TimeZone, Utc};
+use serde_json::json;
+use weather_man::modules::models::{parse_model_comparison, parse_models};
+
+fn models() -> Vec<String> {
+    vec!["ecmwf_ifs025".to_string(), "gfs_seamless".to_string()]
+}
+
+fn response() -> serde_json::Value {
+    json!({
+        "utc_offset_seconds": 0,
+        "hourly": {
+            "time": ["2024-06-01T10:00", "2024-06-01T11:00", "2024-06-01T12:00", "2024-06-01T13:00"],
+            "temperature_2m_ecmwf_ifs025": [18.0, 19.0, 20.0, 21.0],
+            "temperature_2m_gfs_seamless": [18.5, 21.0, 24.5, null],
+            "precipitation_ecmwf_ifs025": [0.0, 0.0, 0.2, 0.0],
+            "precipitation_gfs_seamless": [0.0, 1.5, 3.0, null],
+            "wind_speed_10m_ecmwf_ifs025": [3.0, 3.5, 4.0, 4.2],
+            "wind_speed_10m_gfs_seamless": [2.0, 2.5, 6.0, null]
+        }
+    })
+}
+
+#[test]
+fn test_parse_models() {
+    assert_eq!(
+        parse_models("ECMWF_IFS025, gfs_seamless").unwrap(),
+        models()
+    );
+    assert!(parse_models("icon_seamless").is_err());
+    assert!(parse_models("a,b,c,d").is_err());
+    assert!(parse_models("icon_seamless,gfs&x=1").is_err());
+}
+
+#[test]
+fn test_model_comparison() {
+    let comparison = parse_model_comparison(&response(), &models()).unwrap();
+    assert_eq!(comparison.times.len(), 4);
+    assert_eq!(comparison.models[1].model, "gfs_seamless");
+    assert_eq!(comparison.models[1].precipitation[2], Some(3.0));
+    assert_eq!(comparison.models[1].temperature[3], None);
+
+    assert_eq!(comparison.temperature_spread(0), Some(0.5));
+    assert_eq!(comparison.temperature_spread(2), Some(4.5));
+    // Only one model has data
+    assert_eq!(comparison.temperature_spread(3), None);
+
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 11, 30, 0).unwrap();
+    let upcoming = comparison.upcoming(now, 2);
+    assert_eq!(upcoming.times.len(), 2);
+    assert_eq!(
+        upcoming.times[0],
+        Utc.with_ymd_and_hms(2024, 6, 1, 11, 0, 0).unwrap()
+    );
+    assert_eq!(upcoming.models[0].temperature, vec![Some(19.0), Some(20.0)]);
+    assert_eq!(upcoming.models[1].wind_speed, vec![Some(2.5), Some(6.0)]);
+
+    let unknown = vec!["foo".to_string(), "bar".to_string()];
+    assert!(parse_model_comparison(&response(), &unknown).is_err());
+}
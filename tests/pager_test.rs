@@ -0,0 +1,52 @@
+use std::ffi::OsString;
+use std::io::Write;
+use weather_man::modules::pager::pager_command_with;
+
+fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<OsString> + 'a {
+    move |name| {
+        vars.iter()
+            .find(|(var, _)| *var == name)
+            .map(|(_, value)| OsString::from(value))
+    }
+}
+
+#[test]
+fn test_pager_command() {
+    assert_eq!(pager_command_with(env(&[])).as_deref(), Some("less"));
+    assert_eq!(
+        pager_command_with(env(&[("PAGER", "most")])).as_deref(),
+        Some("most")
+    );
+    assert_eq!(
+        pager_command_with(env(&[("PAGER", "most"), ("WEATHER_MAN_PAGER", "less -S")])).as_deref(),
+        Some("less -S")
+    );
+    // Empty or cat turns paging off
+    assert_eq!(pager_command_with(env(&[("PAGER", "")])), None);
+    assert_eq!(
+        pager_command_with(env(&[("WEATHER_MAN_PAGER", "cat")])),
+        None
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_pager_receives_stdout() {
+    use weather_man::modules::pager::Pager;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("paged.txt");
+    let pager = Pager::start(&format!("cat > '{}'", path.display()))
+        .unwrap()
+        .unwrap();
+    // The test harness captures println!, so write to stdout directly
+    std::io::stdout()
+        .write_all(b"line one\nline two\n")
+        .unwrap();
+    pager.finish();
+
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        "line one\nline two\n"
+    );
+}
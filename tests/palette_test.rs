@@ -0,0 +1,60 @@
+use chrono::Duration;
+use ratatui::style::Color;
+use weather_man::modules::config::ConfigFile;
+use weather_man::modules::forecaster::WeatherForecaster;
+use weather_man::modules::http::HttpContext;
+use weather_man::modules::palette::{hex, ScenePalette};
+use weather_man::modules::types::{WeatherCondition, WeatherConfig};
+
+#[test]
+fn test_hex_colors() {
+    assert_eq!(hex(Color::Rgb(166, 123, 74)), "#a67b4a");
+    assert_eq!(hex(Color::Black), "#000000");
+    assert_eq!(hex(Color::White), "#ffffff");
+}
+
+#[test]
+fn test_scene_colors_follow_the_weather() {
+    let sunny = ScenePalette::new(&WeatherCondition::Clear, 20.0, true);
+    assert_eq!(sunny.sky, hex(Color::Blue));
+    assert_eq!(sunny.horizon, hex(Color::LightBlue));
+    assert_eq!(sunny.ground, hex(Color::Green));
+    assert_eq!(sunny.accent, hex(Color::Yellow));
+
+    let night = ScenePalette::new(&WeatherCondition::Clear, 20.0, false);
+    assert_eq!(night.horizon, hex(Color::Black));
+    assert_eq!(night.accent, hex(Color::White));
+
+    let storm = ScenePalette::new(&WeatherCondition::Thunderstorm, 20.0, true);
+    assert_eq!(storm.condition, "Thunderstorm");
+    assert_eq!(storm.horizon, hex(Color::Black));
+    assert_eq!(storm.accent, hex(Color::LightYellow));
+}
+
+#[test]
+fn test_palette_from_weather_and_env() {
+    let json = serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    let mut weather = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default())
+        .parse_openmeteo_forecast(&json)
+        .unwrap()
+        .current
+        .unwrap();
+    weather.timestamp = weather.sunrise + Duration::hours(1);
+    assert!(ScenePalette::from_weather(&weather).is_day);
+    weather.timestamp = weather.sunset + Duration::hours(1);
+    let palette = ScenePalette::from_weather(&weather);
+    assert!(!palette.is_day);
+
+    let env = palette.to_env();
+    assert!(env.contains("WEATHER_MAN_IS_DAY='0'\n"));
+    assert!(env.contains(&format!("WEATHER_MAN_SKY='{}'\n", palette.sky)));
+}
+
+#[test]
+fn test_palette_hook_setting() {
+    let config = ConfigFile::parse("[hooks]\non_palette = \"~/bin/set-background.sh\"\n").unwrap();
+    assert_eq!(
+        config.hooks.on_palette.as_deref(),
+        Some("~/bin/set-background.sh")
+    );
+}
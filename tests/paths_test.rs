@@ -0,0 +1,75 @@
+use std::ffi::OsString;
+use std::path::PathBuf;
+use weather_man::modules::config::ConfigFile;
+use weather_man::modules::paths::Paths;
+
+fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<OsString> + 'a {
+    move |name| {
+        vars.iter()
+            .find(|(var, _)| *var == name)
+            .map(|(_, value)| OsString::from(value))
+    }
+}
+
+#[test]
+fn test_xdg_directories() {
+    let paths = Paths::resolve_with(
+        None,
+        None,
+        env(&[
+            ("XDG_CONFIG_HOME", "/home/me/.config-alt"),
+            ("XDG_CACHE_HOME", "/tmp/cache"),
+        ]),
+    );
+    assert_eq!(
+        paths.config_file,
+        Some(PathBuf::from(
+            "/home/me/.config-alt/weather_man/config.toml"
+        ))
+    );
+    assert_eq!(
+        paths.cache_dir,
+        Some(PathBuf::from("/tmp/cache/weather_man"))
+    );
+    assert_eq!(
+        paths.forecasts_dir(),
+        Some(PathBuf::from("/tmp/cache/weather_man/forecasts"))
+    );
+    assert_eq!(
+        paths.hazards_dir(),
+        Some(PathBuf::from("/tmp/cache/weather_man/hazards"))
+    );
+
+    // Relative XDG paths are ignored
+    let relative = Paths::resolve_with(None, None, env(&[("XDG_CACHE_HOME", "cache")]));
+    assert_ne!(relative.cache_dir, Some(PathBuf::from("cache/weather_man")));
+}
+
+#[test]
+fn test_flags_override_xdg() {
+    let paths = Paths::resolve_with(
+        Some(PathBuf::from("work.toml")),
+        Some(PathBuf::from("/var/cache/weather")),
+        env(&[("XDG_CONFIG_HOME", "/home/me/.config")]),
+    );
+    assert_eq!(paths.config_file, Some(PathBuf::from("work.toml")));
+    assert_eq!(
+        paths.forecasts_dir(),
+        Some(PathBuf::from("/var/cache/weather/forecasts"))
+    );
+
+    let json = serde_json::to_value(&paths).unwrap();
+    assert_eq!(json["config_file"], "work.toml");
+}
+
+#[test]
+fn test_load_missing_config_gives_defaults() {
+    let dir = tempfile::tempdir().unwrap();
+    let missing = dir.path().join("config.toml");
+    let config = ConfigFile::load(Some(&missing)).unwrap();
+    assert!(config.favorites.locations.is_empty());
+
+    std::fs::write(&missing, "[general]\nlocation = \"Oslo\"\n").unwrap();
+    let config = ConfigFile::load(Some(&missing)).unwrap();
+    assert_eq!(config.general.location.as_deref(), Some("Oslo"));
+}
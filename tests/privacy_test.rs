@@ -0,0 +1,37 @@
+use serde_json::json;
+use weather_man::modules::privacy::{coarse_location, redact_coordinates, round_coordinate};
+use weather_man::modules::types::Location;
+
+#[test]
+fn test_round_coordinate() {
+    assert_eq!(round_coordinate(52.5244), 52.5);
+    assert_eq!(round_coordinate(13.4105), 13.4);
+    assert_eq!(round_coordinate(-33.8688), -33.9);
+}
+
+#[test]
+fn test_coarse_location() {
+    let location = coarse_location(Location {
+        latitude: 52.5244,
+        longitude: 13.4105,
+        ..Location::default()
+    });
+    assert_eq!((location.latitude, location.longitude), (52.5, 13.4));
+}
+
+#[test]
+fn test_redact_nested_coordinates() {
+    let mut value = json!({
+        "location": {"name": "Berlin", "latitude": 52.5244, "longitude": 13.4105},
+        "hazards": [{"lat": 38.123, "lon": 20.456, "magnitude": 4.78}],
+        "temperature": 21.37
+    });
+    redact_coordinates(&mut value);
+    assert_eq!(value["location"]["latitude"], 52.5);
+    assert_eq!(value["location"]["longitude"], 13.4);
+    assert_eq!(value["hazards"][0]["lat"], 38.1);
+    assert_eq!(value["hazards"][0]["lon"], 20.5);
+    // Other numbers are left alone
+    assert_eq!(value["hazards"][0]["magnitude"], 4.78);
+    assert_eq!(value["temperature"], 21.37);
+}
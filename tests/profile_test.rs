@@ -0,0 +1,54 @@
+mod common;
+
+use chrono::Duration;
+use common::{current, location};
+use weather_man::modules::profile::{profile_recommendations, ActivityProfile};
+use weather_man::modules::types::{CurrentWeather, Percent, WeatherCondition};
+
+fn weather(wind_speed: f64, wind_gusts: Option<f64>) -> CurrentWeather {
+    let current = current();
+    CurrentWeather {
+        temperature: 16.0,
+        feels_like: 15.0,
+        humidity: Percent(55.0),
+        wind_speed,
+        wind_gusts,
+        main_condition: WeatherCondition::Clouds,
+        clouds: Percent(50.0),
+        uv_index: 3.0,
+        sunset: current.timestamp + Duration::hours(8),
+        ..current
+    }
+}
+
+#[test]
+fn test_default_profile_adds_nothing() {
+    let advice =
+        profile_recommendations(ActivityProfile::Default, &weather(3.0, None), &location());
+    assert!(advice.is_empty());
+}
+
+#[test]
+fn test_cycling_warns_about_crosswind_gusts() {
+    let advice = profile_recommendations(
+        ActivityProfile::Cycling,
+        &weather(9.0, Some(16.0)),
+        &location(),
+    );
+    assert!(advice.iter().any(|a| a.contains("from the W")));
+    // A westerly hits riders heading north or south side-on
+    assert!(advice
+        .iter()
+        .any(|a| a.contains("Crosswind gusts up to 16 m/s") && a.contains("N/S")));
+}
+
+#[test]
+fn test_photography_golden_hour() {
+    let advice = profile_recommendations(
+        ActivityProfile::Photography,
+        &weather(2.0, None),
+        &location(),
+    );
+    assert_eq!(advice[0], "Evening golden hour: 19:00–20:00.");
+    assert!(advice[1].starts_with("Broken clouds"));
+}
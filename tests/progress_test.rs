@@ -0,0 +1,19 @@
+use weather_man::modules::progress::{Progress, Stage};
+
+#[test]
+fn test_stage_labels() {
+    assert_eq!(Stage::Locating.to_string(), "Locating you");
+    assert_eq!(Stage::Geocoding.to_string(), "Finding the location");
+    assert_eq!(Stage::Forecast.to_string(), "Fetching the forecast");
+    assert_eq!(Stage::Alerts.to_string(), "Checking alerts");
+}
+
+#[test]
+fn test_disabled_progress_is_silent() {
+    // Nothing to draw or clear, in any order
+    let progress = Progress::new(false);
+    progress.clear();
+    progress.stage(Stage::Forecast);
+    progress.stage(Stage::Alerts);
+    progress.clear();
+}
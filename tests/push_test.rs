@@ -0,0 +1,99 @@
+mod common;
+
+use chrono::{DateTime, Duration, Utc};
+use common::{hour, location, series_from};
+use weather_man::modules::config::ThresholdSettings;
+use weather_man::modules::push::{alert_payload, forecast_alerts, threshold_alerts, WebhookFormat};
+use weather_man::modules::types::{HourlyForecast, WeatherCondition};
+use weather_man::modules::utils::Language;
+
+fn noon() -> DateTime<Utc> {
+    common::start() + Duration::hours(12)
+}
+
+#[test]
+fn test_forecast_alerts_merge_consecutive_hours() {
+    let conditions = [
+        WeatherCondition::Clear,
+        WeatherCondition::Thunderstorm,
+        WeatherCondition::Thunderstorm,
+        WeatherCondition::Rain,
+        WeatherCondition::Snow,
+    ];
+    let mut hourly = series_from(noon(), &conditions, |h, condition| {
+        h.main_condition = condition
+    });
+    hourly.push(HourlyForecast {
+        main_condition: WeatherCondition::Tornado,
+        ..hour(32)
+    });
+
+    let alerts = forecast_alerts(&hourly, &location(), 5, noon(), Language::English);
+    assert_eq!(alerts.len(), 2);
+    assert_eq!(alerts[0].event, "Thunderstorm");
+    assert_eq!(alerts[0].end - alerts[0].start, Duration::hours(2));
+    assert_eq!(
+        alerts[0].description,
+        "Thunderstorm expected from Sat 13:00 to Sat 15:00 (in 1 hour)"
+    );
+    assert_eq!(alerts[1].tags, vec!["snow".to_string()]);
+}
+
+#[test]
+fn test_threshold_alerts() {
+    let mut hourly = series_from(noon(), &[0.5; 4], |h, pop| h.pop = pop);
+    hourly[1].wind_speed = 13.0;
+    hourly[2].wind_speed = 12.0;
+    hourly[3].pop = 0.9;
+
+    let thresholds = ThresholdSettings {
+        wind_warning: Some(12.0),
+        rain_notify_pop: Some(0.8),
+        ..Default::default()
+    };
+    let alerts = threshold_alerts(
+        &hourly,
+        &location(),
+        4,
+        &thresholds,
+        noon(),
+        Language::English,
+    );
+    assert_eq!(alerts.len(), 2);
+    assert_eq!(alerts[0].event, "Strong wind");
+    assert_eq!(alerts[0].end - alerts[0].start, Duration::hours(2));
+    assert_eq!(alerts[1].tags, vec!["rain".to_string()]);
+
+    // The default 50 % rain threshold already covers every hour
+    let alerts = threshold_alerts(
+        &hourly,
+        &location(),
+        4,
+        &ThresholdSettings::default(),
+        noon(),
+        Language::English,
+    );
+    let rain = alerts.iter().find(|a| a.event == "Rain likely").unwrap();
+    assert_eq!(rain.end - rain.start, Duration::hours(4));
+}
+
+#[test]
+fn test_alert_payload_formats() {
+    let location = location();
+    let hourly = vec![HourlyForecast {
+        main_condition: WeatherCondition::Hail,
+        ..hour(12)
+    }];
+    let alert = &forecast_alerts(&hourly, &location, 12, noon(), Language::English)[0];
+
+    let slack = alert_payload(WebhookFormat::Slack, &location, alert);
+    assert_eq!(slack["blocks"][1]["text"]["type"], "mrkdwn");
+    assert!(slack["text"].as_str().unwrap().contains("Hail"));
+
+    let discord = alert_payload(WebhookFormat::Discord, &location, alert);
+    assert_eq!(discord["embeds"][0]["description"], alert.description);
+
+    let json = alert_payload(WebhookFormat::Json, &location, alert);
+    assert_eq!(json["type"], "alert");
+    assert_eq!(json["location"]["name"], "Berlin");
+}
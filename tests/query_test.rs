@@ -0,0 +1,51 @@
+mod common;
+
+use common::current;
+use weather_man::modules::query::{format_json, format_lines, parse_fields, query_current};
+use weather_man::modules::types::{CurrentWeather, Percent, WeatherCondition};
+
+fn sample_weather() -> CurrentWeather {
+    CurrentWeather {
+        temperature: 21.5,
+        feels_like: 20.0,
+        humidity: Percent(45.0),
+        wind_speed: 3.2,
+        wind_direction: 180,
+        wind_gusts: Some(7.5),
+        main_condition: WeatherCondition::Clouds,
+        clouds: Percent(60.0),
+        uv_index: 4.0,
+        ..current()
+    }
+}
+
+#[test]
+fn test_parse_fields() {
+    assert_eq!(
+        parse_fields("temperature, Humidity,,wind_speed"),
+        vec!["temperature", "humidity", "wind_speed"]
+    );
+    assert!(parse_fields(" , ").is_empty());
+}
+
+#[test]
+fn test_query_current_values() {
+    let weather = sample_weather();
+    let fields = parse_fields("temp,humidity,condition,rain");
+    let values = query_current(&weather, &fields).unwrap();
+
+    assert_eq!(format_lines(&values), "21.5\n45.0\nClouds\n");
+    assert_eq!(
+        format_json(&values, true).unwrap(),
+        r#"{"condition":"Clouds","humidity":45.0,"rain":null,"temp":21.5}"#
+    );
+    let pretty = format_json(&values, false).unwrap();
+    assert_eq!(pretty.lines().count(), 6);
+    assert!(pretty.contains("\n  \"temp\": 21.5\n"));
+}
+
+#[test]
+fn test_query_unknown_field() {
+    let err = query_current(&sample_weather(), &["dew_point".to_string()]).unwrap_err();
+    assert!(err.to_string().contains("Unknown field 'dew_point'"));
+}
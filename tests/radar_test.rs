@@ -0,0 +1,68 @@
+use chrono::{TimeZone, Utc};
+use weather_man::modules::radar::{
+    decode_png, parse_frames, pixel_position, render_radar, RadarImage,
+};
+
+#[test]
+fn test_parse_frames_keeps_last_hour() {
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+    let json = serde_json::json!({
+        "host": "https://tilecache.rainviewer.com",
+        "radar": {
+            "past": [
+                {"time": now.timestamp() - 7200, "path": "/v2/radar/old"},
+                {"time": now.timestamp() - 600, "path": "/v2/radar/recent"},
+                {"time": now.timestamp(), "path": "/v2/radar/latest"}
+            ]
+        }
+    });
+
+    let (host, frames) = parse_frames(&json, now).unwrap();
+    assert_eq!(host, "https://tilecache.rainviewer.com");
+    let paths: Vec<_> = frames.iter().map(|f| f.path.as_str()).collect();
+    assert_eq!(paths, vec!["/v2/radar/recent", "/v2/radar/latest"]);
+
+    assert!(parse_frames(&serde_json::json!({}), now).is_err());
+}
+
+#[test]
+fn test_pixel_position() {
+    assert_eq!(pixel_position(0.0, 0.0, 0), (128.0, 128.0));
+    let (x, y) = pixel_position(52.52, 13.40, 6);
+    // Berlin lies in tile (34, 20) at zoom 6
+    assert_eq!(((x / 256.0) as u32, (y / 256.0) as u32), (34, 20));
+}
+
+#[test]
+fn test_decode_and_render_radar() {
+    // 4x4 image with rain in the top-left quarter
+    let mut rgba = Vec::new();
+    for y in 0..4 {
+        for x in 0..4 {
+            let rain = x < 2 && y < 2;
+            rgba.extend_from_slice(&[0, 120, 255, if rain { 255 } else { 0 }]);
+        }
+    }
+    let mut bytes = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut bytes, 4, 4);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .unwrap()
+            .write_image_data(&rgba)
+            .unwrap();
+    }
+
+    let image = decode_png(&bytes).unwrap();
+    assert_eq!((image.width, image.height), (4, 4));
+    assert_eq!(image.pixels[0], [0, 120, 255, 255]);
+
+    colored::control::set_override(false);
+    let lines = render_radar(&image, 4, 2);
+    assert_eq!(lines, vec!["▀▀  ", "  ◉ "]);
+
+    let empty = render_radar(&RadarImage::empty(8, 8), 3, 3);
+    assert_eq!(empty[1], " ◉ ");
+}
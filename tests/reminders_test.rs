@@ -0,0 +1,72 @@
+mod common;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use common::series_from;
+use weather_man::modules::config::{ConfigFile, ReminderSettings};
+use weather_man::modules::reminders::{Reminder, ReminderKind, ReminderSchedule};
+use weather_man::modules::types::HourlyForecast;
+
+/// 08:00 on 1 July, where fixture hours start
+fn morning() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 7, 1, 8, 0, 0).unwrap()
+}
+
+/// Sets an hour's temperature and UV index
+fn sunny(hour: &mut HourlyForecast, (temperature, uv): (f64, f64)) {
+    hour.temperature = temperature;
+    hour.uv_index = Some(uv);
+}
+
+fn kinds(reminders: &[Reminder]) -> Vec<ReminderKind> {
+    reminders.iter().map(|r| r.kind).collect()
+}
+
+#[test]
+fn test_reminders_repeat_at_their_interval() {
+    let hours = series_from(morning(), &[(32.0, 8.0); 8], sunny);
+    let settings = ReminderSettings::default();
+    let mut schedule = ReminderSchedule::new();
+    let start = Utc.with_ymd_and_hms(2024, 7, 1, 9, 10, 0).unwrap();
+    let at = |minutes: i64| start + Duration::minutes(minutes);
+
+    let first = schedule.due(&hours, &settings, at(0));
+    assert_eq!(
+        kinds(&first),
+        vec![ReminderKind::Sunscreen, ReminderKind::Hydration]
+    );
+    assert!(first[0].message.contains("UV index is 8"));
+    assert!(first[1].message.contains("32°C"));
+
+    assert!(schedule.due(&hours, &settings, at(30)).is_empty());
+    assert_eq!(
+        kinds(&schedule.due(&hours, &settings, at(60))),
+        vec![ReminderKind::Hydration]
+    );
+    assert_eq!(
+        kinds(&schedule.due(&hours, &settings, at(120))),
+        vec![ReminderKind::Sunscreen, ReminderKind::Hydration]
+    );
+}
+
+#[test]
+fn test_reminders_only_when_needed() {
+    let settings = ReminderSettings::default();
+    let mut schedule = ReminderSchedule::new();
+    let now = Utc.with_ymd_and_hms(2024, 7, 1, 9, 0, 0).unwrap();
+    let mild = series_from(morning(), &[(24.0, 4.0); 8], sunny);
+    assert!(schedule.due(&mild, &settings, now).is_empty());
+    // Outside the forecast
+    let hot = series_from(morning(), &[(35.0, 9.0); 8], sunny);
+    assert!(schedule
+        .due(&hot, &settings, now + Duration::days(1))
+        .is_empty());
+}
+
+#[test]
+fn test_reminder_settings_from_config() {
+    let config: ConfigFile =
+        toml::from_str("[reminders]\nsunscreen_uv = 3.0\nhydration_every_minutes = 30\n").unwrap();
+    assert_eq!(config.reminders.sunscreen_uv, 3.0);
+    assert_eq!(config.reminders.hydration_every_minutes, 30);
+    assert_eq!(config.reminders.sunscreen_every_minutes, 120);
+}
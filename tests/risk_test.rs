@@ -0,0 +1,75 @@
+mod common;
+
+use chrono::{TimeZone, Utc};
+use common::{day_at, hour, location, series_from};
+use weather_man::modules::risk::{assess_risk, RiskLevel};
+use weather_man::modules::types::{DailyForecast, HourlyForecast, Hpa, Location, WeatherCondition};
+
+fn day(condition: WeatherCondition, gusts: f64, precipitation: f64) -> DailyForecast {
+    DailyForecast {
+        wind_gusts: Some(gusts),
+        main_condition: condition,
+        precipitation: Some(precipitation),
+        ..day_at(Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap())
+    }
+}
+
+#[test]
+fn test_quiet_day_is_low_risk() {
+    let risks = assess_risk(&[day(WeatherCondition::Clear, 6.0, 0.0)], &[], &location());
+    assert_eq!(risks[0].level, RiskLevel::Low);
+    assert!(risks[0].reasons.is_empty());
+}
+
+#[test]
+fn test_signals_combine_with_reasons() {
+    // Gusts and rain alone are moderate
+    let risks = assess_risk(&[day(WeatherCondition::Rain, 15.0, 20.0)], &[], &location());
+    assert_eq!(risks[0].level, RiskLevel::Moderate);
+    assert_eq!(risks[0].reasons.len(), 2);
+
+    // Thunderstorms plus a 6 hPa fall in three hours escalate to severe
+    let hourly = series_from(
+        Utc.with_ymd_and_hms(2024, 7, 1, 6, 0, 0).unwrap(),
+        &[1012.0, 1010.0, 1008.0, 1006.0, 1005.0],
+        |h, pressure| h.pressure = Hpa(pressure),
+    );
+    let risks = assess_risk(
+        &[day(WeatherCondition::Thunderstorm, 12.0, 5.0)],
+        &hourly,
+        &location(),
+    );
+    assert_eq!(risks[0].level, RiskLevel::Severe);
+    assert_eq!(
+        risks[0].reasons,
+        vec![
+            "Thunderstorms forecast",
+            "Pressure falling 6 hPa in 3 hours"
+        ]
+    );
+}
+
+#[test]
+fn test_day_matches_local_hours_east_of_plus_twelve() {
+    // Auckland is UTC+13 in January, so the day's 12:00 UTC is already the
+    // next local day; its hours must still be the ones on its own date
+    let location = Location {
+        timezone: "Pacific/Auckland".to_string(),
+        ..location()
+    };
+    let date = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+    let daily = DailyForecast {
+        date,
+        ..day(WeatherCondition::Clouds, 6.0, 0.0)
+    };
+    // 15:00 on 15 January in Auckland
+    let storm = HourlyForecast {
+        timestamp: Utc.with_ymd_and_hms(2024, 1, 15, 2, 0, 0).unwrap(),
+        main_condition: WeatherCondition::Thunderstorm,
+        ..hour(0)
+    };
+
+    let risks = assess_risk(&[daily], &[storm], &location);
+    assert_eq!(risks[0].date, date.date_naive());
+    assert_eq!(risks[0].level, RiskLevel::High);
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use chrono::Duration;
+use common::{series, series_from, start};
+use weather_man::modules::roads::{road_blocks, Risk, RoadVerdict};
+use weather_man::modules::types::HourlyForecast;
+
+/// Sets an hour's temperature, rain and snow
+fn road(hour: &mut HourlyForecast, (temperature, rain, snow): (f64, f64, f64)) {
+    hour.temperature = temperature;
+    hour.rain = Some(rain);
+    hour.snow = Some(snow);
+}
+
+#[test]
+fn test_blocks_cover_next_day() {
+    let hours = series(&[(8.0, 0.0, 0.0); 30], road);
+    let blocks = road_blocks(&[], &hours);
+    assert_eq!(blocks.len(), 8);
+    assert!(blocks.iter().all(|b| b.verdict == RoadVerdict::Go));
+    assert_eq!(blocks[1].start, hours[3].timestamp);
+    assert_eq!(blocks[1].end, hours[6].timestamp);
+}
+
+#[test]
+fn test_wet_road_freezing_after_rain() {
+    // Rain ended just before the window; temperatures drop below zero
+    let history = series(&[(3.0, 1.2, 0.0)], road);
+    let upcoming = series_from(
+        start() + Duration::hours(1),
+        &[(-1.0, 0.0, 0.0), (-2.0, 0.0, 0.0), (-2.0, 0.0, 0.0)],
+        road,
+    );
+    let blocks = road_blocks(&history, &upcoming);
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(blocks[0].black_ice, Risk::High);
+    assert_eq!(blocks[0].verdict, RoadVerdict::Avoid);
+}
+
+#[test]
+fn test_aquaplaning_and_snow() {
+    let heavy_rain = series(&[(12.0, 5.0, 0.0), (12.0, 1.0, 0.0)], road);
+    let blocks = road_blocks(&[], &heavy_rain);
+    assert_eq!(blocks[0].aquaplaning, Risk::Moderate);
+    assert_eq!(blocks[0].verdict, RoadVerdict::Caution);
+
+    let snow = series(&[(-3.0, 0.0, 2.0), (-3.0, 0.0, 3.5)], road);
+    let blocks = road_blocks(&[], &snow);
+    assert!((blocks[0].snowfall - 5.5).abs() < 1e-9);
+    assert_eq!(blocks[0].verdict, RoadVerdict::Avoid);
+}
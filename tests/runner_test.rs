@@ -0,0 +1,231 @@
+use anyhow::{anyhow, Result};
+use futures::future::BoxFuture;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use weather_man::modules::config::ConfigFile;
+use weather_man::modules::forecaster::{RequestVariables, WeatherForecaster};
+use weather_man::modules::http::HttpContext;
+use weather_man::modules::runner::{
+    resolve_location, ForecastSource, LocationSource, Mode, Runner,
+};
+use weather_man::modules::types::{
+    CurrentWeather, DailyForecast, Forecast, HourlyForecast, Location, WeatherConfig,
+};
+use weather_man::modules::ui::WeatherUI;
+
+/// Serves the Open-Meteo fixture instead of calling the API
+struct FixtureForecast(Forecast);
+
+impl FixtureForecast {
+    fn new() -> Self {
+        let json = serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+        let forecast = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default())
+            .parse_openmeteo_forecast(&json)
+            .unwrap();
+        Self(forecast)
+    }
+}
+
+impl ForecastSource for FixtureForecast {
+    fn current_weather<'a>(
+        &'a self,
+        _location: &'a Location,
+    ) -> BoxFuture<'a, Result<CurrentWeather>> {
+        Box::pin(async {
+            self.0
+                .current
+                .clone()
+                .ok_or_else(|| anyhow!("no current data"))
+        })
+    }
+
+    fn hourly_forecast<'a>(
+        &'a self,
+        _location: &'a Location,
+    ) -> BoxFuture<'a, Result<Vec<HourlyForecast>>> {
+        Box::pin(async { Ok(self.0.hourly.clone()) })
+    }
+
+    fn daily_forecast<'a>(
+        &'a self,
+        _location: &'a Location,
+    ) -> BoxFuture<'a, Result<Vec<DailyForecast>>> {
+        Box::pin(async { Ok(self.0.daily.clone()) })
+    }
+
+    fn forecast<'a>(&'a self, _location: &'a Location) -> BoxFuture<'a, Result<Forecast>> {
+        Box::pin(async { Ok(self.0.clone()) })
+    }
+}
+
+/// Counts lookups by name; IP detection always fails
+#[derive(Default)]
+struct FixedLocation {
+    lookups: AtomicUsize,
+}
+
+impl LocationSource for FixedLocation {
+    fn location_by_name<'a>(&'a self, name: &'a str) -> BoxFuture<'a, Result<Location>> {
+        self.lookups.fetch_add(1, Ordering::SeqCst);
+        Box::pin(async move {
+            Ok(Location {
+                name: name.to_string(),
+                timezone: "UTC".to_string(),
+                ..Location::default()
+            })
+        })
+    }
+
+    fn location_from_ip(&self) -> BoxFuture<'_, Result<Location>> {
+        Box::pin(async { Err(anyhow!("offline")) })
+    }
+}
+
+fn json_config(location: Option<&str>) -> WeatherConfig {
+    WeatherConfig {
+        location: location.map(str::to_string),
+        json_output: true,
+        animation_enabled: false,
+        no_charts: true,
+        ..WeatherConfig::default()
+    }
+}
+
+#[test]
+fn test_mode_names_round_trip() {
+    for mode in Mode::ALL {
+        assert_eq!(mode.name().parse::<Mode>().unwrap(), mode);
+    }
+    let err = "weekly".parse::<Mode>().unwrap_err().to_string();
+    assert!(err.contains("best-window"));
+}
+
+#[test]
+fn test_minimal_variables_per_mode() {
+    let uv = Mode::Uv.minimal_variables(true).query();
+    assert!(uv.starts_with("&hourly=temperature_2m,"));
+    assert!(uv.ends_with(",uv_index"));
+    assert!(!uv.contains("daily=") && !uv.contains("current="));
+
+    let current = Mode::Current.minimal_variables(false).query();
+    assert!(current.starts_with("&current=") && !current.contains("hourly="));
+
+    // The charts after a report need the whole forecast
+    assert_eq!(
+        Mode::Current.minimal_variables(true),
+        RequestVariables::full()
+    );
+}
+
+#[tokio::test]
+async fn test_runner_uses_injected_sources() {
+    let locations = Arc::new(FixedLocation::default());
+    let config = json_config(Some("Berlin"));
+    let mut settings = ConfigFile::default();
+    settings.templates.output = Some("${temp}°".to_string());
+    let runner = Runner::new(
+        Arc::new(FixtureForecast::new()),
+        locations.clone(),
+        Arc::new(WeatherUI::new(false, true)),
+        config,
+    )
+    .with_settings(settings);
+
+    let modes = [
+        Mode::Current,
+        Mode::Forecast,
+        Mode::Hourly,
+        Mode::Daily,
+        Mode::Full,
+        Mode::Art,
+        Mode::Big,
+        Mode::Sun,
+        Mode::BestWindow,
+        Mode::Laundry,
+        Mode::Uv,
+        Mode::Energy,
+        Mode::Aviation,
+        Mode::Roads,
+        Mode::Template,
+    ];
+    for mode in modes {
+        runner.run(mode).await.unwrap();
+    }
+    // The location is looked up once and reused by every later mode
+    assert_eq!(locations.lookups.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_runner_reports_location_errors() {
+    let runner = Runner::new(
+        Arc::new(FixtureForecast::new()),
+        Arc::new(FixedLocation::default()),
+        Arc::new(WeatherUI::new(false, true)),
+        json_config(None),
+    );
+
+    let err = runner.run(Mode::Current).await.unwrap_err();
+    assert_eq!(err.to_string(), "offline");
+    // Failed lookups are not remembered
+    let err = runner.run(Mode::Current).await.unwrap_err();
+    assert_eq!(err.to_string(), "offline");
+}
+
+#[tokio::test]
+async fn test_compare_mode_needs_two_providers() {
+    let runner = Runner::new(
+        Arc::new(FixtureForecast::new()),
+        Arc::new(FixedLocation::default()),
+        Arc::new(WeatherUI::new(false, true)),
+        json_config(Some("Berlin")),
+    );
+    let err = runner.run(Mode::Compare).await.unwrap_err();
+    assert!(err.to_string().contains("two providers"));
+
+    let providers: Vec<(String, Arc<dyn ForecastSource>)> = vec![
+        ("best_match".to_string(), Arc::new(FixtureForecast::new())),
+        ("gfs_seamless".to_string(), Arc::new(FixtureForecast::new())),
+    ];
+    runner
+        .with_providers(providers)
+        .run(Mode::Compare)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_favorites_fetched_for_each_location() {
+    let locations = Arc::new(FixedLocation::default());
+    let mut settings = ConfigFile::default();
+    settings.favorites.locations = vec!["Berlin".to_string(), "Oslo".to_string()];
+    let runner = Runner::new(
+        Arc::new(FixtureForecast::new()),
+        locations.clone(),
+        Arc::new(WeatherUI::new(false, true)),
+        json_config(None),
+    );
+
+    assert!(runner.run(Mode::Favorites).await.is_err());
+
+    runner
+        .with_settings(settings)
+        .run(Mode::Favorites)
+        .await
+        .unwrap();
+    assert_eq!(locations.lookups.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_timezone_override() {
+    let locations = FixedLocation::default();
+    let config = WeatherConfig {
+        timezone: Some("Asia/Tokyo".to_string()),
+        ..json_config(Some("Berlin"))
+    };
+
+    let location = resolve_location(&locations, &config).await.unwrap();
+    assert_eq!(location.name, "Berlin");
+    // Days stay those of the place; only clock times move to Tokyo
+    assert_eq!(location.timezone, "UTC");
+    assert_eq!(location.clock_timezone(), "Asia/Tokyo");
+}
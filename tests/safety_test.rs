@@ -0,0 +1,39 @@
+mod common;
+
+use common::current;
+use weather_man::modules::safety::{black_ice_risk, pavement_temperature, safety_warnings};
+use weather_man::modules::types::{CurrentWeather, DetailLevel, Percent};
+
+fn weather(temperature: f64, clouds: u8, rain: Option<f64>) -> CurrentWeather {
+    CurrentWeather {
+        temperature,
+        feels_like: temperature,
+        clouds: Percent(clouds as f64),
+        rain_last_hour: rain,
+        ..current()
+    }
+}
+
+#[test]
+fn test_pavement_temperature() {
+    assert_eq!(pavement_temperature(&weather(28.0, 0, None)), 53.0);
+    assert_eq!(pavement_temperature(&weather(28.0, 100, None)), 35.5);
+}
+
+#[test]
+fn test_black_ice_needs_precipitation() {
+    assert!(black_ice_risk(&weather(0.5, 90, Some(0.4))));
+    assert!(!black_ice_risk(&weather(0.5, 90, None)));
+    assert!(!black_ice_risk(&weather(6.0, 90, Some(0.4))));
+}
+
+#[test]
+fn test_warnings_gated_by_detail_level() {
+    let hot = weather(28.0, 0, None);
+    assert!(safety_warnings(&hot, DetailLevel::Basic).is_empty());
+    assert_eq!(safety_warnings(&hot, DetailLevel::Standard).len(), 1);
+    assert_eq!(safety_warnings(&hot, DetailLevel::Detailed).len(), 2);
+
+    let icy = weather(0.0, 90, Some(1.0));
+    assert_eq!(safety_warnings(&icy, DetailLevel::Basic).len(), 1);
+}
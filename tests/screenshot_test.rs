@@ -0,0 +1,46 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use tempfile::tempdir;
+use weather_man::modules::screenshot::{buffer_to_ansi, buffer_to_text, save_buffer};
+
+fn sample_buffer() -> Buffer {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 2));
+    buffer.set_string(0, 0, "Sunny 25°", Style::default().fg(Color::Yellow));
+    buffer.set_string(0, 1, "⣿⠁", Style::default().fg(Color::Rgb(10, 20, 30)));
+    buffer
+}
+
+#[test]
+fn test_buffer_to_ansi() {
+    let ansi = buffer_to_ansi(&sample_buffer());
+    let lines: Vec<&str> = ansi.lines().collect();
+
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].contains("Sunny 25°"));
+    assert!(lines[0].contains("\x1b[0;33;49m"));
+    assert!(lines[1].contains("38;2;10;20;30"));
+    assert!(lines.iter().all(|line| line.ends_with("\x1b[0m")));
+}
+
+#[test]
+fn test_save_buffer_by_extension() {
+    let dir = tempdir().unwrap();
+    let buffer = sample_buffer();
+
+    let text_path = dir.path().join("view.txt");
+    save_buffer(&buffer, &text_path).unwrap();
+    let text = std::fs::read_to_string(&text_path).unwrap();
+    assert!(text.contains("Sunny"));
+
+    let png_path = dir.path().join("view.png");
+    save_buffer(&buffer, &png_path).unwrap();
+    let png = std::fs::read(&png_path).unwrap();
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+}
+
+#[test]
+fn test_buffer_to_text() {
+    let text = buffer_to_text(&sample_buffer());
+    assert_eq!(text, "Sunny 25°\n⣿⠁\n");
+}
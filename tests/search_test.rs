@@ -0,0 +1,78 @@
+use chrono::Duration;
+use weather_man::modules::forecaster::WeatherForecaster;
+use weather_man::modules::http::HttpContext;
+use weather_man::modules::search::{day_text, hour_text, matches, matching_days, matching_hours};
+use weather_man::modules::types::{
+    DailyForecast, HourlyForecast, Location, WeatherCondition, WeatherConfig,
+};
+
+fn fixture() -> (Vec<HourlyForecast>, Vec<DailyForecast>) {
+    let json = serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    let forecast = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default())
+        .parse_openmeteo_forecast(&json)
+        .unwrap();
+    (forecast.hourly, forecast.daily)
+}
+
+/// A week from the fixture day, rainy on Monday and Wednesday
+fn week() -> Vec<DailyForecast> {
+    let (_, daily) = fixture();
+    (0..7)
+        .map(|i| {
+            let mut day = daily[0].clone();
+            day.date += Duration::days(i);
+            if i == 2 || i == 4 {
+                day.main_condition = WeatherCondition::Rain;
+            }
+            day
+        })
+        .collect()
+}
+
+#[test]
+fn test_day_text() {
+    let (_, daily) = fixture();
+    let text = day_text(&daily[0]);
+    assert!(text.starts_with("saturday sat 01 june 2024-06-01 cloudy"));
+    assert!(!text.contains("rain"));
+}
+
+#[test]
+fn test_hour_text() {
+    let (hourly, _) = fixture();
+    let text = hour_text(&hourly[0], &Location::default());
+    assert!(text.starts_with("10:00 saturday sat"));
+}
+
+#[test]
+fn test_matches_every_word() {
+    let text = "18:00 saturday sat rainy";
+    assert!(matches(text, "SAT"));
+    assert!(matches(text, "rain 18:"));
+    assert!(!matches(text, "rain sun"));
+    assert!(!matches(text, ""));
+    assert!(!matches(text, "   "));
+}
+
+#[test]
+fn test_matching_days() {
+    let days = week();
+    assert_eq!(matching_days(&days, "rain"), vec![2, 4]);
+    assert_eq!(matching_days(&days, "sun"), vec![1]);
+    assert_eq!(matching_days(&days, "06-03"), vec![2]);
+}
+
+#[test]
+fn test_high_chance_of_rain_matches_rain() {
+    let mut days = week();
+    days[5].pop = 0.8;
+    assert_eq!(matching_days(&days, "rain"), vec![2, 4, 5]);
+}
+
+#[test]
+fn test_matching_hours() {
+    let (hourly, _) = fixture();
+    let location = Location::default();
+    assert_eq!(matching_hours(&hourly, &location, "11:"), vec![1]);
+    assert!(matching_hours(&hourly, &location, "03:").is_empty());
+}
@@ -0,0 +1,49 @@
+use clap::ValueEnum;
+use weather_man::modules::config::ConfigFile;
+use weather_man::modules::sections::{Section, Sections};
+
+#[test]
+fn test_all_sections_by_default() {
+    let sections = Sections::default();
+    for section in Section::value_variants() {
+        assert!(sections.shows(*section));
+    }
+    assert_eq!(Sections::new(&[], &[]), sections);
+}
+
+#[test]
+fn test_show_and_hide() {
+    let only = Sections::new(&[Section::Current, Section::Risk], &[]);
+    assert!(only.shows(Section::Current));
+    assert!(only.shows(Section::Risk));
+    assert!(!only.shows(Section::Hourly));
+    assert!(!only.shows(Section::Activities));
+
+    let hidden = Sections::new(&[], &[Section::Activities, Section::Astro]);
+    assert!(!hidden.shows(Section::Activities));
+    assert!(!hidden.shows(Section::Astro));
+    assert!(hidden.shows(Section::Details));
+
+    // Hiding wins over showing
+    let both = Sections::new(&[Section::Current, Section::Hourly], &[Section::Hourly]);
+    assert!(both.shows(Section::Current));
+    assert!(!both.shows(Section::Hourly));
+}
+
+#[test]
+fn test_section_names() {
+    assert_eq!(
+        Section::from_str("recommendations", false),
+        Ok(Section::Recommendations)
+    );
+    assert!(Section::from_str("weather", false).is_err());
+
+    let config: ConfigFile =
+        toml::from_str("[general]\nsections = [\"current\", \"daily\"]\nhide = [\"astro\"]\n")
+            .unwrap();
+    assert_eq!(
+        config.general.sections,
+        vec![Section::Current, Section::Daily]
+    );
+    assert_eq!(config.general.hide, vec![Section::Astro]);
+}
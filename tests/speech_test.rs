@@ -0,0 +1,48 @@
+use chrono::{TimeZone, Utc};
+use weather_man::modules::forecaster::WeatherForecaster;
+use weather_man::modules::http::HttpContext;
+use weather_man::modules::speech::{spoken_summary, TtsEngine};
+use weather_man::modules::types::{Location, WeatherConfig};
+
+#[test]
+fn test_detect_engine() {
+    assert_eq!(
+        TtsEngine::detect_with("macos", |_| true),
+        Some(TtsEngine::Say)
+    );
+    assert_eq!(
+        TtsEngine::detect_with("windows", |_| true),
+        Some(TtsEngine::Sapi)
+    );
+    assert_eq!(
+        TtsEngine::detect_with("linux", |program| program != "espeak-ng"),
+        Some(TtsEngine::Espeak("espeak"))
+    );
+    assert_eq!(
+        TtsEngine::detect_with("linux", |program| program == "spd-say"),
+        Some(TtsEngine::SpdSay)
+    );
+    assert_eq!(TtsEngine::detect_with("linux", |_| false), None);
+}
+
+#[test]
+fn test_spoken_summary() {
+    let json = serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    let mut forecast = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default())
+        .parse_openmeteo_forecast(&json)
+        .unwrap();
+    let location = Location {
+        name: "Berlin".to_string(),
+        ..Location::default()
+    };
+
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 10, 30, 0).unwrap();
+
+    let text = spoken_summary(&forecast, &location, now).unwrap();
+    assert!(text.starts_with("In Berlin it is cloudy and 21 degrees, feeling like"));
+    assert!(text.contains("with a high of 24 degrees."));
+    assert!(!text.contains('°'));
+
+    forecast.current = None;
+    assert!(spoken_summary(&forecast, &location, now).is_none());
+}
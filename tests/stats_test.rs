@@ -0,0 +1,75 @@
+use chrono::{Duration, TimeZone, Utc};
+use weather_man::modules::stats::{UsageEvent, UsageLog, UsageStats};
+
+fn event(minutes: i64, location: &str, latency_ms: Option<u64>) -> UsageEvent {
+    UsageEvent {
+        time: Utc.with_ymd_and_hms(2024, 6, 1, 8, 0, 0).unwrap() + Duration::minutes(minutes),
+        location: location.to_string(),
+        cached: latency_ms.is_none(),
+        latency_ms,
+    }
+}
+
+#[test]
+fn test_usage_stats() {
+    let events = vec![
+        event(0, "Berlin", Some(300)),
+        event(5, "Berlin", None),
+        event(10, "Oslo", Some(500)),
+        event(15, "Berlin", None),
+    ];
+    let stats = UsageStats::from_events(&events);
+
+    assert_eq!(stats.since, Some(events[0].time));
+    assert_eq!(stats.requests, 4);
+    assert_eq!(stats.cache_hits, 2);
+    assert_eq!(stats.api_calls, 2);
+    assert_eq!(stats.hit_rate, 0.5);
+    assert_eq!(stats.average_latency_ms, Some(400));
+    assert_eq!(stats.slowest_latency_ms, Some(500));
+    assert_eq!(
+        stats.top_locations,
+        vec![("Berlin".to_string(), 3), ("Oslo".to_string(), 1)]
+    );
+
+    let empty = UsageStats::from_events(&[]);
+    assert_eq!(empty.since, None);
+    assert_eq!(empty.hit_rate, 0.0);
+    assert_eq!(empty.average_latency_ms, None);
+}
+
+#[test]
+fn test_usage_log() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("weather_man").join("usage.jsonl");
+    let log = UsageLog::new(path.clone());
+    assert!(log.read().unwrap().is_empty());
+
+    log.record(&event(0, "Berlin", Some(250))).unwrap();
+    std::fs::write(
+        &path,
+        std::fs::read_to_string(&path).unwrap() + "not json\n",
+    )
+    .unwrap();
+    log.record(&event(1, "Berlin", None)).unwrap();
+
+    let events = log.read().unwrap();
+    assert_eq!(
+        events,
+        vec![event(0, "Berlin", Some(250)), event(1, "Berlin", None)]
+    );
+}
+
+#[test]
+fn test_usage_log_is_trimmed() {
+    let dir = tempfile::tempdir().unwrap();
+    let log = UsageLog::new(dir.path().join("usage.jsonl"));
+    let name = "A location with a rather long name, to fill the log quickly";
+    for minutes in 0..6000 {
+        log.record(&event(minutes, name, Some(100))).unwrap();
+    }
+
+    let events = log.read().unwrap();
+    assert!(events.len() < 6000);
+    assert_eq!(events.last().unwrap().time, event(5999, name, None).time);
+}
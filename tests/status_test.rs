@@ -0,0 +1,55 @@
+use chrono::{TimeZone, Utc};
+use weather_man::modules::forecaster::WeatherForecaster;
+use weather_man::modules::http::HttpContext;
+use weather_man::modules::status::{status_from_forecast, write_status};
+use weather_man::modules::types::{Forecast, Location, WeatherConfig};
+
+fn forecast() -> Forecast {
+    let json = serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    WeatherForecaster::new(WeatherConfig::default(), HttpContext::default())
+        .parse_openmeteo_forecast(&json)
+        .unwrap()
+}
+
+#[test]
+fn test_status_from_forecast() {
+    let location = Location {
+        name: "Berlin".to_string(),
+        ..Location::default()
+    };
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 10, 30, 0).unwrap();
+    let status = status_from_forecast(&forecast(), &location, now).unwrap();
+
+    assert_eq!(status.location, "Berlin");
+    assert_eq!(status.temperature, 21.4);
+    assert_eq!(status.text, format!("{} 21°", status.icon));
+    assert_eq!(status.pop, 0.1);
+    assert_eq!(status.today_max, Some(23.5));
+
+    let json = serde_json::to_value(&status).unwrap();
+    assert_eq!(json["condition"], "Cloudy");
+    assert_eq!(json["updated"], "2024-06-01T10:30:00Z");
+
+    let mut empty = forecast();
+    empty.current = None;
+    assert!(status_from_forecast(&empty, &location, now).is_none());
+}
+
+#[test]
+fn test_write_status_replaces_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("cache").join("weather.json");
+
+    write_status(&path, "first").unwrap();
+    write_status(&path, "second").unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "second");
+
+    // Only the status file is left behind
+    let files: Vec<_> = std::fs::read_dir(path.parent().unwrap())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(files, vec!["weather.json"]);
+
+    assert!(write_status(dir.path().join("..").as_path(), "x").is_err());
+}
@@ -0,0 +1,79 @@
+mod common;
+
+use chrono::{DateTime, Duration, Utc};
+use common::{location, series_from};
+use weather_man::modules::summary::narrative;
+use weather_man::modules::types::{Forecast, Percent, WeatherCondition};
+use weather_man::modules::utils::Language;
+
+fn start() -> DateTime<Utc> {
+    common::start() + Duration::hours(8)
+}
+
+/// A forecast from a list of (condition, clouds, wind) per hour
+///
+/// Temperatures climb a degree an hour from 15 °C.
+fn forecast(hours: &[(WeatherCondition, f64, f64)]) -> Forecast {
+    let mut hourly = series_from(start(), hours, |h, (condition, clouds, wind)| {
+        h.main_condition = condition;
+        h.clouds = Percent(clouds);
+        h.wind_speed = wind;
+    });
+    for (i, hour) in hourly.iter_mut().enumerate() {
+        hour.temperature = 15.0 + i as f64;
+    }
+    Forecast {
+        current: None,
+        hourly,
+        daily: Vec::new(),
+        timezone_offset: 0,
+        fetched_at: None,
+        units: "metric".to_string(),
+    }
+}
+
+#[test]
+fn test_clearing_then_rain() {
+    use WeatherCondition::*;
+    let mut hours = vec![(Clouds, 90.0, 3.0); 4];
+    hours.extend(vec![(Clear, 10.0, 3.0); 6]);
+    hours.extend(vec![(Rain, 100.0, 12.0); 2]);
+
+    assert_eq!(
+        narrative(&forecast(&hours), &location(), start(), Language::English).unwrap(),
+        "Cloudy start, clearing by noon with a high of 26°. Rain arrives after 18:00 (this evening) with gusty winds."
+    );
+}
+
+#[test]
+fn test_wet_and_dry_days() {
+    use WeatherCondition::*;
+    let mut hours = vec![(Drizzle, 100.0, 3.0); 3];
+    hours.extend(vec![(Clouds, 80.0, 3.0); 3]);
+    hours.extend(vec![(Thunderstorm, 100.0, 3.0); 2]);
+    assert_eq!(
+        narrative(&forecast(&hours), &location(), start(), Language::English).unwrap(),
+        "Drizzle at first, drying out by 11:00 with a high of 22°. Thunderstorms return after 14:00 (this afternoon)."
+    );
+
+    let dry = forecast(&[(Clear, 0.0, 2.0); 3]);
+    assert_eq!(
+        narrative(&dry, &location(), start(), Language::English).unwrap(),
+        "Sunny start with a high of 17°. Staying dry."
+    );
+
+    let wet = forecast(&[(Snow, 100.0, 2.0); 3]);
+    assert_eq!(
+        narrative(&wet, &location(), start(), Language::English).unwrap(),
+        "Snow throughout with a high of 17°."
+    );
+
+    // Past hours are left out
+    assert!(narrative(
+        &dry,
+        &location(),
+        start() + Duration::hours(5),
+        Language::English
+    )
+    .is_none());
+}
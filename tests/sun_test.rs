@@ -0,0 +1,88 @@
+mod common;
+
+use chrono::{Duration, NaiveDate, TimeZone, Utc};
+use weather_man::modules::sun::{
+    format_delta, format_minutes, parse_offset, sun_times, AlarmFormat, SunAlarm, SunEvent,
+};
+use weather_man::modules::types::DailyForecast;
+
+/// A day of `common::day` with sunrise and sunset this many minutes after midnight UTC
+fn day(offset: i64, sunrise_minute: u32, sunset_minute: u32) -> DailyForecast {
+    let day = common::day(offset);
+    let midnight = day.date - Duration::hours(12);
+    DailyForecast {
+        sunrise: midnight + Duration::minutes(sunrise_minute as i64),
+        sunset: midnight + Duration::minutes(sunset_minute as i64),
+        ..day
+    }
+}
+
+#[test]
+fn test_sun_times() {
+    // 04:50-21:30 today, 04:49-21:31 tomorrow
+    let daily = vec![day(0, 290, 1290), day(1, 289, 1291)];
+
+    let sun = sun_times(&daily);
+    assert_eq!(sun.len(), 2);
+    assert_eq!(sun[0].date, NaiveDate::from_ymd_opt(2024, 6, 1).unwrap());
+    assert_eq!(sun[0].day_length_minutes, 1000);
+    assert_eq!(
+        sun[0].solar_noon,
+        Utc.with_ymd_and_hms(2024, 6, 1, 13, 10, 0).unwrap()
+    );
+    assert_eq!(sun[0].next_sunrise_delta_minutes, Some(-1));
+    assert_eq!(sun[0].next_day_length_delta_minutes, Some(2));
+    assert_eq!(sun[1].next_sunrise_delta_minutes, None);
+}
+
+#[test]
+fn test_sun_formatting() {
+    assert_eq!(format_minutes(1000), "16h 40m");
+    assert_eq!(format_minutes(65), "1h 05m");
+    assert_eq!(format_delta(-2), "-2m");
+    assert_eq!(format_delta(3), "+3m");
+}
+
+#[test]
+fn test_parse_offset() {
+    assert_eq!(parse_offset("-30m"), Ok(Duration::minutes(-30)));
+    assert_eq!(parse_offset("+1h"), Ok(Duration::hours(1)));
+    assert_eq!(parse_offset("15"), Ok(Duration::minutes(15)));
+    assert!(parse_offset("soon").is_err());
+}
+
+#[test]
+fn test_next_sun_alarm() {
+    // Sunrise at 04:50 today and 04:49 tomorrow (UTC)
+    let daily = vec![day(0, 290, 1290), day(1, 289, 1291)];
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 4, 30, 0).unwrap();
+
+    // Half an hour before today's sunrise has already passed
+    let alarm = SunAlarm::next(&daily, SunEvent::Sunrise, Duration::minutes(-30), now).unwrap();
+    assert_eq!(
+        alarm.sun_time,
+        Utc.with_ymd_and_hms(2024, 6, 2, 4, 49, 0).unwrap()
+    );
+    assert_eq!(
+        alarm.time,
+        Utc.with_ymd_and_hms(2024, 6, 2, 4, 19, 0).unwrap()
+    );
+    assert_eq!(alarm.offset_minutes, -30);
+    assert_eq!(
+        alarm.format(AlarmFormat::Epoch, "UTC"),
+        alarm.epoch.to_string()
+    );
+    assert_eq!(
+        alarm.format(AlarmFormat::Iso, "Europe/Berlin"),
+        "2024-06-02T06:19:00+02:00"
+    );
+
+    let alarm = SunAlarm::next(&daily, SunEvent::Sunset, Duration::zero(), now).unwrap();
+    assert_eq!(
+        alarm.time,
+        Utc.with_ymd_and_hms(2024, 6, 1, 21, 30, 0).unwrap()
+    );
+
+    let late = Utc.with_ymd_and_hms(2024, 6, 3, 0, 0, 0).unwrap();
+    assert!(SunAlarm::next(&daily, SunEvent::Sunrise, Duration::zero(), late).is_none());
+}
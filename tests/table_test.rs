@@ -0,0 +1,50 @@
+use colored::Colorize;
+use weather_man::modules::table::{display_width, pad, truncate, Align, Table};
+
+#[test]
+fn test_display_width() {
+    assert_eq!(display_width("21.4°C"), 6);
+    assert_eq!(display_width("☀️ Clear"), 8);
+    assert_eq!(display_width("東京"), 4);
+    assert_eq!(display_width("\x1b[1mToday\x1b[0m"), 5);
+}
+
+#[test]
+fn test_pad_and_truncate() {
+    assert_eq!(pad("東京", 6, Align::Left), "東京  ");
+    assert_eq!(pad("5%", 4, Align::Right), "  5%");
+    assert_eq!(pad("ab", 5, Align::Center), " ab  ");
+    assert_eq!(truncate("Thunderstorm", 8), "Thunder…");
+    // A wide character that does not fit is dropped whole
+    assert_eq!(pad("東京都", 5, Align::Left), "東京…");
+    // An emoji keeps its presentation selector when the text is cut after it
+    assert_eq!(truncate("🌧️ Rain", 3), "🌧️…");
+    assert_eq!(display_width(&pad("☁️ Clouds", 4, Align::Left)), 4);
+    // Colors survive truncation and are reset afterwards
+    let cut = truncate("\x1b[1mTomorrow\x1b[0m", 4);
+    assert_eq!(display_width(&cut), 4);
+    assert!(cut.starts_with("\x1b[1mTom…") && cut.ends_with("\x1b[0m"));
+}
+
+#[test]
+fn test_table_rows_line_up() {
+    colored::control::set_override(true);
+    let table = Table::new(&[(5, Align::Center), (10, Align::Left), (6, Align::Right)]);
+    let rows = [
+        table.top(),
+        table.row(&["Hour", "Weather", "Temp"]),
+        table.separator(),
+        table.row(&[
+            "9 AM".bold().to_string(),
+            "☀️ Clear".to_string(),
+            "21.4°C".to_string(),
+        ]),
+        table.row(&["12 PM", "🌧️ Rain in 東京 today", "-3.0°C"]),
+        table.row(&["1 PM"]),
+        table.bottom(),
+    ];
+    for row in &rows {
+        assert_eq!(display_width(row), 31, "{:?}", row);
+    }
+    assert_eq!(rows[5], "│ 1 PM  │            │        │");
+}
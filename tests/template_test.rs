@@ -0,0 +1,92 @@
+use chrono::{TimeZone, Utc};
+use std::collections::BTreeMap;
+use weather_man::modules::config::TemplateSettings;
+use weather_man::modules::forecaster::WeatherForecaster;
+use weather_man::modules::http::HttpContext;
+use weather_man::modules::template::{parse_template, weather_scope, Segment, TemplateEngine};
+use weather_man::modules::types::{Location, WeatherConfig};
+
+fn scope() -> rhai::Scope<'static> {
+    let json = serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    let forecast = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default())
+        .parse_openmeteo_forecast(&json)
+        .unwrap();
+    let location = Location {
+        name: "Berlin".to_string(),
+        timezone: "UTC".to_string(),
+        ..Location::default()
+    };
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 10, 30, 0).unwrap();
+    weather_scope(&forecast, &location, now)
+}
+
+#[test]
+fn test_parse_template() {
+    assert_eq!(
+        parse_template("${temp}°, map: ${#{a: 1}.a} $5").unwrap(),
+        vec![
+            Segment::Expr("temp".to_string()),
+            Segment::Text("°, map: ".to_string()),
+            Segment::Expr("#{a: 1}.a".to_string()),
+            Segment::Text(" $5".to_string()),
+        ]
+    );
+    assert!(parse_template("${temp").is_err());
+    assert!(parse_template("${ }").is_err());
+}
+
+#[test]
+fn test_render_against_forecast() {
+    let engine = TemplateEngine::new();
+    let mut scope = scope();
+
+    assert_eq!(
+        engine
+            .render(
+                "${location}: ${temp}°, bike: ${wind < 8 && pop < 0.3}",
+                &mut scope
+            )
+            .unwrap(),
+        "Berlin: 21.4°, bike: true"
+    );
+    assert_eq!(
+        engine
+            .render(
+                "${hourly[0].hour}h ${today_max.round()} ${daily[0].date} ${condition}",
+                &mut scope
+            )
+            .unwrap(),
+        "10h 24 2024-06-01 Cloudy"
+    );
+}
+
+#[test]
+fn test_alert_conditions() {
+    let engine = TemplateEngine::new();
+    let mut scope = scope();
+    let settings = TemplateSettings {
+        output: None,
+        alerts: BTreeMap::from([
+            ("gusty".to_string(), "gusts > 8".to_string()),
+            ("frost".to_string(), "today_min < 0".to_string()),
+        ]),
+    };
+
+    let report = engine.report(&settings, &mut scope).unwrap();
+    assert_eq!(report.output, None);
+    assert_eq!(report.alerts, vec!["gusty".to_string()]);
+
+    let err = engine.condition("temp + 1", &mut scope).unwrap_err();
+    assert!(err.to_string().contains("not true or false"));
+    assert!(engine.render("${missing}", &mut scope).is_err());
+}
+
+#[test]
+fn test_runaway_expression_is_stopped() {
+    let engine = TemplateEngine::new();
+    let mut scope = scope();
+    let err = engine
+        .render("${(0..10000000).reduce(|sum, x| sum + x, 0)}", &mut scope)
+        .unwrap_err();
+    assert!(err.to_string().contains("Error in"));
+}
@@ -0,0 +1,112 @@
+use chrono::{Duration, TimeZone, Utc};
+use weather_man::modules::timezones::{parse_timezone, timezone_at};
+use weather_man::modules::ui::{convert_from_local, convert_to_local, utc_offset};
+
+#[test]
+fn test_timezone_of_city() {
+    assert_eq!(timezone_at(48.14, 11.58), "Europe/Berlin"); // Munich
+    assert_eq!(timezone_at(47.61, -122.33), "America/Los_Angeles"); // Seattle
+    assert_eq!(timezone_at(34.69, 135.50), "Asia/Tokyo"); // Osaka
+    assert_eq!(timezone_at(-41.29, 174.78), "Pacific/Auckland"); // Wellington
+    assert_eq!(timezone_at(30.57, 104.07), "Asia/Shanghai"); // Chengdu
+    assert_eq!(timezone_at(28.12, -15.43), "Atlantic/Canary"); // Las Palmas
+}
+
+#[test]
+fn test_timezone_near_borders() {
+    // Either side of the US Eastern/Central and Central/Mountain lines
+    assert_eq!(timezone_at(33.75, -84.39), "America/New_York"); // Atlanta
+    assert_eq!(timezone_at(25.76, -80.19), "America/New_York"); // Miami
+    assert_eq!(timezone_at(39.77, -86.16), "America/Indiana/Indianapolis");
+    assert_eq!(timezone_at(41.59, -87.35), "America/Chicago"); // Gary
+    assert_eq!(timezone_at(32.78, -96.80), "America/Chicago"); // Dallas
+    assert_eq!(timezone_at(29.76, -95.37), "America/Chicago"); // Houston
+    assert_eq!(timezone_at(31.76, -106.49), "America/Denver"); // El Paso
+    assert_eq!(timezone_at(43.62, -116.20), "America/Boise"); // Boise
+                                                              // San Diego and Tijuana, 25 km apart
+    assert_eq!(timezone_at(32.72, -117.16), "America/Los_Angeles");
+    assert_eq!(timezone_at(32.51, -117.04), "America/Tijuana");
+    // Strasbourg and Kehl, on either bank of the Rhine
+    assert_eq!(timezone_at(48.58, 7.75), "Europe/Paris");
+    assert_eq!(timezone_at(48.57, 7.82), "Europe/Berlin");
+    assert_eq!(timezone_at(49.84, 24.03), "Europe/Kyiv"); // Lviv
+    assert_eq!(timezone_at(54.71, 20.51), "Europe/Kaliningrad");
+}
+
+#[test]
+fn test_every_zone_converts() {
+    // A zone chrono-tz does not know would show times in UTC
+    for lat in (-80..=80).step_by(10) {
+        for lon in (-180..=180).step_by(10) {
+            let zone = timezone_at(lat as f64, lon as f64);
+            assert!(zone.parse::<chrono_tz::Tz>().is_ok(), "{}", zone);
+        }
+    }
+}
+
+#[test]
+fn test_open_ocean_uses_longitude() {
+    assert_eq!(timezone_at(-40.0, -120.0), "Etc/GMT+8");
+    assert_eq!(timezone_at(-45.0, 75.0), "Etc/GMT-5");
+    assert_eq!(timezone_at(-60.0, 0.0), "Etc/GMT");
+}
+
+#[test]
+fn test_display_timezones() {
+    let winter = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+    let summer = Utc.with_ymd_and_hms(2024, 7, 15, 12, 0, 0).unwrap();
+    // Daylight saving time follows the date
+    assert_eq!(
+        utc_offset("Europe/Paris", &winter),
+        Some(Duration::hours(1))
+    );
+    assert_eq!(
+        utc_offset("Europe/Paris", &summer),
+        Some(Duration::hours(2))
+    );
+    assert_eq!(
+        utc_offset("America/Los_Angeles", &summer),
+        Some(Duration::hours(-7))
+    );
+    assert_eq!(
+        utc_offset("Asia/Kolkata", &summer),
+        Some(Duration::minutes(330))
+    );
+    assert_eq!(utc_offset("UTC", &summer), Some(Duration::zero()));
+    // POSIX signs: Etc/GMT-5 is five hours ahead of UTC
+    assert_eq!(utc_offset("Etc/GMT-5", &summer), Some(Duration::hours(5)));
+    assert_eq!(utc_offset("Etc/GMT+8", &summer), Some(Duration::hours(-8)));
+    assert_eq!(utc_offset("Mars/Olympus_Mons", &summer), None);
+
+    assert_eq!(
+        parse_timezone("America/New_York"),
+        Ok("America/New_York".to_string())
+    );
+    assert_eq!(parse_timezone("Asia/Seoul"), Ok("Asia/Seoul".to_string()));
+    assert_eq!(
+        parse_timezone("Europe/Madrid"),
+        Ok("Europe/Madrid".to_string())
+    );
+    assert!(parse_timezone("Europe/Pariss").is_err());
+}
+
+#[test]
+fn test_local_time_round_trip() {
+    let summer = Utc.with_ymd_and_hms(2024, 7, 15, 12, 0, 0).unwrap();
+    let local = convert_to_local(&summer, "Europe/Madrid");
+    assert_eq!(local.format("%H:%M").to_string(), "14:00");
+    assert_eq!(
+        convert_from_local(local.naive_utc(), "Europe/Madrid"),
+        summer
+    );
+
+    // 02:30 does not exist on the night clocks go forward in Berlin
+    let skipped = chrono::NaiveDate::from_ymd_opt(2024, 3, 31)
+        .unwrap()
+        .and_hms_opt(2, 30, 0)
+        .unwrap();
+    assert_eq!(
+        convert_from_local(skipped, "Europe/Berlin"),
+        Utc.with_ymd_and_hms(2024, 3, 31, 1, 30, 0).unwrap()
+    );
+}
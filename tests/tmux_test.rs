@@ -0,0 +1,49 @@
+use chrono::{Duration, TimeZone, Utc};
+use weather_man::modules::cache::{CachedForecast, ForecastCache};
+use weather_man::modules::forecaster::WeatherForecaster;
+use weather_man::modules::http::HttpContext;
+use weather_man::modules::tmux::{temperature_colour, tmux_line};
+use weather_man::modules::types::{Forecast, Location, WeatherConfig};
+
+fn forecast() -> Forecast {
+    let json = serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    WeatherForecaster::new(WeatherConfig::default(), HttpContext::default())
+        .parse_openmeteo_forecast(&json)
+        .unwrap()
+}
+
+#[test]
+fn test_tmux_line() {
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 10, 30, 0).unwrap();
+    assert_eq!(
+        tmux_line(&forecast(), &Location::default(), now).unwrap(),
+        "#[fg=colour214]☁️ 21°#[default]"
+    );
+    assert_eq!(temperature_colour(-3.0), 39);
+    assert_eq!(temperature_colour(15.0), 114);
+    assert_eq!(temperature_colour(35.0), 196);
+}
+
+#[test]
+fn test_forecast_cache_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = ForecastCache::new(dir.path().join("forecasts"));
+    let key = ForecastCache::key(Some(" New York, US "));
+    assert_eq!(key, "new_york__us");
+    assert_eq!(ForecastCache::key(None), "_auto");
+    assert!(cache.load(&key).is_none());
+
+    let fetched = Utc.with_ymd_and_hms(2024, 6, 1, 10, 0, 0).unwrap();
+    let cached = CachedForecast {
+        fetched,
+        location: Location::default(),
+        forecast: forecast(),
+    };
+    cache.store(&key, &cached).unwrap();
+
+    let loaded = cache.load(&key).unwrap();
+    assert_eq!(loaded.fetched, fetched);
+    assert_eq!(loaded.forecast.hourly.len(), cached.forecast.hourly.len());
+    assert!(loaded.is_fresh(fetched + Duration::minutes(10), Duration::minutes(15)));
+    assert!(!loaded.is_fresh(fetched + Duration::minutes(20), Duration::minutes(15)));
+}
@@ -0,0 +1,502 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+use ratatui::Terminal;
+use weather_man::modules::config::{ConfigFile, TuiLayout};
+use weather_man::modules::forecaster::WeatherForecaster;
+use weather_man::modules::freshness::Staleness;
+use weather_man::modules::http::HttpContext;
+use weather_man::modules::location::LocationOrigin;
+use weather_man::modules::screenshot::buffer_to_text;
+use weather_man::modules::tui::{
+    install_panic_hook, parse_key, render, render_content, Action, HeatmapMetric, KeyBindings,
+    KeyOutcome, MapView, Screen, TuiState, TuiTab, WeatherTui,
+};
+use weather_man::modules::types::{
+    DailyForecast, HourlyForecast, Location, WeatherCondition, WeatherConfig,
+};
+
+#[test]
+fn test_parse_key() {
+    assert_eq!(parse_key("j").unwrap(), KeyCode::Char('j'));
+    assert_eq!(parse_key("?").unwrap(), KeyCode::Char('?'));
+    assert_eq!(parse_key("Left").unwrap(), KeyCode::Left);
+    assert_eq!(parse_key("tab").unwrap(), KeyCode::Tab);
+    assert_eq!(parse_key("F5").unwrap(), KeyCode::F(5));
+    assert!(parse_key("F13").is_err());
+    assert!(parse_key("hyper").is_err());
+}
+
+#[test]
+fn test_keybinding_overrides() {
+    let defaults = KeyBindings::default();
+    assert_eq!(
+        defaults.action_for(KeyCode::Char('j')),
+        Some(Action::NextDay)
+    );
+    assert_eq!(
+        defaults.action_for(KeyCode::Char('?')),
+        Some(Action::ToggleHelp)
+    );
+
+    let config = ConfigFile::parse("[keybindings]\nnext_day = \"n\"\nquit = \"x\"\n").unwrap();
+    let bindings = KeyBindings::from_settings(&config.keybindings).unwrap();
+    assert_eq!(
+        bindings.action_for(KeyCode::Char('n')),
+        Some(Action::NextDay)
+    );
+    assert_eq!(bindings.action_for(KeyCode::Char('j')), None);
+    assert_eq!(bindings.action_for(KeyCode::Char('x')), Some(Action::Quit));
+    assert_eq!(bindings.action_for(KeyCode::Right), Some(Action::NextTab));
+}
+
+#[test]
+fn test_reserved_keys_cannot_be_remapped() {
+    for key in ["1", "6", "Up", "Enter", "+"] {
+        let config =
+            ConfigFile::parse(&format!("[keybindings]\nnext_day = \"{}\"\n", key)).unwrap();
+        assert!(
+            KeyBindings::from_settings(&config.keybindings).is_err(),
+            "{}",
+            key
+        );
+    }
+
+    // Restating a default binding is fine
+    let config = ConfigFile::parse("[keybindings]\nnext_tab = \"Right\"\n").unwrap();
+    assert!(KeyBindings::from_settings(&config.keybindings).is_ok());
+}
+
+#[test]
+fn test_unknown_keybinding_action_rejected() {
+    assert!(ConfigFile::parse("[keybindings]\nteleport = \"t\"\n").is_err());
+}
+
+#[test]
+fn test_heatmap_metric_cycle() {
+    let bindings = KeyBindings::default();
+    assert_eq!(
+        bindings.action_for(KeyCode::Char('m')),
+        Some(Action::CycleHeatmap)
+    );
+
+    let metric = HeatmapMetric::Precipitation;
+    assert_eq!(metric.next(), HeatmapMetric::Temperature);
+    assert_eq!(metric.next().next().next(), HeatmapMetric::Precipitation);
+    assert_ne!(metric.color(0.0), metric.color(100.0));
+}
+
+#[test]
+fn test_map_view_pan_and_zoom() {
+    let mut view = MapView::default();
+    assert_eq!(view.x_bounds(), [-180.0, 180.0]);
+    assert_eq!(view.y_bounds(), [-90.0, 90.0]);
+
+    view.zoom_out();
+    assert_eq!(view.zoom, 1.0);
+
+    view.zoom_in();
+    view.pan(1.0, -1.0);
+    assert_eq!(view.x_bounds(), [-72.0, 108.0]);
+    assert_eq!(view.y_bounds(), [-54.0, 36.0]);
+
+    for _ in 0..10 {
+        view.zoom_in();
+        view.pan(0.0, 1000.0);
+    }
+    assert_eq!(view.zoom, 32.0);
+    assert_eq!(view.latitude, 90.0);
+}
+
+#[test]
+fn test_panic_hook_chains_to_previous_hook() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    let called = Arc::new(AtomicBool::new(false));
+    let seen = called.clone();
+    std::panic::set_hook(Box::new(move |_| seen.store(true, Ordering::SeqCst)));
+    // Installing twice keeps a single wrapper around the hook above
+    install_panic_hook();
+    install_panic_hook();
+
+    assert!(std::panic::catch_unwind(|| panic!("boom")).is_err());
+    assert!(called.load(Ordering::SeqCst));
+    let _ = std::panic::take_hook();
+}
+
+fn fixture_data() -> (Vec<HourlyForecast>, Vec<DailyForecast>, Location) {
+    let json = serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    let forecast = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default())
+        .parse_openmeteo_forecast(&json)
+        .unwrap();
+    let location = Location {
+        name: "Berlin".to_string(),
+        country: "DE".to_string(),
+        ..Location::default()
+    };
+    (forecast.hourly, forecast.daily, location)
+}
+
+fn headless_tui() -> WeatherTui<TestBackend> {
+    let (hourly, daily, location) = fixture_data();
+    WeatherTui::headless(
+        hourly,
+        daily,
+        location,
+        WeatherConfig::default(),
+        &ConfigFile::default(),
+        120,
+        40,
+    )
+    .unwrap()
+}
+
+fn fixture_state() -> TuiState {
+    let (hourly, daily, location) = fixture_data();
+    TuiState::new(
+        hourly,
+        daily,
+        location,
+        WeatherConfig::default(),
+        &ConfigFile::default(),
+    )
+    .unwrap()
+}
+
+fn render_tab(tab: TuiTab) -> String {
+    let mut tui = headless_tui();
+    tui.state_mut().select_tab(tab);
+    tui.render().unwrap()
+}
+
+#[test]
+fn test_headless_render_fills_buffer() {
+    let screen = render_tab(TuiTab::CurrentWeather);
+    assert_eq!(screen.lines().count(), 40);
+    assert!(screen.contains("Weather Man - Berlin [DE"));
+    assert!(screen.contains("Current Weather │ Weather Forecast │ Weather Calendar"));
+    assert!(screen.contains("1-6 Select tab"));
+}
+
+#[test]
+fn test_headless_current_weather_tab() {
+    let screen = render_tab(TuiTab::CurrentWeather);
+    assert!(screen.contains("Weather Visualization"));
+    assert!(screen.contains("Next 24 Hours"));
+    assert!(screen.contains(" 21° "));
+}
+
+#[test]
+fn test_headless_forecast_tab() {
+    let screen = render_tab(TuiTab::WeatherForecast);
+    assert!(screen.contains("7-Day Detailed Forecast"));
+    assert!(screen.contains("Saturday 01 June"));
+    assert!(screen.contains("24°C / 12°C"));
+    assert!(screen.contains("Precip:  20%"));
+}
+
+#[test]
+fn test_headless_calendar_tab() {
+    let screen = render_tab(TuiTab::Calendar);
+    assert!(screen.contains("June 2024"));
+    assert!(screen.contains("12°/23°"));
+    assert!(screen.contains("Saturday 01 June: ☁️ Cloudy, 12°-23°, 20% rain"));
+}
+
+#[test]
+fn test_headless_heatmap_tab() {
+    let screen = render_tab(TuiTab::Heatmap);
+    assert!(screen.contains("Weekly Heatmap - Precipitation probability"));
+    assert!(screen.contains("Sat 01"));
+}
+
+#[test]
+fn test_headless_meteogram_tab() {
+    let screen = render_tab(TuiTab::Meteogram);
+    assert!(screen.contains("Meteogram - next 48h"));
+    assert!(screen.contains("21°C│"));
+}
+
+#[test]
+fn test_headless_map_tab() {
+    let screen = render_tab(TuiTab::Map);
+    assert!(screen.contains("World Map"));
+    assert!(screen.contains("● Berlin 21°"));
+}
+
+#[test]
+fn test_headless_dashboard_layout() {
+    let mut tui = headless_tui();
+    tui.state_mut().set_layout(TuiLayout::Dashboard);
+    let screen = tui.render().unwrap();
+    assert!(screen.contains("Tabs (dashboard active, d to exit)"));
+    assert!(screen.contains("7-Day Summary"));
+    assert!(screen.contains("Sat 01"));
+    assert!(!screen.contains("Weather Calendar──"));
+}
+
+#[test]
+fn test_render_state_without_driver() {
+    let state = fixture_state();
+    let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+    let frame = terminal.draw(|f| render(&state, f)).unwrap();
+    let screen = buffer_to_text(frame.buffer);
+    assert!(screen.contains("Weather Man - Berlin"));
+    assert!(screen.contains("Next 24 Hours"));
+}
+
+#[test]
+fn test_render_content_into_area() {
+    let mut state = fixture_state();
+    state.select_tab(TuiTab::Calendar);
+    let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+    let frame = terminal
+        .draw(|f| render_content(&state, f, Rect::new(10, 5, 80, 20)))
+        .unwrap();
+    let screen = buffer_to_text(frame.buffer);
+    let lines: Vec<&str> = screen.lines().collect();
+
+    // Only the tab itself, inside the given area
+    assert!(!screen.contains("Weather Man"));
+    assert!(!screen.contains("Keys:"));
+    assert!(lines[..5].iter().all(|line| line.is_empty()));
+    assert!(lines[5].starts_with("          ┌Weather Calendar"));
+    assert!(screen.contains("June 2024"));
+}
+
+#[test]
+fn test_handle_key_switches_tabs_and_quits() {
+    let mut state = fixture_state();
+    assert_eq!(state.active_tab(), TuiTab::CurrentWeather);
+
+    assert_eq!(state.handle_key(KeyCode::Char('3')), KeyOutcome::Handled);
+    assert_eq!(state.active_tab(), TuiTab::Calendar);
+    state.handle_key(KeyCode::Right);
+    assert_eq!(state.active_tab(), TuiTab::Calendar);
+    state.handle_key(KeyCode::Tab);
+    assert_eq!(state.active_tab(), TuiTab::Heatmap);
+
+    assert_eq!(state.handle_key(KeyCode::Char('s')), KeyOutcome::Screenshot);
+    assert_eq!(state.handle_key(KeyCode::Char('q')), KeyOutcome::Quit);
+    assert_eq!(state.handle_key(KeyCode::Esc), KeyOutcome::Quit);
+}
+
+#[test]
+fn test_esc_goes_back_through_screens() {
+    let mut state = fixture_state();
+    state.select_tab(TuiTab::Calendar);
+    assert_eq!(state.breadcrumbs(), vec!["Weather Calendar"]);
+
+    // Enter on a forecast day opens it, with help on top
+    state.handle_key(KeyCode::Enter);
+    assert_eq!(state.screen(), Some(Screen::Day));
+    state.handle_key(KeyCode::Char('?'));
+    assert_eq!(state.screen(), Some(Screen::Help));
+    assert_eq!(
+        state.breadcrumbs(),
+        vec!["Weather Calendar", "Saturday 01 June", "Help"]
+    );
+
+    assert_eq!(state.handle_key(KeyCode::Esc), KeyOutcome::Handled);
+    assert_eq!(state.screen(), Some(Screen::Day));
+    assert_eq!(state.handle_key(KeyCode::Esc), KeyOutcome::Handled);
+    assert_eq!(state.screen(), None);
+    assert_eq!(state.active_tab(), TuiTab::Calendar);
+    assert_eq!(state.handle_key(KeyCode::Esc), KeyOutcome::Quit);
+}
+
+#[test]
+fn test_switching_tabs_closes_screens() {
+    let mut state = fixture_state();
+    state.select_tab(TuiTab::WeatherForecast);
+    state.handle_key(KeyCode::Enter);
+    assert_eq!(state.screen(), Some(Screen::Day));
+
+    state.handle_key(KeyCode::Char('4'));
+    assert_eq!(state.screen(), None);
+    assert_eq!(state.active_tab(), TuiTab::Heatmap);
+}
+
+#[test]
+fn test_day_screen_render_shows_breadcrumbs() {
+    let mut tui = headless_tui();
+    tui.state_mut().select_tab(TuiTab::Calendar);
+    tui.state_mut().handle_key(KeyCode::Enter);
+    let screen = tui.render().unwrap();
+
+    assert!(screen.contains("Weather Calendar › Saturday 01 June"));
+    assert!(screen.contains("Temp:    24°C / 12°C"));
+    assert!(!screen.contains("June 2024"));
+    assert!(screen.contains("ESC Back"));
+}
+
+#[test]
+fn test_location_origin_in_title() {
+    let screen = render_tab(TuiTab::CurrentWeather);
+    assert!(screen.contains("(°C)  📍 auto-detected"));
+
+    let mut tui = headless_tui().with_origin(LocationOrigin::Pinned);
+    let screen = tui.render().unwrap();
+    assert!(screen.contains("(°C)  📌 pinned"));
+
+    assert_eq!(
+        tui.state_mut().handle_key(KeyCode::Char('r')),
+        KeyOutcome::Redetect
+    );
+}
+
+#[test]
+fn test_failed_redetect_is_shown_as_status() {
+    let message =
+        "Could not detect your location: IP-based location is disabled in privacy mode".to_string();
+    let mut tui = headless_tui().with_status(Some(message.clone()));
+    let screen = tui.render().unwrap();
+    assert!(screen.contains(&message));
+    assert!(!screen.contains("1-6 Select tab"));
+
+    // The hints come back with the next key press
+    tui.state_mut().handle_key(KeyCode::Char('1'));
+    assert!(tui.render().unwrap().contains("1-6 Select tab"));
+}
+
+#[test]
+fn test_title_shows_data_age() {
+    let mut tui = headless_tui();
+    assert!(tui.render().unwrap().contains("(just now)"));
+    assert_eq!(tui.state().freshness().staleness, Staleness::Fresh);
+
+    let fetched = chrono::Utc::now() - chrono::Duration::hours(3);
+    let mut tui = headless_tui().with_fetched_at(fetched);
+    assert!(tui.render().unwrap().contains("(3 h ago)"));
+    assert_eq!(tui.state().freshness().staleness, Staleness::Stale);
+}
+
+#[test]
+fn test_help_overlay_swallows_next_key() {
+    let mut state = fixture_state();
+    state.handle_key(KeyCode::Char('?'));
+    assert_eq!(state.handle_key(KeyCode::Char('q')), KeyOutcome::Handled);
+    assert_eq!(state.handle_key(KeyCode::Char('q')), KeyOutcome::Quit);
+}
+
+#[test]
+fn test_help_overlay_fits_its_contents() {
+    let mut tui = headless_tui();
+    tui.state_mut().handle_key(KeyCode::Char('?'));
+    let screen = tui.render().unwrap();
+
+    assert!(screen.contains("Esc  Back, or exit weather view"));
+    assert!(screen.contains("Press any key to close"));
+    assert!(screen.contains("Detect the location from your IP address again"));
+}
+
+fn week_state() -> TuiState {
+    let (hourly, daily, location) = fixture_data();
+    let week = (0..7)
+        .map(|i| {
+            let mut day = daily[0].clone();
+            day.date += chrono::Duration::days(i);
+            if i == 2 || i == 4 {
+                day.main_condition = WeatherCondition::Rain;
+            }
+            day
+        })
+        .collect();
+    TuiState::new(
+        hourly,
+        week,
+        location,
+        WeatherConfig::default(),
+        &ConfigFile::default(),
+    )
+    .unwrap()
+}
+
+fn type_text(state: &mut TuiState, text: &str) {
+    for c in text.chars() {
+        state.handle_key(KeyCode::Char(c));
+    }
+}
+
+/// Open the selected day to read its date from the breadcrumbs
+fn selected_day_crumb(state: &mut TuiState) -> String {
+    state.handle_key(KeyCode::Enter);
+    let crumb = state.breadcrumbs().last().unwrap().clone();
+    state.handle_key(KeyCode::Esc);
+    crumb
+}
+
+#[test]
+fn test_search_jumps_between_matching_days() {
+    let mut state = week_state();
+    state.select_tab(TuiTab::WeatherForecast);
+
+    state.handle_key(KeyCode::Char('/'));
+    assert_eq!(state.screen(), Some(Screen::Search));
+    type_text(&mut state, "rain");
+    assert_eq!(state.search(), "rain");
+    state.handle_key(KeyCode::Enter);
+    assert_eq!(state.screen(), None);
+    assert_eq!(selected_day_crumb(&mut state), "Monday 03 June");
+
+    // n moves to the next match and wraps around
+    state.handle_key(KeyCode::Char('n'));
+    assert_eq!(selected_day_crumb(&mut state), "Wednesday 05 June");
+    state.handle_key(KeyCode::Char('n'));
+    assert_eq!(selected_day_crumb(&mut state), "Monday 03 June");
+
+    // Esc clears the search before leaving the view
+    assert_eq!(state.handle_key(KeyCode::Esc), KeyOutcome::Handled);
+    assert_eq!(state.search(), "");
+    assert_eq!(state.handle_key(KeyCode::Esc), KeyOutcome::Quit);
+}
+
+#[test]
+fn test_search_prompt_editing() {
+    let mut state = week_state();
+    state.handle_key(KeyCode::Char('/'));
+    // Keys bound to actions are typed into the prompt
+    type_text(&mut state, "sqx");
+    state.handle_key(KeyCode::Backspace);
+    assert_eq!(state.search(), "sq");
+    assert_eq!(state.handle_key(KeyCode::Esc), KeyOutcome::Handled);
+    assert_eq!(state.search(), "");
+    assert_eq!(state.screen(), None);
+
+    // Only the hourly and daily tabs can be searched
+    state.select_tab(TuiTab::Map);
+    state.handle_key(KeyCode::Char('/'));
+    assert_eq!(state.screen(), None);
+}
+
+#[test]
+fn test_search_filters_hourly_strip() {
+    let mut tui = headless_tui();
+    tui.state_mut().handle_key(KeyCode::Char('/'));
+    type_text(tui.state_mut(), "11:");
+    let screen = tui.render().unwrap();
+
+    assert!(screen.contains("Hours matching \"11:\" (1)"));
+    assert!(screen.contains("Search: 11:▏"));
+    assert!(screen.contains(" 11h "));
+    assert!(!screen.contains(" 10h "));
+}
+
+#[test]
+fn test_ctrl_c_quits_and_other_ctrl_keys_are_ignored() {
+    let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+
+    let mut tui = headless_tui();
+    let state = tui.state_mut();
+    assert_eq!(state.handle_key(ctrl('s')), KeyOutcome::Handled);
+    assert_eq!(state.handle_key(ctrl('r')), KeyOutcome::Handled);
+    assert_eq!(state.handle_key(ctrl('d')), KeyOutcome::Handled);
+    assert_eq!(state.handle_key(ctrl('c')), KeyOutcome::Quit);
+    assert!(!tui.render().unwrap().contains("dashboard active"));
+
+    // Ctrl-C also quits from the help overlay
+    let state = tui.state_mut();
+    state.handle_key(KeyCode::Char('?'));
+    assert_eq!(state.handle_key(ctrl('c')), KeyOutcome::Quit);
+}
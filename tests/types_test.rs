@@ -1,4 +1,6 @@
-use weather_man::modules::types::{DetailLevel, Location, WeatherCondition, WeatherConfig};
+use weather_man::modules::types::{
+    DetailLevel, Hpa, Location, Percent, WeatherCondition, WeatherConfig,
+};
 
 #[test]
 fn test_weather_condition_from_str() {
@@ -17,6 +19,8 @@ fn test_weather_condition_from_str() {
         WeatherCondition::Thunderstorm
     );
     assert_eq!(WeatherCondition::from_str("snow"), WeatherCondition::Snow);
+    assert_eq!(WeatherCondition::from_str("sleet"), WeatherCondition::Sleet);
+    assert_eq!(WeatherCondition::from_str("hail"), WeatherCondition::Hail);
     assert_eq!(WeatherCondition::from_str("mist"), WeatherCondition::Mist);
     assert_eq!(WeatherCondition::from_str("fog"), WeatherCondition::Fog);
     assert_eq!(WeatherCondition::from_str("smoke"), WeatherCondition::Smoke);
@@ -50,6 +54,8 @@ fn test_weather_condition_get_emoji() {
     assert_eq!(WeatherCondition::Drizzle.get_emoji(), "🌦️");
     assert_eq!(WeatherCondition::Thunderstorm.get_emoji(), "⛈️");
     assert_eq!(WeatherCondition::Snow.get_emoji(), "❄️");
+    assert_eq!(WeatherCondition::Sleet.get_emoji(), "🌨️");
+    assert_eq!(WeatherCondition::Hail.get_emoji(), "🧊");
     assert_eq!(WeatherCondition::Mist.get_emoji(), "🌫️");
     assert_eq!(WeatherCondition::Fog.get_emoji(), "🌫️");
     assert_eq!(WeatherCondition::Smoke.get_emoji(), "🌫️");
@@ -70,6 +76,8 @@ fn test_weather_condition_display() {
     assert_eq!(WeatherCondition::Drizzle.to_string(), "Drizzle");
     assert_eq!(WeatherCondition::Thunderstorm.to_string(), "Thunderstorm");
     assert_eq!(WeatherCondition::Snow.to_string(), "Snowy");
+    assert_eq!(WeatherCondition::Sleet.to_string(), "Sleet");
+    assert_eq!(WeatherCondition::Hail.to_string(), "Hail");
     assert_eq!(WeatherCondition::Mist.to_string(), "Misty");
     assert_eq!(WeatherCondition::Fog.to_string(), "Foggy");
     assert_eq!(WeatherCondition::Smoke.to_string(), "Smoky");
@@ -97,6 +105,7 @@ fn test_detail_level() {
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn test_weather_config_default() {
     let config = WeatherConfig::default();
     assert_eq!(config.units, "metric");
@@ -118,3 +127,12 @@ fn test_location_default() {
     assert_eq!(location.region, None);
     assert_eq!(location.state, None);
 }
+
+#[test]
+fn test_unit_newtypes_display() {
+    assert_eq!(Hpa(1013.25).to_string(), "1013 hPa");
+    assert_eq!(format!("{:.1}", Hpa(1013.25)), "1013.2 hPa");
+    assert_eq!(Percent(64.6).to_string(), "65%");
+    assert_eq!(Percent(40.0).fraction(), 0.4);
+    assert_eq!(serde_json::to_string(&Hpa(1013.25)).unwrap(), "1013.25");
+}
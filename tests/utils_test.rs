@@ -1 +1,168 @@
-// This file is kept as a placeholder for utility tests
+use chrono::{Duration, TimeZone, Utc};
+use weather_man::modules::utils::{
+    beaufort, beaufort_description, relative_time, to_json, wind_barb, ClockStyle, DisplayUnits,
+    Language, PrecipUnit, PressureUnit, TempUnit, WindBarb, WindUnit,
+};
+
+#[test]
+fn test_wind_barb_counts() {
+    assert!(WindBarb::from_speed(0.5).is_calm());
+    // 15 kt: one full and one half barb
+    assert_eq!(
+        WindBarb::from_speed(7.7),
+        WindBarb {
+            pennants: 0,
+            full: 1,
+            half: 1
+        }
+    );
+    // 65 kt: pennant, full barb and half barb
+    assert_eq!(
+        WindBarb::from_speed(33.4),
+        WindBarb {
+            pennants: 1,
+            full: 1,
+            half: 1
+        }
+    );
+}
+
+#[test]
+fn test_wind_barb_text() {
+    assert_eq!(wind_barb(0.0, 90), "○");
+    assert_eq!(wind_barb(10.3, 0), "↓╱╱");
+}
+
+#[test]
+fn test_beaufort_scale() {
+    assert_eq!(beaufort(0.2), 0);
+    assert_eq!(beaufort(9.0), 5);
+    assert_eq!(beaufort_description(beaufort(9.0)), "fresh breeze");
+    assert_eq!(beaufort_description(beaufort(18.0)), "gale");
+    assert_eq!(beaufort(40.0), 12);
+}
+
+#[test]
+fn test_unit_conversions() {
+    assert_eq!(WindUnit::Kmh.format(10.0), "36.0 km/h");
+    assert!((WindUnit::Kn.convert(10.0) - 19.44).abs() < 0.01);
+    assert!((WindUnit::Mph.convert(10.0) - 22.37).abs() < 0.01);
+    assert_eq!(PressureUnit::Inhg.format(1013.25), "29.92 inHg");
+    assert_eq!(PressureUnit::Mmhg.format(1013.25), "760 mmHg");
+    assert_eq!(PrecipUnit::In.format(25.4), "1.00 in");
+
+    let imperial = DisplayUnits::for_system("imperial");
+    assert_eq!(imperial.wind, WindUnit::Mph);
+    assert_eq!(imperial.pressure, PressureUnit::Inhg);
+    assert_eq!(DisplayUnits::for_system("metric"), DisplayUnits::default());
+
+    assert_eq!(imperial.temperature, TempUnit::F);
+    assert_eq!(TempUnit::F.format(21.5), "71°F");
+    assert_eq!(TempUnit::F.convert(-40.0), -40.0);
+    assert_eq!(TempUnit::C.format(-3.4), "-3°C");
+}
+
+#[test]
+fn test_to_json_compact() {
+    let value = serde_json::json!({ "temperature": 21.5, "hours": [1, 2] });
+    let compact = to_json(&value, true).unwrap();
+    assert_eq!(compact, r#"{"hours":[1,2],"temperature":21.5}"#);
+    let pretty = to_json(&value, false).unwrap();
+    assert!(pretty.contains('\n'));
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&pretty).unwrap(),
+        value
+    );
+}
+
+#[test]
+fn test_relative_time() {
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 8, 0, 0).unwrap();
+    let at = |hours: i64, minutes: i64| {
+        relative_time(
+            now + Duration::hours(hours) + Duration::minutes(minutes),
+            now,
+            "UTC",
+            Language::English,
+        )
+    };
+    assert_eq!(at(0, -5), "now");
+    assert_eq!(at(0, 1), "in 1 minute");
+    assert_eq!(at(0, 20), "in 20 minutes");
+    assert_eq!(at(1, 0), "in 1 hour");
+    assert_eq!(at(3, 0), "in 3 hours");
+    assert_eq!(at(10, 0), "this evening");
+    assert_eq!(at(14, 0), "tonight");
+    assert_eq!(at(25, 0), "tomorrow morning");
+    assert_eq!(at(72, 0), "Tuesday morning");
+    assert_eq!(at(240, 0), "on Tue 11 Jun");
+}
+
+#[test]
+fn test_relative_time_languages() {
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 8, 0, 0).unwrap();
+    let at = |language: Language, hours: i64| {
+        relative_time(now + Duration::hours(hours), now, "UTC", language)
+    };
+    use Language::*;
+    let cases = [
+        (
+            German,
+            [
+                "in 3 Stunden",
+                "heute Abend",
+                "morgen früh",
+                "Dienstagmorgen",
+                "am 11.6.",
+            ],
+        ),
+        (
+            French,
+            [
+                "dans 3 heures",
+                "ce soir",
+                "demain matin",
+                "mardi matin",
+                "le 11/06",
+            ],
+        ),
+        (
+            Spanish,
+            [
+                "en 3 horas",
+                "esta tarde",
+                "mañana por la mañana",
+                "el martes por la mañana",
+                "el 11/06",
+            ],
+        ),
+    ];
+    for (language, phrases) in cases {
+        for (hours, phrase) in [3, 10, 25, 72, 240].into_iter().zip(phrases) {
+            assert_eq!(at(language, hours), phrase);
+        }
+    }
+    assert_eq!(at(German, 14), "heute Nacht");
+    assert_eq!(at(French, 6), "cet après-midi");
+}
+
+#[test]
+fn test_language_for_locale() {
+    assert_eq!(Language::for_locale("de_DE.UTF-8"), Language::German);
+    assert_eq!(Language::for_locale("fr-CA"), Language::French);
+    assert_eq!(Language::for_locale("es_MX@euro"), Language::Spanish);
+    // Locales without their own phrases fall back to English
+    assert_eq!(Language::for_locale("ja_JP.UTF-8"), Language::English);
+    assert_eq!(Language::for_locale("C"), Language::English);
+}
+
+#[test]
+fn test_clock_style() {
+    assert_eq!(ClockStyle::for_locale("en_US.UTF-8"), ClockStyle::H12);
+    assert_eq!(ClockStyle::for_locale("de_DE.UTF-8"), ClockStyle::H24);
+    assert_eq!(ClockStyle::for_locale("C"), ClockStyle::H24);
+
+    let time = Utc.with_ymd_and_hms(2024, 6, 1, 14, 5, 0).unwrap();
+    assert_eq!(ClockStyle::H12.format(time), "2:05 PM");
+    assert_eq!(ClockStyle::H24.format(time), "14:05");
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use chrono::{DateTime, TimeZone, Utc};
+use common::series;
+use weather_man::modules::uv::{uv_plan, UvLevel};
+
+fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 6, 1, hour, minute, 0).unwrap()
+}
+
+#[test]
+fn test_windows_interpolated_to_the_minute() {
+    // Zero until 08:00, peaking at 8 at noon
+    let mut values = vec![Some(0.0); 8];
+    values.extend([1.0, 2.0, 4.0, 6.0, 8.0, 7.0, 5.0, 2.0, 0.0].map(Some));
+    let plan = uv_plan(&series(&values, |h, uv| h.uv_index = uv), 3.0, 6.0);
+
+    assert_eq!(plan.protect.len(), 1);
+    assert_eq!(plan.protect[0].start, at(9, 30));
+    assert_eq!(plan.protect[0].end, at(14, 40));
+    assert_eq!(plan.avoid.len(), 1);
+    assert_eq!(plan.avoid[0].start, at(11, 0));
+    assert_eq!(plan.avoid[0].end, at(13, 30));
+
+    let peak = plan.peak.unwrap();
+    assert_eq!(peak.timestamp, at(12, 0));
+    assert_eq!(plan.hours[12].level, UvLevel::Avoid);
+    assert_eq!(plan.hours[10].level, UvLevel::Protect);
+    assert_eq!(plan.hours[8].level, UvLevel::Low);
+}
+
+#[test]
+fn test_low_uv_and_missing_values() {
+    let hours = series(&[None, Some(1.0), Some(2.5), None], |h, uv| h.uv_index = uv);
+    let plan = uv_plan(&hours, 3.0, 6.0);
+    assert_eq!(plan.hours.len(), 2);
+    assert!(plan.protect.is_empty());
+    assert!(plan.avoid.is_empty());
+
+    let plan = uv_plan(&series(&[None, None], |h, uv| h.uv_index = uv), 3.0, 6.0);
+    assert!(plan.hours.is_empty());
+    assert!(plan.peak.is_none());
+}
@@ -0,0 +1,40 @@
+use weather_man::modules::forecaster::WeatherForecaster;
+use weather_man::modules::http::HttpContext;
+use weather_man::modules::types::WeatherConfig;
+use weather_man::modules::validate::validate_forecast;
+
+fn fixture() -> serde_json::Value {
+    serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap()
+}
+
+#[test]
+fn test_parse_clamps_glitched_values() {
+    let mut json = fixture();
+    json["current"]["temperature_2m"] = 999.0.into();
+    json["hourly"]["relative_humidity_2m"][0] = 180.into();
+    json["hourly"]["precipitation_probability"][1] = 150.into();
+    json["daily"]["temperature_2m_min"][0] = 30.0.into();
+
+    let forecaster = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default());
+    let forecast = forecaster.parse_openmeteo_forecast(&json).unwrap();
+
+    assert_eq!(forecast.current.unwrap().temperature, 60.0);
+    assert_eq!(forecast.hourly[0].humidity.0, 100.0);
+    assert_eq!(forecast.hourly[1].pop, 1.0);
+    // Minimum above maximum is swapped back
+    assert_eq!(forecast.daily[0].temp_min, 23.5);
+    assert_eq!(forecast.daily[0].temp_max, 30.0);
+}
+
+#[test]
+fn test_validate_drops_out_of_order_hours() {
+    let mut json = fixture();
+    json["hourly"]["time"][1] = "2024-06-01T12:00".into();
+
+    let forecaster = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default());
+    let mut forecast = forecaster.parse_openmeteo_forecast(&json).unwrap();
+    assert_eq!(forecast.hourly.len(), 1);
+
+    // Already clean data has nothing left to report
+    assert!(validate_forecast(&mut forecast).is_empty());
+}
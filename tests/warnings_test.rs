@@ -0,0 +1,50 @@
+mod common;
+
+use chrono::{Duration, TimeZone, Utc};
+use common::day_at;
+use weather_man::modules::config::{ConfigFile, WarningSettings};
+use weather_man::modules::types::DailyForecast;
+use weather_man::modules::warnings::multi_day_warnings;
+
+fn days(temps: &[(f64, f64)]) -> Vec<DailyForecast> {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    temps
+        .iter()
+        .enumerate()
+        .map(|(i, (min, max))| DailyForecast {
+            temp_min: *min,
+            temp_max: *max,
+            ..day_at(start + Duration::days(i as i64))
+        })
+        .collect()
+}
+
+#[test]
+fn test_frost_run_needs_consecutive_nights() {
+    let daily = days(&[
+        (-2.0, 4.0),
+        (3.0, 8.0),
+        (-1.0, 5.0),
+        (-4.0, 2.0),
+        (-3.0, 3.0),
+    ]);
+    let alerts = multi_day_warnings(&daily, &WarningSettings::default());
+
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].event, "Frost warning");
+    assert_eq!(alerts[0].start, daily[2].date);
+    assert_eq!(alerts[0].end, daily[4].date + Duration::days(1));
+    assert!(alerts[0].description.contains("3 consecutive nights"));
+    assert!(alerts[0].description.contains("lowest -4°C"));
+}
+
+#[test]
+fn test_heat_threshold_from_config() {
+    let daily = days(&[(18.0, 27.0), (19.0, 28.0), (20.0, 26.0), (15.0, 22.0)]);
+    assert!(multi_day_warnings(&daily, &WarningSettings::default()).is_empty());
+
+    let config = ConfigFile::parse("[warnings]\nheat_above = 25\nheat_days = 3\n").unwrap();
+    let alerts = multi_day_warnings(&daily, &config.warnings);
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].tags, vec!["heat"]);
+}
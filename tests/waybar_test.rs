@@ -0,0 +1,62 @@
+use chrono::{TimeZone, Utc};
+use weather_man::modules::forecaster::WeatherForecaster;
+use weather_man::modules::http::HttpContext;
+use weather_man::modules::types::{Location, WeatherConfig};
+use weather_man::modules::waybar::{waybar_error, waybar_output};
+
+#[test]
+fn test_waybar_output() {
+    let json = serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    let mut forecast = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default())
+        .parse_openmeteo_forecast(&json)
+        .unwrap();
+    let location = Location {
+        name: "Berlin".to_string(),
+        timezone: "UTC".to_string(),
+        ..Location::default()
+    };
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 10, 30, 0).unwrap();
+
+    let output = waybar_output(&forecast, &location, now).unwrap();
+    assert_eq!(output.text, "☁️ 21°");
+    assert_eq!(output.class, "clouds");
+    let lines: Vec<_> = output.tooltip.lines().collect();
+    assert_eq!(lines[0], "Berlin: Cloudy, 21° (feels 20°)");
+    assert!(lines[1].starts_with("Sat  ☁️ 12° / 24°"));
+
+    // Only the first three days make the tooltip
+    let day = forecast.daily[0].clone();
+    forecast.daily = vec![day; 5];
+    let output = waybar_output(&forecast, &location, now).unwrap();
+    assert_eq!(output.tooltip.lines().count(), 4);
+
+    let value = serde_json::to_value(&output).unwrap();
+    assert_eq!(value.as_object().unwrap().len(), 3);
+    assert!(value["tooltip"].is_string());
+}
+
+#[test]
+fn test_waybar_weekday_east_of_plus_twelve() {
+    let json = serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    let mut forecast = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default())
+        .parse_openmeteo_forecast(&json)
+        .unwrap();
+    // Monday 15 January, when Auckland is at UTC+13
+    forecast.daily[0].date = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+    let location = Location {
+        name: "Auckland".to_string(),
+        timezone: "Pacific/Auckland".to_string(),
+        ..Location::default()
+    };
+    let now = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+
+    let output = waybar_output(&forecast, &location, now).unwrap();
+    assert!(output.tooltip.lines().nth(1).unwrap().starts_with("Mon "));
+}
+
+#[test]
+fn test_waybar_error() {
+    let value = serde_json::to_value(waybar_error("offline")).unwrap();
+    assert_eq!(value["class"], "error");
+    assert_eq!(value["tooltip"], "offline");
+}
@@ -0,0 +1,88 @@
+use chrono::{TimeZone, Utc};
+use unicode_width::UnicodeWidthStr;
+use weather_man::modules::forecaster::WeatherForecaster;
+use weather_man::modules::http::HttpContext;
+use weather_man::modules::icons::IconSet;
+use weather_man::modules::types::{Location, WeatherConfig};
+use weather_man::modules::widget::{widget_from_forecast, Widget, WIDGET_HEIGHT, WIDGET_WIDTH};
+
+fn widget_with(icons: IconSet) -> Widget {
+    let json = serde_json::from_str(include_str!("fixtures/openmeteo_kmh.json")).unwrap();
+    let forecast = WeatherForecaster::new(WeatherConfig::default(), HttpContext::default())
+        .parse_openmeteo_forecast(&json)
+        .unwrap();
+    let location = Location {
+        name: "Berlin".to_string(),
+        ..Location::default()
+    };
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 10, 0, 0).unwrap();
+    widget_from_forecast(&forecast, &location, icons, now).unwrap()
+}
+
+fn widget() -> Widget {
+    widget_with(IconSet::Emoji)
+}
+
+fn assert_size(lines: &[String], width: usize, height: usize) {
+    assert_eq!(lines.len(), height);
+    for line in lines {
+        assert_eq!(line.as_str().width(), width, "{:?}", line);
+    }
+}
+
+#[test]
+fn test_widget_from_forecast() {
+    let widget = widget();
+    assert_eq!(widget.location, "Berlin");
+    assert_eq!(widget.temperature, 21.4);
+    assert_eq!(widget.high, Some(23.5));
+    assert_eq!(widget.low, Some(12.1));
+    // Today's chance, not the next hour's
+    assert_eq!(widget.rain_chance, 0.2);
+}
+
+#[test]
+fn test_widget_card() {
+    let lines = widget().render(WIDGET_WIDTH, WIDGET_HEIGHT);
+    assert_size(&lines, WIDGET_WIDTH, WIDGET_HEIGHT);
+    assert!(lines[0].starts_with('╭'));
+    assert!(lines[9].starts_with('╰'));
+    let card = lines.join("\n");
+    for text in ["21°", "Berlin", "↑ 24°  ↓ 12°", "☔ 20%"] {
+        assert!(card.contains(text), "{} missing from\n{}", text, card);
+    }
+}
+
+#[test]
+fn test_widget_card_sizes() {
+    let widget = Widget {
+        location: "Llanfairpwllgwyngyll, Wales".to_string(),
+        ..widget()
+    };
+    let wide = widget.render(50, 14);
+    assert_size(&wide, 50, 14);
+    assert!(wide.join("\n").contains("Llanfairpwllgwyngyll, Wales"));
+
+    // Small cards keep the temperature and drop spacing, then the details
+    let small = widget.render(16, 4);
+    assert_size(&small, 16, 4);
+    assert!(small[1].contains("21°"));
+    assert!(small[2].contains("Llanfairpwl…"));
+
+    // Sizes below the minimum are raised to it
+    assert_size(&widget.render(1, 1), 12, 3);
+}
+
+#[test]
+fn test_widget_icon_sets() {
+    // The fixture's current weather code 2 is cloudy
+    assert_eq!(widget().icon, "☁️");
+    assert_eq!(widget_with(IconSet::Nerdfont).icon, "\u{e312}");
+    let ascii = widget_with(IconSet::Ascii);
+    assert_eq!(ascii.icon, "=");
+    assert_size(
+        &ascii.render(WIDGET_WIDTH, WIDGET_HEIGHT),
+        WIDGET_WIDTH,
+        WIDGET_HEIGHT,
+    );
+}
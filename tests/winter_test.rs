@@ -0,0 +1,81 @@
+mod common;
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use common::{location, series_from};
+use weather_man::modules::types::{HourlyForecast, WeatherCondition};
+use weather_man::modules::winter::{snowfall_level, winter_report};
+
+/// Midnight UTC on 10 January 2024, where fixture hours start
+fn first() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap()
+}
+
+/// Frosty hours from `first()` with the given snowfall and snow depth, and
+/// the freezing level dropping 10 m an hour from 900 m
+fn winter_hours(snow_and_depth: &[(f64, f64)]) -> Vec<HourlyForecast> {
+    let mut hours = series_from(first(), snow_and_depth, |h, (snow, depth)| {
+        h.temperature = -2.0;
+        h.main_condition = if snow > 0.0 {
+            WeatherCondition::Snow
+        } else {
+            WeatherCondition::Clouds
+        };
+        h.snow = Some(snow);
+        h.snow_depth = Some(depth);
+    });
+    for (i, hour) in hours.iter_mut().enumerate() {
+        hour.freezing_level = Some(900.0 - i as f64 * 10.0);
+    }
+    hours
+}
+
+#[test]
+fn test_snowfall_level() {
+    assert_eq!(snowfall_level(1200.0), 900.0);
+    assert_eq!(snowfall_level(100.0), 0.0);
+}
+
+#[test]
+fn test_winter_report_with_snow() {
+    // Snow falls from 30:00 (06:00 on the second day) for four hours
+    let snow_and_depth: Vec<(f64, f64)> = (0..48)
+        .map(|i| {
+            let snow = if (30..34).contains(&i) { 1.5 } else { 0.0 };
+            let depth = if i >= 30 { 6.0 } else { 0.0 };
+            (snow, depth)
+        })
+        .collect();
+    let hourly = winter_hours(&snow_and_depth);
+    let now = Utc.with_ymd_and_hms(2024, 1, 10, 12, 15, 0).unwrap();
+
+    let report = winter_report(&hourly, &location(), now).unwrap();
+    assert_eq!(report.snow_depth, Some(0.0));
+    assert_eq!(report.snowfall, 6.0);
+    assert_eq!(
+        report.first_snow,
+        Some(Utc.with_ymd_and_hms(2024, 1, 11, 6, 0, 0).unwrap())
+    );
+
+    assert_eq!(report.days.len(), 2);
+    let tomorrow = &report.days[1];
+    assert_eq!(tomorrow.date, NaiveDate::from_ymd_opt(2024, 1, 11).unwrap());
+    assert_eq!(tomorrow.snowfall, 6.0);
+    assert_eq!(tomorrow.snow_depth, Some(6.0));
+    assert_eq!(tomorrow.min_freezing_level, Some(430.0));
+    assert_eq!(tomorrow.min_snowfall_level, Some(130.0));
+    // Today starts at the current hour
+    assert_eq!(report.days[0].min_freezing_level, Some(670.0));
+}
+
+#[test]
+fn test_winter_report_only_with_snow() {
+    let now = first();
+    let dry = winter_hours(&[(0.0, 0.0); 24]);
+    assert!(winter_report(&dry, &location(), now).is_none());
+
+    // Lying snow alone is enough
+    let covered = winter_hours(&[(0.0, 20.0); 24]);
+    let report = winter_report(&covered, &location(), now).unwrap();
+    assert_eq!(report.snow_depth, Some(20.0));
+    assert!(report.first_snow.is_none());
+}
@@ -0,0 +1,85 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use weather_man::modules::climate::{ClimateDay, ClimateService};
+use weather_man::modules::http::HttpContext;
+use weather_man::modules::types::Location;
+use weather_man::modules::yearly::{report_html, report_lines, year_report};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+/// 2023 with a seasonal cycle, rain every fourth day and a wet October
+fn year_2023() -> Vec<ClimateDay> {
+    let start = date(2023, 1, 1);
+    (0..365)
+        .map(|i| {
+            let date = start + Duration::days(i);
+            let season = (date.ordinal0() as f64 / 365.0 * std::f64::consts::TAU).cos();
+            ClimateDay {
+                date,
+                temp_max: Some(15.0 - 12.0 * season),
+                temp_min: Some(7.0 - 10.0 * season),
+                precipitation: Some(match (date.month(), i % 4) {
+                    (10, _) => 5.0,
+                    (_, 0) => 2.0,
+                    _ => 0.0,
+                }),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_year_report() {
+    let mut days = year_2023();
+    // Days from other years are ignored
+    days.push(ClimateDay {
+        date: date(2024, 1, 1),
+        temp_max: Some(40.0),
+        temp_min: Some(-30.0),
+        precipitation: Some(100.0),
+    });
+    let report = year_report(&days, 2023).unwrap();
+
+    assert_eq!(
+        (report.start, report.end),
+        (date(2023, 1, 1), date(2023, 12, 31))
+    );
+    assert_eq!(report.hottest_day.date.month(), 7);
+    assert!((report.hottest_day.value - 27.0).abs() < 0.01);
+    assert_eq!(report.coldest_day.date, date(2023, 1, 1));
+    assert_eq!(report.coldest_day.value, -3.0);
+    assert_eq!(report.wettest_month.month, 10);
+    assert_eq!(report.wettest_month.precipitation, 155.0);
+    assert_eq!(report.wettest_day.value, 5.0);
+    assert!(report.heating_degree_days > report.cooling_degree_days);
+    assert!(report.cooling_degree_days > 0.0);
+
+    assert!(year_report(&days, 2022).is_none());
+}
+
+#[test]
+fn test_report_text_and_html() {
+    let report = year_report(&year_2023(), 2023).unwrap();
+    let lines = report_lines(&report, "Berlin");
+    assert_eq!(lines[0], "Berlin in 2023");
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("Wettest month:    October (155 mm)")));
+
+    let html = report_html(&report, "Q&A <town>");
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<h1>Q&amp;A &lt;town&gt; in 2023</h1>"));
+    assert!(html.contains("<tr><th>Wettest month</th><td>October (155 mm)</td></tr>"));
+}
+
+#[tokio::test]
+async fn test_year_out_of_range() {
+    let service = ClimateService::new(HttpContext::default(), None);
+    let location = Location::default();
+    let today = date(2024, 1, 3);
+    assert!(service.year(&location, 1900, today).await.is_err());
+    assert!(service.year(&location, 2025, today).await.is_err());
+    // Nothing in the archive yet for the first days of the year
+    assert!(service.year(&location, 2024, today).await.is_err());
+}